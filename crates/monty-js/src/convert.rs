@@ -21,6 +21,7 @@
 //!
 //! ### Marked JS types (with `__monty_type__` property):
 //! - `MontyObject::Ellipsis` → `{ __monty_type__: 'Ellipsis' }`
+//! - `MontyObject::NotImplemented` → `{ __monty_type__: 'NotImplemented' }`
 //! - `MontyObject::Tuple` → `Array` with `__tuple__: true`
 //! - `MontyObject::Exception` → `{ __monty_type__: 'Exception', excType, message }`
 //! - `MontyObject::Type` → `{ __monty_type__: 'Type', value }`
@@ -71,7 +72,8 @@ impl ToNapiValue for JsMontyObject<'_> {
 pub fn monty_to_js<'e>(obj: &MontyObject, env: &'e Env) -> Result<JsMontyObject<'e>> {
     let unknown = match obj {
         MontyObject::None => create_js_null(env)?,
-        MontyObject::Ellipsis => create_js_ellipsis(env)?,
+        MontyObject::Ellipsis => create_js_marker("Ellipsis", env)?,
+        MontyObject::NotImplemented => create_js_marker("NotImplemented", env)?,
         MontyObject::Bool(b) => create_js_bool(*b, env)?,
         MontyObject::Int(i) => create_js_int(*i, env)?,
         MontyObject::BigInt(bi) => create_js_bigint(bi, env)?,
@@ -239,10 +241,10 @@ fn create_js_set<'e>(items: &[MontyObject], env: &'e Env) -> Result<Unknown<'e>>
     set.into_unknown(env)
 }
 
-/// Creates a JS object representing Ellipsis: `{ __monty_type__: 'Ellipsis' }`.
-fn create_js_ellipsis(env: &Env) -> Result<Unknown<'_>> {
+/// Creates a JS object representing a singleton like Ellipsis: `{ __monty_type__: 'Ellipsis' }`.
+fn create_js_marker<'e>(monty_type: &str, env: &'e Env) -> Result<Unknown<'e>> {
     let mut obj = Object::new(env)?;
-    obj.set_named_property("__monty_type__", "Ellipsis")?;
+    obj.set_named_property("__monty_type__", monty_type)?;
     obj.into_unknown(env)
 }
 
@@ -540,6 +542,7 @@ fn js_array_to_monty(arr: Object, env: Env) -> Result<MontyObject> {
 fn js_marked_object_to_monty(obj: &Object, monty_type: &str, env: Env) -> Result<MontyObject> {
     match monty_type {
         "Ellipsis" => Ok(MontyObject::Ellipsis),
+        "NotImplemented" => Ok(MontyObject::NotImplemented),
         "Exception" => {
             let exc_type_str: String = obj.get_named_property("excType")?;
             let message: String = obj.get_named_property("message")?;
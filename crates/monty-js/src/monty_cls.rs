@@ -228,7 +228,7 @@ impl Monty {
         external_functions: Option<Object<'env>>,
     ) -> Result<Either<JsMontyObject<'env>, JsMontyException>> {
         let mut print_output = CollectStringPrint::default();
-        let runner = self.runner.clone();
+        let runner = &self.runner;
 
         // Helper macro to handle the execution loop for both tracker types
         macro_rules! run_loop {
@@ -304,8 +304,7 @@ impl Monty {
         // Extract input values
         let input_values = self.extract_input_values(options.and_then(|opts| opts.inputs), *env)?;
 
-        // Clone the runner since start() consumes it - allows reuse of the parsed code
-        let runner = self.runner.clone();
+        let runner = &self.runner;
         let mut print_output = CollectStringPrint::default();
 
         // Start execution with appropriate tracker
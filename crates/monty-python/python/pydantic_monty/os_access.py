@@ -1,6 +1,8 @@
 from __future__ import annotations
 
+import io
 from abc import ABC, abstractmethod
+from functools import cached_property
 from pathlib import PurePosixPath
 from typing import TYPE_CHECKING, Any, Callable, Literal, NamedTuple, Protocol, Sequence, TypeAlias, TypeGuard
 
@@ -29,6 +31,13 @@ OsFunction = Literal[
     'Path.absolute',
     'os.getenv',
     'os.environ',
+    'input',
+    'open',
+    'file.read',
+    'file.readline',
+    'file.readlines',
+    'file.write',
+    'file.close',
 ]
 
 
@@ -172,6 +181,20 @@ class AbstractOS(ABC):
                 return self.getenv(*args)
             case 'os.environ':
                 return self.get_environ()
+            case 'input':
+                return self.input(*args)
+            case 'open':
+                return self.file_open(*args)
+            case 'file.read':
+                return self.file_read(*args)
+            case 'file.readline':
+                return self.file_readline(*args)
+            case 'file.readlines':
+                return self.file_readlines(*args)
+            case 'file.write':
+                return self.file_write(*args)
+            case 'file.close':
+                return self.file_close(*args)
 
     @abstractmethod
     def path_exists(self, path: PurePosixPath) -> bool:
@@ -422,6 +445,94 @@ class AbstractOS(ABC):
         """
         raise NotImplementedError
 
+    def input(self, prompt: str) -> str:
+        """Read a line of input for Monty's `input()` builtin.
+
+        The default implementation behaves like an empty stdin and raises `EOFError`.
+        Override it to supply lines to Monty code.
+
+        Args:
+            prompt: The prompt passed to `input()`, already converted to a string.
+
+        Returns:
+            The line read, without a trailing newline.
+        """
+        raise EOFError('EOF when reading a line')
+
+    def file_open(self, path: str, mode: str) -> int:
+        """Open a file for Monty's `open()` builtin and return a handle for it.
+
+        The default implementation buffers the whole file in memory using `path_read_text`,
+        `path_read_bytes`, `path_write_text` and `path_write_bytes`. Writes are flushed back
+        when the file is closed. Override the `file_*` methods together to stream instead.
+
+        Args:
+            path: The path passed to `open()`.
+            mode: The mode passed to `open()`, already validated by Monty.
+
+        Returns:
+            An integer handle passed to the other `file_*` methods.
+        """
+        binary = 'b' in mode
+        file_path = PurePosixPath(path)
+        if 'r' in mode or 'a' in mode:
+            if 'a' in mode and not self.path_exists(file_path):
+                content: str | bytes = b'' if binary else ''
+            else:
+                content = self.path_read_bytes(file_path) if binary else self.path_read_text(file_path)
+        elif 'x' in mode and self.path_exists(file_path):
+            raise FileExistsError(f'[Errno 17] File exists: {path!r}')
+        else:
+            content = b'' if binary else ''
+        buffer = io.BytesIO(content) if isinstance(content, bytes) else io.StringIO(content)
+        if 'a' in mode:
+            buffer.seek(0, io.SEEK_END)
+        handle = max(self._open_files, default=2) + 1
+        self._open_files[handle] = (file_path, mode, buffer)
+        return handle
+
+    def file_read(self, handle: int, size: int) -> str | bytes:
+        """Read up to `size` characters (or bytes) from a file, or everything if `size` is -1."""
+        return self._open_file(handle)[2].read(size)
+
+    def file_readline(self, handle: int, size: int) -> str | bytes:
+        """Read the next line from a file, returning an empty string at end of file."""
+        return self._open_file(handle)[2].readline(size)
+
+    def file_readlines(self, handle: int) -> list[str] | list[bytes]:
+        """Read the remaining lines from a file."""
+        return self._open_file(handle)[2].readlines()
+
+    def file_write(self, handle: int, data: str | bytes) -> int:
+        """Write `data` to a file, returning the number of characters (or bytes) written."""
+        return self._open_file(handle)[2].write(data)  # pyright: ignore[reportArgumentType]
+
+    def file_close(self, handle: int) -> None:
+        """Close a file, writing its contents back if it was opened for writing."""
+        path, mode, buffer = self._open_file(handle)
+        del self._open_files[handle]
+        if 'r' not in mode or '+' in mode:
+            content = buffer.getvalue()
+            if isinstance(content, bytes):
+                self.path_write_bytes(path, content)
+            else:
+                self.path_write_text(path, content)
+
+    @cached_property
+    def _open_files(self) -> dict[int, tuple[PurePosixPath, str, Any]]:
+        """Files opened by the default `file_open`, keyed by handle, as `(path, mode, buffer)`.
+
+        A cached property rather than an attribute set in `__init__`, so subclasses that
+        don't call `super().__init__()` still get their own table.
+        """
+        return {}
+
+    def _open_file(self, handle: int) -> tuple[PurePosixPath, str, Any]:
+        try:
+            return self._open_files[handle]
+        except KeyError:
+            raise ValueError('I/O operation on closed file.') from None
+
 
 class AbstractFile(Protocol):
     """Protocol defining the interface for files used with OSAccess.
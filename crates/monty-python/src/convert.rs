@@ -97,6 +97,8 @@ pub fn py_to_monty(obj: &Bound<'_, PyAny>) -> PyResult<MontyObject> {
         Ok(MontyObject::FrozenSet(items?))
     } else if obj.is(obj.py().Ellipsis()) {
         Ok(MontyObject::Ellipsis)
+    } else if obj.is(obj.py().NotImplemented()) {
+        Ok(MontyObject::NotImplemented)
     } else if let Ok(exc) = obj.cast::<PyBaseException>() {
         Ok(exc_to_monty_object(exc))
     } else if is_dataclass(obj) {
@@ -121,6 +123,7 @@ pub fn monty_to_py(py: Python<'_>, obj: &MontyObject, dc_registry: &Bound<'_, Py
     match obj {
         MontyObject::None => Ok(py.None()),
         MontyObject::Ellipsis => Ok(py.Ellipsis()),
+        MontyObject::NotImplemented => Ok(py.NotImplemented()),
         MontyObject::Bool(b) => Ok(PyBool::new(py, *b).to_owned().into_any().unbind()),
         MontyObject::Int(i) => Ok(i.into_pyobject(py)?.clone().into_any().unbind()),
         MontyObject::BigInt(bi) => Ok(bi.into_pyobject(py)?.clone().into_any().unbind()),
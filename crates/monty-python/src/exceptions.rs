@@ -383,6 +383,7 @@ pub fn exc_monty_to_py(py: Python<'_>, exc: MontyException) -> PyErr {
         ExcType::RecursionError => exceptions::PyRecursionError::new_err(msg),
         ExcType::AssertionError => exceptions::PyAssertionError::new_err(msg),
         ExcType::AttributeError => exceptions::PyAttributeError::new_err(msg),
+        ExcType::EOFError => exceptions::PyEOFError::new_err(msg),
         ExcType::FrozenInstanceError => {
             if let Ok(exc_cls) = get_frozen_instance_error(py)
                 && let Ok(exc_instance) = exc_cls.call1((PyString::new(py, &msg),))
@@ -408,6 +409,11 @@ pub fn exc_monty_to_py(py: Python<'_>, exc: MontyException) -> PyErr {
         ExcType::FileExistsError => exceptions::PyFileExistsError::new_err(msg),
         ExcType::IsADirectoryError => exceptions::PyIsADirectoryError::new_err(msg),
         ExcType::NotADirectoryError => exceptions::PyNotADirectoryError::new_err(msg),
+        ExcType::Warning => exceptions::PyWarning::new_err(msg),
+        ExcType::UserWarning => exceptions::PyUserWarning::new_err(msg),
+        ExcType::DeprecationWarning => exceptions::PyDeprecationWarning::new_err(msg),
+        ExcType::RuntimeWarning => exceptions::PyRuntimeWarning::new_err(msg),
+        ExcType::FutureWarning => exceptions::PyFutureWarning::new_err(msg),
     }
 }
 
@@ -510,6 +516,21 @@ fn py_err_to_exc_type(exc: &Bound<'_, exceptions::PyBaseException>) -> ExcType {
             ExcType::TimeoutError
         } else if exceptions::PyMemoryError::type_check(exc) {
             ExcType::MemoryError
+        } else if exceptions::PyEOFError::type_check(exc) {
+            ExcType::EOFError
+        // Warning hierarchy
+        } else if exceptions::PyWarning::type_check(exc) {
+            if exceptions::PyUserWarning::type_check(exc) {
+                ExcType::UserWarning
+            } else if exceptions::PyDeprecationWarning::type_check(exc) {
+                ExcType::DeprecationWarning
+            } else if exceptions::PyRuntimeWarning::type_check(exc) {
+                ExcType::RuntimeWarning
+            } else if exceptions::PyFutureWarning::type_check(exc) {
+                ExcType::FutureWarning
+            } else {
+                ExcType::Warning
+            }
         } else {
             ExcType::Exception
         }
@@ -118,6 +118,10 @@ impl<T: ResourceTracker> ResourceTracker for PySignalTracker<T> {
         self.inner.on_free(get_size);
     }
 
+    fn on_grow(&mut self, get_size: impl FnOnce() -> usize) -> Result<(), ResourceError> {
+        self.inner.on_grow(get_size)
+    }
+
     fn check_time(&mut self) -> Result<(), ResourceError> {
         // First check inner tracker's time limit
         self.inner.check_time()?;
@@ -126,6 +130,10 @@ impl<T: ResourceTracker> ResourceTracker for PySignalTracker<T> {
         self.check_python_signals()
     }
 
+    fn on_step(&mut self) -> Result<(), ResourceError> {
+        self.inner.on_step()
+    }
+
     fn check_recursion_depth(&self, current_depth: usize) -> Result<(), ResourceError> {
         self.inner.check_recursion_depth(current_depth)
     }
@@ -133,4 +141,8 @@ impl<T: ResourceTracker> ResourceTracker for PySignalTracker<T> {
     fn check_large_result(&self, estimated_bytes: usize) -> Result<(), ResourceError> {
         self.inner.check_large_result(estimated_bytes)
     }
+
+    fn should_gc(&self, allocations_since_gc: u32) -> bool {
+        self.inner.should_gc(allocations_since_gc)
+    }
 }
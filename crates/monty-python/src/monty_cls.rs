@@ -186,7 +186,7 @@ impl PyMonty {
         // CallbackStringPrint is Send so this works for both print_callback cases
         macro_rules! start_impl {
             ($tracker:expr, $print_output:expr) => {{
-                let runner = self.runner.clone();
+                let runner = &self.runner;
                 py.detach(|| runner.start(input_values, $tracker, &mut $print_output))
                     .map_err(|e| MontyError::new_err(py, e))?
             }};
@@ -350,15 +350,13 @@ impl PyMonty {
         mut print_output: impl PrintWriter + Send,
     ) -> PyResult<Py<PyAny>> {
         let dataclass_registry = self.dataclass_registry.bind(py);
+        let runner = &self.runner;
         if self.external_function_names.is_empty() && os.is_none() {
-            let runner = &self.runner;
             return match py.detach(|| runner.run(input_values, tracker, &mut print_output)) {
                 Ok(v) => monty_to_py(py, &v, dataclass_registry),
                 Err(err) => Err(MontyError::new_err(py, err)),
             };
         }
-        // Clone the runner since start() consumes it - allows reuse of the parsed code
-        let runner = self.runner.clone();
         let mut progress = py
             .detach(|| runner.start(input_values, tracker, &mut print_output))
             .map_err(|e| MontyError::new_err(py, e))?;
@@ -0,0 +1,11 @@
+from typing import Any, Sequence, TypeVar
+
+_T = TypeVar('_T')
+
+def bisect_left(a: Sequence[Any], x: Any, lo: int = 0, hi: int | None = None, *, key: None = None) -> int: ...
+def bisect_right(a: Sequence[Any], x: Any, lo: int = 0, hi: int | None = None, *, key: None = None) -> int: ...
+def insort_left(a: list[_T], x: _T, lo: int = 0, hi: int | None = None, *, key: None = None) -> None: ...
+def insort_right(a: list[_T], x: _T, lo: int = 0, hi: int | None = None, *, key: None = None) -> None: ...
+
+bisect = bisect_right
+insort = insort_right
@@ -0,0 +1,22 @@
+from types import TracebackType
+from typing import Any, Generic, TypeVar, final, overload
+
+_T = TypeVar('_T')
+
+@final
+class suppress:
+    def __init__(self, *exceptions: type[BaseException]) -> None: ...
+    def __enter__(self) -> None: ...
+    def __exit__(
+        self, exctype: type[BaseException] | None, excinst: BaseException | None, exctb: TracebackType | None
+    ) -> bool: ...
+
+@final
+class nullcontext(Generic[_T]):
+    enter_result: _T
+    @overload
+    def __init__(self: nullcontext[None], enter_result: None = None) -> None: ...
+    @overload
+    def __init__(self: nullcontext[_T], enter_result: _T) -> None: ...
+    def __enter__(self) -> _T: ...
+    def __exit__(self, *exctype: Any) -> None: ...
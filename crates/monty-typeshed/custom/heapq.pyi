@@ -0,0 +1,9 @@
+from typing import Any, Iterable, TypeVar
+
+_T = TypeVar('_T')
+
+def heappush(heap: list[_T], item: _T, /) -> None: ...
+def heappop(heap: list[_T], /) -> _T: ...
+def heapify(heap: list[Any], /) -> None: ...
+def nsmallest(n: int, iterable: Iterable[_T], key: None = None) -> list[_T]: ...
+def nlargest(n: int, iterable: Iterable[_T], key: None = None) -> list[_T]: ...
@@ -0,0 +1,27 @@
+from typing import SupportsFloat, SupportsIndex
+from typing_extensions import TypeAlias
+
+_SupportsFloatOrIndex: TypeAlias = SupportsFloat | SupportsIndex
+
+def acos(x: _SupportsFloatOrIndex, /) -> float: ...
+def asin(x: _SupportsFloatOrIndex, /) -> float: ...
+def atan(x: _SupportsFloatOrIndex, /) -> float: ...
+def atan2(y: _SupportsFloatOrIndex, x: _SupportsFloatOrIndex, /) -> float: ...
+def ceil(x: _SupportsFloatOrIndex, /) -> int: ...
+def comb(n: SupportsIndex, k: SupportsIndex, /) -> int: ...
+def degrees(x: _SupportsFloatOrIndex, /) -> float: ...
+def floor(x: _SupportsFloatOrIndex, /) -> int: ...
+def hypot(*coordinates: _SupportsFloatOrIndex) -> float: ...
+def isclose(
+    a: _SupportsFloatOrIndex,
+    b: _SupportsFloatOrIndex,
+    *,
+    rel_tol: _SupportsFloatOrIndex = 1e-09,
+    abs_tol: _SupportsFloatOrIndex = 0.0,
+) -> bool: ...
+def isfinite(x: _SupportsFloatOrIndex, /) -> bool: ...
+def isinf(x: _SupportsFloatOrIndex, /) -> bool: ...
+def isnan(x: _SupportsFloatOrIndex, /) -> bool: ...
+def perm(n: SupportsIndex, k: SupportsIndex | None = None, /) -> int: ...
+def radians(x: _SupportsFloatOrIndex, /) -> float: ...
+def trunc(x: _SupportsFloatOrIndex, /) -> int: ...
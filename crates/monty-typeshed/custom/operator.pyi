@@ -0,0 +1,11 @@
+from typing import Any, final
+
+@final
+class itemgetter:
+    def __init__(self, item: Any, /, *items: Any) -> None: ...
+    def __call__(self, obj: Any, /) -> Any: ...
+
+@final
+class attrgetter:
+    def __init__(self, attr: str, /, *attrs: str) -> None: ...
+    def __call__(self, obj: Any, /) -> Any: ...
@@ -0,0 +1,39 @@
+from typing import Any, final
+
+IGNORECASE: int
+I: int
+MULTILINE: int
+M: int
+DOTALL: int
+S: int
+VERBOSE: int
+X: int
+
+@final
+class Match:
+    def group(self, *groups: int | str) -> Any: ...
+    def groups(self, default: Any = None) -> tuple[Any, ...]: ...
+    def start(self, group: int | str = 0, /) -> int: ...
+    def end(self, group: int | str = 0, /) -> int: ...
+    def span(self, group: int | str = 0, /) -> tuple[int, int]: ...
+
+@final
+class Pattern:
+    @property
+    def pattern(self) -> str: ...
+    @property
+    def flags(self) -> int: ...
+    @property
+    def groups(self) -> int: ...
+    def match(self, string: str, /) -> Match | None: ...
+    def search(self, string: str, /) -> Match | None: ...
+    def findall(self, string: str, /) -> list[Any]: ...
+    def sub(self, repl: str, string: str, count: int = 0) -> str: ...
+    def split(self, string: str, maxsplit: int = 0) -> list[str | Any]: ...
+
+def compile(pattern: str | Pattern, flags: int = 0) -> Pattern: ...
+def match(pattern: str | Pattern, string: str, flags: int = 0) -> Match | None: ...
+def search(pattern: str | Pattern, string: str, flags: int = 0) -> Match | None: ...
+def findall(pattern: str | Pattern, string: str, flags: int = 0) -> list[Any]: ...
+def sub(pattern: str | Pattern, repl: str, string: str, count: int = 0, flags: int = 0) -> str: ...
+def split(pattern: str | Pattern, string: str, maxsplit: int = 0, flags: int = 0) -> list[str | Any]: ...
@@ -0,0 +1,14 @@
+from collections.abc import Hashable, Iterable
+from typing import SupportsFloat, TypeVar
+
+_HashableT = TypeVar('_HashableT', bound=Hashable)
+_NumberT = TypeVar('_NumberT', float, int)
+
+class StatisticsError(ValueError): ...
+
+def mean(data: Iterable[_NumberT]) -> _NumberT: ...
+def median(data: Iterable[_NumberT]) -> _NumberT: ...
+def mode(data: Iterable[_HashableT]) -> _HashableT: ...
+def pstdev(data: Iterable[SupportsFloat]) -> float: ...
+def stdev(data: Iterable[SupportsFloat]) -> float: ...
+def variance(data: Iterable[_NumberT]) -> _NumberT: ...
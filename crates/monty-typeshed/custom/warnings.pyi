@@ -0,0 +1 @@
+def warn(message: str | Warning, category: type[Warning] | None = None, stacklevel: int = 1) -> None: ...
@@ -27,6 +27,7 @@ ALLOWED_FUNCTIONS = {
     'hash',
     'hex',
     'id',
+    'input',
     'isinstance',
     'len',
     'max',
@@ -84,6 +85,7 @@ ALLOWED_CLASSES = {
     'RecursionError',
     'AttributeError',
     'AssertionError',
+    'EOFError',
     'MemoryError',
     'NameError',
     'SyntaxError',
@@ -92,6 +94,11 @@ ALLOWED_CLASSES = {
     'TypeError',
     'ValueError',
     'StopIteration',
+    'Warning',
+    'UserWarning',
+    'DeprecationWarning',
+    'RuntimeWarning',
+    'FutureWarning',
 }
 
 # Dependency modules that builtins.pyi imports from.
@@ -125,16 +132,24 @@ VERSIONS = """\
 _collections_abc: 3.3-
 _typeshed: 3.0-  # not present at runtime, only for type checking
 asyncio: 3.4-
+bisect: 3.0-
 builtins: 3.0-
 collections: 3.0-
+contextlib: 3.0-
 dataclasses: 3.7-
+heapq: 3.0-
+math: 3.0-
+operator: 3.0-
 os: 3.0-
 pathlib: 3.4-
 pathlib.types: 3.14-
+re: 3.0-
+statistics: 3.4-
 sys: 3.0-
 typing: 3.5-
 typing_extensions: 3.7-
 types: 3.0-
+warnings: 3.0-
 """
 
 SCRIPT_DIR = Path(__file__).parent
@@ -6,7 +6,7 @@ use std::ffi::CString;
 use codspeed_criterion_compat::{Bencher, Criterion, black_box, criterion_group, criterion_main};
 #[cfg(not(codspeed))]
 use criterion::{Bencher, Criterion, black_box, criterion_group, criterion_main};
-use monty::MontyRun;
+use monty::{MontyObject, MontyRun, NoLimitTracker, NoPrint};
 #[cfg(not(codspeed))]
 use pprof::criterion::{Output, PProfProfiler};
 // CPython benchmarks are only run locally, not on CodSpeed CI (requires Python + pyo3 setup)
@@ -97,6 +97,26 @@ for i in range(1_000):
 len(v)
 ";
 
+/// Modulo-heavy loop using the fused `x % k == 0` comparison.
+const LOOP_MOD_FIZZBUZZ: &str = "
+count = 0
+for i in range(10_000):
+    if i % 3 == 0 or i % 5 == 0:
+        count += 1
+count
+";
+
+/// While loop bounded by `len()`, using the fused `i < len(x)` comparison.
+const LOOP_LEN: &str = "
+a = list(range(10_000))
+total = 0
+i = 0
+while i < len(a):
+    total += a[i]
+    i += 1
+total
+";
+
 /// Comprehensive benchmark exercising most supported Python features.
 /// Code is shared with test_cases/bench__kitchen_sink.py
 const KITCHEN_SINK: &str = include_str!("../test_cases/bench__kitchen_sink.py");
@@ -122,6 +142,22 @@ for i in range(100_000):
 sum(a)
 ";
 
+/// Appends a million items, `list.append` should stay linear in the number of items.
+const LIST_APPEND_MILLION: &str = "
+a = []
+for i in range(1_000_000):
+    a.append(i)
+len(a)
+";
+
+/// String built with `+=` in a loop, which appends in place since the string isn't shared.
+const STR_IADD: &str = "
+s = ''
+for i in range(100_000):
+    s += 'ab'
+len(s)
+";
+
 const FIB_25: &str = "
 def fib(n):
     if n <= 1:
@@ -143,6 +179,43 @@ const EMPTY_TUPLES: &str = "len([() for _ in range(100_000)])";
 /// 2-tuple creation benchmark - creates 100,000 2-tuples in a list.
 const PAIR_TUPLES: &str = "len([(i, i + 1) for i in range(100_000)])";
 
+/// Repeated str() of a huge integer - the decimal text is computed once and then reused.
+const LONG_INT_STR: &str = "
+x = 2 ** 100000
+total = 0
+for _ in range(100):
+    total += len(str(x))
+total
+";
+
+/// Script run repeatedly by `run_many_monty`, with a different `x` each time.
+const RUN_MANY: &str = "x * 2 + 1";
+
+/// Number of runs per iteration of `run_many_monty`.
+const RUN_MANY_COUNT: i64 = 10_000;
+
+/// Benchmarks running one compiled script 10,000 times with varying inputs.
+/// The code is parsed once; each run only creates a fresh heap and namespace.
+fn run_many_monty(bench: &mut Bencher) {
+    let ex = MontyRun::new(RUN_MANY.to_owned(), "test.py", vec!["x".to_owned()], vec![]).unwrap();
+
+    let run_all = || {
+        let mut total = 0;
+        for x in 0..RUN_MANY_COUNT {
+            let progress = ex
+                .start(vec![MontyObject::Int(x)], NoLimitTracker, &mut NoPrint)
+                .unwrap();
+            let result = progress.into_complete().expect("script makes no external calls");
+            let int_value: i64 = result.as_ref().try_into().unwrap();
+            total += int_value;
+        }
+        total
+    };
+    assert_eq!(run_all(), RUN_MANY_COUNT * RUN_MANY_COUNT);
+
+    bench.iter(|| black_box(run_all()));
+}
+
 /// Benchmarks end-to-end execution (parsing + running) using Monty.
 /// This is different from other benchmarks as it includes parsing in the loop.
 fn end_to_end_monty(bench: &mut Bencher) {
@@ -187,7 +260,18 @@ fn criterion_benchmark(c: &mut Criterion) {
     #[cfg(not(codspeed))]
     c.bench_function("loop_mod_13__cpython", |b| run_cpython(b, LOOP_MOD_13, 77));
 
+    c.bench_function("loop_mod_fizzbuzz__monty", |b| run_monty(b, LOOP_MOD_FIZZBUZZ, 4_667));
+    #[cfg(not(codspeed))]
+    c.bench_function("loop_mod_fizzbuzz__cpython", |b| {
+        run_cpython(b, LOOP_MOD_FIZZBUZZ, 4_667)
+    });
+
+    c.bench_function("loop_len__monty", |b| run_monty(b, LOOP_LEN, 49_995_000));
+    #[cfg(not(codspeed))]
+    c.bench_function("loop_len__cpython", |b| run_cpython(b, LOOP_LEN, 49_995_000));
+
     c.bench_function("end_to_end__monty", end_to_end_monty);
+    c.bench_function("run_many__monty", run_many_monty);
     #[cfg(not(codspeed))]
     c.bench_function("end_to_end__cpython", end_to_end_cpython);
 
@@ -211,6 +295,18 @@ fn criterion_benchmark(c: &mut Criterion) {
         run_cpython(b, LIST_APPEND_INT, 4_999_950_000);
     });
 
+    c.bench_function("list_append_million__monty", |b| {
+        run_monty(b, LIST_APPEND_MILLION, 1_000_000);
+    });
+    #[cfg(not(codspeed))]
+    c.bench_function("list_append_million__cpython", |b| {
+        run_cpython(b, LIST_APPEND_MILLION, 1_000_000);
+    });
+
+    c.bench_function("str_iadd__monty", |b| run_monty(b, STR_IADD, 200_000));
+    #[cfg(not(codspeed))]
+    c.bench_function("str_iadd__cpython", |b| run_cpython(b, STR_IADD, 200_000));
+
     c.bench_function("fib__monty", |b| run_monty(b, FIB_25, 75_025));
     #[cfg(not(codspeed))]
     c.bench_function("fib__cpython", |b| run_cpython(b, FIB_25, 75_025));
@@ -230,6 +326,9 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("pair_tuples__monty", |b| run_monty(b, PAIR_TUPLES, 100_000));
     #[cfg(not(codspeed))]
     c.bench_function("pair_tuples__cpython", |b| run_cpython(b, PAIR_TUPLES, 100_000));
+
+    // No CPython comparison: CPython limits int to str conversion to 4300 digits by default
+    c.bench_function("long_int_str__monty", |b| run_monty(b, LONG_INT_STR, 3_010_300));
 }
 
 // Use pprof flamegraph profiler when running locally (not on CodSpeed)
@@ -192,6 +192,63 @@ impl ArgValues {
         Ok((val1_guard.into_inner(), val2_guard.into_inner()))
     }
 
+    /// Binds arguments to parameters that may be passed either positionally or by keyword.
+    ///
+    /// The first `required` parameters must be provided; missing optional parameters are `None`.
+    /// Error messages match CPython's for functions defined in Python, like the `re` functions.
+    pub fn bind<const N: usize>(
+        self,
+        name: &str,
+        params: [&str; N],
+        required: usize,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<[Option<Value>; N]> {
+        let (positional, kwargs) = self.into_parts();
+        defer_drop_mut!(positional, heap);
+        let kwargs = kwargs.into_iter();
+        defer_drop_mut!(kwargs, heap);
+
+        let positional_count = positional.len();
+        if positional_count > N {
+            return Err(ExcType::type_error_at_most(name, N, positional_count));
+        }
+
+        let mut bound_guard = HeapGuard::new(std::array::from_fn(|_| None), heap);
+        let (bound, heap) = bound_guard.as_parts_mut();
+        for (slot, value) in bound.iter_mut().zip(&mut *positional) {
+            *slot = Some(value);
+        }
+
+        for (key, value) in kwargs {
+            defer_drop!(key, heap);
+            let mut value = HeapGuard::new(value, heap);
+
+            let Some(keyword_name) = key.as_either_str(value.heap()) else {
+                return Err(ExcType::type_error("keywords must be strings"));
+            };
+            let key_str = keyword_name.as_str(interns);
+            let Some(index) = params.iter().position(|param| *param == key_str) else {
+                return Err(ExcType::type_error_unexpected_keyword(name, key_str));
+            };
+            if bound[index].is_some() {
+                return Err(ExcType::type_error(format!(
+                    "{name}() got multiple values for argument '{key_str}'"
+                )));
+            }
+            bound[index] = Some(value.into_inner());
+        }
+
+        let missing: Vec<&str> = (0..required)
+            .filter(|&index| bound[index].is_none())
+            .map(|index| params[index])
+            .collect();
+        if !missing.is_empty() {
+            return Err(ExcType::type_error_missing_positional_with_names(name, &missing));
+        }
+        Ok(bound_guard.into_inner())
+    }
+
     /// Splits into positional iterator and keyword values without allocating
     /// for the common One/Two cases.
     pub fn into_parts(self) -> (ArgPosIter, KwargsValues) {
@@ -13,20 +13,25 @@ use crate::{
 /// Implementation of the chr() builtin function.
 ///
 /// Returns a string representing a character whose Unicode code point is the integer.
-/// The valid range for the argument is from 0 through 1,114,111 (0x10FFFF).
+/// The valid range for the argument is from 0 through 1,114,111 (0x10FFFF), except for the
+/// surrogates U+D800 to U+DFFF which aren't valid Unicode scalar values.
 pub fn builtin_chr(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let value = args.get_one_arg("chr", heap)?;
     defer_drop!(value, heap);
 
     match value {
         Value::Int(n) => {
-            if *n < 0 || *n > 0x0010_FFFF {
-                Err(SimpleException::new_msg(ExcType::ValueError, "chr() arg not in range(0x110000)").into())
-            } else if let Some(c) = char::from_u32(u32::try_from(*n).expect("chr() range check failed")) {
-                Ok(allocate_char(c, heap)?)
-            } else {
-                // This shouldn't happen for valid Unicode range, but handle it
-                Err(SimpleException::new_msg(ExcType::ValueError, "chr() arg not in range(0x110000)").into())
+            let Some(code_point) = u32::try_from(*n).ok().filter(|&n| n <= 0x0010_FFFF) else {
+                return Err(SimpleException::new_msg(ExcType::ValueError, "chr() arg not in range(0x110000)").into());
+            };
+            match char::from_u32(code_point) {
+                Some(c) => Ok(allocate_char(c, heap)?),
+                // Monty strings are UTF-8 and can't hold lone surrogates, unlike CPython's
+                None => Err(SimpleException::new_msg(
+                    ExcType::ValueError,
+                    "chr() arg not in range: surrogate code points are not supported",
+                )
+                .into()),
             }
         }
         Value::Bool(b) => {
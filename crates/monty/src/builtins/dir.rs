@@ -0,0 +1,74 @@
+//! Implementation of the dir() builtin function.
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult},
+    heap::{DropWithHeap, Heap, HeapData},
+    intern::Interns,
+    resource::ResourceTracker,
+    types::{List, PyTrait, str::allocate_string},
+    value::Value,
+};
+
+/// Implementation of the dir() builtin function.
+///
+/// `dir(obj)` returns a sorted list of the attribute names of a module or dataclass
+/// instance. Other objects raise `NotImplementedError`, since Monty doesn't track the
+/// attributes of builtin types.
+///
+/// `dir()` lists the names in the current scope, which needs the current frame, so the VM
+/// handles it (see `bytecode::vm::call`).
+pub fn builtin_dir(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
+    let Some(value) = args.get_zero_one_arg("dir", heap)? else {
+        return Err(RunError::internal("dir() without arguments must be called by the VM"));
+    };
+    defer_drop!(value, heap);
+
+    let names: Vec<String> = match value {
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Module(module) => attr_names(module.attrs().iter().map(|(key, _)| key), heap, interns),
+            HeapData::Dataclass(dc) => {
+                let mut names = attr_names(dc.attrs().iter().map(|(key, _)| key), heap, interns);
+                names.extend(dc.methods().iter().cloned());
+                names
+            }
+            _ => return Err(dir_not_supported(value, heap)),
+        },
+        _ => return Err(dir_not_supported(value, heap)),
+    };
+    sorted_name_list(names, heap)
+}
+
+/// Builds the sorted list of unique names that `dir()` returns.
+pub(crate) fn sorted_name_list(mut names: Vec<String>, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
+    names.sort_unstable();
+    names.dedup();
+    let mut items = Vec::with_capacity(names.len());
+    for name in names {
+        match allocate_string(name, heap) {
+            Ok(item) => items.push(item),
+            Err(err) => {
+                items.drop_with_heap(heap);
+                return Err(err);
+            }
+        }
+    }
+    Ok(Value::Ref(heap.allocate(HeapData::List(List::new(items)))?))
+}
+
+/// Returns the attribute names among `keys`, skipping any that aren't strings.
+fn attr_names<'a>(
+    keys: impl Iterator<Item = &'a Value>,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> Vec<String> {
+    keys.filter_map(|key| key.as_either_str(heap))
+        .map(|name| name.as_str(interns).to_owned())
+        .collect()
+}
+
+/// The error for `dir()` on objects whose attributes Monty doesn't track.
+fn dir_not_supported(value: &Value, heap: &Heap<impl ResourceTracker>) -> RunError {
+    ExcType::not_implemented(format!("dir() is not supported for '{}' objects", value.py_type(heap))).into()
+}
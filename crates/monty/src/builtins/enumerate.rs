@@ -5,7 +5,7 @@ use smallvec::smallvec;
 use crate::{
     args::ArgValues,
     defer_drop, defer_drop_mut,
-    exception_private::{ExcType, RunResult, SimpleException},
+    exception_private::RunResult,
     heap::{Heap, HeapData},
     intern::Interns,
     resource::ResourceTracker,
@@ -29,16 +29,7 @@ pub fn builtin_enumerate(
 
     // Get start index (default 0)
     let mut index: i64 = match start {
-        Some(Value::Int(n)) => *n,
-        Some(Value::Bool(b)) => i64::from(*b),
-        Some(v) => {
-            let type_name = v.py_type(heap);
-            return Err(SimpleException::new_msg(
-                ExcType::TypeError,
-                format!("'{type_name}' object cannot be interpreted as an integer"),
-            )
-            .into());
-        }
+        Some(v) => v.as_int(heap)?,
         None => 0,
     };
 
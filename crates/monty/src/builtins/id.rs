@@ -1,21 +1,24 @@
 //! Implementation of the id() builtin function.
 
-use crate::{args::ArgValues, exception_private::RunResult, heap::Heap, resource::ResourceTracker, value::Value};
+use num_bigint::BigInt;
+
+use crate::{
+    args::ArgValues, exception_private::RunResult, heap::Heap, resource::ResourceTracker, types::LongInt, value::Value,
+};
 
 /// Implementation of the id() builtin function.
 ///
 /// Returns the identity of an object (unique integer for the object's lifetime).
+///
+/// Like CPython, ids are never negative. Tagged ids with the top bit set (the singletons
+/// `None`, `True`, `False`, etc.) don't fit in an `i64`, so they're returned as a `LongInt`.
 pub fn builtin_id(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let value = args.get_one_arg("id", heap)?;
     let id = value.id();
     value.drop_with_heap(heap);
 
-    // Python's id() returns a signed integer; reinterpret bits for large values
-    // On 64-bit: large addresses wrap to negative; on 32-bit: always fits positive
-    #[expect(
-        clippy::cast_possible_wrap,
-        reason = "Python id() returns signed; wrapping intentional"
-    )]
-    let id_i64 = id as i64;
-    Ok(Value::Int(id_i64))
+    match i64::try_from(id) {
+        Ok(id) => Ok(Value::Int(id)),
+        Err(_) => Ok(LongInt::new(BigInt::from(id)).into_value(heap)?),
+    }
 }
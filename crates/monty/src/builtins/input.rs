@@ -0,0 +1,37 @@
+//! Implementation of the input() builtin function.
+
+use crate::{
+    args::ArgValues,
+    exception_private::RunResult,
+    heap::Heap,
+    intern::Interns,
+    os::OsFunction,
+    resource::{DepthGuard, ResourceTracker},
+    types::{AttrCallResult, PyTrait, str::allocate_string},
+};
+
+/// Implementation of the input() builtin function.
+///
+/// Monty never reads from the real stdin. Instead `input(prompt='')` yields
+/// `OsFunction::Input` to the host with a single argument, the prompt converted
+/// with `str()`. The host decides whether to show the prompt and where the line
+/// comes from, and resumes with the line as a `str` (without the trailing newline,
+/// as CPython strips it). Hosts with no input available should resume with an
+/// `EOFError`, matching CPython at end of file.
+pub fn builtin_input(
+    heap: &mut Heap<impl ResourceTracker>,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<AttrCallResult> {
+    let prompt = match args.get_zero_one_arg("input", heap)? {
+        Some(prompt) if prompt.is_str(heap) => prompt,
+        Some(prompt) => {
+            let mut guard = DepthGuard::default();
+            let prompt_str = prompt.py_str(heap, &mut guard, interns).into_owned();
+            prompt.drop_with_heap(heap);
+            allocate_string(prompt_str, heap)?
+        }
+        None => allocate_string(String::new(), heap)?,
+    };
+    Ok(AttrCallResult::OsCall(OsFunction::Input, ArgValues::One(prompt)))
+}
@@ -37,7 +37,7 @@ pub fn builtin_isinstance(heap: &mut Heap<impl ResourceTracker>, args: ArgValues
 /// - Exception types: `isinstance(err, ValueError)`
 /// - Exception hierarchy: `isinstance(err, LookupError)` for KeyError/IndexError
 /// - Nested tuples: `isinstance(x, (int, (str, bytes)))`
-fn isinstance_check(obj_type: Type, classinfo: &Value, heap: &Heap<impl ResourceTracker>) -> Result<bool, ()> {
+pub(crate) fn isinstance_check(obj_type: Type, classinfo: &Value, heap: &Heap<impl ResourceTracker>) -> Result<bool, ()> {
     match classinfo {
         // Single type: isinstance(x, int)
         Value::Builtin(Builtins::Type(t)) => Ok(obj_type.is_instance_of(*t)),
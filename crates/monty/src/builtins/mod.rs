@@ -4,29 +4,28 @@
 //! Each builtin function has its own submodule for organization.
 
 mod abs;
-mod all;
-mod any;
 mod bin;
 mod chr;
+pub(crate) mod dir;
 mod divmod;
 mod enumerate;
 mod hash;
 mod hex;
 mod id;
-mod isinstance;
+mod input;
+pub(crate) mod isinstance;
 mod len;
-mod min_max; // min and max share implementation
-mod next;
+mod numeric;
 mod oct;
+mod open;
 mod ord;
 mod pow;
 mod print;
 mod repr;
 mod reversed;
 mod round;
-mod sorted;
-mod sum;
 mod type_;
+mod vars;
 mod zip;
 
 use std::{fmt::Write, str::FromStr};
@@ -35,13 +34,12 @@ use strum::{Display, EnumString, FromRepr, IntoStaticStr};
 
 use crate::{
     args::ArgValues,
-    exception_private::{ExcType, RunResult},
+    exception_private::{ExcType, RunError, RunResult},
     heap::Heap,
     intern::Interns,
     io::PrintWriter,
     resource::ResourceTracker,
-    types::Type,
-    value::Value,
+    types::{AttrCallResult, Type},
 };
 
 /// Enumerates every interpreter-native Python builtins
@@ -61,6 +59,9 @@ pub(crate) enum Builtins {
 impl Builtins {
     /// Calls this builtin with the given arguments.
     ///
+    /// Returns `AttrCallResult::OsCall` for builtins that need the host (`input()` and
+    /// `open()`); everything else completes immediately with `AttrCallResult::Value`.
+    ///
     /// # Arguments
     /// * `heap` - The heap for allocating objects
     /// * `args` - The arguments to pass to the callable
@@ -72,11 +73,11 @@ impl Builtins {
         args: ArgValues,
         interns: &Interns,
         print: &mut impl PrintWriter,
-    ) -> RunResult<Value> {
+    ) -> RunResult<AttrCallResult> {
         match self {
             Self::Function(b) => b.call(heap, args, interns, print),
-            Self::ExcType(exc) => exc.call(heap, args, interns),
-            Self::Type(t) => t.call(heap, args, interns),
+            Self::ExcType(exc) => exc.call(heap, args, interns).map(AttrCallResult::Value),
+            Self::Type(t) => t.call(heap, args, interns).map(AttrCallResult::Value),
         }
     }
 
@@ -160,12 +161,12 @@ pub enum BuiltinsFunctions {
     // complex - handled by Type enum
     // Delattr,
     // dict - handled by Type enum
-    // Dir,
+    Dir,
     Divmod,
     Enumerate,
     // Eval,
     // Exec,
-    // Filter,
+    Filter,
     // float - handled by Type enum
     // Format,
     // frozenset - handled by Type enum
@@ -176,7 +177,7 @@ pub enum BuiltinsFunctions {
     // Help,
     Hex,
     Id,
-    // Input,
+    Input,
     // int - handled by Type enum
     Isinstance,
     // Issubclass,
@@ -184,14 +185,14 @@ pub enum BuiltinsFunctions {
     Len,
     // list - handled by Type enum
     // Locals,
-    // Map,
+    Map,
     Max,
     // memoryview - handled by Type enum
     Min,
     Next,
     // object - handled by Type enum
     Oct,
-    // Open,
+    Open,
     Ord,
     Pow,
     Print,
@@ -210,7 +211,7 @@ pub enum BuiltinsFunctions {
     // Super,
     // tuple - handled by Type enum
     Type,
-    // Vars,
+    Vars,
     Zip,
     // __import__ - not planned
 }
@@ -220,40 +221,55 @@ impl BuiltinsFunctions {
     ///
     /// The `interns` parameter provides access to interned string content for py_str and py_repr.
     /// The `print` parameter is used for print output.
+    ///
+    /// Builtins that need the host, like `input()` and `open()`, return `AttrCallResult::OsCall` so the VM
+    /// can yield to it; all others return `AttrCallResult::Value`.
     pub(crate) fn call(
         self,
         heap: &mut Heap<impl ResourceTracker>,
         args: ArgValues,
         interns: &Interns,
         print_writer: &mut impl PrintWriter,
-    ) -> RunResult<Value> {
-        match self {
+    ) -> RunResult<AttrCallResult> {
+        let value = match self {
             Self::Abs => abs::builtin_abs(heap, args),
-            Self::All => all::builtin_all(heap, args, interns),
-            Self::Any => any::builtin_any(heap, args, interns),
             Self::Bin => bin::builtin_bin(heap, args),
             Self::Chr => chr::builtin_chr(heap, args),
+            Self::Dir => dir::builtin_dir(heap, args, interns),
             Self::Divmod => divmod::builtin_divmod(heap, args),
             Self::Enumerate => enumerate::builtin_enumerate(heap, args, interns),
             Self::Hash => hash::builtin_hash(heap, args, interns),
             Self::Hex => hex::builtin_hex(heap, args),
             Self::Id => id::builtin_id(heap, args),
+            Self::Input => return input::builtin_input(heap, args, interns),
             Self::Isinstance => isinstance::builtin_isinstance(heap, args),
             Self::Len => len::builtin_len(heap, args, interns),
-            Self::Max => min_max::builtin_max(heap, args, interns),
-            Self::Min => min_max::builtin_min(heap, args, interns),
-            Self::Next => next::builtin_next(heap, args, interns),
             Self::Oct => oct::builtin_oct(heap, args),
+            Self::Open => return open::builtin_open(heap, args, interns),
             Self::Ord => ord::builtin_ord(heap, args, interns),
             Self::Pow => pow::builtin_pow(heap, args),
             Self::Print => print::builtin_print(heap, args, interns, print_writer),
             Self::Repr => repr::builtin_repr(heap, args, interns),
             Self::Reversed => reversed::builtin_reversed(heap, args, interns),
             Self::Round => round::builtin_round(heap, args),
-            Self::Sorted => sorted::builtin_sorted(heap, args, interns),
-            Self::Sum => sum::builtin_sum(heap, args, interns),
             Self::Type => type_::builtin_type(heap, args),
+            Self::Vars => vars::builtin_vars(heap, args, interns),
             Self::Zip => zip::builtin_zip(heap, args, interns),
-        }
+            // These call back into Python code or advance generators, so the VM implements them
+            // (see `bytecode::vm::higher_order`)
+            Self::All
+            | Self::Any
+            | Self::Filter
+            | Self::Map
+            | Self::Max
+            | Self::Min
+            | Self::Next
+            | Self::Sorted
+            | Self::Sum => {
+                args.drop_with_heap(heap);
+                Err(RunError::internal(format!("{self}() must be called by the VM")))
+            }
+        };
+        value.map(AttrCallResult::Value)
     }
 }
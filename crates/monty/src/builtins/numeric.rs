@@ -0,0 +1,94 @@
+//! Numeric tower shared by the `abs()`, `round()` and `divmod()` builtins.
+//!
+//! A number reaches a builtin as a `Value::Int`, a `Value::Bool`, a heap `LongInt` or a
+//! `Value::Float`. [`NumericValue::from_value`] reads any of these into one of three kinds,
+//! treating bools as ints, and [`NumericPair::promote`] brings two numbers to a common kind
+//! the way Python's arithmetic does. Each builtin then needs a single match on the kind.
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use crate::{
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{Heap, HeapData},
+    resource::{ResourceError, ResourceTracker},
+    types::LongInt,
+    value::Value,
+};
+
+/// A number read out of a `Value`.
+pub(super) enum NumericValue {
+    /// An int that fits in an i64, including a bool.
+    Int(i64),
+    /// An int too large for an i64.
+    BigInt(BigInt),
+    Float(f64),
+}
+
+impl NumericValue {
+    /// Reads a number from `value`, returning `None` if it isn't an int, bool or float.
+    pub fn from_value(value: &Value, heap: &Heap<impl ResourceTracker>) -> Option<Self> {
+        match value {
+            Value::Int(n) => Some(Self::Int(*n)),
+            Value::Bool(b) => Some(Self::Int(i64::from(*b))),
+            Value::Float(f) => Some(Self::Float(*f)),
+            Value::Ref(id) => match heap.get(*id) {
+                HeapData::LongInt(li) => Some(Self::BigInt(li.inner().clone())),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Converts the number back to a `Value`, demoting big ints that fit in an i64.
+    pub fn into_value(self, heap: &mut Heap<impl ResourceTracker>) -> Result<Value, ResourceError> {
+        match self {
+            Self::Int(n) => Ok(Value::Int(n)),
+            Self::BigInt(bi) => LongInt::new(bi).into_value(heap),
+            Self::Float(f) => Ok(Value::Float(f)),
+        }
+    }
+
+    /// Converts an int to a float, raising `OverflowError` if it's too large.
+    fn to_f64(&self) -> RunResult<f64> {
+        match self {
+            Self::Int(n) => Ok(*n as f64),
+            Self::BigInt(bi) => bi.to_f64().filter(|f| f.is_finite()).ok_or_else(|| {
+                SimpleException::new_msg(ExcType::OverflowError, "int too large to convert to float").into()
+            }),
+            Self::Float(f) => Ok(*f),
+        }
+    }
+}
+
+/// Two numbers promoted to a common kind.
+pub(super) enum NumericPair {
+    Ints(i64, i64),
+    BigInts(BigInt, BigInt),
+    Floats(f64, f64),
+}
+
+impl NumericPair {
+    /// Promotes two numbers: a float makes both floats, otherwise a big int makes both big ints.
+    ///
+    /// Raises `OverflowError` if a big int is too large to become a float.
+    pub fn promote(a: NumericValue, b: NumericValue) -> RunResult<Self> {
+        match (a, b) {
+            (NumericValue::Int(x), NumericValue::Int(y)) => Ok(Self::Ints(x, y)),
+            (a @ NumericValue::Float(_), b) | (a, b @ NumericValue::Float(_)) => {
+                Ok(Self::Floats(a.to_f64()?, b.to_f64()?))
+            }
+            (NumericValue::BigInt(x), NumericValue::BigInt(y)) => Ok(Self::BigInts(x, y)),
+            (NumericValue::Int(x), NumericValue::BigInt(y)) => Ok(Self::BigInts(BigInt::from(x), y)),
+            (NumericValue::BigInt(x), NumericValue::Int(y)) => Ok(Self::BigInts(x, BigInt::from(y))),
+        }
+    }
+}
+
+/// Converts a bool to the equivalent int, leaving other values unchanged.
+pub fn normalize_bool_to_int(value: Value) -> Value {
+    match value {
+        Value::Bool(b) => Value::Int(i64::from(b)),
+        other => other,
+    }
+}
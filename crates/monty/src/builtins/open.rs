@@ -0,0 +1,155 @@
+//! Implementation of the open() builtin function.
+
+use std::fmt;
+
+use crate::{
+    args::ArgValues,
+    defer_drop, defer_drop_mut,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{Heap, HeapData},
+    intern::Interns,
+    os::OsFunction,
+    resource::ResourceTracker,
+    types::{AttrCallResult, PyTrait, str::allocate_string},
+    value::Value,
+};
+
+/// Implementation of the open() builtin function.
+///
+/// Monty never opens real files. `open(file, mode='r')` validates its arguments and
+/// yields `OsFunction::Open` to the host with the path and mode as strings. The host
+/// returns an opaque `int` handle, which the VM wraps in a `File` object; see
+/// `types::file` for the rest of the protocol.
+///
+/// `file` may be a `str` or a `pathlib.Path`. The `encoding` keyword is accepted for
+/// compatibility but must be a `str` or `None`, since Monty strings are always UTF-8.
+pub fn builtin_open(
+    heap: &mut Heap<impl ResourceTracker>,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<AttrCallResult> {
+    let (pos, kwargs) = args.into_parts();
+    defer_drop_mut!(pos, heap);
+    let kwargs = kwargs.into_iter();
+    defer_drop_mut!(kwargs, heap);
+
+    let pos_count = pos.len();
+    if pos_count > 2 {
+        return Err(ExcType::type_error_at_most("open", 2, pos_count));
+    }
+
+    let mut file = match pos.next() {
+        Some(value) => {
+            defer_drop!(value, heap);
+            Some(file_arg(value, heap, interns)?)
+        }
+        None => None,
+    };
+    let mut mode = match pos.next() {
+        Some(value) => {
+            defer_drop!(value, heap);
+            Some(mode_arg(value, heap, interns)?)
+        }
+        None => None,
+    };
+
+    for (key, value) in kwargs {
+        defer_drop!(key, heap);
+        defer_drop!(value, heap);
+
+        let Some(keyword_name) = key.as_either_str(heap) else {
+            return Err(ExcType::type_error("keywords must be strings"));
+        };
+        match keyword_name.as_str(interns) {
+            "file" if file.is_none() => file = Some(file_arg(value, heap, interns)?),
+            "mode" if mode.is_none() => mode = Some(mode_arg(value, heap, interns)?),
+            "encoding" => {
+                if !matches!(value, Value::None) && !value.is_str(heap) {
+                    return Err(ExcType::type_error(format!(
+                        "open() argument 'encoding' must be str or None, not {}",
+                        value.py_type(heap)
+                    )));
+                }
+            }
+            name @ ("file" | "mode") => {
+                let position = if name == "file" { 1 } else { 2 };
+                return Err(ExcType::type_error(format!(
+                    "argument for open() given by name ('{name}') and position ({position})"
+                )));
+            }
+            other => return Err(ExcType::type_error_unexpected_keyword("open", other)),
+        }
+    }
+
+    let Some(file) = file else {
+        return Err(ExcType::type_error("open() missing required argument 'file' (pos 1)"));
+    };
+    let mode = mode.unwrap_or_else(|| "r".to_owned());
+    validate_mode(&mode)?;
+
+    let file = allocate_string(file, heap)?;
+    let mode = match allocate_string(mode, heap) {
+        Ok(mode) => mode,
+        Err(err) => {
+            file.drop_with_heap(heap);
+            return Err(err);
+        }
+    };
+    Ok(AttrCallResult::OsCall(OsFunction::Open, ArgValues::Two(file, mode)))
+}
+
+/// Extracts the path passed as `open()`'s `file` argument.
+fn file_arg(value: &Value, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<String> {
+    if let Some(s) = value.as_either_str(heap) {
+        return Ok(s.as_str(interns).to_owned());
+    }
+    if let Value::Ref(id) = value
+        && let HeapData::Path(path) = heap.get(*id)
+    {
+        return Ok(path.as_str().to_owned());
+    }
+    Err(ExcType::type_error(format!(
+        "expected str, bytes or os.PathLike object, not {}",
+        value.py_type(heap)
+    )))
+}
+
+/// Extracts the `mode` argument of `open()`, which must be a `str`.
+fn mode_arg(value: &Value, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<String> {
+    match value.as_either_str(heap) {
+        Some(s) => Ok(s.as_str(interns).to_owned()),
+        None => Err(ExcType::type_error(format!(
+            "open() argument 'mode' must be str, not {}",
+            value.py_type(heap)
+        ))),
+    }
+}
+
+/// Validates an `open()` mode string the same way CPython does.
+///
+/// The mode may contain each of `rwxab+t` at most once, exactly one of `rwxa`,
+/// and not both `b` and `t`.
+fn validate_mode(mode: &str) -> RunResult<()> {
+    let mut seen = String::with_capacity(mode.len());
+    for c in mode.chars() {
+        if !"rwxab+t".contains(c) || seen.contains(c) {
+            return Err(mode_error(format!("invalid mode: '{mode}'")));
+        }
+        seen.push(c);
+    }
+    if seen.contains('b') && seen.contains('t') {
+        return Err(mode_error("can't have text and binary mode at once"));
+    }
+    match seen.chars().filter(|c| "rwxa".contains(*c)).count() {
+        1 => Ok(()),
+        0 => Err(mode_error(
+            "Must have exactly one of create/read/write/append mode and at most one plus",
+        )),
+        _ => Err(mode_error("must have exactly one of create/read/write/append mode")),
+    }
+}
+
+/// Creates the `ValueError` raised for an invalid `open()` mode.
+fn mode_error(msg: impl fmt::Display) -> RunError {
+    SimpleException::new_msg(ExcType::ValueError, msg).into()
+}
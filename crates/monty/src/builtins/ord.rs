@@ -23,7 +23,7 @@ pub fn builtin_ord(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, inter
             let s = interns.get_str(*string_id);
             let mut chars = s.chars();
             if let (Some(c), None) = (chars.next(), chars.next()) {
-                Ok(Value::Int(c as i64))
+                Ok(Value::Int(i64::from(u32::from(c))))
             } else {
                 let len = s.chars().count();
                 Err(SimpleException::new_msg(
@@ -37,7 +37,7 @@ pub fn builtin_ord(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, inter
             if let HeapData::Str(s) = heap.get(*id) {
                 let mut chars = s.as_str().chars();
                 if let (Some(c), None) = (chars.next(), chars.next()) {
-                    Ok(Value::Int(c as i64))
+                    Ok(Value::Int(i64::from(u32::from(c))))
                 } else {
                     let len = s.as_str().chars().count();
                     Err(SimpleException::new_msg(
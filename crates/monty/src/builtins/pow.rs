@@ -41,9 +41,9 @@ pub fn builtin_pow(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> Ru
         }
     };
 
-    let base = super::round::normalize_bool_to_int(base);
-    let exp = super::round::normalize_bool_to_int(exp);
-    let modulo = modulo.map(super::round::normalize_bool_to_int);
+    let base = super::numeric::normalize_bool_to_int(base);
+    let exp = super::numeric::normalize_bool_to_int(exp);
+    let modulo = modulo.map(super::numeric::normalize_bool_to_int);
 
     let result = if let Some(m) = &modulo {
         // Three-argument pow: modular exponentiation
@@ -60,6 +60,7 @@ pub fn builtin_print(
     } else {
         print.stdout_push('\n')?;
     }
+    print.print_end()?;
 
     Ok(Value::None)
 }
@@ -14,9 +14,18 @@ use crate::{
 ///
 /// Returns a list with elements in reverse order.
 /// Note: In Python this returns an iterator, but we return a list for simplicity.
+/// A range is reversed into another range instead, so it's never materialized.
 pub fn builtin_reversed(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
     let value = args.get_one_arg("reversed", heap)?;
 
+    if let Value::Ref(id) = &value
+        && let HeapData::Range(range) = heap.get(*id)
+        && let Some(reversed) = range.reversed()
+    {
+        value.drop_with_heap(heap);
+        return Ok(Value::Ref(heap.allocate(HeapData::Range(reversed))?));
+    }
+
     // Collect all items
     let mut iter = MontyIter::new(value, heap, interns)?;
     let mut items: Vec<_> = iter.collect(heap, interns)?;
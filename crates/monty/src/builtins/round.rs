@@ -1,30 +1,30 @@
 //! Implementation of the round() builtin function.
 
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+use super::numeric::NumericValue;
 use crate::{
     args::ArgValues,
     defer_drop,
     exception_private::{ExcType, RunResult, SimpleException},
     heap::Heap,
     resource::ResourceTracker,
-    types::PyTrait,
+    types::{LongInt, PyTrait},
     value::Value,
 };
 
-pub fn normalize_bool_to_int(value: Value) -> Value {
-    match value {
-        Value::Bool(b) => Value::Int(i64::from(b)),
-        other => other,
-    }
-}
-
 /// Implementation of the round() builtin function.
 ///
 /// Rounds a number to a given precision in decimal digits.
 /// If ndigits is omitted or None, returns the nearest integer.
-/// Uses banker's rounding (round half to even).
+/// Uses banker's rounding (round half to even) on the exact value of the number,
+/// so `round(2.675, 2)` is `2.67` because the float is slightly below 2.675.
 pub fn builtin_round(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let (number, ndigits) = args.get_one_two_args("round", heap)?;
-    let number = normalize_bool_to_int(number);
     defer_drop!(number, heap);
     defer_drop!(ndigits, heap);
 
@@ -45,130 +45,106 @@ pub fn builtin_round(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) ->
         None => None,
     };
 
-    match number {
-        Value::Int(n) => {
-            if let Some(d) = digits {
-                if d >= 0 {
-                    // Positive or zero digits: return the integer unchanged
-                    Ok(Value::Int(*n))
-                } else {
-                    // Negative digits: round to tens, hundreds, etc. using banker's rounding
-                    // -d is positive since d < 0; use try_from to safely convert
-                    let exp = u32::try_from(-d).unwrap_or(u32::MAX);
-                    let factor = 10_i64.saturating_pow(exp);
-                    let rounded_f = bankers_round(*n as f64 / factor as f64);
-                    let rounded = f64_to_i64(rounded_f) * factor;
-                    Ok(Value::Int(rounded))
-                }
-            } else {
-                // No digits specified: return the integer unchanged
-                Ok(Value::Int(*n))
-            }
-        }
-        Value::Float(f) => {
-            if let Some(d) = digits {
-                // Round to `d` decimal places using banker's rounding.
-                Ok(Value::Float(round_float_to_digits(*f, d)))
-            } else {
-                // No digits: round to nearest integer and return int (banker's rounding)
-                if f.is_nan() {
-                    Err(SimpleException::new_msg(ExcType::ValueError, "cannot convert float NaN to integer").into())
-                } else if f.is_infinite() {
-                    Err(
-                        SimpleException::new_msg(ExcType::OverflowError, "cannot convert float infinity to integer")
-                            .into(),
-                    )
-                } else {
-                    Ok(Value::Int(f64_to_i64(bankers_round(*f))))
-                }
-            }
-        }
-        _ => {
-            let type_name = number.py_type(heap);
-            Err(SimpleException::new_msg(
-                ExcType::TypeError,
-                format!("type {type_name} doesn't define __round__ method"),
-            )
-            .into())
+    let Some(number) = NumericValue::from_value(number, heap) else {
+        let type_name = number.py_type(heap);
+        return Err(SimpleException::new_msg(
+            ExcType::TypeError,
+            format!("type {type_name} doesn't define __round__ method"),
+        )
+        .into());
+    };
+    match (number, digits) {
+        // Negative digits: round to tens, hundreds, etc.
+        (NumericValue::Int(n), Some(d)) if d < 0 => {
+            Ok(LongInt::new(round_int_to_digits(&BigInt::from(n), d)).into_value(heap)?)
         }
+        (NumericValue::BigInt(bi), Some(d)) if d < 0 => Ok(LongInt::new(round_int_to_digits(&bi, d)).into_value(heap)?),
+        // No digits or non-negative digits: the integer is returned unchanged
+        (int @ (NumericValue::Int(_) | NumericValue::BigInt(_)), _) => Ok(int.into_value(heap)?),
+        // Round to `d` decimal places, always returning a float
+        (NumericValue::Float(f), Some(d)) => Ok(Value::Float(round_float_to_digits(f, d))),
+        // No digits: round to nearest integer and return int, promoting large values
+        (NumericValue::Float(f), None) => LongInt::from_f64(f.round_ties_even(), heap),
     }
 }
 
-/// Implements banker's rounding (round half to even).
-///
-/// This is the rounding mode used by Python's `round()` function.
-/// When the value is exactly halfway between two integers, it rounds to the nearest even integer.
-fn bankers_round(value: f64) -> f64 {
-    let floor = value.floor();
-    let frac = value - floor;
-
-    if frac < 0.5 {
-        floor
-    } else if frac > 0.5 {
-        floor + 1.0
-    } else {
-        // Exactly 0.5 - round to even
-        if f64_to_i64(floor) % 2 == 0 { floor } else { floor + 1.0 }
+/// Rounds an integer to a negative number of decimal digits, e.g. to the nearest hundred
+/// for `digits == -2`, using banker's rounding.
+fn round_int_to_digits(n: &BigInt, digits: i64) -> BigInt {
+    let exp = digits.unsigned_abs();
+    // 10**exp is more than twice |n| once it has more bits than n, so everything rounds to 0.
+    // This also stops huge exponents from allocating an enormous power of ten.
+    if exp.saturating_mul(3) > n.bits() + 1 {
+        return BigInt::zero();
     }
+    let factor = BigInt::from(10_u32).pow(u32::try_from(exp).expect("exp bounded by bit count"));
+    let (quot, rem) = n.div_mod_floor(&factor);
+    round_half_even(quot, &(rem * 2_u32), &factor) * factor
 }
 
 /// Rounds a finite float to a given number of decimal digits using banker's rounding.
 ///
-/// This is used for `round(x, ndigits)` where Python always returns a float.
+/// This is used for `round(x, ndigits)` where Python always returns a float. Like CPython,
+/// the rounding is done on the exact binary value using integer arithmetic, and the decimal
+/// result is then converted back to the nearest float.
 ///
-/// For large `ndigits` values where scaling by `10**ndigits` would overflow/underflow `f64`,
-/// CPython returns either the original value (large positive `ndigits`) or a signed zero
-/// (large negative `ndigits`). We mirror that behavior and also preserve the sign of `0.0`.
+/// For `ndigits` beyond what a float can represent, CPython returns either the original
+/// value (large positive `ndigits`) or a signed zero (large negative `ndigits`). We mirror
+/// that behavior and also preserve the sign of `0.0`.
 fn round_float_to_digits(value: f64, digits: i64) -> f64 {
-    if !value.is_finite() {
+    // Same limits as CPython: beyond these every float is unchanged or rounds to zero
+    const NDIGITS_MAX: i64 = 323;
+    const NDIGITS_MIN: i64 = -308;
+
+    if !value.is_finite() || value == 0.0 || digits > NDIGITS_MAX {
         return value;
     }
+    if digits < NDIGITS_MIN {
+        return 0.0_f64.copysign(value);
+    }
 
-    let rounded = if digits >= 0 {
-        let Ok(exp) = i32::try_from(digits) else {
-            return value;
-        };
-        let multiplier = 10_f64.powi(exp);
-        if !multiplier.is_finite() {
-            return value;
-        }
-        let scaled = value * multiplier;
-        if !scaled.is_finite() {
-            return value;
-        }
-        bankers_round(scaled) / multiplier
+    // |value| == mantissa * 2**exponent exactly
+    let bits = value.to_bits();
+    let biased_exponent = i64::try_from((bits >> 52) & 0x7ff).expect("11-bit exponent fits in i64");
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
     } else {
-        let Ok(exp) = i32::try_from(digits) else {
-            return 0.0_f64.copysign(value);
-        };
-        let multiplier = 10_f64.powi(exp);
-        if multiplier == 0.0 {
-            return 0.0_f64.copysign(value);
-        }
-        let scaled = value * multiplier;
-        bankers_round(scaled) / multiplier
+        (fraction | (1 << 52), biased_exponent - 1075)
     };
 
-    if rounded == 0.0 {
-        0.0_f64.copysign(value)
+    // Compute numerator / denominator == |value| * 10**digits
+    let mut numerator = BigInt::from(mantissa);
+    let mut denominator = BigInt::one();
+    if exponent >= 0 {
+        numerator <<= exponent;
+    } else {
+        denominator <<= -exponent;
+    }
+    let power = BigInt::from(10_u32).pow(u32::try_from(digits.unsigned_abs()).expect("digits within limits"));
+    if digits >= 0 {
+        numerator *= power;
     } else {
-        rounded
+        denominator *= power;
     }
+
+    let (quot, rem) = numerator.div_rem(&denominator);
+    let rounded = round_half_even(quot, &(rem * 2_u32), &denominator);
+
+    // Parsing the decimal string gives the correctly rounded float, including the sign of zero
+    let sign = if value.is_sign_negative() { "-" } else { "" };
+    format!("{sign}{rounded}e{}", -digits)
+        .parse()
+        .expect("formatted decimal is a valid float")
 }
 
-/// Converts `f64` to `i64` using saturating float-to-int casting.
-///
-/// Monty uses `i64` for integer values, so float-to-int conversion must pick a
-/// bounded representation:
-/// - Values outside the `i64` range saturate to `i64::MIN`/`i64::MAX`
-/// - `NaN` converts to `0`
-///
-/// This behavior is provided by Rust's `as` casting rules for float-to-int.
-fn f64_to_i64(value: f64) -> i64 {
-    #[expect(
-        clippy::cast_possible_truncation,
-        reason = "intentional truncation; float-to-int casts saturate and map NaN to 0"
-    )]
-    let result = value as i64;
-    result
+/// Rounds the quotient of a division given twice the remainder and the divisor,
+/// with ties going to the even quotient.
+fn round_half_even(quot: BigInt, twice_rem: &BigInt, divisor: &BigInt) -> BigInt {
+    match twice_rem.cmp(divisor) {
+        Ordering::Less => quot,
+        Ordering::Greater => quot + 1,
+        Ordering::Equal if quot.is_even() => quot,
+        Ordering::Equal => quot + 1,
+    }
 }
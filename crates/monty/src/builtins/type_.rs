@@ -2,15 +2,49 @@
 
 use super::Builtins;
 use crate::{
-    args::ArgValues, defer_drop, exception_private::RunResult, heap::Heap, resource::ResourceTracker, types::PyTrait,
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, Heap},
+    resource::ResourceTracker,
+    types::{PyTrait, Type},
     value::Value,
 };
 
 /// Implementation of the type() builtin function.
 ///
-/// Returns the type of an object.
+/// `type(obj)` returns the type of an object.
+///
+/// `type(name, bases, namespace)` creates a class in CPython. Monty doesn't support classes
+/// yet, so after checking the argument types like CPython does, this form raises
+/// `NotImplementedError`.
 pub fn builtin_type(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
-    let value = args.get_one_arg("type", heap)?;
-    defer_drop!(value, heap);
-    Ok(Value::Builtin(Builtins::Type(value.py_type(heap))))
+    let (positional, kwargs) = args.into_parts();
+    if !kwargs.is_empty() || !matches!(positional.len(), 1 | 3) {
+        positional.drop_with_heap(heap);
+        kwargs.drop_with_heap(heap);
+        return Err(ExcType::type_error("type() takes 1 or 3 arguments"));
+    }
+    let args: Vec<Value> = positional.collect();
+    defer_drop!(args, heap);
+
+    match args.as_slice() {
+        [value] => Ok(Value::Builtin(Builtins::Type(value.py_type(heap)))),
+        [name, bases, namespace] => {
+            for (position, value, expected) in [
+                (1, name, Type::Str),
+                (2, bases, Type::Tuple),
+                (3, namespace, Type::Dict),
+            ] {
+                let actual = value.py_type(heap);
+                if actual != expected {
+                    return Err(ExcType::type_error(format!(
+                        "type.__new__() argument {position} must be {expected}, not {actual}"
+                    )));
+                }
+            }
+            Err(ExcType::not_implemented("creating classes with type() is not supported").into())
+        }
+        _ => unreachable!("argument count checked above"),
+    }
 }
@@ -0,0 +1,48 @@
+//! Implementation of the vars() builtin function.
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult},
+    heap::{Heap, HeapData},
+    intern::Interns,
+    resource::ResourceTracker,
+    types::Dict,
+    value::Value,
+};
+
+/// Implementation of the vars() builtin function.
+///
+/// `vars(obj)` returns the attributes of a module or dataclass instance as a dict. Unlike
+/// CPython's `__dict__`, the dict is a copy, so changing it doesn't change the object.
+/// Other objects raise `TypeError`, as they have no `__dict__` in CPython either.
+///
+/// `vars()` returns the names in the current scope, which needs the current frame, so the
+/// VM handles it (see `bytecode::vm::call`).
+pub fn builtin_vars(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
+    let Some(value) = args.get_zero_one_arg("vars", heap)? else {
+        return Err(RunError::internal("vars() without arguments must be called by the VM"));
+    };
+    defer_drop!(value, heap);
+
+    let pairs = match value {
+        Value::Ref(id) => heap.with_entry_mut(*id, |heap, data| {
+            let attrs = match data {
+                HeapData::Module(module) => module.attrs(),
+                HeapData::Dataclass(dc) => dc.attrs(),
+                _ => return None,
+            };
+            let pairs: Vec<(Value, Value)> = attrs
+                .iter()
+                .map(|(k, v)| (k.clone_with_heap(heap), v.clone_with_heap(heap)))
+                .collect();
+            Some(pairs)
+        }),
+        _ => None,
+    };
+    let Some(pairs) = pairs else {
+        return Err(ExcType::type_error("vars() argument must have __dict__ attribute"));
+    };
+    let dict = Dict::from_pairs(pairs, heap, interns)?;
+    Ok(Value::Ref(heap.allocate(HeapData::Dict(dict))?))
+}
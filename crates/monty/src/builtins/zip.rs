@@ -1,13 +1,13 @@
 //! Implementation of the zip() builtin function.
 
 use crate::{
-    args::ArgValues,
+    args::{ArgValues, KwargsValues},
     defer_drop_mut,
-    exception_private::RunResult,
-    heap::{Heap, HeapData},
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData},
     intern::Interns,
     resource::ResourceTracker,
-    types::{List, MontyIter, allocate_tuple, tuple::TupleVec},
+    types::{List, MontyIter, PyTrait, allocate_tuple, tuple::TupleVec},
     value::Value,
 };
 
@@ -15,13 +15,13 @@ use crate::{
 ///
 /// Returns a list of tuples, where the i-th tuple contains the i-th element
 /// from each of the argument iterables. Stops when the shortest iterable is exhausted.
+/// With `strict=True`, raises `ValueError` instead if the iterables differ in length.
 /// Note: In Python this returns an iterator, but we return a list for simplicity.
 pub fn builtin_zip(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
     let (positional, kwargs) = args.into_parts();
     defer_drop_mut!(positional, heap);
 
-    // TODO: support kwargs (strict)
-    kwargs.not_supported_yet("zip", heap)?;
+    let strict = extract_strict_kwarg(kwargs, heap, interns)?;
 
     if positional.len() == 0 {
         // zip() with no arguments returns empty list
@@ -44,34 +44,135 @@ pub fn builtin_zip(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, inter
         }
     }
 
+    let result = zip_iterators(&mut iterators, strict, heap, interns);
+
+    // Clean up iterators
+    for iter in iterators {
+        iter.drop_with_heap(heap);
+    }
+
+    let heap_id = heap.allocate(HeapData::List(List::new(result?)))?;
+    Ok(Value::Ref(heap_id))
+}
+
+/// Collects the tuples of items from `iterators` until one of them is exhausted.
+fn zip_iterators(
+    iterators: &mut [MontyIter],
+    strict: bool,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Vec<Value>> {
     let mut result: Vec<Value> = Vec::new();
 
     // Zip until shortest iterator is exhausted
-    'outer: loop {
+    let exhausted = 'rows: loop {
         let mut tuple_items = TupleVec::with_capacity(iterators.len());
 
-        for iter in &mut iterators {
-            if let Some(item) = iter.for_next(heap, interns)? {
+        for (position, iter) in iterators.iter_mut().enumerate() {
+            let next = iter.for_next(heap, interns);
+            if let Ok(Some(item)) = next {
                 tuple_items.push(item);
-            } else {
-                // This iterator is exhausted - drop partial tuple items and stop
-                for item in tuple_items {
-                    item.drop_with_heap(heap);
+                continue;
+            }
+            // This iterator is exhausted or failed - drop partial tuple items
+            for item in tuple_items {
+                item.drop_with_heap(heap);
+            }
+            match next {
+                Ok(_) => break 'rows position,
+                Err(e) => {
+                    result.drop_with_heap(heap);
+                    return Err(e);
                 }
-                break 'outer;
             }
         }
 
         // Create tuple from collected items
-        let tuple_val = allocate_tuple(tuple_items, heap)?;
-        result.push(tuple_val);
+        match allocate_tuple(tuple_items, heap) {
+            Ok(tuple_val) => result.push(tuple_val),
+            Err(e) => {
+                result.drop_with_heap(heap);
+                return Err(e.into());
+            }
+        }
+    };
+
+    if strict && let Err(e) = check_all_exhausted(iterators, exhausted, heap, interns) {
+        result.drop_with_heap(heap);
+        return Err(e);
     }
+    Ok(result)
+}
 
-    // Clean up iterators
-    for iter in iterators {
-        iter.drop_with_heap(heap);
+/// Checks that every iterator ended together, given the position of the first one found exhausted.
+///
+/// Like CPython, this pulls one more item from each iterator after the first to detect
+/// any that are longer.
+fn check_all_exhausted(
+    iterators: &mut [MontyIter],
+    exhausted: usize,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<()> {
+    if exhausted > 0 {
+        return Err(length_mismatch(exhausted, "shorter"));
+    }
+    for (position, iter) in iterators.iter_mut().enumerate().skip(1) {
+        if let Some(item) = iter.for_next(heap, interns)? {
+            item.drop_with_heap(heap);
+            return Err(length_mismatch(position, "longer"));
+        }
     }
+    Ok(())
+}
 
-    let heap_id = heap.allocate(HeapData::List(List::new(result)))?;
-    Ok(Value::Ref(heap_id))
+/// The `ValueError` for a `strict=True` argument at `position` that is `shorter` or `longer`
+/// than the ones before it.
+fn length_mismatch(position: usize, relation: &str) -> RunError {
+    let earlier = if position == 1 {
+        "argument 1".to_owned()
+    } else {
+        format!("arguments 1-{position}")
+    };
+    SimpleException::new_msg(
+        ExcType::ValueError,
+        format!("zip() argument {} is {relation} than {earlier}", position + 1),
+    )
+    .into()
+}
+
+/// Extracts the `strict` keyword argument, which is `False` if not given.
+fn extract_strict_kwarg(
+    kwargs: KwargsValues,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<bool> {
+    let mut strict = false;
+    let mut error: Option<RunError> = None;
+
+    for (key, value) in kwargs {
+        // If we already hit an error, just drop remaining values
+        if error.is_none() {
+            match key.as_either_str(heap) {
+                Some(keyword_name) if keyword_name.as_str(interns) == "strict" => match value.to_bool(heap, interns) {
+                    Ok(value) => strict = value,
+                    Err(err) => error = Some(err),
+                },
+                Some(keyword_name) => {
+                    error = Some(ExcType::type_error(format!(
+                        "'{}' is an invalid keyword argument for zip()",
+                        keyword_name.as_str(interns)
+                    )));
+                }
+                None => error = Some(ExcType::type_error("keywords must be strings")),
+            }
+        }
+        key.drop_with_heap(heap);
+        value.drop_with_heap(heap);
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(strict),
+    }
 }
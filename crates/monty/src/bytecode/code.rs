@@ -112,6 +112,15 @@ impl Code {
         self.local_names.get(slot as usize).copied()
     }
 
+    /// Returns the local variable names by slot index.
+    ///
+    /// Slots with no recorded name hold `StringId::default()`. Used by `dir()` and `vars()`
+    /// to list the names in the current scope.
+    #[must_use]
+    pub fn local_names(&self) -> &[StringId] {
+        &self.local_names
+    }
+
     /// Returns whether the slot is an assigned local (vs an undefined reference).
     ///
     /// Used to determine whether to raise `UnboundLocalError` (true) or `NameError` (false)
@@ -139,6 +148,12 @@ impl Code {
             .find(|entry| entry.bytecode_offset <= offset_u32)
     }
 
+    /// Returns the exception handler table, innermost entries first.
+    #[must_use]
+    pub fn exception_table(&self) -> &[ExceptionEntry] {
+        &self.exception_table
+    }
+
     /// Finds an exception handler for the given bytecode offset.
     ///
     /// Searches the exception table for an entry whose protected range contains
@@ -290,6 +305,18 @@ impl ExceptionEntry {
         }
     }
 
+    /// Returns the start of the protected range (inclusive).
+    #[must_use]
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    /// Returns the end of the protected range (exclusive).
+    #[must_use]
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
     /// Returns the handler bytecode offset.
     #[must_use]
     pub fn handler(&self) -> u32 {
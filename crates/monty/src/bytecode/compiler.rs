@@ -87,6 +87,11 @@ pub struct Compiler<'a> {
     /// clear the current exception (`ClearException`) and pop the exception
     /// value from the stack before jumping to the finally path or loop target.
     except_handler_depth: usize,
+
+    /// Whether expression statements echo their value with `PrintExpr` instead of discarding it.
+    ///
+    /// Only set for module code compiled in REPL mode; function bodies never echo.
+    repl: bool,
 }
 
 /// Information about a loop for break/continue handling.
@@ -106,6 +111,9 @@ struct LoopInfo {
     /// Whether this loop has an iterator on the stack.
     /// True for `for` loops, false for `while` loops.
     has_iterator_on_stack: bool,
+    /// The except handler depth when this loop was entered.
+    /// Break/continue only leave the handlers entered inside the loop.
+    except_handler_depth: usize,
 }
 
 /// A break or continue that needs to go through a finally block.
@@ -134,6 +142,12 @@ struct FinallyTarget {
     /// The loop depth when this finally was entered.
     /// Used to determine if break/continue targets a loop outside this finally.
     loop_depth_at_entry: usize,
+    /// The except handler depth when this finally was entered.
+    /// A return only leaves the handlers entered inside the try statement.
+    except_handler_depth_at_entry: usize,
+    /// The stack depth at try entry, which the "finally with return" path expects
+    /// below the return value.
+    stack_depth: u16,
 }
 
 /// Result of module compilation: the module code and all compiled functions.
@@ -155,6 +169,7 @@ impl<'a> Compiler<'a> {
             cell_base: 0,
             finally_targets: Vec::new(),
             except_handler_depth: 0,
+            repl: false,
         }
     }
 
@@ -168,6 +183,7 @@ impl<'a> Compiler<'a> {
             cell_base,
             finally_targets: Vec::new(),
             except_handler_depth: 0,
+            repl: false,
         }
     }
 
@@ -176,12 +192,17 @@ impl<'a> Compiler<'a> {
     /// Returns the compiled module Code and all compiled Functions, or a compile
     /// error if limits were exceeded. The module implicitly returns the value
     /// of the last expression, or None if empty.
+    ///
+    /// With `repl` set, every expression statement in the module code (but not in
+    /// function bodies) echoes its value, like CPython's `single` compile mode.
     pub fn compile_module(
         nodes: &[PreparedNode],
         interns: &Interns,
         num_locals: u16,
+        repl: bool,
     ) -> Result<CompileResult, CompileError> {
         let mut compiler = Compiler::new(interns, Vec::new());
+        compiler.repl = repl;
         compiler.compile_block(nodes)?;
 
         // Module returns None if no explicit return
@@ -223,9 +244,15 @@ impl<'a> Compiler<'a> {
     }
 
     /// Compiles a block of statements.
+    ///
+    /// Statements after an unconditional `return`, `raise`, `break` or `continue` can never
+    /// run, so they are not compiled.
     fn compile_block(&mut self, nodes: &[PreparedNode]) -> Result<(), CompileError> {
         for node in nodes {
             self.compile_stmt(node)?;
+            if is_unconditional_exit(node) {
+                break;
+            }
         }
         Ok(())
     }
@@ -240,7 +267,11 @@ impl<'a> Compiler<'a> {
         match node {
             Node::Expr(expr) => {
                 self.compile_expr(expr)?;
-                self.code.emit(Opcode::Pop); // Discard result
+                if self.repl {
+                    self.code.emit(Opcode::PrintExpr); // Echo result
+                } else {
+                    self.code.emit(Opcode::Pop); // Discard result
+                }
             }
             Node::Return(expr) => {
                 self.compile_expr(expr)?;
@@ -284,6 +315,16 @@ impl<'a> Compiler<'a> {
                     self.compile_unpack_target(target);
                 }
             }
+            Node::ChainAssign { targets, object } => {
+                self.compile_expr(object)?;
+                // Keep a copy of the value for every target except the last
+                for (i, target) in targets.iter().enumerate() {
+                    if i < targets.len() - 1 {
+                        self.code.emit(Opcode::Dup);
+                    }
+                    self.compile_unpack_target(target);
+                }
+            }
             Node::OpAssign { target, op, object } => {
                 let Some(opcode) = operator_to_inplace_opcode(op) else {
                     return Err(CompileError::new(
@@ -310,6 +351,33 @@ impl<'a> Compiler<'a> {
                 self.code.set_location(*target_position, None);
                 self.code.emit(Opcode::StoreSubscr);
             }
+            Node::SubscriptOpAssign {
+                object,
+                index,
+                op,
+                value,
+                target_position,
+            } => {
+                let Some(opcode) = operator_to_inplace_opcode(op) else {
+                    return Err(CompileError::new(
+                        "matrix multiplication augmented assignment (@=) is not yet supported",
+                        *target_position,
+                    ));
+                };
+                // Evaluate obj and index once, keeping a copy of both for the store:
+                // [obj, index] -> [obj, index, obj, index] -> [obj, index, old]
+                self.compile_expr(object)?;
+                self.compile_expr(index)?;
+                self.code.emit(Opcode::Dup2);
+                self.code.set_location(*target_position, None);
+                self.code.emit(Opcode::BinarySubscr);
+                // [obj, index, old] -> [obj, index, new] -> [new, obj, index]
+                self.compile_expr(value)?;
+                self.code.emit(opcode);
+                self.code.emit(Opcode::Rot3);
+                self.code.set_location(*target_position, None);
+                self.code.emit(Opcode::StoreSubscr);
+            }
             Node::AttrAssign {
                 object,
                 attr,
@@ -327,6 +395,33 @@ impl<'a> Compiler<'a> {
                     u16::try_from(name_id.index()).expect("name index exceeds u16"),
                 );
             }
+            Node::AttrOpAssign {
+                object,
+                attr,
+                op,
+                value,
+                target_position,
+            } => {
+                let Some(opcode) = operator_to_inplace_opcode(op) else {
+                    return Err(CompileError::new(
+                        "matrix multiplication augmented assignment (@=) is not yet supported",
+                        *target_position,
+                    ));
+                };
+                let name_idx = u16::try_from(attr.string_id().expect("LoadAttr requires interned attr name").index())
+                    .expect("name index exceeds u16");
+                // Evaluate obj once, keeping a copy for the store: [obj] -> [obj, obj] -> [obj, old]
+                self.compile_expr(object)?;
+                self.code.emit(Opcode::Dup);
+                self.code.set_location(*target_position, None);
+                self.code.emit_u16(Opcode::LoadAttr, name_idx);
+                // [obj, old] -> [obj, new] -> [new, obj]
+                self.compile_expr(value)?;
+                self.code.emit(opcode);
+                self.code.emit(Opcode::Rot2);
+                self.code.set_location(*target_position, None);
+                self.code.emit_u16(Opcode::StoreAttr, name_idx);
+            }
             Node::If { test, body, or_else } => self.compile_if(test, body, or_else)?,
             Node::For {
                 target,
@@ -344,6 +439,11 @@ impl<'a> Compiler<'a> {
                     self.code.emit(Opcode::Reraise);
                 }
             }
+            Node::RaiseFrom { exc, cause } => {
+                self.compile_expr(exc)?;
+                self.compile_expr(cause)?;
+                self.code.emit(Opcode::RaiseFrom);
+            }
             Node::FunctionDef(func_def) => self.compile_function_def(func_def)?,
             Node::Try(try_block) => self.compile_try(try_block)?,
             Node::Import { module_name, binding } => self.compile_import(*module_name, binding),
@@ -403,6 +503,7 @@ impl<'a> Compiler<'a> {
             func_def.cell_param_indices.clone(),
             func_def.default_exprs.len(),
             func_def.is_async,
+            func_def.is_generator,
             body_code,
         );
         functions.push(function);
@@ -447,7 +548,8 @@ impl<'a> Compiler<'a> {
     /// This is similar to `compile_function_def` but:
     /// - Does NOT store the function to a name slot (it stays on the stack as an expression result)
     ///
-    /// The lambda's `PreparedFunctionDef` already has `<lambda>` as its name.
+    /// The lambda's `PreparedFunctionDef` already has `<lambda>` as its name. This also
+    /// creates the hidden `<genexpr>` function of a generator expression.
     fn compile_lambda(&mut self, func_def: &PreparedFunctionDef) -> Result<(), CompileError> {
         let func_pos = func_def.name.position;
 
@@ -483,6 +585,7 @@ impl<'a> Compiler<'a> {
             func_def.cell_param_indices.clone(),
             func_def.default_exprs.len(),
             func_def.is_async,
+            func_def.is_generator,
             body_code,
         );
         functions.push(function);
@@ -743,6 +846,21 @@ impl<'a> Compiler<'a> {
                 self.compile_list_comp(elt, generators)?;
             }
 
+            Expr::Generator { func_def, iter } => {
+                // Create the hidden function, then call it with an iterator over the outermost
+                // iterable, which gives the generator without running any of its body
+                self.compile_lambda(func_def)?;
+                self.compile_expr(iter)?;
+                self.code.set_location(expr_loc.position, None);
+                self.code.emit(Opcode::GetIter);
+                self.code.emit_u8(Opcode::CallFunction, 1);
+            }
+
+            Expr::GeneratorExp { .. } => {
+                // GeneratorExp should be converted to Generator during prepare phase
+                unreachable!("Expr::GeneratorExp should not exist after prepare phase")
+            }
+
             Expr::SetComp { elt, generators } => {
                 self.compile_set_comp(elt, generators)?;
             }
@@ -769,6 +887,13 @@ impl<'a> Compiler<'a> {
                 self.code.emit(Opcode::Await);
             }
 
+            Expr::Yield(value) => {
+                // Hand the value to whoever advanced the generator; the expression's own
+                // result is None, since values can't be sent into a generator
+                self.compile_expr(value)?;
+                self.code.emit(Opcode::YieldValue);
+            }
+
             Expr::Slice { lower, upper, step } => {
                 // Compile slice components: start, stop, step (push None for missing)
                 if let Some(lower) = lower {
@@ -830,7 +955,7 @@ impl<'a> Compiler<'a> {
                 }
             }
 
-            // For Float, Str, Bytes, Ellipsis - use LoadConst with Value::from
+            // For Float, Str, Bytes, Ellipsis, NotImplemented - use LoadConst with Value::from
             _ => {
                 let idx = self.code.add_const(Value::from(*literal));
                 self.code.emit_u16(Opcode::LoadConst, idx);
@@ -1035,12 +1160,21 @@ impl<'a> Compiler<'a> {
     // ========================================================================
 
     /// Compiles an if/else statement.
+    ///
+    /// If the test is a literal (e.g. `if False:`, or a condition folded to a literal), only
+    /// the branch that will run is compiled.
     fn compile_if(
         &mut self,
         test: &ExprLoc,
         body: &[PreparedNode],
         or_else: &[PreparedNode],
     ) -> Result<(), CompileError> {
+        match literal_truthiness(test) {
+            Some(true) => return self.compile_block(body),
+            Some(false) => return self.compile_block(or_else),
+            None => {}
+        }
+
         self.compile_expr(test)?;
 
         if or_else.is_empty() {
@@ -1770,6 +1904,7 @@ impl<'a> Compiler<'a> {
             start: loop_start,
             break_jumps: Vec::new(),
             has_iterator_on_stack: true,
+            except_handler_depth: self.except_handler_depth,
         });
 
         // ForIter: advance iterator or jump to end
@@ -1822,30 +1957,44 @@ impl<'a> Compiler<'a> {
     /// - No `ForIter` (use `JumpIfFalse` instead)
     /// - `continue` jumps to condition evaluation
     /// - `break` doesn't need to pop iterator (nothing extra on stack)
+    ///
+    /// Literal tests are special cased: `while False:` compiles to just the else block, and
+    /// `while True:` has no condition check at all, so its else block is unreachable.
     fn compile_while(
         &mut self,
         test: &ExprLoc,
         body: &[PreparedNode],
         or_else: &[PreparedNode],
     ) -> Result<(), CompileError> {
+        let truthiness = literal_truthiness(test);
+        if truthiness == Some(false) {
+            return self.compile_block(or_else);
+        }
+
         let loop_start = self.code.current_offset();
 
         self.loop_stack.push(LoopInfo {
             start: loop_start,
             break_jumps: Vec::new(),
             has_iterator_on_stack: false,
+            except_handler_depth: self.except_handler_depth,
         });
 
-        self.compile_expr(test)?;
-        let end_jump = self.code.emit_jump(Opcode::JumpIfFalse);
+        let end_jump = if truthiness == Some(true) {
+            None
+        } else {
+            self.compile_expr(test)?;
+            Some(self.code.emit_jump(Opcode::JumpIfFalse))
+        };
 
         self.compile_block(body)?;
         self.code.emit_jump_to(Opcode::Jump, loop_start);
 
-        self.code.patch_jump(end_jump);
         let loop_info = self.loop_stack.pop().expect("loop stack underflow");
 
-        if !or_else.is_empty() {
+        // The else block runs when the condition becomes false, which `while True:` never does
+        if let Some(end_jump) = end_jump {
+            self.code.patch_jump(end_jump);
             self.compile_block(or_else)?;
         }
 
@@ -1868,9 +2017,11 @@ impl<'a> Compiler<'a> {
     ///
     /// With finally:
     /// 1. Clean up exception state if inside except handler
-    /// 2. Pop the iterator if in a `for` loop
-    /// 3. Jump to "finally with break" path (patched when try compilation completes)
-    /// 4. That path runs finally, then jumps to after the else block
+    /// 2. Jump to "finally with break" path (patched when try compilation completes)
+    /// 3. That path runs finally, then pops the iterator and jumps to after the else block
+    ///
+    /// The iterator stays on the stack while finally runs, so a `continue` in the
+    /// finally block can still resume the loop.
     fn compile_break(&mut self, position: CodeRange) -> Result<(), CompileError> {
         if self.loop_stack.is_empty() {
             return Err(CompileError::new("'break' outside loop", position));
@@ -1878,20 +2029,14 @@ impl<'a> Compiler<'a> {
 
         let target_loop_depth = self.loop_stack.len() - 1;
 
-        // If inside except handlers, clean up ALL exception states
+        // If inside except handlers, clean up the exception states of those entered inside the loop
         // Each nested except handler has pushed an exception onto the stack,
         // so we need to clear/pop each one when breaking out
-        for _ in 0..self.except_handler_depth {
+        for _ in self.loop_stack[target_loop_depth].except_handler_depth..self.except_handler_depth {
             self.code.emit(Opcode::ClearException);
             self.code.emit(Opcode::Pop); // Pop the exception value
         }
 
-        // Pop the iterator only for `for` loops (has iterator on stack)
-        // `while` loops don't have an iterator to pop
-        if self.loop_stack[target_loop_depth].has_iterator_on_stack {
-            self.code.emit(Opcode::Pop);
-        }
-
         // Check if we need to go through any finally blocks
         // We need to run finally if break crosses the try boundary, i.e., if
         // we're breaking from a loop that existed before the try started.
@@ -1914,8 +2059,7 @@ impl<'a> Compiler<'a> {
         }
 
         // No finally to go through, jump directly to loop end
-        let jump = self.code.emit_jump(Opcode::Jump);
-        self.loop_stack[target_loop_depth].break_jumps.push(jump);
+        self.compile_jump_to_loop_end(target_loop_depth);
 
         // The code following this break is unreachable at runtime, but the compiler
         // will still emit cleanup code for each enclosing except handler (ClearException + Pop).
@@ -1928,6 +2072,16 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Pops the loop's iterator (only `for` loops have one on the stack) and jumps to
+    /// after the loop's else block.
+    fn compile_jump_to_loop_end(&mut self, target_loop_depth: usize) {
+        if self.loop_stack[target_loop_depth].has_iterator_on_stack {
+            self.code.emit(Opcode::Pop);
+        }
+        let jump = self.code.emit_jump(Opcode::Jump);
+        self.loop_stack[target_loop_depth].break_jumps.push(jump);
+    }
+
     /// Compiles a continue statement.
     ///
     /// Continue jumps back to the loop start (the ForIter instruction) which
@@ -1940,10 +2094,10 @@ impl<'a> Compiler<'a> {
 
         let target_loop_depth = self.loop_stack.len() - 1;
 
-        // If inside except handlers, clean up ALL exception states
+        // If inside except handlers, clean up the exception states of those entered inside the loop
         // Each nested except handler has pushed an exception onto the stack,
         // so we need to clear/pop each one when continuing
-        for _ in 0..self.except_handler_depth {
+        for _ in self.loop_stack[target_loop_depth].except_handler_depth..self.except_handler_depth {
             self.code.emit(Opcode::ClearException);
             self.code.emit(Opcode::Pop); // Pop the exception value
         }
@@ -2023,8 +2177,7 @@ impl<'a> Compiler<'a> {
 
         // No more finally blocks, jump directly to the loop target
         if is_break {
-            let jump = self.code.emit_jump(Opcode::Jump);
-            self.loop_stack[target_loop_depth].break_jumps.push(jump);
+            self.compile_jump_to_loop_end(target_loop_depth);
         } else {
             // else continue
             let loop_start = self.loop_stack[target_loop_depth].start;
@@ -2349,12 +2502,32 @@ impl<'a> Compiler<'a> {
     /// If we're inside a try-finally block, the return value is kept on the stack
     /// and we jump to a "finally with return" section that runs finally then returns.
     /// Otherwise, we emit a direct `ReturnValue`.
+    ///
+    /// Returning leaves any except handlers (and finally blocks with a pending exception)
+    /// it's inside, so their exceptions are cleared first.
     fn compile_return(&mut self) {
+        let handler_depth_at_target = self
+            .finally_targets
+            .last()
+            .map_or(0, |target| target.except_handler_depth_at_entry);
+        for _ in handler_depth_at_target..self.except_handler_depth {
+            self.code.emit(Opcode::ClearException);
+        }
+
         if let Some(finally_target) = self.finally_targets.last_mut() {
             // Inside a try-finally: jump to finally, then return
-            // Return value is already on stack
+            // Return value is already on stack. Drop anything pushed since the try was
+            // entered (exceptions of handlers, loop iterators) from beneath it, so the
+            // finally path runs with the stack it was compiled for.
+            let depth = self.code.stack_depth();
+            while self.code.stack_depth() > finally_target.stack_depth + 1 {
+                self.code.emit(Opcode::Rot2);
+                self.code.emit(Opcode::Pop);
+            }
             let jump = self.code.emit_jump(Opcode::Jump);
             finally_target.return_jumps.push(jump);
+            // The code following this return is unreachable, track the stack as if it returned here
+            self.code.set_stack_depth(depth.saturating_sub(1));
         } else {
             // Normal return
             self.code.emit(Opcode::ReturnValue);
@@ -2366,7 +2539,7 @@ impl<'a> Compiler<'a> {
     /// The bytecode structure is:
     /// ```text
     /// <try_body>                     # protected range
-    /// JUMP to_else_or_finally        # skip handlers if no exception
+    /// JUMP to_else                   # skip handlers if no exception
     /// handler_dispatch:              # exception pushed by VM
     ///   # for each handler:
     ///   <check exception type>
@@ -2377,17 +2550,27 @@ impl<'a> Compiler<'a> {
     ///   RERAISE                      # no handler matched
     /// else_block:
     ///   <else_body>
+    ///   JUMP to_finally              # only with finally
+    /// finally_cleanup:               # exception pending
+    ///   <finally_body>
+    ///   POP
+    ///   RERAISE
+    /// finally_with_return/break/continue:
+    ///   <finally_body>
+    ///   RETURN_VALUE or JUMP to_loop
     /// finally_block:
     ///   <finally_body>
     /// end:
     /// ```
     ///
     /// For finally blocks, exceptions that propagate through the handler dispatch
-    /// (including RERAISE when no handler matches) are caught by a second exception
-    /// entry that ensures finally runs before propagation.
+    /// (including RERAISE when no handler matches) or the else block are caught by
+    /// a second exception entry that ensures finally runs before propagation.
     ///
     /// Returns inside try/except/else jump to a "finally with return" path that
-    /// runs the finally code then returns the value.
+    /// runs the finally code then returns the value. As in CPython, a return, break
+    /// or continue in the finally block itself wins: it replaces the pending return
+    /// value, or discards the pending exception.
     ///
     /// **Note:** The finally block code is emitted multiple times (once for each
     /// control flow path: normal, exception, return, break, continue). This is the
@@ -2409,6 +2592,8 @@ impl<'a> Compiler<'a> {
                 break_jumps: Vec::new(),
                 continue_jumps: Vec::new(),
                 loop_depth_at_entry: self.loop_stack.len(),
+                except_handler_depth_at_entry: self.except_handler_depth,
+                stack_depth,
             });
         }
 
@@ -2449,6 +2634,33 @@ impl<'a> Compiler<'a> {
         // Mark end of handler dispatch (for finally exception entry)
         let handler_dispatch_end = self.code.current_offset();
 
+        // === Else block (runs if no exception) ===
+        // Compiled before the finally target is popped, so returns/break/continue in it
+        // still go through finally
+        self.code.patch_jump(after_try_jump);
+        // Normal path from try body, stack = stack_depth
+        self.code.set_stack_depth(stack_depth);
+        let else_start = self.code.current_offset();
+        if has_else {
+            self.compile_block(&try_block.or_else)?;
+        }
+        let else_end = self.code.current_offset();
+
+        // Without finally, the normal path (empty) follows directly. With finally, the
+        // cleanup and return/break/continue paths come first, so jump over them.
+        if has_finally {
+            finally_jumps.push(self.code.emit_jump(Opcode::Jump));
+        }
+
+        // Pop finally target and get all the jumps that need to go through finally.
+        // The finally body itself is compiled outside the target, so a return/break/continue
+        // in it doesn't run it again.
+        let finally_target = if has_finally {
+            Some(self.finally_targets.pop().expect("finally_targets should not be empty"))
+        } else {
+            None
+        };
+
         // === Finally cleanup handler (for exceptions during handler dispatch) ===
         // This catches exceptions from RERAISE (and any other exceptions in handlers)
         // and ensures finally runs before the exception propagates.
@@ -2456,12 +2668,13 @@ impl<'a> Compiler<'a> {
             let cleanup_start = self.code.current_offset();
             // Exception value is on stack (pushed by VM), so stack = stack_depth + 1
             self.code.set_stack_depth(stack_depth + 1);
-            // We need to pop it, run finally, then reraise
-            // But we can't easily save the exception, so we use a different approach:
-            // The exception is already on the exception_stack from handle_exception,
-            // so we can just pop from operand stack, run finally, then reraise.
-            self.code.emit(Opcode::Pop); // Pop exception from operand stack
+            // The exception stays on the stack (and on the exception_stack from handle_exception)
+            // while finally runs, as in an except handler: exceptions raised in finally get it as
+            // their context, and return/break/continue in finally clear it, discarding it.
+            self.except_handler_depth += 1;
             self.compile_block(&try_block.finally)?;
+            self.except_handler_depth -= 1;
+            self.code.emit(Opcode::Pop); // Pop exception from operand stack
             self.code.emit(Opcode::Reraise); // Re-raise from exception_stack
             Some(cleanup_start)
         } else {
@@ -2469,24 +2682,19 @@ impl<'a> Compiler<'a> {
         };
 
         // === Finally with return/break/continue paths ===
-        // Pop finally target and get all the jumps that need to go through finally
-        let finally_with_return_start = if has_finally {
-            let finally_target = self.finally_targets.pop().expect("finally_targets should not be empty");
-
+        if let Some(finally_target) = finally_target {
             // === Finally with return path ===
-            let return_start = if finally_target.return_jumps.is_empty() {
-                None
-            } else {
-                let start = self.code.current_offset();
+            if !finally_target.return_jumps.is_empty() {
                 for jump in finally_target.return_jumps {
                     self.code.patch_jump(jump);
                 }
                 // Return value is on stack, stack = stack_depth + 1
                 self.code.set_stack_depth(stack_depth + 1);
+                // A return in finally returns its own value instead; the pending one is
+                // dropped with the frame
                 self.compile_block(&try_block.finally)?;
                 self.compile_return();
-                Some(start)
-            };
+            }
 
             // === Finally with break path ===
             // For each break, run finally then either:
@@ -2496,9 +2704,8 @@ impl<'a> Compiler<'a> {
                 for break_info in &finally_target.break_jumps {
                     self.code.patch_jump(break_info.jump);
                 }
-                // Break already popped the iterator, so stack = stack_depth - 1
-                // (the iterator was on stack at try entry, break removed it)
-                self.code.set_stack_depth(stack_depth.saturating_sub(1));
+                // Break leaves the iterator on the stack until finally has run, stack = stack_depth
+                self.code.set_stack_depth(stack_depth);
                 self.compile_block(&try_block.finally)?;
                 // After finally, compile the break again (handles nested finally or direct jump)
                 self.compile_control_flow_after_finally(&finally_target.break_jumps, true);
@@ -2515,24 +2722,10 @@ impl<'a> Compiler<'a> {
                 // After finally, compile the continue again (handles nested finally or direct jump)
                 self.compile_control_flow_after_finally(&finally_target.continue_jumps, false);
             }
-
-            return_start
-        } else {
-            None
-        };
-
-        // === Else block (runs if no exception) ===
-        self.code.patch_jump(after_try_jump);
-        // Normal path from try body, stack = stack_depth
-        self.code.set_stack_depth(stack_depth);
-        let else_start = self.code.current_offset();
-        if has_else {
-            self.compile_block(&try_block.or_else)?;
         }
-        let else_end = self.code.current_offset();
 
         // === Normal finally path (no exception pending, no return) ===
-        // Patch all jumps from handlers to go here
+        // Patch all jumps from handlers and the else block to go here
         for jump in finally_jumps {
             self.code.patch_jump(jump);
         }
@@ -2567,18 +2760,10 @@ impl<'a> Compiler<'a> {
             ));
         }
 
-        // Entry 3: Finally with return -> finally cleanup
-        // If an exception occurs while running finally (in the return path), catch it
-        if let (Some(return_start), Some(cleanup_start)) = (finally_with_return_start, finally_cleanup_start) {
-            self.code.add_exception_entry(ExceptionEntry::new(
-                u32::try_from(return_start).expect("bytecode offset exceeds u32"),
-                u32::try_from(else_start).expect("bytecode offset exceeds u32"), // End at else_start (before else block)
-                u32::try_from(cleanup_start).expect("bytecode offset exceeds u32"),
-                stack_depth,
-            ));
-        }
+        // Exceptions raised by finally itself propagate without running it again, so the
+        // copies of the finally block aren't covered.
 
-        // Entry 4: Else block -> finally cleanup (only if has_finally and has_else)
+        // Entry 3: Else block -> finally cleanup (only if has_finally and has_else)
         // Exceptions in else block should go through finally
         if has_else && let Some(cleanup_start) = finally_cleanup_start {
             self.code.add_exception_entry(ExceptionEntry::new(
@@ -2810,6 +2995,31 @@ impl CompileError {
 // Operator Mapping Functions
 // ============================================================================
 
+/// Returns true if control never falls through from `node` to the next statement.
+fn is_unconditional_exit(node: &PreparedNode) -> bool {
+    matches!(
+        node,
+        Node::Return(_)
+            | Node::ReturnNone
+            | Node::Raise(_)
+            | Node::RaiseFrom { .. }
+            | Node::Break { .. }
+            | Node::Continue { .. }
+    )
+}
+
+/// Returns the truthiness of `expr` if it is a literal whose truthiness is known without
+/// looking anything up, e.g. `True`, `0` or `None`.
+fn literal_truthiness(expr: &ExprLoc) -> Option<bool> {
+    match expr.expr {
+        Expr::Literal(Literal::Bool(b)) => Some(b),
+        Expr::Literal(Literal::None) => Some(false),
+        Expr::Literal(Literal::Int(i)) => Some(i != 0),
+        Expr::Literal(Literal::Float(f)) => Some(f != 0.0),
+        _ => None,
+    }
+}
+
 /// Maps a binary `Operator` to its corresponding `Opcode`.
 fn operator_to_opcode(op: &Operator) -> Opcode {
     match op {
@@ -2877,6 +3087,7 @@ fn cmp_operator_to_opcode(op: &CmpOperator) -> Opcode {
         CmpOperator::IsNot => Opcode::CompareIsNot,
         CmpOperator::In => Opcode::CompareIn,
         CmpOperator::NotIn => Opcode::CompareNotIn,
+        CmpOperator::LtLen => Opcode::CompareLtLen,
         // ModEq is handled specially at the call site (needs constant operand)
         CmpOperator::ModEq(_) => unreachable!("ModEq handled at call site"),
     }
@@ -0,0 +1,439 @@
+//! Human-readable bytecode listings for debugging the compiler.
+//!
+//! The disassembler walks a `Code` object's raw bytecode, decoding each opcode and its
+//! operands exactly as the VM's fetch loop does, and renders one instruction per line:
+//!
+//! ```text
+//!   1           0 LoadSmallInt            1
+//!               2 StoreLocal              0 (x)
+//!   2           4 LoadLocal0                (x)
+//!               5 JumpIfFalse             3 (to 11)
+//! ```
+//!
+//! The first column is the source line (shown only when it changes), then the bytecode
+//! offset, the opcode name and the decoded operands. Where an operand refers to something
+//! with a name - interned strings, local slots, constants, functions, builtins - the
+//! resolved value is shown in parentheses.
+
+use std::fmt::{self, Write};
+
+use super::{code::Code, op::Opcode};
+use crate::{
+    builtins::BuiltinsFunctions,
+    intern::{FunctionId, Interns, StringId},
+    modules::BuiltinModule,
+    types::{Type, bytes::bytes_repr_fmt, str::string_repr_fmt},
+    value::Value,
+};
+
+/// Writes a listing of `code` to `f`, one instruction per line, followed by its exception table.
+///
+/// `module_code` is used to resolve the names of global slots: globals live in the module
+/// namespace, so their names are registered in the module's code object rather than the
+/// function's. When disassembling the module itself, pass the same code for both.
+pub(crate) fn disassemble_code(code: &Code, module_code: &Code, interns: &Interns, f: &mut impl Write) -> fmt::Result {
+    let bytecode = code.bytecode();
+    let mut reader = OperandReader { bytecode, ip: 0 };
+    let mut last_line = None;
+
+    while reader.ip < bytecode.len() {
+        let offset = reader.ip;
+        let byte = reader.u8();
+        let Ok(opcode) = Opcode::try_from(byte) else {
+            writeln!(f, "{offset:>12} <invalid opcode {byte}>")?;
+            continue;
+        };
+
+        let mut text = String::new();
+        let line = code.location_for_offset(offset).map(|entry| entry.range().start().line);
+        match line {
+            Some(line_no) if line != last_line => {
+                if last_line.is_some() {
+                    writeln!(f)?;
+                }
+                write!(text, "{line_no:>4}")?;
+                last_line = line;
+            }
+            _ => text.push_str("    "),
+        }
+
+        let mut operands = String::new();
+        let mut detail = String::new();
+        decode_operands(
+            opcode,
+            &mut reader,
+            code,
+            module_code,
+            interns,
+            &mut operands,
+            &mut detail,
+        )?;
+
+        let name = format!("{opcode:?}");
+        write!(text, "{offset:>8} {name:<22} {operands:>3}")?;
+        if !detail.is_empty() {
+            write!(text, " ({detail})")?;
+        }
+        writeln!(f, "{}", text.trim_end())?;
+    }
+
+    let exception_table = code.exception_table();
+    if !exception_table.is_empty() {
+        writeln!(f, "ExceptionTable:")?;
+        for entry in exception_table {
+            writeln!(
+                f,
+                "  {} to {} -> {} [{}]",
+                entry.start(),
+                entry.end(),
+                entry.handler(),
+                entry.stack_depth()
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Decodes the operands of a single instruction, advancing `reader` past them.
+///
+/// Raw operand values are written to `operands` (space separated) and the resolved,
+/// human-readable meaning of those operands to `detail`. The layout of each opcode's
+/// operands must match the `fetch_*!` calls in the VM's main loop.
+fn decode_operands(
+    opcode: Opcode,
+    reader: &mut OperandReader<'_>,
+    code: &Code,
+    module_code: &Code,
+    interns: &Interns,
+    operands: &mut String,
+    detail: &mut String,
+) -> fmt::Result {
+    match opcode {
+        // === No operands ===
+        Opcode::Pop
+        | Opcode::PrintExpr
+        | Opcode::Dup
+        | Opcode::Dup2
+        | Opcode::Rot2
+        | Opcode::Rot3
+        | Opcode::LoadNone
+        | Opcode::LoadTrue
+        | Opcode::LoadFalse
+        | Opcode::BinaryAdd
+        | Opcode::BinarySub
+        | Opcode::BinaryMul
+        | Opcode::BinaryDiv
+        | Opcode::BinaryFloorDiv
+        | Opcode::BinaryMod
+        | Opcode::BinaryPow
+        | Opcode::BinaryAnd
+        | Opcode::BinaryOr
+        | Opcode::BinaryXor
+        | Opcode::BinaryLShift
+        | Opcode::BinaryRShift
+        | Opcode::BinaryMatMul
+        | Opcode::CompareEq
+        | Opcode::CompareNe
+        | Opcode::CompareLt
+        | Opcode::CompareLe
+        | Opcode::CompareGt
+        | Opcode::CompareGe
+        | Opcode::CompareIs
+        | Opcode::CompareIsNot
+        | Opcode::CompareIn
+        | Opcode::CompareNotIn
+        | Opcode::CompareLtLen
+        | Opcode::UnaryNot
+        | Opcode::UnaryNeg
+        | Opcode::UnaryPos
+        | Opcode::UnaryInvert
+        | Opcode::InplaceAdd
+        | Opcode::InplaceSub
+        | Opcode::InplaceMul
+        | Opcode::InplaceDiv
+        | Opcode::InplaceFloorDiv
+        | Opcode::InplaceMod
+        | Opcode::InplacePow
+        | Opcode::InplaceAnd
+        | Opcode::InplaceOr
+        | Opcode::InplaceXor
+        | Opcode::InplaceLShift
+        | Opcode::InplaceRShift
+        | Opcode::BuildSlice
+        | Opcode::ListExtend
+        | Opcode::ListToTuple
+        | Opcode::BinarySubscr
+        | Opcode::StoreSubscr
+        | Opcode::GetIter
+        | Opcode::Raise
+        | Opcode::RaiseFrom
+        | Opcode::Reraise
+        | Opcode::ClearException
+        | Opcode::CheckExcMatch
+        | Opcode::ReturnValue
+        | Opcode::Await
+        | Opcode::YieldValue
+        | Opcode::Nop => {}
+
+        // === Slot loads with the slot baked into the opcode ===
+        Opcode::LoadLocal0 => write_local_name(code, 0, interns, detail)?,
+        Opcode::LoadLocal1 => write_local_name(code, 1, interns, detail)?,
+        Opcode::LoadLocal2 => write_local_name(code, 2, interns, detail)?,
+        Opcode::LoadLocal3 => write_local_name(code, 3, interns, detail)?,
+
+        // === Constants ===
+        Opcode::LoadConst | Opcode::CompareModEq | Opcode::RaiseImportError => {
+            let idx = reader.u16();
+            write!(operands, "{idx}")?;
+            write_const(code.constants().get(idx), interns, detail)?;
+        }
+        Opcode::LoadSmallInt => write!(operands, "{}", reader.i8())?,
+
+        // === Variables ===
+        Opcode::LoadLocal | Opcode::StoreLocal | Opcode::DeleteLocal => {
+            let slot = u16::from(reader.u8());
+            write!(operands, "{slot}")?;
+            write_local_name(code, slot, interns, detail)?;
+        }
+        Opcode::LoadLocalW | Opcode::StoreLocalW => {
+            let slot = reader.u16();
+            write!(operands, "{slot}")?;
+            write_local_name(code, slot, interns, detail)?;
+        }
+        Opcode::LoadGlobal | Opcode::StoreGlobal => {
+            let slot = reader.u16();
+            write!(operands, "{slot}")?;
+            write_local_name(module_code, slot, interns, detail)?;
+        }
+        Opcode::LoadCell | Opcode::StoreCell => write!(operands, "{}", reader.u16())?,
+
+        // === Collection building ===
+        Opcode::BuildList | Opcode::BuildTuple | Opcode::BuildDict | Opcode::BuildSet | Opcode::BuildFString => {
+            write!(operands, "{}", reader.u16())?
+        }
+        Opcode::ListAppend | Opcode::SetAdd | Opcode::DictSetItem => write!(operands, "{}", reader.u8())?,
+        Opcode::FormatValue => {
+            let flags = reader.u8();
+            write!(operands, "{flags}")?;
+            write_format_flags(flags, detail)?;
+        }
+        Opcode::DictMerge => {
+            let name_id = reader.u16();
+            write!(operands, "{name_id}")?;
+            write!(detail, "{}", interns.get_str(StringId::from_index(name_id)))?;
+        }
+
+        // === Attributes ===
+        Opcode::LoadAttr | Opcode::LoadAttrImport | Opcode::StoreAttr => {
+            let name_id = reader.u16();
+            write!(operands, "{name_id}")?;
+            write!(detail, "{}", interns.get_str(StringId::from_index(name_id)))?;
+        }
+
+        // === Calls ===
+        Opcode::CallFunction => write!(operands, "{}", reader.u8())?,
+        Opcode::CallBuiltinFunction => {
+            let builtin_id = reader.u8();
+            let arg_count = reader.u8();
+            write!(operands, "{builtin_id} {arg_count}")?;
+            match BuiltinsFunctions::from_repr(builtin_id) {
+                Some(builtin) => write!(detail, "{builtin}")?,
+                None => write!(detail, "<invalid builtin>")?,
+            }
+        }
+        Opcode::CallBuiltinType => {
+            let type_id = reader.u8();
+            let arg_count = reader.u8();
+            write!(operands, "{type_id} {arg_count}")?;
+            match Type::callable_from_u8(type_id) {
+                Some(t) => write!(detail, "{t}")?,
+                None => write!(detail, "<invalid type>")?,
+            }
+        }
+        Opcode::CallFunctionKw => {
+            let pos_count = reader.u8();
+            let kw_count = reader.u8();
+            write!(operands, "{pos_count} {kw_count}")?;
+            write_kwnames(reader, kw_count, interns, detail)?;
+        }
+        Opcode::CallAttr => {
+            let name_id = reader.u16();
+            let arg_count = reader.u8();
+            write!(operands, "{name_id} {arg_count}")?;
+            write!(detail, "{}", interns.get_str(StringId::from_index(name_id)))?;
+        }
+        Opcode::CallAttrKw => {
+            let name_id = reader.u16();
+            let pos_count = reader.u8();
+            let kw_count = reader.u8();
+            write!(operands, "{name_id} {pos_count} {kw_count}")?;
+            write!(detail, "{}", interns.get_str(StringId::from_index(name_id)))?;
+            if kw_count > 0 {
+                write!(detail, "; ")?;
+            }
+            write_kwnames(reader, kw_count, interns, detail)?;
+        }
+        Opcode::CallFunctionExtended => {
+            let flags = reader.u8();
+            write!(operands, "{flags}")?;
+            if flags & 0x01 != 0 {
+                write!(detail, "with kwargs")?;
+            }
+        }
+        Opcode::CallAttrExtended => {
+            let name_id = reader.u16();
+            let flags = reader.u8();
+            write!(operands, "{name_id} {flags}")?;
+            write!(detail, "{}", interns.get_str(StringId::from_index(name_id)))?;
+            if flags & 0x01 != 0 {
+                write!(detail, ", with kwargs")?;
+            }
+        }
+
+        // === Control flow: offsets are relative to the end of the instruction ===
+        Opcode::Jump
+        | Opcode::JumpIfTrue
+        | Opcode::JumpIfFalse
+        | Opcode::JumpIfTrueOrPop
+        | Opcode::JumpIfFalseOrPop
+        | Opcode::ForIter => {
+            let offset = reader.i16();
+            write!(operands, "{offset}")?;
+            let target = i64::try_from(reader.ip).expect("bytecode offset exceeds i64") + i64::from(offset);
+            write!(detail, "to {target}")?;
+        }
+
+        // === Function definition ===
+        Opcode::MakeFunction => {
+            let func_id = reader.u16();
+            let defaults_count = reader.u8();
+            write!(operands, "{func_id} {defaults_count}")?;
+            write_function_name(func_id, interns, detail)?;
+        }
+        Opcode::MakeClosure => {
+            let func_id = reader.u16();
+            let defaults_count = reader.u8();
+            let cell_count = reader.u8();
+            write!(operands, "{func_id} {defaults_count} {cell_count}")?;
+            write_function_name(func_id, interns, detail)?;
+        }
+
+        // === Unpacking ===
+        Opcode::UnpackSequence => write!(operands, "{}", reader.u8())?,
+        Opcode::UnpackEx => {
+            let before = reader.u8();
+            let after = reader.u8();
+            write!(operands, "{before} {after}")?;
+        }
+
+        // === Modules ===
+        Opcode::LoadModule => {
+            let module_id = reader.u8();
+            write!(operands, "{module_id}")?;
+            match BuiltinModule::from_repr(module_id) {
+                Some(module) => write!(detail, "{}", format!("{module:?}").to_lowercase())?,
+                None => write!(detail, "<invalid module>")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Cursor over a bytecode stream, mirroring the VM's `fetch_*!` macros.
+///
+/// Reads past the end of the bytecode yield zero rather than panicking, so a truncated
+/// or corrupt stream still produces a (clearly wrong) listing instead of aborting.
+struct OperandReader<'a> {
+    bytecode: &'a [u8],
+    ip: usize,
+}
+
+impl OperandReader<'_> {
+    /// Reads one unsigned byte.
+    fn u8(&mut self) -> u8 {
+        let byte = self.bytecode.get(self.ip).copied().unwrap_or_default();
+        self.ip += 1;
+        byte
+    }
+
+    /// Reads one signed byte.
+    fn i8(&mut self) -> i8 {
+        i8::from_ne_bytes([self.u8()])
+    }
+
+    /// Reads a little-endian u16.
+    fn u16(&mut self) -> u16 {
+        u16::from_le_bytes([self.u8(), self.u8()])
+    }
+
+    /// Reads a little-endian i16.
+    fn i16(&mut self) -> i16 {
+        i16::from_le_bytes([self.u8(), self.u8()])
+    }
+}
+
+/// Writes the name registered for a local slot, if the compiler recorded one.
+fn write_local_name(code: &Code, slot: u16, interns: &Interns, f: &mut impl Write) -> fmt::Result {
+    match code.local_name(slot) {
+        Some(name_id) => write!(f, "{}", interns.get_str(name_id)),
+        None => Ok(()),
+    }
+}
+
+/// Writes the name of the function referenced by a `MakeFunction`/`MakeClosure` operand.
+fn write_function_name(func_id: u16, interns: &Interns, f: &mut impl Write) -> fmt::Result {
+    let function = interns.get_function(FunctionId::from_index(func_id));
+    write!(f, "{}", interns.get_str(function.name.name_id))
+}
+
+/// Reads `kw_count` keyword-name operands and writes them as a comma separated list.
+fn write_kwnames(reader: &mut OperandReader<'_>, kw_count: u8, interns: &Interns, f: &mut impl Write) -> fmt::Result {
+    for i in 0..kw_count {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        let name_id = reader.u16();
+        write!(f, "{}=", interns.get_str(StringId::from_index(name_id)))?;
+    }
+    Ok(())
+}
+
+/// Writes the meaning of a `FormatValue` flags byte, e.g. `!r, with spec`.
+fn write_format_flags(flags: u8, f: &mut impl Write) -> fmt::Result {
+    let conversion = match flags & 0x03 {
+        1 => Some("!s"),
+        2 => Some("!r"),
+        3 => Some("!a"),
+        _ => None,
+    };
+    if let Some(conversion) = conversion {
+        write!(f, "{conversion}")?;
+    }
+    if flags & 0x04 != 0 {
+        if conversion.is_some() {
+            write!(f, ", ")?;
+        }
+        write!(f, "with spec")?;
+    }
+    Ok(())
+}
+
+/// Writes a Python-style repr of a constant pool entry.
+///
+/// Constants are always immediate values (the compiler never puts heap references in the
+/// pool), so they can be rendered without access to a heap.
+fn write_const(value: &Value, interns: &Interns, f: &mut impl Write) -> fmt::Result {
+    match value {
+        Value::None => write!(f, "None"),
+        Value::Ellipsis => write!(f, "Ellipsis"),
+        Value::NotImplemented => write!(f, "NotImplemented"),
+        Value::Bool(true) => write!(f, "True"),
+        Value::Bool(false) => write!(f, "False"),
+        Value::Int(i) => write!(f, "{i}"),
+        Value::Float(v) => write!(f, "{v:?}"),
+        Value::InternString(id) => string_repr_fmt(interns.get_str(*id), f),
+        Value::InternBytes(id) => bytes_repr_fmt(interns.get_bytes(*id), f),
+        Value::InternLongInt(id) => write!(f, "{}", interns.get_long_int(*id)),
+        other => write!(f, "{other:?}"),
+    }
+}
@@ -10,14 +10,17 @@
 //! - `code` - Code object containing bytecode and metadata
 //! - `builder` - CodeBuilder for emitting bytecode during compilation
 //! - `compiler` - AST to bytecode compiler
+//! - `disasm` - Human-readable bytecode listings for debugging
 //! - `vm` - Virtual machine for bytecode execution
 
 mod builder;
 mod code;
 mod compiler;
+mod disasm;
 mod op;
 mod vm;
 
 pub use code::Code;
 pub use compiler::Compiler;
+pub(crate) use disasm::disassemble_code;
 pub use vm::{FrameExit, VM, VMSnapshot};
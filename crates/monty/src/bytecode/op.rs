@@ -332,7 +332,6 @@ pub enum Opcode {
     // Note: No SetupTry/PopExceptHandler - we use static exception_table
     /// Raise TOS as exception.
     Raise,
-    // NOTE: RaiseFrom removed - `raise ... from ...` not supported by parser
     /// Re-raise current exception (bare `raise`).
     Reraise,
     /// Clear current_exception when exiting except block.
@@ -385,6 +384,27 @@ pub enum Opcode {
     ///
     /// The operand is an index into the constant pool where the module name string is stored.
     RaiseImportError,
+
+    // === Later Additions ===
+    // New opcodes are appended here so existing opcodes keep their discriminants, which
+    // serialized bytecode depends on.
+    /// Duplicate top two: [a, b] -> [a, b, a, b].
+    Dup2,
+    /// Length comparison: a < len(b).
+    ///
+    /// An optimization for loop conditions like `i < len(items)`. Pops b then a,
+    /// computes `len(b)`, then compares a with it.
+    CompareLtLen,
+    /// Pop TOS and write its repr to stdout unless it is None, like CPython's `PRINT_EXPR`.
+    ///
+    /// Only emitted for top-level expression statements when compiling in REPL mode.
+    PrintExpr,
+    /// Raise TOS1 with TOS as its cause (`raise exc from cause`).
+    RaiseFrom,
+    /// Pop TOS and suspend the current generator, handing the value to its caller.
+    ///
+    /// When the generator is resumed, None is pushed as the value of the yield.
+    YieldValue,
 }
 
 impl TryFrom<u8> for Opcode {
@@ -409,20 +429,22 @@ impl Opcode {
             BinaryOr, BinaryPow, BinaryRShift, BinarySub, BinarySubscr, BinaryXor, BuildDict, BuildFString, BuildList,
             BuildSet, BuildSlice, BuildTuple, CallAttr, CallAttrExtended, CallAttrKw, CallBuiltinFunction,
             CallBuiltinType, CallFunction, CallFunctionExtended, CallFunctionKw, CheckExcMatch, ClearException,
-            CompareEq, CompareGe, CompareGt, CompareIn, CompareIs, CompareIsNot, CompareLe, CompareLt, CompareModEq,
-            CompareNe, CompareNotIn, DeleteLocal, DictMerge, DictSetItem, Dup, ForIter, FormatValue, GetIter,
-            InplaceAdd, InplaceAnd, InplaceDiv, InplaceFloorDiv, InplaceLShift, InplaceMod, InplaceMul, InplaceOr,
-            InplacePow, InplaceRShift, InplaceSub, InplaceXor, Jump, JumpIfFalse, JumpIfFalseOrPop, JumpIfTrue,
-            JumpIfTrueOrPop, ListAppend, ListExtend, ListToTuple, LoadAttr, LoadAttrImport, LoadCell, LoadConst,
-            LoadFalse, LoadGlobal, LoadLocal, LoadLocal0, LoadLocal1, LoadLocal2, LoadLocal3, LoadLocalW, LoadModule,
-            LoadNone, LoadSmallInt, LoadTrue, MakeClosure, MakeFunction, Nop, Pop, Raise, RaiseImportError, Reraise,
-            ReturnValue, Rot2, Rot3, SetAdd, StoreAttr, StoreCell, StoreGlobal, StoreLocal, StoreLocalW, StoreSubscr,
-            UnaryInvert, UnaryNeg, UnaryNot, UnaryPos, UnpackEx, UnpackSequence,
+            CompareEq, CompareGe, CompareGt, CompareIn, CompareIs, CompareIsNot, CompareLe, CompareLt, CompareLtLen,
+            CompareModEq, CompareNe, CompareNotIn, DeleteLocal, DictMerge, DictSetItem, Dup, Dup2, ForIter,
+            FormatValue, GetIter, InplaceAdd, InplaceAnd, InplaceDiv, InplaceFloorDiv, InplaceLShift, InplaceMod,
+            InplaceMul, InplaceOr, InplacePow, InplaceRShift, InplaceSub, InplaceXor, Jump, JumpIfFalse,
+            JumpIfFalseOrPop, JumpIfTrue, JumpIfTrueOrPop, ListAppend, ListExtend, ListToTuple, LoadAttr,
+            LoadAttrImport, LoadCell, LoadConst, LoadFalse, LoadGlobal, LoadLocal, LoadLocal0, LoadLocal1, LoadLocal2,
+            LoadLocal3, LoadLocalW, LoadModule, LoadNone, LoadSmallInt, LoadTrue, MakeClosure, MakeFunction, Nop, Pop,
+            PrintExpr, Raise, RaiseFrom, RaiseImportError, Reraise, ReturnValue, Rot2, Rot3, SetAdd, StoreAttr,
+            StoreCell, StoreGlobal, StoreLocal, StoreLocalW, StoreSubscr, UnaryInvert, UnaryNeg, UnaryNot, UnaryPos,
+            UnpackEx, UnpackSequence, YieldValue,
         };
         Some(match self {
             // Stack operations
-            Pop => -1,
+            Pop | PrintExpr => -1,
             Dup => 1,
+            Dup2 => 2,
             Rot2 | Rot3 => 0, // reorder, no net change
 
             // Constants & Literals (all push 1)
@@ -440,7 +462,7 @@ impl Opcode {
 
             // Comparisons: pop 2, push 1 = -1
             CompareEq | CompareNe | CompareLt | CompareLe | CompareGt | CompareGe | CompareIs | CompareIsNot
-            | CompareIn | CompareNotIn | CompareModEq => -1,
+            | CompareIn | CompareNotIn | CompareModEq | CompareLtLen => -1,
 
             // Unary operations: pop 1, push 1 = 0
             UnaryNot | UnaryNeg | UnaryPos | UnaryInvert => 0,
@@ -485,14 +507,16 @@ impl Opcode {
             GetIter => 0,           // pop iterable, push iterator
             ForIter => return None, // pushes value or jumps (variable)
 
-            // Async/await
-            Await => 0, // pop awaitable, push result
+            // Async/await and generators
+            Await => 0,      // pop awaitable, push result
+            YieldValue => 0, // pop yielded value, push None on resume
 
             // Function definition - push 1 (the function/closure)
             MakeFunction | MakeClosure => 1,
 
             // Exception handling
             Raise => -1,         // pop exception
+            RaiseFrom => -2,     // pop exception and cause
             Reraise => 0,        // no stack change (reads from exception_stack)
             ClearException => 0, // clears exception_stack, no operand stack change
             CheckExcMatch => 0,  // pop exc_type, push bool (net 0, but exc stays)
@@ -531,17 +555,23 @@ mod tests {
 
     #[test]
     fn test_opcode_roundtrip() {
-        // Verify that all opcodes from 0 to RaiseImportError (last opcode) can be converted to u8 and back
-        for byte in 0..=Opcode::RaiseImportError as u8 {
+        // Verify that all opcodes from 0 to YieldValue (last opcode) can be converted to u8 and back
+        for byte in 0..=Opcode::YieldValue as u8 {
             let opcode = Opcode::try_from(byte).unwrap();
             assert_eq!(opcode as u8, byte, "opcode {opcode:?} has wrong discriminant");
         }
     }
 
+    #[test]
+    fn test_original_opcodes_keep_discriminants() {
+        // RaiseImportError was the last of the original opcodes; later additions go after it
+        assert_eq!(Opcode::RaiseImportError as u8, 107);
+    }
+
     #[test]
     fn test_invalid_opcode() {
         // Byte just after the last valid opcode should fail
-        let result = Opcode::try_from(Opcode::RaiseImportError as u8 + 1);
+        let result = Opcode::try_from(Opcode::YieldValue as u8 + 1);
         assert!(result.is_err());
         // 255 should also fail
         let result = Opcode::try_from(255u8);
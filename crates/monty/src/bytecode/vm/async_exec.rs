@@ -619,6 +619,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                 namespace_idx: f.namespace_idx,
                 cells: f.cells,
                 call_position: f.call_position,
+                generator: f.generator,
             })
             .collect();
         let stack = std::mem::take(&mut self.stack);
@@ -676,6 +677,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                         function_id: sf.function_id,
                         cells: sf.cells,
                         call_position: sf.call_position,
+                        generator: sf.generator,
                     }
                 })
                 .collect();
@@ -1,14 +1,17 @@
 //! Binary and in-place operation helpers for the VM.
 
-use super::VM;
+use super::{CachedFrame, VM};
 use crate::{
+    bytecode::op::Opcode,
     defer_drop,
-    exception_private::{ExcType, RunError},
-    heap::HeapGuard,
+    exception_private::{ExcType, RunError, RunResult},
+    heap::{HeapData, HeapGuard},
     io::PrintWriter,
+    namespace::{GLOBAL_NS_IDX, NamespaceId},
+    printf::{bytes_percent_format, percent_format},
     resource::ResourceTracker,
-    types::PyTrait,
-    value::BitwiseOp,
+    types::{Bytes, PyTrait, str::allocate_string},
+    value::{BitwiseOp, Value},
 };
 
 impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
@@ -38,10 +41,11 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         }
     }
 
-    /// Binary subtraction with proper refcount handling.
+    /// Binary subtraction with proper refcount handling, also used for the in-place operator.
     ///
+    /// `op` is the operator symbol named in the `TypeError` for unsupported operands.
     /// Uses lazy type capture: only calls `py_type()` in error paths.
-    pub(super) fn binary_sub(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_sub(&mut self, op: &str) -> Result<(), RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -57,16 +61,17 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             Ok(None) => {
                 let lhs_type = lhs.py_type(this.heap);
                 let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("-", lhs_type, rhs_type))
+                Err(ExcType::binary_type_error(op, lhs_type, rhs_type))
             }
             Err(e) => Err(e.into()),
         }
     }
 
-    /// Binary multiplication with proper refcount handling.
+    /// Binary multiplication with proper refcount handling, also used for the in-place operator.
     ///
+    /// `op` is the operator symbol named in the `TypeError` for unsupported operands.
     /// Uses lazy type capture: only calls `py_type()` in error paths.
-    pub(super) fn binary_mult(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_mult(&mut self, op: &str) -> Result<(), RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -82,16 +87,17 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             Ok(None) => {
                 let lhs_type = lhs.py_type(this.heap);
                 let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("*", lhs_type, rhs_type))
+                Err(ExcType::binary_type_error(op, lhs_type, rhs_type))
             }
             Err(e) => Err(e),
         }
     }
 
-    /// Binary division with proper refcount handling.
+    /// Binary division with proper refcount handling, also used for the in-place operator.
     ///
+    /// `op` is the operator symbol named in the `TypeError` for unsupported operands.
     /// Uses lazy type capture: only calls `py_type()` in error paths.
-    pub(super) fn binary_div(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_div(&mut self, op: &str) -> Result<(), RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -107,16 +113,17 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             Ok(None) => {
                 let lhs_type = lhs.py_type(this.heap);
                 let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("/", lhs_type, rhs_type))
+                Err(ExcType::binary_type_error(op, lhs_type, rhs_type))
             }
             Err(e) => Err(e),
         }
     }
 
-    /// Binary floor division with proper refcount handling.
+    /// Binary floor division with proper refcount handling, also used for the in-place operator.
     ///
+    /// `op` is the operator symbol named in the `TypeError` for unsupported operands.
     /// Uses lazy type capture: only calls `py_type()` in error paths.
-    pub(super) fn binary_floordiv(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_floordiv(&mut self, op: &str) -> Result<(), RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -132,16 +139,17 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             Ok(None) => {
                 let lhs_type = lhs.py_type(this.heap);
                 let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("//", lhs_type, rhs_type))
+                Err(ExcType::binary_type_error(op, lhs_type, rhs_type))
             }
             Err(e) => Err(e),
         }
     }
 
-    /// Binary modulo with proper refcount handling.
+    /// Binary modulo with proper refcount handling, also used for the in-place operator.
     ///
+    /// `op` is the operator symbol named in the `TypeError` for unsupported operands.
     /// Uses lazy type capture: only calls `py_type()` in error paths.
-    pub(super) fn binary_mod(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_mod(&mut self, op: &str) -> Result<(), RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -149,7 +157,11 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         let lhs = this.pop();
         defer_drop!(lhs, this);
 
-        match lhs.py_mod(rhs, this.heap) {
+        let result = match lhs.py_mod(rhs, this.heap) {
+            Ok(None) => this.percent_format(lhs, rhs),
+            other => other,
+        };
+        match result {
             Ok(Some(v)) => {
                 this.push(v);
                 Ok(())
@@ -157,17 +169,35 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             Ok(None) => {
                 let lhs_type = lhs.py_type(this.heap);
                 let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("%", lhs_type, rhs_type))
+                Err(ExcType::binary_type_error(op, lhs_type, rhs_type))
             }
             Err(e) => Err(e),
         }
     }
 
-    /// Binary power with proper refcount handling.
+    /// printf-style formatting for `str % args` and `bytes % args`, the fallback when
+    /// `py_mod` doesn't handle `%`.
     ///
+    /// Returns `Ok(None)` if `lhs` is not a string or bytes so the caller can raise the usual
+    /// unsupported-operand error.
+    pub(super) fn percent_format(&mut self, lhs: &Value, rhs: &Value) -> RunResult<Option<Value>> {
+        if let Some(formatted) = percent_format(lhs, rhs, self.heap, self.interns)? {
+            return Ok(Some(allocate_string(formatted, self.heap)?));
+        }
+        match bytes_percent_format(lhs, rhs, self.heap, self.interns)? {
+            Some(formatted) => Ok(Some(Value::Ref(
+                self.heap.allocate(HeapData::Bytes(Bytes::new(formatted)))?,
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Binary power with proper refcount handling, also used for the in-place operator.
+    ///
+    /// `op` is the operator symbol named in the `TypeError` for unsupported operands.
     /// Uses lazy type capture: only calls `py_type()` in error paths.
     #[inline(never)]
-    pub(super) fn binary_pow(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_pow(&mut self, op: &str) -> Result<(), RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -183,7 +213,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             Ok(None) => {
                 let lhs_type = lhs.py_type(this.heap);
                 let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("** or pow()", lhs_type, rhs_type))
+                Err(ExcType::binary_type_error(op, lhs_type, rhs_type))
             }
             Err(e) => Err(e),
         }
@@ -192,7 +222,9 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     /// Binary bitwise operation on integers.
     ///
     /// Pops two values, performs the bitwise operation, and pushes the result.
-    pub(super) fn binary_bitwise(&mut self, op: BitwiseOp) -> Result<(), RunError> {
+    /// `|` is also the union operator for dicts and sets. Also used for the in-place
+    /// operators, `symbol` being the operator named in the `TypeError` for unsupported operands.
+    pub(super) fn binary_bitwise(&mut self, op: BitwiseOp, symbol: &str) -> Result<(), RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -200,7 +232,58 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         let lhs = this.pop();
         defer_drop!(lhs, this);
 
-        let result = lhs.py_bitwise(rhs, op, this.heap)?;
+        if op == BitwiseOp::Or
+            && let Some(result) = lhs.py_union(rhs, this.heap, this.interns)?
+        {
+            this.push(result);
+            return Ok(());
+        }
+
+        match lhs.py_bitwise(rhs, op, this.heap)? {
+            Some(result) => {
+                this.push(result);
+                Ok(())
+            }
+            None => {
+                let lhs_type = lhs.py_type(this.heap);
+                let rhs_type = rhs.py_type(this.heap);
+                Err(ExcType::binary_type_error(symbol, lhs_type, rhs_type))
+            }
+        }
+    }
+
+    /// In-place bitwise or (`|=`).
+    ///
+    /// Dicts and sets are updated in place; other operands fall back to `|`.
+    ///
+    /// Note: Cannot use `defer_drop!` for `lhs` here because on successful in-place
+    /// operation, we need to push `lhs` back onto the stack rather than drop it.
+    pub(super) fn inplace_or(&mut self) -> Result<(), RunError> {
+        let this = self;
+
+        let rhs = this.pop();
+        defer_drop!(rhs, this);
+        // Use HeapGuard because the in-place union will push lhs back on the stack if successful
+        let mut lhs_guard = HeapGuard::new(this.pop(), this);
+        let (lhs, this) = lhs_guard.as_parts_mut();
+
+        if lhs.py_inplace_union(rhs, this.heap, this.interns)? {
+            let (lhs, this) = lhs_guard.into_parts();
+            this.push(lhs);
+            return Ok(());
+        }
+
+        let result = match lhs.py_union(rhs, this.heap, this.interns)? {
+            Some(result) => result,
+            None => match lhs.py_bitwise(rhs, BitwiseOp::Or, this.heap)? {
+                Some(result) => result,
+                None => {
+                    let lhs_type = lhs.py_type(this.heap);
+                    let rhs_type = rhs.py_type(this.heap);
+                    return Err(ExcType::binary_type_error("|=", lhs_type, rhs_type));
+                }
+            },
+        };
         this.push(result);
         Ok(())
     }
@@ -214,7 +297,11 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     ///
     /// Note: Cannot use `defer_drop!` for `lhs` here because on successful in-place
     /// operation, we need to push `lhs` back onto the stack rather than drop it.
-    pub(super) fn inplace_add(&mut self) -> Result<(), RunError> {
+    pub(super) fn inplace_add(&mut self, cached_frame: &CachedFrame<'_>) -> Result<(), RunError> {
+        if self.append_str_in_place(cached_frame)? {
+            return Ok(());
+        }
+
         let this = self;
 
         let rhs = this.pop();
@@ -242,6 +329,54 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         Err(ExcType::binary_type_error("+=", lhs_type, rhs_type))
     }
 
+    /// Fast path for `s += x` on strings, appending to `s` in place rather than copying it.
+    ///
+    /// Strings are immutable, so this is only done when nothing else can see the change: the
+    /// next instruction stores the result back to the variable holding `s`, and that variable
+    /// and the operand stack hold its only references. Building a string in a loop is then
+    /// linear rather than quadratic, like CPython's equivalent optimization. An aliased string
+    /// is still copied.
+    ///
+    /// Returns `false` with the stack untouched when the fast path doesn't apply.
+    fn append_str_in_place(&mut self, cached_frame: &CachedFrame<'_>) -> Result<bool, RunError> {
+        let [.., Value::Ref(lhs_id), rhs] = self.stack.as_slice() else {
+            return Ok(false);
+        };
+        let lhs_id = *lhs_id;
+        if self.store_target(cached_frame).and_then(Value::ref_id) != Some(lhs_id)
+            || !self.heap.append_unshared_str(lhs_id, 2, rhs, self.interns)?
+        {
+            return Ok(false);
+        }
+        let rhs = self.pop();
+        rhs.drop_with_heap(self.heap);
+        Ok(true)
+    }
+
+    /// Returns the current value of the variable that the instruction at the cached IP stores
+    /// to, or `None` if that instruction isn't a local or global store.
+    fn store_target(&self, cached_frame: &CachedFrame<'_>) -> Option<&Value> {
+        let bytecode = cached_frame.code.bytecode();
+        let ip = cached_frame.ip;
+        let (namespace_idx, slot) = match Opcode::try_from(*bytecode.get(ip)?).ok()? {
+            Opcode::StoreLocal => (cached_frame.namespace_idx, u16::from(*bytecode.get(ip + 1)?)),
+            Opcode::StoreLocalW => (
+                cached_frame.namespace_idx,
+                u16::from_le_bytes([*bytecode.get(ip + 1)?, *bytecode.get(ip + 2)?]),
+            ),
+            Opcode::StoreGlobal => (
+                GLOBAL_NS_IDX,
+                u16::from_le_bytes([*bytecode.get(ip + 1)?, *bytecode.get(ip + 2)?]),
+            ),
+            _ => return None,
+        };
+        Some(
+            self.namespaces
+                .get(namespace_idx)
+                .get(NamespaceId::new(usize::from(slot))),
+        )
+    }
+
     /// Binary matrix multiplication (`@` operator).
     ///
     /// Currently not implemented - returns a `NotImplementedError`.
@@ -4,27 +4,53 @@
 //! functions for executing function calls. The main entry points are the `exec_*`
 //! methods which are called from the VM's main dispatch loop.
 
-use super::{CallFrame, VM};
+use super::{
+    CallFrame, FrameExit, VM,
+    generator::{
+        builtin_drains_generators, method_drains_generators, module_function_drains_generators, type_drains_generators,
+    },
+};
 use crate::{
     args::{ArgValues, KwargsValues},
     asyncio::Coroutine,
-    builtins::{Builtins, BuiltinsFunctions},
-    exception_private::{ExcType, RunError},
+    builtins::{Builtins, BuiltinsFunctions, dir::sorted_name_list},
+    exception_private::{ExcType, RunError, RunResult},
     heap::{DropWithHeap, Heap, HeapData, HeapId},
     intern::{ExtFunctionId, FunctionId, Interns, StaticStrings, StringId},
     io::PrintWriter,
+    modules::{
+        ModuleFunctions,
+        warnings::{self, WarningsFunctions},
+    },
+    namespace::NamespaceId,
     os::OsFunction,
-    resource::ResourceTracker,
+    resource::{ResourceError, ResourceTracker},
     types::{
-        AttrCallResult, Dict, PyTrait, Type,
+        AttrCallResult, Dict, Generator, PyTrait, Type,
         bytes::{bytes_fromhex, call_bytes_method},
         dict::dict_fromkeys,
-        list::do_list_sort,
-        str::call_str_method,
+        operator::call_getter,
+        str::{call_str_method, str_maketrans},
     },
     value::{EitherStr, Value},
 };
 
+/// Maximum number of nested callbacks from builtins like `sorted(key=...)`.
+///
+/// This isn't a Python recursion limit: callback frames count towards the configured
+/// recursion limit like any other frame. It bounds the Rust stack instead. Each callback
+/// runs in a nested `run()`, and overflowing the host's stack aborts the process rather
+/// than raising an exception. The value is low enough for a debug build, where `run()` has
+/// its largest stack frame, to nest this deep on a 2 MiB thread stack (Rust's default for
+/// spawned threads). Release builds use the same value so code hits the limit the same way
+/// however Monty was built.
+///
+/// Only callbacks running inside callbacks add up: `sorted(key=lambda r: max(r, key=abs))`
+/// is two deep, and a generator advanced by `sum()` or `any()` is one more. Builtins nested
+/// directly in code stay well below the limit. It's reached by recursion through a builtin,
+/// like a function that calls itself through `map()`, which raises `RecursionError`.
+const MAX_CALLBACK_DEPTH: usize = 20;
+
 /// Result of executing a call opcode.
 ///
 /// Used by the `exec_*` methods to communicate what action the VM's main loop
@@ -75,11 +101,15 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     ///
     /// Calls a builtin function directly without stack manipulation for the callable.
     /// This is an optimization that avoids constant pool lookup and stack manipulation.
-    pub(super) fn exec_call_builtin_function(&mut self, builtin_id: u8, arg_count: usize) -> Result<Value, RunError> {
+    pub(super) fn exec_call_builtin_function(
+        &mut self,
+        builtin_id: u8,
+        arg_count: usize,
+    ) -> Result<CallResult, RunError> {
         // Convert u8 to BuiltinsFunctions via FromRepr
         if let Some(builtin) = BuiltinsFunctions::from_repr(builtin_id) {
             let args = self.pop_n_args(arg_count);
-            builtin.call(self.heap, args, self.interns, self.print_writer)
+            self.call_builtin_function(builtin, args)
         } else {
             Err(RunError::internal("CallBuiltinFunction: invalid builtin_id"))
         }
@@ -92,7 +122,10 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     pub(super) fn exec_call_builtin_type(&mut self, type_id: u8, arg_count: usize) -> Result<Value, RunError> {
         // Convert u8 to Type via callable_from_u8
         if let Some(t) = Type::callable_from_u8(type_id) {
-            let args = self.pop_n_args(arg_count);
+            let mut args = self.pop_n_args(arg_count);
+            if type_drains_generators(t) {
+                args = self.drain_generator_args(args)?;
+            }
             t.call(self.heap, args, self.interns)
         } else {
             Err(RunError::internal("CallBuiltinType: invalid type_id"))
@@ -256,19 +289,58 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     /// For interned strings (`Value::InternString`), uses the unified `call_str_method`.
     /// For interned bytes (`Value::InternBytes`), uses the unified `call_bytes_method`.
     ///
-    /// Special handling: `list.sort(key=...)` is intercepted here to allow calling
-    /// builtin key functions with VM access.
-    fn call_attr(&mut self, obj: Value, name_id: StringId, args: ArgValues) -> Result<CallResult, RunError> {
+    /// Special handling: `list.sort(key=...)` is intercepted here so the key function
+    /// can be called with VM access, and `warnings.warn()` so it can reach the print writer.
+    /// Generators passed to methods like `str.join()` are drained into lists first.
+    fn call_attr(&mut self, obj: Value, name_id: StringId, mut args: ArgValues) -> Result<CallResult, RunError> {
         let attr = EitherStr::Interned(name_id);
 
+        if method_drains_generators(name_id) && self.has_generator_arg(&args) {
+            args = match self.drain_generator_args(args) {
+                Ok(args) => args,
+                Err(err) => {
+                    obj.drop_with_heap(self.heap);
+                    return Err(err);
+                }
+            };
+        }
+
         match obj {
             Value::Ref(heap_id) => {
                 // Check for list.sort - needs special handling for key functions
                 if name_id == StaticStrings::Sort && matches!(self.heap.get(heap_id), HeapData::List(_)) {
-                    let result = do_list_sort(heap_id, args, self.heap, self.interns, self.print_writer);
+                    let result = self.sort_list(heap_id, args);
                     obj.drop_with_heap(self.heap);
                     return result.map(|()| CallResult::Push(Value::None));
                 }
+                // list.append is the most common method call in loops, so it skips the generic dispatch
+                if name_id == StaticStrings::Append
+                    && matches!(self.heap.get(heap_id), HeapData::List(_))
+                    && let ArgValues::One(item) = args
+                {
+                    self.heap.with_entry_mut(heap_id, |heap, data| {
+                        let HeapData::List(list) = data else {
+                            unreachable!("checked above")
+                        };
+                        list.append(heap, item);
+                    });
+                    // Charge the list's growth, as `call_attr_raw` does for other methods
+                    let charged = self.heap.update_size(heap_id);
+                    obj.drop_with_heap(self.heap);
+                    charged?;
+                    return Ok(CallResult::Push(Value::None));
+                }
+                // Check for warnings.warn - needs the print writer to report the warning
+                if name_id == StaticStrings::Warn
+                    && let HeapData::Module(module) = self.heap.get(heap_id)
+                    && module.name() == StaticStrings::Warnings
+                {
+                    obj.drop_with_heap(self.heap);
+                    return self.call_function(
+                        Value::ModuleFunction(ModuleFunctions::Warnings(WarningsFunctions::Warn)),
+                        args,
+                    );
+                }
                 // Call the method on the heap object using call_attr_raw to support OS/external calls
                 let result = self.heap.call_attr_raw(heap_id, &attr, args, self.interns);
                 obj.drop_with_heap(self.heap);
@@ -301,19 +373,33 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     /// Calls a callable value with the given arguments.
     ///
     /// Dispatches based on the callable type:
-    /// - `Value::Builtin`: calls builtin directly, returns `Push`
+    /// - `Value::Builtin`: calls builtin directly, returns `Push` (or `OsCall` for `input()`)
     /// - `Value::ModuleFunction`: calls module function directly, returns `Push`
     /// - `Value::ExtFunction`: returns `External` for caller to execute
     /// - `Value::DefFunction`: pushes a new frame, returns `FramePushed`
     /// - `Value::Ref`: checks for closure/function on heap
     fn call_function(&mut self, callable: Value, args: ArgValues) -> Result<CallResult, RunError> {
         match callable {
+            Value::Builtin(Builtins::Function(builtin)) => self.call_builtin_function(builtin, args),
+            Value::Builtin(builtin @ Builtins::Type(t)) if type_drains_generators(t) => {
+                let args = self.drain_generator_args(args)?;
+                let result = builtin.call(self.heap, args, self.interns, self.print_writer)?;
+                Ok(result.into())
+            }
             Value::Builtin(builtin) => {
                 let result = builtin.call(self.heap, args, self.interns, self.print_writer)?;
-                Ok(CallResult::Push(result))
+                Ok(result.into())
+            }
+            Value::ModuleFunction(ModuleFunctions::Warnings(function)) => {
+                warnings::call(self.heap, function, args, self.interns, self.print_writer).map(CallResult::Push)
             }
             Value::ModuleFunction(mf) => {
-                let result = mf.call(self.heap, args)?;
+                let args = if module_function_drains_generators(mf) {
+                    self.drain_generator_args(args)?
+                } else {
+                    args
+                };
+                let result = mf.call(self.heap, args, self.interns)?;
                 Ok(result.into())
             }
             Value::ExtFunction(ext_id) => {
@@ -335,7 +421,203 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         }
     }
 
-    /// Handles calling a heap-allocated callable (closure or function with defaults).
+    /// Calls a builtin function.
+    ///
+    /// Builtins that call back into Python code or advance generators are implemented on the
+    /// VM (see `higher_order`), as are `dir()` and `vars()` without arguments, which read the
+    /// current scope; the rest only need the heap.
+    fn call_builtin_function(&mut self, builtin: BuiltinsFunctions, args: ArgValues) -> Result<CallResult, RunError> {
+        let value = match builtin {
+            BuiltinsFunctions::All => self.builtin_any_all(args, false),
+            BuiltinsFunctions::Any => self.builtin_any_all(args, true),
+            BuiltinsFunctions::Filter => self.builtin_filter(args),
+            BuiltinsFunctions::Map => self.builtin_map(args),
+            BuiltinsFunctions::Max => self.builtin_min_max(args, false),
+            BuiltinsFunctions::Min => self.builtin_min_max(args, true),
+            BuiltinsFunctions::Next => self.builtin_next(args),
+            BuiltinsFunctions::Sorted => self.builtin_sorted(args),
+            BuiltinsFunctions::Sum => self.builtin_sum(args),
+            BuiltinsFunctions::Dir | BuiltinsFunctions::Vars => {
+                let name: &'static str = builtin.into();
+                match args.get_zero_one_arg(name, self.heap)? {
+                    Some(value) => {
+                        let result = builtin.call(self.heap, ArgValues::One(value), self.interns, self.print_writer)?;
+                        return Ok(result.into());
+                    }
+                    None if builtin == BuiltinsFunctions::Dir => self.scope_dir(),
+                    None => self.scope_vars(),
+                }
+            }
+            _ => {
+                let args = if builtin_drains_generators(builtin) {
+                    self.drain_generator_args(args)?
+                } else {
+                    args
+                };
+                return Ok(builtin.call(self.heap, args, self.interns, self.print_writer)?.into());
+            }
+        };
+        value.map(CallResult::Push)
+    }
+
+    /// Implements `dir()` without arguments: the sorted names bound in the current scope.
+    fn scope_dir(&mut self) -> RunResult<Value> {
+        let names = self
+            .scope_slots()
+            .into_iter()
+            .map(|(name, _)| self.interns.get_str(name).to_owned())
+            .collect();
+        sorted_name_list(names, self.heap)
+    }
+
+    /// Implements `vars()` without arguments: a dict of the names bound in the current scope.
+    fn scope_vars(&mut self) -> RunResult<Value> {
+        let namespace_idx = self.current_frame().namespace_idx;
+        let pairs = self
+            .scope_slots()
+            .into_iter()
+            .map(|(name, slot)| {
+                let value = self.namespaces.get(namespace_idx).get(slot).clone_with_heap(self.heap);
+                (Value::InternString(name), value)
+            })
+            .collect();
+        let dict = Dict::from_pairs(pairs, self.heap, self.interns)?;
+        Ok(Value::Ref(self.heap.allocate(HeapData::Dict(dict))?))
+    }
+
+    /// Returns the name and slot of each bound variable in the current frame's namespace.
+    ///
+    /// The code only records names for the slots it reads or writes, so parameter names come
+    /// from the function's signature. Variables captured by nested functions live in cells
+    /// rather than in the namespace slots, so they're left out, as are the hidden variables
+    /// that `with` statements expand to, whose names start with `.`.
+    fn scope_slots(&self) -> Vec<(StringId, NamespaceId)> {
+        let frame = self.current_frame();
+        let namespace = self.namespaces.get(frame.namespace_idx);
+        let params: Vec<StringId> = frame.function_id.map_or_else(Vec::new, |id| {
+            self.interns.get_function(id).signature.param_names().collect()
+        });
+        let local_names = frame.code.local_names();
+        (0..params.len().max(local_names.len()))
+            .filter_map(|slot| {
+                let name = *params.get(slot).or_else(|| local_names.get(slot))?;
+                let slot = NamespaceId::new(slot);
+                let bound = match namespace.get_opt(slot) {
+                    None | Some(Value::Undefined) => false,
+                    Some(Value::Ref(id)) => !matches!(self.heap.get(*id), HeapData::Cell(_)),
+                    Some(_) => true,
+                };
+                let hidden = name == StringId::default() || self.interns.get_str(name).starts_with('.');
+                (bound && !hidden).then_some((name, slot))
+            })
+            .collect()
+    }
+
+    /// Calls `callable` with `args` from Rust and returns its result.
+    ///
+    /// This is how builtins that take a function, like `sorted(key=...)` and `map()`, call it.
+    /// Builtin callables return immediately. Defined functions and closures push a frame,
+    /// which [`run_callback_frame`](Self::run_callback_frame) runs to completion.
+    ///
+    /// The callback runs inside the builtin, so it can't pause the VM: calling an external
+    /// function or an OS function from it raises `NotImplementedError`.
+    pub(super) fn call_value(&mut self, callable: &Value, args: ArgValues) -> RunResult<Value> {
+        let callable = callable.clone_with_heap(self.heap);
+        match self.call_function(callable, args)? {
+            CallResult::Push(value) => Ok(value),
+            CallResult::FramePushed => self.run_callback_frame(),
+            CallResult::External(_, args) => {
+                args.drop_with_heap(self.heap);
+                Err(ExcType::not_supported_in_callback("external function calls"))
+            }
+            CallResult::OsCall(_, args) => {
+                args.drop_with_heap(self.heap);
+                Err(ExcType::not_supported_in_callback("OS calls"))
+            }
+        }
+    }
+
+    /// Calls `callable` on behalf of the host and runs it to completion.
+    ///
+    /// Used by `MontyFunction::call` on a VM with no frames, so a defined function's frame
+    /// is the last one and returns the same way module code does. A reference to `callable`
+    /// stays on the stack while it runs, which keeps it reachable for the garbage collector;
+    /// `cleanup()` drops it afterwards.
+    pub fn call_from_host(&mut self, callable: &Value, args: ArgValues) -> Result<FrameExit, RunError> {
+        self.push(callable.clone_with_heap(self.heap));
+        let callable = callable.clone_with_heap(self.heap);
+        match self.call_function(callable, args)? {
+            CallResult::Push(value) => Ok(FrameExit::Return(value)),
+            CallResult::FramePushed => self.run(),
+            CallResult::External(ext_function_id, args) => Ok(FrameExit::ExternalCall {
+                ext_function_id,
+                args,
+                call_id: self.allocate_call_id(),
+            }),
+            CallResult::OsCall(function, args) => Ok(FrameExit::OsCall {
+                function,
+                args,
+                call_id: self.allocate_call_id(),
+            }),
+        }
+    }
+
+    /// Runs the frame just pushed by [`call_value`](Self::call_value) until it returns.
+    ///
+    /// Sets `callback_frames` so the nested `run()` returns when this frame does, and so an
+    /// exception the function doesn't handle unwinds only as far as the builtin. The
+    /// builtin's instruction IP is saved and restored around the nested run, since errors
+    /// it raises afterwards are located with it.
+    ///
+    /// If the callback tries to yield to the host, the yield is turned into a
+    /// `NotImplementedError` raised at that point, which the callback may catch.
+    pub(super) fn run_callback_frame(&mut self) -> RunResult<Value> {
+        if self.callback_depth >= MAX_CALLBACK_DEPTH {
+            self.pop_frame();
+            return Err(ResourceError::Recursion {
+                limit: MAX_CALLBACK_DEPTH,
+                depth: MAX_CALLBACK_DEPTH + 1,
+            }
+            .into());
+        }
+
+        let outer_callback_frames = std::mem::replace(&mut self.callback_frames, self.frames.len());
+        let outer_ip = self.instruction_ip;
+        self.callback_depth += 1;
+
+        let mut exit = self.run();
+        let result = loop {
+            let error = match exit {
+                Ok(FrameExit::Return(value)) => break Ok(value),
+                Ok(FrameExit::ExternalCall { args, .. }) => {
+                    args.drop_with_heap(self.heap);
+                    ExcType::not_supported_in_callback("external function calls")
+                }
+                Ok(FrameExit::OsCall { args, .. }) => {
+                    args.drop_with_heap(self.heap);
+                    self.file_resume = None;
+                    ExcType::not_supported_in_callback("OS calls")
+                }
+                Ok(FrameExit::ResolveFutures(_)) => RunError::internal("callback frame yielded to resolve futures"),
+                // Errors handled by `handle_exception` have already unwound to the builtin
+                Err(error) if self.frames.len() < self.callback_frames => break Err(error),
+                // Errors returned directly by `run()`, like the time limit, still need unwinding
+                Err(error) => error,
+            };
+            match self.handle_exception(error) {
+                Some(error) => break Err(error),
+                None => exit = self.run(),
+            }
+        };
+
+        self.callback_depth -= 1;
+        self.callback_frames = outer_callback_frames;
+        self.instruction_ip = outer_ip;
+        result
+    }
+
+    /// Handles calling a heap-allocated callable (closure, function with defaults, or
+    /// `operator` getter).
     ///
     /// Uses a two-phase approach to avoid borrow conflicts:
     /// 1. Copy data without incrementing refcounts
@@ -346,6 +628,13 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         callable: Value,
         args: ArgValues,
     ) -> Result<CallResult, RunError> {
+        // `itemgetter`/`attrgetter` objects run without a frame
+        if matches!(self.heap.get(heap_id), HeapData::Getter(_)) {
+            let result = call_getter(self.heap, heap_id, args, self.interns);
+            callable.drop_with_heap(self.heap);
+            return result.map(CallResult::Push);
+        }
+
         // Phase 1: Copy data (func_id, cells, defaults) without refcount changes
         let (func_id, cells, defaults) = match self.heap.get(heap_id) {
             HeapData::Closure(fid, cells, defaults) => {
@@ -606,7 +895,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     // Frame Setup
     // ========================================================================
 
-    /// Calls a defined function by pushing a new frame or creating a coroutine.
+    /// Calls a defined function by pushing a new frame or creating a coroutine or generator.
     ///
     /// For sync functions: sets up the function's namespace with bound arguments,
     /// cell variables, and free variables, then pushes a new frame.
@@ -614,6 +903,9 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     /// For async functions: binds arguments immediately but returns a Coroutine
     /// instead of pushing a frame. The coroutine stores the pre-bound namespace
     /// and will be executed when awaited.
+    ///
+    /// For the hidden functions of generator expressions: likewise returns a Generator,
+    /// which runs the function's body as it is advanced.
     fn call_def_function(
         &mut self,
         func_id: FunctionId,
@@ -627,6 +919,9 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         if func.is_async {
             // Async function: create a Coroutine instead of pushing a frame
             self.create_coroutine(func_id, cells, defaults, args)
+        } else if func.is_generator {
+            // Generator expression: create a Generator instead of pushing a frame
+            self.create_generator(func_id, cells, defaults, args)
         } else {
             // Sync function: push a new frame
             self.call_sync_function(func_id, cells, defaults, args)
@@ -645,6 +940,42 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         defaults: Vec<Value>,
         args: ArgValues,
     ) -> Result<CallResult, RunError> {
+        let (namespace, frame_cells) = self.bind_unregistered_namespace(func_id, cells, defaults, args)?;
+        let coroutine = Coroutine::new(func_id, namespace, frame_cells);
+        let coroutine_id = self.heap.allocate(HeapData::Coroutine(coroutine))?;
+
+        Ok(CallResult::Push(Value::Ref(coroutine_id)))
+    }
+
+    /// Creates a Generator for a call of a generator expression's hidden function.
+    ///
+    /// Like [`create_coroutine`](Self::create_coroutine), the namespace is bound now and
+    /// stored in the Generator, whose frame is pushed each time it's advanced.
+    fn create_generator(
+        &mut self,
+        func_id: FunctionId,
+        cells: &[HeapId],
+        defaults: Vec<Value>,
+        args: ArgValues,
+    ) -> Result<CallResult, RunError> {
+        let (namespace, frame_cells) = self.bind_unregistered_namespace(func_id, cells, defaults, args)?;
+        let generator = Generator::new(func_id, namespace, frame_cells);
+        let generator_id = self.heap.allocate(HeapData::Generator(generator))?;
+
+        Ok(CallResult::Push(Value::Ref(generator_id)))
+    }
+
+    /// Builds the namespace of a function call without registering it with `Namespaces`.
+    ///
+    /// Returns the namespace and the HeapIds of the cells its frame uses, for functions
+    /// whose frames are pushed later: coroutines and generators.
+    fn bind_unregistered_namespace(
+        &mut self,
+        func_id: FunctionId,
+        cells: &[HeapId],
+        defaults: Vec<Value>,
+        args: ArgValues,
+    ) -> RunResult<(Vec<Value>, Vec<HeapId>)> {
         let func = self.interns.get_function(func_id);
 
         // 1. Create namespace vector (not registered with Namespaces)
@@ -673,7 +1004,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             default.drop_with_heap(self.heap);
         }
 
-        // Track created cell HeapIds for the coroutine
+        // Track created cell HeapIds for the frame
         let mut frame_cells: Vec<HeapId> = Vec::with_capacity(func.cell_var_count + cells.len());
 
         // 3. Create cells for variables captured by nested functions
@@ -706,11 +1037,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             namespace.resize_with(func.namespace_size, || Value::Undefined);
         }
 
-        // 6. Create Coroutine on heap
-        let coroutine = Coroutine::new(func_id, namespace, frame_cells);
-        let coroutine_id = self.heap.allocate(HeapData::Coroutine(coroutine))?;
-
-        Ok(CallResult::Push(Value::Ref(coroutine_id)))
+        Ok((namespace, frame_cells))
     }
 
     /// Calls a sync function by pushing a new frame.
@@ -804,8 +1131,8 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
 
 /// Dispatches a classmethod call on a type object.
 ///
-/// Handles classmethods like `dict.fromkeys()` and `bytes.fromhex()` that are
-/// called on the type itself rather than on an instance.
+/// Handles classmethods like `dict.fromkeys()` and `bytes.fromhex()`, and static methods
+/// like `str.maketrans()`, that are called on the type itself rather than on an instance.
 fn call_type_method(
     t: Type,
     method_id: StringId,
@@ -816,6 +1143,7 @@ fn call_type_method(
     match (t, method_id) {
         (Type::Dict, m) if m == StaticStrings::Fromkeys => return dict_fromkeys(args, heap, interns),
         (Type::Bytes, m) if m == StaticStrings::Fromhex => return bytes_fromhex(args, heap, interns),
+        (Type::Str, m) if m == StaticStrings::Maketrans => return str_maketrans(args, heap, interns),
         _ => {}
     }
     // Other types or unknown methods - report actual type name, not 'type'
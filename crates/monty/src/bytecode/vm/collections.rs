@@ -2,13 +2,13 @@
 
 use smallvec::SmallVec;
 
-use super::VM;
+use super::{VM, generator::VmIter};
 use crate::{
     exception_private::{ExcType, RunError, SimpleException},
-    heap::HeapData,
+    heap::{DropWithHeap, HeapData},
     intern::StringId,
     io::PrintWriter,
-    resource::ResourceTracker,
+    resource::{ResourceTracker, check_repeat_size},
     types::{Dict, List, PyTrait, Set, Slice, Type, allocate_tuple, slice::value_to_option_i64, str::allocate_char},
     value::Value,
 };
@@ -160,7 +160,15 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             self.heap.mark_potential_cycle();
         }
 
+        let grown = match &list_ref {
+            Value::Ref(id) => self.heap.update_size(*id),
+            _ => Ok(()),
+        };
         iterable.drop_with_heap(self.heap);
+        if let Err(err) = grown {
+            list_ref.drop_with_heap(self.heap);
+            return Err(err.into());
+        }
         self.push(list_ref);
         Ok(())
     }
@@ -297,7 +305,12 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             }
         }
 
+        let grown = self.heap.update_size(dict_id);
         mapping.drop_with_heap(self.heap);
+        if let Err(err) = grown {
+            dict_ref.drop_with_heap(self.heap);
+            return Err(err.into());
+        }
         self.push(dict_ref);
         Ok(())
     }
@@ -331,7 +344,9 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                 value.drop_with_heap(heap);
                 Err(RunError::internal("ListAppend: expected list on heap"))
             }
-        })
+        })?;
+        self.heap.update_size(list_id)?;
+        Ok(())
     }
 
     /// Adds TOS to set for comprehension.
@@ -359,6 +374,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                 Err(RunError::internal("SetAdd: expected set on heap"))
             }
         })?;
+        self.heap.update_size(set_id)?;
 
         Ok(())
     }
@@ -396,6 +412,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         if let Some(old) = old_value {
             old.drop_with_heap(self.heap);
         }
+        self.heap.update_size(dict_id)?;
 
         Ok(())
     }
@@ -471,17 +488,13 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                     }
                     return Ok(());
                 }
-                other => {
-                    let type_name = other.py_type(self.heap);
-                    value.drop_with_heap(self.heap);
-                    return Err(unpack_type_error(type_name));
+                _ => {
+                    return self.unpack_iterable(value, count);
                 }
             },
-            // Non-iterable types
+            // Other iterables (bytes) and non-iterable types
             _ => {
-                let type_name = value.py_type(self.heap);
-                value.drop_with_heap(self.heap);
-                return Err(unpack_type_error(type_name));
+                return self.unpack_iterable(value, count);
             }
         };
 
@@ -505,6 +518,38 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         Ok(())
     }
 
+    /// Unpacks any other iterable (ranges, dicts, sets, bytes, iterators, generators) into n values on
+    /// the stack.
+    ///
+    /// Takes at most one item more than `count`, so an endless iterator still fails. Like
+    /// CPython, the "too many values" error then can't say how many values there were.
+    fn unpack_iterable(&mut self, value: Value, count: usize) -> Result<(), RunError> {
+        let mut iter = self.unpack_iter(value)?;
+        let mut items: Vec<Value> = Vec::with_capacity(count);
+        let result = loop {
+            match self.vm_iter_next(&mut iter) {
+                Ok(Some(item)) if items.len() == count => {
+                    item.drop_with_heap(self.heap);
+                    break Err(unpack_too_many_error(count));
+                }
+                Ok(Some(item)) => items.push(item),
+                Ok(None) if items.len() < count => break Err(unpack_size_error(count, items.len())),
+                Ok(None) => break Ok(()),
+                Err(err) => break Err(err),
+            }
+        };
+        iter.drop_with_heap(self.heap);
+        if let Err(err) = result {
+            items.drop_with_heap(self.heap);
+            return Err(err);
+        }
+        // Push items in reverse order so first item is on top
+        for item in items.into_iter().rev() {
+            self.push(item);
+        }
+        Ok(())
+    }
+
     /// Unpacks a sequence with a starred target.
     ///
     /// `before` is the number of targets before the star, `after` is the number after.
@@ -567,17 +612,9 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                     self.push_unpack_ex_results(&items, before, after)?;
                     return Ok(());
                 }
-                other => {
-                    let type_name = other.py_type(self.heap);
-                    value.drop_with_heap(self.heap);
-                    return Err(unpack_type_error(type_name));
-                }
+                _ => return self.unpack_ex_iterable(value, before, after),
             },
-            _ => {
-                let type_name = value.py_type(self.heap);
-                value.drop_with_heap(self.heap);
-                return Err(unpack_type_error(type_name));
-            }
+            _ => return self.unpack_ex_iterable(value, before, after),
         };
 
         // Increment refcounts BEFORE dropping the container.
@@ -601,6 +638,45 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         Ok(())
     }
 
+    /// Unpacks any other iterable with a starred target, like [`Self::unpack_iterable`].
+    ///
+    /// The starred target takes all remaining items, so they are collected with a check
+    /// against the memory limit as they go.
+    fn unpack_ex_iterable(&mut self, value: Value, before: usize, after: usize) -> Result<(), RunError> {
+        let mut iter = self.unpack_iter(value)?;
+        let mut items: Vec<Value> = Vec::with_capacity(before + after);
+        let result = loop {
+            if let Err(err) = check_repeat_size(items.len() + 1, std::mem::size_of::<Value>(), self.heap.tracker()) {
+                break Err(err.into());
+            }
+            match self.vm_iter_next(&mut iter) {
+                Ok(Some(item)) => items.push(item),
+                Ok(None) => break Ok(()),
+                Err(err) => break Err(err),
+            }
+        };
+        iter.drop_with_heap(self.heap);
+        if let Err(err) = result {
+            items.drop_with_heap(self.heap);
+            return Err(err);
+        }
+        if items.len() < before + after {
+            let len = items.len();
+            items.drop_with_heap(self.heap);
+            return Err(unpack_ex_too_few_error(before + after, len));
+        }
+        // Collected items are already owned, so they are pushed without further refcount changes
+        self.push_unpack_ex_results(&items, before, after)
+    }
+
+    /// Creates an iterator over an iterable that unpacking has no fast path for.
+    ///
+    /// Raises the unpacking TypeError rather than the usual "not iterable" one for non-iterables.
+    fn unpack_iter(&mut self, value: Value) -> Result<VmIter, RunError> {
+        let type_name = value.py_type(self.heap);
+        self.vm_iter(value).map_err(|_| unpack_type_error(type_name))
+    }
+
     /// Helper to push unpacked items with starred target onto the stack.
     ///
     /// Takes a slice of items and creates the middle list.
@@ -662,6 +738,14 @@ fn unpack_size_error(expected: usize, actual: usize) -> RunError {
     SimpleException::new_msg(ExcType::ValueError, message).into()
 }
 
+/// Creates the ValueError for an iterable with more values than targets.
+///
+/// The values are only counted for sequences, see [`unpack_size_error`].
+fn unpack_too_many_error(expected: usize) -> RunError {
+    let message = format!("too many values to unpack (expected {expected})");
+    SimpleException::new_msg(ExcType::ValueError, message).into()
+}
+
 /// Creates a TypeError for attempting to unpack a non-iterable type.
 fn unpack_type_error(type_name: Type) -> RunError {
     SimpleException::new_msg(
@@ -1,8 +1,11 @@
 //! Comparison operation helpers for the VM.
 
+use std::cmp::Ordering;
+
 use super::VM;
 use crate::{
     exception_private::{ExcType, RunError},
+    heap::HeapData,
     io::PrintWriter,
     resource::{DepthGuard, ResourceTracker},
     types::{LongInt, PyTrait},
@@ -35,7 +38,10 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     }
 
     /// Ordering comparison with a predicate.
-    pub(super) fn compare_ord<F>(&mut self, check: F) -> Result<(), RunError>
+    ///
+    /// Values that can't be ordered raise `TypeError`, naming the operator `op` and the types
+    /// that couldn't be compared, except for NaN, which compares false with everything.
+    pub(super) fn compare_ord<F>(&mut self, op: &str, check: F) -> Result<(), RunError>
     where
         F: FnOnce(std::cmp::Ordering) -> bool,
     {
@@ -43,11 +49,11 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         let lhs = self.pop();
         let mut guard = DepthGuard::default();
         let result = lhs
-            .py_cmp(&rhs, self.heap, &mut guard, self.interns)?
-            .is_some_and(check);
+            .py_cmp_for_op(&rhs, op, self.heap, &mut guard, self.interns)
+            .map(|ordering| ordering.is_some_and(check));
         lhs.drop_with_heap(self.heap);
         rhs.drop_with_heap(self.heap);
-        self.push(Value::Bool(result));
+        self.push(Value::Bool(result?));
         Ok(())
     }
 
@@ -76,7 +82,13 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         let container = self.pop(); // container (rhs)
         let item = self.pop(); // item to find (lhs)
 
-        let result = container.py_contains(&item, self.heap, self.interns);
+        let result = match &container {
+            // Generators run their frames to produce items, which needs the VM
+            Value::Ref(id) if matches!(self.heap.get(*id), HeapData::Generator(_)) => {
+                self.generator_contains(*id, &item)
+            }
+            _ => container.py_contains(&item, self.heap, self.interns),
+        };
 
         item.drop_with_heap(self.heap);
         container.drop_with_heap(self.heap);
@@ -86,6 +98,37 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         Ok(())
     }
 
+    /// Length comparison: a < len(b)
+    ///
+    /// This is an optimization for loop conditions like `i < len(items)`. Ints compare
+    /// directly against the length; other left-hand sides fall back to an ordinary `<`
+    /// comparison with the length as an int, so errors match the unfused form.
+    pub(super) fn compare_lt_len(&mut self) -> Result<(), RunError> {
+        let container = self.pop(); // len() argument (b)
+        let len = container.py_len(self.heap, self.interns);
+        let Some(len) = len else {
+            let type_name = container.py_type(self.heap);
+            container.drop_with_heap(self.heap);
+            let lhs = self.pop();
+            lhs.drop_with_heap(self.heap);
+            return Err(ExcType::type_error(format!(
+                "object of type '{type_name}' has no len()"
+            )));
+        };
+        container.drop_with_heap(self.heap);
+        let len = i64::try_from(len).expect("len exceeds i64::MAX");
+
+        let lhs = self.pop();
+        if let Value::Int(i) = lhs {
+            self.push(Value::Bool(i < len));
+            Ok(())
+        } else {
+            self.push(lhs);
+            self.push(Value::Int(len));
+            self.compare_ord("<", Ordering::is_lt)
+        }
+    }
+
     /// Modulo equality comparison: a % b == k
     ///
     /// This is an optimization for patterns like `x % 3 == 0`. The constant k
@@ -113,7 +156,11 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         } else {
             // Fallback: compute py_mod then compare with py_eq
             // This handles LongInt and other Ref types
-            let mod_value = lhs.py_mod(&rhs, self.heap);
+            let mod_value = match lhs.py_mod(&rhs, self.heap) {
+                Ok(None) => self.percent_format(&lhs, &rhs),
+                other => other,
+            };
+            let operand_types = (lhs.py_type(self.heap), rhs.py_type(self.heap));
             lhs.drop_with_heap(self.heap);
             rhs.drop_with_heap(self.heap);
 
@@ -136,7 +183,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                     self.push(Value::Bool(is_equal));
                     Ok(())
                 }
-                Ok(None) => Err(ExcType::type_error("unsupported operand type(s) for %")),
+                Ok(None) => Err(ExcType::binary_type_error("%", operand_types.0, operand_types.1)),
                 Err(e) => Err(e),
             }
         }
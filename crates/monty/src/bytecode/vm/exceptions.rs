@@ -68,31 +68,52 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     /// Takes ownership of the exception value and drops it properly.
     /// The `is_raise` flag indicates if this is from a `raise` statement (hide caret).
     pub(super) fn make_exception(&mut self, exc_value: Value, is_raise: bool) -> RunError {
-        let simple_exc = match &exc_value {
-            // Exception instance on heap
-            Value::Ref(heap_id) => {
-                if let HeapData::Exception(exc) = self.heap.get(*heap_id) {
-                    // Clone the exception
-                    let exc_clone = exc.clone();
-                    // Drop the value with proper heap cleanup
-                    exc_value.drop_with_heap(self.heap);
-                    exc_clone
-                } else {
-                    // Not an exception type
-                    exc_value.drop_with_heap(self.heap);
-                    SimpleException::new_msg(ExcType::TypeError, "exceptions must derive from BaseException")
-                }
+        let simple_exc = self.take_exception(exc_value).unwrap_or_else(not_an_exception);
+        self.raise_error(simple_exc, is_raise)
+    }
+
+    /// Creates a RunError for `raise exc from cause`.
+    ///
+    /// The cause may be an exception, an exception type (which is instantiated with no message),
+    /// or `None`, which only hides the exception's context.
+    pub(super) fn make_exception_from(&mut self, exc_value: Value, cause_value: Value) -> RunError {
+        let cause = match cause_value {
+            Value::None => Some(None),
+            cause_value => self.take_exception(cause_value).map(Some),
+        };
+        let simple_exc = match (self.take_exception(exc_value), cause) {
+            (Some(mut exc), Some(cause)) => {
+                exc.set_cause(cause);
+                exc
             }
-            // Exception type (e.g., `raise ValueError` instead of `raise ValueError()`)
-            // Instantiate with no message
-            Value::Builtin(Builtins::ExcType(exc_type)) => SimpleException::new_none(*exc_type),
-            // Invalid exception value
-            _ => {
-                exc_value.drop_with_heap(self.heap);
-                SimpleException::new_msg(ExcType::TypeError, "exceptions must derive from BaseException")
+            (Some(_), None) => {
+                SimpleException::new_msg(ExcType::TypeError, "exception causes must derive from BaseException")
             }
+            (None, _) => not_an_exception(),
         };
+        self.raise_error(simple_exc, true)
+    }
 
+    /// Takes the exception out of a raised value: an exception instance, or an exception type
+    /// (e.g., `raise ValueError` instead of `raise ValueError()`) instantiated with no message.
+    ///
+    /// Takes ownership of the value and drops it properly. Returns `None` if the value isn't
+    /// an exception.
+    fn take_exception(&mut self, exc_value: Value) -> Option<SimpleException> {
+        let simple_exc = match &exc_value {
+            Value::Ref(heap_id) => match self.heap.get(*heap_id) {
+                HeapData::Exception(exc) => Some(exc.clone()),
+                _ => None,
+            },
+            Value::Builtin(Builtins::ExcType(exc_type)) => Some(SimpleException::new_none(*exc_type)),
+            _ => None,
+        };
+        exc_value.drop_with_heap(self.heap);
+        simple_exc
+    }
+
+    /// Wraps an exception raised at the current execution point in a RunError.
+    fn raise_error(&self, simple_exc: SimpleException, is_raise: bool) -> RunError {
         // Create frame with appropriate hide_caret setting
         let frame = if is_raise {
             RawStackFrame::from_raise(self.current_position(), self.current_frame_name())
@@ -107,6 +128,34 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         })
     }
 
+    /// Sets the exception being handled, if any, as the implicit `__context__` of a newly
+    /// raised exception.
+    ///
+    /// An except block (or a finally block with an exception pending) keeps its exception on
+    /// the operand stack while it runs, so the top of `exception_stack` only counts if it's
+    /// still there: an exception escaping an except block leaves its entry behind without
+    /// running `ClearException`.
+    ///
+    /// Raising the handled exception itself (`except E as e: raise e`) leaves its context
+    /// unchanged. Exceptions are copied when caught, so this compares by value; the copy
+    /// includes the traceback recorded when it was caught, which distinguishes it from a new
+    /// exception with the same message.
+    fn set_exception_context(&self, exc: &mut SimpleException) {
+        let Some(Value::Ref(handled_id)) = self.exception_stack.last() else {
+            return;
+        };
+        let being_handled = self
+            .stack
+            .iter()
+            .any(|value| matches!(value, Value::Ref(id) if id == handled_id));
+        if being_handled
+            && let HeapData::Exception(handled) = self.heap.get(*handled_id)
+            && *handled != *exc
+        {
+            exc.set_context(handled.clone());
+        }
+    }
+
     /// Handles an exception by searching for a handler in the exception table.
     ///
     /// Returns:
@@ -128,18 +177,10 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             return Some(self.unwind_for_traceback(error));
         }
 
-        // Only catchable exceptions can be handled
-        let exc_info = match &error {
-            RunError::Exc(exc) => exc.clone(),
-            RunError::UncatchableExc(_) | RunError::Internal(_) => unreachable!(),
-        };
-
-        // Create exception value to push on stack
-        let exc_value = self.create_exception_value(&exc_info);
-        let exc_value = match exc_value {
-            Ok(v) => v,
-            Err(e) => return Some(e),
-        };
+        // Chain the exception to the one being handled, if any
+        if let RunError::Exc(exc) = &mut error {
+            self.set_exception_context(&mut exc.exc);
+        }
 
         // Search for handler in current and outer frames
         loop {
@@ -152,11 +193,21 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                 let handler_offset = usize::try_from(entry.handler()).expect("handler offset exceeds usize");
                 let target_stack_depth = frame.stack_base + entry.stack_depth() as usize;
 
+                // Create exception value to push on stack
+                let RunError::Exc(exc) = &error else {
+                    unreachable!("only catchable exceptions are handled")
+                };
+                let exc_value = match self.create_exception_value(exc) {
+                    Ok(v) => v,
+                    Err(e) => return Some(e),
+                };
+
                 // Unwind stack to target depth (drop excess values)
                 while self.stack.len() > target_stack_depth {
                     let value = self.stack.pop().unwrap();
                     value.drop_with_heap(self.heap);
                 }
+                self.drop_unwound_exceptions();
 
                 // Push exception value onto stack (handler expects it)
                 let exc_for_stack = exc_value.clone_with_heap(self.heap);
@@ -175,8 +226,6 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             // No handler in this frame - pop frame and try outer
             if self.frames.len() <= 1 {
                 // No more frames - exception is unhandled
-                exc_value.drop_with_heap(self.heap);
-
                 // For spawned tasks, fail the task instead of propagating
                 if self.is_spawned_task() {
                     match self.handle_task_failure(error) {
@@ -197,6 +246,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
             // Get the call site position before popping frame
             // This is where the caller invoked the function that's failing
             let call_position = self.current_frame().call_position;
+            let leaving_callback = self.frames.len() == self.callback_frames;
 
             // Pop this frame
             self.pop_frame();
@@ -211,11 +261,14 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                 }
             }
 
-            // Update instruction_ip for the new frame
-            self.instruction_ip = self
-                .current_frame()
-                .call_position
-                .map_or(0, |p| p.start().line as usize);
+            // A function called by a builtin like `sorted()` raises into the builtin, not the caller's frame
+            if leaving_callback {
+                return Some(error);
+            }
+
+            // Look for a handler around the instruction the caller is executing: its IP was
+            // synced past that instruction (a call or a `for` loop advancing a generator)
+            self.instruction_ip = self.current_frame().ip - 1;
         }
     }
 
@@ -228,6 +281,7 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
         while self.frames.len() > 1 {
             // Get the call site position before popping frame
             let call_position = self.current_frame().call_position;
+            let leaving_callback = self.frames.len() == self.callback_frames;
 
             // Pop this frame (cleans up namespace, etc.)
             self.pop_frame();
@@ -241,15 +295,42 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
                     RunError::Internal(_) => {}
                 }
             }
+
+            // Stop at a builtin like `sorted()`, which propagates the error to its own caller
+            if leaving_callback {
+                break;
+            }
         }
         error
     }
 
+    /// Drops the `exception_stack` entries of except blocks (and finally blocks with an
+    /// exception pending) that the stack was just unwound out of.
+    ///
+    /// Such blocks keep their exception on the operand stack while they run, so an entry is
+    /// stale once its exception is no longer there. Without this, a later bare `raise` would
+    /// re-raise an exception whose handler was left by another exception, and a finally block
+    /// would re-raise it instead of its own pending exception.
+    fn drop_unwound_exceptions(&mut self) {
+        let entries = std::mem::take(&mut self.exception_stack);
+        for exc in entries {
+            let on_stack = matches!(&exc, Value::Ref(exc_id)
+                if self.stack.iter().any(|value| matches!(value, Value::Ref(id) if id == exc_id)));
+            if on_stack {
+                self.exception_stack.push(exc);
+            } else {
+                exc.drop_with_heap(self.heap);
+            }
+        }
+    }
+
     /// Creates an exception Value from exception info.
     ///
-    /// Allocates an Exception on the heap and returns a Value::Ref to it.
+    /// Allocates an Exception on the heap and returns a Value::Ref to it. The traceback so far
+    /// is kept with the exception in case it becomes the cause or context of another one.
     fn create_exception_value(&mut self, exc: &ExceptionRaise) -> Result<Value, RunError> {
-        let exception = exc.exc.clone();
+        let mut exception = exc.exc.clone();
+        exception.set_traceback(exc.frame.clone());
         let heap_id = self.heap.allocate(HeapData::Exception(exception))?;
         Ok(Value::Ref(heap_id))
     }
@@ -258,35 +339,38 @@ impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
     ///
     /// Validates that `exc_type` is a valid exception type (ExcType or tuple of ExcTypes).
     /// Returns `Ok(true)` if exception matches, `Ok(false)` if not, or `Err` if exc_type is invalid.
+    ///
+    /// As in CPython, every item of a tuple is validated before any is matched, so an invalid
+    /// item is an error even when an earlier item matches. Nested tuples are not allowed.
     pub(super) fn check_exc_match(&self, exception: &Value, exc_type: &Value) -> Result<bool, RunError> {
         let exc_type_enum = exception.py_type(self.heap);
-        self.check_exc_match_inner(exc_type_enum, exc_type)
-    }
-
-    /// Inner recursive helper for check_exc_match that handles tuples.
-    fn check_exc_match_inner(&self, exc_type_enum: Type, exc_type: &Value) -> Result<bool, RunError> {
+        let is_match =
+            |handler_type: ExcType| matches!(exc_type_enum, Type::Exception(et) if et.is_subclass_of(handler_type));
         match exc_type {
             // Valid exception type
-            Value::Builtin(Builtins::ExcType(handler_type)) => {
-                // Check if exception is an instance of handler_type
-                Ok(matches!(exc_type_enum, Type::Exception(et) if et.is_subclass_of(*handler_type)))
-            }
+            Value::Builtin(Builtins::ExcType(handler_type)) => Ok(is_match(*handler_type)),
             // Tuple of exception types
             Value::Ref(id) => {
-                if let HeapData::Tuple(tuple) = self.heap.get(*id) {
-                    for v in tuple.as_slice() {
-                        if self.check_exc_match_inner(exc_type_enum, v)? {
-                            return Ok(true);
-                        }
-                    }
-                    Ok(false)
-                } else {
+                let HeapData::Tuple(tuple) = self.heap.get(*id) else {
                     // Not a tuple - invalid exception type
-                    Err(ExcType::except_invalid_type_error())
+                    return Err(ExcType::except_invalid_type_error());
+                };
+                let mut matched = false;
+                for v in tuple.as_slice() {
+                    let Value::Builtin(Builtins::ExcType(handler_type)) = v else {
+                        return Err(ExcType::except_invalid_type_error());
+                    };
+                    matched |= is_match(*handler_type);
                 }
+                Ok(matched)
             }
             // Any other type is invalid for except clause
             _ => Err(ExcType::except_invalid_type_error()),
         }
     }
 }
+
+/// The error for raising a value that isn't an exception.
+fn not_an_exception() -> SimpleException {
+    SimpleException::new_msg(ExcType::TypeError, "exceptions must derive from BaseException")
+}
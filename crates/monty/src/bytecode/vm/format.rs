@@ -11,6 +11,23 @@ use crate::{
 };
 
 impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
+    /// Echoes the value of a REPL expression statement.
+    ///
+    /// Stack: [value] -> []
+    /// Writes `repr(value)` and a newline to stdout unless the value is `None`.
+    pub(super) fn print_expr(&mut self) -> Result<(), RunError> {
+        let value = self.pop();
+        if !matches!(value, Value::None) {
+            let mut guard = DepthGuard::default();
+            let repr = value.py_repr(self.heap, &mut guard, self.interns);
+            value.drop_with_heap(self.heap);
+            self.print_writer.stdout_write(repr)?;
+            self.print_writer.stdout_push('\n')?;
+            self.print_writer.print_end()?;
+        }
+        Ok(())
+    }
+
     /// Builds an f-string by concatenating n string parts from the stack.
     pub(super) fn build_fstring(&mut self, count: usize) -> Result<(), RunError> {
         let parts = self.pop_n(count);
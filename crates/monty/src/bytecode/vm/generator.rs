@@ -0,0 +1,323 @@
+//! Running the frames of generator expressions.
+//!
+//! A generator's frame is pushed each time it's advanced and popped again at its next
+//! `yield`, with its namespace and operand stack moved back into the heap `Generator`.
+//!
+//! A `for` loop advances a generator from the run loop: the frame yields straight onto the
+//! loop's stack, and when the frame returns the loop jumps to its exit. Builtins like
+//! `sum()` and `any()` instead advance it through [`VM::generator_next`], which runs the
+//! frame as a callback until it yields, so like other callbacks it can't call external
+//! functions. Either way each item is produced only when it's consumed.
+//!
+//! Builtins and methods implemented with only heap access, like `enumerate()` and
+//! `str.join()`, can't run a frame, so generators passed to them are drained into lists first.
+
+use super::{CallFrame, VM};
+use crate::{
+    args::ArgValues,
+    builtins::BuiltinsFunctions,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{StaticStrings, StringId},
+    io::PrintWriter,
+    modules::{ModuleFunctions, heapq::HeapqFunctions},
+    resource::{DepthGuard, ResourceTracker, check_repeat_size},
+    types::{List, MontyIter, PyTrait, Type, generator::GeneratorState},
+    value::Value,
+};
+
+/// Marks a call frame as a generator's, saying how the generator is being advanced.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub(crate) struct GeneratorFrame {
+    /// The generator whose frame this is.
+    pub id: HeapId,
+    /// Where the `for` loop advancing the generator continues once it's exhausted, or
+    /// `None` if a builtin is advancing it.
+    pub loop_exit: Option<usize>,
+}
+
+/// An iterator advanced by the VM, which can run generators as well as a `MontyIter`.
+pub(super) enum VmIter {
+    Iter(MontyIter),
+    /// A generator, owning a reference to it.
+    Generator(HeapId),
+}
+
+impl<T: ResourceTracker> DropWithHeap<T> for VmIter {
+    fn drop_with_heap(self, heap: &mut Heap<T>) {
+        match self {
+            Self::Iter(iter) => iter.drop_with_heap(heap),
+            Self::Generator(id) => Value::Ref(id).drop_with_heap(heap),
+        }
+    }
+}
+
+impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
+    /// Pushes the frame of generator `id` to run it until it next yields.
+    ///
+    /// `loop_exit` is recorded in the frame, see [`GeneratorFrame`]. Returns `false` without
+    /// pushing a frame if the generator has already finished.
+    pub(super) fn resume_generator(&mut self, id: HeapId, loop_exit: Option<usize>) -> RunResult<bool> {
+        let HeapData::Generator(generator) = self.heap.get_mut(id) else {
+            return Err(RunError::internal("resume_generator: expected a generator"));
+        };
+        let resuming = match generator.state {
+            GeneratorState::New => false,
+            GeneratorState::Suspended => true,
+            GeneratorState::Running => {
+                return Err(SimpleException::new_msg(ExcType::ValueError, "generator already executing").into());
+            }
+            GeneratorState::Finished => return Ok(false),
+        };
+        generator.state = GeneratorState::Running;
+        let func_id = generator.func_id;
+        let ip = generator.ip;
+        let cells = generator.frame_cells.clone();
+        let namespace = std::mem::take(&mut generator.namespace);
+        let stack = std::mem::take(&mut generator.stack);
+
+        let call_position = self.current_position();
+        let namespace_idx = match self.namespaces.register_prebuilt(namespace, self.heap) {
+            Ok(namespace_idx) => namespace_idx,
+            Err(err) => {
+                // The namespace has been dropped, so the generator can't run again
+                if let HeapData::Generator(generator) = self.heap.get_mut(id) {
+                    generator.state = GeneratorState::Finished;
+                }
+                stack.drop_with_heap(self.heap);
+                return Err(err.into());
+            }
+        };
+        // The namespace and stack are accounted for as the frame's while it runs, so this only shrinks
+        self.heap.update_size(id)?;
+
+        let stack_base = self.stack.len();
+        self.stack.extend(stack);
+        if resuming {
+            // The value of the `yield` expression the frame is paused at
+            self.push(Value::None);
+        }
+        let code = &self.interns.get_function(func_id).code;
+        let mut frame = CallFrame::new_function(code, stack_base, namespace_idx, func_id, cells, Some(call_position));
+        frame.ip = ip;
+        frame.generator = Some(GeneratorFrame { id, loop_exit });
+        self.frames.push(frame);
+        Ok(true)
+    }
+
+    /// Pops the current generator frame at a `yield`, saving its state in the generator.
+    ///
+    /// `ip` is where the frame resumes. The saved state is charged to the generator's size.
+    pub(super) fn suspend_generator(&mut self, ip: usize) -> (GeneratorFrame, RunResult<()>) {
+        let frame = self.frames.pop().expect("no frame to suspend");
+        let generator_frame = frame.generator.expect("yield outside a generator frame");
+        let stack = self.stack.split_off(frame.stack_base);
+        let namespace = self.namespaces.take(frame.namespace_idx, self.heap);
+
+        let HeapData::Generator(generator) = self.heap.get_mut(generator_frame.id) else {
+            unreachable!("generator frame without a generator")
+        };
+        generator.namespace = namespace;
+        generator.stack = stack;
+        generator.ip = ip;
+        generator.state = GeneratorState::Suspended;
+        let charged = self.heap.update_size(generator_frame.id).map_err(RunError::from);
+        (generator_frame, charged)
+    }
+
+    /// Advances generator `id` for a builtin, returning its next item or `None` once it's exhausted.
+    pub(super) fn generator_next(&mut self, id: HeapId) -> RunResult<Option<Value>> {
+        if !self.resume_generator(id, None)? {
+            return Ok(None);
+        }
+        let value = self.run_callback_frame()?;
+        // Yielding leaves the generator suspended, while returning finishes it
+        if matches!(self.heap.get(id), HeapData::Generator(generator) if generator.state == GeneratorState::Finished) {
+            value.drop_with_heap(self.heap);
+            return Ok(None);
+        }
+        Ok(Some(value))
+    }
+
+    /// Advances generator `id` until it yields an item equal to `item`, for `in`.
+    pub(super) fn generator_contains(&mut self, id: HeapId, item: &Value) -> RunResult<bool> {
+        let mut guard = DepthGuard::default();
+        while let Some(el) = self.generator_next(id)? {
+            let eq = item.py_eq(&el, self.heap, &mut guard, self.interns);
+            el.drop_with_heap(self.heap);
+            if eq? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Runs generator `id` to completion, collecting its items.
+    ///
+    /// The items are checked against the memory limit as they're collected.
+    pub(super) fn collect_generator(&mut self, id: HeapId) -> RunResult<Vec<Value>> {
+        let mut items = Vec::new();
+        let result = loop {
+            if let Err(err) = check_repeat_size(items.len() + 1, std::mem::size_of::<Value>(), self.heap.tracker()) {
+                break Err(err.into());
+            }
+            match self.generator_next(id) {
+                Ok(Some(item)) => items.push(item),
+                Ok(None) => break Ok(()),
+                Err(err) => break Err(err),
+            }
+        };
+        match result {
+            Ok(()) => Ok(items),
+            Err(err) => {
+                items.drop_with_heap(self.heap);
+                Err(err)
+            }
+        }
+    }
+
+    /// Creates an iterator over `iterable` that the VM advances with [`vm_iter_next`](Self::vm_iter_next).
+    pub(super) fn vm_iter(&mut self, iterable: Value) -> RunResult<VmIter> {
+        if let Value::Ref(id) = iterable
+            && matches!(self.heap.get(id), HeapData::Generator(_))
+        {
+            return Ok(VmIter::Generator(id));
+        }
+        MontyIter::new(iterable, self.heap, self.interns).map(VmIter::Iter)
+    }
+
+    /// Returns the next item of `iter`, or `None` once it's exhausted.
+    pub(super) fn vm_iter_next(&mut self, iter: &mut VmIter) -> RunResult<Option<Value>> {
+        match iter {
+            VmIter::Iter(iter) => iter.for_next(self.heap, self.interns),
+            VmIter::Generator(id) => self.generator_next(*id),
+        }
+    }
+
+    /// Returns whether any positional argument in `args` is a generator.
+    pub(super) fn has_generator_arg(&self, args: &ArgValues) -> bool {
+        let is_generator =
+            |value: &Value| matches!(value, Value::Ref(id) if matches!(self.heap.get(*id), HeapData::Generator(_)));
+        match args {
+            ArgValues::One(value) => is_generator(value),
+            ArgValues::Two(first, second) => is_generator(first) || is_generator(second),
+            ArgValues::ArgsKargs { args, .. } => args.iter().any(is_generator),
+            ArgValues::Empty | ArgValues::Kwargs(_) => false,
+        }
+    }
+
+    /// Replaces each generator among the positional arguments with a list of its items.
+    pub(super) fn drain_generator_args(&mut self, args: ArgValues) -> RunResult<ArgValues> {
+        match args {
+            ArgValues::One(value) => self.drain_generator(value).map(ArgValues::One),
+            ArgValues::Two(first, second) => {
+                let first = match self.drain_generator(first) {
+                    Ok(first) => first,
+                    Err(err) => {
+                        second.drop_with_heap(self.heap);
+                        return Err(err);
+                    }
+                };
+                match self.drain_generator(second) {
+                    Ok(second) => Ok(ArgValues::Two(first, second)),
+                    Err(err) => {
+                        first.drop_with_heap(self.heap);
+                        Err(err)
+                    }
+                }
+            }
+            ArgValues::ArgsKargs { args, kwargs } => {
+                let mut drained = Vec::with_capacity(args.len());
+                let mut result = Ok(());
+                let mut args = args.into_iter();
+                for arg in args.by_ref() {
+                    match self.drain_generator(arg) {
+                        Ok(arg) => drained.push(arg),
+                        Err(err) => {
+                            result = Err(err);
+                            break;
+                        }
+                    }
+                }
+                if let Err(err) = result {
+                    drained.drop_with_heap(self.heap);
+                    args.collect::<Vec<_>>().drop_with_heap(self.heap);
+                    kwargs.drop_with_heap(self.heap);
+                    return Err(err);
+                }
+                Ok(ArgValues::ArgsKargs { args: drained, kwargs })
+            }
+            args @ (ArgValues::Empty | ArgValues::Kwargs(_)) => Ok(args),
+        }
+    }
+
+    /// Runs `value` to completion and returns a list of its items if it's a generator,
+    /// and returns any other value unchanged.
+    fn drain_generator(&mut self, value: Value) -> RunResult<Value> {
+        let Value::Ref(id) = value else {
+            return Ok(value);
+        };
+        if !matches!(self.heap.get(id), HeapData::Generator(_)) {
+            return Ok(value);
+        }
+        let items = self.collect_generator(id);
+        value.drop_with_heap(self.heap);
+        let list = List::new(items?);
+        Ok(Value::Ref(self.heap.allocate(HeapData::List(list))?))
+    }
+}
+
+/// Returns whether the builtin function `builtin` iterates over its arguments with only heap access.
+pub(super) fn builtin_drains_generators(builtin: BuiltinsFunctions) -> bool {
+    matches!(
+        builtin,
+        BuiltinsFunctions::Enumerate | BuiltinsFunctions::Reversed | BuiltinsFunctions::Zip
+    )
+}
+
+/// Returns whether calling the type `t` iterates over its arguments with only heap access.
+pub(super) fn type_drains_generators(t: Type) -> bool {
+    matches!(
+        t,
+        Type::List | Type::Tuple | Type::Dict | Type::Set | Type::FrozenSet | Type::Bytes | Type::ByteArray
+    )
+}
+
+/// Returns whether the module function `function` iterates over its arguments with only heap access.
+pub(super) fn module_function_drains_generators(function: ModuleFunctions) -> bool {
+    matches!(
+        function,
+        ModuleFunctions::Statistics(_) | ModuleFunctions::Heapq(HeapqFunctions::Nlargest | HeapqFunctions::Nsmallest)
+    )
+}
+
+/// Returns whether the method or module function named `name_id` iterates over its arguments
+/// with only heap access.
+///
+/// Methods like `list.append()` that take a generator as an ordinary object aren't listed.
+pub(super) fn method_drains_generators(name_id: StringId) -> bool {
+    matches!(
+        StaticStrings::from_string_id(name_id),
+        Some(
+            StaticStrings::Join
+                | StaticStrings::Extend
+                | StaticStrings::Update
+                | StaticStrings::Union
+                | StaticStrings::Intersection
+                | StaticStrings::Difference
+                | StaticStrings::SymmetricDifference
+                | StaticStrings::Issubset
+                | StaticStrings::Issuperset
+                | StaticStrings::Isdisjoint
+                | StaticStrings::Fromkeys
+                | StaticStrings::Mean
+                | StaticStrings::Median
+                | StaticStrings::Mode
+                | StaticStrings::Pstdev
+                | StaticStrings::Stdev
+                | StaticStrings::Variance
+                | StaticStrings::Nlargest
+                | StaticStrings::Nsmallest
+        )
+    )
+}
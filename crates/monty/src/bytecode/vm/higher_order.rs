@@ -0,0 +1,488 @@
+//! Builtins that call back into Python code.
+//!
+//! `sorted()`, `min()`, `max()`, `filter()`, `map()` and `list.sort()` take a function that
+//! may be defined in Python. Rather than living in `builtins` with only heap access, they
+//! are implemented on the VM and call the function through [`VM::call_value`].
+//!
+//! Those builtins and `sum()`, `any()`, `all()` and `next()` also advance generators, which
+//! run Python code too, so they iterate with [`VM::vm_iter_next`] and only take the items
+//! they need.
+//!
+//! Unlike CPython, `filter()` and `map()` aren't lazy: they call the function for every item
+//! when they're called, and return an iterator over the results. So the function's side
+//! effects all happen up front, and an infinite iterable never finishes.
+//!
+//! While a callback runs, the builtin holds its working values in Rust where the garbage
+//! collector can't see them, so the VM postpones collection until all callbacks finish.
+
+use std::cmp::Ordering;
+
+use super::{VM, generator::VmIter};
+use crate::{
+    args::{ArgValues, KwargsValues},
+    defer_drop, defer_drop_mut,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, HeapData, HeapGuard, HeapId},
+    io::PrintWriter,
+    resource::{DepthGuard, ResourceTracker},
+    types::{List, MontyIter, PyTrait, Type, iter::iterator_next, list::sort_values},
+    value::Value,
+};
+
+impl<T: ResourceTracker, P: PrintWriter> VM<'_, T, P> {
+    /// Implementation of `sorted(iterable, /, *, key=None, reverse=False)`.
+    ///
+    /// Returns a new sorted list from the items in an iterable.
+    pub(super) fn builtin_sorted(&mut self, args: ArgValues) -> RunResult<Value> {
+        let this = self;
+        let (positional, kwargs) = args.into_parts();
+        defer_drop_mut!(positional, this);
+        let positional_len = positional.len();
+        if positional_len != 1 {
+            kwargs.drop_with_heap(this.heap);
+            return Err(ExcType::type_error(format!(
+                "sorted expected 1 argument, got {positional_len}"
+            )));
+        }
+
+        let (key, reverse) = this.sort_options(kwargs)?;
+        defer_drop!(key, this);
+        let iterable = positional.next().expect("sorted() argument count checked above");
+        let mut items_guard = HeapGuard::new(this.collect_iterable(iterable)?, this);
+        let (items, this) = items_guard.as_parts_mut();
+        this.sort_items(items, key.as_ref(), reverse)?;
+
+        let (items, this) = items_guard.into_parts();
+        let heap_id = this.heap.allocate(HeapData::List(List::new(items)))?;
+        Ok(Value::Ref(heap_id))
+    }
+
+    /// Implementation of `list.sort(*, key=None, reverse=False)`.
+    ///
+    /// The items are moved out of the list while it is sorted, so a key function sees an
+    /// empty list. As in CPython, if the key function adds items, the sorted items are still
+    /// put back but `ValueError` is raised.
+    pub(super) fn sort_list(&mut self, list_id: HeapId, args: ArgValues) -> RunResult<()> {
+        let this = self;
+        let (positional, kwargs) = args.into_parts();
+        defer_drop!(positional, this);
+        if positional.len() > 0 {
+            kwargs.drop_with_heap(this.heap);
+            return Err(ExcType::type_error("sort() takes no positional arguments"));
+        }
+        let (key, reverse) = this.sort_options(kwargs)?;
+        defer_drop!(key, this);
+
+        let HeapData::List(list) = this.heap.get_mut(list_id) else {
+            return Err(RunError::internal("expected list in sort_list"));
+        };
+        let items = std::mem::take(list.as_vec_mut());
+        defer_drop_mut!(items, this);
+
+        let result = this.sort_items(items, key.as_ref(), reverse);
+
+        let HeapData::List(list) = this.heap.get_mut(list_id) else {
+            return Err(RunError::internal("expected list in sort_list"));
+        };
+        let added = std::mem::replace(list.as_vec_mut(), std::mem::take(items));
+        let modified = !added.is_empty();
+        added.drop_with_heap(this.heap);
+
+        if modified && result.is_ok() {
+            return Err(SimpleException::new_msg(ExcType::ValueError, "list modified during sort").into());
+        }
+        result
+    }
+
+    /// Implementation of `min()` and `max()`; `is_min` selects which.
+    ///
+    /// Supports both `min(iterable, *, key=None, default=...)` and `min(arg1, arg2, *args, key=None)`.
+    /// With a `key` function, items are compared by the value it returns. When several items
+    /// are equally small (or large), the first one wins.
+    pub(super) fn builtin_min_max(&mut self, args: ArgValues, is_min: bool) -> RunResult<Value> {
+        let this = self;
+        let func_name = if is_min { "min" } else { "max" };
+        let (positional, kwargs) = args.into_parts();
+        defer_drop_mut!(positional, this);
+
+        let (key, default) = this.min_max_options(func_name, kwargs)?;
+        defer_drop!(key, this);
+        // Only dropped if there's a best item, otherwise it's the result
+        let mut default_guard = HeapGuard::new(default, this);
+        let (default, this) = default_guard.as_parts();
+
+        let best = match positional.len() {
+            0 => return Err(ExcType::type_error_at_least(func_name, 1, 0)),
+            1 => {
+                let iterable = positional.next().expect("min()/max() argument count checked above");
+                let iter = this.vm_iter(iterable)?;
+                defer_drop_mut!(iter, this);
+                this.min_max_of(is_min, key.as_ref(), &mut |vm| vm.vm_iter_next(iter))?
+            }
+            _ if default.is_some() => {
+                return Err(ExcType::type_error(format!(
+                    "Cannot specify a default for {func_name}() with multiple positional arguments"
+                )));
+            }
+            _ => this.min_max_of(is_min, key.as_ref(), &mut |_| Ok(positional.next()))?,
+        };
+
+        match best {
+            Some(best) => Ok(best),
+            None => default_guard.into_inner().ok_or_else(|| {
+                SimpleException::new_msg(ExcType::ValueError, format!("{func_name}() iterable argument is empty"))
+                    .into()
+            }),
+        }
+    }
+
+    /// Implementation of `filter(function, iterable)`.
+    ///
+    /// Returns an iterator over the items of `iterable` for which `function(item)` is true,
+    /// or over the true items themselves if `function` is `None`. Unlike CPython, every item
+    /// is tested when `filter()` is called and the iterator runs over the results.
+    pub(super) fn builtin_filter(&mut self, args: ArgValues) -> RunResult<Value> {
+        let this = self;
+        let positional = args.into_pos_only("filter", this.heap)?;
+        defer_drop_mut!(positional, this);
+        let positional_len = positional.len();
+        if positional_len != 2 {
+            return Err(ExcType::type_error(format!(
+                "filter expected 2 arguments, got {positional_len}"
+            )));
+        }
+        let function = match positional.next().expect("filter() argument count checked above") {
+            Value::None => None,
+            function => Some(function),
+        };
+        defer_drop!(function, this);
+        let iterable = positional.next().expect("filter() argument count checked above");
+        let iter = this.vm_iter(iterable)?;
+        defer_drop_mut!(iter, this);
+
+        let mut kept_guard = HeapGuard::new(Vec::new(), this);
+        let (kept, this) = kept_guard.as_parts_mut();
+        this.filter_into(function.as_ref(), iter, kept)?;
+        let (kept, this) = kept_guard.into_parts();
+        this.iter_over(kept)
+    }
+
+    /// Implementation of `map(function, iterable, *iterables)`.
+    ///
+    /// Returns an iterator over `function` applied to the items of the iterables in parallel,
+    /// stopping at the shortest. Unlike CPython, `function` is called for every item when
+    /// `map()` is called and the iterator runs over the results.
+    pub(super) fn builtin_map(&mut self, args: ArgValues) -> RunResult<Value> {
+        let this = self;
+        let positional = args.into_pos_only("map", this.heap)?;
+        defer_drop_mut!(positional, this);
+        if positional.len() < 2 {
+            return Err(ExcType::type_error("map() must have at least two arguments."));
+        }
+        let function = positional.next().expect("map() argument count checked above");
+        defer_drop!(function, this);
+
+        let mut iters_guard = HeapGuard::new(Vec::with_capacity(positional.len()), this);
+        let (iters, this) = iters_guard.as_parts_mut();
+        for iterable in positional.by_ref() {
+            iters.push(this.vm_iter(iterable)?);
+        }
+
+        let mut results_guard = HeapGuard::new(Vec::new(), this);
+        let (results, this) = results_guard.as_parts_mut();
+        this.map_into(function, iters, results)?;
+        let (results, this) = results_guard.into_parts();
+        this.iter_over(results)
+    }
+
+    /// Implementation of `sum(iterable, /, start=0)`.
+    ///
+    /// Sums the items of an iterable from left to right. String start values are rejected,
+    /// as in CPython (`''.join(seq)` concatenates strings).
+    pub(super) fn builtin_sum(&mut self, args: ArgValues) -> RunResult<Value> {
+        let this = self;
+        let (iterable, start) = args.get_one_two_args("sum", this.heap)?;
+        let mut accumulator_guard = HeapGuard::new(start.unwrap_or(Value::Int(0)), this);
+
+        {
+            let (accumulator, this) = accumulator_guard.as_parts_mut();
+            if accumulator.py_type(this.heap) == Type::Str {
+                iterable.drop_with_heap(this.heap);
+                return Err(SimpleException::new_msg(
+                    ExcType::TypeError,
+                    "sum() can't sum strings [use ''.join(seq) instead]",
+                )
+                .into());
+            }
+            let iter = this.vm_iter(iterable)?;
+            defer_drop_mut!(iter, this);
+
+            // Sum the items one at a time, so lazy iterators are never materialized
+            while let Some(item) = this.vm_iter_next(iter)? {
+                defer_drop!(item, this);
+                let Some(sum) = accumulator.py_add(item, this.heap, this.interns)? else {
+                    let accumulator_type = accumulator.py_type(this.heap);
+                    let item_type = item.py_type(this.heap);
+                    return Err(ExcType::binary_type_error("+", accumulator_type, item_type));
+                };
+                std::mem::replace(accumulator, sum).drop_with_heap(this.heap);
+            }
+        }
+
+        Ok(accumulator_guard.into_inner())
+    }
+
+    /// Implementation of `any(iterable)` and `all(iterable)`; `is_any` selects which.
+    ///
+    /// Stops at the first true item for `any()`, or the first false item for `all()`, so
+    /// later items of a generator are never produced.
+    pub(super) fn builtin_any_all(&mut self, args: ArgValues, is_any: bool) -> RunResult<Value> {
+        let this = self;
+        let iterable = args.get_one_arg(if is_any { "any" } else { "all" }, this.heap)?;
+        let iter = this.vm_iter(iterable)?;
+        defer_drop_mut!(iter, this);
+
+        while let Some(item) = this.vm_iter_next(iter)? {
+            defer_drop!(item, this);
+            if item.to_bool(this.heap, this.interns)? == is_any {
+                return Ok(Value::Bool(is_any));
+            }
+        }
+        Ok(Value::Bool(!is_any))
+    }
+
+    /// Implementation of `next(iterator[, default])`.
+    ///
+    /// Returns the next item of the iterator, or `default` once it's exhausted. Without a
+    /// default, an exhausted iterator raises `StopIteration`.
+    pub(super) fn builtin_next(&mut self, args: ArgValues) -> RunResult<Value> {
+        let this = self;
+        let (iterator, default) = args.get_one_two_args("next", this.heap)?;
+        defer_drop!(iterator, this);
+
+        match iterator {
+            Value::Ref(id) if matches!(this.heap.get(*id), HeapData::Generator(_)) => {
+                // Only dropped if the generator produces an item, otherwise it's the result
+                let mut default_guard = HeapGuard::new(default, this);
+                match default_guard.heap().generator_next(*id)? {
+                    Some(item) => Ok(item),
+                    None => default_guard.into_inner().ok_or_else(ExcType::stop_iteration),
+                }
+            }
+            _ => iterator_next(iterator, default, this.heap, this.interns),
+        }
+    }
+
+    /// Parses the keyword-only `key` and `reverse` arguments of `sorted()` and `list.sort()`.
+    ///
+    /// Returns the key function, which is `None` if omitted or passed as `None`, and whether
+    /// to sort in reverse.
+    fn sort_options(&mut self, kwargs: KwargsValues) -> RunResult<(Option<Value>, bool)> {
+        let this = self;
+        // Both report errors as `sort()`, as CPython's `sorted()` delegates to `list.sort()`
+        let (key, reverse) =
+            ArgValues::Kwargs(kwargs).extract_two_kwargs_only("sort", "key", "reverse", this.heap, this.interns)?;
+        let mut key_guard = HeapGuard::new(key, this);
+        let reverse = match reverse {
+            Some(reverse) => {
+                let this = key_guard.heap();
+                defer_drop!(reverse, this);
+                reverse.to_bool(this.heap, this.interns)?
+            }
+            None => false,
+        };
+        let key = match key_guard.into_inner() {
+            Some(Value::None) => None,
+            key => key,
+        };
+        Ok((key, reverse))
+    }
+
+    /// Parses the keyword-only `key` and `default` arguments of `min()` and `max()`.
+    ///
+    /// A `key` of `None` is treated as no key function.
+    fn min_max_options(&mut self, func_name: &str, kwargs: KwargsValues) -> RunResult<(Option<Value>, Option<Value>)> {
+        let this = self;
+        let options: [Option<Value>; 2] = [None, None];
+        let mut options_guard = HeapGuard::new(options, this);
+
+        {
+            let ([key, default], this) = options_guard.as_parts_mut();
+            let kwargs = kwargs.into_iter();
+            defer_drop_mut!(kwargs, this);
+            for (name, value) in kwargs {
+                defer_drop!(name, this);
+                let slot = match name.as_either_str(this.heap) {
+                    Some(name) if name.as_str(this.interns) == "key" => Ok(&mut *key),
+                    Some(name) if name.as_str(this.interns) == "default" => Ok(&mut *default),
+                    Some(name) => Err(ExcType::type_error(format!(
+                        "'{}' is an invalid keyword argument for {func_name}()",
+                        name.as_str(this.interns)
+                    ))),
+                    None => Err(ExcType::type_error("keywords must be strings")),
+                };
+                match slot {
+                    Ok(slot) => slot.replace(value).drop_with_heap(this.heap),
+                    Err(err) => {
+                        value.drop_with_heap(this.heap);
+                        return Err(err);
+                    }
+                }
+            }
+        }
+
+        let [key, default] = options_guard.into_inner();
+        let key = match key {
+            Some(Value::None) => None,
+            key => key,
+        };
+        Ok((key, default))
+    }
+
+    /// Collects the items of an iterable into a `Vec`.
+    fn collect_iterable(&mut self, iterable: Value) -> RunResult<Vec<Value>> {
+        let this = self;
+        let iter = this.vm_iter(iterable)?;
+        defer_drop_mut!(iter, this);
+        match iter {
+            VmIter::Iter(iter) => iter.collect(this.heap, this.interns),
+            VmIter::Generator(id) => this.collect_generator(*id),
+        }
+    }
+
+    /// Sorts `items` in place, comparing `key(item)` instead of the items if `key` is given.
+    fn sort_items(&mut self, items: &mut Vec<Value>, key: Option<&Value>, reverse: bool) -> RunResult<()> {
+        let Some(key) = key else {
+            return sort_values(items, None, reverse, self.heap, self.interns);
+        };
+
+        let this = self;
+        let keys = Vec::with_capacity(items.len());
+        defer_drop_mut!(keys, this);
+        for item in items.iter() {
+            let arg = item.clone_with_heap(this.heap);
+            keys.push(this.call_value(key, ArgValues::One(arg))?);
+        }
+        sort_values(items, Some(keys.as_slice()), reverse, this.heap, this.interns)
+    }
+
+    /// Finds the smallest (or largest) item returned by `next`, or `None` if there are none.
+    ///
+    /// Items are compared by `key(item)` if `key` is given. Only a strictly smaller (or
+    /// larger) item replaces the current best, so ties keep the first item.
+    fn min_max_of(
+        &mut self,
+        is_min: bool,
+        key: Option<&Value>,
+        next: &mut dyn FnMut(&mut Self) -> RunResult<Option<Value>>,
+    ) -> RunResult<Option<Value>> {
+        let this = self;
+        let Some(first) = next(this)? else {
+            return Ok(None);
+        };
+        let mut best_guard = HeapGuard::new(first, this);
+
+        {
+            let (best, this) = best_guard.as_parts_mut();
+            let best_key = this.key_of(key, best)?;
+            defer_drop_mut!(best_key, this);
+            // CPython tests `item < best` for min() and `item > best` for max(), so NaN never
+            // replaces the best item and is kept if it comes first
+            let (op, wanted) = if is_min {
+                ("<", Ordering::Less)
+            } else {
+                (">", Ordering::Greater)
+            };
+            let mut guard = DepthGuard::default();
+
+            while let Some(item) = next(this)? {
+                // A better item is swapped with the best one, so the guards drop whichever lost
+                let mut item_guard = HeapGuard::new(item, this);
+                let (item, this) = item_guard.as_parts_mut();
+                let item_key = this.key_of(key, item)?;
+                defer_drop_mut!(item_key, this);
+
+                let best_compared = best_key.as_ref().unwrap_or(&*best);
+                let item_compared = item_key.as_ref().unwrap_or(&*item);
+                let ordering = item_compared.py_cmp_for_op(best_compared, op, this.heap, &mut guard, this.interns)?;
+                if ordering == Some(wanted) {
+                    std::mem::swap(best, item);
+                    std::mem::swap(best_key, item_key);
+                }
+            }
+        }
+
+        Ok(Some(best_guard.into_inner()))
+    }
+
+    /// Calls the key function on `item`, returning `None` if there is no key function.
+    fn key_of(&mut self, key: Option<&Value>, item: &Value) -> RunResult<Option<Value>> {
+        match key {
+            Some(key) => {
+                let arg = item.clone_with_heap(self.heap);
+                self.call_value(key, ArgValues::One(arg)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Moves the items of `iter` for which `function` returns true into `kept`.
+    fn filter_into(&mut self, function: Option<&Value>, iter: &mut VmIter, kept: &mut Vec<Value>) -> RunResult<()> {
+        let this = self;
+        while let Some(item) = this.vm_iter_next(iter)? {
+            // Only dropped if the item isn't kept
+            let mut item_guard = HeapGuard::new(item, this);
+            let (item, this) = item_guard.as_parts();
+            let keep = match function {
+                Some(function) => {
+                    let arg = item.clone_with_heap(this.heap);
+                    let result = this.call_value(function, ArgValues::One(arg))?;
+                    defer_drop!(result, this);
+                    result.to_bool(this.heap, this.interns)?
+                }
+                None => item.to_bool(this.heap, this.interns)?,
+            };
+            if keep {
+                kept.push(item_guard.into_inner());
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls `function` with one item from each of `iters` until any is exhausted.
+    fn map_into(&mut self, function: &Value, iters: &mut [VmIter], results: &mut Vec<Value>) -> RunResult<()> {
+        let this = self;
+        loop {
+            let mut items_guard = HeapGuard::new(Vec::with_capacity(iters.len()), this);
+            let (items, this) = items_guard.as_parts_mut();
+            for iter in iters.iter_mut() {
+                let Some(item) = this.vm_iter_next(iter)? else {
+                    return Ok(());
+                };
+                items.push(item);
+            }
+
+            let (mut items, this) = items_guard.into_parts();
+            let args = match items.len() {
+                1 => ArgValues::One(items.pop().expect("one item")),
+                2 => {
+                    let second = items.pop().expect("two items");
+                    let first = items.pop().expect("two items");
+                    ArgValues::Two(first, second)
+                }
+                _ => ArgValues::ArgsKargs {
+                    args: items,
+                    kwargs: KwargsValues::Empty,
+                },
+            };
+            results.push(this.call_value(function, args)?);
+        }
+    }
+
+    /// Returns an iterator over `items`, used for the results of `filter()` and `map()`.
+    fn iter_over(&mut self, items: Vec<Value>) -> RunResult<Value> {
+        let list = Value::Ref(self.heap.allocate(HeapData::List(List::new(items)))?);
+        let iter = MontyIter::new(list, self.heap, self.interns)?;
+        Ok(Value::Ref(self.heap.allocate(HeapData::Iter(iter))?))
+    }
+}
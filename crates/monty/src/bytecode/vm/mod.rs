@@ -11,11 +11,14 @@ mod collections;
 mod compare;
 mod exceptions;
 mod format;
+mod generator;
+mod higher_order;
 mod scheduler;
 
 use std::cmp::Ordering;
 
 use call::CallResult;
+use generator::GeneratorFrame;
 use scheduler::Scheduler;
 
 use crate::{
@@ -32,7 +35,7 @@ use crate::{
     os::OsFunction,
     parse::CodeRange,
     resource::ResourceTracker,
-    types::{LongInt, MontyIter, PyTrait, iter::advance_on_heap},
+    types::{LongInt, MontyIter, PyTrait, file::FileResume, generator::GeneratorState, iter::advance_on_heap},
     value::{BitwiseOp, Value},
 };
 
@@ -153,8 +156,8 @@ macro_rules! jump_relative {
 /// Handles the result of a call operation that returns `CallResult`.
 ///
 /// This macro eliminates the repetitive pattern of matching on `CallResult`
-/// variants that appears in LoadAttr, CallFunction, CallFunctionKw, CallAttr,
-/// CallAttrKw, and CallFunctionExtended opcodes.
+/// variants that appears in LoadAttr, CallFunction, CallBuiltinFunction, CallFunctionKw,
+/// CallAttr, CallAttrKw, and CallFunctionExtended opcodes.
 ///
 /// Actions taken for each variant:
 /// - `Push(value)`: Push the value onto the stack
@@ -168,6 +171,7 @@ macro_rules! handle_call_result {
             Ok(CallResult::Push(result)) => $self.push(result),
             Ok(CallResult::FramePushed) => reload_cache!($self, $cached_frame),
             Ok(CallResult::External(ext_id, args)) => {
+                $self.file_resume = None;
                 let call_id = $self.allocate_call_id();
                 // Sync cached IP back to frame before snapshot for resume
                 $self.current_frame_mut().ip = $cached_frame.ip;
@@ -178,6 +182,7 @@ macro_rules! handle_call_result {
                 });
             }
             Ok(CallResult::OsCall(func, args)) => {
+                $self.file_resume = FileResume::for_os_call(func, &args, $self.heap, $self.interns);
                 let call_id = $self.allocate_call_id();
                 // Sync cached IP back to frame before snapshot for resume
                 $self.current_frame_mut().ip = $cached_frame.ip;
@@ -261,6 +266,9 @@ pub struct CallFrame<'code> {
 
     /// Call site position (for tracebacks).
     call_position: Option<CodeRange>,
+
+    /// The generator this frame runs, if it is a generator expression's frame.
+    generator: Option<GeneratorFrame>,
 }
 
 impl<'code> CallFrame<'code> {
@@ -274,6 +282,7 @@ impl<'code> CallFrame<'code> {
             function_id: None,
             cells: Vec::new(),
             call_position: None,
+            generator: None,
         }
     }
 
@@ -294,6 +303,7 @@ impl<'code> CallFrame<'code> {
             function_id: Some(function_id),
             cells,
             call_position,
+            generator: None,
         }
     }
 }
@@ -344,6 +354,9 @@ pub struct SerializedFrame {
 
     /// Call site position (for tracebacks).
     call_position: Option<CodeRange>,
+
+    /// The generator this frame runs, if any.
+    generator: Option<GeneratorFrame>,
 }
 
 impl CallFrame<'_> {
@@ -356,6 +369,7 @@ impl CallFrame<'_> {
             namespace_idx: self.namespace_idx,
             cells: self.cells.clone(),
             call_position: self.call_position,
+            generator: self.generator,
         }
     }
 }
@@ -398,6 +412,9 @@ pub struct VMSnapshot {
     /// This enables async execution to be paused and resumed across host calls.
     /// None if no async operations have been performed yet.
     scheduler: Option<Scheduler>,
+
+    /// Conversion to apply to the host's result for the pending file OS call, if any.
+    file_resume: Option<FileResume>,
 }
 
 // ============================================================================
@@ -459,6 +476,26 @@ pub struct VM<'a, T: ResourceTracker, P: PrintWriter> {
     /// Stored here because the main task's frames have `function_id: None` and
     /// need a reference to the module code when being restored after task switching.
     module_code: Option<&'a Code>,
+
+    /// Conversion to apply to the host's result when resuming after a file OS call.
+    ///
+    /// Set when yielding `open()` (wrap the handle in a `File`) or iteration over a file
+    /// (turn the lines into an iterator), and consumed by `resume()`.
+    file_resume: Option<FileResume>,
+
+    /// Frame count at which the innermost callback frame returns to a builtin, or 0 if none.
+    ///
+    /// Builtins like `sorted(key=...)` call Python functions through `call_value()`, which runs
+    /// the function's frame in a nested `run()`. When the stack is back to this many frames,
+    /// `ReturnValue` and exception unwinding hand control back to the builtin instead of the
+    /// calling frame. Callbacks never outlive a `run()` call, so this isn't part of snapshots.
+    callback_frames: usize,
+
+    /// Number of callbacks currently running, bounded by `MAX_CALLBACK_DEPTH`.
+    ///
+    /// The builtin running a callback holds values only it can see, so garbage collection
+    /// is postponed until no callbacks are running.
+    callback_depth: usize,
 }
 
 impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
@@ -481,6 +518,9 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
             next_call_id: 0,
             scheduler: None, // Lazy - no allocation for sync code
             module_code: None,
+            file_resume: None,
+            callback_frames: 0,
+            callback_depth: 0,
         }
     }
 
@@ -522,6 +562,7 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                     function_id: sf.function_id,
                     cells: sf.cells,
                     call_position: sf.call_position,
+                    generator: sf.generator,
                 }
             })
             .collect();
@@ -538,6 +579,9 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
             next_call_id: snapshot.next_call_id,
             scheduler: snapshot.scheduler,
             module_code: Some(module_code),
+            file_resume: snapshot.file_resume,
+            callback_frames: 0,
+            callback_depth: 0,
         }
     }
     /// Consumes the VM and creates a snapshot for pause/resume if needed.
@@ -571,6 +615,7 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
             instruction_ip: self.instruction_ip,
             next_call_id: self.next_call_id,
             scheduler: self.scheduler,
+            file_resume: self.file_resume,
         }
     }
 
@@ -672,11 +717,12 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
         let mut cached_frame: CachedFrame<'a> = self.new_cached_frame();
 
         loop {
-            // Check time limit and trigger GC if needed at each instruction.
+            // Check time and step limits and trigger GC if needed at each instruction.
             // For NoLimitTracker, these are inlined no-ops that compile away.
             self.heap.tracker_mut().check_time()?;
+            self.heap.tracker_mut().on_step()?;
 
-            if self.heap.should_gc() {
+            if self.heap.should_gc() && self.callback_depth == 0 {
                 // Sync IP before GC for safety
                 self.current_frame_mut().ip = cached_frame.ip;
                 self.run_gc();
@@ -700,6 +746,9 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                     let value = self.pop();
                     value.drop_with_heap(self.heap);
                 }
+                Opcode::PrintExpr => {
+                    try_catch_sync!(self, cached_frame, self.print_expr());
+                }
                 Opcode::Dup => {
                     // Copy without incrementing refcount first (avoids borrow conflict)
                     let value = self.peek().copy_for_extend();
@@ -709,6 +758,17 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                     }
                     self.push(value);
                 }
+                Opcode::Dup2 => {
+                    // Duplicate top two: [a, b] → [a, b, a, b]
+                    let len = self.stack.len();
+                    for i in [len - 2, len - 1] {
+                        let value = self.stack[i].copy_for_extend();
+                        if let Value::Ref(id) = &value {
+                            self.heap.inc_ref(*id);
+                        }
+                        self.push(value);
+                    }
+                }
                 Opcode::Rot2 => {
                     // Swap top two: [a, b] → [b, a]
                     let len = self.stack.len();
@@ -795,30 +855,30 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                 }
                 // Binary Operations - route through exception handling for tracebacks
                 Opcode::BinaryAdd => try_catch_sync!(self, cached_frame, self.binary_add()),
-                Opcode::BinarySub => try_catch_sync!(self, cached_frame, self.binary_sub()),
-                Opcode::BinaryMul => try_catch_sync!(self, cached_frame, self.binary_mult()),
-                Opcode::BinaryDiv => try_catch_sync!(self, cached_frame, self.binary_div()),
-                Opcode::BinaryFloorDiv => try_catch_sync!(self, cached_frame, self.binary_floordiv()),
-                Opcode::BinaryMod => try_catch_sync!(self, cached_frame, self.binary_mod()),
-                Opcode::BinaryPow => try_catch_sync!(self, cached_frame, self.binary_pow()),
+                Opcode::BinarySub => try_catch_sync!(self, cached_frame, self.binary_sub("-")),
+                Opcode::BinaryMul => try_catch_sync!(self, cached_frame, self.binary_mult("*")),
+                Opcode::BinaryDiv => try_catch_sync!(self, cached_frame, self.binary_div("/")),
+                Opcode::BinaryFloorDiv => try_catch_sync!(self, cached_frame, self.binary_floordiv("//")),
+                Opcode::BinaryMod => try_catch_sync!(self, cached_frame, self.binary_mod("%")),
+                Opcode::BinaryPow => try_catch_sync!(self, cached_frame, self.binary_pow("** or pow()")),
                 // Bitwise operations - only work on integers
-                Opcode::BinaryAnd => try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::And)),
-                Opcode::BinaryOr => try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::Or)),
-                Opcode::BinaryXor => try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::Xor)),
+                Opcode::BinaryAnd => try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::And, "&")),
+                Opcode::BinaryOr => try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::Or, "|")),
+                Opcode::BinaryXor => try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::Xor, "^")),
                 Opcode::BinaryLShift => {
-                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::LShift));
+                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::LShift, "<<"));
                 }
                 Opcode::BinaryRShift => {
-                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::RShift));
+                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::RShift, ">>"));
                 }
                 Opcode::BinaryMatMul => try_catch_sync!(self, cached_frame, self.binary_matmul()),
                 // Comparison Operations
                 Opcode::CompareEq => try_catch_sync!(self, cached_frame, self.compare_eq()),
                 Opcode::CompareNe => try_catch_sync!(self, cached_frame, self.compare_ne()),
-                Opcode::CompareLt => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_lt)),
-                Opcode::CompareLe => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_le)),
-                Opcode::CompareGt => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_gt)),
-                Opcode::CompareGe => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_ge)),
+                Opcode::CompareLt => try_catch_sync!(self, cached_frame, self.compare_ord("<", Ordering::is_lt)),
+                Opcode::CompareLe => try_catch_sync!(self, cached_frame, self.compare_ord("<=", Ordering::is_le)),
+                Opcode::CompareGt => try_catch_sync!(self, cached_frame, self.compare_ord(">", Ordering::is_gt)),
+                Opcode::CompareGe => try_catch_sync!(self, cached_frame, self.compare_ord(">=", Ordering::is_ge)),
                 Opcode::CompareIs => self.compare_is(false),
                 Opcode::CompareIsNot => self.compare_is(true),
                 Opcode::CompareIn => try_catch_sync!(self, cached_frame, self.compare_in(false)),
@@ -828,12 +888,16 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                     let k = cached_frame.code.constants().get(const_idx);
                     try_catch_sync!(self, cached_frame, self.compare_mod_eq(k));
                 }
+                Opcode::CompareLtLen => try_catch_sync!(self, cached_frame, self.compare_lt_len()),
                 // Unary Operations
                 Opcode::UnaryNot => {
                     let value = self.pop();
-                    let result = !value.py_bool(self.heap, self.interns);
+                    let result = value.to_bool(self.heap, self.interns);
                     value.drop_with_heap(self.heap);
-                    self.push(Value::Bool(result));
+                    match result {
+                        Ok(result) => self.push(Value::Bool(!result)),
+                        Err(err) => catch_sync!(self, cached_frame, err),
+                    }
                 }
                 Opcode::UnaryNeg => {
                     // Unary minus - negate numeric value
@@ -927,26 +991,26 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                     }
                 }
                 // In-place Operations - route through exception handling
-                Opcode::InplaceAdd => try_catch_sync!(self, cached_frame, self.inplace_add()),
+                Opcode::InplaceAdd => try_catch_sync!(self, cached_frame, self.inplace_add(&cached_frame)),
                 // Other in-place ops use the same logic as binary ops for now
-                Opcode::InplaceSub => try_catch_sync!(self, cached_frame, self.binary_sub()),
-                Opcode::InplaceMul => try_catch_sync!(self, cached_frame, self.binary_mult()),
-                Opcode::InplaceDiv => try_catch_sync!(self, cached_frame, self.binary_div()),
-                Opcode::InplaceFloorDiv => try_catch_sync!(self, cached_frame, self.binary_floordiv()),
-                Opcode::InplaceMod => try_catch_sync!(self, cached_frame, self.binary_mod()),
-                Opcode::InplacePow => try_catch_sync!(self, cached_frame, self.binary_pow()),
+                Opcode::InplaceSub => try_catch_sync!(self, cached_frame, self.binary_sub("-=")),
+                Opcode::InplaceMul => try_catch_sync!(self, cached_frame, self.binary_mult("*=")),
+                Opcode::InplaceDiv => try_catch_sync!(self, cached_frame, self.binary_div("/=")),
+                Opcode::InplaceFloorDiv => try_catch_sync!(self, cached_frame, self.binary_floordiv("//=")),
+                Opcode::InplaceMod => try_catch_sync!(self, cached_frame, self.binary_mod("%=")),
+                Opcode::InplacePow => try_catch_sync!(self, cached_frame, self.binary_pow("**=")),
                 Opcode::InplaceAnd => {
-                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::And));
+                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::And, "&="));
                 }
-                Opcode::InplaceOr => try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::Or)),
+                Opcode::InplaceOr => try_catch_sync!(self, cached_frame, self.inplace_or()),
                 Opcode::InplaceXor => {
-                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::Xor));
+                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::Xor, "^="));
                 }
                 Opcode::InplaceLShift => {
-                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::LShift));
+                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::LShift, "<<="));
                 }
                 Opcode::InplaceRShift => {
-                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::RShift));
+                    try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::RShift, ">>="));
                 }
                 // Collection Building - route through exception handling
                 Opcode::BuildList => {
@@ -1045,47 +1109,85 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                 Opcode::JumpIfTrue => {
                     let offset = fetch_i16!(cached_frame);
                     let cond = self.pop();
-                    if cond.py_bool(self.heap, self.interns) {
-                        jump_relative!(cached_frame.ip, offset);
-                    }
+                    let result = cond.to_bool(self.heap, self.interns);
                     cond.drop_with_heap(self.heap);
+                    match result {
+                        Ok(true) => jump_relative!(cached_frame.ip, offset),
+                        Ok(false) => {}
+                        Err(err) => catch_sync!(self, cached_frame, err),
+                    }
                 }
                 Opcode::JumpIfFalse => {
                     let offset = fetch_i16!(cached_frame);
                     let cond = self.pop();
-                    if !cond.py_bool(self.heap, self.interns) {
-                        jump_relative!(cached_frame.ip, offset);
-                    }
+                    let result = cond.to_bool(self.heap, self.interns);
                     cond.drop_with_heap(self.heap);
+                    match result {
+                        Ok(false) => jump_relative!(cached_frame.ip, offset),
+                        Ok(true) => {}
+                        Err(err) => catch_sync!(self, cached_frame, err),
+                    }
                 }
                 Opcode::JumpIfTrueOrPop => {
                     let offset = fetch_i16!(cached_frame);
-                    if self.peek().py_bool(self.heap, self.interns) {
-                        jump_relative!(cached_frame.ip, offset);
-                    } else {
-                        let value = self.pop();
-                        value.drop_with_heap(self.heap);
+                    match self.peek().to_bool(self.heap, self.interns) {
+                        Ok(true) => jump_relative!(cached_frame.ip, offset),
+                        Ok(false) => {
+                            let value = self.pop();
+                            value.drop_with_heap(self.heap);
+                        }
+                        Err(err) => catch_sync!(self, cached_frame, err),
                     }
                 }
                 Opcode::JumpIfFalseOrPop => {
                     let offset = fetch_i16!(cached_frame);
-                    if self.peek().py_bool(self.heap, self.interns) {
-                        let value = self.pop();
-                        value.drop_with_heap(self.heap);
-                    } else {
-                        jump_relative!(cached_frame.ip, offset);
+                    match self.peek().to_bool(self.heap, self.interns) {
+                        Ok(true) => {
+                            let value = self.pop();
+                            value.drop_with_heap(self.heap);
+                        }
+                        Ok(false) => jump_relative!(cached_frame.ip, offset),
+                        Err(err) => catch_sync!(self, cached_frame, err),
                     }
                 }
                 // Iteration - route through exception handling
                 Opcode::GetIter => {
                     let value = self.pop();
-                    // Create a MontyIter from the value and store on heap
-                    match MontyIter::new(value, self.heap, self.interns) {
-                        Ok(iter) => match self.heap.allocate(HeapData::Iter(iter)) {
-                            Ok(heap_id) => self.push(Value::Ref(heap_id)),
-                            Err(e) => catch_sync!(self, cached_frame, e.into()),
-                        },
-                        Err(e) => catch_sync!(self, cached_frame, e),
+                    if let Value::Ref(heap_id) = value
+                        && let HeapData::File(file) = self.heap.get(heap_id)
+                    {
+                        // Files need the host to read their lines before they can be iterated,
+                        // resume() then turns the returned list into an iterator
+                        let result = file.iter_os_call();
+                        value.drop_with_heap(self.heap);
+                        match result {
+                            Ok((function, args)) => {
+                                self.file_resume = Some(FileResume::Iter);
+                                let call_id = self.allocate_call_id();
+                                // Sync cached IP back to frame before snapshot for resume
+                                self.current_frame_mut().ip = cached_frame.ip;
+                                return Ok(FrameExit::OsCall {
+                                    function,
+                                    args,
+                                    call_id,
+                                });
+                            }
+                            Err(e) => catch_sync!(self, cached_frame, e),
+                        }
+                    } else if let Value::Ref(heap_id) = value
+                        && matches!(self.heap.get(heap_id), HeapData::Iter(_) | HeapData::Generator(_))
+                    {
+                        // Iterators and generators are their own iterators, so looping advances them in place
+                        self.push(value);
+                    } else {
+                        // Create a MontyIter from the value and store on heap
+                        match MontyIter::new(value, self.heap, self.interns) {
+                            Ok(iter) => match self.heap.allocate(HeapData::Iter(iter)) {
+                                Ok(heap_id) => self.push(Value::Ref(heap_id)),
+                                Err(e) => catch_sync!(self, cached_frame, e.into()),
+                            },
+                            Err(e) => catch_sync!(self, cached_frame, e),
+                        }
                     }
                 }
                 Opcode::ForIter => {
@@ -1095,6 +1197,28 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                         return Err(RunError::internal("ForIter: expected iterator ref on stack"));
                     };
 
+                    if matches!(self.heap.get(heap_id), HeapData::Generator(_)) {
+                        // A generator runs its frame, which yields the next item onto this frame's
+                        // stack or jumps to the loop's exit when it returns
+                        self.current_frame_mut().ip = cached_frame.ip;
+                        let mut loop_exit = cached_frame.ip;
+                        jump_relative!(loop_exit, offset);
+                        match self.resume_generator(heap_id, Some(loop_exit)) {
+                            Ok(true) => reload_cache!(self, cached_frame),
+                            Ok(false) => {
+                                let iter = self.pop();
+                                iter.drop_with_heap(self.heap);
+                                cached_frame.ip = loop_exit;
+                            }
+                            Err(e) => {
+                                let iter = self.pop();
+                                iter.drop_with_heap(self.heap);
+                                catch_sync!(self, cached_frame, e);
+                            }
+                        }
+                        continue;
+                    }
+
                     // Use advance_iterator which avoids std::mem::replace overhead
                     // by using a two-phase approach: read state, get value, update index
                     match advance_on_heap(self.heap, heap_id, self.interns) {
@@ -1127,11 +1251,11 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                     let builtin_id = fetch_u8!(cached_frame);
                     let arg_count = fetch_u8!(cached_frame) as usize;
 
-                    match self.exec_call_builtin_function(builtin_id, arg_count) {
-                        Ok(result) => self.push(result),
-                        // IP sync deferred to error path (no frame push possible)
-                        Err(err) => catch_sync!(self, cached_frame, err),
-                    }
+                    handle_call_result!(
+                        self,
+                        cached_frame,
+                        self.exec_call_builtin_function(builtin_id, arg_count)
+                    );
                 }
                 Opcode::CallBuiltinType => {
                     // Fetch operands: type_id (u8) + arg_count (u8)
@@ -1278,6 +1402,12 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                     let error = self.make_exception(exc, true); // is_raise=true, hide caret
                     catch_sync!(self, cached_frame, error);
                 }
+                Opcode::RaiseFrom => {
+                    let cause = self.pop();
+                    let exc = self.pop();
+                    let error = self.make_exception_from(exc, cause);
+                    catch_sync!(self, cached_frame, error);
+                }
                 Opcode::Reraise => {
                     // Pop the current exception from the stack to re-raise it
                     // If caught, handle_exception will push it back
@@ -1308,6 +1438,25 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                 // Return - reload cache after popping frame
                 Opcode::ReturnValue => {
                     let value = self.pop();
+                    if self.frames.len() == self.callback_frames {
+                        // Function called by a builtin like `sorted()` - hand the value back to it
+                        self.pop_frame();
+                        return Ok(FrameExit::Return(value));
+                    }
+                    if let Some(GeneratorFrame {
+                        loop_exit: Some(loop_exit),
+                        ..
+                    }) = self.current_frame().generator
+                    {
+                        // Generator exhausted by a `for` loop - drop it and leave the loop
+                        value.drop_with_heap(self.heap);
+                        self.pop_frame();
+                        let iter = self.pop();
+                        iter.drop_with_heap(self.heap);
+                        self.current_frame_mut().ip = loop_exit;
+                        reload_cache!(self, cached_frame);
+                        continue;
+                    }
                     if self.frames.len() == 1 {
                         // Last frame - check if this is main task or spawned task
                         let is_main_task = self.is_main_task();
@@ -1345,6 +1494,24 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                 }
                 // Async/Await
                 Opcode::Await => {
+                    if self.current_frame().generator.is_some() {
+                        catch_sync!(
+                            self,
+                            cached_frame,
+                            ExcType::not_implemented("await expressions in generator expressions are not supported")
+                                .into()
+                        );
+                        continue;
+                    }
+                    if self.callback_depth > 0 {
+                        // Awaiting may switch tasks, which would leave the builtin's frames behind
+                        catch_sync!(
+                            self,
+                            cached_frame,
+                            ExcType::not_supported_in_callback("await expressions")
+                        );
+                        continue;
+                    }
                     // Sync IP before exec (may push new frame for coroutine)
                     self.current_frame_mut().ip = cached_frame.ip;
                     let result = self.exec_get_awaitable();
@@ -1365,6 +1532,29 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
                         }
                     }
                 }
+                // Generators
+                Opcode::YieldValue => {
+                    let value = self.pop();
+                    let (generator, charged) = self.suspend_generator(cached_frame.ip);
+                    if let Err(e) = charged {
+                        value.drop_with_heap(self.heap);
+                        if generator.loop_exit.is_none() {
+                            // The builtin advancing the generator handles the error
+                            return Err(e);
+                        }
+                        // The generator's frame is gone, so look for a handler around the `for` loop
+                        self.instruction_ip = self.current_frame().ip - 1;
+                        catch_sync!(self, cached_frame, e);
+                        continue;
+                    }
+                    if generator.loop_exit.is_none() {
+                        // Advanced by a builtin like `sum()` - hand the item back to it
+                        return Ok(FrameExit::Return(value));
+                    }
+                    // Advanced by a `for` loop - the item is the loop's next value
+                    self.push(value);
+                    reload_cache!(self, cached_frame);
+                }
                 // Unpacking - route through exception handling
                 Opcode::UnpackSequence => {
                     let count = fetch_u8!(cached_frame) as usize;
@@ -1414,9 +1604,19 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
     ///
     /// Pushes the return value onto the stack and continues execution.
     pub fn resume(&mut self, obj: MontyObject) -> Result<FrameExit, RunError> {
+        let file_resume = self.file_resume.take();
         let value = obj
             .to_value(self.heap, self.interns)
             .map_err(|e| SimpleException::new(ExcType::RuntimeError, Some(format!("invalid return type: {e}"))))?;
+        let value = match file_resume {
+            // Errors wrapping a file result are raised where the OS call was made, so a
+            // surrounding `try` can catch them like an exception from the host
+            Some(file_resume) => match file_resume.apply(value, self.heap, self.interns) {
+                Ok(value) => value,
+                Err(err) => return self.resume_with_exception(err),
+            },
+            None => value,
+        };
         self.push(value);
         self.run()
     }
@@ -1426,6 +1626,7 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
     /// Uses the exception handling mechanism to try to catch the exception.
     /// If caught, continues execution at the handler. If not, propagates the error.
     pub fn resume_with_exception(&mut self, error: RunError) -> Result<FrameExit, RunError> {
+        self.file_resume = None;
         // Use the normal exception handling mechanism
         // handle_exception returns None if caught, Some(error) if not caught
         if let Some(uncaught_error) = self.handle_exception(error) {
@@ -1487,9 +1688,15 @@ impl<'a, T: ResourceTracker, P: PrintWriter> VM<'a, T, P> {
 
     /// Pops the current frame from the call stack.
     ///
-    /// Cleans up the frame's stack region and namespace (except for global namespace).
+    /// Cleans up the frame's stack region and namespace (except for global namespace). A
+    /// generator's frame is popped when it returns or raises, which finishes the generator.
     pub(super) fn pop_frame(&mut self) {
         let frame = self.frames.pop().expect("no frame to pop");
+        if let Some(generator) = frame.generator
+            && let HeapData::Generator(generator) = self.heap.get_mut(generator.id)
+        {
+            generator.state = GeneratorState::Finished;
+        }
         // Clean up frame's stack region
         while self.stack.len() > frame.stack_base {
             let value = self.stack.pop().unwrap();
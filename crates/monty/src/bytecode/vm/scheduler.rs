@@ -14,6 +14,7 @@ use std::collections::VecDeque;
 
 use ahash::{AHashMap, AHashSet};
 
+use super::GeneratorFrame;
 use crate::{
     args::ArgValues,
     asyncio::{CallId, TaskId},
@@ -100,6 +101,8 @@ pub(crate) struct SerializedTaskFrame {
     pub cells: Vec<HeapId>,
     /// Call site position (for tracebacks).
     pub call_position: Option<CodeRange>,
+    /// The generator this frame runs, if any.
+    pub generator: Option<GeneratorFrame>,
 }
 
 impl Task {
@@ -97,8 +97,22 @@ pub enum ExcType {
     /// Subclass of OSError - for when a path is not a directory but one was expected.
     NotADirectoryError,
 
+    // --- Warning hierarchy ---
+    /// Base class for warning categories, used with `warnings.warn()`.
+    Warning,
+    /// Subclass of Warning - the default category for `warnings.warn()`.
+    UserWarning,
+    /// Subclass of Warning.
+    DeprecationWarning,
+    /// Subclass of Warning.
+    RuntimeWarning,
+    /// Subclass of Warning.
+    FutureWarning,
+
     // --- Standalone exception types ---
     AssertionError,
+    /// Raised by `input()` when the host has no more input to supply.
+    EOFError,
     MemoryError,
     StopIteration,
     SyntaxError,
@@ -114,6 +128,7 @@ impl ExcType {
     /// - `LookupError` is the base for `KeyError` and `IndexError`
     /// - `ArithmeticError` is the base for `ZeroDivisionError` and `OverflowError`
     /// - `RuntimeError` is the base for `RecursionError` and `NotImplementedError`
+    /// - `Warning` is the base for `UserWarning`, `DeprecationWarning`, `RuntimeWarning` and `FutureWarning`
     ///
     /// Returns true if `self` would be caught by `except handler_type:`.
     #[must_use]
@@ -145,6 +160,11 @@ impl ExcType {
                 self,
                 Self::FileNotFoundError | Self::FileExistsError | Self::IsADirectoryError | Self::NotADirectoryError
             ),
+            // Warning catches all warning categories
+            Self::Warning => matches!(
+                self,
+                Self::UserWarning | Self::DeprecationWarning | Self::RuntimeWarning | Self::FutureWarning
+            ),
             // All other types only match exactly (handled by self == handler_type above)
             _ => false,
         }
@@ -297,26 +317,28 @@ impl ExcType {
 
     /// Creates a TypeError for unhashable types used as dict keys.
     ///
-    /// This matches Python 3.14's error message:
-    /// `TypeError: cannot use 'list' as a dict key (unhashable type: 'list')`
+    /// `unhashable` is the type that makes the key unhashable, which differs from the key's
+    /// own type for containers like tuples. This matches Python 3.14's error message:
+    /// `TypeError: cannot use 'tuple' as a dict key (unhashable type: 'list')`
     #[must_use]
-    pub(crate) fn type_error_unhashable_dict_key(type_: Type) -> RunError {
+    pub(crate) fn type_error_unhashable_dict_key(type_: Type, unhashable: Type) -> RunError {
         SimpleException::new_msg(
             Self::TypeError,
-            format!("cannot use '{type_}' as a dict key (unhashable type: '{type_}')"),
+            format!("cannot use '{type_}' as a dict key (unhashable type: '{unhashable}')"),
         )
         .into()
     }
 
     /// Creates a TypeError for unhashable types used as set elements.
     ///
-    /// This matches Python 3.14's error message:
+    /// `unhashable` is the type that makes the element unhashable, as for dict keys. This
+    /// matches Python 3.14's error message:
     /// `TypeError: cannot use 'list' as a set element (unhashable type: 'list')`
     #[must_use]
-    pub(crate) fn type_error_unhashable_set_element(type_: Type) -> RunError {
+    pub(crate) fn type_error_unhashable_set_element(type_: Type, unhashable: Type) -> RunError {
         SimpleException::new_msg(
             Self::TypeError,
-            format!("cannot use '{type_}' as a set element (unhashable type: '{type_}')"),
+            format!("cannot use '{type_}' as a set element (unhashable type: '{unhashable}')"),
         )
         .into()
     }
@@ -402,6 +424,7 @@ impl ExcType {
     /// Creates a TypeError for when a function receives more arguments than allowed.
     ///
     /// Matches CPython's format: `{name} expected at most {max} arguments, got {actual}`
+    /// (singular "argument" when `max` is 1).
     ///
     /// # Arguments
     /// * `name` - The function name (e.g., "get", "pop")
@@ -409,10 +432,11 @@ impl ExcType {
     /// * `actual` - Number of arguments actually provided
     #[must_use]
     pub(crate) fn type_error_at_most(name: &str, max: usize, actual: usize) -> RunError {
-        // CPython: "get expected at most 2 arguments, got 3"
+        // CPython: "get expected at most 2 arguments, got 3", "input expected at most 1 argument, got 2"
+        let plural = if max == 1 { "" } else { "s" };
         SimpleException::new_msg(
             Self::TypeError,
-            format!("{name} expected at most {max} arguments, got {actual}"),
+            format!("{name} expected at most {max} argument{plural}, got {actual}"),
         )
         .into()
     }
@@ -671,6 +695,22 @@ impl ExcType {
         SimpleException::new_msg(Self::ValueError, "slice step cannot be zero").into()
     }
 
+    /// Creates a TypeError for using `NotImplemented` as a condition or in `bool()`.
+    ///
+    /// Matches CPython 3.14's format: `TypeError: NotImplemented should not be used in a boolean context`
+    #[must_use]
+    pub(crate) fn type_error_not_implemented_bool() -> RunError {
+        ExcType::type_error("NotImplemented should not be used in a boolean context")
+    }
+
+    /// Creates a ValueError for an operation on a file that has been closed.
+    ///
+    /// Matches CPython's format: `ValueError: I/O operation on closed file.`
+    #[must_use]
+    pub(crate) fn value_error_closed_file() -> RunError {
+        SimpleException::new_msg(Self::ValueError, "I/O operation on closed file.").into()
+    }
+
     /// Creates a TypeError for slice indices that are not integers or None.
     ///
     /// Matches CPython's format: `TypeError: slice indices must be integers or None or have an __index__ method`
@@ -755,28 +795,18 @@ impl ExcType {
         SimpleException::new_msg(Self::IndexError, "range object index out of range").into()
     }
 
-    /// Creates a TypeError for non-integer sequence indices (getitem).
+    /// Creates a TypeError for non-integer sequence indices.
     ///
-    /// Matches CPython's format: `TypeError('{type}' indices must be integers, not '{index_type}')`
+    /// Matches CPython's format, which is `TypeError('{type} indices must be integers or slices, not {index_type}')`
+    /// for most sequences but differs for `str` and `bytes`.
     #[must_use]
     pub(crate) fn type_error_indices(type_str: Type, index_type: Type) -> RunError {
-        SimpleException::new_msg(
-            Self::TypeError,
-            format!("{type_str} indices must be integers, not '{index_type}'"),
-        )
-        .into()
-    }
-
-    /// Creates a TypeError for non-integer list indices (setitem/assignment).
-    ///
-    /// Matches CPython's format: `TypeError('list indices must be integers or slices, not {index_type}')`
-    #[must_use]
-    pub(crate) fn type_error_list_assignment_indices(index_type: Type) -> RunError {
-        SimpleException::new_msg(
-            Self::TypeError,
-            format!("list indices must be integers or slices, not {index_type}"),
-        )
-        .into()
+        let msg = match type_str {
+            Type::Str => format!("string indices must be integers, not '{index_type}'"),
+            Type::Bytes => format!("byte indices must be integers or slices, not {index_type}"),
+            _ => format!("{type_str} indices must be integers or slices, not {index_type}"),
+        };
+        SimpleException::new_msg(Self::TypeError, msg).into()
     }
 
     /// Creates a NameError for accessing a free variable (nonlocal/closure) before it's assigned.
@@ -838,6 +868,19 @@ impl ExcType {
         SimpleException::new_msg(Self::NotImplementedError, msg)
     }
 
+    /// Creates a NotImplementedError for an operation a function called back by a builtin can't do.
+    ///
+    /// Functions passed to builtins like `sorted(key=...)` and `map()` run to completion inside
+    /// the builtin, so they can't pause the VM to wait for the host.
+    #[must_use]
+    pub(crate) fn not_supported_in_callback(what: &str) -> RunError {
+        SimpleException::new_msg(
+            Self::NotImplementedError,
+            format!("{what} are not supported in functions called by builtins like sorted() or map()"),
+        )
+        .into()
+    }
+
     /// Creates a ZeroDivisionError for division by zero.
     ///
     /// Matches CPython 3.14's format: `ZeroDivisionError('division by zero')`
@@ -898,17 +941,45 @@ impl ExcType {
         SimpleException::new_msg(Self::OverflowError, "Python int too large to convert to C ssize_t").into()
     }
 
+    /// Creates a ValueError for converting a NaN float to an integer.
+    ///
+    /// Matches CPython's format: `ValueError: cannot convert float NaN to integer`
+    #[must_use]
+    pub(crate) fn value_error_float_nan_to_int() -> RunError {
+        SimpleException::new_msg(Self::ValueError, "cannot convert float NaN to integer").into()
+    }
+
+    /// Creates an OverflowError for converting an infinite float to an integer.
+    ///
+    /// Matches CPython's format: `OverflowError: cannot convert float infinity to integer`
+    #[must_use]
+    pub(crate) fn overflow_error_float_inf_to_int() -> RunError {
+        SimpleException::new_msg(Self::OverflowError, "cannot convert float infinity to integer").into()
+    }
+
     /// Creates a TypeError for unsupported binary operations.
     ///
     /// For `+` or `+=` with str/list on the left side, uses CPython's special format:
     /// `can only concatenate {type} (not "{other}") to {type}`
     ///
+    /// For `*` or `*=` with a sequence on either side, uses CPython's format naming the other operand:
+    /// `can't multiply sequence by non-int of type '{other}'`
+    ///
     /// For other cases, uses the generic format:
     /// `unsupported operand type(s) for {op}: '{left}' and '{right}'`
     #[must_use]
     pub(crate) fn binary_type_error(op: &str, lhs_type: Type, rhs_type: Type) -> RunError {
+        let is_sequence = |t: Type| {
+            matches!(
+                t,
+                Type::Str | Type::Bytes | Type::ByteArray | Type::List | Type::Tuple | Type::NamedTuple
+            )
+        };
         let message = if (op == "+" || op == "+=") && (lhs_type == Type::Str || lhs_type == Type::List) {
             format!("can only concatenate {lhs_type} (not \"{rhs_type}\") to {lhs_type}")
+        } else if (op == "*" || op == "*=") && (is_sequence(lhs_type) || is_sequence(rhs_type)) {
+            let other_type = if is_sequence(lhs_type) { rhs_type } else { lhs_type };
+            format!("can't multiply sequence by non-int of type '{other_type}'")
         } else {
             format!("unsupported operand type(s) for {op}: '{lhs_type}' and '{rhs_type}'")
         };
@@ -1001,6 +1072,14 @@ impl ExcType {
         SimpleException::new_msg(Self::ValueError, "empty separator").into()
     }
 
+    /// Creates a TypeError for a str method argument that must be a string.
+    ///
+    /// Matches CPython's format: `TypeError: must be str, not {type}`
+    #[must_use]
+    pub(crate) fn type_error_must_be_str(type_: Type) -> RunError {
+        SimpleException::new_msg(Self::TypeError, format!("must be str, not {type_}")).into()
+    }
+
     /// Creates a TypeError for fillchar argument that is not a single character.
     ///
     /// Matches CPython's format: `TypeError: The fill character must be exactly one character long`
@@ -1009,6 +1088,30 @@ impl ExcType {
         SimpleException::new_msg(Self::TypeError, "The fill character must be exactly one character long").into()
     }
 
+    /// Creates a TypeError for `str % args` when the template has more specifiers than arguments.
+    ///
+    /// Matches CPython's format: `TypeError: not enough arguments for format string`
+    #[must_use]
+    pub(crate) fn type_error_printf_not_enough_args() -> RunError {
+        SimpleException::new_msg(Self::TypeError, "not enough arguments for format string").into()
+    }
+
+    /// Creates a TypeError for `str % args` when some arguments were not consumed by the template.
+    ///
+    /// Matches CPython's format: `TypeError: not all arguments converted during string formatting`
+    #[must_use]
+    pub(crate) fn type_error_printf_not_all_converted() -> RunError {
+        SimpleException::new_msg(Self::TypeError, "not all arguments converted during string formatting").into()
+    }
+
+    /// Creates a TypeError for `bytes % args` when some arguments were not consumed by the template.
+    ///
+    /// Matches CPython's format: `TypeError: not all arguments converted during bytes formatting`
+    #[must_use]
+    pub(crate) fn type_error_bytes_printf_not_all_converted() -> RunError {
+        SimpleException::new_msg(Self::TypeError, "not all arguments converted during bytes formatting").into()
+    }
+
     /// Creates a StopIteration exception for when an iterator is exhausted.
     ///
     /// Matches CPython's format: `StopIteration`
@@ -1110,6 +1213,40 @@ impl ExcType {
 pub(crate) struct SimpleException {
     exc_type: ExcType,
     arg: Option<String>,
+    /// Traceback and chained exceptions, boxed since most exceptions have neither.
+    #[serde(default)]
+    chain: Option<Box<ExceptionChain>>,
+}
+
+/// Most exceptions kept in the `__cause__`/`__context__` chain of a single exception.
+///
+/// Chained exceptions are copied rather than shared, so without a limit a loop that raises
+/// while handling the previous iteration's exception would copy an ever longer chain.
+const MAX_CHAINED_EXCEPTIONS: usize = 32;
+
+/// The parts of an exception that only matter once it's chained to another exception.
+#[derive(Debug, Clone, Default, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+struct ExceptionChain {
+    /// Where the exception was raised, recorded when it's caught so the traceback can be
+    /// shown if it becomes the cause or context of another exception.
+    traceback: Option<RawStackFrame>,
+    /// The exception given by `raise ... from cause`.
+    cause: Option<SimpleException>,
+    /// The exception that was being handled when this one was raised.
+    context: Option<SimpleException>,
+    /// Set by `raise ... from`, hides the context when showing the exception.
+    suppress_context: bool,
+}
+
+impl ExceptionChain {
+    /// Number of exceptions in the cause and context chains.
+    fn len(&self) -> usize {
+        [&self.cause, &self.context]
+            .into_iter()
+            .flatten()
+            .map(|exc| 1 + exc.chain_len())
+            .sum()
+    }
 }
 
 impl fmt::Display for SimpleException {
@@ -1122,6 +1259,7 @@ impl From<MontyException> for SimpleException {
         Self {
             exc_type: exc.exc_type(),
             arg: exc.into_message(),
+            chain: None,
         }
     }
 }
@@ -1130,7 +1268,11 @@ impl SimpleException {
     /// Creates a new exception with the given type and optional argument message.
     #[must_use]
     pub fn new(exc_type: ExcType, arg: Option<String>) -> Self {
-        Self { exc_type, arg }
+        Self {
+            exc_type,
+            arg,
+            chain: None,
+        }
     }
 
     /// Creates a new exception with the given type and argument message.
@@ -1139,13 +1281,18 @@ impl SimpleException {
         Self {
             exc_type,
             arg: Some(arg.to_string()),
+            chain: None,
         }
     }
 
     /// Creates a new exception with the given type and no argument message.
     #[must_use]
     pub fn new_none(exc_type: ExcType) -> Self {
-        Self { exc_type, arg: None }
+        Self {
+            exc_type,
+            arg: None,
+            chain: None,
+        }
     }
 
     #[must_use]
@@ -1158,6 +1305,116 @@ impl SimpleException {
         self.arg.as_ref()
     }
 
+    /// The exception given by `raise ... from cause`, if any.
+    #[must_use]
+    pub fn cause(&self) -> Option<&Self> {
+        self.chain.as_ref().and_then(|chain| chain.cause.as_ref())
+    }
+
+    /// The exception that was being handled when this one was raised, if any.
+    #[must_use]
+    pub fn context(&self) -> Option<&Self> {
+        self.chain.as_ref().and_then(|chain| chain.context.as_ref())
+    }
+
+    /// Whether the context is hidden when showing the exception, set by `raise ... from`.
+    #[must_use]
+    pub fn suppress_context(&self) -> bool {
+        self.chain.as_ref().is_some_and(|chain| chain.suppress_context)
+    }
+
+    /// Sets the explicit cause from `raise ... from cause`, `None` for `raise ... from None`.
+    ///
+    /// Either way the context is no longer shown, matching CPython.
+    pub(crate) fn set_cause(&mut self, cause: Option<Self>) {
+        let chain = self.chain.get_or_insert_default();
+        chain.cause = cause;
+        chain.suppress_context = true;
+        self.limit_chain();
+    }
+
+    /// Sets the implicit context: the exception being handled when this one was raised.
+    pub(crate) fn set_context(&mut self, context: Self) {
+        self.chain.get_or_insert_default().context = Some(context);
+        self.limit_chain();
+    }
+
+    /// Records where the exception was raised, replacing any earlier traceback.
+    pub(crate) fn set_traceback(&mut self, frame: Option<RawStackFrame>) {
+        if frame.is_some() || self.chain.is_some() {
+            self.chain.get_or_insert_default().traceback = frame;
+        }
+    }
+
+    /// Number of exceptions in this exception's cause and context chains.
+    fn chain_len(&self) -> usize {
+        self.chain.as_ref().map_or(0, |chain| chain.len())
+    }
+
+    /// Keeps the chain within `MAX_CHAINED_EXCEPTIONS` by dropping the chains of the context,
+    /// then of the cause, while keeping those two exceptions.
+    fn limit_chain(&mut self) {
+        let Some(chain) = self.chain.as_deref_mut() else {
+            return;
+        };
+        if chain.len() > MAX_CHAINED_EXCEPTIONS
+            && let Some(context) = &mut chain.context
+        {
+            context.clear_links();
+        }
+        if chain.len() > MAX_CHAINED_EXCEPTIONS
+            && let Some(cause) = &mut chain.cause
+        {
+            cause.clear_links();
+        }
+    }
+
+    /// Drops the cause and context, keeping the traceback.
+    fn clear_links(&mut self) {
+        if let Some(chain) = &mut self.chain {
+            chain.cause = None;
+            chain.context = None;
+        }
+    }
+
+    /// Converts the exception and its chain to a `MontyException` for the public API.
+    ///
+    /// Chained exceptions use the traceback recorded when they were caught.
+    fn into_python_exception(self, traceback: Vec<StackFrame>, interns: &Interns, source: &str) -> MontyException {
+        let exc = MontyException::new_full(self.exc_type, self.arg, traceback);
+        let Some(chain) = self.chain else {
+            return exc;
+        };
+        let convert = |linked: Self| {
+            let traceback = stack_frames(
+                linked.chain.as_ref().and_then(|c| c.traceback.as_ref()),
+                interns,
+                source,
+            );
+            linked.into_python_exception(traceback, interns, source)
+        };
+        let ExceptionChain {
+            cause,
+            context,
+            suppress_context,
+            ..
+        } = *chain;
+        exc.with_chain(cause.map(convert), context.map(convert), suppress_context)
+    }
+
+    /// Estimates the memory used by the exception, including its chain.
+    pub(crate) fn py_estimate_size(&self) -> usize {
+        let chain_size = self.chain.as_ref().map_or(0, |chain| {
+            std::mem::size_of::<ExceptionChain>()
+                + [&chain.cause, &chain.context]
+                    .into_iter()
+                    .flatten()
+                    .map(Self::py_estimate_size)
+                    .sum::<usize>()
+        });
+        std::mem::size_of::<Self>() + self.arg.as_ref().map_or(0, String::len) + chain_size
+    }
+
     /// str() for an exception
     #[must_use]
     pub fn py_str(&self) -> String {
@@ -1203,7 +1460,9 @@ impl SimpleException {
 
     /// Gets an attribute from this exception.
     ///
-    /// Handles the `.args` attribute by allocating a tuple containing the message.
+    /// Handles the `.args` attribute by allocating a tuple containing the message, and the
+    /// chaining attributes `__cause__`, `__context__` and `__suppress_context__`. Chained
+    /// exceptions are copied to new heap objects, so they don't keep their identity.
     /// Returns `Err(AttributeError)` for all other attributes.
     pub fn py_getattr(
         &self,
@@ -1220,12 +1479,26 @@ impl SimpleException {
                 smallvec![]
             };
             Ok(Some(AttrCallResult::Value(allocate_tuple(elements, heap)?)))
+        } else if attr_id == StaticStrings::DunderCause {
+            Ok(Some(AttrCallResult::Value(allocate_chained(self.cause(), heap)?)))
+        } else if attr_id == StaticStrings::DunderContext {
+            Ok(Some(AttrCallResult::Value(allocate_chained(self.context(), heap)?)))
+        } else if attr_id == StaticStrings::DunderSuppressContext {
+            Ok(Some(AttrCallResult::Value(Value::Bool(self.suppress_context()))))
         } else {
             Ok(None)
         }
     }
 }
 
+/// Copies a chained exception to the heap, or returns `None` if there isn't one.
+fn allocate_chained(exc: Option<&SimpleException>, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
+    match exc {
+        Some(exc) => Ok(Value::Ref(heap.allocate(HeapData::Exception(exc.clone()))?)),
+        None => Ok(Value::None),
+    }
+}
+
 /// A raised exception with optional stack frame for traceback.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ExceptionRaise {
@@ -1307,30 +1580,29 @@ impl ExceptionRaise {
     /// Extracts preview lines from the source code for traceback display.
     #[must_use]
     pub fn into_python_exception(self, interns: &Interns, source: &str) -> MontyException {
-        let traceback = self
-            .frame
-            .map(|frame| {
-                let mut frames = Vec::new();
-                let mut current = Some(&frame);
-                while let Some(f) = current {
-                    frames.push(StackFrame::from_raw(f, interns, source));
-                    current = f.parent.as_deref();
-                }
-                // Reverse so outermost frame is first (Python's "most recent call last" ordering)
-                frames.reverse();
-                frames
-            })
-            .unwrap_or_default();
-
-        MontyException::new_full(self.exc.exc_type(), self.exc.arg().cloned(), traceback)
+        let traceback = stack_frames(self.frame.as_ref(), interns, source);
+        self.exc.into_python_exception(traceback, interns, source)
     }
 }
 
+/// Converts a chain of raw frames to the public traceback, outermost frame first.
+fn stack_frames(frame: Option<&RawStackFrame>, interns: &Interns, source: &str) -> Vec<StackFrame> {
+    let mut frames = Vec::new();
+    let mut current = frame;
+    while let Some(f) = current {
+        frames.push(StackFrame::from_raw(f, interns, source));
+        current = f.parent.as_deref();
+    }
+    // Reverse so outermost frame is first (Python's "most recent call last" ordering)
+    frames.reverse();
+    frames
+}
+
 /// A stack frame for traceback information.
 ///
 /// Stores position information and optional function name as StringId.
 /// The actual name string must be looked up externally when formatting the traceback.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct RawStackFrame {
     pub position: CodeRange,
     /// The name of the frame (function name StringId, or None for module-level code).
@@ -1444,8 +1716,8 @@ impl RunError {
 /// Examples:
 /// - `["a"]` -> `'a'`
 /// - `["a", "b"]` -> `'a' and 'b'`
-/// - `["a", "b", "c"]` -> `'a', 'b' and 'c'`
-fn format_param_names(names: &[&str]) -> String {
+/// - `["a", "b", "c"]` -> `'a', 'b', and 'c'`
+pub(crate) fn format_param_names(names: &[&str]) -> String {
     match names.len() {
         0 => String::new(),
         1 => format!("'{}'", names[0]),
@@ -1453,7 +1725,7 @@ fn format_param_names(names: &[&str]) -> String {
         _ => {
             let last = names.last().unwrap();
             let rest: Vec<_> = names[..names.len() - 1].iter().map(|n| format!("'{n}'")).collect();
-            format!("{} and '{last}'", rest.join(", "))
+            format!("{}, and '{last}'", rest.join(", "))
         }
     }
 }
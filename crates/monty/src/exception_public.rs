@@ -16,6 +16,12 @@ pub struct MontyException {
     message: Option<String>,
     /// Stack trace of the exception, first is the outermost frame shown first in the traceback
     traceback: Vec<StackFrame>,
+    /// The exception given by `raise ... from cause`
+    cause: Option<Box<Self>>,
+    /// The exception that was being handled when this one was raised
+    context: Option<Box<Self>>,
+    /// Whether the context is hidden in the traceback, set by `raise ... from`
+    suppress_context: bool,
 }
 
 /// Number of identical consecutive frames to show before collapsing.
@@ -26,6 +32,19 @@ const REPEAT_FRAMES_SHOWN: usize = 3;
 /// Display implementation for MontyException should exactly match python traceback format.
 impl fmt::Display for MontyException {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Like CPython, print the chained exception first
+        if let Some(cause) = &self.cause {
+            write!(
+                f,
+                "{cause}\n\nThe above exception was the direct cause of the following exception:\n\n"
+            )?;
+        } else if let Some(context) = self.context.as_ref().filter(|_| !self.suppress_context) {
+            write!(
+                f,
+                "{context}\n\nDuring handling of the above exception, another exception occurred:\n\n"
+            )?;
+        }
+
         // Print the traceback header if we have frames
         if !self.traceback.is_empty() {
             writeln!(f, "Traceback (most recent call last):")?;
@@ -81,6 +100,9 @@ impl MontyException {
             exc_type,
             message,
             traceback: vec![],
+            cause: None,
+            context: None,
+            suppress_context: false,
         }
     }
 
@@ -114,6 +136,28 @@ impl MontyException {
         &self.traceback
     }
 
+    /// The exception given by `raise ... from cause`, equivalent of python's `exc.__cause__`.
+    #[must_use]
+    pub fn cause(&self) -> Option<&Self> {
+        self.cause.as_deref()
+    }
+
+    /// The exception that was being handled when this one was raised, equivalent of python's
+    /// `exc.__context__`.
+    #[must_use]
+    pub fn context(&self) -> Option<&Self> {
+        self.context.as_deref()
+    }
+
+    /// Whether the context is hidden in the traceback, equivalent of python's
+    /// `exc.__suppress_context__`.
+    ///
+    /// Set by `raise ... from`, including `raise ... from None`.
+    #[must_use]
+    pub fn suppress_context(&self) -> bool {
+        self.suppress_context
+    }
+
     /// Returns a compact summary of the exception.
     ///
     /// Format: `ExceptionType: message` (e.g., `NotImplementedError: feature not supported`)
@@ -146,14 +190,27 @@ impl MontyException {
             exc_type,
             message,
             traceback,
+            cause: None,
+            context: None,
+            suppress_context: false,
         }
     }
 
+    pub(crate) fn with_chain(mut self, cause: Option<Self>, context: Option<Self>, suppress_context: bool) -> Self {
+        self.cause = cause.map(Box::new);
+        self.context = context.map(Box::new);
+        self.suppress_context = suppress_context;
+        self
+    }
+
     pub(crate) fn runtime_error(err: impl fmt::Display) -> Self {
         Self {
             exc_type: ExcType::RuntimeError,
             message: Some(err.to_string()),
             traceback: vec![],
+            cause: None,
+            context: None,
+            suppress_context: false,
         }
     }
 }
@@ -199,6 +199,11 @@ pub enum Expr {
     /// Raises `TypeError` for non-awaitable values.
     /// Unlike standard Python, `await` is allowed at module level (like Jupyter notebooks).
     Await(Box<ExprLoc>),
+    /// Yield expression, only produced for the body of a generator expression.
+    ///
+    /// Suspends the generator, handing the value to whoever advanced it. The source
+    /// language's `yield` is still rejected by the parser.
+    Yield(Box<ExprLoc>),
     /// F-string expression containing literal and interpolated parts.
     ///
     /// At evaluation time, each part is processed in sequence:
@@ -224,6 +229,13 @@ pub enum Expr {
         elt: Box<ExprLoc>,
         generators: Vec<Comprehension>,
     },
+    /// Generator expression: `(elt for target in iter if cond...)`
+    ///
+    /// Only present in parsed form. During preparation it is converted to `Expr::Generator`.
+    GeneratorExp {
+        elt: Box<ExprLoc>,
+        generators: Vec<Comprehension>,
+    },
     /// Set comprehension: `{elt for target in iter if cond...}`
     ///
     /// Builds a new set by iterating and optionally filtering. Duplicate values
@@ -266,6 +278,19 @@ pub enum Expr {
         /// The body is wrapped as `[Node::Return(body_expr)]` during preparation.
         func_def: Box<PreparedFunctionDef>,
     },
+    /// Generator expression (prepared form).
+    ///
+    /// Like CPython, the expression becomes a hidden `<genexpr>` function whose only
+    /// parameter, `.0`, is an iterator over the outermost iterable. That iterable is
+    /// evaluated in the enclosing scope; calling the function creates a generator
+    /// which computes each item only when it is asked for.
+    Generator {
+        /// The hidden function. Its body is the comprehension rewritten as nested
+        /// `for` and `if` statements around `yield elt`.
+        func_def: Box<PreparedFunctionDef>,
+        /// The outermost iterable, prepared in the enclosing scope.
+        iter: Box<ExprLoc>,
+    },
     /// Named expression (walrus operator): `(target := value)`
     ///
     /// Evaluates `value`, assigns it to `target`, and returns the value as the
@@ -335,6 +360,7 @@ impl Expr {
 pub enum Literal {
     Ellipsis,
     None,
+    NotImplemented,
     Bool(bool),
     Int(i64),
     Float(f64),
@@ -358,6 +384,7 @@ impl From<Literal> for Value {
         match literal {
             Literal::Ellipsis => Self::Ellipsis,
             Literal::None => Self::None,
+            Literal::NotImplemented => Self::NotImplemented,
             Literal::Bool(b) => Self::Bool(b),
             Literal::Int(v) => Self::Int(v),
             Literal::Float(v) => Self::Float(v),
@@ -398,6 +425,11 @@ pub enum Node<F> {
     Return(ExprLoc),
     ReturnNone,
     Raise(Option<ExprLoc>),
+    /// `raise exc from cause`, where `cause` may be `None` to hide the exception's context.
+    RaiseFrom {
+        exc: ExprLoc,
+        cause: ExprLoc,
+    },
     Assert {
         test: ExprLoc,
         msg: Option<ExprLoc>,
@@ -417,6 +449,14 @@ pub enum Node<F> {
         targets_position: CodeRange,
         object: ExprLoc,
     },
+    /// Chained assignment (e.g., `a = b = 0` or `x = (y, z) = pair`).
+    ///
+    /// The right-hand side is evaluated once, then stored to each target from left to right.
+    /// Each target can be a name or an unpacking pattern.
+    ChainAssign {
+        targets: Vec<UnpackTarget>,
+        object: ExprLoc,
+    },
     OpAssign {
         target: Identifier,
         op: Operator,
@@ -429,6 +469,18 @@ pub enum Node<F> {
         /// Position of the subscript expression (e.g., `lst[10]`) for traceback carets.
         target_position: CodeRange,
     },
+    /// Augmented subscript assignment (e.g., `counts[key] += 1`).
+    ///
+    /// The container and index are evaluated once, then used for both the load and the store.
+    /// The container can be any expression, so nested targets like `grid[i][j] += 1` work.
+    SubscriptOpAssign {
+        object: ExprLoc,
+        index: ExprLoc,
+        op: Operator,
+        value: ExprLoc,
+        /// Position of the subscript expression (e.g., `lst[10]`) for traceback carets.
+        target_position: CodeRange,
+    },
     /// Attribute assignment (e.g., `point.x = 5` or `a.b.c = 5`).
     ///
     /// Assigns a value to an attribute on an object. For mutable dataclasses,
@@ -440,6 +492,17 @@ pub enum Node<F> {
         target_position: CodeRange,
         value: ExprLoc,
     },
+    /// Augmented attribute assignment (e.g., `point.x += 1`).
+    ///
+    /// The object is evaluated once, then used for both the attribute load and the store.
+    AttrOpAssign {
+        object: ExprLoc,
+        attr: EitherStr,
+        op: Operator,
+        value: ExprLoc,
+        /// Position of the attribute expression (e.g., `point.x`) for traceback carets.
+        target_position: CodeRange,
+    },
     For {
         /// Loop target - either a single identifier or tuple unpacking pattern.
         target: UnpackTarget,
@@ -565,6 +628,11 @@ pub struct PreparedFunctionDef {
     /// When true, calling this function creates a `Coroutine` object instead of
     /// immediately pushing a frame.
     pub is_async: bool,
+    /// Whether this is the hidden function of a generator expression.
+    ///
+    /// When true, calling this function creates a `Generator` object which runs the
+    /// body one item at a time, instead of immediately pushing a frame.
+    pub is_generator: bool,
 }
 
 /// Type alias for prepared AST nodes (output of prepare phase).
@@ -623,4 +691,6 @@ pub enum CmpOperator {
     NotIn,
     // we should support floats too, either via a Number type, or ModEqInt and ModEqFloat
     ModEq(i64),
+    /// `left < len(right)`, fused so loop conditions like `i < len(items)` skip the builtin call.
+    LtLen,
 }
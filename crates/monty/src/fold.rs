@@ -0,0 +1,179 @@
+//! Compile-time constant folding.
+//!
+//! Expressions whose operands are all literals, like `60 * 60 * 24` or `'a' + 'b'`, are
+//! evaluated while parsing and replaced by a single literal. Folding only ever
+//! happens when the result is exactly what the runtime would compute:
+//!
+//! - Operations that raise at runtime (`1 / 0`, `10 % 0`, `2 ** -1` on a zero base, ...) are
+//!   left alone so the error is raised when the code runs, with the usual traceback.
+//! - Results larger than [`MAX_FOLDED_INT_BITS`] or [`MAX_FOLDED_STR_LEN`] are left alone so
+//!   resource limits still apply to them. Without the caps, chains like `s + s + s + ...` or
+//!   repeated `**` would allocate unbounded memory before the code even runs.
+//! - Int arithmetic that overflows i64 produces a `LongInt` literal, and `LongInt` results
+//!   that fit in i64 are demoted, mirroring `LongInt::into_value`.
+
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::ToPrimitive;
+
+use crate::{
+    expressions::{CmpOperator, Expr, Literal, Operator},
+    intern::InternerBuilder,
+    value::float_divmod,
+};
+
+/// Largest `LongInt` result, in bits, that folding will produce. Matches CPython's limit.
+const MAX_FOLDED_INT_BITS: u64 = 128;
+
+/// Largest string result, in bytes, that folding will produce. Matches CPython's limit.
+const MAX_FOLDED_STR_LEN: usize = 4096;
+
+/// Evaluates `expr` if it is an operation on literals, returning the resulting literal.
+///
+/// Only looks at the top level of `expr`: operands are expected to have been folded
+/// already, which happens naturally since the parser builds expressions bottom-up.
+pub(crate) fn fold_expr(expr: &Expr, interner: &mut InternerBuilder) -> Option<Literal> {
+    match expr {
+        Expr::Op { left, op, right } => {
+            let (Expr::Literal(left), Expr::Literal(right)) = (&left.expr, &right.expr) else {
+                return None;
+            };
+            fold_binary(*left, op, *right, interner)
+        }
+        Expr::CmpOp { left, op, right } => {
+            let (Expr::Literal(left), Expr::Literal(right)) = (&left.expr, &right.expr) else {
+                return None;
+            };
+            fold_compare(*left, op, *right, interner)
+        }
+        Expr::UnaryMinus(operand) => match operand.expr {
+            Expr::Literal(Literal::Int(v)) => int_literal(-BigInt::from(v), interner),
+            Expr::Literal(Literal::Float(v)) => Some(Literal::Float(-v)),
+            Expr::Literal(Literal::LongInt(id)) => {
+                let negated = -interner.get_long_int(id);
+                int_literal(negated, interner)
+            }
+            _ => None,
+        },
+        Expr::UnaryPlus(operand) => match operand.expr {
+            Expr::Literal(literal @ (Literal::Int(_) | Literal::Float(_) | Literal::LongInt(_))) => Some(literal),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Folds a binary operation on two literals.
+fn fold_binary(left: Literal, op: &Operator, right: Literal, interner: &mut InternerBuilder) -> Option<Literal> {
+    match (left, right) {
+        (Literal::Int(a), Literal::Int(b)) => fold_int(a, op, b, interner),
+        (Literal::Float(a), Literal::Float(b)) => fold_float(a, op, b),
+        (Literal::Int(a), Literal::Float(b)) => fold_float(a as f64, op, b),
+        (Literal::Float(a), Literal::Int(b)) => fold_float(a, op, b as f64),
+        (Literal::LongInt(_), Literal::Int(_) | Literal::LongInt(_)) | (Literal::Int(_), Literal::LongInt(_)) => {
+            let a = literal_bigint(left, interner)?;
+            let b = literal_bigint(right, interner)?;
+            let result = match op {
+                Operator::Add => a + b,
+                Operator::Sub => a - b,
+                Operator::Mult => a * b,
+                _ => return None,
+            };
+            int_literal(result, interner)
+        }
+        (Literal::Str(a), Literal::Str(b)) if op == &Operator::Add => {
+            let (a, b) = (interner.get_str(a), interner.get_str(b));
+            if a.len() + b.len() > MAX_FOLDED_STR_LEN {
+                return None;
+            }
+            let concat = format!("{a}{b}");
+            Some(Literal::Str(interner.intern(&concat)))
+        }
+        _ => None,
+    }
+}
+
+/// Folds a binary operation on two i64 literals, promoting to `LongInt` on overflow.
+///
+/// Operations are done on BigInts, the results are small enough that this doesn't matter
+/// and it means overflow never needs special casing.
+fn fold_int(a: i64, op: &Operator, b: i64, interner: &mut InternerBuilder) -> Option<Literal> {
+    let (x, y) = (BigInt::from(a), BigInt::from(b));
+    let result = match op {
+        Operator::Add => x + y,
+        Operator::Sub => x - y,
+        Operator::Mult => x * y,
+        // Division by zero is left for the runtime to raise
+        Operator::Div if b != 0 => return Some(Literal::Float(a as f64 / b as f64)),
+        Operator::FloorDiv if b != 0 => x.div_floor(&y),
+        Operator::Mod if b != 0 => x.mod_floor(&y),
+        // Only small results are folded, so big powers stay subject to resource limits
+        Operator::Pow => return a.checked_pow(u32::try_from(b).ok()?).map(Literal::Int),
+        Operator::BitAnd => x & y,
+        Operator::BitOr => x | y,
+        Operator::BitXor => x ^ y,
+        _ => return None,
+    };
+    int_literal(result, interner)
+}
+
+/// Folds a binary operation on two floats (ints having been converted like at runtime).
+fn fold_float(a: f64, op: &Operator, b: f64) -> Option<Literal> {
+    let result = match op {
+        Operator::Add => a + b,
+        Operator::Sub => a - b,
+        Operator::Mult => a * b,
+        // Division by zero is left for the runtime to raise
+        Operator::Div if b != 0.0 => a / b,
+        Operator::FloorDiv if b != 0.0 => float_divmod(a, b).0,
+        Operator::Mod if b != 0.0 => float_divmod(a, b).1,
+        _ => return None,
+    };
+    Some(Literal::Float(result))
+}
+
+/// Folds a comparison between two literals of the same type.
+///
+/// Mixed int/float comparisons are left to the runtime so there is a single
+/// implementation of their semantics.
+fn fold_compare(left: Literal, op: &CmpOperator, right: Literal, interner: &InternerBuilder) -> Option<Literal> {
+    let ordering = match (left, right) {
+        (Literal::Int(a), Literal::Int(b)) => a.partial_cmp(&b),
+        (Literal::Float(a), Literal::Float(b)) => a.partial_cmp(&b),
+        (Literal::Str(a), Literal::Str(b)) => interner.get_str(a).partial_cmp(interner.get_str(b)),
+        _ => return None,
+    };
+    // NaN compares unequal to everything and is neither less nor greater
+    let result = match op {
+        CmpOperator::Eq => ordering == Some(Ordering::Equal),
+        CmpOperator::NotEq => ordering != Some(Ordering::Equal),
+        CmpOperator::Lt => ordering.is_some_and(Ordering::is_lt),
+        CmpOperator::LtE => ordering.is_some_and(Ordering::is_le),
+        CmpOperator::Gt => ordering.is_some_and(Ordering::is_gt),
+        CmpOperator::GtE => ordering.is_some_and(Ordering::is_ge),
+        _ => return None,
+    };
+    Some(Literal::Bool(result))
+}
+
+/// Returns the value of an int or `LongInt` literal as a BigInt.
+fn literal_bigint(literal: Literal, interner: &InternerBuilder) -> Option<BigInt> {
+    match literal {
+        Literal::Int(v) => Some(BigInt::from(v)),
+        Literal::LongInt(id) => Some(interner.get_long_int(id).clone()),
+        _ => None,
+    }
+}
+
+/// Creates an int literal, using `Literal::Int` if the value fits in i64.
+///
+/// Returns `None` if the value is larger than [`MAX_FOLDED_INT_BITS`].
+fn int_literal(value: BigInt, interner: &mut InternerBuilder) -> Option<Literal> {
+    match value.to_i64() {
+        Some(v) => Some(Literal::Int(v)),
+        None if value.bits() <= MAX_FOLDED_INT_BITS => Some(Literal::LongInt(interner.intern_long_int(value))),
+        None => None,
+    }
+}
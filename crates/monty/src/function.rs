@@ -63,6 +63,12 @@ pub(crate) struct Function {
     /// immediately pushing a frame. The coroutine captures the bound arguments
     /// and starts execution only when awaited.
     pub is_async: bool,
+    /// Whether this is the hidden function of a generator expression.
+    ///
+    /// When true, calling this function creates a `Generator` object instead of
+    /// immediately pushing a frame. The body runs a step at a time as the
+    /// generator is advanced.
+    pub is_generator: bool,
     /// Compiled bytecode for this function body.
     pub code: Code,
 }
@@ -81,6 +87,7 @@ impl Function {
     /// * `cell_param_indices` - Maps cell indices to parameter indices for captured parameters
     /// * `defaults_count` - Number of default parameter values
     /// * `is_async` - Whether this is an async function
+    /// * `is_generator` - Whether this is a generator expression's function
     /// * `code` - The compiled bytecode for the function body
     #[expect(clippy::too_many_arguments)]
     pub fn new(
@@ -92,6 +99,7 @@ impl Function {
         cell_param_indices: Vec<Option<usize>>,
         defaults_count: usize,
         is_async: bool,
+        is_generator: bool,
         code: Code,
     ) -> Self {
         Self {
@@ -103,17 +111,16 @@ impl Function {
             cell_param_indices,
             defaults_count,
             is_async,
+            is_generator,
             code,
         }
     }
 
     /// Writes the Python repr() string for this function to a formatter.
+    ///
+    /// Matches CPython's `<function foo at 0x...>`, where `py_id` is the `id()` of the function
+    /// value. Nested functions show their plain name rather than CPython's qualified name.
     pub fn py_repr_fmt<W: Write>(&self, f: &mut W, interns: &Interns, py_id: usize) -> std::fmt::Result {
-        write!(
-            f,
-            "<function '{}' at 0x{:x}>",
-            interns.get_str(self.name.name_id),
-            py_id
-        )
+        write!(f, "<function {} at 0x{py_id:x}>", interns.get_str(self.name.name_id))
     }
 }
@@ -16,13 +16,14 @@ use crate::{
     args::ArgValues,
     asyncio::{Coroutine, GatherFuture, GatherItem},
     exception_private::{ExcType, RunResult, SimpleException},
-    intern::{FunctionId, Interns, StringId},
+    intern::{FunctionId, Interns, StaticStrings, StringId},
     resource::{DepthGuard, ResourceError, ResourceTracker, check_mult_size, check_repeat_size},
     types::{
-        AttrCallResult, Bytes, Dataclass, Dict, FrozenSet, List, LongInt, Module, MontyIter, NamedTuple, Path, PyTrait,
-        Range, Set, Slice, Str, Tuple, Type, allocate_tuple,
+        AttrCallResult, ByteArray, Bytes, ContextManager, Dataclass, Dict, File, FrozenSet, Generator, Getter, List,
+        LongInt, Module, MontyIter, NamedTuple, Path, PyTrait, Range, ReMatch, RePattern, Set, Slice, Str, Tuple, Type,
+        allocate_tuple, bytearray, contextlib, file, re, tuple::hash_tuple_items,
     },
-    value::{EitherStr, Value},
+    value::{EitherStr, Value, bytes_hash, str_hash},
 };
 
 /// Unique identifier for values stored inside the heap arena.
@@ -52,6 +53,8 @@ const EMPTY_TUPLE_ID: HeapId = HeapId(0);
 pub(crate) enum HeapData {
     Str(Str),
     Bytes(Bytes),
+    /// A `bytearray`, the mutable counterpart of `Bytes`.
+    ByteArray(ByteArray),
     List(List),
     Tuple(Tuple),
     NamedTuple(NamedTuple),
@@ -120,6 +123,11 @@ pub(crate) enum HeapData {
     /// Contains pre-bound arguments and captured cells, ready to be awaited.
     /// When awaited, a new frame is pushed using the stored namespace.
     Coroutine(Coroutine),
+    /// A generator object from a generator expression.
+    ///
+    /// Holds the suspended frame's namespace and operand stack between items. The VM
+    /// pushes a frame from this state each time the generator is advanced.
+    Generator(Generator),
     /// A gather() result tracking multiple coroutines/tasks.
     ///
     /// Created by asyncio.gather() and spawns tasks when awaited.
@@ -130,6 +138,19 @@ pub(crate) enum HeapData {
     /// Pure methods (name, parent, etc.) are handled directly by the VM.
     /// I/O methods (exists, read_text, etc.) yield external function calls.
     Path(Path),
+    /// A file object returned by the `open()` builtin.
+    ///
+    /// Holds the opaque handle the host returned for `open()`. All reads, writes
+    /// and closing yield external function calls keyed by that handle.
+    File(File),
+    /// A compiled regular expression from `re.compile()`.
+    RePattern(RePattern),
+    /// The result of a successful `re` match.
+    ReMatch(ReMatch),
+    /// A callable from `operator.itemgetter()` or `operator.attrgetter()`.
+    Getter(Getter),
+    /// A context manager from `contextlib.suppress()` or `contextlib.nullcontext()`.
+    ContextManager(ContextManager),
 }
 
 impl HeapData {
@@ -158,7 +179,10 @@ impl HeapData {
                 | Self::Iter(_)
                 | Self::Module(_)
                 | Self::Coroutine(_)
+                | Self::Generator(_)
                 | Self::GatherFuture(_)
+                | Self::Getter(_)
+                | Self::ContextManager(_)
         )
     }
 
@@ -187,10 +211,13 @@ impl HeapData {
             Self::Dataclass(dc) => dc.has_refs(),
             Self::Iter(iter) => iter.has_refs(),
             Self::Module(m) => m.has_refs(),
+            Self::Getter(getter) => getter.has_refs(),
+            Self::ContextManager(manager) => manager.has_refs(),
             // Coroutines always have refs (namespace values, frame_cells)
             Self::Coroutine(coro) => {
                 !coro.frame_cells.is_empty() || coro.namespace.iter().any(|v| matches!(v, Value::Ref(_)))
             }
+            Self::Generator(generator) => generator.has_refs(),
             // GatherFutures have refs from coroutine items and results
             Self::GatherFuture(gather) => {
                 gather
@@ -205,11 +232,15 @@ impl HeapData {
             // Leaf types cannot have refs
             Self::Str(_)
             | Self::Bytes(_)
+            | Self::ByteArray(_)
             | Self::Range(_)
             | Self::Slice(_)
             | Self::Exception(_)
             | Self::LongInt(_)
-            | Self::Path(_) => false,
+            | Self::Path(_)
+            | Self::File(_)
+            | Self::RePattern(_)
+            | Self::ReMatch(_) => false,
         }
     }
 
@@ -230,40 +261,15 @@ impl HeapData {
         match self {
             // Hash just the actual string or bytes content for consistency with Value::InternString/InternBytes
             // hence we don't include the discriminant
-            Self::Str(s) => {
-                let mut hasher = DefaultHasher::new();
-                s.as_str().hash(&mut hasher);
-                Some(hasher.finish())
-            }
-            Self::Bytes(b) => {
-                let mut hasher = DefaultHasher::new();
-                b.as_slice().hash(&mut hasher);
-                Some(hasher.finish())
-            }
+            Self::Str(s) => Some(str_hash(s.as_str())),
+            Self::Bytes(b) => Some(bytes_hash(b.as_slice())),
             Self::FrozenSet(fs) => {
                 // FrozenSet hash is XOR of element hashes (order-independent)
                 fs.compute_hash(heap, interns)
             }
-            Self::Tuple(t) => {
-                let mut hasher = DefaultHasher::new();
-                discriminant(self).hash(&mut hasher);
-                // Tuple is hashable only if all elements are hashable
-                for obj in t.as_slice() {
-                    let h = obj.py_hash(heap, interns)?;
-                    h.hash(&mut hasher);
-                }
-                Some(hasher.finish())
-            }
-            Self::NamedTuple(nt) => {
-                let mut hasher = DefaultHasher::new();
-                discriminant(self).hash(&mut hasher);
-                // Hash only by elements (not type_name) to match equality semantics
-                for obj in nt.as_vec() {
-                    let h = obj.py_hash(heap, interns)?;
-                    h.hash(&mut hasher);
-                }
-                Some(hasher.finish())
-            }
+            Self::Tuple(t) => hash_tuple_items(t.as_slice(), heap, interns),
+            // Hash only by elements (not type_name) to match equality with tuples
+            Self::NamedTuple(nt) => hash_tuple_items(nt.as_vec(), heap, interns),
             Self::Closure(f, _, _) | Self::FunctionDefaults(f, _) => {
                 let mut hasher = DefaultHasher::new();
                 discriminant(self).hash(&mut hasher);
@@ -297,17 +303,29 @@ impl HeapData {
                 path.as_str().hash(&mut hasher);
                 Some(hasher.finish())
             }
-            // Mutable types, exceptions, iterators, modules, and async types cannot be hashed
+            // Patterns are immutable and hashable, equal patterns have the same source and flags
+            Self::RePattern(pattern) => {
+                let mut hasher = DefaultHasher::new();
+                discriminant(self).hash(&mut hasher);
+                pattern.hash_key().hash(&mut hasher);
+                Some(hasher.finish())
+            }
+            // Mutable types, exceptions, iterators, modules, files, matches, and async types cannot be hashed
             // (Cell is handled specially in get_or_compute_hash)
             Self::List(_)
+            | Self::ByteArray(_)
             | Self::Dict(_)
             | Self::Set(_)
             | Self::Cell(_)
             | Self::Exception(_)
             | Self::Iter(_)
             | Self::Module(_)
+            | Self::File(_)
+            | Self::ReMatch(_)
             | Self::Coroutine(_)
             | Self::GatherFuture(_) => None,
+            // Getters, context managers and generators use identity-based hashing, handled in get_or_compute_hash
+            Self::Getter(_) | Self::ContextManager(_) | Self::Generator(_) => None,
             // LongInt is immutable and hashable
             Self::LongInt(li) => Some(li.hash()),
         }
@@ -323,6 +341,7 @@ impl PyTrait for HeapData {
         match self {
             Self::Str(s) => s.py_type(heap),
             Self::Bytes(b) => b.py_type(heap),
+            Self::ByteArray(b) => b.py_type(heap),
             Self::List(l) => l.py_type(heap),
             Self::Tuple(t) => t.py_type(heap),
             Self::NamedTuple(nt) => nt.py_type(heap),
@@ -340,7 +359,13 @@ impl PyTrait for HeapData {
             Self::LongInt(_) => Type::Int,
             Self::Module(_) => Type::Module,
             Self::Coroutine(_) | Self::GatherFuture(_) => Type::Coroutine,
+            Self::Generator(_) => Type::Generator,
             Self::Path(p) => p.py_type(heap),
+            Self::File(file) => file.py_type(heap),
+            Self::RePattern(pattern) => pattern.py_type(heap),
+            Self::ReMatch(re_match) => re_match.py_type(heap),
+            Self::Getter(getter) => getter.py_type(heap),
+            Self::ContextManager(manager) => manager.py_type(heap),
         }
     }
 
@@ -348,20 +373,24 @@ impl PyTrait for HeapData {
         match self {
             Self::Str(s) => s.py_estimate_size(),
             Self::Bytes(b) => b.py_estimate_size(),
+            Self::ByteArray(b) => b.py_estimate_size(),
             Self::List(l) => l.py_estimate_size(),
             Self::Tuple(t) => t.py_estimate_size(),
             Self::NamedTuple(nt) => nt.py_estimate_size(),
             Self::Dict(d) => d.py_estimate_size(),
             Self::Set(s) => s.py_estimate_size(),
             Self::FrozenSet(fs) => fs.py_estimate_size(),
-            // TODO: should include size of captured cells and defaults
-            Self::Closure(_, _, _) | Self::FunctionDefaults(_, _) => 0,
+            // Captured cells are heap entries of their own, so only their ids count here
+            Self::Closure(_, cells, defaults) => {
+                cells.capacity() * std::mem::size_of::<HeapId>() + defaults.capacity() * std::mem::size_of::<Value>()
+            }
+            Self::FunctionDefaults(_, defaults) => defaults.capacity() * std::mem::size_of::<Value>(),
             Self::Cell(v) => std::mem::size_of::<Value>() + v.py_estimate_size(),
             Self::Range(_) => std::mem::size_of::<Range>(),
             Self::Slice(s) => s.py_estimate_size(),
-            Self::Exception(e) => std::mem::size_of::<SimpleException>() + e.arg().map_or(0, String::len),
+            Self::Exception(e) => e.py_estimate_size(),
             Self::Dataclass(dc) => dc.py_estimate_size(),
-            Self::Iter(_) => std::mem::size_of::<MontyIter>(),
+            Self::Iter(iter) => iter.estimate_size(),
             Self::LongInt(li) => li.estimate_size(),
             Self::Module(m) => std::mem::size_of::<Module>() + m.attrs().py_estimate_size(),
             Self::Coroutine(coro) => {
@@ -369,6 +398,7 @@ impl PyTrait for HeapData {
                     + coro.namespace.len() * std::mem::size_of::<Value>()
                     + coro.frame_cells.len() * std::mem::size_of::<HeapId>()
             }
+            Self::Generator(generator) => generator.estimate_size(),
             Self::GatherFuture(gather) => {
                 std::mem::size_of::<GatherFuture>()
                     + gather.items.len() * std::mem::size_of::<crate::asyncio::GatherItem>()
@@ -376,6 +406,11 @@ impl PyTrait for HeapData {
                     + gather.pending_calls.len() * std::mem::size_of::<crate::asyncio::CallId>()
             }
             Self::Path(p) => p.py_estimate_size(),
+            Self::File(file) => file.py_estimate_size(),
+            Self::RePattern(pattern) => pattern.py_estimate_size(),
+            Self::ReMatch(re_match) => re_match.py_estimate_size(),
+            Self::Getter(getter) => getter.py_estimate_size(),
+            Self::ContextManager(manager) => manager.py_estimate_size(),
         }
     }
 
@@ -383,6 +418,7 @@ impl PyTrait for HeapData {
         match self {
             Self::Str(s) => PyTrait::py_len(s, heap, interns),
             Self::Bytes(b) => PyTrait::py_len(b, heap, interns),
+            Self::ByteArray(b) => PyTrait::py_len(b, heap, interns),
             Self::List(l) => PyTrait::py_len(l, heap, interns),
             Self::Tuple(t) => PyTrait::py_len(t, heap, interns),
             Self::NamedTuple(nt) => PyTrait::py_len(nt, heap, interns),
@@ -390,7 +426,8 @@ impl PyTrait for HeapData {
             Self::Set(s) => PyTrait::py_len(s, heap, interns),
             Self::FrozenSet(fs) => PyTrait::py_len(fs, heap, interns),
             Self::Range(r) => Some(r.len()),
-            // Cells, Slices, Exceptions, Dataclasses, Iterators, LongInts, Modules, Paths, and async types don't have length
+            // Cells, Slices, Exceptions, Dataclasses, Iterators, LongInts, Modules, Paths, Files, regex objects,
+            // getters, and async types don't have length
             Self::Cell(_)
             | Self::Closure(_, _, _)
             | Self::FunctionDefaults(_, _)
@@ -401,8 +438,14 @@ impl PyTrait for HeapData {
             | Self::LongInt(_)
             | Self::Module(_)
             | Self::Coroutine(_)
+            | Self::Generator(_)
             | Self::GatherFuture(_)
-            | Self::Path(_) => None,
+            | Self::Path(_)
+            | Self::File(_)
+            | Self::RePattern(_)
+            | Self::ReMatch(_)
+            | Self::Getter(_)
+            | Self::ContextManager(_) => None,
         }
     }
 
@@ -416,6 +459,11 @@ impl PyTrait for HeapData {
         match (self, other) {
             (Self::Str(a), Self::Str(b)) => a.py_eq(b, heap, guard, interns),
             (Self::Bytes(a), Self::Bytes(b)) => a.py_eq(b, heap, guard, interns),
+            (Self::ByteArray(a), Self::ByteArray(b)) => a.py_eq(b, heap, guard, interns),
+            // bytes and bytearray compare by content (matching CPython behavior)
+            (Self::Bytes(a), Self::ByteArray(b)) | (Self::ByteArray(b), Self::Bytes(a)) => {
+                Ok(a.as_slice() == b.as_slice())
+            }
             (Self::List(a), Self::List(b)) => a.py_eq(b, heap, guard, interns),
             (Self::Tuple(a), Self::Tuple(b)) => a.py_eq(b, heap, guard, interns),
             (Self::NamedTuple(a), Self::NamedTuple(b)) => a.py_eq(b, heap, guard, interns),
@@ -439,6 +487,9 @@ impl PyTrait for HeapData {
             (Self::Dict(a), Self::Dict(b)) => a.py_eq(b, heap, guard, interns),
             (Self::Set(a), Self::Set(b)) => a.py_eq(b, heap, guard, interns),
             (Self::FrozenSet(a), Self::FrozenSet(b)) => a.py_eq(b, heap, guard, interns),
+            // Sets and frozensets compare by elements (matching CPython behavior)
+            (Self::Set(a), Self::FrozenSet(b)) => a.storage().eq(b.storage(), heap, guard, interns),
+            (Self::FrozenSet(a), Self::Set(b)) => a.storage().eq(b.storage(), heap, guard, interns),
             (Self::Closure(a_id, a_cells, _), Self::Closure(b_id, b_cells, _)) => {
                 Ok(*a_id == *b_id && a_cells == b_cells)
             }
@@ -451,12 +502,18 @@ impl PyTrait for HeapData {
             (Self::Slice(a), Self::Slice(b)) => a.py_eq(b, heap, guard, interns),
             // Path equality
             (Self::Path(a), Self::Path(b)) => a.py_eq(b, heap, guard, interns),
-            // Cells, Exceptions, Iterators, Modules, and async types compare by identity only (handled at Value level via HeapId comparison)
+            (Self::RePattern(a), Self::RePattern(b)) => a.py_eq(b, heap, guard, interns),
+            // Cells, Exceptions, Iterators, Modules, Files, matches, getters, context managers, and async types compare by identity only (handled at Value level via HeapId comparison)
             (Self::Cell(_), Self::Cell(_))
             | (Self::Exception(_), Self::Exception(_))
             | (Self::Iter(_), Self::Iter(_))
             | (Self::Module(_), Self::Module(_))
+            | (Self::File(_), Self::File(_))
+            | (Self::ReMatch(_), Self::ReMatch(_))
+            | (Self::Getter(_), Self::Getter(_))
+            | (Self::ContextManager(_), Self::ContextManager(_))
             | (Self::Coroutine(_), Self::Coroutine(_))
+            | (Self::Generator(_), Self::Generator(_))
             | (Self::GatherFuture(_), Self::GatherFuture(_)) => Ok(false),
             _ => Ok(false), // Different types are never equal
         }
@@ -466,6 +523,7 @@ impl PyTrait for HeapData {
         match self {
             Self::Str(s) => s.py_dec_ref_ids(stack),
             Self::Bytes(b) => b.py_dec_ref_ids(stack),
+            Self::ByteArray(b) => b.py_dec_ref_ids(stack),
             Self::List(l) => l.py_dec_ref_ids(stack),
             Self::Tuple(t) => t.py_dec_ref_ids(stack),
             Self::NamedTuple(nt) => nt.py_dec_ref_ids(stack),
@@ -490,6 +548,8 @@ impl PyTrait for HeapData {
             Self::Dataclass(dc) => dc.py_dec_ref_ids(stack),
             Self::Iter(iter) => iter.py_dec_ref_ids(stack),
             Self::Module(m) => m.py_dec_ref_ids(stack),
+            Self::Getter(getter) => getter.py_dec_ref_ids(stack),
+            Self::ContextManager(manager) => manager.py_dec_ref_ids(stack),
             Self::Coroutine(coro) => {
                 // Decrement ref count for frame cells
                 stack.extend(coro.frame_cells.iter().copied());
@@ -498,6 +558,7 @@ impl PyTrait for HeapData {
                     value.py_dec_ref_ids(stack);
                 }
             }
+            Self::Generator(generator) => generator.py_dec_ref_ids(stack),
             Self::GatherFuture(gather) => {
                 // Decrement ref count for coroutine HeapIds
                 for item in &gather.items {
@@ -510,8 +571,15 @@ impl PyTrait for HeapData {
                     result.py_dec_ref_ids(stack);
                 }
             }
-            // Range, Slice, Exception, LongInt, and Path have no nested heap references
-            Self::Range(_) | Self::Slice(_) | Self::Exception(_) | Self::LongInt(_) | Self::Path(_) => {}
+            // Range, Slice, Exception, LongInt, Path, File, and regex objects have no nested heap references
+            Self::Range(_)
+            | Self::Slice(_)
+            | Self::Exception(_)
+            | Self::LongInt(_)
+            | Self::Path(_)
+            | Self::File(_)
+            | Self::RePattern(_)
+            | Self::ReMatch(_) => {}
         }
     }
 
@@ -519,6 +587,7 @@ impl PyTrait for HeapData {
         match self {
             Self::Str(s) => s.py_bool(heap, interns),
             Self::Bytes(b) => b.py_bool(heap, interns),
+            Self::ByteArray(b) => b.py_bool(heap, interns),
             Self::List(l) => l.py_bool(heap, interns),
             Self::Tuple(t) => t.py_bool(heap, interns),
             Self::NamedTuple(nt) => nt.py_bool(heap, interns),
@@ -535,8 +604,14 @@ impl PyTrait for HeapData {
             Self::LongInt(li) => !li.is_zero(),
             Self::Module(_) => true,       // Modules are always truthy
             Self::Coroutine(_) => true,    // Coroutines are always truthy
+            Self::Generator(_) => true,    // Generators are always truthy
             Self::GatherFuture(_) => true, // GatherFutures are always truthy
             Self::Path(p) => p.py_bool(heap, interns),
+            Self::File(file) => file.py_bool(heap, interns),
+            Self::RePattern(pattern) => pattern.py_bool(heap, interns),
+            Self::ReMatch(re_match) => re_match.py_bool(heap, interns),
+            Self::Getter(getter) => getter.py_bool(heap, interns),
+            Self::ContextManager(manager) => manager.py_bool(heap, interns),
         }
     }
 
@@ -551,6 +626,7 @@ impl PyTrait for HeapData {
         match self {
             Self::Str(s) => s.py_repr_fmt(f, heap, heap_ids, guard, interns),
             Self::Bytes(b) => b.py_repr_fmt(f, heap, heap_ids, guard, interns),
+            Self::ByteArray(b) => b.py_repr_fmt(f, heap, heap_ids, guard, interns),
             Self::List(l) => l.py_repr_fmt(f, heap, heap_ids, guard, interns),
             Self::Tuple(t) => t.py_repr_fmt(f, heap, heap_ids, guard, interns),
             Self::NamedTuple(nt) => nt.py_repr_fmt(f, heap, heap_ids, guard, interns),
@@ -574,8 +650,14 @@ impl PyTrait for HeapData {
                 let name = interns.get_str(func.name.name_id);
                 write!(f, "<coroutine object {name}>")
             }
+            Self::Generator(_) => write!(f, "<generator object <genexpr>>"),
             Self::GatherFuture(gather) => write!(f, "<gather({})>", gather.item_count()),
             Self::Path(p) => p.py_repr_fmt(f, heap, heap_ids, guard, interns),
+            Self::File(file) => file.py_repr_fmt(f, heap, heap_ids, guard, interns),
+            Self::RePattern(pattern) => pattern.py_repr_fmt(f, heap, heap_ids, guard, interns),
+            Self::ReMatch(re_match) => re_match.py_repr_fmt(f, heap, heap_ids, guard, interns),
+            Self::Getter(getter) => getter.py_repr_fmt(f, heap, heap_ids, guard, interns),
+            Self::ContextManager(manager) => manager.py_repr_fmt(f, heap, heap_ids, guard, interns),
         }
     }
 
@@ -589,7 +671,7 @@ impl PyTrait for HeapData {
             // Strings return their value directly without quotes
             Self::Str(s) => s.py_str(heap, guard, interns),
             // LongInt returns its string representation
-            Self::LongInt(li) => Cow::Owned(li.to_string()),
+            Self::LongInt(li) => Cow::Owned(li.decimal().to_owned()),
             // Exceptions return just the message (or empty string if no message)
             Self::Exception(e) => Cow::Owned(e.py_str()),
             // Paths return the path string without the PosixPath() wrapper
@@ -608,6 +690,16 @@ impl PyTrait for HeapData {
         match (self, other) {
             (Self::Str(a), Self::Str(b)) => a.py_add(b, heap, interns),
             (Self::Bytes(a), Self::Bytes(b)) => a.py_add(b, heap, interns),
+            (Self::ByteArray(a), Self::ByteArray(b)) => a.py_add(b, heap, interns),
+            // Mixing bytes and bytearray gives the type of the left operand
+            (Self::Bytes(a), Self::ByteArray(b)) => {
+                let id = heap.allocate(Self::Bytes([a.as_slice(), b.as_slice()].concat().into()))?;
+                Ok(Some(Value::Ref(id)))
+            }
+            (Self::ByteArray(a), Self::Bytes(b)) => {
+                let id = heap.allocate(Self::ByteArray(ByteArray::new([a.as_slice(), b.as_slice()].concat())))?;
+                Ok(Some(Value::Ref(id)))
+            }
             (Self::List(a), Self::List(b)) => a.py_add(b, heap, interns),
             (Self::Tuple(a), Self::Tuple(b)) => a.py_add(b, heap, interns),
             (Self::Dict(a), Self::Dict(b)) => a.py_add(b, heap, interns),
@@ -678,8 +770,8 @@ impl PyTrait for HeapData {
         interns: &Interns,
     ) -> Result<bool, crate::resource::ResourceError> {
         match self {
-            Self::Str(s) => s.py_iadd(other, heap, self_id, interns),
             Self::Bytes(b) => b.py_iadd(other, heap, self_id, interns),
+            Self::ByteArray(b) => b.py_iadd(other, heap, self_id, interns),
             Self::List(l) => l.py_iadd(other, heap, self_id, interns),
             Self::Tuple(t) => t.py_iadd(other, heap, self_id, interns),
             Self::Dict(d) => d.py_iadd(other, heap, self_id, interns),
@@ -726,6 +818,8 @@ impl PyTrait for HeapData {
             Self::Dataclass(dc) => dc.py_call_attr_raw(heap, attr, args, interns),
             // Module has special handling for OS calls (os.getenv, etc.)
             Self::Module(m) => m.py_call_attr_raw(heap, attr, args, interns),
+            // File methods all yield OS calls keyed by the file's handle
+            Self::File(file) => file.py_call_attr_raw(heap, attr, args, interns),
             // All other types use the default implementation (wrap py_call_attr)
             _ => self.py_call_attr(heap, attr, args, interns).map(AttrCallResult::Value),
         }
@@ -735,6 +829,7 @@ impl PyTrait for HeapData {
         match self {
             Self::Str(s) => s.py_getitem(key, heap, interns),
             Self::Bytes(b) => b.py_getitem(key, heap, interns),
+            Self::ByteArray(b) => b.py_getitem(key, heap, interns),
             Self::List(l) => l.py_getitem(key, heap, interns),
             Self::Tuple(t) => t.py_getitem(key, heap, interns),
             Self::NamedTuple(nt) => nt.py_getitem(key, heap, interns),
@@ -774,6 +869,8 @@ impl PyTrait for HeapData {
             Self::Slice(s) => s.py_getattr(attr_id, heap, interns),
             Self::Exception(exc) => exc.py_getattr(attr_id, heap, interns),
             Self::Path(p) => p.py_getattr(attr_id, heap, interns),
+            Self::File(file) => file.py_getattr(attr_id, heap, interns),
+            Self::RePattern(pattern) => pattern.py_getattr(attr_id, heap, interns),
             // All other types don't support attribute access via py_getattr
             _ => Ok(None),
         }
@@ -794,7 +891,7 @@ enum HashState {
 impl HashState {
     fn for_data(data: &HeapData) -> Self {
         match data {
-            // Cells are hashable by identity (like all Python objects without __hash__ override)
+            // Cells, getters, context managers and generators are hashable by identity (like all Python objects without __hash__ override)
             // FrozenSet is immutable and hashable
             // Range is immutable and hashable
             // Slice is immutable and hashable (like in CPython)
@@ -806,6 +903,9 @@ impl HashState {
             | HeapData::NamedTuple(_)
             | HeapData::FrozenSet(_)
             | HeapData::Cell(_)
+            | HeapData::Getter(_)
+            | HeapData::ContextManager(_)
+            | HeapData::Generator(_)
             | HeapData::Closure(_, _, _)
             | HeapData::FunctionDefaults(_, _)
             | HeapData::Range(_)
@@ -819,15 +919,18 @@ impl HashState {
                     Self::Unhashable
                 }
             }
-            // Path is immutable and hashable
-            HeapData::Path(_) => Self::Unknown,
-            // Mutable containers, exceptions, iterators, modules, and async types are unhashable
+            // Path and compiled patterns are immutable and hashable
+            HeapData::Path(_) | HeapData::RePattern(_) => Self::Unknown,
+            // Mutable containers, exceptions, iterators, modules, files, matches, and async types are unhashable
             HeapData::List(_)
+            | HeapData::ByteArray(_)
             | HeapData::Dict(_)
             | HeapData::Set(_)
             | HeapData::Exception(_)
             | HeapData::Iter(_)
             | HeapData::Module(_)
+            | HeapData::File(_)
+            | HeapData::ReMatch(_)
             | HeapData::Coroutine(_)
             | HeapData::GatherFuture(_) => Self::Unhashable,
         }
@@ -845,15 +948,35 @@ impl HashState {
 /// they can `.take()` the data out (leaving `None`), pass `&mut Heap` to user code,
 /// then restore the data. This avoids unsafe code while keeping `refcount` accessible
 /// for `inc_ref`/`dec_ref` during the borrow.
+///
+/// The `size` field records how many bytes the resource tracker has been charged for the
+/// entry, so freeing it credits back exactly that amount even if the data has grown since.
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct HeapValue {
     refcount: usize,
     /// The payload data. Temporarily `None` while borrowed via `with_entry_mut`/`call_attr`.
     data: Option<HeapData>,
+    /// Estimated size of `data` last charged to the resource tracker, see [`Heap::update_size`].
+    size: usize,
     /// Current hashing status / cached hash value
     hash_state: HashState,
 }
 
+/// A summary of heap usage, for hosts choosing resource limits.
+///
+/// Read it from a paused execution with `Snapshot::heap_stats()` or
+/// `FutureSnapshot::heap_stats()`. None of the counts include the empty tuple
+/// singleton, which every heap starts with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Number of live heap objects.
+    pub live_objects: usize,
+    /// Approximate bytes used by live heap objects, estimated the same way as for `max_memory`.
+    pub memory: usize,
+    /// Highest number of heap objects that have been live at once.
+    pub peak_objects: usize,
+}
+
 /// Reference-counted arena that backs all heap-only runtime values.
 ///
 /// Uses a free list to reuse slots from freed values, keeping memory usage
@@ -940,12 +1063,6 @@ macro_rules! restore_data {
     }};
 }
 
-/// GC interval - run GC every 100,000 applicable allocations.
-///
-/// This is intentionally infrequent to minimize overhead while still
-/// eventually collecting reference cycles.
-const GC_INTERVAL: u32 = 100_000;
-
 impl<T: ResourceTracker> Heap<T> {
     /// Creates a new heap with the given resource tracker.
     ///
@@ -982,6 +1099,23 @@ impl<T: ResourceTracker> Heap<T> {
         self.entries.len()
     }
 
+    /// Returns the number of live objects, their estimated size, and the peak object count.
+    ///
+    /// Freed slots are reused before the heap grows, so the number of slots is the
+    /// most objects that have been live at once.
+    pub fn stats(&self) -> HeapStats {
+        // 1.. to skip index 0 which is the empty tuple singleton
+        let (live_objects, memory) = self.entries[1..]
+            .iter()
+            .flatten()
+            .fold((0, 0), |(count, memory), entry| (count + 1, memory + entry.size));
+        HeapStats {
+            live_objects,
+            memory,
+            peak_objects: self.entries.len() - 1,
+        }
+    }
+
     /// Marks that a reference cycle may exist in the heap.
     ///
     /// Call this when a container (list, dict, tuple, etc.) stores a reference
@@ -1012,7 +1146,8 @@ impl<T: ResourceTracker> Heap<T> {
     /// When allocating a container that contains heap references, marks potential
     /// cycles to enable garbage collection.
     pub fn allocate(&mut self, data: HeapData) -> Result<HeapId, ResourceError> {
-        self.tracker.on_allocate(|| data.py_estimate_size())?;
+        let size = data.py_estimate_size();
+        self.tracker.on_allocate(|| size)?;
         if data.is_gc_tracked() {
             self.allocations_since_gc = self.allocations_since_gc.wrapping_add(1);
             // Mark potential cycles if this container has heap references.
@@ -1027,6 +1162,7 @@ impl<T: ResourceTracker> Heap<T> {
         let new_entry = HeapValue {
             refcount: 1,
             data: Some(data),
+            size,
             hash_state,
         };
 
@@ -1090,9 +1226,7 @@ impl<T: ResourceTracker> Heap<T> {
             self.free_list.push(id);
 
             // Notify tracker of freed memory
-            if let Some(ref data) = value.data {
-                self.tracker.on_free(|| data.py_estimate_size());
-            }
+            self.tracker.on_free(|| value.size);
 
             // Collect child IDs and mark Values as Dereferenced (when ref-count-panic enabled)
             if let Some(mut data) = value.data {
@@ -1140,6 +1274,41 @@ impl<T: ResourceTracker> Heap<T> {
             .expect("Heap::get_mut: data currently borrowed")
     }
 
+    /// Charges the resource tracker for a change in the size of a heap entry.
+    ///
+    /// Containers like lists and dicts grow in place, so call this after mutating one to
+    /// re-estimate its size and charge or credit the difference. Method calls through
+    /// [`Heap::call_attr_raw`] do this automatically.
+    ///
+    /// Returns `Err(ResourceError::Memory)` if growth takes memory use over the limit. The
+    /// growth is charged either way, since the memory is already in use.
+    ///
+    /// # Panics
+    /// Panics if the value ID is invalid, the value has already been freed,
+    /// or the data is currently borrowed via `with_entry_mut`/`call_attr`.
+    pub fn update_size(&mut self, id: HeapId) -> Result<(), ResourceError> {
+        let entry = self
+            .entries
+            .get_mut(id.index())
+            .expect("Heap::update_size: slot missing")
+            .as_mut()
+            .expect("Heap::update_size: object already freed");
+        let new_size = entry
+            .data
+            .as_ref()
+            .expect("Heap::update_size: data currently borrowed")
+            .py_estimate_size();
+        let old_size = std::mem::replace(&mut entry.size, new_size);
+        if new_size > old_size {
+            self.tracker.on_grow(|| new_size - old_size)
+        } else {
+            if new_size < old_size {
+                self.tracker.on_free(|| old_size - new_size);
+            }
+            Ok(())
+        }
+    }
+
     /// Returns or computes the hash for the heap entry at the given ID.
     ///
     /// Hashes are computed lazily on first use and then cached. Returns
@@ -1162,8 +1331,10 @@ impl<T: ResourceTracker> Heap<T> {
             HashState::Unknown => {}
         }
 
-        // Handle Cell specially - uses identity-based hashing (like Python cell objects)
-        if let Some(HeapData::Cell(_)) = &entry.data {
+        // Cells, getters, context managers and generators use identity-based hashing (like Python cell objects)
+        if let Some(HeapData::Cell(_) | HeapData::Getter(_) | HeapData::ContextManager(_) | HeapData::Generator(_)) =
+            &entry.data
+        {
             let mut hasher = DefaultHasher::new();
             id.hash(&mut hasher);
             let hash = hasher.finish();
@@ -1207,6 +1378,23 @@ impl<T: ResourceTracker> Heap<T> {
         args: ArgValues,
         interns: &Interns,
     ) -> RunResult<AttrCallResult> {
+        // Regex objects are called in place so their methods can inspect arguments that are
+        // the object itself, see `re::call_attr`
+        if matches!(self.get(id), HeapData::RePattern(_) | HeapData::ReMatch(_)) {
+            return re::call_attr(self, id, attr, args, interns).map(AttrCallResult::Value);
+        }
+        // Likewise bytearrays, so `b.extend(b)` can read the bytearray it extends
+        if matches!(self.get(id), HeapData::ByteArray(_)) {
+            return bytearray::call_attr(self, id, attr, args, interns).map(AttrCallResult::Value);
+        }
+        // And context managers, whose `__enter__` may return the object itself
+        if matches!(self.get(id), HeapData::ContextManager(_)) {
+            return contextlib::call_attr(self, id, attr, args, interns).map(AttrCallResult::Value);
+        }
+        if attr.static_string() == Some(StaticStrings::DunderEnter) && matches!(self.get(id), HeapData::File(_)) {
+            return file::file_enter(self, id, args).map(AttrCallResult::Value);
+        }
+
         // Take data out so the borrow of self.entries ends
         let mut data = take_data!(self, id, "call_attr");
 
@@ -1214,7 +1402,15 @@ impl<T: ResourceTracker> Heap<T> {
 
         // Restore data
         restore_data!(self, id, data, "call_attr_raw");
-        result
+
+        // Methods like `append()` and `update()` grow the object in place
+        match (result, self.update_size(id)) {
+            (Ok(AttrCallResult::Value(value)), Err(err)) => {
+                value.drop_with_heap(self);
+                Err(err.into())
+            }
+            (result, _) => result,
+        }
     }
 
     /// Gives mutable access to a heap entry while allowing reentrant heap usage
@@ -1375,6 +1571,65 @@ impl<T: ResourceTracker> Heap<T> {
         }
     }
 
+    /// Helper for the `s += x` fast path: appends a string to the string at `id` in place.
+    ///
+    /// Strings are immutable, so this is only done when `id` has exactly `refcount` references
+    /// and the caller knows they're all about to be replaced by the result, so nothing can see
+    /// the change. The cached hash is cleared since the contents change.
+    ///
+    /// Returns `Ok(false)` without changing anything if `id` isn't a `Str`, has any other
+    /// references, or `suffix` isn't a string. The string's growth is charged to the tracker,
+    /// returning `Err(ResourceError::Memory)` if that goes over the memory limit.
+    pub fn append_unshared_str(
+        &mut self,
+        id: HeapId,
+        refcount: usize,
+        suffix: &Value,
+        interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        let entry = self
+            .entries
+            .get(id.index())
+            .expect("Heap::append_unshared_str: slot missing")
+            .as_ref()
+            .expect("Heap::append_unshared_str: object already freed");
+        if entry.refcount != refcount || !matches!(entry.data, Some(HeapData::Str(_))) {
+            return Ok(false);
+        }
+
+        // Take the string out so the suffix can be read from the heap while appending
+        let mut data = take_data!(self, id, "append_unshared_str");
+        let HeapData::Str(s) = &mut data else {
+            unreachable!("checked above")
+        };
+        let suffix = match suffix {
+            Value::InternString(string_id) => Some(interns.get_str(*string_id)),
+            Value::Ref(suffix_id) if *suffix_id != id => match self.get(*suffix_id) {
+                HeapData::Str(suffix) => Some(suffix.as_str()),
+                _ => None,
+            },
+            _ => None,
+        };
+        let result = match suffix {
+            Some(suffix) => check_repeat_size(s.as_str().len() + suffix.len(), 1, &self.tracker).map(|()| {
+                s.as_string_mut().push_str(suffix);
+                true
+            }),
+            None => Ok(false),
+        };
+
+        restore_data!(self, id, data, "append_unshared_str");
+        if matches!(result, Ok(true)) {
+            self.entries[id.index()]
+                .as_mut()
+                .expect("Heap::append_unshared_str: object already freed")
+                .hash_state = HashState::Unknown;
+            // The string grew in place, so charge the growth like an allocation of the result
+            self.update_size(id)?;
+        }
+        result
+    }
+
     /// Multiplies a heap-allocated value by an `i64`.
     ///
     /// If `id` refers to a `LongInt`, performs integer multiplication with a size
@@ -1473,6 +1728,14 @@ impl<T: ResourceTracker> Heap<T> {
                 restore_data!(self, id, data, "mult_sequence");
                 Ok(Some(Value::Ref(self.allocate(HeapData::Bytes(repeated.into()))?)))
             }
+            HeapData::ByteArray(b) => {
+                check_repeat_size(b.as_slice().len(), count, &self.tracker)?;
+                let repeated = b.as_slice().repeat(count);
+                restore_data!(self, id, data, "mult_sequence");
+                Ok(Some(Value::Ref(
+                    self.allocate(HeapData::ByteArray(ByteArray::new(repeated)))?,
+                )))
+            }
             HeapData::List(list) => {
                 if count == 0 {
                     restore_data!(self, id, data, "mult_sequence");
@@ -1571,11 +1834,11 @@ impl<T: ResourceTracker> Heap<T> {
 
     /// Returns whether garbage collection should run.
     ///
-    /// True if reference cycles count exist in the heap
-    /// and the number of allocations since the last GC exceeds the interval.
+    /// True if reference cycles may exist in the heap and the resource tracker
+    /// schedules a collection after this many allocations since the last GC.
     #[inline]
     pub fn should_gc(&self) -> bool {
-        self.may_have_cycles && self.allocations_since_gc >= GC_INTERVAL
+        self.may_have_cycles && self.tracker.should_gc(self.allocations_since_gc)
     }
 
     /// Runs mark-sweep garbage collection to free unreachable cycles.
@@ -1594,26 +1857,7 @@ impl<T: ResourceTracker> Heap<T> {
     /// # Arguments
     /// * `root` - HeapIds that are roots
     pub fn collect_garbage(&mut self, root: Vec<HeapId>) {
-        // Mark phase: collect all reachable IDs using BFS
-        // Use Vec<bool> instead of HashSet for O(1) operations without hashing overhead
-        let mut reachable: Vec<bool> = vec![false; self.entries.len()];
-        let mut work_list: Vec<HeapId> = root;
-
-        while let Some(id) = work_list.pop() {
-            let idx = id.index();
-            // Skip if out of bounds or already visited
-            if idx >= reachable.len() || reachable[idx] {
-                continue;
-            }
-            reachable[idx] = true;
-
-            // Add children to work list
-            if let Some(Some(entry)) = self.entries.get(idx)
-                && let Some(ref data) = entry.data
-            {
-                collect_child_ids(data, &mut work_list);
-            }
-        }
+        let reachable = self.mark_reachable(root);
 
         // Sweep phase: free unreachable values
         for (id, value) in self.entries.iter_mut().enumerate() {
@@ -1624,9 +1868,7 @@ impl<T: ResourceTracker> Heap<T> {
             // This entry is unreachable - free it
             if let Some(value) = value.take() {
                 // Notify tracker of freed memory
-                if let Some(ref data) = value.data {
-                    self.tracker.on_free(|| data.py_estimate_size());
-                }
+                self.tracker.on_free(|| value.size);
 
                 self.free_list.push(HeapId(id));
 
@@ -1642,6 +1884,77 @@ impl<T: ResourceTracker> Heap<T> {
         self.may_have_cycles = false;
         self.allocations_since_gc = 0;
     }
+
+    /// Counts the unreachable heap values that are only kept alive by reference cycles.
+    ///
+    /// Uses trial deletion: a value unreachable from `root` is cyclic garbage when every
+    /// reference to it comes from another unreachable value, since garbage collection
+    /// would free it. An unreachable value with more references than that is a refcount
+    /// leak, so it isn't counted. Doesn't free anything.
+    #[must_use]
+    #[cfg(feature = "ref-count-return")]
+    pub fn cyclic_garbage_count(&self, root: Vec<HeapId>) -> usize {
+        let reachable = self.mark_reachable(root);
+
+        // Count the references each unreachable value holds to other unreachable values
+        let mut internal_refs: Vec<usize> = vec![0; self.entries.len()];
+        let mut children = Vec::new();
+        for (idx, entry) in self.entries.iter().enumerate() {
+            if reachable[idx] {
+                continue;
+            }
+            if let Some(entry) = entry
+                && let Some(ref data) = entry.data
+            {
+                collect_child_ids(data, &mut children);
+                for child in children.drain(..) {
+                    if reachable.get(child.index()) == Some(&false) {
+                        internal_refs[child.index()] += 1;
+                    }
+                }
+            }
+        }
+
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(idx, entry)| {
+                !reachable[*idx]
+                    && entry
+                        .as_ref()
+                        .is_some_and(|entry| entry.refcount == internal_refs[*idx])
+            })
+            .count()
+    }
+
+    /// Mark phase of garbage collection: finds every heap value reachable from `root`.
+    ///
+    /// The empty tuple singleton is always reachable, as `get_empty_tuple` hands it out
+    /// without allocating.
+    fn mark_reachable(&self, root: Vec<HeapId>) -> Vec<bool> {
+        // Collect all reachable IDs using BFS
+        // Use Vec<bool> instead of HashSet for O(1) operations without hashing overhead
+        let mut reachable: Vec<bool> = vec![false; self.entries.len()];
+        let mut work_list: Vec<HeapId> = root;
+        work_list.push(EMPTY_TUPLE_ID);
+
+        while let Some(id) = work_list.pop() {
+            let idx = id.index();
+            // Skip if out of bounds or already visited
+            if idx >= reachable.len() || reachable[idx] {
+                continue;
+            }
+            reachable[idx] = true;
+
+            // Add children to work list
+            if let Some(Some(entry)) = self.entries.get(idx)
+                && let Some(ref data) = entry.data
+            {
+                collect_child_ids(data, &mut work_list);
+            }
+        }
+        reachable
+    }
 }
 
 /// Computes the number of significant bits in an `i64`.
@@ -1688,11 +2001,15 @@ fn collect_child_ids(data: &HeapData, work_list: &mut Vec<HeapId>) {
         // Leaf types with no heap references
         HeapData::Str(_)
         | HeapData::Bytes(_)
+        | HeapData::ByteArray(_)
         | HeapData::Range(_)
         | HeapData::Exception(_)
         | HeapData::LongInt(_)
         | HeapData::Slice(_)
-        | HeapData::Path(_) => {}
+        | HeapData::Path(_)
+        | HeapData::File(_)
+        | HeapData::RePattern(_)
+        | HeapData::ReMatch(_) => {}
         HeapData::List(list) => {
             // Skip iteration if no refs - major GC optimization for lists of primitives
             if !list.contains_refs() {
@@ -1811,6 +2128,28 @@ fn collect_child_ids(data: &HeapData, work_list: &mut Vec<HeapId>) {
                 }
             }
         }
+        HeapData::Getter(Getter::Item(items)) => {
+            // itemgetter items can be any value
+            for item in items {
+                if let Value::Ref(id) = item {
+                    work_list.push(*id);
+                }
+            }
+        }
+        HeapData::Getter(Getter::Attr(_)) => {}
+        HeapData::ContextManager(ContextManager::Suppress(exceptions)) => {
+            // suppress() accepts any values, they're only checked when an exception is raised
+            for exc in exceptions {
+                if let Value::Ref(id) = exc {
+                    work_list.push(*id);
+                }
+            }
+        }
+        HeapData::ContextManager(ContextManager::NullContext(value)) => {
+            if let Value::Ref(id) = value {
+                work_list.push(*id);
+            }
+        }
         HeapData::Coroutine(coro) => {
             // Add captured cells to work list
             for cell_id in &coro.frame_cells {
@@ -1823,6 +2162,14 @@ fn collect_child_ids(data: &HeapData, work_list: &mut Vec<HeapId>) {
                 }
             }
         }
+        HeapData::Generator(generator) => {
+            // Add the saved namespace and stack values, which include the captured cells
+            for value in generator.values() {
+                if let Value::Ref(id) = value {
+                    work_list.push(*id);
+                }
+            }
+        }
         HeapData::GatherFuture(gather) => {
             // Add coroutine HeapIds to work list
             for item in &gather.items {
@@ -1882,7 +2229,7 @@ impl<T: ResourceTracker> ContainsHeap<T> for Heap<T> {
 /// call on any branch leaks reference counts. Prefer [`defer_drop!`] or [`HeapGuard`] to
 /// guarantee cleanup automatically rather than inserting manual calls in every branch.
 ///
-/// Implemented for `Value`, `Option<V>`, `Vec<Value>`, `ArgValues`, iterators, and other
+/// Implemented for `Value`, `Option<V>`, `Vec<V>`, `ArgValues`, iterators, and other
 /// types that hold heap references.
 pub(crate) trait DropWithHeap<T: ResourceTracker> {
     /// Consume `self` and decrement reference counts for any heap-allocated values contained within.
@@ -1905,7 +2252,15 @@ impl<T: ResourceTracker, U: DropWithHeap<T>> DropWithHeap<T> for Option<U> {
     }
 }
 
-impl<T: ResourceTracker> DropWithHeap<T> for Vec<Value> {
+impl<T: ResourceTracker, U: DropWithHeap<T>, const N: usize> DropWithHeap<T> for [U; N] {
+    fn drop_with_heap(self, heap: &mut Heap<T>) {
+        for value in self {
+            value.drop_with_heap(heap);
+        }
+    }
+}
+
+impl<T: ResourceTracker, U: DropWithHeap<T>> DropWithHeap<T> for Vec<U> {
     fn drop_with_heap(self, heap: &mut Heap<T>) {
         for value in self {
             value.drop_with_heap(heap);
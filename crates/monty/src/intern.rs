@@ -79,6 +79,11 @@ pub enum StaticStrings {
     EmptyString,
     #[strum(serialize = "<module>")]
     Module,
+    #[strum(serialize = "<genexpr>")]
+    GenExpr,
+    /// Hidden parameter of a generator expression's function, holding the outermost iterator.
+    #[strum(serialize = ".0")]
+    GenExprIter,
     // ==========================
     // List methods
     // Also uses shared: POP, CLEAR, COPY, REMOVE
@@ -173,8 +178,11 @@ pub enum StaticStrings {
     Zfill,
     // Additional string methods
     Encode,
+    FormatMap,
     Isidentifier,
     Istitle,
+    Maketrans,
+    Translate,
 
     // ==========================
     // Bytes methods
@@ -321,9 +329,115 @@ pub enum StaticStrings {
     #[strum(serialize = "default")]
     Default,
 
+    // ==========================
+    // math module strings
+    #[strum(serialize = "math")]
+    Math,
+    Floor,
+    Ceil,
+    Trunc,
+    Comb,
+    Perm,
+    Isclose,
+    Isnan,
+    Isinf,
+    Isfinite,
+    Degrees,
+    Radians,
+    Atan,
+    Asin,
+    Acos,
+    Atan2,
+    Hypot,
+
+    // ==========================
+    // warnings module strings
+    #[strum(serialize = "warnings")]
+    Warnings,
+    Warn,
+
+    // ==========================
+    // re module strings
+    // Also uses shared: SPLIT, START
+    #[strum(serialize = "re")]
+    Re,
+    Compile,
+    Match,
+    Search,
+    Findall,
+    Sub,
+    Group,
+    Groups,
+    Span,
+    End,
+    Pattern,
+    Flags,
+    #[strum(serialize = "IGNORECASE")]
+    Ignorecase,
+    #[strum(serialize = "MULTILINE")]
+    Multiline,
+    #[strum(serialize = "DOTALL")]
+    Dotall,
+    #[strum(serialize = "VERBOSE")]
+    Verbose,
+
+    // ==========================
+    // operator module strings
+    Operator,
+    Itemgetter,
+    Attrgetter,
+
+    // ==========================
+    // contextlib module strings
+    Contextlib,
+    Suppress,
+    Nullcontext,
+
+    // ==========================
+    // Context manager protocol, used by `with` statements
+    #[strum(serialize = "__enter__")]
+    DunderEnter,
+    #[strum(serialize = "__exit__")]
+    DunderExit,
+
+    // ==========================
+    // heapq module strings
+    Heapq,
+    Heappush,
+    Heappop,
+    Heapify,
+    Nsmallest,
+    Nlargest,
+
+    // ==========================
+    // bisect module strings
+    Bisect,
+    BisectLeft,
+    BisectRight,
+    Insort,
+    InsortLeft,
+    InsortRight,
+
+    // ==========================
+    // statistics module strings
+    Statistics,
+    Mean,
+    Median,
+    Pstdev,
+    Stdev,
+    Variance,
+    #[strum(serialize = "StatisticsError")]
+    StatisticsError,
+
     // ==========================
     // Exception attributes
     Args,
+    #[strum(serialize = "__cause__")]
+    DunderCause,
+    #[strum(serialize = "__context__")]
+    DunderContext,
+    #[strum(serialize = "__suppress_context__")]
+    DunderSuppressContext,
 
     // ==========================
     // Type attributes
@@ -403,6 +517,15 @@ pub enum StaticStrings {
     #[strum(serialize = "rename")]
     Rename,
 
+    // File methods and attributes (methods yield external calls)
+    Read,
+    Readline,
+    Readlines,
+    Write,
+    Close,
+    Closed,
+    Mode,
+
     // Slice attributes
     Start,
     Stop,
@@ -607,6 +730,16 @@ impl InternerBuilder {
     pub fn get_str(&self, id: StringId) -> &str {
         get_str(&self.strings, id)
     }
+
+    /// Looks up a long integer by its `LongIntId`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `LongIntId` is invalid.
+    #[inline]
+    pub fn get_long_int(&self, id: LongIntId) -> &BigInt {
+        &self.long_ints[id.index()]
+    }
 }
 
 /// Looks up a string by its `StringId`.
@@ -658,6 +791,23 @@ impl Interns {
         get_str(&self.strings, id)
     }
 
+    /// Finds the `StringId` of a string, if it was interned.
+    ///
+    /// Used for names only known at runtime, like the arguments of `operator.attrgetter()`.
+    /// A name that was never interned has no `StringId`, so no attribute lookup can find it.
+    /// Strings interned from the code are searched linearly, so this is slow compared to
+    /// `get_str`.
+    pub fn find_str(&self, s: &str) -> Option<StringId> {
+        if s.len() == 1 {
+            Some(StringId::from_ascii(s.as_bytes()[0]))
+        } else if let Ok(ss) = StaticStrings::from_str(s) {
+            Some(ss.into())
+        } else {
+            let index = self.strings.iter().position(|string| string == s)?;
+            Some(StringId((index + INTERN_STRING_ID_OFFSET).try_into().ok()?))
+        }
+    }
+
     /// Looks up bytes by their `BytesId`.
     ///
     /// # Panics
@@ -688,6 +838,12 @@ impl Interns {
         self.functions.get(id.index()).expect("Function not found")
     }
 
+    /// Returns all compiled functions, indexed by `FunctionId`.
+    #[inline]
+    pub fn functions(&self) -> &[Function] {
+        &self.functions
+    }
+
     /// Lookup an external function name by its `ExtFunctionId`
     ///
     /// # Panics
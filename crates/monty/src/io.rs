@@ -25,6 +25,27 @@ pub trait PrintWriter {
     /// # Arguments
     /// * `end` - The character to print after the formatted output.
     fn stdout_push(&mut self, end: char) -> Result<(), MontyException>;
+
+    /// Called after each `print()` call has written all of its output, including `end`.
+    ///
+    /// The default implementation does nothing. Implement this to treat each call's output
+    /// as a unit, e.g. to flush it or to record where one call ends and the next begins.
+    fn print_end(&mut self) -> Result<(), MontyException> {
+        Ok(())
+    }
+
+    /// Called for each warning issued with `warnings.warn()`.
+    ///
+    /// The default implementation discards the warning. Implement this to surface warnings
+    /// from sandboxed code, e.g. deprecation notices, to users.
+    ///
+    /// # Arguments
+    /// * `category` - The name of the warning category, e.g. `UserWarning`.
+    /// * `message` - The warning message.
+    fn warn(&mut self, category: &str, message: Cow<'_, str>) -> Result<(), MontyException> {
+        let _ = (category, message);
+        Ok(())
+    }
 }
 
 /// Default `PrintWriter` that writes to stdout.
@@ -43,6 +64,11 @@ impl PrintWriter for StdPrint {
         print!("{end}");
         Ok(())
     }
+
+    fn warn(&mut self, category: &str, message: Cow<'_, str>) -> Result<(), MontyException> {
+        eprintln!("{category}: {message}");
+        Ok(())
+    }
 }
 
 /// A `PrintWriter` that collects all output into a string.
@@ -50,15 +76,20 @@ impl PrintWriter for StdPrint {
 /// Uses interior mutability via `RefCell` to allow collecting output
 /// while being passed as a shared reference through the execution stack.
 ///
-/// Useful for testing or capturing print output programmatically.
+/// Useful for testing or capturing print output programmatically. Besides the combined
+/// output, it records where each `print()` call ends, see [`CollectStringPrint::prints`].
 #[derive(Debug, Default)]
-pub struct CollectStringPrint(String);
+pub struct CollectStringPrint {
+    output: String,
+    /// Offsets into `output` at which each `print()` call's output ends.
+    print_ends: Vec<usize>,
+}
 
 impl CollectStringPrint {
     /// Creates a new empty `CollectStringPrint`.
     #[must_use]
     pub fn new() -> Self {
-        Self(String::new())
+        Self::default()
     }
 
     /// Returns the collected output as a string slice.
@@ -67,24 +98,52 @@ impl CollectStringPrint {
     /// Panics if the internal RefCell is currently borrowed mutably.
     #[must_use]
     pub fn output(&self) -> &str {
-        self.0.as_str()
+        self.output.as_str()
     }
 
     /// Consumes the writer and returns the collected output.
     #[must_use]
     pub fn into_output(self) -> String {
-        self.0
+        self.output
+    }
+
+    /// Returns the output of each `print()` call separately, including its `end`.
+    ///
+    /// Lets tests assert on the sequence of prints: `print('a', end='')` followed by
+    /// `print('b')` gives `["a", "b\n"]`. Output from a call that didn't finish, e.g.
+    /// because formatting an argument raised, is returned as a final item.
+    #[must_use]
+    pub fn prints(&self) -> Vec<&str> {
+        let mut start = 0;
+        let mut prints: Vec<&str> = self
+            .print_ends
+            .iter()
+            .map(|&end| {
+                let print = &self.output[start..end];
+                start = end;
+                print
+            })
+            .collect();
+        if start < self.output.len() {
+            prints.push(&self.output[start..]);
+        }
+        prints
     }
 }
 
 impl PrintWriter for CollectStringPrint {
     fn stdout_write(&mut self, output: Cow<'_, str>) -> Result<(), MontyException> {
-        self.0.push_str(&output);
+        self.output.push_str(&output);
         Ok(())
     }
 
     fn stdout_push(&mut self, end: char) -> Result<(), MontyException> {
-        self.0.push(end);
+        self.output.push(end);
+        Ok(())
+    }
+
+    fn print_end(&mut self) -> Result<(), MontyException> {
+        self.print_ends.push(self.output.len());
         Ok(())
     }
 }
@@ -9,6 +9,7 @@ mod bytecode;
 mod exception_private;
 mod exception_public;
 mod expressions;
+mod fold;
 mod fstring;
 mod function;
 mod intern;
@@ -19,9 +20,11 @@ mod object;
 mod os;
 mod parse;
 mod prepare;
+mod printf;
 mod resource;
 mod run;
 mod signature;
+mod str_format;
 mod types;
 mod value;
 
@@ -30,11 +33,15 @@ pub use crate::run::RefCountOutput;
 pub use crate::{
     exception_private::ExcType,
     exception_public::{CodeLoc, MontyException, StackFrame},
+    heap::HeapStats,
     io::{CollectStringPrint, NoPrint, PrintWriter, StdPrint},
     object::{DictPairs, InvalidInputError, MontyObject},
     os::{OsFunction, dir_stat, file_stat, stat_result, symlink_stat},
     resource::{
-        DEFAULT_MAX_RECURSION_DEPTH, LimitedTracker, NoLimitTracker, ResourceError, ResourceLimits, ResourceTracker,
+        DEFAULT_GC_INTERVAL, DEFAULT_MAX_RECURSION_DEPTH, LimitedTracker, NoLimitTracker, ResourceError,
+        ResourceLimits, ResourceTracker,
+    },
+    run::{
+        CompileOptions, ExternalResult, FutureSnapshot, MontyFunction, MontyFuture, MontyRun, RunProgress, Snapshot,
     },
-    run::{ExternalResult, FutureSnapshot, MontyFuture, MontyRun, RunProgress, Snapshot},
 };
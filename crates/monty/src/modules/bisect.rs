@@ -0,0 +1,174 @@
+//! Implementation of the `bisect` module.
+//!
+//! Provides Python's `bisect` module functions for sorted sequences:
+//! - `bisect_left(a, x, lo=0, hi=len(a))`: Index to insert `x` before any equal items
+//! - `bisect_right(a, x, lo=0, hi=len(a))`: Index to insert `x` after any equal items, also
+//!   available as `bisect`
+//! - `insort_left(a, x, lo=0, hi=len(a))`: Insert `x` into the list `a` at `bisect_left()`
+//! - `insort_right(a, x, lo=0, hi=len(a))`: Insert `x` into the list `a` at `bisect_right()`,
+//!   also available as `insort`
+//!
+//! The search works on any sequence, looking items up by index, so it only reads
+//! `log2(hi - lo)` items. `key` functions aren't supported, since module functions can't
+//! call back into Python code.
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::{ModuleFunctions, heapq::less_than},
+    resource::{DepthGuard, ResourceError, ResourceTracker},
+    types::{AttrCallResult, Module, PyTrait, re::int_arg},
+    value::Value,
+};
+
+/// Bisect module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum BisectFunctions {
+    BisectLeft,
+    BisectRight,
+    InsortLeft,
+    InsortRight,
+}
+
+/// Creates the `bisect` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Bisect);
+
+    for (name, function) in [
+        (StaticStrings::BisectLeft, BisectFunctions::BisectLeft),
+        (StaticStrings::BisectRight, BisectFunctions::BisectRight),
+        (StaticStrings::Bisect, BisectFunctions::BisectRight),
+        (StaticStrings::InsortLeft, BisectFunctions::InsortLeft),
+        (StaticStrings::InsortRight, BisectFunctions::InsortRight),
+        (StaticStrings::Insort, BisectFunctions::InsortRight),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Bisect(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a bisect module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    functions: BisectFunctions,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<AttrCallResult> {
+    let name = functions.to_string();
+    let [Some(a), Some(x), lo, hi, key] = args.bind(&name, ["a", "x", "lo", "hi", "key"], 2, heap, interns)? else {
+        unreachable!("bind checks required arguments")
+    };
+    defer_drop!(a, heap);
+
+    let right = matches!(functions, BisectFunctions::BisectRight | BisectFunctions::InsortRight);
+    let index = match bisect_index(a, &x, lo, hi, key, right, heap, interns) {
+        Ok(index) => index,
+        Err(err) => {
+            x.drop_with_heap(heap);
+            return Err(err);
+        }
+    };
+
+    match functions {
+        BisectFunctions::BisectLeft | BisectFunctions::BisectRight => {
+            x.drop_with_heap(heap);
+            Ok(AttrCallResult::Value(Value::Int(index)))
+        }
+        BisectFunctions::InsortLeft | BisectFunctions::InsortRight => {
+            if let Value::Ref(id) = a
+                && matches!(heap.get(*id), HeapData::List(_))
+            {
+                let index = usize::try_from(index).expect("bisect_index is never negative");
+                heap.with_entry_mut(*id, |heap, data| {
+                    if let HeapData::List(list) = data {
+                        list.insert(heap, index, x);
+                    }
+                });
+                heap.update_size(*id)?;
+                Ok(AttrCallResult::Value(Value::None))
+            } else {
+                // CPython calls `a.insert()`, which only lists have
+                x.drop_with_heap(heap);
+                Err(ExcType::attribute_error(a.py_type(heap), "insert"))
+            }
+        }
+    }
+}
+
+/// Finds where `x` belongs in the sorted slice `a[lo:hi]`.
+///
+/// With `right`, the index is after any items equal to `x`, otherwise before them. Like
+/// CPython, `hi` defaults to `len(a)` and isn't checked against it, so a larger `hi` can
+/// raise an `IndexError` from the lookup.
+#[expect(clippy::too_many_arguments)]
+fn bisect_index(
+    a: &Value,
+    x: &Value,
+    lo: Option<Value>,
+    hi: Option<Value>,
+    key: Option<Value>,
+    right: bool,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<i64> {
+    let key_given = key.as_ref().is_some_and(|key| !matches!(key, Value::None));
+    key.drop_with_heap(heap);
+    let lo = int_arg(lo, 0, heap);
+    let hi = match hi {
+        Some(Value::None) | None => Ok(None),
+        hi => int_arg(hi, 0, heap).map(Some),
+    };
+    let (mut lo, hi) = (lo?, hi?);
+    if key_given {
+        return Err(ExcType::not_implemented("bisect functions with a key function are not supported").into());
+    }
+    if lo < 0 {
+        return Err(SimpleException::new_msg(ExcType::ValueError, "lo must be non-negative").into());
+    }
+    let mut hi = match hi {
+        Some(hi) => hi,
+        None => match a.py_len(heap, interns) {
+            Some(len) => i64::try_from(len).expect("sequence length fits in i64"),
+            None => {
+                return Err(ExcType::type_error(format!(
+                    "object of type '{}' has no len()",
+                    a.py_type(heap)
+                )));
+            }
+        },
+    };
+
+    let mut guard = DepthGuard::default();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let item = a.py_getitem(&Value::Int(mid), heap, interns)?;
+        defer_drop!(item, heap);
+        let go_left = if right {
+            less_than(x, item, heap, &mut guard, interns)?
+        } else {
+            !less_than(item, x, heap, &mut guard, interns)?
+        };
+        if go_left {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Ok(lo)
+}
@@ -0,0 +1,69 @@
+//! Implementation of the `contextlib` module.
+//!
+//! Provides a minimal implementation of Python's `contextlib` module with:
+//! - `suppress(*exceptions)`: A context manager that swallows the listed exceptions
+//! - `nullcontext(enter_result=None)`: A context manager that does nothing
+//!
+//! Both are used with `with` statements, see [`crate::types::ContextManager`].
+
+use crate::{
+    args::ArgValues,
+    exception_private::RunResult,
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, ContextManager, Module},
+    value::Value,
+};
+
+/// Contextlib module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum ContextlibFunctions {
+    Suppress,
+    Nullcontext,
+}
+
+/// Creates the `contextlib` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Contextlib);
+
+    for (name, function) in [
+        (StaticStrings::Suppress, ContextlibFunctions::Suppress),
+        (StaticStrings::Nullcontext, ContextlibFunctions::Nullcontext),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Contextlib(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a contextlib module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    functions: ContextlibFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    let manager = match functions {
+        ContextlibFunctions::Suppress => ContextManager::Suppress(args.into_pos_only("suppress", heap)?.collect()),
+        ContextlibFunctions::Nullcontext => {
+            let enter_result = args.get_zero_one_arg("nullcontext", heap)?;
+            ContextManager::NullContext(enter_result.unwrap_or(Value::None))
+        }
+    };
+    Ok(AttrCallResult::Value(Value::Ref(
+        heap.allocate(HeapData::ContextManager(manager))?,
+    )))
+}
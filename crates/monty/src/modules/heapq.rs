@@ -0,0 +1,300 @@
+//! Implementation of the `heapq` module.
+//!
+//! Provides a minimal implementation of Python's `heapq` module with:
+//! - `heappush(heap, item)`: Push `item` onto the heap
+//! - `heappop(heap)`: Pop and return the smallest item from the heap
+//! - `heapify(x)`: Transform the list `x` into a heap in place
+//! - `nsmallest(n, iterable)`: The `n` smallest items of `iterable`, smallest first
+//! - `nlargest(n, iterable)`: The `n` largest items of `iterable`, largest first
+//!
+//! Heaps are regular lists kept in binary min-heap order (`heap[k] <= heap[2*k+1]` and
+//! `heap[k] <= heap[2*k+2]`). The sifting follows CPython exactly, so the resulting list
+//! layout is the same as in CPython for the same sequence of operations.
+//!
+//! `nsmallest()` and `nlargest()` don't support `key` functions, since module functions
+//! can't call back into Python code.
+
+use std::cmp::Ordering;
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{DepthGuard, ResourceError, ResourceTracker},
+    types::{AttrCallResult, List, Module, MontyIter, PyTrait, list::sort_values},
+    value::Value,
+};
+
+/// Heapq module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum HeapqFunctions {
+    Heappush,
+    Heappop,
+    Heapify,
+    Nsmallest,
+    Nlargest,
+}
+
+/// Creates the `heapq` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Heapq);
+
+    for (name, function) in [
+        (StaticStrings::Heappush, HeapqFunctions::Heappush),
+        (StaticStrings::Heappop, HeapqFunctions::Heappop),
+        (StaticStrings::Heapify, HeapqFunctions::Heapify),
+        (StaticStrings::Nsmallest, HeapqFunctions::Nsmallest),
+        (StaticStrings::Nlargest, HeapqFunctions::Nlargest),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Heapq(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a heapq module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    functions: HeapqFunctions,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<AttrCallResult> {
+    let value = match functions {
+        HeapqFunctions::Heappush => {
+            let (list, item) = args.get_two_args("heappush", heap)?;
+            defer_drop!(list, heap);
+            let list_id = match list_arg(list, "heappush() argument 1", heap) {
+                Ok(list_id) => list_id,
+                Err(err) => {
+                    item.drop_with_heap(heap);
+                    return Err(err);
+                }
+            };
+            // The item stays in the list even if a comparison fails, so charge it either way
+            let pushed = heappush(list_id, item, heap, interns);
+            heap.update_size(list_id)?;
+            pushed?;
+            Value::None
+        }
+        HeapqFunctions::Heappop => {
+            let list = args.get_one_arg("heappop", heap)?;
+            defer_drop!(list, heap);
+            let list_id = list_arg(list, "heappop() argument", heap)?;
+            heappop(list_id, heap, interns)?
+        }
+        HeapqFunctions::Heapify => {
+            let list = args.get_one_arg("heapify", heap)?;
+            defer_drop!(list, heap);
+            let list_id = list_arg(list, "heapify() argument", heap)?;
+            with_list_items(list_id, heap, |items, heap| {
+                let mut guard = DepthGuard::default();
+                for pos in (0..items.len() / 2).rev() {
+                    sift_up(items, pos, heap, &mut guard, interns)?;
+                }
+                Ok(())
+            })?;
+            Value::None
+        }
+        HeapqFunctions::Nsmallest | HeapqFunctions::Nlargest => {
+            let largest = functions == HeapqFunctions::Nlargest;
+            let name = if largest { "nlargest" } else { "nsmallest" };
+            let [Some(n), Some(iterable), key] = args.bind(name, ["n", "iterable", "key"], 2, heap, interns)? else {
+                unreachable!("bind checks required arguments")
+            };
+            defer_drop!(n, heap);
+            let key_given = key.as_ref().is_some_and(|key| !matches!(key, Value::None));
+            key.drop_with_heap(heap);
+            if key_given {
+                iterable.drop_with_heap(heap);
+                return Err(ExcType::not_implemented(format!("{name}() with a key function is not supported")).into());
+            }
+            let n = n.as_int(heap);
+            let n = match n {
+                Ok(n) => n,
+                Err(err) => {
+                    iterable.drop_with_heap(heap);
+                    return Err(err);
+                }
+            };
+            n_extreme(n, iterable, largest, heap, interns)?
+        }
+    };
+    Ok(AttrCallResult::Value(value))
+}
+
+/// Returns the id of the list `value`, or a `TypeError` naming `what` if it isn't a list.
+fn list_arg(value: &Value, what: &str, heap: &Heap<impl ResourceTracker>) -> RunResult<HeapId> {
+    if let Value::Ref(id) = value
+        && matches!(heap.get(*id), HeapData::List(_))
+    {
+        return Ok(*id);
+    }
+    Err(ExcType::type_error(format!(
+        "{what} must be list, not {}",
+        value.py_type(heap)
+    )))
+}
+
+/// Runs `f` on the items of the list at `list_id`.
+///
+/// The items are moved out of the list while `f` runs so that comparisons can use the
+/// heap, and are put back afterwards whether or not `f` succeeds. Comparisons can't run
+/// Python code, so nothing else can modify the list in the meantime.
+fn with_list_items<T: ResourceTracker, R>(
+    list_id: HeapId,
+    heap: &mut Heap<T>,
+    f: impl FnOnce(&mut Vec<Value>, &mut Heap<T>) -> RunResult<R>,
+) -> RunResult<R> {
+    let HeapData::List(list) = heap.get_mut(list_id) else {
+        unreachable!("list_arg checks the type")
+    };
+    let mut items = std::mem::take(list.as_vec_mut());
+    let result = f(&mut items, heap);
+    let contains_refs = items.iter().any(|item| matches!(item, Value::Ref(_)));
+    if contains_refs {
+        heap.mark_potential_cycle();
+    }
+    let HeapData::List(list) = heap.get_mut(list_id) else {
+        unreachable!("list_arg checks the type")
+    };
+    *list.as_vec_mut() = items;
+    if contains_refs {
+        list.set_contains_refs();
+    }
+    result
+}
+
+/// Implements `heappush()`: appends `item` and sifts it towards the root.
+///
+/// Like CPython, the item stays in the list if a comparison fails.
+fn heappush(list_id: HeapId, item: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<()> {
+    with_list_items(list_id, heap, |items, heap| {
+        items.push(item);
+        let pos = items.len() - 1;
+        sift_down(items, 0, pos, heap, &mut DepthGuard::default(), interns)
+    })
+}
+
+/// Implements `heappop()`: removes the root and moves the last item into its place.
+fn heappop(list_id: HeapId, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+    with_list_items(list_id, heap, |items, heap| {
+        let Some(last) = items.pop() else {
+            return Err(SimpleException::new_msg(ExcType::IndexError, "index out of range").into());
+        };
+        if items.is_empty() {
+            return Ok(last);
+        }
+        let smallest = std::mem::replace(&mut items[0], last);
+        match sift_up(items, 0, heap, &mut DepthGuard::default(), interns) {
+            Ok(()) => Ok(smallest),
+            Err(err) => {
+                smallest.drop_with_heap(heap);
+                Err(err)
+            }
+        }
+    })
+}
+
+/// Implements `nsmallest()` and `nlargest()`.
+///
+/// Equivalent to `sorted(iterable)[:n]` (or `sorted(iterable, reverse=True)[:n]`), which
+/// is how CPython documents them, so equal items keep their original order.
+fn n_extreme(
+    n: i64,
+    iterable: Value,
+    largest: bool,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Value> {
+    let mut iter = MontyIter::new(iterable, heap, interns)?;
+    let items: RunResult<Vec<Value>> = iter.collect(heap, interns);
+    iter.drop_with_heap(heap);
+    let mut items = items?;
+
+    if let Err(err) = sort_values(&mut items, None, largest, heap, interns) {
+        items.drop_with_heap(heap);
+        return Err(err);
+    }
+    let n = usize::try_from(n).unwrap_or(0);
+    if n < items.len() {
+        items.split_off(n).drop_with_heap(heap);
+    }
+    Ok(Value::Ref(heap.allocate(HeapData::List(List::new(items)))?))
+}
+
+/// Moves the item at `pos` up towards `start` until its parent is not greater than it.
+///
+/// This is CPython's `_siftdown`, named for the direction the parents move.
+fn sift_down(
+    items: &mut [Value],
+    start: usize,
+    mut pos: usize,
+    heap: &mut Heap<impl ResourceTracker>,
+    guard: &mut DepthGuard,
+    interns: &Interns,
+) -> RunResult<()> {
+    while pos > start {
+        let parent = (pos - 1) / 2;
+        if !less_than(&items[pos], &items[parent], heap, guard, interns)? {
+            break;
+        }
+        items.swap(pos, parent);
+        pos = parent;
+    }
+    Ok(())
+}
+
+/// Moves the item at `pos` down to a leaf along the path of smaller children, then back up
+/// to its proper place.
+///
+/// This is CPython's `_siftup`, which does fewer comparisons than stopping as soon as the
+/// item is in order, since the item usually belongs near the bottom.
+fn sift_up(
+    items: &mut [Value],
+    mut pos: usize,
+    heap: &mut Heap<impl ResourceTracker>,
+    guard: &mut DepthGuard,
+    interns: &Interns,
+) -> RunResult<()> {
+    let start = pos;
+    let mut child = 2 * pos + 1;
+    while child < items.len() {
+        let right = child + 1;
+        if right < items.len() && !less_than(&items[child], &items[right], heap, guard, interns)? {
+            child = right;
+        }
+        items.swap(pos, child);
+        pos = child;
+        child = 2 * pos + 1;
+    }
+    sift_down(items, start, pos, heap, guard, interns)
+}
+
+/// Evaluates `left < right`, raising a `TypeError` if the values can't be ordered.
+///
+/// NaN is less than nothing, as in CPython. Also used by the `bisect` module.
+pub(super) fn less_than(
+    left: &Value,
+    right: &Value,
+    heap: &mut Heap<impl ResourceTracker>,
+    guard: &mut DepthGuard,
+    interns: &Interns,
+) -> RunResult<bool> {
+    let ordering = left.py_cmp_for_op(right, "<", heap, guard, interns)?;
+    Ok(ordering == Some(Ordering::Less))
+}
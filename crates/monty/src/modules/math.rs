@@ -0,0 +1,448 @@
+//! Implementation of the `math` module.
+//!
+//! Provides a minimal implementation of Python's `math` module with:
+//! - `floor(x)`: Largest integer less than or equal to `x`
+//! - `ceil(x)`: Smallest integer greater than or equal to `x`
+//! - `trunc(x)`: `x` with its fractional part removed
+//! - `comb(n, k)`: Number of ways to choose `k` items from `n` without order
+//! - `perm(n, k=None)`: Number of ways to choose `k` items from `n` in order
+//! - `isclose(a, b, *, rel_tol=1e-09, abs_tol=0.0)`: Whether `a` and `b` are approximately equal
+//! - `isnan(x)`, `isinf(x)`, `isfinite(x)`: Classify `x` as a float
+//! - `degrees(x)`, `radians(x)`: Convert an angle between radians and degrees
+//! - `atan(x)`, `asin(x)`, `acos(x)`, `atan2(y, x)`: Inverse trigonometric functions, in radians
+//! - `hypot(*coordinates)`: Euclidean distance from the origin
+//!
+//! The rounding functions return an `int`, promoting to `LongInt` for floats outside the
+//! i64 range. The rounding itself is shared with `int()` through [`round_to_int`].
+//! `comb()` and `perm()` are computed exactly with `BigInt` arithmetic.
+
+use num_bigint::BigInt;
+use num_traits::{One, Signed, ToPrimitive};
+
+use crate::{
+    args::ArgValues,
+    defer_drop, defer_drop_mut,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker, check_pow_size},
+    types::{AttrCallResult, LongInt, Module, PyTrait},
+    value::{Value, extract_bigint, float_repr_fmt},
+};
+
+/// Math module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum MathFunctions {
+    Floor,
+    Ceil,
+    Trunc,
+    Comb,
+    Perm,
+    Isclose,
+    Isnan,
+    Isinf,
+    Isfinite,
+    Degrees,
+    Radians,
+    Atan,
+    Asin,
+    Acos,
+    Atan2,
+    Hypot,
+}
+
+/// Creates the `math` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Math);
+
+    for (name, function) in [
+        (StaticStrings::Floor, MathFunctions::Floor),
+        (StaticStrings::Ceil, MathFunctions::Ceil),
+        (StaticStrings::Trunc, MathFunctions::Trunc),
+        (StaticStrings::Comb, MathFunctions::Comb),
+        (StaticStrings::Perm, MathFunctions::Perm),
+        (StaticStrings::Isclose, MathFunctions::Isclose),
+        (StaticStrings::Isnan, MathFunctions::Isnan),
+        (StaticStrings::Isinf, MathFunctions::Isinf),
+        (StaticStrings::Isfinite, MathFunctions::Isfinite),
+        (StaticStrings::Degrees, MathFunctions::Degrees),
+        (StaticStrings::Radians, MathFunctions::Radians),
+        (StaticStrings::Atan, MathFunctions::Atan),
+        (StaticStrings::Asin, MathFunctions::Asin),
+        (StaticStrings::Acos, MathFunctions::Acos),
+        (StaticStrings::Atan2, MathFunctions::Atan2),
+        (StaticStrings::Hypot, MathFunctions::Hypot),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Math(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a math module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    functions: MathFunctions,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<AttrCallResult> {
+    let value = match functions {
+        MathFunctions::Floor => round_arg(heap, args, "math.floor", IntRounding::Floor)?,
+        MathFunctions::Ceil => round_arg(heap, args, "math.ceil", IntRounding::Ceil)?,
+        MathFunctions::Trunc => round_arg(heap, args, "math.trunc", IntRounding::Trunc)?,
+        MathFunctions::Comb => comb(heap, args)?,
+        MathFunctions::Perm => perm(heap, args)?,
+        MathFunctions::Isclose => isclose(heap, args, interns)?,
+        MathFunctions::Isnan => float_predicate(heap, args, "math.isnan", f64::is_nan)?,
+        MathFunctions::Isinf => float_predicate(heap, args, "math.isinf", f64::is_infinite)?,
+        MathFunctions::Isfinite => float_predicate(heap, args, "math.isfinite", f64::is_finite)?,
+        MathFunctions::Degrees => float_function(heap, args, "math.degrees", f64::to_degrees)?,
+        MathFunctions::Radians => float_function(heap, args, "math.radians", f64::to_radians)?,
+        MathFunctions::Atan => float_function(heap, args, "math.atan", f64::atan)?,
+        MathFunctions::Asin => unit_domain_function(heap, args, "math.asin", f64::asin)?,
+        MathFunctions::Acos => unit_domain_function(heap, args, "math.acos", f64::acos)?,
+        MathFunctions::Atan2 => atan2(heap, args)?,
+        MathFunctions::Hypot => hypot(heap, args)?,
+    };
+    Ok(AttrCallResult::Value(value))
+}
+
+/// Direction in which [`round_to_int`] rounds a float.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IntRounding {
+    /// Toward negative infinity, like `math.floor()`.
+    Floor,
+    /// Toward positive infinity, like `math.ceil()`.
+    Ceil,
+    /// Toward zero, like `math.trunc()` and `int()`.
+    Trunc,
+}
+
+/// Rounds a real number to an `int` in the given direction.
+///
+/// Ints (including bools, which become plain ints) and `LongInt`s are already integral and
+/// come back unchanged. Floats are rounded and then converted with [`LongInt::from_f64`],
+/// so large values promote to `LongInt` and NaN or infinity raise like CPython.
+///
+/// Returns `Ok(None)` if `value` is not a real number, so callers can raise their own
+/// `TypeError`.
+pub(crate) fn round_to_int(
+    value: &Value,
+    rounding: IntRounding,
+    heap: &mut Heap<impl ResourceTracker>,
+) -> RunResult<Option<Value>> {
+    let rounded = match value {
+        Value::Int(i) => Value::Int(*i),
+        Value::Bool(b) => Value::Int(i64::from(*b)),
+        Value::InternLongInt(id) => Value::InternLongInt(*id),
+        Value::Float(f) => {
+            let f = match rounding {
+                IntRounding::Floor => f.floor(),
+                IntRounding::Ceil => f.ceil(),
+                IntRounding::Trunc => f.trunc(),
+            };
+            LongInt::from_f64(f, heap)?
+        }
+        Value::Ref(id) if matches!(heap.get(*id), HeapData::LongInt(_)) => value.clone_with_heap(heap),
+        _ => return Ok(None),
+    };
+    Ok(Some(rounded))
+}
+
+/// Implements `math.floor()`, `math.ceil()` and `math.trunc()`.
+fn round_arg(
+    heap: &mut Heap<impl ResourceTracker>,
+    args: ArgValues,
+    name: &str,
+    rounding: IntRounding,
+) -> RunResult<Value> {
+    let x = args.get_one_arg(name, heap)?;
+    defer_drop!(x, heap);
+    match round_to_int(x, rounding, heap)? {
+        Some(value) => Ok(value),
+        None if rounding == IntRounding::Trunc => Err(ExcType::type_error(format!(
+            "type {} doesn't define __trunc__ method",
+            x.py_type(heap)
+        ))),
+        None => Err(ExcType::type_error(format!(
+            "must be real number, not {}",
+            x.py_type(heap)
+        ))),
+    }
+}
+
+/// Implements `math.comb(n, k)`, the binomial coefficient `n! / (k! * (n - k)!)`.
+///
+/// Uses the multiplicative formula over `min(k, n - k)` steps, dividing as it goes so every
+/// intermediate value is itself a binomial coefficient and the division is always exact.
+fn comb(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (n, k) = args.get_two_args("comb", heap)?;
+    defer_drop!(n, heap);
+    defer_drop!(k, heap);
+    let n = nonnegative_int_arg(n, "n", heap)?;
+    let k = nonnegative_int_arg(k, "k", heap)?;
+    if k > n {
+        return Ok(Value::Int(0));
+    }
+
+    let k = (&n - &k).min(k);
+    let Some(k) = k.to_i64() else {
+        return Err(overflow_error(&format!("min(n - k, k) must not exceed {}", i64::MAX)));
+    };
+    // The result is at most n ** k
+    check_pow_size(n.bits(), k.unsigned_abs(), heap.tracker())?;
+
+    let start = &n - k;
+    let mut result = BigInt::one();
+    for i in 1..=k {
+        heap.tracker_mut().check_time()?;
+        result *= &start + i;
+        result /= i;
+    }
+    Ok(LongInt::new(result).into_value(heap)?)
+}
+
+/// Implements `math.perm(n, k=None)`, the number of `k`-permutations `n! / (n - k)!`.
+///
+/// With `k` omitted this is `n!`.
+fn perm(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (n, k) = args.get_one_two_args("perm", heap)?;
+    defer_drop!(n, heap);
+    defer_drop!(k, heap);
+    let (n, k) = match k {
+        // Like CPython, `perm(n)` is `factorial(n)`, including its error message
+        Some(Value::None) | None => {
+            let n = int_arg(n, heap)?;
+            if n.is_negative() {
+                return Err(value_error("factorial() not defined for negative values"));
+            }
+            (n.clone(), n)
+        }
+        Some(k) => (nonnegative_int_arg(n, "n", heap)?, nonnegative_int_arg(k, "k", heap)?),
+    };
+    if k > n {
+        return Ok(Value::Int(0));
+    }
+
+    let Some(k) = k.to_i64() else {
+        return Err(overflow_error(&format!("k must not exceed {}", i64::MAX)));
+    };
+    // The result is at most n ** k
+    check_pow_size(n.bits(), k.unsigned_abs(), heap.tracker())?;
+
+    let mut result = BigInt::one();
+    for i in 0..k {
+        heap.tracker_mut().check_time()?;
+        result *= &n - i;
+    }
+    Ok(LongInt::new(result).into_value(heap)?)
+}
+
+/// Implements `math.isclose(a, b, *, rel_tol=1e-09, abs_tol=0.0)`.
+///
+/// Uses CPython's formula: the values are close if their difference is within `rel_tol` of
+/// either value's magnitude, or within `abs_tol`. Infinities are only close to themselves and
+/// NaN is close to nothing, not even itself.
+fn isclose(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
+    let [Some(a), Some(b), rel_tol, abs_tol] =
+        args.bind("isclose", ["a", "b", "rel_tol", "abs_tol"], 2, heap, interns)?
+    else {
+        unreachable!("bind checks required arguments")
+    };
+    defer_drop!(a, heap);
+    defer_drop!(b, heap);
+    defer_drop!(rel_tol, heap);
+    defer_drop!(abs_tol, heap);
+
+    let a = float_arg(a, heap)?;
+    let b = float_arg(b, heap)?;
+    let rel_tol = rel_tol.as_ref().map_or(Ok(1e-9), |tol| float_arg(tol, heap))?;
+    let abs_tol = abs_tol.as_ref().map_or(Ok(0.0), |tol| float_arg(tol, heap))?;
+    if rel_tol < 0.0 || abs_tol < 0.0 {
+        return Err(value_error("tolerances must be non-negative"));
+    }
+
+    // Catches equal infinities, which the difference below can't
+    if a == b {
+        return Ok(Value::Bool(true));
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return Ok(Value::Bool(false));
+    }
+    let diff = (b - a).abs();
+    let close = diff <= (rel_tol * b).abs() || diff <= (rel_tol * a).abs() || diff <= abs_tol;
+    Ok(Value::Bool(close))
+}
+
+/// Implements `math.isnan()`, `math.isinf()` and `math.isfinite()`.
+fn float_predicate(
+    heap: &mut Heap<impl ResourceTracker>,
+    args: ArgValues,
+    name: &str,
+    predicate: fn(f64) -> bool,
+) -> RunResult<Value> {
+    let x = args.get_one_arg(name, heap)?;
+    defer_drop!(x, heap);
+    Ok(Value::Bool(predicate(float_arg(x, heap)?)))
+}
+
+/// Implements single-argument float functions that are defined for every float.
+fn float_function(
+    heap: &mut Heap<impl ResourceTracker>,
+    args: ArgValues,
+    name: &str,
+    function: fn(f64) -> f64,
+) -> RunResult<Value> {
+    let x = args.get_one_arg(name, heap)?;
+    defer_drop!(x, heap);
+    Ok(Value::Float(function(float_arg(x, heap)?)))
+}
+
+/// Implements `math.asin()` and `math.acos()`, which are only defined from -1 to 1.
+///
+/// NaN is passed through rather than rejected, like CPython.
+fn unit_domain_function(
+    heap: &mut Heap<impl ResourceTracker>,
+    args: ArgValues,
+    name: &str,
+    function: fn(f64) -> f64,
+) -> RunResult<Value> {
+    let x = args.get_one_arg(name, heap)?;
+    defer_drop!(x, heap);
+    let x = float_arg(x, heap)?;
+    if x.abs() > 1.0 {
+        let mut repr = String::new();
+        float_repr_fmt(x, &mut repr).expect("writing to a String cannot fail");
+        return Err(value_error(&format!(
+            "expected a number in range from -1 up to 1, got {repr}"
+        )));
+    }
+    Ok(Value::Float(function(x)))
+}
+
+/// Implements `math.atan2(y, x)`, the angle of the point `(x, y)` from the positive x axis.
+fn atan2(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (y, x) = args.get_two_args("atan2", heap)?;
+    defer_drop!(y, heap);
+    defer_drop!(x, heap);
+    let y = float_arg(y, heap)?;
+    let x = float_arg(x, heap)?;
+    Ok(Value::Float(y.atan2(x)))
+}
+
+/// Implements `math.hypot(*coordinates)`, the Euclidean norm of any number of coordinates.
+///
+/// An infinite coordinate gives `inf` even if another is NaN, and no coordinates give `0.0`.
+fn hypot(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let coordinates = args.into_pos_only("math.hypot", heap)?;
+    defer_drop_mut!(coordinates, heap);
+    let mut floats = Vec::with_capacity(coordinates.len());
+    for coordinate in coordinates.by_ref() {
+        defer_drop!(coordinate, heap);
+        floats.push(float_arg(coordinate, heap)?.abs());
+    }
+    Ok(Value::Float(vector_norm(&floats)))
+}
+
+/// Computes the Euclidean norm of non-negative floats without overflowing or underflowing.
+///
+/// Large or tiny vectors are first scaled by a power of two, which is exact, so squaring the
+/// components stays in range. The squares are then summed with their rounding errors carried
+/// alongside (each product's error is recovered exactly with a fused multiply-add), so exact
+/// cases like `hypot(3, 4) == 5.0` come out exact and other results agree with CPython's.
+fn vector_norm(floats: &[f64]) -> f64 {
+    let max = floats.iter().copied().fold(0.0, f64::max);
+    if max.is_infinite() {
+        return f64::INFINITY;
+    }
+    if floats.iter().any(|f| f.is_nan()) {
+        return f64::NAN;
+    }
+    if max == 0.0 {
+        return 0.0;
+    }
+
+    let scale = if max > 2f64.powi(500) {
+        2f64.powi(-600)
+    } else if max < 2f64.powi(-500) {
+        2f64.powi(600)
+    } else {
+        1.0
+    };
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+    for &f in floats {
+        let f = f * scale;
+        let square = f * f;
+        compensation += f.mul_add(f, -square);
+        // Two-sum: the exact rounding error of `sum + square`
+        let total = sum + square;
+        compensation += if sum >= square {
+            (sum - total) + square
+        } else {
+            (square - total) + sum
+        };
+        sum = total;
+    }
+    // One Newton step against the exact residual corrects the last bit of the square root
+    let mut norm = (sum + compensation).sqrt();
+    let square = norm * norm;
+    let residual = ((sum - square) - norm.mul_add(norm, -square)) + compensation;
+    norm += residual / (2.0 * norm);
+    norm / scale
+}
+
+/// Converts a real number argument to a float like CPython's `math` functions.
+///
+/// Ints that don't fit in a float raise `OverflowError`; anything else raises `TypeError`.
+fn float_arg(value: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<f64> {
+    if let Value::Float(f) = value {
+        return Ok(*f);
+    }
+    let Some(int) = extract_bigint(value, heap) else {
+        return Err(ExcType::type_error(format!(
+            "must be real number, not {}",
+            value.py_type(heap)
+        )));
+    };
+    int.to_f64()
+        .filter(|f| f.is_finite())
+        .ok_or_else(|| overflow_error("int too large to convert to float"))
+}
+
+/// Extracts the argument `name` of `math.comb()` or `math.perm()` as a non-negative integer.
+fn nonnegative_int_arg(value: &Value, name: &str, heap: &Heap<impl ResourceTracker>) -> RunResult<BigInt> {
+    let int = int_arg(value, heap)?;
+    if int.is_negative() {
+        return Err(value_error(&format!("{name} must be a non-negative integer")));
+    }
+    Ok(int)
+}
+
+/// Extracts an integer argument, raising `TypeError` for anything but an int or bool.
+fn int_arg(value: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<BigInt> {
+    extract_bigint(value, heap).ok_or_else(|| ExcType::type_error_not_integer(value.py_type(heap)))
+}
+
+/// Creates a `ValueError` for an out-of-range argument.
+fn value_error(message: &str) -> RunError {
+    SimpleException::new_msg(ExcType::ValueError, message).into()
+}
+
+/// Creates an `OverflowError` for an argument too large to work with.
+fn overflow_error(message: &str) -> RunError {
+    SimpleException::new_msg(ExcType::OverflowError, message).into()
+}
@@ -1,7 +1,8 @@
 //! Built-in module implementations.
 //!
 //! This module provides implementations for Python built-in modules like `sys`, `typing`,
-//! and `asyncio`. These are created on-demand when import statements are executed.
+//! `asyncio`, `math`, `re`, `operator`, `heapq`, `bisect`, `statistics`, `contextlib` and
+//! `warnings`. These are created on-demand when import statements are executed.
 
 use std::fmt::{self, Write};
 
@@ -9,18 +10,26 @@ use strum::FromRepr;
 
 use crate::{
     args::ArgValues,
-    exception_private::RunResult,
-    heap::{Heap, HeapId},
+    exception_private::{RunError, RunResult},
+    heap::{DropWithHeap, Heap, HeapId},
     intern::{Interns, StaticStrings, StringId},
     resource::{ResourceError, ResourceTracker},
     types::AttrCallResult,
 };
 
 pub(crate) mod asyncio;
+pub(crate) mod bisect;
+pub(crate) mod contextlib;
+pub(crate) mod heapq;
+pub(crate) mod math;
+pub(crate) mod operator;
 pub(crate) mod os;
 pub(crate) mod pathlib;
+pub(crate) mod re;
+pub(crate) mod statistics;
 pub(crate) mod sys;
 pub(crate) mod typing;
+pub(crate) mod warnings;
 
 /// Built-in modules that can be imported.
 #[repr(u8)]
@@ -36,6 +45,22 @@ pub(crate) enum BuiltinModule {
     Pathlib,
     /// The `os` module providing operating system interface (only `getenv()` implemented).
     Os,
+    /// The `math` module providing mathematical functions (a subset, see [`math`]).
+    Math,
+    /// The `warnings` module for issuing warnings to the host (only `warn()` implemented).
+    Warnings,
+    /// The `re` module for regular expressions (a subset, see [`crate::types::re`]).
+    Re,
+    /// The `operator` module (only `itemgetter()` and `attrgetter()` implemented).
+    Operator,
+    /// The `heapq` module for binary heaps stored in lists.
+    Heapq,
+    /// The `bisect` module for searching and inserting into sorted lists.
+    Bisect,
+    /// The `statistics` module (only averages, `mode()` and the spread functions implemented).
+    Statistics,
+    /// The `contextlib` module (only `suppress()` and `nullcontext()` implemented).
+    Contextlib,
 }
 
 impl BuiltinModule {
@@ -47,6 +72,14 @@ impl BuiltinModule {
             StaticStrings::Asyncio => Some(Self::Asyncio),
             StaticStrings::Pathlib => Some(Self::Pathlib),
             StaticStrings::Os => Some(Self::Os),
+            StaticStrings::Math => Some(Self::Math),
+            StaticStrings::Warnings => Some(Self::Warnings),
+            StaticStrings::Re => Some(Self::Re),
+            StaticStrings::Operator => Some(Self::Operator),
+            StaticStrings::Heapq => Some(Self::Heapq),
+            StaticStrings::Bisect => Some(Self::Bisect),
+            StaticStrings::Statistics => Some(Self::Statistics),
+            StaticStrings::Contextlib => Some(Self::Contextlib),
             _ => None,
         }
     }
@@ -65,6 +98,14 @@ impl BuiltinModule {
             Self::Asyncio => asyncio::create_module(heap, interns),
             Self::Pathlib => pathlib::create_module(heap, interns),
             Self::Os => os::create_module(heap, interns),
+            Self::Math => math::create_module(heap, interns),
+            Self::Warnings => warnings::create_module(heap, interns),
+            Self::Re => re::create_module(heap, interns),
+            Self::Operator => operator::create_module(heap, interns),
+            Self::Heapq => heapq::create_module(heap, interns),
+            Self::Bisect => bisect::create_module(heap, interns),
+            Self::Statistics => statistics::create_module(heap, interns),
+            Self::Contextlib => contextlib::create_module(heap, interns),
         }
     }
 }
@@ -74,6 +115,14 @@ impl BuiltinModule {
 pub(crate) enum ModuleFunctions {
     Asyncio(asyncio::AsyncioFunctions),
     Os(os::OsFunctions),
+    Math(math::MathFunctions),
+    Warnings(warnings::WarningsFunctions),
+    Re(re::ReFunctions),
+    Operator(operator::OperatorFunctions),
+    Heapq(heapq::HeapqFunctions),
+    Bisect(bisect::BisectFunctions),
+    Statistics(statistics::StatisticsFunctions),
+    Contextlib(contextlib::ContextlibFunctions),
 }
 
 impl fmt::Display for ModuleFunctions {
@@ -81,6 +130,14 @@ impl fmt::Display for ModuleFunctions {
         match self {
             Self::Asyncio(func) => write!(f, "{func}"),
             Self::Os(func) => write!(f, "{func}"),
+            Self::Math(func) => write!(f, "{func}"),
+            Self::Warnings(func) => write!(f, "{func}"),
+            Self::Re(func) => write!(f, "{func}"),
+            Self::Operator(func) => write!(f, "{func}"),
+            Self::Heapq(func) => write!(f, "{func}"),
+            Self::Bisect(func) => write!(f, "{func}"),
+            Self::Statistics(func) => write!(f, "{func}"),
+            Self::Contextlib(func) => write!(f, "{func}"),
         }
     }
 }
@@ -90,10 +147,29 @@ impl ModuleFunctions {
     ///
     /// Returns `AttrCallResult` to support both immediate values and OS calls that
     /// require host involvement (e.g., `os.getenv()` needs the host to provide environment variables).
-    pub fn call(self, heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<AttrCallResult> {
+    ///
+    /// Warnings functions need the print writer, so the VM calls them through [`warnings::call`]
+    /// instead and they can't be reached here.
+    pub fn call(
+        self,
+        heap: &mut Heap<impl ResourceTracker>,
+        args: ArgValues,
+        interns: &Interns,
+    ) -> RunResult<AttrCallResult> {
         match self {
             Self::Asyncio(functions) => asyncio::call(heap, functions, args),
             Self::Os(functions) => os::call(heap, functions, args),
+            Self::Math(functions) => math::call(heap, functions, args, interns),
+            Self::Warnings(_) => {
+                args.drop_with_heap(heap);
+                Err(RunError::internal("warnings functions must be called through the VM"))
+            }
+            Self::Re(functions) => re::call(heap, functions, args, interns),
+            Self::Operator(functions) => operator::call(heap, functions, args, interns),
+            Self::Heapq(functions) => heapq::call(heap, functions, args, interns),
+            Self::Bisect(functions) => bisect::call(heap, functions, args, interns),
+            Self::Statistics(functions) => statistics::call(heap, functions, args, interns),
+            Self::Contextlib(functions) => contextlib::call(heap, functions, args),
         }
     }
 
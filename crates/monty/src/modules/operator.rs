@@ -0,0 +1,76 @@
+//! Implementation of the `operator` module.
+//!
+//! Provides a minimal implementation of Python's `operator` module with:
+//! - `itemgetter(*items)`: Returns a callable that subscripts its argument with the items
+//! - `attrgetter(*attrs)`: Returns a callable that looks up the (possibly dotted) attributes
+//!
+//! Both are mostly used as `key` functions for `sorted()`, `min()` and `max()`.
+
+use crate::{
+    args::ArgValues,
+    exception_private::{ExcType, RunResult},
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, Getter, Module},
+    value::Value,
+};
+
+/// Operator module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum OperatorFunctions {
+    Itemgetter,
+    Attrgetter,
+}
+
+/// Creates the `operator` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Operator);
+
+    for (name, function) in [
+        (StaticStrings::Itemgetter, OperatorFunctions::Itemgetter),
+        (StaticStrings::Attrgetter, OperatorFunctions::Attrgetter),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Operator(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to an operator module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    functions: OperatorFunctions,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<AttrCallResult> {
+    let getter = match functions {
+        OperatorFunctions::Itemgetter => {
+            let items: Vec<Value> = args.into_pos_only("itemgetter", heap)?.collect();
+            if items.is_empty() {
+                return Err(ExcType::type_error("itemgetter expected 1 argument, got 0"));
+            }
+            Getter::Item(items)
+        }
+        OperatorFunctions::Attrgetter => {
+            let names: Vec<Value> = args.into_pos_only("attrgetter", heap)?.collect();
+            Getter::attrgetter(names, heap, interns)?
+        }
+    };
+    Ok(AttrCallResult::Value(Value::Ref(
+        heap.allocate(HeapData::Getter(getter))?,
+    )))
+}
@@ -0,0 +1,186 @@
+//! Implementation of the `re` module.
+//!
+//! Provides a subset of Python's `re` module:
+//! - `compile(pattern, flags=0)`: Compile a pattern into a `re.Pattern`
+//! - `match(pattern, string, flags=0)`: Match at the start of `string`
+//! - `search(pattern, string, flags=0)`: Find the first match anywhere in `string`
+//! - `findall(pattern, string, flags=0)`: List all non-overlapping matches
+//! - `sub(pattern, repl, string, count=0, flags=0)`: Replace matches with `repl`
+//! - `split(pattern, string, maxsplit=0, flags=0)`: Split `string` by the matches
+//! - The flags `IGNORECASE`/`I`, `MULTILINE`/`M`, `DOTALL`/`S` and `VERBOSE`/`X`
+//!
+//! Matching is done with the Rust `regex` crate, see [`crate::types::re`] for the supported
+//! pattern syntax and the differences from CPython.
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings, StringId},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{
+        AttrCallResult, Module, RePattern,
+        re::{self, int_arg, repl_arg, text_arg},
+    },
+    value::Value,
+};
+
+/// Re module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum ReFunctions {
+    Compile,
+    Match,
+    Search,
+    Findall,
+    Sub,
+    Split,
+}
+
+/// Creates the `re` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Re);
+
+    for (name, function) in [
+        (StaticStrings::Compile, ReFunctions::Compile),
+        (StaticStrings::Match, ReFunctions::Match),
+        (StaticStrings::Search, ReFunctions::Search),
+        (StaticStrings::Findall, ReFunctions::Findall),
+        (StaticStrings::Sub, ReFunctions::Sub),
+        (StaticStrings::Split, ReFunctions::Split),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Re(function)),
+            heap,
+            interns,
+        );
+    }
+
+    for (name, short_name, flag) in [
+        (StaticStrings::Ignorecase, b'I', re::IGNORECASE),
+        (StaticStrings::Multiline, b'M', re::MULTILINE),
+        (StaticStrings::Dotall, b'S', re::DOTALL),
+        (StaticStrings::Verbose, b'X', re::VERBOSE),
+    ] {
+        module.set_attr(name, Value::Int(flag), heap, interns);
+        module.set_attr(StringId::from_ascii(short_name), Value::Int(flag), heap, interns);
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a re module function.
+///
+/// Arguments are all converted before any error is returned, so that values passed to
+/// later parameters aren't leaked when an earlier one is invalid.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    functions: ReFunctions,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<AttrCallResult> {
+    let value = match functions {
+        ReFunctions::Compile => {
+            let [Some(pattern), flags] = args.bind("compile", ["pattern", "flags"], 1, heap, interns)? else {
+                unreachable!("bind checks required arguments")
+            };
+            // Compiling a compiled pattern returns it unchanged, like CPython
+            if let Value::Ref(id) = pattern
+                && matches!(heap.get(id), HeapData::RePattern(_))
+                && flags.is_none()
+            {
+                return Ok(AttrCallResult::Value(pattern));
+            }
+            let pattern = pattern_arg(pattern, flags, heap, interns)?;
+            Value::Ref(heap.allocate(HeapData::RePattern(pattern))?)
+        }
+        ReFunctions::Match | ReFunctions::Search => {
+            let name = if functions == ReFunctions::Match {
+                "match"
+            } else {
+                "search"
+            };
+            let [Some(pattern), Some(string), flags] =
+                args.bind(name, ["pattern", "string", "flags"], 2, heap, interns)?
+            else {
+                unreachable!("bind checks required arguments")
+            };
+            let pattern = pattern_arg(pattern, flags, heap, interns);
+            let text = text_arg(string, heap, interns);
+            pattern?.find_match(text?, functions == ReFunctions::Match, heap)?
+        }
+        ReFunctions::Findall => {
+            let [Some(pattern), Some(string), flags] =
+                args.bind("findall", ["pattern", "string", "flags"], 2, heap, interns)?
+            else {
+                unreachable!("bind checks required arguments")
+            };
+            let pattern = pattern_arg(pattern, flags, heap, interns);
+            let text = text_arg(string, heap, interns);
+            pattern?.findall(&text?, heap)?
+        }
+        ReFunctions::Sub => {
+            let [Some(pattern), Some(repl), Some(string), count, flags] =
+                args.bind("sub", ["pattern", "repl", "string", "count", "flags"], 3, heap, interns)?
+            else {
+                unreachable!("bind checks required arguments")
+            };
+            let pattern = pattern_arg(pattern, flags, heap, interns);
+            let repl = repl_arg(repl, heap, interns);
+            let text = text_arg(string, heap, interns);
+            let count = int_arg(count, 0, heap);
+            pattern?.sub(&repl?, &text?, count?, heap)?
+        }
+        ReFunctions::Split => {
+            let [Some(pattern), Some(string), maxsplit, flags] =
+                args.bind("split", ["pattern", "string", "maxsplit", "flags"], 2, heap, interns)?
+            else {
+                unreachable!("bind checks required arguments")
+            };
+            let pattern = pattern_arg(pattern, flags, heap, interns);
+            let text = text_arg(string, heap, interns);
+            let maxsplit = int_arg(maxsplit, 0, heap);
+            pattern?.split(&text?, maxsplit?, heap)?
+        }
+    };
+    Ok(AttrCallResult::Value(value))
+}
+
+/// Converts the `pattern` and `flags` arguments of a module function into a compiled pattern.
+///
+/// `pattern` may be a `str` or an already compiled pattern. Compiled patterns carry their
+/// own flags, so passing flags as well is an error like in CPython.
+fn pattern_arg(
+    pattern: Value,
+    flags: Option<Value>,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<RePattern> {
+    defer_drop!(pattern, heap);
+    let flags = int_arg(flags, 0, heap)?;
+    if let Value::Ref(id) = pattern
+        && let HeapData::RePattern(compiled) = heap.get(*id)
+    {
+        if flags != 0 {
+            return Err(SimpleException::new_msg(
+                ExcType::ValueError,
+                "cannot process flags argument with a compiled pattern",
+            )
+            .into());
+        }
+        return Ok(compiled.clone());
+    }
+    match pattern.as_either_str(heap) {
+        Some(source) => RePattern::new(source.as_str(interns).to_owned(), flags),
+        None => Err(ExcType::type_error("first argument must be string or compiled pattern")),
+    }
+}
@@ -0,0 +1,389 @@
+//! Implementation of the `statistics` module.
+//!
+//! Provides a subset of Python's `statistics` module over iterables of numbers:
+//! - `mean(data)`: Arithmetic mean
+//! - `median(data)`: Middle value, or the mean of the two middle values
+//! - `mode(data)`: Most common value, the first one seen if several are equally common
+//! - `pstdev(data)`: Population standard deviation
+//! - `stdev(data)`: Sample standard deviation
+//! - `variance(data)`: Sample variance
+//!
+//! Data made only of ints and bools is computed exactly with `BigInt` arithmetic, so like
+//! CPython `mean()` and `variance()` return an `int` when the result is whole. Once a float
+//! is involved the variance is accumulated with Welford's algorithm, which avoids the
+//! cancellation of subtracting large sums of squares. CPython uses exact fractions for
+//! floats too, so float results can differ from it in the last digit.
+//!
+//! CPython raises `StatisticsError`, a subclass of `ValueError`. Here `StatisticsError` is an
+//! alias of `ValueError`, so both `except statistics.StatisticsError` and `except ValueError`
+//! catch it. The optional `xbar` and `mu` arguments aren't supported.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::{
+    args::ArgValues,
+    builtins::Builtins,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker, check_mult_size},
+    types::{AttrCallResult, Dict, LongInt, Module, MontyIter, PyTrait, Type, list::sort_values},
+    value::{Value, extract_bigint},
+};
+
+/// Statistics module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum StatisticsFunctions {
+    Mean,
+    Median,
+    Mode,
+    Pstdev,
+    Stdev,
+    Variance,
+}
+
+/// Creates the `statistics` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Statistics);
+
+    for (name, function) in [
+        (StaticStrings::Mean, StatisticsFunctions::Mean),
+        (StaticStrings::Median, StatisticsFunctions::Median),
+        (StaticStrings::Mode, StatisticsFunctions::Mode),
+        (StaticStrings::Pstdev, StatisticsFunctions::Pstdev),
+        (StaticStrings::Stdev, StatisticsFunctions::Stdev),
+        (StaticStrings::Variance, StatisticsFunctions::Variance),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Statistics(function)),
+            heap,
+            interns,
+        );
+    }
+    module.set_attr(
+        StaticStrings::StatisticsError,
+        Value::Builtin(Builtins::ExcType(ExcType::ValueError)),
+        heap,
+        interns,
+    );
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a statistics module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    functions: StatisticsFunctions,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<AttrCallResult> {
+    let [Some(data)] = args.bind(&functions.to_string(), ["data"], 1, heap, interns)? else {
+        unreachable!("bind checks required arguments")
+    };
+    let value = match functions {
+        StatisticsFunctions::Median => median(data, heap, interns)?,
+        StatisticsFunctions::Mode => mode(data, heap, interns)?,
+        StatisticsFunctions::Mean => {
+            let numbers = Numbers::collect(data, heap, interns)?;
+            if numbers.is_empty() {
+                return Err(statistics_error("mean requires at least one data point"));
+            }
+            numbers.mean().into_value(heap)?
+        }
+        StatisticsFunctions::Variance => {
+            let numbers = Numbers::collect(data, heap, interns)?;
+            if numbers.len() < 2 {
+                return Err(statistics_error("variance requires at least two data points"));
+            }
+            numbers.variance(1, heap)?.into_value(heap)?
+        }
+        StatisticsFunctions::Stdev => {
+            let numbers = Numbers::collect(data, heap, interns)?;
+            if numbers.len() < 2 {
+                return Err(statistics_error("stdev requires at least two data points"));
+            }
+            Value::Float(numbers.variance(1, heap)?.to_f64()?.sqrt())
+        }
+        StatisticsFunctions::Pstdev => {
+            let numbers = Numbers::collect(data, heap, interns)?;
+            if numbers.is_empty() {
+                return Err(statistics_error("pstdev requires at least one data point"));
+            }
+            Value::Float(numbers.variance(0, heap)?.to_f64()?.sqrt())
+        }
+    };
+    Ok(AttrCallResult::Value(value))
+}
+
+/// Implements `statistics.median()`.
+///
+/// Like CPython this sorts the data and, for an even count, returns
+/// `(data[i - 1] + data[i]) / 2`, so it works on anything that can be ordered and averaged.
+fn median(data: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+    let mut iter = MontyIter::new(data, heap, interns)?;
+    let items: RunResult<Vec<Value>> = iter.collect(heap, interns);
+    iter.drop_with_heap(heap);
+    let mut items = items?;
+    if let Err(err) = sort_values(&mut items, None, false, heap, interns) {
+        items.drop_with_heap(heap);
+        return Err(err);
+    }
+    defer_drop!(items, heap);
+
+    let n = items.len();
+    if n == 0 {
+        return Err(statistics_error("no median for empty data"));
+    }
+    if n % 2 == 1 {
+        return Ok(items[n / 2].clone_with_heap(heap));
+    }
+    let (low, high) = (&items[n / 2 - 1], &items[n / 2]);
+    let Some(sum) = low.py_add(high, heap, interns)? else {
+        return Err(ExcType::binary_type_error("+", low.py_type(heap), high.py_type(heap)));
+    };
+    defer_drop!(sum, heap);
+    match sum.py_div(&Value::Int(2), heap, interns)? {
+        Some(middle) => Ok(middle),
+        None => Err(ExcType::binary_type_error("/", sum.py_type(heap), Type::Int)),
+    }
+}
+
+/// Implements `statistics.mode()`, counting the items in a dict like CPython's `Counter`.
+fn mode(data: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+    let mut iter = MontyIter::new(data, heap, interns)?;
+    let mut counts = Dict::new();
+    let counted = count_items(&mut iter, &mut counts, heap, interns);
+    iter.drop_with_heap(heap);
+
+    // The counts are ints, so only the items need dropping
+    let mut most_common: Option<(Value, i64)> = None;
+    for (item, count) in counts {
+        let Value::Int(count) = count else {
+            unreachable!("mode counts are ints")
+        };
+        // Dicts keep insertion order, so on ties the first item seen wins
+        if most_common.as_ref().is_none_or(|(_, best)| count > *best) {
+            most_common
+                .replace((item, count))
+                .map(|(item, _)| item)
+                .drop_with_heap(heap);
+        } else {
+            item.drop_with_heap(heap);
+        }
+    }
+    let most_common = most_common.map(|(item, _)| item);
+    if let Err(err) = counted {
+        most_common.drop_with_heap(heap);
+        return Err(err);
+    }
+    most_common.ok_or_else(|| statistics_error("no mode for empty data"))
+}
+
+/// Counts how many times each item of `iter` occurs, in order of first occurrence.
+fn count_items(
+    iter: &mut MontyIter,
+    counts: &mut Dict,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<()> {
+    while let Some(item) = iter.for_next(heap, interns)? {
+        let count = match counts.get(&item, heap, interns) {
+            Ok(Some(Value::Int(count))) => *count,
+            Ok(_) => 0,
+            Err(err) => {
+                item.drop_with_heap(heap);
+                return Err(err);
+            }
+        };
+        counts
+            .set(item, Value::Int(count + 1), heap, interns)?
+            .drop_with_heap(heap);
+    }
+    Ok(())
+}
+
+/// The numeric data of a `statistics` function.
+///
+/// Stays exact as long as every item is an int, and switches to floats at the first
+/// float, converting the ints collected so far.
+enum Numbers {
+    Ints(Vec<BigInt>),
+    Floats(Vec<f64>),
+}
+
+impl Numbers {
+    /// Collects the items of the iterable `data`, raising `TypeError` for anything but a number.
+    fn collect(data: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Self> {
+        let mut iter = MontyIter::new(data, heap, interns)?;
+        let mut numbers = Self::Ints(Vec::new());
+        let result = numbers.extend(&mut iter, heap, interns);
+        iter.drop_with_heap(heap);
+        result.map(|()| numbers)
+    }
+
+    /// Adds the items of `iter`, which the caller drops.
+    fn extend(
+        &mut self,
+        iter: &mut MontyIter,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<()> {
+        while let Some(item) = iter.for_next(heap, interns)? {
+            defer_drop!(item, heap);
+            if let Value::Float(f) = item {
+                if let Self::Ints(ints) = self {
+                    *self = Self::Floats(ints.iter().map(bigint_to_f64).collect::<RunResult<_>>()?);
+                }
+                if let Self::Floats(floats) = self {
+                    floats.push(*f);
+                }
+            } else if let Some(int) = extract_bigint(item, heap) {
+                match self {
+                    Self::Ints(ints) => ints.push(int),
+                    Self::Floats(floats) => floats.push(bigint_to_f64(&int)?),
+                }
+            } else {
+                return Err(ExcType::type_error(format!(
+                    "can't convert type '{}' to numerator/denominator",
+                    item.py_type(heap)
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Ints(ints) => ints.len(),
+            Self::Floats(floats) => floats.len(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Computes the mean of at least one number.
+    fn mean(&self) -> Real {
+        match self {
+            Self::Ints(ints) => Real::ratio(ints.iter().sum(), BigInt::from(ints.len())),
+            Self::Floats(floats) => {
+                let n = floats.len() as f64;
+                let mean = compensated_sum(floats.iter().copied()) / n;
+                if mean.is_infinite() && floats.iter().all(|f| f.is_finite()) {
+                    // The sum overflowed, so divide before adding instead
+                    Real::Float(compensated_sum(floats.iter().map(|f| f / n)))
+                } else {
+                    Real::Float(mean)
+                }
+            }
+        }
+    }
+
+    /// Computes the variance with `ddof` delta degrees of freedom: 1 for the sample
+    /// variance and 0 for the population variance.
+    ///
+    /// The caller checks there are more than `ddof` numbers.
+    fn variance(&self, ddof: usize, heap: &Heap<impl ResourceTracker>) -> RunResult<Real> {
+        match self {
+            Self::Ints(ints) => {
+                // n * sum(x ** 2) - sum(x) ** 2 is n ** 2 times the population variance
+                let max_bits = ints.iter().map(BigInt::bits).max().unwrap_or(0);
+                check_mult_size(max_bits, max_bits, heap.tracker())?;
+                let n = BigInt::from(ints.len());
+                let (mut sum, mut sum_squares) = (BigInt::zero(), BigInt::zero());
+                for int in ints {
+                    sum += int;
+                    sum_squares += int * int;
+                }
+                let deviations = &n * sum_squares - &sum * &sum;
+                Ok(Real::ratio(deviations, &n * (ints.len() - ddof)))
+            }
+            Self::Floats(floats) => {
+                // Welford's algorithm, updating the mean and the sum of squared deviations
+                let (mut mean, mut deviations) = (0.0_f64, 0.0_f64);
+                for (i, &f) in floats.iter().enumerate() {
+                    let delta = f - mean;
+                    mean += delta / (i + 1) as f64;
+                    deviations += delta * (f - mean);
+                }
+                Ok(Real::Float(deviations / (floats.len() - ddof) as f64))
+            }
+        }
+    }
+}
+
+/// A result of a `statistics` function, an int when it's exactly whole.
+enum Real {
+    Int(BigInt),
+    Float(f64),
+}
+
+impl Real {
+    /// Divides `numerator` by the positive `denominator`, keeping the result an int if it's whole.
+    fn ratio(numerator: BigInt, denominator: BigInt) -> Self {
+        let (quotient, remainder) = numerator.div_rem(&denominator);
+        if remainder.is_zero() {
+            Self::Int(quotient)
+        } else {
+            // Like int true division, both sides are converted to floats first
+            let numerator = numerator.to_f64().unwrap_or(f64::INFINITY);
+            let denominator = denominator.to_f64().unwrap_or(f64::INFINITY);
+            Self::Float(numerator / denominator)
+        }
+    }
+
+    fn to_f64(&self) -> RunResult<f64> {
+        match self {
+            Self::Int(int) => bigint_to_f64(int),
+            Self::Float(f) => Ok(*f),
+        }
+    }
+
+    fn into_value(self, heap: &mut Heap<impl ResourceTracker>) -> Result<Value, ResourceError> {
+        match self {
+            Self::Int(int) => LongInt::new(int).into_value(heap),
+            Self::Float(f) => Ok(Value::Float(f)),
+        }
+    }
+}
+
+/// Adds floats with Neumaier's compensated summation, which carries the rounding error of each
+/// addition alongside the sum.
+fn compensated_sum(floats: impl Iterator<Item = f64>) -> f64 {
+    let (mut sum, mut compensation) = (0.0_f64, 0.0);
+    for f in floats {
+        let total = sum + f;
+        compensation += if sum.abs() >= f.abs() {
+            (sum - total) + f
+        } else {
+            (f - total) + sum
+        };
+        sum = total;
+    }
+    sum + compensation
+}
+
+/// Converts an int data point to a float, raising `OverflowError` if it's too large.
+fn bigint_to_f64(int: &BigInt) -> RunResult<f64> {
+    int.to_f64()
+        .filter(|f| f.is_finite())
+        .ok_or_else(|| SimpleException::new_msg(ExcType::OverflowError, "int too large to convert to float").into())
+}
+
+/// Creates the error CPython raises as `StatisticsError`, which is a `ValueError` here.
+fn statistics_error(message: &str) -> RunError {
+    SimpleException::new_msg(ExcType::ValueError, message).into()
+}
@@ -0,0 +1,146 @@
+//! Implementation of the `warnings` module.
+//!
+//! Provides a minimal implementation of Python's `warnings` module with:
+//! - `warn(message, category=None, stacklevel=1)`: Issue a warning
+//!
+//! Rather than printing to stderr, warnings are passed to the host through
+//! [`PrintWriter::warn`], so embedders decide how (and whether) to surface them.
+//! There are no warning filters: every call is reported.
+
+use crate::{
+    args::ArgValues,
+    builtins::Builtins,
+    defer_drop, defer_drop_mut,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId},
+    intern::{Interns, StaticStrings},
+    io::PrintWriter,
+    modules::ModuleFunctions,
+    resource::{DepthGuard, ResourceError, ResourceTracker},
+    types::{Module, PyTrait},
+    value::Value,
+};
+
+/// Warnings module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum WarningsFunctions {
+    Warn,
+}
+
+/// Parameter names of `warnings.warn()`, in positional order.
+const WARN_PARAMS: [&str; 3] = ["message", "category", "stacklevel"];
+
+/// Creates the `warnings` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Warnings);
+
+    module.set_attr(
+        StaticStrings::Warn,
+        Value::ModuleFunction(ModuleFunctions::Warnings(WarningsFunctions::Warn)),
+        heap,
+        interns,
+    );
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a warnings module function.
+///
+/// Unlike other module functions these need the print writer, so the VM calls this
+/// directly rather than going through [`ModuleFunctions::call`].
+pub(crate) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    functions: WarningsFunctions,
+    args: ArgValues,
+    interns: &Interns,
+    print: &mut impl PrintWriter,
+) -> RunResult<Value> {
+    match functions {
+        WarningsFunctions::Warn => warn(heap, args, interns, print),
+    }
+}
+
+/// Implements `warnings.warn()`.
+///
+/// `stacklevel` is accepted for compatibility but ignored, since warnings are reported
+/// without a source location.
+fn warn(
+    heap: &mut Heap<impl ResourceTracker>,
+    args: ArgValues,
+    interns: &Interns,
+    print: &mut impl PrintWriter,
+) -> RunResult<Value> {
+    let (positional, kwargs) = args.into_parts();
+    // Missing parameters are filled with None, which is also the default for both optional ones
+    let mut params: Vec<Value> = positional.collect();
+    let positional_count = params.len();
+    params.resize_with(WARN_PARAMS.len().max(positional_count), || Value::None);
+    defer_drop_mut!(params, heap);
+    let kwargs = kwargs.into_iter();
+    defer_drop_mut!(kwargs, heap);
+
+    if positional_count > WARN_PARAMS.len() {
+        return Err(ExcType::type_error_at_most("warn", WARN_PARAMS.len(), positional_count));
+    }
+
+    let mut message_given = positional_count > 0;
+    for (key, value) in kwargs {
+        defer_drop!(key, heap);
+        let mut value = HeapGuard::new(value, heap);
+
+        let Some(keyword_name) = key.as_either_str(value.heap()) else {
+            return Err(ExcType::type_error("keywords must be strings"));
+        };
+        let key_str = keyword_name.as_str(interns);
+        let Some(index) = WARN_PARAMS.iter().position(|param| *param == key_str) else {
+            return Err(ExcType::type_error_unexpected_keyword("warn", key_str));
+        };
+        if index < positional_count {
+            return Err(ExcType::type_error(format!(
+                "warn() got multiple values for argument '{key_str}'"
+            )));
+        }
+        message_given |= index == 0;
+        let old = std::mem::replace(&mut params[index], value.into_inner());
+        old.drop_with_heap(heap);
+    }
+
+    if !message_given {
+        return Err(ExcType::type_error(
+            "warn() missing required argument 'message' (pos 1)",
+        ));
+    }
+
+    let category = warning_category(&params[0], &params[1], heap)?;
+    let message = params[0].py_str(heap, &mut DepthGuard::default(), interns);
+    print.warn(category.into(), message)?;
+    Ok(Value::None)
+}
+
+/// Determines the category of a warning, like CPython.
+///
+/// A `Warning` instance passed as the message carries its own category, otherwise the
+/// `category` argument is used, defaulting to `UserWarning`.
+fn warning_category(message: &Value, category: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<ExcType> {
+    if let Value::Ref(id) = message
+        && let HeapData::Exception(exc) = heap.get(*id)
+        && exc.exc_type().is_subclass_of(ExcType::Warning)
+    {
+        return Ok(exc.exc_type());
+    }
+    match category {
+        Value::None => Ok(ExcType::UserWarning),
+        Value::Builtin(Builtins::ExcType(exc_type)) if exc_type.is_subclass_of(ExcType::Warning) => Ok(*exc_type),
+        other => Err(ExcType::type_error(format!(
+            "category must be a Warning subclass, not '{}'",
+            other.py_type(heap)
+        ))),
+    }
+}
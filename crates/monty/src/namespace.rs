@@ -1,6 +1,6 @@
 use crate::{
     exception_private::ExceptionRaise,
-    heap::{Heap, HeapId},
+    heap::{DropWithHeap, Heap, HeapId},
     parse::CodeRange,
     resource::{ResourceError, ResourceTracker},
     value::Value,
@@ -40,7 +40,6 @@ impl Namespace {
         &self.0[index.index()]
     }
 
-    #[cfg(feature = "ref-count-return")]
     pub fn get_opt(&self, index: NamespaceId) -> Option<&Value> {
         self.0.get(index.index())
     }
@@ -180,7 +179,7 @@ impl Namespaces {
     ///
     /// This is similar to `new_namespace` but takes an already-populated `Vec<Value>` instead
     /// of creating an empty one. Used when starting execution of a coroutine whose namespace
-    /// was pre-bound at call time.
+    /// was pre-bound at call time, and when resuming a generator.
     ///
     /// # Arguments
     /// * `namespace` - The pre-built namespace values
@@ -190,6 +189,8 @@ impl Namespaces {
     /// * `Ok(NamespaceId)` - Index of the registered namespace
     /// * `Err(ResourceError::Recursion)` - If adding this namespace would exceed recursion limit
     /// * `Err(ResourceError::Memory)` - If adding this namespace would exceed memory limits
+    ///
+    /// On error the namespace values are dropped.
     pub fn register_prebuilt(
         &mut self,
         namespace: Vec<Value>,
@@ -197,11 +198,15 @@ impl Namespaces {
     ) -> Result<NamespaceId, ResourceError> {
         // Check recursion depth BEFORE memory allocation (fail fast)
         let current_depth = self.stack.len() - 1;
-        heap.tracker().check_recursion_depth(current_depth)?;
-
-        // Track the memory used by this namespace's slots
         let size = namespace.len() * std::mem::size_of::<Value>();
-        heap.tracker_mut().on_allocate(|| size)?;
+        let checked = heap
+            .tracker()
+            .check_recursion_depth(current_depth)
+            .and_then(|()| heap.tracker_mut().on_allocate(|| size));
+        if let Err(e) = checked {
+            namespace.drop_with_heap(heap);
+            return Err(e);
+        }
 
         // Try to reuse an existing slot, or push a new one
         if let Some(reuse_id) = self.reuse_ids.pop() {
@@ -236,6 +241,19 @@ impl Namespaces {
         self.reuse_ids.push(namespace_id);
     }
 
+    /// Removes a namespace and returns its values without dropping them.
+    ///
+    /// The counterpart of `register_prebuilt`, used when a generator pauses and keeps its
+    /// namespace until it's resumed. The namespace's memory is credited back to the heap's
+    /// `ResourceTracker`; whoever keeps the values accounts for them.
+    pub fn take(&mut self, namespace_id: NamespaceId, heap: &mut Heap<impl ResourceTracker>) -> Vec<Value> {
+        let namespace = std::mem::take(&mut self.stack[namespace_id.index()].0);
+        let size = namespace.len() * std::mem::size_of::<Value>();
+        heap.tracker_mut().on_free(|| size);
+        self.reuse_ids.push(namespace_id);
+        namespace
+    }
+
     /// Cleans up the global namespace by dropping all values with proper ref counting.
     ///
     /// Call this before the namespaces is dropped to properly decrement reference counts
@@ -22,7 +22,7 @@ use crate::{
         set::{FrozenSet, Set},
         str::{Str, StringRepr, string_repr_fmt},
     },
-    value::{EitherStr, Value},
+    value::{EitherStr, Value, float_repr_fmt},
 };
 
 /// A Python value that can be passed to or returned from the interpreter.
@@ -40,7 +40,7 @@ use crate::{
 ///
 /// # Hashability
 ///
-/// Only immutable variants (`None`, `Ellipsis`, `Bool`, `Int`, `Float`, `String`, `Bytes`)
+/// Only immutable variants (`None`, `Ellipsis`, `NotImplemented`, `Bool`, `Int`, `Float`, `String`, `Bytes`)
 /// implement `Hash`. Attempting to hash mutable variants (`List`, `Dict`) will panic.
 ///
 /// # JSON Serialization
@@ -73,6 +73,8 @@ pub enum MontyObject {
     Ellipsis,
     /// Python's `None` singleton.
     None,
+    /// Python's `NotImplemented` singleton.
+    NotImplemented,
     /// Python boolean (`True` or `False`).
     Bool(bool),
     /// Python integer (64-bit signed).
@@ -186,7 +188,7 @@ impl MontyObject {
 
     /// Converts this `MontyObject` into an `Value`, allocating on the heap if needed.
     ///
-    /// Immediate values (None, Bool, Int, Float, Ellipsis, Exception) are created directly.
+    /// Immediate values (None, Bool, Int, Float, Ellipsis, NotImplemented, Exception) are created directly.
     /// Heap-allocated values (String, Bytes, List, Tuple, Dict) are allocated
     /// via the heap and wrapped in `Value::Ref`.
     ///
@@ -201,6 +203,7 @@ impl MontyObject {
         match self {
             Self::Ellipsis => Ok(Value::Ellipsis),
             Self::None => Ok(Value::None),
+            Self::NotImplemented => Ok(Value::NotImplemented),
             Self::Bool(b) => Ok(Value::Bool(b)),
             Self::Int(i) => Ok(Value::Int(i)),
             Self::BigInt(bi) => Ok(LongInt::new(bi).into_value(heap)?),
@@ -338,6 +341,7 @@ impl MontyObject {
             Value::Undefined => panic!("Undefined found while converting to MontyObject"),
             Value::Ellipsis => Self::Ellipsis,
             Value::None => Self::None,
+            Value::NotImplemented => Self::NotImplemented,
             Value::Bool(b) => Self::Bool(*b),
             Value::Int(i) => Self::Int(*i),
             Value::Float(f) => Self::Float(*f),
@@ -361,6 +365,12 @@ impl MontyObject {
                 let result = match heap.get(*id) {
                     HeapData::Str(s) => Self::String(s.as_str().to_owned()),
                     HeapData::Bytes(b) => Self::Bytes(b.as_slice().to_owned()),
+                    // MontyObject has no bytearray variant, so the host sees the repr
+                    HeapData::ByteArray(b) => {
+                        let mut s = String::new();
+                        let _ = b.py_repr_fmt(&mut s, heap, visited, guard, interns);
+                        Self::Repr(s)
+                    }
                     HeapData::List(list) => Self::List(
                         list.as_slice()
                             .iter()
@@ -479,6 +489,14 @@ impl MontyObject {
                         Self::Repr(format!("<gather({})>", gather.item_count()))
                     }
                     HeapData::Path(path) => Self::Path(path.as_str().to_owned()),
+                    // Files, regex objects, getters, context managers and generators are only meaningful inside
+                    // the VM, the host sees their repr
+                    HeapData::File(_)
+                    | HeapData::RePattern(_)
+                    | HeapData::ReMatch(_)
+                    | HeapData::Getter(_)
+                    | HeapData::ContextManager(_)
+                    | HeapData::Generator(_) => Self::Repr(object.py_repr(heap, guard, interns).into_owned()),
                 };
 
                 // Remove from visited set after processing
@@ -509,18 +527,12 @@ impl MontyObject {
         match self {
             Self::Ellipsis => f.write_str("Ellipsis"),
             Self::None => f.write_str("None"),
+            Self::NotImplemented => f.write_str("NotImplemented"),
             Self::Bool(true) => f.write_str("True"),
             Self::Bool(false) => f.write_str("False"),
             Self::Int(v) => write!(f, "{v}"),
             Self::BigInt(v) => write!(f, "{v}"),
-            Self::Float(v) => {
-                let s = v.to_string();
-                f.write_str(&s)?;
-                if !s.contains('.') {
-                    f.write_str(".0")?;
-                }
-                Ok(())
-            }
+            Self::Float(v) => float_repr_fmt(*v, f),
             Self::String(s) => string_repr_fmt(s, f),
             Self::Bytes(b) => f.write_str(&bytes_repr(b)),
             Self::List(l) => {
@@ -545,6 +557,10 @@ impl MontyObject {
                         item.repr_fmt(f)?;
                     }
                 }
+                // A single-item tuple needs a trailing comma, like `(1,)`
+                if t.len() == 1 {
+                    f.write_char(',')?;
+                }
                 f.write_char(')')
             }
             Self::NamedTuple {
@@ -673,7 +689,7 @@ impl MontyObject {
     pub fn is_truthy(&self) -> bool {
         match self {
             Self::None => false,
-            Self::Ellipsis => true,
+            Self::Ellipsis | Self::NotImplemented => true,
             Self::Bool(b) => *b,
             Self::Int(i) => *i != 0,
             Self::BigInt(bi) => !bi.is_zero(),
@@ -700,6 +716,7 @@ impl MontyObject {
     pub fn type_name(&self) -> &'static str {
         match self {
             Self::None => "NoneType",
+            Self::NotImplemented => "NotImplementedType",
             Self::Ellipsis => "ellipsis",
             Self::Bool(_) => "bool",
             Self::Int(_) | Self::BigInt(_) => "int",
@@ -735,7 +752,7 @@ impl Hash for MontyObject {
         }
 
         match self {
-            Self::Ellipsis | Self::None => {}
+            Self::Ellipsis | Self::None | Self::NotImplemented => {}
             Self::Bool(bool) => bool.hash(state),
             Self::Int(i) => i.hash(state),
             Self::BigInt(bi) => {
@@ -763,6 +780,7 @@ impl PartialEq for MontyObject {
         match (self, other) {
             (Self::Ellipsis, Self::Ellipsis) => true,
             (Self::None, Self::None) => true,
+            (Self::NotImplemented, Self::NotImplemented) => true,
             (Self::Bool(a), Self::Bool(b)) => a == b,
             (Self::Int(a), Self::Int(b)) => a == b,
             (Self::BigInt(a), Self::BigInt(b)) => a == b,
@@ -81,6 +81,28 @@ pub enum OsFunction {
     /// Get the entire environment as a dictionary
     #[strum(serialize = "os.environ")]
     GetEnviron,
+    /// Read a line of input for the `input()` builtin; the single argument is the prompt string
+    #[strum(serialize = "input")]
+    Input,
+    /// Open a file for the `open()` builtin; the arguments are the path and mode strings,
+    /// and the host returns an `int` handle (see `types::file` for the full protocol)
+    #[strum(serialize = "open")]
+    Open,
+    /// Read from an open file; the arguments are the handle and a size (-1 for everything)
+    #[strum(serialize = "file.read")]
+    FileRead,
+    /// Read a single line from an open file; the arguments are the handle and a size limit
+    #[strum(serialize = "file.readline")]
+    FileReadline,
+    /// Read all remaining lines from an open file as a list
+    #[strum(serialize = "file.readlines")]
+    FileReadlines,
+    /// Write a `str` (or `bytes` in binary mode) to an open file
+    #[strum(serialize = "file.write")]
+    FileWrite,
+    /// Close an open file
+    #[strum(serialize = "file.close")]
+    FileClose,
 }
 
 impl TryFrom<StaticStrings> for OsFunction {
@@ -12,14 +12,15 @@ use ruff_text_size::{Ranged, TextRange};
 use crate::{
     StackFrame,
     args::{ArgExprs, Kwarg},
-    builtins::Builtins,
+    builtins::{Builtins, BuiltinsFunctions},
     exception_private::ExcType,
     exception_public::{CodeLoc, MontyException},
     expressions::{
         Callable, CmpOperator, Comprehension, Expr, ExprLoc, Identifier, Literal, Node, Operator, UnpackTarget,
     },
+    fold::fold_expr,
     fstring::{ConversionFlag, FStringPart, FormatSpec},
-    intern::{InternerBuilder, StringId},
+    intern::{InternerBuilder, StaticStrings, StringId},
     value::EitherStr,
 };
 
@@ -156,6 +157,8 @@ pub struct Parser<'a> {
     /// Starts at MAX_NESTING_DEPTH and decrements on each nested level.
     /// When it reaches zero, we return a "too many nested parentheses" error.
     depth_remaining: u16,
+    /// Number of `with` items expanded so far, used to give each one unique hidden names.
+    with_count: usize,
 }
 
 impl<'a> Parser<'a> {
@@ -175,11 +178,25 @@ impl<'a> Parser<'a> {
             filename_id,
             interner,
             depth_remaining: MAX_NESTING_DEPTH,
+            with_count: 0,
         }
     }
 
     fn parse_statements(&mut self, statements: Vec<Stmt>) -> Result<Vec<ParseNode>, ParseError> {
-        statements.into_iter().map(|f| self.parse_statement(f)).collect()
+        let mut nodes = Vec::with_capacity(statements.len());
+        for statement in statements {
+            match statement {
+                // `with` expands to several statements, see `parse_with`
+                Stmt::With(with) => {
+                    self.decr_depth_remaining(|| with.range)?;
+                    let result = self.parse_with(with);
+                    self.depth_remaining += 1;
+                    nodes.extend(result?);
+                }
+                statement => nodes.push(self.parse_statement(statement)?),
+            }
+        }
+        Ok(nodes)
     }
 
     fn parse_elif_else_clauses(&mut self, clauses: Vec<ElifElseClause>) -> Result<Vec<ParseNode>, ParseError> {
@@ -218,6 +235,138 @@ impl<'a> Parser<'a> {
         Ok(ExceptHandler { exc_type, name, body })
     }
 
+    /// Parses a `with` statement into the statements it's equivalent to.
+    ///
+    /// There's no dedicated node: `with mgr as target: body` expands to
+    ///
+    /// ```python
+    /// .with_manager_0 = mgr
+    /// target = .with_manager_0.__enter__()
+    /// .with_pending_0 = True
+    /// try:
+    ///     body
+    /// except BaseException as .with_exception_0:
+    ///     .with_pending_0 = False
+    ///     if not .with_manager_0.__exit__(type(.with_exception_0), .with_exception_0, None):
+    ///         raise
+    /// finally:
+    ///     if .with_pending_0:
+    ///         .with_manager_0.__exit__(None, None, None)
+    /// ```
+    ///
+    /// so `return`, `break` and `continue` in the body still call `__exit__`. The hidden names
+    /// start with `.` so they can't clash with Python variables. Several items nest, the first
+    /// being entered first and exited last.
+    ///
+    /// Unlike CPython, `__exit__` gets `None` for the traceback, and an object without
+    /// `__enter__` raises `AttributeError` rather than `TypeError`.
+    fn parse_with(&mut self, with: ast::StmtWith) -> Result<Vec<ParseNode>, ParseError> {
+        let ast::StmtWith {
+            items,
+            body,
+            is_async,
+            range,
+            ..
+        } = with;
+        if is_async {
+            return Err(ParseError::not_implemented(
+                "async context managers (async with)",
+                self.convert_range(range),
+            ));
+        }
+        let mut nodes = self.parse_statements(body)?;
+        for item in items.into_iter().rev() {
+            nodes = self.parse_with_item(item, nodes)?;
+        }
+        Ok(nodes)
+    }
+
+    /// Expands a single `with` item around `body`, see `parse_with`.
+    fn parse_with_item(&mut self, item: ast::WithItem, body: Vec<ParseNode>) -> Result<Vec<ParseNode>, ParseError> {
+        let position = self.convert_range(item.context_expr.range());
+        let index = self.with_count;
+        self.with_count += 1;
+        let manager = Identifier::new(self.interner.intern(&format!(".with_manager_{index}")), position);
+        let pending = Identifier::new(self.interner.intern(&format!(".with_pending_{index}")), position);
+        let exception = Identifier::new(self.interner.intern(&format!(".with_exception_{index}")), position);
+
+        let name = |ident: Identifier| ExprLoc::new(position, Expr::Name(ident));
+        let literal = |literal: Literal| ExprLoc::new(position, Expr::Literal(literal));
+        let call_method = |method: StaticStrings, args: ArgExprs| {
+            ExprLoc::new(
+                position,
+                Expr::AttrCall {
+                    object: Box::new(name(manager)),
+                    attr: EitherStr::Interned(method.into()),
+                    args: Box::new(args),
+                },
+            )
+        };
+
+        let mut nodes = vec![Node::Assign {
+            target: manager,
+            object: self.parse_expression(item.context_expr)?,
+        }];
+        let enter = call_method(StaticStrings::DunderEnter, ArgExprs::Empty);
+        nodes.push(match item.optional_vars {
+            Some(target) => self.parse_assignment_value(*target, enter)?,
+            None => Node::Expr(enter),
+        });
+        nodes.push(Node::Assign {
+            target: pending,
+            object: literal(Literal::Bool(true)),
+        });
+
+        let exc_type = ExprLoc::new(
+            position,
+            Expr::Call {
+                callable: Callable::Builtin(Builtins::Function(BuiltinsFunctions::Type)),
+                args: Box::new(ArgExprs::One(name(exception))),
+            },
+        );
+        let exit_with_exception = call_method(
+            StaticStrings::DunderExit,
+            ArgExprs::Args(vec![exc_type, name(exception), literal(Literal::None)]),
+        );
+        let handler = ExceptHandler {
+            exc_type: Some(ExprLoc::new(
+                position,
+                Expr::Builtin(Builtins::ExcType(ExcType::BaseException)),
+            )),
+            name: Some(exception),
+            body: vec![
+                Node::Assign {
+                    target: pending,
+                    object: literal(Literal::Bool(false)),
+                },
+                Node::If {
+                    test: ExprLoc::new(position, Expr::Not(Box::new(exit_with_exception))),
+                    body: vec![Node::Raise(None)],
+                    or_else: Vec::new(),
+                },
+            ],
+        };
+        let exit = call_method(
+            StaticStrings::DunderExit,
+            ArgExprs::Args(vec![
+                literal(Literal::None),
+                literal(Literal::None),
+                literal(Literal::None),
+            ]),
+        );
+        nodes.push(Node::Try(Try {
+            body,
+            handlers: vec![handler],
+            or_else: Vec::new(),
+            finally: vec![Node::If {
+                test: name(pending),
+                body: vec![Node::Expr(exit)],
+                or_else: Vec::new(),
+            }],
+        }));
+        Ok(nodes)
+    }
+
     fn parse_statement(&mut self, statement: Stmt) -> Result<ParseNode, ParseError> {
         self.decr_depth_remaining(|| statement.range())?;
         let result = self.parse_statement_impl(statement);
@@ -280,12 +429,44 @@ impl<'a> Parser<'a> {
             Stmt::TypeAlias(t) => Err(ParseError::not_implemented("type aliases", self.convert_range(t.range))),
             Stmt::Assign(ast::StmtAssign {
                 targets, value, range, ..
-            }) => self.parse_assignment(first(targets, self.convert_range(range))?, *value),
-            Stmt::AugAssign(ast::StmtAugAssign { target, op, value, .. }) => Ok(Node::OpAssign {
-                target: self.parse_identifier(*target)?,
-                op: convert_op(op),
-                object: self.parse_expression(*value)?,
-            }),
+            }) => {
+                if targets.len() > 1 {
+                    self.parse_chain_assignment(targets, *value)
+                } else {
+                    self.parse_assignment(first(targets, self.convert_range(range))?, *value)
+                }
+            }
+            Stmt::AugAssign(ast::StmtAugAssign { target, op, value, .. }) => match *target {
+                AstExpr::Subscript(ast::ExprSubscript {
+                    value: object,
+                    slice,
+                    range,
+                    ..
+                }) => Ok(Node::SubscriptOpAssign {
+                    object: self.parse_expression(*object)?,
+                    index: self.parse_expression(*slice)?,
+                    op: convert_op(op),
+                    value: self.parse_expression(*value)?,
+                    target_position: self.convert_range(range),
+                }),
+                AstExpr::Attribute(ast::ExprAttribute {
+                    value: object,
+                    attr,
+                    range,
+                    ..
+                }) => Ok(Node::AttrOpAssign {
+                    object: self.parse_expression(*object)?,
+                    attr: EitherStr::Interned(self.interner.intern(attr.id())),
+                    op: convert_op(op),
+                    value: self.parse_expression(*value)?,
+                    target_position: self.convert_range(range),
+                }),
+                target => Ok(Node::OpAssign {
+                    target: self.parse_identifier(target)?,
+                    op: convert_op(op),
+                    object: self.parse_expression(*value)?,
+                }),
+            },
             Stmt::AnnAssign(ast::StmtAnnAssign { target, value, .. }) => match value {
                 Some(value) => self.parse_assignment(*target, *value),
                 None => Ok(Node::Pass),
@@ -323,35 +504,40 @@ impl<'a> Parser<'a> {
                 elif_else_clauses,
                 ..
             }) => {
+                // `TYPE_CHECKING` is always False in Monty, and these blocks often hold imports
+                // or constructs Monty doesn't support, so the body isn't parsed at all
+                if is_type_checking(&test) {
+                    let test = ExprLoc::new(self.convert_range(test.range()), Expr::Literal(Literal::Bool(false)));
+                    let or_else = self.parse_elif_else_clauses(elif_else_clauses)?;
+                    return Ok(Node::If {
+                        test,
+                        body: Vec::new(),
+                        or_else,
+                    });
+                }
                 let test = self.parse_expression(*test)?;
                 let body = self.parse_statements(body)?;
                 let or_else = self.parse_elif_else_clauses(elif_else_clauses)?;
                 Ok(Node::If { test, body, or_else })
             }
-            Stmt::With(ast::StmtWith { is_async, range, .. }) => {
-                if is_async {
-                    Err(ParseError::not_implemented(
-                        "async context managers (async with)",
-                        self.convert_range(range),
-                    ))
-                } else {
-                    Err(ParseError::not_implemented(
-                        "context managers (with statements)",
-                        self.convert_range(range),
-                    ))
-                }
-            }
+            Stmt::With(_) => unreachable!("with statements are expanded by parse_statements"),
             Stmt::Match(m) => Err(ParseError::not_implemented(
                 "pattern matching (match statements)",
                 self.convert_range(m.range),
             )),
-            Stmt::Raise(ast::StmtRaise { exc, .. }) => {
-                // TODO add cause to Node::Raise
+            Stmt::Raise(ast::StmtRaise { exc, cause, .. }) => {
                 let expr = match exc {
                     Some(expr) => Some(self.parse_expression(*expr)?),
                     None => None,
                 };
-                Ok(Node::Raise(expr))
+                match (expr, cause) {
+                    (Some(exc), Some(cause)) => Ok(Node::RaiseFrom {
+                        exc,
+                        cause: self.parse_expression(*cause)?,
+                    }),
+                    // the grammar only allows `from` after an exception
+                    (expr, _) => Ok(Node::Raise(expr)),
+                }
             }
             Stmt::Try(ast::StmtTry {
                 body,
@@ -498,6 +684,12 @@ impl<'a> Parser<'a> {
     /// Handles simple assignments (x = value), subscript assignments (dict[key] = value),
     /// attribute assignments (obj.attr = value), and tuple unpacking (a, b = value)
     fn parse_assignment(&mut self, lhs: AstExpr, rhs: AstExpr) -> Result<ParseNode, ParseError> {
+        let rhs = self.parse_expression(rhs)?;
+        self.parse_assignment_value(lhs, rhs)
+    }
+
+    /// Like `parse_assignment`, for a value that's already been parsed.
+    fn parse_assignment_value(&mut self, lhs: AstExpr, rhs: ExprLoc) -> Result<ParseNode, ParseError> {
         match lhs {
             // Subscript assignment like dict[key] = value
             AstExpr::Subscript(ast::ExprSubscript {
@@ -505,7 +697,7 @@ impl<'a> Parser<'a> {
             }) => Ok(Node::SubscriptAssign {
                 target: self.parse_identifier(*value)?,
                 index: self.parse_expression(*slice)?,
-                value: self.parse_expression(rhs)?,
+                value: rhs,
                 target_position: self.convert_range(range),
             }),
             // Attribute assignment like obj.attr = value (supports chained like a.b.c = value)
@@ -513,7 +705,7 @@ impl<'a> Parser<'a> {
                 object: self.parse_expression(*value)?,
                 attr: EitherStr::Interned(self.interner.intern(attr.id())),
                 target_position: self.convert_range(range),
-                value: self.parse_expression(rhs)?,
+                value: rhs,
             }),
             // Tuple unpacking like a, b = value or (a, b), c = nested
             AstExpr::Tuple(ast::ExprTuple { elts, range, .. }) => {
@@ -525,7 +717,7 @@ impl<'a> Parser<'a> {
                 Ok(Node::UnpackAssign {
                     targets,
                     targets_position,
-                    object: self.parse_expression(rhs)?,
+                    object: rhs,
                 })
             }
             // List unpacking like [a, b] = value or [a, *rest] = value
@@ -538,17 +730,39 @@ impl<'a> Parser<'a> {
                 Ok(Node::UnpackAssign {
                     targets,
                     targets_position,
-                    object: self.parse_expression(rhs)?,
+                    object: rhs,
                 })
             }
             // Simple identifier assignment like x = value
             _ => Ok(Node::Assign {
                 target: self.parse_identifier(lhs)?,
-                object: self.parse_expression(rhs)?,
+                object: rhs,
             }),
         }
     }
 
+    /// Parses a chained assignment like `a = b = value`.
+    ///
+    /// Only names and unpacking patterns are supported as targets; subscript and
+    /// attribute targets are rejected since they'd need the value stored in between
+    /// evaluating their object and index.
+    fn parse_chain_assignment(&mut self, targets: Vec<AstExpr>, rhs: AstExpr) -> Result<ParseNode, ParseError> {
+        let targets = targets
+            .into_iter()
+            .map(|target| match target {
+                AstExpr::Subscript(_) | AstExpr::Attribute(_) => Err(ParseError::not_implemented(
+                    "subscript and attribute targets in chained assignment",
+                    self.convert_range(target.range()),
+                )),
+                target => self.parse_unpack_target(target),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Node::ChainAssign {
+            targets,
+            object: self.parse_expression(rhs)?,
+        })
+    }
+
     /// Parses an expression from the ruff AST into Monty's ExprLoc representation.
     ///
     /// Includes depth tracking to prevent stack overflow from deeply nested structures.
@@ -605,14 +819,14 @@ impl<'a> Parser<'a> {
             }) => {
                 let left = Box::new(self.parse_expression(*left)?);
                 let right = Box::new(self.parse_expression(*right)?);
-                Ok(ExprLoc {
+                Ok(self.fold_constant(ExprLoc {
                     position: self.convert_range(range),
                     expr: Expr::Op {
                         left,
                         op: convert_op(op),
                         right,
                     },
-                })
+                }))
             }
             AstExpr::UnaryOp(ast::ExprUnaryOp { op, operand, range, .. }) => match op {
                 UnaryOp::Not => {
@@ -621,11 +835,11 @@ impl<'a> Parser<'a> {
                 }
                 UnaryOp::USub => {
                     let operand = Box::new(self.parse_expression(*operand)?);
-                    Ok(ExprLoc::new(self.convert_range(range), Expr::UnaryMinus(operand)))
+                    Ok(self.fold_constant(ExprLoc::new(self.convert_range(range), Expr::UnaryMinus(operand))))
                 }
                 UnaryOp::UAdd => {
                     let operand = Box::new(self.parse_expression(*operand)?);
-                    Ok(ExprLoc::new(self.convert_range(range), Expr::UnaryPlus(operand)))
+                    Ok(self.fold_constant(ExprLoc::new(self.convert_range(range), Expr::UnaryPlus(operand))))
                 }
                 UnaryOp::Invert => {
                     let operand = Box::new(self.parse_expression(*operand)?);
@@ -758,14 +972,11 @@ impl<'a> Parser<'a> {
             AstExpr::Generator(ast::ExprGenerator {
                 elt, generators, range, ..
             }) => {
-                // TODO: When proper generators are implemented, this should produce
-                // Expr::Generator instead of Expr::ListComp. Currently we treat generator
-                // expressions as list comprehensions since we don't have generator support.
                 let elt = Box::new(self.parse_expression(*elt)?);
                 let generators = self.parse_comprehension_generators(generators)?;
                 Ok(ExprLoc::new(
                     self.convert_range(range),
-                    Expr::ListComp { elt, generators },
+                    Expr::GeneratorExp { elt, generators },
                 ))
             }
             AstExpr::Await(a) => {
@@ -793,14 +1004,15 @@ impl<'a> Parser<'a> {
 
                 // Simple case: single comparison (most common)
                 if ops_vec.len() == 1 {
-                    return Ok(ExprLoc::new(
+                    let expr = ExprLoc::new(
                         position,
                         Expr::CmpOp {
                             left: Box::new(self.parse_expression(*left)?),
                             op: convert_compare_op(ops_vec.into_iter().next().unwrap()),
                             right: Box::new(self.parse_expression(comparators_vec.into_iter().next().unwrap())?),
                         },
-                    ));
+                    );
+                    return Ok(self.fold_constant(expr));
                 }
 
                 // Chain comparison: transform to nested And expressions
@@ -964,6 +1176,11 @@ impl<'a> Parser<'a> {
                 // Check if the name is a builtin function or exception type
                 let expr = if let Ok(builtin) = name.parse::<Builtins>() {
                     Expr::Builtin(builtin)
+                } else if name == "Ellipsis" {
+                    // The `Ellipsis` builtin is the same singleton as `...`
+                    Expr::Literal(Literal::Ellipsis)
+                } else if name == "NotImplemented" {
+                    Expr::Literal(Literal::NotImplemented)
                 } else {
                     Expr::Name(self.identifier(&id, range))
                 };
@@ -1217,17 +1434,17 @@ impl<'a> Parser<'a> {
             match fstring_part {
                 ast::FStringPart::Literal(lit) => {
                     // Literal string segment - intern for use at runtime
-                    let processed = lit.value.to_string();
-                    if !processed.is_empty() {
-                        let string_id = self.interner.intern(&processed);
-                        parts.push(FStringPart::Literal(string_id));
-                    }
+                    self.push_fstring_literal(&mut parts, &lit.value.to_string());
                 }
                 ast::FStringPart::FString(fstring) => {
                     // Interpolated f-string section
                     for element in &fstring.elements {
-                        let part = self.parse_fstring_element(element)?;
-                        parts.push(part);
+                        if let InterpolatedStringElement::Literal(lit) = element {
+                            self.push_fstring_literal(&mut parts, &lit.value.to_string());
+                        } else {
+                            let part = self.parse_fstring_element(element)?;
+                            parts.push(part);
+                        }
                     }
                 }
             }
@@ -1246,6 +1463,22 @@ impl<'a> Parser<'a> {
         Ok(ExprLoc::new(self.convert_range(range), Expr::FString(parts)))
     }
 
+    /// Appends a literal segment to the parts of an f-string.
+    ///
+    /// Implicitly concatenated strings like `f"{x}a" "b"` produce adjacent literal segments,
+    /// these are merged so the f-string is built from as few parts as possible.
+    fn push_fstring_literal(&mut self, parts: &mut Vec<FStringPart>, literal: &str) {
+        if literal.is_empty() {
+            return;
+        }
+        if let Some(FStringPart::Literal(previous)) = parts.last_mut() {
+            let merged = format!("{}{literal}", self.interner.get_str(*previous));
+            *previous = self.interner.intern(&merged);
+        } else {
+            parts.push(FStringPart::Literal(self.interner.intern(literal)));
+        }
+    }
+
     /// Parses a single f-string element (literal or interpolation).
     fn parse_fstring_element(&mut self, element: &InterpolatedStringElement) -> Result<FStringPart, ParseError> {
         match element {
@@ -1334,6 +1567,14 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Replaces an operation on literals with its result, see [`crate::fold`].
+    fn fold_constant(&mut self, expr_loc: ExprLoc) -> ExprLoc {
+        match fold_expr(&expr_loc.expr, &mut self.interner) {
+            Some(literal) => ExprLoc::new(expr_loc.position, Expr::Literal(literal)),
+            None => expr_loc,
+        }
+    }
+
     fn convert_range(&self, range: TextRange) -> CodeRange {
         let start = range.start().into();
         let (start_line_no, start_line_start, _) = self.index_to_position(start);
@@ -1392,6 +1633,21 @@ fn first<T: fmt::Debug>(v: Vec<T>, position: CodeRange) -> Result<T, ParseError>
     }
 }
 
+/// Returns true if `expr` is `TYPE_CHECKING` or `typing.TYPE_CHECKING`.
+///
+/// This is a purely syntactic check, so a user variable named `TYPE_CHECKING` is treated
+/// the same way, which is also what type checkers do.
+fn is_type_checking(expr: &AstExpr) -> bool {
+    match expr {
+        AstExpr::Name(ast::ExprName { id, .. }) => id.as_str() == "TYPE_CHECKING",
+        AstExpr::Attribute(ast::ExprAttribute { value, attr, .. }) => {
+            attr.as_str() == "TYPE_CHECKING"
+                && matches!(value.as_ref(), AstExpr::Name(ast::ExprName { id, .. }) if id.as_str() == "typing")
+        }
+        _ => false,
+    }
+}
+
 fn convert_op(op: AstOperator) -> Operator {
     match op {
         AstOperator::Add => Operator::Add,
@@ -4,12 +4,13 @@ use ahash::{AHashMap, AHashSet};
 
 use crate::{
     args::ArgExprs,
+    builtins::{Builtins, BuiltinsFunctions},
     expressions::{
         Callable, CmpOperator, Comprehension, Expr, ExprLoc, Identifier, Literal, NameScope, Node, Operator,
         PreparedFunctionDef, PreparedNode, UnpackTarget,
     },
     fstring::{FStringPart, FormatSpec},
-    intern::{InternerBuilder, StringId},
+    intern::{InternerBuilder, StaticStrings, StringId},
     namespace::NamespaceId,
     parse::{CodeRange, ExceptHandler, ParseError, ParseNode, ParseResult, ParsedSignature, RawFunctionDef, Try},
     signature::Signature,
@@ -41,19 +42,26 @@ pub struct PrepareResult {
 ///
 /// The namespace will be converted to runtime Objects when execution begins and the heap is available.
 /// At module level, the local namespace IS the global namespace.
+///
+/// With `optimize` set, code is prepared like CPython's `-O` mode: `assert` statements are
+/// dropped and `__debug__` is `False`. With `repl` set, the last expression is left as a
+/// statement so the compiler echoes it like every other top-level expression.
 pub(crate) fn prepare(
     parse_result: ParseResult,
     input_names: Vec<String>,
     external_functions: &[String],
+    optimize: bool,
+    repl: bool,
 ) -> Result<PrepareResult, ParseError> {
     let ParseResult { nodes, interner } = parse_result;
-    let mut p = Prepare::new_module(input_names, external_functions, &interner);
+    let mut p = Prepare::new_module(input_names, external_functions, &interner, optimize);
     let mut prepared_nodes = p.prepare_nodes(nodes)?;
 
     // In the root frame, the last expression is implicitly returned
     // if it's not None. This matches Python REPL behavior where the last expression
     // value is displayed/returned.
-    if let Some(Node::Expr(expr_loc)) = prepared_nodes.last()
+    if !repl
+        && let Some(Node::Expr(expr_loc)) = prepared_nodes.last()
         && !expr_loc.expr.is_none()
     {
         let new_expr_loc = expr_loc.clone();
@@ -85,6 +93,8 @@ pub(crate) fn prepare(
 struct Prepare<'i> {
     /// Reference to the string interner for looking up names in error messages.
     interner: &'i InternerBuilder,
+    /// Whether to prepare code in optimized mode, where asserts are dropped and `__debug__` is False.
+    optimize: bool,
     /// Maps variable names to their indices in this scope's namespace vector
     name_map: AHashMap<String, NamespaceId>,
     /// Number of items in the namespace
@@ -130,7 +140,13 @@ impl<'i> Prepare<'i> {
     /// * `input_names` - Names that should be pre-registered in the namespace (e.g., external variables)
     /// * `external_functions` - Names of external functions to pre-register
     /// * `interner` - Reference to the string interner for looking up names
-    fn new_module(input_names: Vec<String>, external_functions: &[String], interner: &'i InternerBuilder) -> Self {
+    /// * `optimize` - Whether asserts are dropped and `__debug__` is False
+    fn new_module(
+        input_names: Vec<String>,
+        external_functions: &[String],
+        interner: &'i InternerBuilder,
+        optimize: bool,
+    ) -> Self {
         let mut name_map = AHashMap::with_capacity(input_names.len() + external_functions.len());
         for (index, name) in external_functions.iter().enumerate() {
             name_map.insert(name.clone(), NamespaceId::new(index));
@@ -141,6 +157,7 @@ impl<'i> Prepare<'i> {
         let namespace_size = name_map.len();
         Self {
             interner,
+            optimize,
             name_map,
             namespace_size,
             is_module_scope: true,
@@ -170,6 +187,7 @@ impl<'i> Prepare<'i> {
     /// * `enclosing_locals` - Names that exist as locals in the enclosing function (for nonlocal resolution)
     /// * `cell_var_names` - Names that are captured by nested functions (must be stored in cells)
     /// * `interner` - Reference to the string interner for looking up names
+    /// * `optimize` - Whether asserts are dropped and `__debug__` is False
     #[expect(clippy::too_many_arguments)]
     fn new_function(
         capacity: usize,
@@ -182,6 +200,7 @@ impl<'i> Prepare<'i> {
         enclosing_locals: Option<AHashSet<String>>,
         cell_var_names: AHashSet<String>,
         interner: &'i InternerBuilder,
+        optimize: bool,
     ) -> Self {
         let mut name_map = AHashMap::with_capacity(capacity);
         for (index, string_id) in params.iter().enumerate() {
@@ -227,6 +246,7 @@ impl<'i> Prepare<'i> {
 
         Self {
             interner,
+            optimize,
             name_map,
             namespace_size,
             is_module_scope: false,
@@ -261,33 +281,19 @@ impl<'i> Prepare<'i> {
                 Node::ReturnNone => new_nodes.push(Node::ReturnNone),
                 Node::Raise(exc) => {
                     let expr = match exc {
-                        Some(expr) => {
-                            match expr.expr {
-                                // Handle raising an exception type constant without instantiation,
-                                // e.g. `raise TypeError`. This is transformed into a call: `raise TypeError()`
-                                // so the exception is properly instantiated before being raised.
-                                // Also handle raising a builtin constant (unlikely but consistent)
-                                Expr::Builtin(b) => {
-                                    let call_expr = Expr::Call {
-                                        callable: Callable::Builtin(b),
-                                        args: Box::new(ArgExprs::Empty),
-                                    };
-                                    Some(ExprLoc::new(expr.position, call_expr))
-                                }
-                                Expr::Name(id) => {
-                                    // Handle raising a variable - could be an exception type or instance.
-                                    // The runtime will determine whether to call it (type) or raise it directly (instance).
-                                    let position = id.position;
-                                    let (resolved_id, _is_new) = self.get_id(id);
-                                    Some(ExprLoc::new(position, Expr::Name(resolved_id)))
-                                }
-                                _ => Some(self.prepare_expression(expr)?),
-                            }
-                        }
+                        Some(expr) => Some(self.prepare_raised_exception(expr)?),
                         None => None,
                     };
                     new_nodes.push(Node::Raise(expr));
                 }
+                Node::RaiseFrom { exc, cause } => {
+                    let exc = self.prepare_raised_exception(exc)?;
+                    // The cause may be an exception type, which the VM instantiates
+                    let cause = self.prepare_expression(cause)?;
+                    new_nodes.push(Node::RaiseFrom { exc, cause });
+                }
+                // Like `python -O`, optimized mode drops asserts without evaluating them
+                Node::Assert { .. } if self.optimize => {}
                 Node::Assert { test, msg } => {
                     let test = self.prepare_expression(test)?;
                     let msg = match msg {
@@ -321,6 +327,14 @@ impl<'i> Prepare<'i> {
                         object,
                     });
                 }
+                Node::ChainAssign { targets, object } => {
+                    let object = self.prepare_expression(object)?;
+                    let targets = targets
+                        .into_iter()
+                        .map(|target| self.prepare_unpack_target(target))
+                        .collect();
+                    new_nodes.push(Node::ChainAssign { targets, object });
+                }
                 Node::OpAssign { target, op, object } => {
                     // Track that this name was assigned
                     self.names_assigned_in_order
@@ -346,6 +360,25 @@ impl<'i> Prepare<'i> {
                         target_position,
                     });
                 }
+                Node::SubscriptOpAssign {
+                    object,
+                    index,
+                    op,
+                    value,
+                    target_position,
+                } => {
+                    // Like SubscriptAssign, this modifies the container rather than assigning a name
+                    let object = self.prepare_expression(object)?;
+                    let index = self.prepare_expression(index)?;
+                    let value = self.prepare_expression(value)?;
+                    new_nodes.push(Node::SubscriptOpAssign {
+                        object,
+                        index,
+                        op,
+                        value,
+                        target_position,
+                    });
+                }
                 Node::AttrAssign {
                     object,
                     attr,
@@ -362,6 +395,23 @@ impl<'i> Prepare<'i> {
                         value,
                     });
                 }
+                Node::AttrOpAssign {
+                    object,
+                    attr,
+                    op,
+                    value,
+                    target_position,
+                } => {
+                    let object = self.prepare_expression(object)?;
+                    let value = self.prepare_expression(value)?;
+                    new_nodes.push(Node::AttrOpAssign {
+                        object,
+                        attr,
+                        op,
+                        value,
+                        target_position,
+                    });
+                }
                 Node::For {
                     target,
                     iter,
@@ -527,6 +577,31 @@ impl<'i> Prepare<'i> {
         Ok(new_nodes)
     }
 
+    /// Prepares the exception expression of a `raise` statement.
+    fn prepare_raised_exception(&mut self, expr: ExprLoc) -> Result<ExprLoc, ParseError> {
+        match expr.expr {
+            // Handle raising an exception type constant without instantiation,
+            // e.g. `raise TypeError`. This is transformed into a call: `raise TypeError()`
+            // so the exception is properly instantiated before being raised.
+            // Also handle raising a builtin constant (unlikely but consistent)
+            Expr::Builtin(b) => {
+                let call_expr = Expr::Call {
+                    callable: Callable::Builtin(b),
+                    args: Box::new(ArgExprs::Empty),
+                };
+                Ok(ExprLoc::new(expr.position, call_expr))
+            }
+            Expr::Name(id) => {
+                // Handle raising a variable - could be an exception type or instance.
+                // The runtime will determine whether to call it (type) or raise it directly (instance).
+                let position = id.position;
+                let (resolved_id, _is_new) = self.get_id(id);
+                Ok(ExprLoc::new(position, Expr::Name(resolved_id)))
+            }
+            _ => self.prepare_expression(expr),
+        }
+    }
+
     /// Prepares an exception handler by resolving names in the exception type and body.
     ///
     /// The exception variable (if present) is treated as an assigned name in the current scope.
@@ -561,6 +636,8 @@ impl<'i> Prepare<'i> {
     /// - Lists and tuples are recursively prepared
     /// - Modulo equality patterns like `x % n == k` (constant right-hand side) are optimized to
     ///   `CmpOperator::ModEq`
+    /// - `x == None` and `x != None` become identity checks, and `i < len(x)` is optimized to
+    ///   `CmpOperator::LtLen`
     ///
     /// # Errors
     /// Returns a NameError if an attribute call references an undefined variable
@@ -569,6 +646,10 @@ impl<'i> Prepare<'i> {
         let expr = match expr {
             Expr::Literal(object) => Expr::Literal(object),
             Expr::Builtin(callable) => Expr::Builtin(callable),
+            // `__debug__` is a constant, which lets `if __debug__:` blocks be dropped when optimizing
+            Expr::Name(name) if self.interner.get_str(name.name_id) == "__debug__" => {
+                Expr::Literal(Literal::Bool(!self.optimize))
+            }
             Expr::Name(name) => Expr::Name(self.get_id(name).0),
             Expr::Op { left, op, right } => Expr::Op {
                 left: Box::new(self.prepare_expression(*left)?),
@@ -671,6 +752,10 @@ impl<'i> Prepare<'i> {
                     generators,
                 }
             }
+            Expr::GeneratorExp { elt, generators } => {
+                // Convert the generator expression into a call of its hidden function
+                return self.prepare_generator_exp(*elt, generators, position);
+            }
             Expr::SetComp { elt, generators } => {
                 let (generators, elt, _) = self.prepare_comprehension(generators, Some(*elt), None)?;
                 Expr::SetComp {
@@ -695,9 +780,9 @@ impl<'i> Prepare<'i> {
                 // Convert the raw lambda into a prepared lambda expression
                 return self.prepare_lambda(name_id, &signature, &body, position);
             }
-            Expr::Lambda { .. } => {
-                // Lambda should only be created during prepare, never during parsing
-                unreachable!("Expr::Lambda should not exist before prepare phase")
+            Expr::Lambda { .. } | Expr::Generator { .. } => {
+                // These are only created during prepare, never during parsing
+                unreachable!("prepared function expressions should not exist before prepare phase")
             }
             Expr::Slice { lower, upper, step } => Expr::Slice {
                 lower: lower.map(|e| self.prepare_expression(*e)).transpose()?.map(Box::new),
@@ -716,6 +801,7 @@ impl<'i> Prepare<'i> {
                 }
             }
             Expr::Await(value) => Expr::Await(Box::new(self.prepare_expression(*value)?)),
+            Expr::Yield(value) => Expr::Yield(Box::new(self.prepare_expression(*value)?)),
         };
 
         // Optimization: Transform `(x % n) == value` with any constant right-hand side into a
@@ -744,6 +830,51 @@ impl<'i> Prepare<'i> {
             });
         }
 
+        // Optimization: `x == None` is the same as `x is None`, since no value other than None
+        // itself compares equal to None, and identity is a cheaper check than equality.
+        if let Expr::CmpOp { left, op, right } = &expr
+            && matches!(op, CmpOperator::Eq | CmpOperator::NotEq)
+            && right.expr.is_none()
+        {
+            let op = if op == &CmpOperator::Eq {
+                CmpOperator::Is
+            } else {
+                CmpOperator::IsNot
+            };
+            let new_expr = Expr::CmpOp {
+                left: left.clone(),
+                op,
+                right: right.clone(),
+            };
+            return Ok(ExprLoc {
+                position,
+                expr: new_expr,
+            });
+        }
+
+        // Optimization: Transform `i < len(x)` into a specialized LtLen operator.
+        // This is the usual `while i < len(items):` loop condition, and fusing it avoids the
+        // builtin call machinery on every iteration. `len` always refers to the builtin here
+        // since builtin names are resolved at parse time.
+        if let Expr::CmpOp { left, op, right } = &expr
+            && op == &CmpOperator::Lt
+            && let Expr::Call {
+                callable: Callable::Builtin(Builtins::Function(BuiltinsFunctions::Len)),
+                args,
+            } = &right.expr
+            && let ArgExprs::One(arg) = args.as_ref()
+        {
+            let new_expr = Expr::CmpOp {
+                left: left.clone(),
+                op: CmpOperator::LtLen,
+                right: Box::new(arg.clone()),
+            };
+            return Ok(ExprLoc {
+                position,
+                expr: new_expr,
+            });
+        }
+
         Ok(ExprLoc { position, expr })
     }
 
@@ -771,29 +902,15 @@ impl<'i> Prepare<'i> {
     ) -> Result<(Vec<Comprehension>, Option<ExprLoc>, Option<(ExprLoc, ExprLoc)>), ParseError> {
         // Per PEP 572, walrus operators inside comprehensions bind in the ENCLOSING scope.
         // Pre-register walrus targets before saving scope state, so they persist after restore.
-        let mut walrus_targets: AHashSet<String> = AHashSet::new();
-        if let Some(ref e) = elt {
-            collect_assigned_names_from_expr(e, &mut walrus_targets, self.interner);
-        }
+        let mut exprs: Vec<&ExprLoc> = elt.iter().collect();
         if let Some((ref k, ref v)) = key_value {
-            collect_assigned_names_from_expr(k, &mut walrus_targets, self.interner);
-            collect_assigned_names_from_expr(v, &mut walrus_targets, self.interner);
-        }
-        for generator in &generators {
-            // Note: we don't scan iter expressions here because walrus in iterable is not allowed
-            for cond in &generator.ifs {
-                collect_assigned_names_from_expr(cond, &mut walrus_targets, self.interner);
-            }
-        }
-        // Pre-allocate slots for walrus targets in the enclosing scope
-        for name in &walrus_targets {
-            if !self.name_map.contains_key(name) {
-                let slot = NamespaceId::new(self.namespace_size);
-                self.namespace_size += 1;
-                self.name_map.insert(name.clone(), slot);
-                self.names_assigned_in_order.insert(name.clone());
-            }
+            exprs.extend([k, v]);
         }
+        self.register_walrus_targets(&generators, &exprs);
+
+        // Loop variables captured by closures inside the comprehension are stored in cells
+        let mut captured: AHashSet<String> = AHashSet::new();
+        collect_cell_vars_from_comprehension(&generators, &exprs, &AHashSet::new(), &mut captured, self.interner);
 
         // Save current scope state for isolation
         let saved_name_map = self.name_map.clone();
@@ -814,7 +931,7 @@ impl<'i> Prepare<'i> {
         // We allocate slots but don't mark them as "assigned" yet - this causes
         // UnboundLocalError if a later generator's iter references an earlier-declared
         // but not-yet-assigned loop variable.
-        let first_target = self.prepare_unpack_target_for_comprehension(first_gen.target);
+        let first_target = self.prepare_unpack_target_for_comprehension(first_gen.target, &captured);
 
         // Collect remaining generators so we can pre-shadow their targets
         let remaining_gens: Vec<Comprehension> = generators_iter.collect();
@@ -824,7 +941,7 @@ impl<'i> Prepare<'i> {
         // so referencing a later loop var in an earlier iter raises UnboundLocalError.
         let mut preshadowed_targets: Vec<UnpackTarget> = Vec::with_capacity(remaining_gens.len());
         for generator in &remaining_gens {
-            preshadowed_targets.push(self.prepare_unpack_target_shadow_only(generator.target.clone()));
+            preshadowed_targets.push(self.prepare_unpack_target_shadow_only(generator.target.clone(), &captured));
         }
 
         // Prepare first generator's filters (can see first loop variable)
@@ -877,6 +994,32 @@ impl<'i> Prepare<'i> {
         Ok((prepared_generators, prepared_elt, prepared_key_value))
     }
 
+    /// Pre-allocates slots in this scope for the walrus targets inside a comprehension.
+    ///
+    /// Per PEP 572 these bind in the enclosing scope rather than the comprehension's.
+    /// `exprs` are the element expressions; iterables are not scanned because a walrus
+    /// is not allowed there. Returns the target names.
+    fn register_walrus_targets(&mut self, generators: &[Comprehension], exprs: &[&ExprLoc]) -> AHashSet<String> {
+        let mut walrus_targets: AHashSet<String> = AHashSet::new();
+        for e in exprs {
+            collect_assigned_names_from_expr(e, &mut walrus_targets, self.interner);
+        }
+        for generator in generators {
+            for cond in &generator.ifs {
+                collect_assigned_names_from_expr(cond, &mut walrus_targets, self.interner);
+            }
+        }
+        for name in &walrus_targets {
+            if !self.name_map.contains_key(name) {
+                let slot = NamespaceId::new(self.namespace_size);
+                self.namespace_size += 1;
+                self.name_map.insert(name.clone(), slot);
+                self.names_assigned_in_order.insert(name.clone());
+            }
+        }
+        walrus_targets
+    }
+
     /// Prepares an unpack target by resolving identifiers recursively.
     ///
     /// Handles both single identifiers and nested tuples like `(a, b), c`.
@@ -909,42 +1052,20 @@ impl<'i> Prepare<'i> {
     ///
     /// Unlike regular unpack targets, comprehension targets need new slots to shadow
     /// any existing bindings with the same name.
-    fn prepare_unpack_target_for_comprehension(&mut self, target: UnpackTarget) -> UnpackTarget {
+    fn prepare_unpack_target_for_comprehension(
+        &mut self,
+        target: UnpackTarget,
+        captured: &AHashSet<String>,
+    ) -> UnpackTarget {
         match target {
-            UnpackTarget::Name(ident) => {
-                let name_str = self.interner.get_str(ident.name_id).to_string();
-                let comp_var_id = NamespaceId::new(self.namespace_size);
-                self.namespace_size += 1;
-
-                // Shadow any existing binding
-                self.shadow_for_comprehension(&name_str, comp_var_id);
-
-                UnpackTarget::Name(Identifier::new_with_scope(
-                    ident.name_id,
-                    ident.position,
-                    comp_var_id,
-                    NameScope::Local,
-                ))
-            }
+            UnpackTarget::Name(ident) => UnpackTarget::Name(self.prepare_comprehension_var(ident, captured, true)),
             UnpackTarget::Starred(ident) => {
-                let name_str = self.interner.get_str(ident.name_id).to_string();
-                let comp_var_id = NamespaceId::new(self.namespace_size);
-                self.namespace_size += 1;
-
-                // Shadow any existing binding
-                self.shadow_for_comprehension(&name_str, comp_var_id);
-
-                UnpackTarget::Starred(Identifier::new_with_scope(
-                    ident.name_id,
-                    ident.position,
-                    comp_var_id,
-                    NameScope::Local,
-                ))
+                UnpackTarget::Starred(self.prepare_comprehension_var(ident, captured, true))
             }
             UnpackTarget::Tuple { targets, position } => {
                 let resolved_targets: Vec<UnpackTarget> = targets
                     .into_iter()
-                    .map(|t| self.prepare_unpack_target_for_comprehension(t)) // Recursive call
+                    .map(|t| self.prepare_unpack_target_for_comprehension(t, captured)) // Recursive call
                     .collect();
                 UnpackTarget::Tuple {
                     targets: resolved_targets,
@@ -958,52 +1079,16 @@ impl<'i> Prepare<'i> {
     ///
     /// Allocates namespace slots without marking as assigned, causing UnboundLocalError
     /// if accessed before assignment.
-    fn prepare_unpack_target_shadow_only(&mut self, target: UnpackTarget) -> UnpackTarget {
+    fn prepare_unpack_target_shadow_only(&mut self, target: UnpackTarget, captured: &AHashSet<String>) -> UnpackTarget {
         match target {
-            UnpackTarget::Name(ident) => {
-                let name_str = self.interner.get_str(ident.name_id).to_string();
-                let comp_var_id = NamespaceId::new(self.namespace_size);
-                self.namespace_size += 1;
-
-                // Shadow but do NOT add to names_assigned_in_order yet
-                self.name_map.insert(name_str.clone(), comp_var_id);
-                self.free_var_map.remove(&name_str);
-                self.cell_var_map.remove(&name_str);
-                if let Some(ref mut enclosing) = self.enclosing_locals {
-                    enclosing.remove(&name_str);
-                }
-
-                UnpackTarget::Name(Identifier::new_with_scope(
-                    ident.name_id,
-                    ident.position,
-                    comp_var_id,
-                    NameScope::Local,
-                ))
-            }
+            UnpackTarget::Name(ident) => UnpackTarget::Name(self.prepare_comprehension_var(ident, captured, false)),
             UnpackTarget::Starred(ident) => {
-                let name_str = self.interner.get_str(ident.name_id).to_string();
-                let comp_var_id = NamespaceId::new(self.namespace_size);
-                self.namespace_size += 1;
-
-                // Shadow but do NOT add to names_assigned_in_order yet
-                self.name_map.insert(name_str.clone(), comp_var_id);
-                self.free_var_map.remove(&name_str);
-                self.cell_var_map.remove(&name_str);
-                if let Some(ref mut enclosing) = self.enclosing_locals {
-                    enclosing.remove(&name_str);
-                }
-
-                UnpackTarget::Starred(Identifier::new_with_scope(
-                    ident.name_id,
-                    ident.position,
-                    comp_var_id,
-                    NameScope::Local,
-                ))
+                UnpackTarget::Starred(self.prepare_comprehension_var(ident, captured, false))
             }
             UnpackTarget::Tuple { targets, position } => {
                 let resolved_targets: Vec<UnpackTarget> = targets
                     .into_iter()
-                    .map(|t| self.prepare_unpack_target_shadow_only(t)) // Recursive call
+                    .map(|t| self.prepare_unpack_target_shadow_only(t, captured)) // Recursive call
                     .collect();
                 UnpackTarget::Tuple {
                     targets: resolved_targets,
@@ -1013,19 +1098,59 @@ impl<'i> Prepare<'i> {
         }
     }
 
+    /// Resolves a single comprehension loop variable, shadowing any existing binding.
+    ///
+    /// A loop variable normally gets a fresh slot. One that a closure inside the comprehension
+    /// captures (listed in `captured`) instead uses the cell the scope analysis reserved for it,
+    /// so the closure sees the variable's current value. When `assigned` is false the variable
+    /// is only shadowed, so reading it before its loop starts raises UnboundLocalError.
+    fn prepare_comprehension_var(
+        &mut self,
+        ident: Identifier,
+        captured: &AHashSet<String>,
+        assigned: bool,
+    ) -> Identifier {
+        let name_str = self.interner.get_str(ident.name_id).to_string();
+        let (slot, scope) = if captured.contains(&name_str)
+            && let Some(&slot) = self.cell_var_map.get(&name_str)
+        {
+            (slot, NameScope::Cell)
+        } else {
+            let slot = NamespaceId::new(self.namespace_size);
+            self.namespace_size += 1;
+            (slot, NameScope::Local)
+        };
+
+        if assigned {
+            self.shadow_for_comprehension(&name_str, slot, scope);
+        } else {
+            self.name_map.insert(name_str.clone(), slot);
+            if scope == NameScope::Local {
+                self.free_var_map.remove(&name_str);
+                self.cell_var_map.remove(&name_str);
+            }
+            if let Some(ref mut enclosing) = self.enclosing_locals {
+                enclosing.remove(&name_str);
+            }
+        }
+        Identifier::new_with_scope(ident.name_id, ident.position, slot, scope)
+    }
+
     /// Shadows a name in all scope maps for comprehension isolation.
     ///
     /// This ensures the comprehension loop variable takes precedence over any
     /// variable with the same name from enclosing scopes.
-    fn shadow_for_comprehension(&mut self, name_str: &str, comp_var_id: NamespaceId) {
+    fn shadow_for_comprehension(&mut self, name_str: &str, comp_var_id: NamespaceId, scope: NameScope) {
         // The lookup order in get_id is: global_declarations, free_var_map, cell_var_map,
         // assigned_names, enclosing_locals, then name_map. So we must update/remove from all maps
         // checked before name_map to ensure the comprehension variable shadows any captured
-        // variable with the same name.
+        // variable with the same name. A captured loop variable keeps its own cell_var_map entry.
         self.name_map.insert(name_str.to_string(), comp_var_id);
         self.names_assigned_in_order.insert(name_str.to_string());
-        self.free_var_map.remove(name_str);
-        self.cell_var_map.remove(name_str);
+        if scope == NameScope::Local {
+            self.free_var_map.remove(name_str);
+            self.cell_var_map.remove(name_str);
+        }
         // Also remove from enclosing_locals to prevent get_id from re-capturing the variable
         if let Some(ref mut enclosing) = self.enclosing_locals {
             enclosing.remove(name_str);
@@ -1107,59 +1232,20 @@ impl<'i> Prepare<'i> {
             Some(enclosing_locals),
             scope_info.cell_var_names,
             self.interner,
+            self.optimize,
         );
 
         // Prepare the function body
         let prepared_body = inner_prepare.prepare_nodes(body)?;
 
-        // Mark variables that the inner function captures as our cell_vars
-        // These are the names that appear in inner_prepare.free_var_map
-        // Add to cell_var_map if not already present (may have been pre-populated or added earlier)
-        for captured_name in inner_prepare.free_var_map.keys() {
-            if !self.cell_var_map.contains_key(captured_name) && !self.free_var_map.contains_key(captured_name) {
-                // Only add to cell_var_map if not already a free_var (pass-through case)
-                // Allocate a namespace slot for the cell reference
-                let slot = match self.name_map.entry(captured_name.clone()) {
-                    Entry::Occupied(e) => *e.get(),
-                    Entry::Vacant(e) => {
-                        let slot = NamespaceId::new(self.namespace_size);
-                        self.namespace_size += 1;
-                        e.insert(slot);
-                        slot
-                    }
-                };
-                self.cell_var_map.insert(captured_name.clone(), slot);
-            }
-        }
-
-        // Build free_var_enclosing_slots: enclosing namespace slots for captured variables
-        // At call time, cells are pushed sequentially, so we only need the enclosing slots.
-        // Sort by our slot index to ensure consistent ordering (matches namespace layout).
-        let mut free_var_entries: Vec<_> = inner_prepare.free_var_map.into_iter().collect();
-        free_var_entries.sort_by_key(|(_, our_slot)| *our_slot);
-
-        let free_var_enclosing_slots: Vec<NamespaceId> = free_var_entries
-            .into_iter()
-            .map(|(var_name, _our_slot)| {
-                // Determine the namespace slot in the enclosing scope where the cell reference lives:
-                // - If it's in cell_var_map, it's a cell we own (allocated in this scope)
-                // - If it's in free_var_map, it's a cell we captured from further up
-                // - Otherwise, this is a prepare-time bug
-                if let Some(&slot) = self.cell_var_map.get(&var_name) {
-                    slot
-                } else if let Some(&slot) = self.free_var_map.get(&var_name) {
-                    slot
-                } else {
-                    panic!("free_var '{var_name}' not found in enclosing scope's cell_var_map or free_var_map");
-                }
-            })
-            .collect();
-
         // cell_var_count: number of cells to create at call time for variables captured by nested functions
         // Slots are implicitly params.len()..params.len()+cell_var_count in the namespace layout
         let cell_var_count = inner_prepare.cell_var_map.len();
         let namespace_size = inner_prepare.namespace_size;
 
+        // Our cells for the variables the inner function captures, in the order it expects them
+        let free_var_enclosing_slots = self.link_free_vars(inner_prepare.free_var_map);
+
         // Build cell_param_indices: maps cell indices to parameter indices for captured parameters.
         // When a parameter is captured by a nested function, we need to copy its value into the cell.
         let cell_param_indices: Vec<Option<usize>> = if cell_var_count == 0 {
@@ -1242,6 +1328,7 @@ impl<'i> Prepare<'i> {
             cell_param_indices,
             default_exprs,
             is_async,
+            is_generator: false,
         }))
     }
 
@@ -1279,28 +1366,7 @@ impl<'i> Prepare<'i> {
         // (Lambdas can't have global/nonlocal declarations, but can have nested functions)
         let scope_info = collect_function_scope_info(&body_nodes, &param_names, self.interner);
 
-        // Get the global name map to pass to the function preparer
-        let global_name_map = if self.is_module_scope {
-            self.name_map.clone()
-        } else {
-            self.global_name_map.clone().unwrap_or_default()
-        };
-
-        // Build enclosing_locals: names that are local to this scope or captured from enclosing scope.
-        // This includes free_vars so that nested lambdas can capture pass-through variables.
-        let enclosing_locals: AHashSet<String> = if self.is_module_scope {
-            AHashSet::new()
-        } else {
-            let mut locals = self.assigned_names.clone();
-            for key in self.name_map.keys() {
-                locals.insert(key.clone());
-            }
-            // Include free_vars so nested functions/lambdas can capture pass-through variables
-            for key in self.free_var_map.keys() {
-                locals.insert(key.clone());
-            }
-            locals
-        };
+        let (global_name_map, enclosing_locals) = self.closure_scope();
 
         // Filter potential_captures to get actual implicit captures
         let implicit_captures: AHashSet<String> = scope_info
@@ -1321,48 +1387,18 @@ impl<'i> Prepare<'i> {
             Some(enclosing_locals),
             scope_info.cell_var_names,
             self.interner,
+            self.optimize,
         );
 
         // Prepare the lambda body
         let prepared_body = inner_prepare.prepare_nodes(body_nodes)?;
 
-        // Mark variables that the inner function captures as our cell_vars
-        for captured_name in inner_prepare.free_var_map.keys() {
-            if !self.cell_var_map.contains_key(captured_name) && !self.free_var_map.contains_key(captured_name) {
-                let slot = match self.name_map.entry(captured_name.clone()) {
-                    Entry::Occupied(e) => *e.get(),
-                    Entry::Vacant(e) => {
-                        let slot = NamespaceId::new(self.namespace_size);
-                        self.namespace_size += 1;
-                        e.insert(slot);
-                        slot
-                    }
-                };
-                self.cell_var_map.insert(captured_name.clone(), slot);
-            }
-        }
-
-        // Build free_var_enclosing_slots
-        let mut free_var_entries: Vec<_> = inner_prepare.free_var_map.into_iter().collect();
-        free_var_entries.sort_by_key(|(_, our_slot)| *our_slot);
-
-        let free_var_enclosing_slots: Vec<NamespaceId> = free_var_entries
-            .into_iter()
-            .map(|(var_name, _our_slot)| {
-                if let Some(&slot) = self.cell_var_map.get(&var_name) {
-                    slot
-                } else if let Some(&slot) = self.free_var_map.get(&var_name) {
-                    slot
-                } else {
-                    panic!("free_var '{var_name}' not found in enclosing scope's cell_var_map or free_var_map");
-                }
-            })
-            .collect();
-
-        // Build cell_param_indices
+        // Link our cells for the variables the lambda captures
         let cell_var_count = inner_prepare.cell_var_map.len();
         let namespace_size = inner_prepare.namespace_size;
+        let free_var_enclosing_slots = self.link_free_vars(inner_prepare.free_var_map);
 
+        // Build cell_param_indices
         let cell_param_indices: Vec<Option<usize>> = if cell_var_count == 0 {
             Vec::new()
         } else {
@@ -1439,6 +1475,7 @@ impl<'i> Prepare<'i> {
             cell_param_indices,
             default_exprs,
             is_async: false,
+            is_generator: false,
         };
 
         Ok(ExprLoc::new(
@@ -1449,6 +1486,199 @@ impl<'i> Prepare<'i> {
         ))
     }
 
+    /// Prepares a generator expression, converting it into a call of a hidden function.
+    ///
+    /// As in CPython, `(elt for x in xs if cond for y in ys)` becomes a `<genexpr>` function
+    /// taking an iterator over `xs` as its `.0` parameter, with the body
+    ///
+    /// ```text
+    /// for x in .0:
+    ///     if cond:
+    ///         for y in ys:
+    ///             yield elt
+    /// ```
+    ///
+    /// The loop variables are then ordinary locals of that function, while the names it
+    /// reads from this scope are captured like a lambda's. Walrus targets bind in this
+    /// scope, so they are declared `nonlocal` (or `global` at module level) in the body.
+    fn prepare_generator_exp(
+        &mut self,
+        elt: ExprLoc,
+        mut generators: Vec<Comprehension>,
+        position: CodeRange,
+    ) -> Result<ExprLoc, ParseError> {
+        let iter_param: StringId = StaticStrings::GenExprIter.into();
+        let walrus_targets = self.register_walrus_targets(&generators, &[&elt]);
+
+        // The outermost iterable is evaluated here; the body loops over the `.0` parameter instead
+        let first_gen = generators
+            .first_mut()
+            .expect("comprehension must have at least one generator");
+        let iter_position = first_gen.iter.position;
+        let iter_name = ExprLoc::new(iter_position, Expr::Name(Identifier::new(iter_param, iter_position)));
+        let iter = self.prepare_expression(std::mem::replace(&mut first_gen.iter, iter_name))?;
+
+        let elt_position = elt.position;
+        let mut body: Vec<ParseNode> = vec![Node::Expr(ExprLoc::new(elt_position, Expr::Yield(Box::new(elt))))];
+        for generator in generators.into_iter().rev() {
+            for test in generator.ifs.into_iter().rev() {
+                body = vec![Node::If {
+                    test,
+                    body,
+                    or_else: Vec::new(),
+                }];
+            }
+            body = vec![Node::For {
+                target: generator.target,
+                iter: generator.iter,
+                body,
+                or_else: Vec::new(),
+            }];
+        }
+
+        let param_names = [iter_param];
+        let mut scope_info = collect_function_scope_info(&body, &param_names, self.interner);
+        for name in walrus_targets {
+            scope_info.assigned_names.remove(&name);
+            scope_info.cell_var_names.remove(&name);
+            if self.is_module_scope || self.global_names.contains(&name) {
+                scope_info.global_names.insert(name);
+            } else {
+                scope_info.nonlocal_names.insert(name);
+            }
+        }
+
+        let (global_name_map, mut enclosing_locals) = self.closure_scope();
+        // A nested generator's own `.0` parameter must not resolve to ours
+        enclosing_locals.remove(self.interner.get_str(iter_param));
+        let implicit_captures: AHashSet<String> = scope_info
+            .potential_captures
+            .into_iter()
+            .filter(|name| enclosing_locals.contains(name))
+            .collect();
+
+        let mut inner_prepare = Prepare::new_function(
+            body.len(),
+            &param_names,
+            scope_info.assigned_names,
+            scope_info.global_names,
+            scope_info.nonlocal_names,
+            implicit_captures,
+            global_name_map,
+            Some(enclosing_locals),
+            scope_info.cell_var_names,
+            self.interner,
+            self.optimize,
+        );
+        let prepared_body = inner_prepare.prepare_nodes(body)?;
+
+        // The `.0` parameter is never captured, so none of the cells hold a parameter
+        let cell_var_count = inner_prepare.cell_var_map.len();
+        let namespace_size = inner_prepare.namespace_size;
+        let free_var_enclosing_slots = self.link_free_vars(inner_prepare.free_var_map);
+
+        let func_def = PreparedFunctionDef {
+            name: Identifier::new_with_scope(
+                StaticStrings::GenExpr.into(),
+                position,
+                NamespaceId::new(0), // Placeholder, not actually used for storage
+                NameScope::Local,
+            ),
+            signature: Signature::new(Vec::new(), 0, vec![iter_param], 0, None, Vec::new(), Vec::new(), None),
+            body: prepared_body,
+            namespace_size,
+            free_var_enclosing_slots,
+            cell_var_count,
+            cell_param_indices: vec![None; cell_var_count],
+            default_exprs: Vec::new(),
+            is_async: false,
+            is_generator: true,
+        };
+
+        Ok(ExprLoc::new(
+            position,
+            Expr::Generator {
+                func_def: Box::new(func_def),
+                iter: Box::new(iter),
+            },
+        ))
+    }
+
+    /// Returns the global name map and enclosing locals for a lambda or generator defined here.
+    ///
+    /// The enclosing locals are the names the closure can capture from this scope. They include
+    /// our own free variables, so nested closures can capture pass-through variables.
+    fn closure_scope(&self) -> (AHashMap<String, NamespaceId>, AHashSet<String>) {
+        let global_name_map = if self.is_module_scope {
+            self.name_map.clone()
+        } else {
+            self.global_name_map.clone().unwrap_or_default()
+        };
+
+        let enclosing_locals: AHashSet<String> = if self.is_module_scope {
+            AHashSet::new()
+        } else {
+            let mut locals = self.assigned_names.clone();
+            for key in self.name_map.keys() {
+                locals.insert(key.clone());
+            }
+            for key in self.free_var_map.keys() {
+                locals.insert(key.clone());
+            }
+            locals
+        };
+        (global_name_map, enclosing_locals)
+    }
+
+    /// Links the variables a nested function captures to cells in this scope.
+    ///
+    /// Takes the nested function's `free_var_map` and returns `free_var_enclosing_slots`:
+    /// the slots in our namespace holding the cells it captures, in the order it expects them.
+    fn link_free_vars(&mut self, free_var_map: AHashMap<String, NamespaceId>) -> Vec<NamespaceId> {
+        // Mark variables that the inner function captures as our cell_vars
+        // These are the names that appear in the nested free_var_map
+        // Add to cell_var_map if not already present (may have been pre-populated or added earlier)
+        for captured_name in free_var_map.keys() {
+            if !self.cell_var_map.contains_key(captured_name) && !self.free_var_map.contains_key(captured_name) {
+                // Only add to cell_var_map if not already a free_var (pass-through case)
+                // Allocate a namespace slot for the cell reference
+                let slot = match self.name_map.entry(captured_name.clone()) {
+                    Entry::Occupied(e) => *e.get(),
+                    Entry::Vacant(e) => {
+                        let slot = NamespaceId::new(self.namespace_size);
+                        self.namespace_size += 1;
+                        e.insert(slot);
+                        slot
+                    }
+                };
+                self.cell_var_map.insert(captured_name.clone(), slot);
+            }
+        }
+
+        // Build free_var_enclosing_slots: enclosing namespace slots for captured variables
+        // At call time, cells are pushed sequentially, so we only need the enclosing slots.
+        // Sort by our slot index to ensure consistent ordering (matches namespace layout).
+        let mut free_var_entries: Vec<_> = free_var_map.into_iter().collect();
+        free_var_entries.sort_by_key(|(_, our_slot)| *our_slot);
+
+        free_var_entries
+            .into_iter()
+            .map(|(var_name, _our_slot)| {
+                // Determine the namespace slot in the enclosing scope where the cell reference lives:
+                // - If it's in cell_var_map, it's a cell we own (allocated in this scope)
+                // - If it's in free_var_map, it's a cell we captured from further up
+                // - Otherwise, this is a prepare-time bug
+                if let Some(&slot) = self.cell_var_map.get(&var_name) {
+                    slot
+                } else if let Some(&slot) = self.free_var_map.get(&var_name) {
+                    slot
+                } else {
+                    panic!("free_var '{var_name}' not found in enclosing scope's cell_var_map or free_var_map");
+                }
+            })
+            .collect()
+    }
+
     /// Resolves an identifier to its namespace index and scope, creating a new entry if needed.
     ///
     /// TODO This whole implementation seems ugly at best.
@@ -1804,6 +2034,13 @@ fn collect_scope_info_from_node(
             // Scan value expression for walrus operators
             collect_assigned_names_from_expr(object, assigned_names, interner);
         }
+        Node::ChainAssign { targets, object } => {
+            for target in targets {
+                collect_names_from_unpack_target(target, assigned_names, interner);
+            }
+            // Scan value expression for walrus operators
+            collect_assigned_names_from_expr(object, assigned_names, interner);
+        }
         Node::OpAssign { target, object, .. } => {
             assigned_names.insert(interner.get_str(target.name_id).to_string());
             // Scan value expression for walrus operators
@@ -1815,7 +2052,14 @@ fn collect_scope_info_from_node(
             collect_assigned_names_from_expr(index, assigned_names, interner);
             collect_assigned_names_from_expr(value, assigned_names, interner);
         }
-        Node::AttrAssign { object, value, .. } => {
+        Node::SubscriptOpAssign {
+            object, index, value, ..
+        } => {
+            collect_assigned_names_from_expr(object, assigned_names, interner);
+            collect_assigned_names_from_expr(index, assigned_names, interner);
+            collect_assigned_names_from_expr(value, assigned_names, interner);
+        }
+        Node::AttrAssign { object, value, .. } | Node::AttrOpAssign { object, value, .. } => {
             // Attribute assignment doesn't create a new name, it modifies existing object
             // But scan expressions for walrus operators
             collect_assigned_names_from_expr(object, assigned_names, interner);
@@ -1909,6 +2153,10 @@ fn collect_scope_info_from_node(
         Node::Raise(Some(expr)) => {
             collect_assigned_names_from_expr(expr, assigned_names, interner);
         }
+        Node::RaiseFrom { exc, cause } => {
+            collect_assigned_names_from_expr(exc, assigned_names, interner);
+            collect_assigned_names_from_expr(cause, assigned_names, interner);
+        }
         Node::Assert { test, msg } => {
             collect_assigned_names_from_expr(test, assigned_names, interner);
             if let Some(m) = msg {
@@ -1959,7 +2207,8 @@ fn collect_assigned_names_from_expr(expr: &ExprLoc, assigned_names: &mut AHashSe
         | Expr::UnaryMinus(operand)
         | Expr::UnaryPlus(operand)
         | Expr::UnaryInvert(operand)
-        | Expr::Await(operand) => {
+        | Expr::Await(operand)
+        | Expr::Yield(operand) => {
             collect_assigned_names_from_expr(operand, assigned_names, interner);
         }
         Expr::Subscript { object, index } => {
@@ -1986,7 +2235,9 @@ fn collect_assigned_names_from_expr(expr: &ExprLoc, assigned_names: &mut AHashSe
             collect_assigned_names_from_expr(orelse, assigned_names, interner);
         }
         // Per PEP 572, walrus in comprehensions assigns to the ENCLOSING scope
-        Expr::ListComp { elt, generators } | Expr::SetComp { elt, generators } => {
+        Expr::ListComp { elt, generators }
+        | Expr::GeneratorExp { elt, generators }
+        | Expr::SetComp { elt, generators } => {
             collect_assigned_names_from_expr(elt, assigned_names, interner);
             for generator in generators {
                 collect_assigned_names_from_expr(&generator.iter, assigned_names, interner);
@@ -2024,7 +2275,7 @@ fn collect_assigned_names_from_expr(expr: &ExprLoc, assigned_names: &mut AHashSe
             }
         }
         // Lambda bodies have their own scope - walrus inside them doesn't affect us
-        Expr::LambdaRaw { .. } | Expr::Lambda { .. } => {}
+        Expr::LambdaRaw { .. } | Expr::Lambda { .. } | Expr::Generator { .. } => {}
         // Leaf expressions don't contain walrus operators
         Expr::Literal(_) | Expr::Builtin(_) | Expr::Name(_) => {}
     }
@@ -2177,7 +2428,7 @@ fn collect_cell_vars_from_node(
         Node::Expr(expr) | Node::Return(expr) => {
             collect_cell_vars_from_expr(expr, our_locals, cell_vars, interner);
         }
-        Node::Assign { object, .. } | Node::UnpackAssign { object, .. } => {
+        Node::Assign { object, .. } | Node::UnpackAssign { object, .. } | Node::ChainAssign { object, .. } => {
             collect_cell_vars_from_expr(object, our_locals, cell_vars, interner);
         }
         Node::OpAssign { object, .. } => {
@@ -2187,7 +2438,14 @@ fn collect_cell_vars_from_node(
             collect_cell_vars_from_expr(index, our_locals, cell_vars, interner);
             collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
         }
-        Node::AttrAssign { object, value, .. } => {
+        Node::SubscriptOpAssign {
+            object, index, value, ..
+        } => {
+            collect_cell_vars_from_expr(object, our_locals, cell_vars, interner);
+            collect_cell_vars_from_expr(index, our_locals, cell_vars, interner);
+            collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
+        }
+        Node::AttrAssign { object, value, .. } | Node::AttrOpAssign { object, value, .. } => {
             collect_cell_vars_from_expr(object, our_locals, cell_vars, interner);
             collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
         }
@@ -2302,23 +2560,24 @@ fn collect_cell_vars_from_expr(
             collect_cell_vars_from_expr(orelse, our_locals, cell_vars, interner);
         }
         Expr::ListComp { elt, generators } | Expr::SetComp { elt, generators } => {
-            collect_cell_vars_from_expr(elt, our_locals, cell_vars, interner);
-            for generator in generators {
-                collect_cell_vars_from_expr(&generator.iter, our_locals, cell_vars, interner);
-                for cond in &generator.ifs {
-                    collect_cell_vars_from_expr(cond, our_locals, cell_vars, interner);
-                }
-            }
+            collect_cell_vars_from_comprehension(generators, &[elt.as_ref()], our_locals, cell_vars, interner);
         }
         Expr::DictComp { key, value, generators } => {
-            collect_cell_vars_from_expr(key, our_locals, cell_vars, interner);
-            collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
-            for generator in generators {
-                collect_cell_vars_from_expr(&generator.iter, our_locals, cell_vars, interner);
-                for cond in &generator.ifs {
-                    collect_cell_vars_from_expr(cond, our_locals, cell_vars, interner);
+            collect_cell_vars_from_comprehension(generators, &[key.as_ref(), value.as_ref()], our_locals, cell_vars, interner);
+        }
+        Expr::GeneratorExp { elt, generators } => {
+            // The body runs in the generator's own function, so like a lambda it captures
+            // the names of ours it uses, including walrus targets, which bind in our scope
+            let mut referenced = AHashSet::new();
+            collect_referenced_names_from_comprehension_body(generators, Some(elt), None, &mut referenced, interner);
+            collect_assigned_names_from_expr(expr, &mut referenced, interner);
+            for name in &referenced {
+                if our_locals.contains(name) {
+                    cell_vars.insert(name.clone());
                 }
             }
+            // Only the first iterable is evaluated in our scope
+            collect_cell_vars_from_expr(&generators[0].iter, our_locals, cell_vars, interner);
         }
         Expr::FString(parts) => {
             for part in parts {
@@ -2331,11 +2590,44 @@ fn collect_cell_vars_from_expr(
             // Only scan the value expression for cell vars
             collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
         }
-        Expr::Await(value) => {
+        Expr::Await(value) | Expr::Yield(value) => {
             collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
         }
         // Leaf expressions
-        Expr::Literal(_) | Expr::Builtin(_) | Expr::Name(_) | Expr::Lambda { .. } | Expr::Slice { .. } => {}
+        Expr::Literal(_)
+        | Expr::Builtin(_)
+        | Expr::Name(_)
+        | Expr::Lambda { .. }
+        | Expr::Generator { .. }
+        | Expr::Slice { .. } => {}
+    }
+}
+
+/// Collects cell_vars from closures inside an inlined comprehension.
+///
+/// The first iterable is evaluated in our scope. The rest of the comprehension also sees the
+/// loop variables, which live in our namespace, so closures that capture them need cells too.
+/// `exprs` are the element expressions.
+fn collect_cell_vars_from_comprehension(
+    generators: &[Comprehension],
+    exprs: &[&ExprLoc],
+    our_locals: &AHashSet<String>,
+    cell_vars: &mut AHashSet<String>,
+    interner: &InternerBuilder,
+) {
+    let mut comp_locals = our_locals.clone();
+    for generator in generators {
+        collect_names_from_unpack_target(&generator.target, &mut comp_locals, interner);
+    }
+    for (i, generator) in generators.iter().enumerate() {
+        let iter_locals = if i == 0 { our_locals } else { &comp_locals };
+        collect_cell_vars_from_expr(&generator.iter, iter_locals, cell_vars, interner);
+        for cond in &generator.ifs {
+            collect_cell_vars_from_expr(cond, &comp_locals, cell_vars, interner);
+        }
+    }
+    for expr in exprs {
+        collect_cell_vars_from_expr(expr, &comp_locals, cell_vars, interner);
     }
 }
 
@@ -2399,6 +2691,10 @@ fn collect_referenced_names_from_node(node: &ParseNode, referenced: &mut AHashSe
         Node::Return(expr) => collect_referenced_names_from_expr(expr, referenced, interner),
         Node::Raise(Some(expr)) => collect_referenced_names_from_expr(expr, referenced, interner),
         Node::Raise(None) => {}
+        Node::RaiseFrom { exc, cause } => {
+            collect_referenced_names_from_expr(exc, referenced, interner);
+            collect_referenced_names_from_expr(cause, referenced, interner);
+        }
         Node::Assert { test, msg } => {
             collect_referenced_names_from_expr(test, referenced, interner);
             if let Some(m) = msg {
@@ -2408,7 +2704,7 @@ fn collect_referenced_names_from_node(node: &ParseNode, referenced: &mut AHashSe
         Node::Assign { object, .. } => {
             collect_referenced_names_from_expr(object, referenced, interner);
         }
-        Node::UnpackAssign { object, .. } => {
+        Node::UnpackAssign { object, .. } | Node::ChainAssign { object, .. } => {
             collect_referenced_names_from_expr(object, referenced, interner);
         }
         Node::OpAssign { target, object, .. } => {
@@ -2423,7 +2719,14 @@ fn collect_referenced_names_from_node(node: &ParseNode, referenced: &mut AHashSe
             collect_referenced_names_from_expr(index, referenced, interner);
             collect_referenced_names_from_expr(value, referenced, interner);
         }
-        Node::AttrAssign { object, value, .. } => {
+        Node::SubscriptOpAssign {
+            object, index, value, ..
+        } => {
+            collect_referenced_names_from_expr(object, referenced, interner);
+            collect_referenced_names_from_expr(index, referenced, interner);
+            collect_referenced_names_from_expr(value, referenced, interner);
+        }
+        Node::AttrAssign { object, value, .. } | Node::AttrOpAssign { object, value, .. } => {
             collect_referenced_names_from_expr(object, referenced, interner);
             collect_referenced_names_from_expr(value, referenced, interner);
         }
@@ -2563,7 +2866,9 @@ fn collect_referenced_names_from_expr(
             collect_referenced_names_from_expr(body, referenced, interner);
             collect_referenced_names_from_expr(orelse, referenced, interner);
         }
-        Expr::ListComp { elt, generators } | Expr::SetComp { elt, generators } => {
+        Expr::ListComp { elt, generators }
+        | Expr::GeneratorExp { elt, generators }
+        | Expr::SetComp { elt, generators } => {
             collect_referenced_names_from_comprehension(generators, Some(elt), None, referenced, interner);
         }
         Expr::DictComp { key, value, generators } => {
@@ -2606,9 +2911,9 @@ fn collect_referenced_names_from_expr(
                 }
             }
         }
-        Expr::Lambda { .. } => {
-            // Lambda should only exist after preparation; this function operates on raw expressions
-            unreachable!("Expr::Lambda should not exist during scope analysis")
+        Expr::Lambda { .. } | Expr::Generator { .. } => {
+            // These only exist after preparation; this function operates on raw expressions
+            unreachable!("prepared function expressions should not exist during scope analysis")
         }
         Expr::Named { value, .. } => {
             // Only the value is referenced; target is being assigned, not read
@@ -2625,7 +2930,7 @@ fn collect_referenced_names_from_expr(
                 collect_referenced_names_from_expr(expr, referenced, interner);
             }
         }
-        Expr::Await(value) => {
+        Expr::Await(value) | Expr::Yield(value) => {
             collect_referenced_names_from_expr(value, referenced, interner);
         }
     }
@@ -2641,6 +2946,22 @@ fn collect_referenced_names_from_comprehension(
     key_value: Option<(&ExprLoc, &ExprLoc)>,
     referenced: &mut AHashSet<String>,
     interner: &InternerBuilder,
+) {
+    // FIRST generator's iter expression truly references enclosing scope
+    // (evaluated before any loop variable is defined).
+    collect_referenced_names_from_expr(&generators[0].iter, referenced, interner);
+    collect_referenced_names_from_comprehension_body(generators, elt, key_value, referenced, interner);
+}
+
+/// Collects referenced names from a comprehension, except for its first iterable.
+///
+/// This is everything a generator expression's hidden function evaluates.
+fn collect_referenced_names_from_comprehension_body(
+    generators: &[Comprehension],
+    elt: Option<&ExprLoc>,
+    key_value: Option<(&ExprLoc, &ExprLoc)>,
+    referenced: &mut AHashSet<String>,
+    interner: &InternerBuilder,
 ) {
     // Track loop variable names (these are local to the comprehension)
     let mut comp_locals: AHashSet<String> = AHashSet::new();
@@ -2650,11 +2971,7 @@ fn collect_referenced_names_from_comprehension(
     let mut inner_refs: AHashSet<String> = AHashSet::new();
 
     for (i, comp) in generators.iter().enumerate() {
-        if i == 0 {
-            // FIRST generator's iter expression truly references enclosing scope
-            // (evaluated before any loop variable is defined).
-            collect_referenced_names_from_expr(&comp.iter, referenced, interner);
-        } else {
+        if i > 0 {
             // SUBSEQUENT generators' iter expressions can reference prior loop variables.
             // For example, in `[y for x in xs for y in x]`, the `x` in the second
             // generator's iter is the first generator's loop variable, not outer scope.
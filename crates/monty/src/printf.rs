@@ -0,0 +1,688 @@
+//! printf-style (`%`) string formatting.
+//!
+//! Implements `str % args` and `bytes % args`, CPython's "old-style" formatting. The template is scanned
+//! for conversion specifiers of the form `%[flags][width][.precision][length]type`,
+//! each of which consumes one value from the arguments (or two/three when `*` is used
+//! for the width or precision).
+//!
+//! Float bodies are rendered with the numeric helpers from [`crate::fstring`] so the
+//! two formatting paths agree on digits; sign handling and padding are applied here
+//! because printf flags (`-`, `0`, `+`, space, `#`) don't map one-to-one onto the
+//! format mini-language.
+//!
+//! With a dict right operand, a specifier may name a key instead (`%(name)s`), and the
+//! value is looked up in the dict. Other mappings aren't supported yet and raise
+//! `TypeError` like non-mappings do.
+//!
+//! A bytes template is decoded as Latin-1 so both kinds share one scanner: every byte
+//! becomes the char with the same code, conversions only ever produce chars below 256,
+//! and the result is encoded back byte for byte. Only `%s`/`%b`, `%r`/`%a` and `%c`
+//! differ, taking bytes-like arguments and producing ASCII reprs as in CPython.
+
+use std::{borrow::Cow, iter::Peekable, str::Chars};
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, Signed, ToPrimitive};
+
+use crate::{
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    fstring::{ParsedFormatSpec, ascii_escape, format_char, format_float_e, format_float_f, format_float_g},
+    heap::{Heap, HeapData},
+    intern::Interns,
+    resource::{DepthGuard, ResourceTracker, check_repeat_size},
+    types::{Dict, PyTrait, Type, bytearray::bytes_like},
+    value::Value,
+};
+
+/// Formats `template % args` if `template` is a string.
+///
+/// Returns `Ok(None)` when `template` is not a `str`, so the caller can fall back to
+/// raising the usual "unsupported operand type(s) for %" error.
+///
+/// A tuple right operand supplies one value per specifier; any other value is treated
+/// as a single argument. As in CPython, leftover arguments are an error unless the
+/// right operand is a non-tuple, non-str object supporting `__getitem__` (e.g.
+/// `'abc' % []` is `'abc'`).
+///
+/// `%(key)` specifiers look up their value in a dict right operand; missing keys raise
+/// `KeyError`.
+pub(crate) fn percent_format(
+    template: &Value,
+    args: &Value,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Option<String>> {
+    let template = match template {
+        Value::InternString(id) => interns.get_str(*id),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Str(s) => s.as_str(),
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    format_template(template, TemplateKind::Str, args, heap, interns).map(Some)
+}
+
+/// Formats `template % args` if `template` is `bytes`.
+///
+/// Returns `Ok(None)` when `template` is not `bytes`. Behaves like [`percent_format`],
+/// except that `%(key)` looks up a bytes key, and a bytes right operand counts as a
+/// single argument rather than a mapping.
+pub(crate) fn bytes_percent_format(
+    template: &Value,
+    args: &Value,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Option<Vec<u8>>> {
+    let template = match template {
+        Value::InternBytes(id) => interns.get_bytes(*id),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Bytes(b) => b.as_slice(),
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    let template: String = template.iter().copied().map(char::from).collect();
+    let formatted = format_template(&template, TemplateKind::Bytes, args, heap, interns)?;
+    let bytes = formatted
+        .chars()
+        .map(|c| u8::try_from(c).expect("bytes conversions only produce Latin-1 chars"))
+        .collect();
+    Ok(Some(bytes))
+}
+
+/// Whether a template is a `str` or a `bytes` decoded as Latin-1.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TemplateKind {
+    Str,
+    Bytes,
+}
+
+/// Formats `template % args`, the scanner shared by `str` and `bytes` templates.
+fn format_template(
+    template: &str,
+    kind: TemplateKind,
+    args: &Value,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<String> {
+    let (items, is_tuple) = match args {
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Tuple(tuple) => (tuple.as_slice(), true),
+            HeapData::NamedTuple(nt) => (nt.as_vec().as_slice(), true),
+            _ => (std::slice::from_ref(args), false),
+        },
+        _ => (std::slice::from_ref(args), false),
+    };
+    let mapping = match args {
+        Value::Ref(id) if !is_tuple => match heap.get(*id) {
+            HeapData::Dict(dict) => Some(dict),
+            _ => None,
+        },
+        _ => None,
+    };
+    let mut args_iter = FormatArgs {
+        items,
+        next: 0,
+        mapping,
+        keyed: None,
+        kind,
+    };
+
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+    // Character index of the next char to be read, used in "unsupported format character" errors
+    let mut index = 0;
+
+    while let Some(c) = chars.next() {
+        index += 1;
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+        let spec = parse_spec(&mut chars, &mut index, &mut args_iter, heap, interns)?;
+        if spec.type_char == '%' {
+            result.push('%');
+            continue;
+        }
+        // Both the width and the precision control how many characters are generated, so check
+        // the result so far plus the (possibly huge) field against the resource limits first
+        let field_len = spec.width.max(spec.precision.unwrap_or(0));
+        check_repeat_size(1, result.len().saturating_add(field_len), heap.tracker())?;
+        let arg = args_iter.next_arg()?;
+        match kind {
+            TemplateKind::Str => format_arg(arg, &spec, &mut result, heap, interns)?,
+            TemplateKind::Bytes => format_bytes_arg(arg, &spec, &mut result, heap, interns)?,
+        }
+        // A field's length can also come from its argument, like a long string for `%s`
+        check_repeat_size(1, result.len(), heap.tracker())?;
+    }
+
+    if args_iter.next < items.len() {
+        let mapping_like = !is_tuple
+            && match kind {
+                TemplateKind::Str => matches!(args.py_type(heap), Type::Dict | Type::List | Type::Bytes | Type::Range),
+                TemplateKind::Bytes => matches!(args.py_type(heap), Type::Dict | Type::List | Type::Range),
+            };
+        if !mapping_like {
+            return Err(match kind {
+                TemplateKind::Str => ExcType::type_error_printf_not_all_converted(),
+                TemplateKind::Bytes => ExcType::type_error_bytes_printf_not_all_converted(),
+            });
+        }
+    }
+    Ok(result)
+}
+
+/// Cursor over the values consumed by conversion specifiers.
+struct FormatArgs<'a> {
+    items: &'a [Value],
+    next: usize,
+    /// The dict that `%(key)` specifiers look up, if the right operand is one.
+    mapping: Option<&'a Dict>,
+    /// The value found for the current specifier's key, consumed by the next `next_arg`.
+    keyed: Option<&'a Value>,
+    /// Whether keys are looked up as `str` or `bytes`.
+    kind: TemplateKind,
+}
+
+impl<'a> FormatArgs<'a> {
+    /// Returns the next unconsumed argument, or the CPython arity error if there is none.
+    fn next_arg(&mut self) -> RunResult<&'a Value> {
+        if let Some(value) = self.keyed.take() {
+            return Ok(value);
+        }
+        let item = self
+            .items
+            .get(self.next)
+            .ok_or_else(ExcType::type_error_printf_not_enough_args)?;
+        self.next += 1;
+        Ok(item)
+    }
+
+    /// Looks up `key` in the mapping, making its value the next argument.
+    ///
+    /// As in CPython, this uses up the positional arguments, so mixing keyed and unkeyed
+    /// specifiers after the first key raises "not enough arguments".
+    fn use_key(&mut self, key: &str, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<()> {
+        let Some(mapping) = self.mapping else {
+            return Err(ExcType::type_error("format requires a mapping"));
+        };
+        let value = match self.kind {
+            TemplateKind::Str => mapping.get_by_str(key, heap, interns),
+            TemplateKind::Bytes => {
+                let key_bytes: Vec<u8> = key
+                    .chars()
+                    .map(|c| u8::try_from(c).expect("bytes templates only contain Latin-1 chars"))
+                    .collect();
+                mapping.get_by_bytes(&key_bytes, heap, interns)
+            }
+        };
+        let Some(value) = value else {
+            return Err(SimpleException::new_msg(ExcType::KeyError, key).into());
+        };
+        self.keyed = Some(value);
+        self.next = self.items.len();
+        Ok(())
+    }
+}
+
+/// A parsed `%` conversion specifier.
+struct PrintfSpec {
+    /// `-` flag: left-justify within the field (overrides `0`).
+    left_justify: bool,
+    /// `0` flag: pad numbers with zeros between the sign/prefix and the digits.
+    zero_pad: bool,
+    /// `+` or ` ` flag: sign shown for non-negative numbers.
+    sign: Option<char>,
+    /// `#` flag: alternate form (`0o`/`0x`/`0X` prefixes for integers).
+    alternate: bool,
+    /// Minimum field width.
+    width: usize,
+    /// Precision: minimum digits for integers, decimals for floats, max chars for strings.
+    precision: Option<usize>,
+    /// Conversion type character, e.g. `d`, `s`, `f`, or `%` for a literal percent.
+    type_char: char,
+}
+
+/// Parses a conversion specifier after its leading `%`.
+///
+/// `*` widths and precisions are taken from `args` as they are encountered, matching
+/// CPython's left-to-right consumption order. A negative `*` width means left-justify.
+/// A `(key)` is looked up straight away, before the rest of the specifier is parsed.
+fn parse_spec(
+    chars: &mut Peekable<Chars<'_>>,
+    index: &mut usize,
+    args: &mut FormatArgs<'_>,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<PrintfSpec> {
+    let mut spec = PrintfSpec {
+        left_justify: false,
+        zero_pad: false,
+        sign: None,
+        alternate: false,
+        width: 0,
+        precision: None,
+        type_char: '%',
+    };
+
+    let keyed = chars.peek() == Some(&'(');
+    if keyed {
+        chars.next();
+        *index += 1;
+        let key = parse_key(chars, index)?;
+        args.use_key(&key, heap, interns)?;
+    }
+
+    // Flags
+    while let Some(&c) = chars.peek() {
+        match c {
+            '-' => spec.left_justify = true,
+            '0' => spec.zero_pad = true,
+            '+' => spec.sign = Some('+'),
+            ' ' => {
+                if spec.sign.is_none() {
+                    spec.sign = Some(' ');
+                }
+            }
+            '#' => spec.alternate = true,
+            _ => break,
+        }
+        chars.next();
+        *index += 1;
+    }
+
+    // Width
+    if chars.peek() == Some(&'*') {
+        chars.next();
+        *index += 1;
+        let width = star_arg(args.next_arg()?)?;
+        if width < 0 {
+            spec.left_justify = true;
+        }
+        spec.width = usize::try_from(width.unsigned_abs()).unwrap_or(usize::MAX);
+    } else {
+        spec.width = parse_digits(chars, index);
+    }
+
+    // Precision
+    if chars.peek() == Some(&'.') {
+        chars.next();
+        *index += 1;
+        if chars.peek() == Some(&'*') {
+            chars.next();
+            *index += 1;
+            let precision = star_arg(args.next_arg()?)?;
+            spec.precision = Some(usize::try_from(precision).unwrap_or(0));
+        } else {
+            spec.precision = Some(parse_digits(chars, index));
+        }
+    }
+
+    // Length modifiers are accepted and ignored, as in CPython
+    while matches!(chars.peek(), Some('h' | 'l' | 'L')) {
+        chars.next();
+        *index += 1;
+    }
+
+    let Some(type_char) = chars.next() else {
+        return Err(SimpleException::new_msg(ExcType::ValueError, "incomplete format").into());
+    };
+    *index += 1;
+    // `%%` can't take a key
+    let literal_percent = type_char == '%' && !keyed;
+    if !literal_percent
+        && !matches!(
+            type_char,
+            's' | 'r' | 'a' | 'c' | 'd' | 'i' | 'u' | 'o' | 'x' | 'X' | 'e' | 'E' | 'f' | 'F' | 'g' | 'G'
+        )
+        && !(type_char == 'b' && args.kind == TemplateKind::Bytes)
+    {
+        let code = u32::from(type_char);
+        return Err(SimpleException::new_msg(
+            ExcType::ValueError,
+            format!(
+                "unsupported format character '{type_char}' (0x{code:x}) at index {}",
+                *index - 1
+            ),
+        )
+        .into());
+    }
+    spec.type_char = type_char;
+    Ok(spec)
+}
+
+/// Parses a mapping key after its opening `(`, up to the matching `)`.
+///
+/// Parentheses nest, so `%(a(b))s` uses the key `a(b)`.
+fn parse_key(chars: &mut Peekable<Chars<'_>>, index: &mut usize) -> RunResult<String> {
+    let mut key = String::new();
+    let mut depth = 1usize;
+    loop {
+        let Some(c) = chars.next() else {
+            return Err(SimpleException::new_msg(ExcType::ValueError, "incomplete format key").into());
+        };
+        *index += 1;
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(key);
+                }
+            }
+            _ => {}
+        }
+        key.push(c);
+    }
+}
+
+/// Parses a run of decimal digits, saturating on overflow (the resource check rejects it later).
+fn parse_digits(chars: &mut Peekable<Chars<'_>>, index: &mut usize) -> usize {
+    let mut n: usize = 0;
+    while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+        n = n.saturating_mul(10).saturating_add(d as usize);
+        chars.next();
+        *index += 1;
+    }
+    n
+}
+
+/// Extracts the integer supplied for a `*` width or precision.
+fn star_arg(value: &Value) -> RunResult<i64> {
+    match value {
+        Value::Int(n) => Ok(*n),
+        Value::Bool(b) => Ok(i64::from(*b)),
+        _ => Err(ExcType::type_error("* wants int")),
+    }
+}
+
+/// Formats a single argument according to `spec` and appends it to `out`.
+fn format_arg(
+    arg: &Value,
+    spec: &PrintfSpec,
+    out: &mut String,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<()> {
+    match spec.type_char {
+        's' | 'r' | 'a' => {
+            let mut guard = DepthGuard::default();
+            let text = match spec.type_char {
+                's' => arg.py_str(heap, &mut guard, interns),
+                'r' => arg.py_repr(heap, &mut guard, interns),
+                _ => Cow::Owned(ascii_escape(&arg.py_repr(heap, &mut guard, interns))),
+            };
+            push_text(out, &text, spec);
+        }
+        'c' => {
+            let text = char_arg(arg, heap, interns)?;
+            push_text(out, &text, spec);
+        }
+        'd' | 'i' | 'u' => {
+            let n = real_to_int(arg, spec.type_char, heap)?;
+            push_int(out, &n, 10, spec);
+        }
+        'o' | 'x' | 'X' => {
+            let Some(n) = int_value(arg, heap) else {
+                let type_char = spec.type_char;
+                let arg_type = arg.py_type(heap);
+                return Err(ExcType::type_error(format!(
+                    "%{type_char} format: an integer is required, not {arg_type}"
+                )));
+            };
+            let radix = if spec.type_char == 'o' { 8 } else { 16 };
+            push_int(out, &n, radix, spec);
+        }
+        _ => {
+            let f = float_value(arg, heap)?;
+            push_float(out, f, spec);
+        }
+    }
+    Ok(())
+}
+
+/// Formats a single argument of a bytes template according to `spec` and appends it to `out`.
+///
+/// `%s` and `%b` take a bytes-like argument, `%r` and `%a` both give the ASCII repr, and
+/// `%c` takes an int in `range(256)` or a single byte. Numeric conversions are the same
+/// as for `str`.
+fn format_bytes_arg(
+    arg: &Value,
+    spec: &PrintfSpec,
+    out: &mut String,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<()> {
+    match spec.type_char {
+        's' | 'b' => {
+            let Some(bytes) = bytes_like(arg, heap, interns) else {
+                let arg_type = arg.py_type(heap);
+                return Err(ExcType::type_error(format!(
+                    "%b requires a bytes-like object, or an object that implements __bytes__, not '{arg_type}'"
+                )));
+            };
+            let text: String = bytes.iter().copied().map(char::from).collect();
+            push_text(out, &text, spec);
+        }
+        'r' | 'a' => {
+            let mut guard = DepthGuard::default();
+            let text = ascii_escape(&arg.py_repr(heap, &mut guard, interns));
+            push_text(out, &text, spec);
+        }
+        'c' => {
+            let byte = match arg {
+                Value::Int(n) => Some(u8::try_from(*n).map_err(|_| byte_char_range_error())?),
+                Value::Bool(b) => Some(u8::from(*b)),
+                _ if arg.py_type(heap) == Type::Int => return Err(byte_char_range_error()),
+                _ => bytes_like(arg, heap, interns).and_then(|bytes| match bytes {
+                    [byte] => Some(*byte),
+                    _ => None,
+                }),
+            };
+            let Some(byte) = byte else {
+                return Err(ExcType::type_error(
+                    "%c requires an integer in range(256) or a single byte",
+                ));
+            };
+            push_text(out, &char::from(byte).to_string(), spec);
+        }
+        _ => format_arg(arg, spec, out, heap, interns)?,
+    }
+    Ok(())
+}
+
+/// The `OverflowError` for a `%c` int outside `range(256)` in a bytes template.
+fn byte_char_range_error() -> RunError {
+    SimpleException::new_msg(ExcType::OverflowError, "%c arg not in range(256)").into()
+}
+
+/// Appends a text conversion (`%s`, `%r`, `%a`, `%c`), truncated to the precision and padded
+/// with spaces. The `0` flag has no effect on text.
+fn push_text(out: &mut String, text: &str, spec: &PrintfSpec) {
+    let text: Cow<'_, str> = match spec.precision {
+        Some(precision) => Cow::Owned(text.chars().take(precision).collect()),
+        None => Cow::Borrowed(text),
+    };
+    let padding = spec.width.saturating_sub(text.chars().count());
+    if spec.left_justify {
+        out.push_str(&text);
+        out.extend(std::iter::repeat_n(' ', padding));
+    } else {
+        out.extend(std::iter::repeat_n(' ', padding));
+        out.push_str(&text);
+    }
+}
+
+/// Returns the character for `%c`: an int code point or a single-character string.
+fn char_arg(arg: &Value, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<String> {
+    match arg {
+        Value::Int(n) => Ok(format_char(*n, &ParsedFormatSpec::default())?),
+        Value::Bool(b) => Ok(format_char(i64::from(*b), &ParsedFormatSpec::default())?),
+        _ => {
+            let arg_type = arg.py_type(heap);
+            match arg_type {
+                Type::Int => {
+                    Err(SimpleException::new_msg(ExcType::OverflowError, "%c arg not in range(0x110000)").into())
+                }
+                Type::Str => {
+                    let mut guard = DepthGuard::default();
+                    let s = arg.py_str(heap, &mut guard, interns);
+                    let len = s.chars().count();
+                    if len == 1 {
+                        Ok(s.into_owned())
+                    } else {
+                        Err(ExcType::type_error(format!(
+                            "%c requires an int or a unicode character, not a string of length {len}"
+                        )))
+                    }
+                }
+                _ => Err(ExcType::type_error(format!(
+                    "%c requires an int or a unicode character, not {arg_type}"
+                ))),
+            }
+        }
+    }
+}
+
+/// Returns the value of an `int` (or `bool`) argument, or `None` for any other type.
+fn int_value(arg: &Value, heap: &Heap<impl ResourceTracker>) -> Option<BigInt> {
+    match arg {
+        Value::Int(n) => Some(BigInt::from(*n)),
+        Value::Bool(b) => Some(BigInt::from(i64::from(*b))),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::LongInt(li) => Some(li.inner().clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Converts a `%d` argument to an integer, truncating floats towards zero like `int()`.
+fn real_to_int(arg: &Value, type_char: char, heap: &Heap<impl ResourceTracker>) -> RunResult<BigInt> {
+    if let Some(n) = int_value(arg, heap) {
+        return Ok(n);
+    }
+    match arg {
+        Value::Float(f) if f.is_nan() => Err(ExcType::value_error_float_nan_to_int()),
+        Value::Float(f) if f.is_infinite() => Err(ExcType::overflow_error_float_inf_to_int()),
+        Value::Float(f) => Ok(BigInt::from_f64(f.trunc()).expect("finite float converts to BigInt")),
+        _ => {
+            let arg_type = arg.py_type(heap);
+            Err(ExcType::type_error(format!(
+                "%{type_char} format: a real number is required, not {arg_type}"
+            )))
+        }
+    }
+}
+
+/// Converts a float-conversion argument (`%f`, `%e`, `%g`, ...) to `f64`.
+fn float_value(arg: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<f64> {
+    if let Value::Float(f) = arg {
+        return Ok(*f);
+    }
+    match int_value(arg, heap) {
+        Some(n) => n.to_f64().ok_or_else(|| {
+            SimpleException::new_msg(ExcType::OverflowError, "int too large to convert to float").into()
+        }),
+        None => {
+            let arg_type = arg.py_type(heap);
+            Err(ExcType::type_error(format!("must be real number, not {arg_type}")))
+        }
+    }
+}
+
+/// Appends an integer conversion in the given radix.
+///
+/// The precision is a minimum number of digits, and `#` adds a `0o`/`0x`/`0X` prefix
+/// for octal and hex conversions.
+fn push_int(out: &mut String, n: &BigInt, radix: u32, spec: &PrintfSpec) {
+    let mut digits = n.abs().to_str_radix(radix);
+    if spec.type_char == 'X' {
+        digits.make_ascii_uppercase();
+    }
+    let min_digits = spec.precision.unwrap_or(0);
+    if min_digits > digits.len() {
+        digits.insert_str(0, &"0".repeat(min_digits - digits.len()));
+    }
+    let prefix = match (spec.alternate, spec.type_char) {
+        (true, 'o') => "0o",
+        (true, 'x') => "0x",
+        (true, 'X') => "0X",
+        _ => "",
+    };
+    push_number(out, sign_str(n.is_negative(), spec), prefix, &digits, spec);
+}
+
+/// Appends a float conversion, rendering the digits with the f-string helpers.
+///
+/// NaN and infinities are written as `nan`/`inf` (`NAN`/`INF` for uppercase types); like
+/// CPython, the `0` flag still zero-pads them.
+fn push_float(out: &mut String, f: f64, spec: &PrintfSpec) {
+    let uppercase = spec.type_char.is_ascii_uppercase();
+    let is_negative = f.is_sign_negative() && !f.is_nan();
+    if !f.is_finite() {
+        let body = match (f.is_nan(), uppercase) {
+            (true, false) => "nan",
+            (true, true) => "NAN",
+            (false, false) => "inf",
+            (false, true) => "INF",
+        };
+        push_number(out, sign_str(is_negative, spec), "", body, spec);
+        return;
+    }
+
+    let body_spec = ParsedFormatSpec {
+        fill: ' ',
+        precision: Some(spec.precision.unwrap_or(6)),
+        ..Default::default()
+    };
+    let abs = f.abs();
+    let body = match spec.type_char {
+        'e' | 'E' => format_float_e(abs, &body_spec, uppercase),
+        'g' | 'G' => {
+            let body = format_float_g(abs, &body_spec);
+            if uppercase { body.to_uppercase() } else { body }
+        }
+        _ => format_float_f(abs, &body_spec),
+    };
+    push_number(out, sign_str(is_negative, spec), "", &body, spec);
+}
+
+/// Returns the sign prefix for a number: `-` for negatives, otherwise the `+`/space flag.
+fn sign_str(is_negative: bool, spec: &PrintfSpec) -> &'static str {
+    if is_negative {
+        "-"
+    } else {
+        match spec.sign {
+            Some('+') => "+",
+            Some(' ') => " ",
+            _ => "",
+        }
+    }
+}
+
+/// Appends `sign + prefix + body` padded to the field width.
+///
+/// With the `0` flag, zeros go between the prefix and the body; otherwise spaces go on the left, or on the right when left-justified.
+fn push_number(out: &mut String, sign: &str, prefix: &str, body: &str, spec: &PrintfSpec) {
+    let len = sign.len() + prefix.len() + body.len();
+    let padding = spec.width.saturating_sub(len);
+    if spec.left_justify {
+        out.push_str(sign);
+        out.push_str(prefix);
+        out.push_str(body);
+        out.extend(std::iter::repeat_n(' ', padding));
+    } else if spec.zero_pad {
+        out.push_str(sign);
+        out.push_str(prefix);
+        out.extend(std::iter::repeat_n('0', padding));
+        out.push_str(body);
+    } else {
+        out.extend(std::iter::repeat_n(' ', padding));
+        out.push_str(sign);
+        out.push_str(prefix);
+        out.push_str(body);
+    }
+}
@@ -162,13 +162,15 @@ impl Default for DepthGuard {
 /// Error returned when a resource limit is exceeded during execution.
 ///
 /// This allows the sandbox to enforce strict limits on allocation count,
-/// execution time, and memory usage.
+/// execution time or steps, and memory usage.
 #[derive(Debug, Clone)]
 pub enum ResourceError {
     /// Maximum number of allocations exceeded.
     Allocation { limit: usize, count: usize },
     /// Maximum execution time exceeded.
     Time { limit: Duration, elapsed: Duration },
+    /// Maximum number of executed bytecode instructions exceeded.
+    Steps { limit: u64, steps: u64 },
     /// Maximum memory usage exceeded.
     Memory { limit: usize, used: usize },
     /// Maximum recursion depth exceeded.
@@ -186,6 +188,9 @@ impl fmt::Display for ResourceError {
             Self::Time { limit, elapsed } => {
                 write!(f, "time limit exceeded: {elapsed:?} > {limit:?}")
             }
+            Self::Steps { limit, steps } => {
+                write!(f, "step limit exceeded: {steps} > {limit}")
+            }
             Self::Memory { limit, used } => {
                 write!(f, "memory limit exceeded: {used} bytes > {limit} bytes")
             }
@@ -208,6 +213,7 @@ impl ResourceError {
     /// - `Allocation` → `MemoryError`
     /// - `Memory` → `MemoryError`
     /// - `Time` → `TimeoutError`
+    /// - `Steps` → `TimeoutError`
     /// - `Recursion` → `RecursionError`
     #[must_use]
     pub(crate) fn into_exception(self, frame: Option<RawStackFrame>) -> ExceptionRaise {
@@ -224,6 +230,10 @@ impl ResourceError {
                 ExcType::TimeoutError,
                 Some(format!("time limit exceeded: {elapsed:?} > {limit:?}")),
             ),
+            Self::Steps { limit, steps } => (
+                ExcType::TimeoutError,
+                Some(format!("step limit exceeded: {steps} > {limit}")),
+            ),
             Self::Recursion { .. } => (
                 ExcType::RecursionError,
                 Some("maximum recursion depth exceeded".to_string()),
@@ -249,9 +259,10 @@ impl From<ResourceError> for RunError {
 /// Implementations can enforce limits on allocations, time, and memory,
 /// as well as schedule periodic garbage collection.
 ///
-/// All implementations should eventually trigger garbage collection to handle
-/// reference cycles. The `should_gc` method controls *frequency*, not whether
-/// GC runs at all.
+/// Implementations should eventually trigger garbage collection to handle
+/// reference cycles. The `should_gc` method controls *frequency*; only a host
+/// that explicitly opts out (see `ResourceLimits::collect_cycles`) should never
+/// collect them.
 pub trait ResourceTracker: fmt::Debug {
     /// Called before each heap allocation.
     ///
@@ -268,12 +279,33 @@ pub trait ResourceTracker: fmt::Debug {
     /// * `size` - Size in bytes of the freed allocation
     fn on_free(&mut self, get_size: impl FnOnce() -> usize);
 
+    /// Called after a heap object grows in place, e.g. a list appended to.
+    ///
+    /// Unlike `on_allocate`, the memory is already in use, so it should be counted even
+    /// when the limit is exceeded. Growth doesn't count as an allocation.
+    ///
+    /// Returns `Ok(())` if memory use is still within the limit, or
+    /// `Err(ResourceError::Memory)` if the growth took it over.
+    ///
+    /// # Arguments
+    /// * `size` - Number of bytes the object grew by
+    fn on_grow(&mut self, get_size: impl FnOnce() -> usize) -> Result<(), ResourceError>;
+
     /// Called periodically (at statement boundaries) to check time limits.
     ///
     /// Returns `Ok(())` if within time limit, or `Err(ResourceError::Time)`
     /// if the limit is exceeded.
     fn check_time(&mut self) -> Result<(), ResourceError>;
 
+    /// Called before executing each bytecode instruction to check the step limit.
+    ///
+    /// Unlike the time limit, the number of steps a program takes is the same on every
+    /// run, so this gives deterministic limits for tests and snapshots.
+    ///
+    /// Returns `Ok(())` if within the step limit, or `Err(ResourceError::Steps)`
+    /// if the limit is exceeded.
+    fn on_step(&mut self) -> Result<(), ResourceError>;
+
     /// Called before pushing a new call frame to check recursion depth.
     ///
     /// Returns `Ok(())` if within recursion limit, or `Err(ResourceError::Recursion)`
@@ -295,6 +327,16 @@ pub trait ResourceTracker: fmt::Debug {
     ///
     /// Returns `Ok(())` to allow the operation, or `Err(ResourceError)` to reject.
     fn check_large_result(&self, estimated_bytes: usize) -> Result<(), ResourceError>;
+
+    /// Called between instructions while the heap may contain reference cycles.
+    ///
+    /// Returns `true` to run a mark-sweep collection now, which frees every heap
+    /// object that isn't reachable from the namespaces, the operand stack or the
+    /// exception stack.
+    ///
+    /// # Arguments
+    /// * `allocations_since_gc` - GC-tracked allocations since the last collection
+    fn should_gc(&self, allocations_since_gc: u32) -> bool;
 }
 
 /// A resource tracker that imposes no limits except default recursion limit.
@@ -312,11 +354,21 @@ impl ResourceTracker for NoLimitTracker {
     #[inline]
     fn on_free(&mut self, _: impl FnOnce() -> usize) {}
 
+    #[inline]
+    fn on_grow(&mut self, _: impl FnOnce() -> usize) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
     #[inline]
     fn check_time(&mut self) -> Result<(), ResourceError> {
         Ok(())
     }
 
+    #[inline]
+    fn on_step(&mut self) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
     /// Set the recursion limit to 1000.
     ///
     /// The high limit here may cause stack overflow errors in debug mode, but do not those errors should
@@ -339,6 +391,11 @@ impl ResourceTracker for NoLimitTracker {
         // No limit - always allow operations regardless of result size
         Ok(())
     }
+
+    #[inline]
+    fn should_gc(&self, allocations_since_gc: u32) -> bool {
+        allocations_since_gc >= DEFAULT_GC_INTERVAL
+    }
 }
 
 /// Configuration for resource limits.
@@ -352,10 +409,21 @@ pub struct ResourceLimits {
     pub max_allocations: Option<usize>,
     /// Maximum execution time.
     pub max_duration: Option<Duration>,
+    /// Maximum number of bytecode instructions to execute.
+    ///
+    /// A deterministic alternative to `max_duration`: the same program always
+    /// stops at the same point, whatever machine it runs on.
+    pub max_steps: Option<u64>,
     /// Maximum heap memory in bytes (approximate).
     pub max_memory: Option<usize>,
     /// Run garbage collection every N allocations.
+    ///
+    /// Defaults to `DEFAULT_GC_INTERVAL` when `None`.
     pub gc_interval: Option<usize>,
+    /// Whether garbage collection reclaims reference cycles.
+    ///
+    /// Enabled when `None`. Without it, cycles are only freed when execution ends.
+    pub collect_cycles: Option<bool>,
     /// Maximum recursion depth (function call stack depth).
     pub max_recursion_depth: Option<usize>,
 }
@@ -363,6 +431,12 @@ pub struct ResourceLimits {
 /// Recommended maximum recursion depth if not otherwise specified.
 pub const DEFAULT_MAX_RECURSION_DEPTH: usize = 1000;
 
+/// GC interval if not otherwise specified - run GC every 100,000 GC-tracked allocations.
+///
+/// This is intentionally infrequent to minimize overhead while still
+/// eventually collecting reference cycles.
+pub const DEFAULT_GC_INTERVAL: u32 = 100_000;
+
 impl ResourceLimits {
     /// Creates a new ResourceLimits with all limits disabled, except max recursion which is set to 1000.
     #[must_use]
@@ -387,6 +461,16 @@ impl ResourceLimits {
         self
     }
 
+    /// Sets the maximum number of bytecode instructions to execute.
+    ///
+    /// Use this instead of `max_duration` when execution must be reproducible,
+    /// e.g. in CI or snapshot tests.
+    #[must_use]
+    pub fn max_steps(mut self, limit: u64) -> Self {
+        self.max_steps = Some(limit);
+        self
+    }
+
     /// Sets the maximum memory usage in bytes.
     #[must_use]
     pub fn max_memory(mut self, limit: usize) -> Self {
@@ -401,6 +485,17 @@ impl ResourceLimits {
         self
     }
 
+    /// Sets whether garbage collection reclaims reference cycles.
+    ///
+    /// Reference counting frees everything else as soon as it's unreachable, so
+    /// disabling this only trades memory held by cycles for skipping the
+    /// mark-sweep passes.
+    #[must_use]
+    pub fn collect_cycles(mut self, enabled: bool) -> Self {
+        self.collect_cycles = Some(enabled);
+        self
+    }
+
     /// Sets the maximum recursion depth (function call stack depth).
     #[must_use]
     pub fn max_recursion_depth(mut self, limit: Option<usize>) -> Self {
@@ -411,9 +506,9 @@ impl ResourceLimits {
 
 /// A resource tracker that enforces configurable limits.
 ///
-/// Tracks allocation count, memory usage, and execution time, returning
+/// Tracks allocation count, memory usage, execution time and steps, returning
 /// errors when limits are exceeded. Also schedules garbage collection
-/// at configurable intervals.
+/// at configurable intervals, unless cycle collection is disabled.
 ///
 /// When serialized/deserialized, the `start_time` is reset to `Instant::now()`.
 /// This means time limits restart from zero after deserialization.
@@ -428,6 +523,8 @@ pub struct LimitedTracker {
     allocation_count: usize,
     /// Current approximate memory usage in bytes.
     current_memory: usize,
+    /// Number of bytecode instructions executed.
+    step_count: u64,
 }
 
 impl LimitedTracker {
@@ -442,6 +539,7 @@ impl LimitedTracker {
             start_time: Instant::now(),
             allocation_count: 0,
             current_memory: 0,
+            step_count: 0,
         }
     }
 
@@ -457,6 +555,12 @@ impl LimitedTracker {
         self.current_memory
     }
 
+    /// Returns the number of bytecode instructions executed.
+    #[must_use]
+    pub fn step_count(&self) -> u64 {
+        self.step_count
+    }
+
     /// Returns the elapsed time since tracker creation.
     #[must_use]
     pub fn elapsed(&self) -> Duration {
@@ -499,6 +603,17 @@ impl ResourceTracker for LimitedTracker {
         self.current_memory = self.current_memory.saturating_sub(get_size());
     }
 
+    fn on_grow(&mut self, get_size: impl FnOnce() -> usize) -> Result<(), ResourceError> {
+        self.current_memory += get_size();
+        match self.limits.max_memory {
+            Some(max) if self.current_memory > max => Err(ResourceError::Memory {
+                limit: max,
+                used: self.current_memory,
+            }),
+            _ => Ok(()),
+        }
+    }
+
     fn check_time(&mut self) -> Result<(), ResourceError> {
         if let Some(max) = self.limits.max_duration {
             let elapsed = self.start_time.elapsed();
@@ -509,6 +624,19 @@ impl ResourceTracker for LimitedTracker {
         Ok(())
     }
 
+    fn on_step(&mut self) -> Result<(), ResourceError> {
+        self.step_count += 1;
+        if let Some(max) = self.limits.max_steps
+            && self.step_count > max
+        {
+            return Err(ResourceError::Steps {
+                limit: max,
+                steps: self.step_count,
+            });
+        }
+        Ok(())
+    }
+
     fn check_recursion_depth(&self, current_depth: usize) -> Result<(), ResourceError> {
         if let Some(max) = self.limits.max_recursion_depth {
             // current_depth is before push, so new depth would be current_depth + 1
@@ -535,4 +663,14 @@ impl ResourceTracker for LimitedTracker {
         }
         Ok(())
     }
+
+    fn should_gc(&self, allocations_since_gc: u32) -> bool {
+        if self.limits.collect_cycles == Some(false) {
+            return false;
+        }
+        match self.limits.gc_interval {
+            Some(interval) => usize::try_from(allocations_since_gc).is_ok_and(|count| count >= interval),
+            None => allocations_since_gc >= DEFAULT_GC_INTERVAL,
+        }
+    }
 }
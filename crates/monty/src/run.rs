@@ -1,12 +1,19 @@
 //! Public interface for running Monty code.
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{
+    fmt::Write,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 
 use crate::{
     ExcType, MontyException,
+    args::{ArgValues, KwargsValues},
     asyncio::CallId,
-    bytecode::{Code, Compiler, FrameExit, VM, VMSnapshot},
-    exception_private::RunResult,
-    heap::Heap,
+    bytecode::{Code, Compiler, FrameExit, VM, VMSnapshot, disassemble_code},
+    exception_private::{RunResult, SimpleException},
+    heap::{Heap, HeapData, HeapStats},
     intern::{ExtFunctionId, Interns},
     io::{PrintWriter, StdPrint},
     namespace::Namespaces,
@@ -15,6 +22,7 @@ use crate::{
     parse::parse,
     prepare::prepare,
     resource::{NoLimitTracker, ResourceTracker},
+    types::{Dict, PyTrait},
     value::Value,
 };
 
@@ -25,6 +33,10 @@ use crate::{
 /// - **Iterative execution**: Use `start()` to start execution which will pause at external function calls and
 ///   can be resumed later
 ///
+/// Code is parsed and compiled once, in `new()`. The compiled program is immutable and shared behind an `Arc`,
+/// while every run creates its own heap and namespaces, so one runner can be run any number of times with
+/// different inputs, and cloning it is cheap.
+///
 /// # Example
 /// ```
 /// use monty::{MontyRun, MontyObject};
@@ -35,8 +47,8 @@ use crate::{
 /// ```
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MontyRun {
-    /// The underlying executor containing parsed AST and interns.
-    executor: Executor,
+    /// The compiled program, shared by clones and by every run started from this runner.
+    executor: Arc<Executor>,
 }
 
 impl MontyRun {
@@ -58,7 +70,39 @@ impl MontyRun {
         input_names: Vec<String>,
         external_functions: Vec<String>,
     ) -> Result<Self, MontyException> {
-        Executor::new(code, script_name, input_names, external_functions).map(|executor| Self { executor })
+        Self::new_with_options(
+            code,
+            script_name,
+            input_names,
+            external_functions,
+            CompileOptions::default(),
+        )
+    }
+
+    /// Like [`MontyRun::new`], but with options controlling how the code is compiled.
+    ///
+    /// # Example
+    /// ```
+    /// use monty::{CompileOptions, MontyObject, MontyRun};
+    ///
+    /// let code = "assert False\n__debug__".to_owned();
+    /// let options = CompileOptions::new().optimize(true);
+    /// let runner = MontyRun::new_with_options(code, "test.py", vec![], vec![], options).unwrap();
+    /// assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Bool(false));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `MontyException` if the code cannot be parsed.
+    pub fn new_with_options(
+        code: String,
+        script_name: &str,
+        input_names: Vec<String>,
+        external_functions: Vec<String>,
+        options: CompileOptions,
+    ) -> Result<Self, MontyException> {
+        Executor::new(code, script_name, input_names, external_functions, options).map(|executor| Self {
+            executor: Arc::new(executor),
+        })
     }
 
     /// Returns the code that was parsed to create this snapshot.
@@ -67,6 +111,19 @@ impl MontyRun {
         &self.executor.code
     }
 
+    /// Returns a human-readable listing of the compiled bytecode, for debugging.
+    ///
+    /// The module code is listed first, followed by every function defined anywhere in the
+    /// code (including nested functions and lambdas) in definition order. Each instruction is
+    /// shown with its source line, bytecode offset, opcode and operands, with names, constants
+    /// and jump targets resolved where possible.
+    ///
+    /// The format is intended for people, not programs, and may change between releases.
+    #[must_use]
+    pub fn disassemble(&self) -> String {
+        self.executor.disassemble()
+    }
+
     /// Executes the code and returns both the result and reference count data, used for testing only.
     #[cfg(feature = "ref-count-return")]
     pub fn run_ref_counts(&self, inputs: Vec<MontyObject>) -> Result<RefCountOutput, MontyException> {
@@ -118,11 +175,12 @@ impl MontyRun {
         postcard::from_bytes(bytes)
     }
 
-    /// Starts execution with the given inputs and resource tracker, consuming self.
+    /// Starts execution with the given inputs and resource tracker.
     ///
-    /// Creates the heap and namespaces, then begins execution.
+    /// Creates the heap and namespaces, then begins execution. The runner is left untouched,
+    /// so it can start any number of further runs.
     ///
-    /// For iterative execution, `start()` consumes self and returns a `RunProgress`:
+    /// For iterative execution, `start()` returns a `RunProgress`:
     /// - `RunProgress::FunctionCall { ..., state }` - external function call, call `state.run(return_value)` to resume
     /// - `RunProgress::Complete(value)` - execution finished
     ///
@@ -144,12 +202,12 @@ impl MontyRun {
     /// This method should not panic under normal operation. Internal assertions
     /// may panic if the VM reaches an inconsistent state (indicating a bug).
     pub fn start<T: ResourceTracker>(
-        self,
+        &self,
         inputs: Vec<MontyObject>,
         resource_tracker: T,
         print: &mut impl PrintWriter,
     ) -> Result<RunProgress<T>, MontyException> {
-        let executor = self.executor;
+        let executor = Arc::clone(&self.executor);
 
         // Create heap and prepare namespaces
         let mut heap = Heap::new(executor.namespace_size, resource_tracker);
@@ -166,6 +224,218 @@ impl MontyRun {
         // Handle the result using the destructured parts
         handle_vm_result(vm_result, vm_state, executor, heap, namespaces)
     }
+
+    /// Runs the code to completion and returns the function it evaluates to, so the host can call it.
+    ///
+    /// The last expression of the code must be callable, typically the name of a function defined
+    /// with `def` or a `lambda`. The returned [`MontyFunction`] keeps the heap and global namespace
+    /// alive, so each call sees the module's globals, including changes made by earlier calls.
+    ///
+    /// Like `run()`, this does not support external functions or OS calls, either while running
+    /// the module or during later calls.
+    ///
+    /// # Example
+    /// ```
+    /// use monty::{MontyObject, MontyRun, NoLimitTracker, StdPrint};
+    ///
+    /// let code = "def double(x):\n    return x * 2\ndouble";
+    /// let runner = MontyRun::new(code.to_owned(), "plugin.py", vec![], vec![]).unwrap();
+    /// let mut double = runner.run_to_function(vec![], NoLimitTracker, &mut StdPrint).unwrap();
+    /// let result = double.call(vec![MontyObject::Int(21)], vec![], &mut StdPrint).unwrap();
+    /// assert_eq!(result, MontyObject::Int(42));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `MontyException` if the inputs are invalid, the code raises an exception,
+    /// or the value it evaluates to is not callable.
+    pub fn run_to_function<T: ResourceTracker>(
+        &self,
+        inputs: Vec<MontyObject>,
+        resource_tracker: T,
+        print: &mut impl PrintWriter,
+    ) -> Result<MontyFunction<T>, MontyException> {
+        let executor = Arc::clone(&self.executor);
+
+        let mut heap = Heap::new(executor.namespace_size, resource_tracker);
+        let mut namespaces = executor.prepare_namespaces(inputs, &mut heap)?;
+
+        let mut vm = VM::new(&mut heap, &mut namespaces, &executor.interns, print);
+        let vm_result = vm.run_module(&executor.module_code);
+        vm.cleanup();
+
+        let function = match vm_result {
+            Ok(FrameExit::Return(value)) => value,
+            other => {
+                let err = match frame_exit_to_object(other, &mut heap, &executor.interns) {
+                    Err(err) => err,
+                    Ok(_) => unreachable!("only a return produces a value"),
+                };
+                #[cfg(feature = "ref-count-panic")]
+                namespaces.drop_global_with_heap(&mut heap);
+                return Err(err.into_python_exception(&executor.interns, &executor.code));
+            }
+        };
+
+        if !is_callable(&function, &heap) {
+            let message = format!("'{}' object is not callable", function.py_type(&heap));
+            function.drop_with_heap(&mut heap);
+            #[cfg(feature = "ref-count-panic")]
+            namespaces.drop_global_with_heap(&mut heap);
+            return Err(MontyException::new(ExcType::TypeError, Some(message)));
+        }
+
+        Ok(MontyFunction {
+            executor,
+            heap,
+            namespaces,
+            function,
+        })
+    }
+}
+
+/// A function defined by Monty code that the host can call, created by [`MontyRun::run_to_function`].
+///
+/// Owns the heap and namespaces the function was defined in, so closures, default arguments
+/// and module globals stay alive between calls. Each call runs on a fresh VM over that state.
+///
+/// # Type Parameters
+/// * `T` - Resource tracker implementation, shared by all calls
+#[derive(Debug)]
+pub struct MontyFunction<T: ResourceTracker> {
+    /// The executor containing compiled code and interns.
+    executor: Arc<Executor>,
+    /// The heap containing the function and everything it references.
+    heap: Heap<T>,
+    /// The namespaces containing the module's globals.
+    namespaces: Namespaces,
+    /// The callable returned by the module code.
+    function: Value,
+}
+
+impl<T: ResourceTracker> MontyFunction<T> {
+    /// Calls the function with the given arguments and returns its result.
+    ///
+    /// # Arguments
+    /// * `args` - Positional arguments
+    /// * `kwargs` - Keyword arguments as (name, value) pairs
+    /// * `print` - Writer for print output
+    ///
+    /// # Errors
+    /// Returns `MontyException` if an argument is invalid (e.g., `MontyObject::Repr`) or the
+    /// call raises an exception. The function can be called again after an error.
+    pub fn call(
+        &mut self,
+        args: Vec<MontyObject>,
+        kwargs: Vec<(MontyObject, MontyObject)>,
+        print: &mut impl PrintWriter,
+    ) -> Result<MontyObject, MontyException> {
+        let interns = &self.executor.interns;
+        let args = host_call_args(args, kwargs, &mut self.heap, interns)
+            .map_err(|e| e.into_python_exception(interns, &self.executor.code))?;
+
+        let mut vm = VM::new(&mut self.heap, &mut self.namespaces, interns, print);
+        let result = vm.call_from_host(&self.function, args);
+        vm.cleanup();
+
+        frame_exit_to_object(result, &mut self.heap, interns)
+            .map_err(|e| e.into_python_exception(interns, &self.executor.code))
+    }
+}
+
+impl<T: ResourceTracker> Drop for MontyFunction<T> {
+    fn drop(&mut self) {
+        std::mem::replace(&mut self.function, Value::None).drop_with_heap(&mut self.heap);
+        #[cfg(feature = "ref-count-panic")]
+        self.namespaces.drop_global_with_heap(&mut self.heap);
+    }
+}
+
+/// Returns whether `value` can be called by [`MontyFunction::call`].
+fn is_callable(value: &Value, heap: &Heap<impl ResourceTracker>) -> bool {
+    match value {
+        Value::Builtin(_) | Value::ModuleFunction(_) | Value::DefFunction(_) | Value::ExtFunction(_) => true,
+        Value::Ref(id) => matches!(
+            heap.get(*id),
+            HeapData::Closure(..) | HeapData::FunctionDefaults(..) | HeapData::Getter(_)
+        ),
+        _ => false,
+    }
+}
+
+/// Converts the host's arguments for [`MontyFunction::call`] to `ArgValues`.
+///
+/// On error, any values already converted are dropped.
+fn host_call_args(
+    args: Vec<MontyObject>,
+    kwargs: Vec<(MontyObject, MontyObject)>,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<ArgValues> {
+    let mut positional = Vec::with_capacity(args.len());
+    let mut keywords = Vec::with_capacity(kwargs.len());
+    let mut error = None;
+    for arg in args {
+        match arg.to_value(heap, interns) {
+            Ok(value) => positional.push(value),
+            Err(e) => {
+                error = Some(e);
+                break;
+            }
+        }
+    }
+    if error.is_none() {
+        for (key, value) in kwargs {
+            let key = match key.to_value(heap, interns) {
+                Ok(key) => key,
+                Err(e) => {
+                    error = Some(e);
+                    break;
+                }
+            };
+            match value.to_value(heap, interns) {
+                Ok(value) => keywords.push((key, value)),
+                Err(e) => {
+                    key.drop_with_heap(heap);
+                    error = Some(e);
+                    break;
+                }
+            }
+        }
+    }
+    if let Some(e) = error {
+        positional.drop_with_heap(heap);
+        for (key, value) in keywords {
+            key.drop_with_heap(heap);
+            value.drop_with_heap(heap);
+        }
+        return Err(SimpleException::new_msg(ExcType::RuntimeError, format!("invalid input type: {e}")).into());
+    }
+
+    let kwargs = if keywords.is_empty() {
+        KwargsValues::Empty
+    } else {
+        match Dict::from_pairs(keywords, heap, interns) {
+            Ok(dict) => KwargsValues::Dict(dict),
+            Err(err) => {
+                positional.drop_with_heap(heap);
+                return Err(err);
+            }
+        }
+    };
+    Ok(match (positional.len(), kwargs) {
+        (0, KwargsValues::Empty) => ArgValues::Empty,
+        (1, KwargsValues::Empty) => ArgValues::One(positional.pop().expect("length checked")),
+        (2, KwargsValues::Empty) => {
+            let second = positional.pop().expect("length checked");
+            let first = positional.pop().expect("length checked");
+            ArgValues::Two(first, second)
+        }
+        (0, kwargs) => ArgValues::Kwargs(kwargs),
+        (_, kwargs) => ArgValues::ArgsKargs {
+            args: positional,
+            kwargs,
+        },
+    })
 }
 
 /// Result of a single step of iterative execution.
@@ -316,7 +586,7 @@ impl<T: ResourceTracker + serde::de::DeserializeOwned> RunProgress<T> {
 #[serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::de::DeserializeOwned"))]
 pub struct Snapshot<T: ResourceTracker> {
     /// The executor containing compiled code and interns.
-    executor: Executor,
+    executor: Arc<Executor>,
     /// The VM state containing stack, frames, and exception state.
     vm_state: VMSnapshot,
     /// The heap containing all allocated objects.
@@ -437,6 +707,39 @@ impl<T: ResourceTracker> Snapshot<T> {
     pub fn run_pending(self, print: &mut impl PrintWriter) -> Result<RunProgress<T>, MontyException> {
         self.run(MontyFuture, print)
     }
+
+    /// Returns the heap usage of the paused execution.
+    #[must_use]
+    pub fn heap_stats(&self) -> HeapStats {
+        self.heap.stats()
+    }
+}
+
+impl<T: ResourceTracker + serde::Serialize> Snapshot<T> {
+    /// Serializes the paused execution to a binary format.
+    ///
+    /// The serialized data holds the whole runtime state (heap, namespaces, call frames
+    /// and resource tracker), so it can be stored and later resumed with `load()`,
+    /// possibly in another process.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn dump(&self) -> Result<Vec<u8>, postcard::Error> {
+        postcard::to_allocvec(self)
+    }
+}
+
+impl<T: ResourceTracker + serde::de::DeserializeOwned> Snapshot<T> {
+    /// Deserializes a paused execution from binary format.
+    ///
+    /// # Arguments
+    /// * `bytes` - The serialized state from `dump()`
+    ///
+    /// # Errors
+    /// Returns an error if deserialization fails.
+    pub fn load(bytes: &[u8]) -> Result<Self, postcard::Error> {
+        postcard::from_bytes(bytes)
+    }
 }
 
 /// Execution state paused while waiting for external future results.
@@ -453,7 +756,7 @@ impl<T: ResourceTracker> Snapshot<T> {
 #[serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::de::DeserializeOwned"))]
 pub struct FutureSnapshot<T: ResourceTracker> {
     /// The executor containing compiled code and interns.
-    executor: Executor,
+    executor: Arc<Executor>,
     /// The VM state containing stack, frames, and exception state.
     vm_state: VMSnapshot,
     /// The heap containing all allocated objects.
@@ -470,6 +773,12 @@ impl<T: ResourceTracker> FutureSnapshot<T> {
         &self.pending_call_ids
     }
 
+    /// Returns the heap usage of the paused execution.
+    #[must_use]
+    pub fn heap_stats(&self) -> HeapStats {
+        self.heap.stats()
+    }
+
     /// Resumes execution with results for some or all pending futures.
     ///
     /// **Incremental resolution**: You don't need to provide all results at once.
@@ -606,6 +915,33 @@ impl<T: ResourceTracker> FutureSnapshot<T> {
     }
 }
 
+impl<T: ResourceTracker + serde::Serialize> FutureSnapshot<T> {
+    /// Serializes the paused execution to a binary format.
+    ///
+    /// The serialized data holds the whole runtime state (heap, namespaces, call frames
+    /// and resource tracker), so it can be stored and later resumed with `load()`,
+    /// possibly in another process.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn dump(&self) -> Result<Vec<u8>, postcard::Error> {
+        postcard::to_allocvec(self)
+    }
+}
+
+impl<T: ResourceTracker + serde::de::DeserializeOwned> FutureSnapshot<T> {
+    /// Deserializes a paused execution from binary format.
+    ///
+    /// # Arguments
+    /// * `bytes` - The serialized state from `dump()`
+    ///
+    /// # Errors
+    /// Returns an error if deserialization fails.
+    pub fn load(bytes: &[u8]) -> Result<Self, postcard::Error> {
+        postcard::from_bytes(bytes)
+    }
+}
+
 /// Handles a FrameExit result and converts it to RunProgress for FutureSnapshot.
 ///
 /// This is a standalone function to avoid partial move issues when destructuring FutureSnapshot.
@@ -613,7 +949,7 @@ impl<T: ResourceTracker> FutureSnapshot<T> {
 fn handle_vm_result<T: ResourceTracker>(
     result: RunResult<FrameExit>,
     vm_state: Option<VMSnapshot>,
-    executor: Executor,
+    executor: Arc<Executor>,
     mut heap: Heap<T>,
     mut namespaces: Namespaces,
 ) -> Result<RunProgress<T>, MontyException> {
@@ -687,10 +1023,60 @@ fn handle_vm_result<T: ResourceTracker>(
     }
 }
 
+/// Options controlling how code is compiled, see [`MontyRun::new_with_options`].
+///
+/// Use `CompileOptions::default()` for the same behavior as [`MontyRun::new`], or build
+/// custom options with the builder pattern.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompileOptions {
+    /// Compile in optimized mode, like CPython's `-O` flag: `assert` statements are
+    /// removed and `__debug__` is `False`.
+    pub optimize: bool,
+    /// Compile for an interactive frontend, like CPython's `single` compile mode: the `repr()`
+    /// of every top-level expression statement that isn't `None` is written to stdout, and the
+    /// run always returns `None` instead of the value of the last expression.
+    pub repl: bool,
+}
+
+impl CompileOptions {
+    /// Creates options with the defaults, matching a plain `python` invocation.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to compile in optimized mode.
+    #[must_use]
+    pub fn optimize(mut self, optimize: bool) -> Self {
+        self.optimize = optimize;
+        self
+    }
+
+    /// Sets whether to compile in REPL mode, echoing the value of each top-level expression.
+    ///
+    /// # Example
+    /// ```
+    /// use monty::{CollectStringPrint, CompileOptions, MontyObject, MontyRun, NoLimitTracker};
+    ///
+    /// let code = "x = 1\nx + 1\nNone\n'done'".to_owned();
+    /// let options = CompileOptions::new().repl(true);
+    /// let runner = MontyRun::new_with_options(code, "<stdin>", vec![], vec![], options).unwrap();
+    /// let mut print = CollectStringPrint::new();
+    /// assert_eq!(runner.run(vec![], NoLimitTracker, &mut print).unwrap(), MontyObject::None);
+    /// assert_eq!(print.output(), "2\n'done'\n");
+    /// ```
+    #[must_use]
+    pub fn repl(mut self, repl: bool) -> Self {
+        self.repl = repl;
+        self
+    }
+}
+
 /// Lower level interface to parse code and run it to completion.
 ///
 /// This is an internal type used by [`MontyRun`]. It stores the compiled bytecode and source code
-/// for error reporting.
+/// for error reporting. It is never mutated after compilation (apart from the heap capacity hint),
+/// so runs and snapshots share it through an `Arc` instead of copying it.
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct Executor {
     /// Number of slots needed in the global namespace.
@@ -711,32 +1097,24 @@ struct Executor {
     heap_capacity: AtomicUsize,
 }
 
-impl Clone for Executor {
-    fn clone(&self) -> Self {
-        Self {
-            namespace_size: self.namespace_size,
-            #[cfg(feature = "ref-count-return")]
-            name_map: self.name_map.clone(),
-            module_code: self.module_code.clone(),
-            interns: self.interns.clone(),
-            external_function_ids: self.external_function_ids.clone(),
-            code: self.code.clone(),
-            heap_capacity: AtomicUsize::new(self.heap_capacity.load(Ordering::Relaxed)),
-        }
-    }
-}
-
 impl Executor {
-    /// Creates a new executor with the given code, filename, input names, and external functions.
+    /// Creates a new executor with the given code, filename, input names, external functions and options.
     fn new(
         code: String,
         script_name: &str,
         input_names: Vec<String>,
         external_functions: Vec<String>,
+        options: CompileOptions,
     ) -> Result<Self, MontyException> {
         let parse_result = parse(&code, script_name).map_err(|e| e.into_python_exc(script_name, &code))?;
-        let prepared = prepare(parse_result, input_names, &external_functions)
-            .map_err(|e| e.into_python_exc(script_name, &code))?;
+        let prepared = prepare(
+            parse_result,
+            input_names,
+            &external_functions,
+            options.optimize,
+            options.repl,
+        )
+        .map_err(|e| e.into_python_exc(script_name, &code))?;
 
         // Incrementing order matches the indexes used in intern::Interns::get_external_function_name
         let external_function_ids = (0..external_functions.len()).map(ExtFunctionId::new).collect();
@@ -746,7 +1124,7 @@ impl Executor {
 
         // Compile the module to bytecode, which also compiles all nested functions
         let namespace_size_u16 = u16::try_from(prepared.namespace_size).expect("module namespace size exceeds u16");
-        let compile_result = Compiler::compile_module(&prepared.nodes, &interns, namespace_size_u16)
+        let compile_result = Compiler::compile_module(&prepared.nodes, &interns, namespace_size_u16, options.repl)
             .map_err(|e| e.into_python_exc(script_name, &code))?;
 
         // Set the compiled functions in the interns
@@ -764,6 +1142,21 @@ impl Executor {
         })
     }
 
+    /// Disassembles the module code and all compiled functions, see [`MontyRun::disassemble`].
+    fn disassemble(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "Disassembly of <module>:").expect("writing to a String cannot fail");
+        disassemble_code(&self.module_code, &self.module_code, &self.interns, &mut out)
+            .expect("writing to a String cannot fail");
+        for function in self.interns.functions() {
+            let name = self.interns.get_str(function.name.name_id);
+            writeln!(out, "\nDisassembly of {name}:").expect("writing to a String cannot fail");
+            disassemble_code(&function.code, &self.module_code, &self.interns, &mut out)
+                .expect("writing to a String cannot fail");
+        }
+        out
+    }
+
     /// Executes the code with a custom resource tracker.
     ///
     /// This provides full control over resource tracking and garbage collection
@@ -828,6 +1221,14 @@ impl Executor {
         let mut vm = VM::new(&mut heap, &mut namespaces, &self.interns, &mut print);
         let frame_exit_result = vm.run_module(&self.module_code);
 
+        // Once the module has finished, only the globals and the return value keep values alive
+        let return_id = match &frame_exit_result {
+            Ok(FrameExit::Return(value)) => value.ref_id(),
+            _ => None,
+        };
+        let roots = namespaces.iter_heap_ids().chain(return_id).collect();
+        let cyclic_garbage = heap.cyclic_garbage_count(roots);
+
         // Compute ref counts before consuming the heap - return value is still alive
         let final_namespace = namespaces.into_global();
         let mut counts = ahash::AHashMap::new();
@@ -858,6 +1259,7 @@ impl Executor {
             counts,
             unique_refs,
             heap_count,
+            cyclic_garbage,
             allocations_since_gc,
         })
     }
@@ -931,6 +1333,11 @@ pub struct RefCountOutput {
     pub counts: ahash::AHashMap<String, usize>,
     pub unique_refs: usize,
     pub heap_count: usize,
+    /// Number of live heap values that are unreachable and only kept alive by reference cycles.
+    ///
+    /// Garbage collection would free these, so strict matching excuses them. Unreachable values
+    /// with leaked references aren't included.
+    pub cyclic_garbage: usize,
     /// Number of GC-tracked allocations since the last garbage collection.
     ///
     /// If GC ran during execution, this will be lower than the total number of
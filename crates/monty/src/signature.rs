@@ -6,7 +6,7 @@
 
 use crate::{
     args::{ArgValues, KwargsValues},
-    exception_private::{ExcType, RunResult, SimpleException},
+    exception_private::{ExcType, RunResult, SimpleException, format_param_names},
     expressions::Identifier,
     heap::{Heap, HeapData},
     intern::{Interns, StringId},
@@ -639,7 +639,7 @@ impl Signature {
     /// Returns an iterator over all parameter names in namespace slot order.
     ///
     /// Order: pos_args, args, var_args (if present), kwargs, var_kwargs (if present)
-    fn param_names(&self) -> impl Iterator<Item = StringId> + '_ {
+    pub fn param_names(&self) -> impl Iterator<Item = StringId> + '_ {
         let pos_args = self.pos_args.iter().flat_map(|v| v.iter().copied());
         let args = self.args.iter().flat_map(|v| v.iter().copied());
         let var_args = self.var_args.iter().copied();
@@ -672,26 +672,19 @@ impl Signature {
         let name_str = interns.get_str(func_name.name_id);
         let param_count = self.param_count();
         let msg = if let Some(missing_count) = param_count.checked_sub(actual_count) {
-            // Missing arguments - show actual parameter names
-            let mut msg = format!(
-                "{}() missing {} required positional argument{}: ",
-                name_str,
-                missing_count,
-                if missing_count == 1 { "" } else { "s" }
-            );
-            // Collect parameter names, skipping the ones already provided
-            let mut missing_names: Vec<_> = self
+            // Missing arguments - show actual parameter names, skipping the ones already provided
+            let missing_names: Vec<_> = self
                 .param_names()
                 .skip(actual_count)
-                .map(|string_id| format!("'{}'", interns.get_str(string_id)))
+                .map(|string_id| interns.get_str(string_id))
                 .collect();
-            let last = missing_names.pop().unwrap();
-            if !missing_names.is_empty() {
-                msg.push_str(&missing_names.join(", "));
-                msg.push_str(", and ");
-            }
-            msg.push_str(&last);
-            msg
+            format!(
+                "{}() missing {} required positional argument{}: {}",
+                name_str,
+                missing_count,
+                if missing_count == 1 { "" } else { "s" },
+                format_param_names(&missing_names)
+            )
         } else {
             // Too many arguments
             format!(
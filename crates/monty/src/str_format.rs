@@ -0,0 +1,290 @@
+//! `str.format_map()` template formatting.
+//!
+//! Templates use the replacement-field syntax of PEP 3101: literal text with `{{` and `}}`
+//! escapes, and fields of the form `{field_name[!conversion][:format_spec]}`. The field
+//! name is a mapping key followed by any number of `[index]` lookups; an index made of
+//! digits is looked up as an `int`, anything else as a `str`. The format spec may itself
+//! contain nested replacement fields (e.g. `{value:{width}}`), which are expanded before
+//! the spec is parsed, and is then applied with the same machinery as f-strings.
+//!
+//! Since `format_map()` has no positional arguments, empty and numeric field names raise
+//! `ValueError` as in CPython. Attribute lookups (`{obj.attr}`) aren't supported yet and
+//! raise `NotImplementedError`.
+
+use std::{fmt, str::Chars};
+
+use crate::{
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    fstring::{ParsedFormatSpec, ascii_escape, format_string, format_with_spec},
+    heap::{DropWithHeap, Heap},
+    intern::Interns,
+    resource::{DepthGuard, ResourceTracker},
+    types::{PyTrait, Type, str::allocate_string},
+    value::Value,
+};
+
+/// How deeply replacement fields may nest inside format specs, matching CPython.
+const MAX_RECURSION: u8 = 2;
+
+/// Formats `template` with `{key}` fields looked up in `mapping`.
+///
+/// Missing keys raise whatever the mapping's `__getitem__` raises, so a dict gives the usual
+/// `KeyError`.
+pub(crate) fn format_map(
+    template: &str,
+    mapping: &Value,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<String> {
+    render(template, mapping, MAX_RECURSION, heap, interns)
+}
+
+/// A replacement field split into its parts, borrowed from the template.
+struct Field<'a> {
+    name: &'a str,
+    conversion: Option<char>,
+    spec: &'a str,
+}
+
+/// Renders `template`, with `depth` levels of nesting still allowed.
+fn render(
+    template: &str,
+    mapping: &Value,
+    depth: u8,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<String> {
+    if depth == 0 {
+        return Err(value_error("Max string recursion exceeded"));
+    }
+
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '{' if chars.next_if(|&(_, c)| c == '{').is_some() => result.push('{'),
+            '}' if chars.next_if(|&(_, c)| c == '}').is_some() => result.push('}'),
+            '}' => return Err(value_error("Single '}' encountered in format string")),
+            '{' => {
+                if chars.peek().is_none() {
+                    return Err(value_error("Single '{' encountered in format string"));
+                }
+                let field = parse_field(&template[start + 1..])?;
+                let consumed = field.len;
+                render_field(&field.field, mapping, depth, &mut result, heap, interns)?;
+                // skip over the field and its closing brace
+                for _ in 0..consumed {
+                    chars.next();
+                }
+            }
+            c => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+/// A parsed field along with the number of template chars it spans, closing brace included.
+struct ParsedField<'a> {
+    field: Field<'a>,
+    len: usize,
+}
+
+/// Splits the replacement field at the start of `rest` (just after its opening brace).
+fn parse_field(rest: &str) -> RunResult<ParsedField<'_>> {
+    let mut chars = rest.chars();
+    let mut len = 0;
+
+    // field name, up to `!`, `:` or `}` outside of brackets
+    let mut in_brackets = false;
+    let name_end = loop {
+        let Some(c) = next_counted(&mut chars, &mut len) else {
+            return Err(value_error("expected '}' before end of string"));
+        };
+        match c {
+            '[' => in_brackets = true,
+            ']' => in_brackets = false,
+            _ if in_brackets => {}
+            '{' => return Err(value_error("unexpected '{' in field name")),
+            '!' | ':' | '}' => break (c, byte_len(rest, len - 1)),
+            _ => {}
+        }
+    };
+    let (mut terminator, name_len) = name_end;
+    let name = &rest[..name_len];
+
+    let mut conversion = None;
+    if terminator == '!' {
+        match next_counted(&mut chars, &mut len) {
+            None => return Err(value_error("expected '}' before end of string")),
+            Some('}') => return Err(value_error("unmatched '{' in format spec")),
+            Some(c) => conversion = Some(c),
+        }
+        terminator = match next_counted(&mut chars, &mut len) {
+            None => return Err(value_error("expected '}' before end of string")),
+            Some(c @ (':' | '}')) => c,
+            Some(_) => return Err(value_error("expected ':' after conversion specifier")),
+        };
+    }
+
+    let mut spec = "";
+    if terminator == ':' {
+        let spec_start = byte_len(rest, len);
+        let mut open = 1usize;
+        loop {
+            match next_counted(&mut chars, &mut len) {
+                None => return Err(value_error("expected '}' before end of string")),
+                Some('{') => open += 1,
+                Some('}') => {
+                    open -= 1;
+                    if open == 0 {
+                        break;
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+        spec = &rest[spec_start..byte_len(rest, len - 1)];
+    }
+
+    Ok(ParsedField {
+        field: Field { name, conversion, spec },
+        len,
+    })
+}
+
+/// Advances `chars`, counting the chars consumed in `len`.
+fn next_counted(chars: &mut Chars<'_>, len: &mut usize) -> Option<char> {
+    let c = chars.next()?;
+    *len += 1;
+    Some(c)
+}
+
+/// Returns the byte length of the first `char_count` chars of `s`.
+fn byte_len(s: &str, char_count: usize) -> usize {
+    s.char_indices().nth(char_count).map_or(s.len(), |(index, _)| index)
+}
+
+/// Looks up a field, converts and formats it, and appends the result.
+fn render_field(
+    field: &Field<'_>,
+    mapping: &Value,
+    depth: u8,
+    result: &mut String,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<()> {
+    let value = lookup(field.name, mapping, heap, interns)?;
+    let spec_text = if field.spec.contains('{') {
+        render(field.spec, mapping, depth - 1, heap, interns)
+    } else {
+        Ok(field.spec.to_owned())
+    };
+    let formatted = spec_text.and_then(|spec_text| format_field(&value, field.conversion, &spec_text, heap, interns));
+    value.drop_with_heap(heap);
+    result.push_str(&formatted?);
+    Ok(())
+}
+
+/// Applies the conversion and format spec of a field to its value.
+fn format_field(
+    value: &Value,
+    conversion: Option<char>,
+    spec_text: &str,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<String> {
+    let mut guard = DepthGuard::default();
+    let converted = match conversion {
+        None => None,
+        Some('s') => Some(value.py_str(heap, &mut guard, interns).into_owned()),
+        Some('r') => Some(value.py_repr(heap, &mut guard, interns).into_owned()),
+        Some('a') => Some(ascii_escape(&value.py_repr(heap, &mut guard, interns))),
+        Some(c) => return Err(value_error(format!("Unknown conversion specifier {c}"))),
+    };
+
+    if spec_text.is_empty() {
+        return Ok(match converted {
+            Some(s) => s,
+            None => value.py_str(heap, &mut guard, interns).into_owned(),
+        });
+    }
+
+    let spec = spec_text.parse::<ParsedFormatSpec>().map_err(|invalid| {
+        let value_type = if converted.is_some() {
+            Type::Str
+        } else {
+            value.py_type(heap)
+        };
+        value_error(format!(
+            "Invalid format specifier '{invalid}' for object of type '{value_type}'"
+        ))
+    })?;
+    match converted {
+        Some(s) => Ok(format_string(&s, &spec)?),
+        None => format_with_spec(value, &spec, heap, &mut guard, interns),
+    }
+}
+
+/// Resolves a field name like `key[0][name]` against `mapping`.
+fn lookup(name: &str, mapping: &Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+    let first_end = name.find(['.', '[']).unwrap_or(name.len());
+    let (first, mut rest) = name.split_at(first_end);
+    if first.is_empty() || first.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(value_error("Format string contains positional fields"));
+    }
+
+    let key = allocate_string(first.to_owned(), heap)?;
+    let value = mapping.py_getitem(&key, heap, interns);
+    key.drop_with_heap(heap);
+    let mut value = value?;
+
+    while !rest.is_empty() {
+        let step = match rest.as_bytes()[0] {
+            b'.' => {
+                let attr_end = rest[1..].find(['.', '[']).map_or(rest.len(), |index| index + 1);
+                if attr_end == 1 {
+                    Err(value_error("Empty attribute in format string"))
+                } else {
+                    Err(ExcType::not_implemented("attribute lookups in format fields are not supported").into())
+                }
+            }
+            b'[' => match rest.find(']') {
+                None => Err(value_error("Missing ']' in format string")),
+                Some(1) => Err(value_error("Empty attribute in format string")),
+                Some(close) => match rest.as_bytes().get(close + 1) {
+                    None | Some(b'.' | b'[') => {
+                        let index = &rest[1..close];
+                        rest = &rest[close + 1..];
+                        index_item(&value, index, heap, interns)
+                    }
+                    Some(_) => Err(value_error("Only '.' or '[' may follow ']' in format field specifier")),
+                },
+            },
+            _ => unreachable!("field name parts start with '.' or '['"),
+        };
+        value.drop_with_heap(heap);
+        value = step?;
+    }
+    Ok(value)
+}
+
+/// Looks up `value[index]`, using an `int` key when `index` is all digits.
+fn index_item(
+    value: &Value,
+    index: &str,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Value> {
+    let key = match index.parse::<i64>() {
+        Ok(i) if index.bytes().all(|b| b.is_ascii_digit()) => Value::Int(i),
+        _ => allocate_string(index.to_owned(), heap)?,
+    };
+    let item = value.py_getitem(&key, heap, interns);
+    key.drop_with_heap(heap);
+    item
+}
+
+/// A `ValueError` for a malformed template.
+fn value_error(msg: impl fmt::Display) -> RunError {
+    SimpleException::new_msg(ExcType::ValueError, msg).into()
+}
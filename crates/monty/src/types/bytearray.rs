@@ -0,0 +1,416 @@
+//! Python `bytearray` type, a mutable sequence of bytes.
+//!
+//! Supports construction, indexing and slicing, item and slice assignment, `+` and `+=`,
+//! and the buffer methods `append()`, `extend()` and `decode()`. Methods and item
+//! assignment run with the bytearray still on the heap (see [`call_attr`] and [`setitem`]),
+//! so the argument may be the bytearray itself, as in `b.extend(b)`.
+
+use std::fmt::Write;
+
+use ahash::AHashSet;
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    resource::{DepthGuard, ResourceError, ResourceTracker, check_repeat_size},
+    types::{
+        MontyIter, PyTrait, Type,
+        bytes::{bytes_decode, bytes_repr_fmt, get_byte_at_index, get_bytes_slice},
+    },
+    value::{EitherStr, Value},
+};
+
+/// Python bytearray value stored on the heap.
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ByteArray(Vec<u8>);
+
+impl ByteArray {
+    /// Creates a new ByteArray from a byte vector.
+    #[must_use]
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns a reference to the inner byte slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the inner byte vector.
+    pub fn as_vec_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.0
+    }
+
+    /// Creates a bytearray from the `bytearray()` constructor call.
+    ///
+    /// - `bytearray()` with no args returns an empty bytearray
+    /// - `bytearray(int)` returns a bytearray of that length filled with zeros
+    /// - `bytearray(bytes)` and `bytearray(bytearray)` copy the bytes
+    /// - `bytearray(iterable)` takes ints in `range(0, 256)` from the iterable
+    ///
+    /// Strings need an encoding argument, which isn't supported, so they raise `TypeError`.
+    pub fn init(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
+        let bytes = match args.get_zero_one_arg("bytearray", heap)? {
+            None => Vec::new(),
+            Some(value) => {
+                defer_drop!(value, heap);
+                match value {
+                    Value::Int(_) | Value::Bool(_) => {
+                        let count = value.as_index(heap, Type::ByteArray)?;
+                        if count < 0 {
+                            return Err(ExcType::value_error_negative_bytes_count());
+                        }
+                        let size = usize::try_from(count).expect("bytearray count validated non-negative");
+                        check_repeat_size(size, 1, heap.tracker())?;
+                        vec![0u8; size]
+                    }
+                    _ if value.py_type(heap) == Type::Str => {
+                        return Err(ExcType::type_error("string argument without an encoding"));
+                    }
+                    _ => {
+                        let value_type = value.py_type(heap);
+                        collect_bytes(value, heap, interns)?.ok_or_else(|| {
+                            ExcType::type_error(format!("cannot convert '{value_type}' object to bytearray"))
+                        })?
+                    }
+                }
+            }
+        };
+        Ok(Value::Ref(heap.allocate(HeapData::ByteArray(Self(bytes)))?))
+    }
+}
+
+impl PyTrait for ByteArray {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        Type::ByteArray
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.0.capacity()
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        Some(self.0.len())
+    }
+
+    fn py_getitem(&self, key: &Value, heap: &mut Heap<impl ResourceTracker>, _interns: &Interns) -> RunResult<Value> {
+        if let Value::Ref(id) = key
+            && let HeapData::Slice(slice) = heap.get(*id)
+        {
+            let (start, stop, step) = slice
+                .indices(self.0.len())
+                .map_err(|()| ExcType::value_error_slice_step_zero())?;
+
+            let sliced_bytes = get_bytes_slice(&self.0, start, stop, step);
+            let heap_id = heap.allocate(HeapData::ByteArray(Self::new(sliced_bytes)))?;
+            return Ok(Value::Ref(heap_id));
+        }
+
+        let index = key.as_index(heap, Type::ByteArray)?;
+        let byte = get_byte_at_index(&self.0, index).ok_or_else(index_error)?;
+        Ok(Value::Int(i64::from(byte)))
+    }
+
+    fn py_eq(
+        &self,
+        other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        Ok(self.0 == other.0)
+    }
+
+    /// Bytearrays don't contain nested heap references.
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {
+        // No-op: bytearrays don't hold Value references
+    }
+
+    fn py_bool(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> bool {
+        !self.0.is_empty()
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        _heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> std::fmt::Result {
+        f.write_str("bytearray(")?;
+        bytes_repr_fmt(&self.0, f)?;
+        f.write_char(')')
+    }
+
+    fn py_add(
+        &self,
+        other: &Self,
+        heap: &mut Heap<impl ResourceTracker>,
+        _interns: &Interns,
+    ) -> Result<Option<Value>, ResourceError> {
+        let bytes = [self.0.as_slice(), other.0.as_slice()].concat();
+        Ok(Some(Value::Ref(heap.allocate(HeapData::ByteArray(Self(bytes)))?)))
+    }
+
+    /// Extends the bytearray in place with the contents of a bytes or bytearray.
+    ///
+    /// `self_id` is needed for `b += b`, where `other` is this bytearray whose data has been
+    /// taken out of the heap.
+    fn py_iadd(
+        &mut self,
+        other: Value,
+        heap: &mut Heap<impl ResourceTracker>,
+        self_id: Option<HeapId>,
+        interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        let extended = if other.ref_id().is_some() && other.ref_id() == self_id {
+            check_repeat_size(self.0.len(), 2, heap.tracker())?;
+            self.0.extend_from_within(..);
+            true
+        } else if let Some(bytes) = bytes_like(&other, heap, interns) {
+            check_repeat_size(self.0.len() + bytes.len(), 1, heap.tracker())?;
+            self.0.extend_from_slice(bytes);
+            true
+        } else {
+            false
+        };
+        other.drop_with_heap(heap);
+        Ok(extended)
+    }
+}
+
+/// Calls a method on the bytearray at `id`.
+///
+/// Called by [`Heap::call_attr_raw`] without taking the bytearray's data out of the heap, so
+/// arguments are read in full before the bytearray is changed.
+pub(crate) fn call_attr(
+    heap: &mut Heap<impl ResourceTracker>,
+    id: HeapId,
+    attr: &EitherStr,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<Value> {
+    match attr.static_string() {
+        Some(StaticStrings::Append) => {
+            let item = args.get_one_arg("bytearray.append", heap)?;
+            defer_drop!(item, heap);
+            let byte = byte_value(item, heap)?;
+            get_bytearray_mut(heap, id).0.push(byte);
+            heap.update_size(id)?;
+            Ok(Value::None)
+        }
+        Some(StaticStrings::Extend) => {
+            let iterable = args.get_one_arg("bytearray.extend", heap)?;
+            defer_drop!(iterable, heap);
+            let iterable_type = iterable.py_type(heap);
+            let Some(bytes) = collect_bytes(iterable, heap, interns)? else {
+                return Err(ExcType::type_error(format!(
+                    "can't extend bytearray with {iterable_type}"
+                )));
+            };
+            let len = get_bytearray(heap, id).0.len();
+            check_repeat_size(len + bytes.len(), 1, heap.tracker())?;
+            get_bytearray_mut(heap, id).0.extend(bytes);
+            heap.update_size(id)?;
+            Ok(Value::None)
+        }
+        Some(StaticStrings::Decode) => {
+            let bytes = get_bytearray(heap, id).0.clone();
+            bytes_decode(&bytes, args, heap, interns)
+        }
+        _ => {
+            args.drop_with_heap(heap);
+            Err(ExcType::attribute_error(Type::ByteArray, attr.as_str(interns)))
+        }
+    }
+}
+
+/// Assigns `value` to `bytearray[key]`, where `key` is an index or a slice.
+///
+/// Like [`call_attr`], this runs with the bytearray still on the heap, so `value` may be the
+/// bytearray itself. A slice with step 1 may be replaced by any number of bytes, an extended
+/// slice only by exactly as many bytes as it selects.
+pub(crate) fn setitem(
+    heap: &mut Heap<impl ResourceTracker>,
+    id: HeapId,
+    key: Value,
+    value: Value,
+    interns: &Interns,
+) -> RunResult<()> {
+    defer_drop!(key, heap);
+    defer_drop!(value, heap);
+    let len = get_bytearray(heap, id).0.len();
+
+    let slice_indices = match key {
+        Value::Ref(key_id) => match heap.get(*key_id) {
+            HeapData::Slice(slice) => Some(slice.indices(len)),
+            _ => None,
+        },
+        _ => None,
+    };
+    let Some(indices) = slice_indices else {
+        let index = key.as_index(heap, Type::ByteArray)?;
+        let byte = byte_value(value, heap)?;
+        let position = resolve_index(index, len).ok_or_else(index_error)?;
+        get_bytearray_mut(heap, id).0[position] = byte;
+        return Ok(());
+    };
+
+    let (start, stop, step) = indices.map_err(|()| ExcType::value_error_slice_step_zero())?;
+    let bytes = if value.py_type(heap) == Type::Str {
+        None
+    } else {
+        collect_bytes(value, heap, interns)?
+    };
+    let Some(bytes) = bytes else {
+        return Err(ExcType::type_error(
+            "can assign only bytes, buffers, or iterables of ints in range(0, 256)",
+        ));
+    };
+
+    if step == 1 {
+        let stop = stop.max(start);
+        check_repeat_size(len - (stop - start) + bytes.len(), 1, heap.tracker())?;
+        get_bytearray_mut(heap, id).0.splice(start..stop, bytes);
+        heap.update_size(id)?;
+        return Ok(());
+    }
+
+    let positions = slice_positions(len, start, stop, step);
+    if positions.len() != bytes.len() {
+        return Err(SimpleException::new_msg(
+            ExcType::ValueError,
+            format!(
+                "attempt to assign bytes of size {} to extended slice of size {}",
+                bytes.len(),
+                positions.len()
+            ),
+        )
+        .into());
+    }
+    let data = &mut get_bytearray_mut(heap, id).0;
+    for (position, byte) in positions.into_iter().zip(bytes) {
+        data[position] = byte;
+    }
+    Ok(())
+}
+
+/// Returns the contents of a `bytes` or `bytearray` value.
+pub(crate) fn bytes_like<'a>(
+    value: &Value,
+    heap: &'a Heap<impl ResourceTracker>,
+    interns: &'a Interns,
+) -> Option<&'a [u8]> {
+    match value {
+        Value::InternBytes(bytes_id) => Some(interns.get_bytes(*bytes_id)),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Bytes(b) => Some(b.as_slice()),
+            HeapData::ByteArray(b) => Some(b.as_slice()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the bytes that `value` adds to a bytearray, or `None` if it isn't iterable.
+///
+/// Bytes and bytearrays give their contents, other iterables must yield ints in
+/// `range(0, 256)`.
+fn collect_bytes(
+    value: &Value,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Option<Vec<u8>>> {
+    if let Some(bytes) = bytes_like(value, heap, interns) {
+        return Ok(Some(bytes.to_vec()));
+    }
+    let Ok(mut iter) = MontyIter::new(value.clone_with_heap(heap), heap, interns) else {
+        return Ok(None);
+    };
+    let mut bytes = Vec::new();
+    let result = loop {
+        match iter.for_next(heap, interns) {
+            Ok(Some(item)) => {
+                let byte = byte_value(&item, heap);
+                item.drop_with_heap(heap);
+                match byte {
+                    Ok(byte) => bytes.push(byte),
+                    Err(err) => break Err(err),
+                }
+            }
+            Ok(None) => break Ok(Some(bytes)),
+            Err(err) => break Err(err),
+        }
+    };
+    iter.drop_with_heap(heap);
+    result
+}
+
+/// Converts an int to the byte stored by `append()` and item assignment.
+fn byte_value(value: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<u8> {
+    let n = match value {
+        Value::Int(n) => *n,
+        Value::Bool(b) => i64::from(*b),
+        Value::Ref(id) if matches!(heap.get(*id), HeapData::LongInt(_)) => return Err(byte_range_error()),
+        _ => return Err(ExcType::type_error_not_integer(value.py_type(heap))),
+    };
+    u8::try_from(n).map_err(|_| byte_range_error())
+}
+
+/// Resolves a possibly negative index into a position in a bytearray of length `len`.
+fn resolve_index(index: i64, len: usize) -> Option<usize> {
+    let len = i64::try_from(len).ok()?;
+    let normalized = if index < 0 { index + len } else { index };
+    if (0..len).contains(&normalized) {
+        usize::try_from(normalized).ok()
+    } else {
+        None
+    }
+}
+
+/// Lists the positions selected by an extended slice, in the order `get_bytes_slice` visits
+/// them.
+fn slice_positions(len: usize, start: usize, stop: usize, step: i64) -> Vec<usize> {
+    if let Ok(step) = usize::try_from(step) {
+        (start..stop.min(len)).step_by(step).collect()
+    } else {
+        let step = usize::try_from(step.unsigned_abs()).expect("slice step fits in usize");
+        // stop > len means the slice runs to the beginning
+        let lowest = if stop > len { 0 } else { stop + 1 };
+        if start >= len || start < lowest {
+            return Vec::new();
+        }
+        (lowest..=start).rev().step_by(step).collect()
+    }
+}
+
+/// Returns the bytearray stored at `id`.
+fn get_bytearray(heap: &Heap<impl ResourceTracker>, id: HeapId) -> &ByteArray {
+    match heap.get(id) {
+        HeapData::ByteArray(b) => b,
+        _ => unreachable!("bytearray functions are only called for bytearrays"),
+    }
+}
+
+/// Returns the bytearray stored at `id` for changing it.
+fn get_bytearray_mut(heap: &mut Heap<impl ResourceTracker>, id: HeapId) -> &mut ByteArray {
+    match heap.get_mut(id) {
+        HeapData::ByteArray(b) => b,
+        _ => unreachable!("bytearray functions are only called for bytearrays"),
+    }
+}
+
+/// The `ValueError` for an int outside `range(0, 256)`.
+pub(crate) fn byte_range_error() -> RunError {
+    SimpleException::new_msg(ExcType::ValueError, "byte must be in range(0, 256)").into()
+}
+
+/// The `IndexError` for an index outside the bytearray.
+fn index_error() -> RunError {
+    SimpleException::new_msg(ExcType::IndexError, "bytearray index out of range").into()
+}
@@ -175,17 +175,13 @@ impl Bytes {
         &self.0
     }
 
-    /// Returns a mutable reference to the inner byte vector.
-    pub fn as_vec_mut(&mut self) -> &mut Vec<u8> {
-        &mut self.0
-    }
-
     /// Creates bytes from the `bytes()` constructor call.
     ///
     /// - `bytes()` with no args returns empty bytes
     /// - `bytes(int)` returns bytes of that length filled with zeros
     /// - `bytes(string)` encodes the string as UTF-8 (simplified, no encoding param)
     /// - `bytes(bytes)` returns a copy of the bytes
+    /// - `bytes(bytearray)` returns the bytearray's contents as bytes
     ///
     /// Note: Full Python semantics for bytes() are more complex (encoding, errors params).
     pub fn init(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
@@ -216,6 +212,7 @@ impl Bytes {
                     Value::Ref(id) => match heap.get(*id) {
                         HeapData::Str(s) => heap.allocate(HeapData::Bytes(Self::new(s.as_str().as_bytes().to_vec()))),
                         HeapData::Bytes(b) => heap.allocate(HeapData::Bytes(Self::new(b.as_slice().to_vec()))),
+                        HeapData::ByteArray(b) => heap.allocate(HeapData::Bytes(Self::new(b.as_slice().to_vec()))),
                         _ => {
                             let err = ExcType::type_error_bytes_init(v.py_type(heap));
                             v.drop_with_heap(heap);
@@ -267,7 +264,7 @@ impl PyTrait for Bytes {
     }
 
     fn py_estimate_size(&self) -> usize {
-        std::mem::size_of::<Self>() + self.0.len()
+        std::mem::size_of::<Self>() + self.0.capacity()
     }
 
     fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
@@ -518,7 +515,7 @@ pub fn bytes_repr(bytes: &[u8]) -> String {
 /// Implements Python's `bytes.decode([encoding[, errors]])` method.
 ///
 /// Converts bytes to a string. Currently only supports UTF-8 encoding.
-fn bytes_decode(
+pub(crate) fn bytes_decode(
     bytes: &[u8],
     args: ArgValues,
     heap: &mut Heap<impl ResourceTracker>,
@@ -644,7 +641,7 @@ fn bytes_find(
 
 /// Finds the first occurrence of needle in haystack.
 fn find_subsequence(haystack: &[u8], needle: &[u8]) -> Option<usize> {
-    haystack.windows(needle.len()).position(|window| window == needle)
+    memchr::memmem::find(haystack, needle)
 }
 
 /// Implements Python's `bytes.index(sub[, start[, end]])` method.
@@ -1195,10 +1192,7 @@ fn bytes_rfind(
 
 /// Finds the last occurrence of needle in haystack.
 fn rfind_subsequence(haystack: &[u8], needle: &[u8]) -> Option<usize> {
-    if needle.len() > haystack.len() {
-        return None;
-    }
-    haystack.windows(needle.len()).rposition(|window| window == needle)
+    memchr::memmem::rfind(haystack, needle)
 }
 
 /// Implements Python's `bytes.rindex(sub[, start[, end]])` method.
@@ -1868,9 +1862,15 @@ fn parse_bytes_splitlines_args(
     // Extract positional keepends (default false)
     let mut has_pos_keepends = keepends_value.is_some();
     let mut keepends = if let Some(v) = keepends_value {
-        let result = v.py_bool(heap, interns);
+        let result = v.to_bool(heap, interns);
         v.drop_with_heap(heap);
-        result
+        match result {
+            Ok(result) => result,
+            Err(err) => {
+                kwargs.drop_with_heap(heap);
+                return Err(err);
+            }
+        }
     } else {
         false
     };
@@ -1892,7 +1892,14 @@ fn parse_bytes_splitlines_args(
                     "bytes.splitlines() got multiple values for argument 'keepends'",
                 ));
             }
-            keepends = value.py_bool(heap, interns);
+            keepends = match value.to_bool(heap, interns) {
+                Ok(keepends) => keepends,
+                Err(err) => {
+                    key.drop_with_heap(heap);
+                    value.drop_with_heap(heap);
+                    return Err(err);
+                }
+            };
             has_pos_keepends = true;
         } else {
             key.drop_with_heap(heap);
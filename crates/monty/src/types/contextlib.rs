@@ -0,0 +1,180 @@
+//! Context managers created by the `contextlib` module: `suppress` and `nullcontext`.
+//!
+//! `with` statements call `__enter__` and `__exit__` as ordinary methods (see
+//! `Parser::parse_with`), so these objects only need to implement those two methods.
+
+use std::fmt::Write;
+
+use ahash::AHashSet;
+
+use crate::{
+    args::ArgValues,
+    builtins::{Builtins, isinstance::isinstance_check},
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    resource::{DepthGuard, ResourceError, ResourceTracker},
+    types::{PyTrait, Type},
+    value::{EitherStr, Value},
+};
+
+/// A `contextlib.suppress` or `contextlib.nullcontext` object.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) enum ContextManager {
+    /// `suppress(*exceptions)`: swallows exceptions that are instances of any of the classes.
+    ///
+    /// The classes aren't checked until an exception is raised, matching CPython.
+    Suppress(Vec<Value>),
+    /// `nullcontext(enter_result=None)`: returns `enter_result` from `__enter__` and does
+    /// nothing else.
+    NullContext(Value),
+}
+
+impl ContextManager {
+    /// The name of the context manager's type, used in error messages.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Suppress(_) => "suppress",
+            Self::NullContext(_) => "nullcontext",
+        }
+    }
+
+    /// Returns whether the context manager holds any heap references.
+    pub fn has_refs(&self) -> bool {
+        match self {
+            Self::Suppress(exceptions) => exceptions.iter().any(|exc| matches!(exc, Value::Ref(_))),
+            Self::NullContext(value) => matches!(value, Value::Ref(_)),
+        }
+    }
+}
+
+impl PyTrait for ContextManager {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        match self {
+            Self::Suppress(_) => Type::Suppress,
+            Self::NullContext(_) => Type::NullContext,
+        }
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        _other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Context managers compare by identity, which is handled at the Value level
+        Ok(false)
+    }
+
+    fn py_bool(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        _heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> std::fmt::Result {
+        write!(f, "<contextlib.{} object>", self.name())
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        match self {
+            Self::Suppress(exceptions) => {
+                for exc in exceptions {
+                    exc.py_dec_ref_ids(stack);
+                }
+            }
+            Self::NullContext(value) => value.py_dec_ref_ids(stack),
+        }
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + match self {
+                Self::Suppress(exceptions) => exceptions.len() * std::mem::size_of::<Value>(),
+                Self::NullContext(_) => 0,
+            }
+    }
+}
+
+/// Calls `__enter__` or `__exit__` on the context manager stored at `id`.
+///
+/// Called by [`Heap::call_attr_raw`] without taking the object's data out of the heap, so
+/// `nullcontext(x).__enter__()` can return `x` with a new reference.
+pub(crate) fn call_attr(
+    heap: &mut Heap<impl ResourceTracker>,
+    id: HeapId,
+    attr: &EitherStr,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<Value> {
+    let HeapData::ContextManager(manager) = heap.get(id) else {
+        unreachable!("contextlib::call_attr is only called for context managers")
+    };
+    let name = manager.name();
+    match attr.static_string() {
+        Some(StaticStrings::DunderEnter) => {
+            args.check_zero_args(&format!("{name}.__enter__"), heap)?;
+            let value = match heap.get(id) {
+                HeapData::ContextManager(ContextManager::NullContext(value)) => value.copy_for_extend(),
+                _ => Value::None,
+            };
+            if let Value::Ref(value_id) = value {
+                heap.inc_ref(value_id);
+            }
+            Ok(value)
+        }
+        Some(StaticStrings::DunderExit) => {
+            let args: Vec<Value> = args.into_pos_only(&format!("{name}.__exit__"), heap)?.collect();
+            defer_drop!(args, heap);
+            match heap.get(id) {
+                HeapData::ContextManager(ContextManager::Suppress(exceptions)) => {
+                    let [exc_type, _, _] = args.as_slice() else {
+                        return Err(ExcType::type_error_arg_count("suppress.__exit__", 3, args.len()));
+                    };
+                    suppresses(exceptions, exc_type, heap).map(Value::Bool)
+                }
+                _ => Ok(Value::None),
+            }
+        }
+        _ => {
+            let type_ = heap.get(id).py_type(heap);
+            args.drop_with_heap(heap);
+            Err(ExcType::attribute_error(type_, attr.as_str(interns)))
+        }
+    }
+}
+
+/// Returns whether `suppress(*exceptions)` swallows an exception of class `exc_type`.
+fn suppresses(exceptions: &[Value], exc_type: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<bool> {
+    let exc_type = match exc_type {
+        Value::None => return Ok(false),
+        Value::Builtin(Builtins::ExcType(exc)) => Type::Exception(*exc),
+        Value::Builtin(Builtins::Type(t)) => *t,
+        _ => return Err(ExcType::type_error("issubclass() arg 1 must be a class")),
+    };
+    for exc in exceptions {
+        match isinstance_check(exc_type, exc, heap) {
+            Ok(true) => return Ok(true),
+            Ok(false) => {}
+            Err(()) => {
+                return Err(SimpleException::new_msg(
+                    ExcType::TypeError,
+                    "issubclass() arg 2 must be a class, a tuple of classes, or a union",
+                )
+                .into());
+            }
+        }
+    }
+    Ok(false)
+}
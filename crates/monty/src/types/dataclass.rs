@@ -199,7 +199,11 @@ impl PyTrait for Dataclass {
             + self.name.py_estimate_size()
             + self.field_names.iter().map(String::len).sum::<usize>()
             + self.attrs.py_estimate_size()
-            + self.methods.len() * std::mem::size_of::<String>()
+            + self
+                .methods
+                .iter()
+                .map(|method| std::mem::size_of::<String>() + method.capacity())
+                .sum::<usize>()
     }
 
     fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
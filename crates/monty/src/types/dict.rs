@@ -1,8 +1,4 @@
-use std::{
-    collections::hash_map::DefaultHasher,
-    fmt::Write,
-    hash::{Hash, Hasher},
-};
+use std::fmt::Write;
 
 use ahash::AHashSet;
 use hashbrown::{HashTable, hash_table::Entry};
@@ -16,7 +12,7 @@ use crate::{
     intern::{Interns, StaticStrings},
     resource::{DepthGuard, ResourceError, ResourceTracker},
     types::Type,
-    value::{EitherStr, Value},
+    value::{EitherStr, Value, bytes_hash, str_hash},
 };
 
 /// Python dict type preserving insertion order.
@@ -205,10 +201,7 @@ impl Dict {
     /// This is an O(1) lookup that doesn't require mutable heap access.
     /// Only works for string keys - returns None if the key is not found.
     pub fn get_by_str(&self, key_str: &str, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> Option<&Value> {
-        // Compute hash for the string key
-        let mut hasher = DefaultHasher::new();
-        key_str.hash(&mut hasher);
-        let hash = hasher.finish();
+        let hash = str_hash(key_str);
 
         // Find entry with matching hash and key
         self.indices
@@ -229,6 +222,35 @@ impl Dict {
             .map(|&idx| &self.entries[idx].value)
     }
 
+    /// Gets a value from the dict by bytes key (immutable lookup).
+    ///
+    /// The bytes counterpart of [`Self::get_by_str`]: only matches `bytes` keys.
+    pub fn get_by_bytes(
+        &self,
+        key_bytes: &[u8],
+        heap: &Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> Option<&Value> {
+        let hash = bytes_hash(key_bytes);
+
+        self.indices
+            .find(hash, |&idx| {
+                let entry_key = &self.entries[idx].key;
+                match entry_key {
+                    Value::InternBytes(id) => interns.get_bytes(*id) == key_bytes,
+                    Value::Ref(id) => {
+                        if let HeapData::Bytes(b) = heap.get(*id) {
+                            b.as_slice() == key_bytes
+                        } else {
+                            false
+                        }
+                    }
+                    _ => false,
+                }
+            })
+            .map(|&idx| &self.entries[idx].value)
+    }
+
     /// Sets a key-value pair in the dict.
     ///
     /// The caller transfers ownership of `key` and `value` to the dict. Their refcounts
@@ -236,7 +258,8 @@ impl Dict {
     /// were already incremented (e.g., via `clone_with_heap` or `evaluate_use`).
     ///
     /// If the key already exists, replaces the old value and returns it (caller now
-    /// owns the old value and is responsible for its refcount).
+    /// owns the old value and is responsible for its refcount). Like CPython, the existing
+    /// key is kept, so `d[1.0] = x` on a dict with key `1` leaves the key as `1`.
     /// Returns Err if key is unhashable.
     pub fn set(
         &mut self,
@@ -261,19 +284,17 @@ impl Dict {
             }
         };
 
-        let entry = DictEntry { key, value, hash };
         if let Some(index) = opt_index {
-            // Key exists, replace in place to preserve insertion order
-            let old_entry = std::mem::replace(&mut self.entries[index], entry);
-
-            // Decrement refcount for old key (we're discarding it)
-            old_entry.key.drop_with_heap(heap);
+            // Key exists, replace the value in place to preserve insertion order.
+            // The new duplicate key is dropped since we keep the existing key.
+            let old_value = std::mem::replace(&mut self.entries[index].value, value);
+            key.drop_with_heap(heap);
             // Transfer ownership of the old value to caller (no clone needed)
-            Ok(Some(old_entry.value))
+            Ok(Some(old_value))
         } else {
             // Key doesn't exist, add new pair to indices and entries
             let index = self.entries.len();
-            self.entries.push(entry);
+            self.entries.push(DictEntry { key, value, hash });
             self.indices
                 .insert_unique(hash, index, |index| self.entries[*index].hash);
             Ok(None)
@@ -293,9 +314,10 @@ impl Dict {
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> RunResult<Option<(Value, Value)>> {
-        let hash = key
-            .py_hash(heap, interns)
-            .ok_or_else(|| ExcType::type_error_unhashable_dict_key(key.py_type(heap)))?;
+        let Some(hash) = key.py_hash(heap, interns) else {
+            let unhashable = key.unhashable_type(heap, interns);
+            return Err(ExcType::type_error_unhashable_dict_key(key.py_type(heap), unhashable));
+        };
 
         // Create a guard for key equality comparisons.
         let mut guard = DepthGuard::default();
@@ -354,6 +376,24 @@ impl Dict {
             .collect()
     }
 
+    /// Returns a new dict with the entries of this dict followed by those of `other`,
+    /// so values from `other` win on key conflicts (`self | other`).
+    pub fn union(&self, other: &Self, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Self> {
+        let pairs: Vec<(Value, Value)> = self
+            .iter()
+            .chain(other.iter())
+            .map(|(k, v)| (k.clone_with_heap(heap), v.clone_with_heap(heap)))
+            .collect();
+        Self::from_pairs(pairs, heap, interns)
+    }
+
+    /// Updates the dict in place from a mapping or an iterable of pairs, like
+    /// `dict.update(other)` (`self |= other`).
+    pub fn update(&mut self, other: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<()> {
+        dict_update(self, ArgValues::One(other), heap, interns)?;
+        Ok(())
+    }
+
     /// Returns the number of key-value pairs in the dict.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -438,9 +478,10 @@ impl Dict {
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> RunResult<(Option<usize>, u64)> {
-        let hash = key
-            .py_hash(heap, interns)
-            .ok_or_else(|| ExcType::type_error_unhashable_dict_key(key.py_type(heap)))?;
+        let Some(hash) = key.py_hash(heap, interns) else {
+            let unhashable = key.unhashable_type(heap, interns);
+            return Err(ExcType::type_error_unhashable_dict_key(key.py_type(heap), unhashable));
+        };
 
         // Create a guard for key equality comparisons. Dict keys are typically
         // shallow (strings, ints, tuples of primitives), so recursion errors
@@ -507,8 +548,11 @@ impl PyTrait for Dict {
     }
 
     fn py_estimate_size(&self) -> usize {
-        // Dict size: struct overhead + entries (2 Values per entry for key+value)
-        std::mem::size_of::<Self>() + self.len() * 2 * std::mem::size_of::<Value>()
+        // Dict size: struct overhead + dense entries (key, value and hash) + index table,
+        // which needs a control byte per slot on top of the stored index
+        std::mem::size_of::<Self>()
+            + self.entries.capacity() * std::mem::size_of::<DictEntry>()
+            + self.indices.capacity() * (std::mem::size_of::<usize>() + 1)
     }
 
     fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
@@ -0,0 +1,393 @@
+//! Python file objects returned by the `open()` builtin.
+//!
+//! Monty never touches the real filesystem. `open()` yields `OsFunction::Open` to the
+//! host, which returns an opaque `int` handle; Monty wraps that handle in a [`File`],
+//! and every later operation on the file yields another OS call keyed by the handle.
+//!
+//! # Host protocol
+//!
+//! | OS function      | Arguments        | Host returns                                        |
+//! |------------------|------------------|-----------------------------------------------------|
+//! | `open`           | `(path, mode)`   | `int` handle identifying the open file              |
+//! | `file.read`      | `(handle, size)` | `str` (`bytes` in binary mode), `size` is -1 for all |
+//! | `file.readline`  | `(handle, size)` | the next line including `\n`, empty at end of file  |
+//! | `file.readlines` | `(handle,)`      | `list` of the remaining lines                       |
+//! | `file.write`     | `(handle, data)` | `int` number of characters (or bytes) written       |
+//! | `file.close`     | `(handle,)`      | `None`                                              |
+//!
+//! `path` and `mode` are always strings; `mode` has already been validated. Iterating
+//! over a file (`for line in f`) yields `file.readlines` once and then iterates over the
+//! returned list. Leaving a `with open(...) as f:` block yields `file.close` like `close()`
+//! does. Reading a write-only file or writing a read-only one raises `OSError` without
+//! yielding. The host reports other failures, such as `FileNotFoundError`, by resuming
+//! with an exception, which Python code can catch.
+
+use std::fmt::Write;
+
+use ahash::AHashSet;
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings, StringId},
+    os::OsFunction,
+    resource::{DepthGuard, ResourceError, ResourceTracker},
+    types::{AttrCallResult, MontyIter, PyTrait, Str, Type, str::StringRepr},
+    value::{EitherStr, Value},
+};
+
+/// Python file object backed by a host file handle.
+///
+/// Stores the handle returned by the host for `open()` along with the name and mode the
+/// file was opened with. Only the `closed` flag is tracked locally, so operations on a
+/// closed file fail without a round trip to the host.
+///
+/// Text files report `_io.TextIOWrapper` and binary files report `_io.BufferedReader`,
+/// `_io.BufferedWriter` or `_io.BufferedRandom` depending on the mode, like CPython.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct File {
+    /// Opaque handle returned by the host for `open()`.
+    handle: i64,
+    /// The path passed to `open()`, exposed as `file.name`.
+    name: String,
+    /// The mode passed to `open()`, exposed as `file.mode`.
+    mode: String,
+    /// Whether `close()` has been called.
+    closed: bool,
+}
+
+impl File {
+    /// Creates a new open file for a handle returned by the host.
+    #[must_use]
+    pub fn new(handle: i64, name: String, mode: String) -> Self {
+        Self {
+            handle,
+            name,
+            mode,
+            closed: false,
+        }
+    }
+
+    /// Returns whether the file was opened in binary mode.
+    fn is_binary(&self) -> bool {
+        self.mode.contains('b')
+    }
+
+    /// Returns whether the mode allows reading: `'r'` or any `'+'` mode.
+    fn is_readable(&self) -> bool {
+        self.mode.contains('r') || self.mode.contains('+')
+    }
+
+    /// Returns whether the mode allows writing: `'w'`, `'a'`, `'x'` or any `'+'` mode.
+    fn is_writable(&self) -> bool {
+        !self.mode.contains('r') || self.mode.contains('+')
+    }
+
+    /// Returns the Python type of the file, which depends on its mode.
+    fn file_type(&self) -> Type {
+        if !self.is_binary() {
+            Type::TextIOWrapper
+        } else if self.mode.contains('+') {
+            Type::BufferedRandom
+        } else if self.is_readable() {
+            Type::BufferedReader
+        } else {
+            Type::BufferedWriter
+        }
+    }
+
+    /// Returns the OS call used to iterate over the lines of this file.
+    ///
+    /// Called by the VM's `GetIter` opcode; the VM turns the list the host returns into
+    /// an iterator (see [`FileResume::Iter`]).
+    pub fn iter_os_call(&self) -> RunResult<(OsFunction, ArgValues)> {
+        self.check_open()?;
+        self.check_readable()?;
+        Ok((OsFunction::FileReadlines, ArgValues::One(Value::Int(self.handle))))
+    }
+
+    /// Returns a `ValueError` if the file has been closed.
+    fn check_open(&self) -> RunResult<()> {
+        if self.closed {
+            Err(ExcType::value_error_closed_file())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns an `OSError` if the file wasn't opened for reading.
+    ///
+    /// CPython raises `io.UnsupportedOperation`, a subclass of `OSError`, with the same message.
+    fn check_readable(&self) -> RunResult<()> {
+        if self.is_readable() {
+            Ok(())
+        } else {
+            let msg = if self.is_binary() { "read" } else { "not readable" };
+            Err(SimpleException::new_msg(ExcType::OSError, msg).into())
+        }
+    }
+
+    /// Returns an `OSError` if the file wasn't opened for writing.
+    fn check_writable(&self) -> RunResult<()> {
+        if self.is_writable() {
+            Ok(())
+        } else {
+            let msg = if self.is_binary() { "write" } else { "not writable" };
+            Err(SimpleException::new_msg(ExcType::OSError, msg).into())
+        }
+    }
+}
+
+impl PyTrait for File {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        self.file_type()
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        _other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Files compare by identity, which is handled at the Value level
+        Ok(false)
+    }
+
+    fn py_bool(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        _heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> std::fmt::Result {
+        if self.is_binary() {
+            write!(f, "<{} name={}>", self.file_type(), StringRepr(&self.name))
+        } else {
+            write!(
+                f,
+                "<_io.TextIOWrapper name={} mode={} encoding='utf-8'>",
+                StringRepr(&self.name),
+                StringRepr(&self.mode)
+            )
+        }
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {
+        // File doesn't contain heap references, nothing to do
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.name.capacity() + self.mode.capacity()
+    }
+
+    fn py_call_attr_raw(
+        &mut self,
+        heap: &mut Heap<impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+        interns: &Interns,
+    ) -> RunResult<AttrCallResult> {
+        let method = attr.static_string();
+        let check = match method {
+            Some(StaticStrings::Read | StaticStrings::Readline | StaticStrings::Readlines) => {
+                self.check_open().and_then(|()| self.check_readable())
+            }
+            Some(StaticStrings::Write) => self.check_open().and_then(|()| self.check_writable()),
+            _ => Ok(()),
+        };
+        if let Err(err) = check {
+            args.drop_with_heap(heap);
+            return Err(err);
+        }
+
+        let handle = Value::Int(self.handle);
+        let (function, args) = match method {
+            Some(StaticStrings::Read) => {
+                let size = size_arg(args.get_zero_one_arg("read", heap)?, heap)?;
+                (OsFunction::FileRead, ArgValues::Two(handle, Value::Int(size)))
+            }
+            Some(StaticStrings::Readline) => {
+                let size = size_arg(args.get_zero_one_arg("readline", heap)?, heap)?;
+                (OsFunction::FileReadline, ArgValues::Two(handle, Value::Int(size)))
+            }
+            Some(StaticStrings::Readlines) => {
+                args.check_zero_args("readlines", heap)?;
+                (OsFunction::FileReadlines, ArgValues::One(handle))
+            }
+            Some(StaticStrings::Write) => {
+                let data = args.get_one_arg("write", heap)?;
+                if let Err(err) = check_write_data(&data, self.is_binary(), heap) {
+                    data.drop_with_heap(heap);
+                    return Err(err);
+                }
+                (OsFunction::FileWrite, ArgValues::Two(handle, data))
+            }
+            Some(method @ (StaticStrings::Close | StaticStrings::DunderExit)) => {
+                // `with open(...) as f:` closes the file on exit whatever happened in the block
+                if method == StaticStrings::Close {
+                    args.check_zero_args("close", heap)?;
+                } else {
+                    args.drop_with_heap(heap);
+                }
+                // Closing an already closed file is a no-op, matching CPython
+                if self.closed {
+                    return Ok(AttrCallResult::Value(Value::None));
+                }
+                self.closed = true;
+                return Ok(AttrCallResult::OsCall(OsFunction::FileClose, ArgValues::One(handle)));
+            }
+            _ => {
+                args.drop_with_heap(heap);
+                return Err(ExcType::attribute_error(self.file_type(), attr.as_str(interns)));
+            }
+        };
+        Ok(AttrCallResult::OsCall(function, args))
+    }
+
+    fn py_getattr(
+        &self,
+        attr_id: StringId,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<Option<AttrCallResult>> {
+        let v = match StaticStrings::from_string_id(attr_id) {
+            Some(StaticStrings::Name) => Value::Ref(heap.allocate(HeapData::Str(Str::new(self.name.clone())))?),
+            Some(StaticStrings::Mode) => Value::Ref(heap.allocate(HeapData::Str(Str::new(self.mode.clone())))?),
+            Some(StaticStrings::Closed) => Value::Bool(self.closed),
+            _ => return Err(ExcType::attribute_error(self.file_type(), interns.get_str(attr_id))),
+        };
+        Ok(Some(AttrCallResult::Value(v)))
+    }
+}
+
+/// Implements `file.__enter__()` for `with open(...) as f:`, returning the file itself.
+///
+/// Takes the file's id rather than the file, since it returns a new reference to it.
+pub(crate) fn file_enter(heap: &mut Heap<impl ResourceTracker>, id: HeapId, args: ArgValues) -> RunResult<Value> {
+    args.check_zero_args("__enter__", heap)?;
+    let HeapData::File(file) = heap.get(id) else {
+        unreachable!("file_enter is only called for files")
+    };
+    file.check_open()?;
+    heap.inc_ref(id);
+    Ok(Value::Ref(id))
+}
+
+/// Converts the optional `size` argument of `read()` and `readline()` to an integer.
+///
+/// `None` and a missing argument both mean "read everything" and are passed to the host as -1.
+fn size_arg(size: Option<Value>, heap: &mut Heap<impl ResourceTracker>) -> RunResult<i64> {
+    let Some(size) = size else {
+        return Ok(-1);
+    };
+    defer_drop!(size, heap);
+    match size {
+        Value::None => Ok(-1),
+        Value::Int(n) => Ok(*n),
+        Value::Bool(b) => Ok(i64::from(*b)),
+        other => Err(ExcType::type_error(format!(
+            "argument should be integer or None, not '{}'",
+            other.py_type(heap)
+        ))),
+    }
+}
+
+/// Checks that data passed to `write()` matches the file's mode.
+///
+/// Text files accept only `str` and binary files accept only `bytes`, like CPython.
+fn check_write_data(data: &Value, binary: bool, heap: &Heap<impl ResourceTracker>) -> RunResult<()> {
+    let data_type = data.py_type(heap);
+    if binary {
+        if data_type == Type::Bytes {
+            Ok(())
+        } else {
+            Err(ExcType::type_error(format!(
+                "a bytes-like object is required, not '{data_type}'"
+            )))
+        }
+    } else if data_type == Type::Str {
+        Ok(())
+    } else {
+        Err(ExcType::type_error(format!(
+            "write() argument must be str, not {data_type}"
+        )))
+    }
+}
+
+/// Post-processing the VM applies to the host's result for a file OS call.
+///
+/// Most OS call results are pushed onto the stack unchanged. Two file operations need
+/// the result wrapped first: `open()` turns the host's handle into a [`File`], and
+/// iteration turns the list of lines into an iterator. The VM records which one applies
+/// when it yields and calls [`FileResume::apply`] when the host resumes.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) enum FileResume {
+    /// Wrap the handle returned for `open()` in a `File` with this name and mode.
+    Open { name: String, mode: String },
+    /// Iterate over the list returned for `file.readlines`.
+    Iter,
+}
+
+impl FileResume {
+    /// Returns the post-processing needed for an OS call the VM is about to yield, if any.
+    ///
+    /// Only `open()` is recognised here; iteration is set up directly by the `GetIter` opcode
+    /// since it shares `file.readlines` with the `readlines()` method.
+    pub fn for_os_call(
+        function: OsFunction,
+        args: &ArgValues,
+        heap: &Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> Option<Self> {
+        if function != OsFunction::Open {
+            return None;
+        }
+        let ArgValues::Two(name, mode) = args else {
+            return None;
+        };
+        Some(Self::Open {
+            name: name.as_either_str(heap)?.as_str(interns).to_owned(),
+            mode: mode.as_either_str(heap)?.as_str(interns).to_owned(),
+        })
+    }
+
+    /// Converts the host's result into the value the Python code sees.
+    pub fn apply(self, value: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+        match self {
+            Self::Open { name, mode } => {
+                let Value::Int(handle) = value else {
+                    let value_type = value.py_type(heap);
+                    value.drop_with_heap(heap);
+                    return Err(invalid_handle_error(value_type));
+                };
+                let file = File::new(handle, name, mode);
+                Ok(Value::Ref(heap.allocate(HeapData::File(file))?))
+            }
+            Self::Iter => {
+                let iter = MontyIter::new(value, heap, interns)?;
+                Ok(Value::Ref(heap.allocate(HeapData::Iter(iter))?))
+            }
+        }
+    }
+}
+
+/// Error raised when the host resumes `open()` with something other than an `int` handle.
+fn invalid_handle_error(value_type: Type) -> RunError {
+    SimpleException::new_msg(
+        ExcType::RuntimeError,
+        format!("invalid return type: open() handle must be int, not {value_type}"),
+    )
+    .into()
+}
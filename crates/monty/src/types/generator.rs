@@ -0,0 +1,85 @@
+//! Generator objects created by generator expressions.
+//!
+//! A generator expression compiles to a hidden `<genexpr>` function whose body yields each
+//! item. Calling that function creates a [`Generator`] holding its bound namespace instead of
+//! pushing a frame. Each time the generator is advanced the VM pushes a frame for it, runs
+//! the body up to the next `YieldValue`, and moves the frame's namespace and operand stack
+//! back into the generator, so items are produced one at a time as they are consumed.
+
+use crate::{heap::HeapId, intern::FunctionId, value::Value};
+
+/// How far a generator has run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) enum GeneratorState {
+    /// Created but not yet advanced.
+    New,
+    /// Paused at a `yield`, with its namespace and stack saved in the generator.
+    Suspended,
+    /// Its frame is on the VM's call stack.
+    Running,
+    /// Returned or raised; advancing it yields nothing more.
+    Finished,
+}
+
+/// A generator object from a generator expression.
+///
+/// The namespace has the same layout as a function's: `[params][cell_vars][free_vars][locals]`.
+/// While the generator is running its namespace is registered with the VM's `Namespaces` and
+/// `namespace` and `stack` are empty.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Generator {
+    /// The hidden `<genexpr>` function whose body the generator runs.
+    pub func_id: FunctionId,
+    /// The saved namespace values, which own the references to the frame's cells.
+    pub namespace: Vec<Value>,
+    /// The cells the frame reads and writes through, which the namespace keeps alive.
+    pub frame_cells: Vec<HeapId>,
+    /// The saved operand stack, holding the iterators of the loops the body is inside.
+    pub stack: Vec<Value>,
+    /// Where the frame resumes in the function's bytecode.
+    pub ip: usize,
+    /// Current execution state.
+    pub state: GeneratorState,
+}
+
+impl Generator {
+    /// Creates a generator that will run `func_id` with the pre-bound `namespace`.
+    pub fn new(func_id: FunctionId, namespace: Vec<Value>, frame_cells: Vec<HeapId>) -> Self {
+        Self {
+            func_id,
+            namespace,
+            frame_cells,
+            stack: Vec::new(),
+            ip: 0,
+            state: GeneratorState::New,
+        }
+    }
+
+    /// Returns whether the saved namespace or stack holds any heap references.
+    pub fn has_refs(&self) -> bool {
+        self.namespace
+            .iter()
+            .chain(&self.stack)
+            .any(|v| matches!(v, Value::Ref(_)))
+    }
+
+    /// Collects the heap references held by the saved namespace and stack.
+    pub fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        for value in self.namespace.iter_mut().chain(&mut self.stack) {
+            value.py_dec_ref_ids(stack);
+        }
+    }
+
+    /// Iterates over the saved namespace and stack values, for garbage collection.
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.namespace.iter().chain(&self.stack)
+    }
+
+    /// Estimates the memory used by the generator and its saved state.
+    #[must_use]
+    pub fn estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + (self.namespace.len() + self.stack.len()) * std::mem::size_of::<Value>()
+            + self.frame_cells.len() * std::mem::size_of::<HeapId>()
+    }
+}
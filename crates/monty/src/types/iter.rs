@@ -18,7 +18,7 @@
 //! - Single `get_mut()` call to compute value and advance index
 //! - No additional heap access needed during iteration
 //!
-//! **Multi-phase approach** for complex iterators (IterStr, HeapRef):
+//! **Multi-phase approach** for complex iterators (IterStr, HeapRef, Iterator):
 //! 1. `iter_state()` - reads current state without mutation, returns `Option<IterState>`
 //! 2. Get the value (may access other heap objects like strings or containers)
 //! 3. `advance()` - updates the index after the caller has done its work
@@ -26,6 +26,10 @@
 //! This allows `advance_on_heap()` to coordinate access without extracting
 //! the iterator from the heap (avoiding `std::mem::replace` overhead).
 //!
+//! Iterating over an iterator object (from `iter()`, `map()` or `filter()`) advances
+//! that object in place, item by item, so the items it yields are consumed from it
+//! rather than copied up front.
+//!
 //! ## Builtin Support
 //!
 //! The `iterator_next()` helper implements the `next()` builtin.
@@ -75,7 +79,7 @@ impl MontyIter {
 
         // Check if already an iterator - return self
         if let Value::Ref(id) = &iterable
-            && matches!(heap.get(*id), HeapData::Iter(_))
+            && matches!(heap.get(*id), HeapData::Iter(_) | HeapData::Generator(_))
         {
             // Already an iterator - return it (refcount already correct from caller)
             return Ok(iterable);
@@ -133,6 +137,16 @@ impl MontyIter {
         matches!(self.value, Value::Ref(_))
     }
 
+    /// Estimates the memory size of this iterator, including the copy of an iterated string.
+    #[must_use]
+    pub fn estimate_size(&self) -> usize {
+        let owned = match &self.iter_value {
+            IterValue::IterStr { string, .. } => string.capacity(),
+            _ => 0,
+        };
+        std::mem::size_of::<Self>() + owned
+    }
+
     /// Returns a reference to the underlying value being iterated.
     ///
     /// Used by GC to traverse heap references held by the iterator.
@@ -143,7 +157,7 @@ impl MontyIter {
     /// Returns the current iterator state without mutation.
     ///
     /// This is used by the multi-phase approach in `advance_on_heap()` for complex
-    /// iterator types (IterStr, HeapRef). Simple types (Range, InternBytes, ASCII
+    /// iterator types (IterStr, HeapRef, Iterator). Simple types (Range, InternBytes, ASCII
     /// IterStr) are handled by the fast path and should not call this method.
     ///
     /// Returns `None` if the iterator is exhausted.
@@ -173,13 +187,14 @@ impl MontyIter {
                     })
                 }
             }
+            IterValue::Iterator { iter_id } => Some(IterState::Iterator { iter_id: *iter_id }),
             IterValue::HeapRef {
                 heap_id,
                 len,
                 checks_mutation,
             } => {
                 // For types with captured len, check exhaustion here.
-                // For List (len=None), exhaustion is checked in advance_on_heap().
+                // For List and ByteArray (len=None), exhaustion is checked in advance_on_heap().
                 if let Some(l) = len
                     && self.index >= *l
                 {
@@ -212,6 +227,14 @@ impl MontyIter {
         }
     }
 
+    /// Marks a list or bytearray iterator as exhausted by capturing its current position as
+    /// its length, so items added to the container later are not yielded.
+    fn mark_exhausted(&mut self) {
+        if let IterValue::HeapRef { len, .. } = &mut self.iter_value {
+            *len = Some(self.index);
+        }
+    }
+
     /// Attempts to advance simple iterator types that don't need additional heap access.
     ///
     /// Returns `Some(result)` if handled (Range, InternBytes, ASCII IterStr),
@@ -259,7 +282,7 @@ impl MontyIter {
                     Some(Ok(Some(Value::Int(i64::from(bytes[i])))))
                 }
             }
-            IterValue::HeapRef { .. } => None,
+            IterValue::HeapRef { .. } | IterValue::Iterator { .. } => None,
         }
     }
 
@@ -328,11 +351,20 @@ impl MontyIter {
                 let item = get_heap_item(heap, *heap_id, i, expected_len)?;
                 // Check for list exhaustion (list can shrink during iteration)
                 let Some(item) = item else {
+                    // Like CPython, the iterator stays exhausted even if the list grows again
+                    *len = Some(i);
                     return Ok(None);
                 };
                 self.index += 1;
                 Ok(Some(clone_and_inc_ref(item, heap)))
             }
+            IterValue::Iterator { iter_id } => {
+                let item = advance_on_heap(heap, *iter_id, interns)?;
+                if item.is_some() {
+                    self.index += 1;
+                }
+                Ok(item)
+            }
         }
     }
 
@@ -345,14 +377,20 @@ impl MontyIter {
         let len = match &self.iter_value {
             IterValue::Range { len, .. } | IterValue::IterStr { len, .. } | IterValue::InternBytes { len, .. } => *len,
             IterValue::HeapRef { heap_id, len, .. } => {
-                // For List (len=None), check current length dynamically
-                len.unwrap_or_else(|| {
-                    let HeapData::List(list) = heap.get(*heap_id) else {
-                        panic!("HeapRef with len=None should only be List")
-                    };
-                    list.len()
+                // For List and ByteArray (len=None), check current length dynamically
+                len.unwrap_or_else(|| match heap.get(*heap_id) {
+                    HeapData::List(list) => list.len(),
+                    HeapData::ByteArray(b) => b.as_slice().len(),
+                    _ => panic!("HeapRef with len=None should only be List or ByteArray"),
                 })
             }
+            // The inner iterator tracks its own position
+            IterValue::Iterator { iter_id } => {
+                let HeapData::Iter(iter) = heap.get(*iter_id) else {
+                    panic!("Iterator should refer to an iterator on the heap");
+                };
+                return iter.size_hint(heap);
+            }
         };
         len.saturating_sub(self.index)
     }
@@ -442,6 +480,10 @@ pub(crate) fn advance_on_heap(
             let item = get_heap_item(heap, heap_id, index, expected_len)?;
             // Check for list exhaustion (list can shrink during iteration)
             let Some(item) = item else {
+                let HeapData::Iter(iter) = heap.get_mut(iter_id) else {
+                    panic!("advance_on_heap: expected Iterator on heap");
+                };
+                iter.mark_exhausted();
                 return Ok(None);
             };
             // Inc refcount after borrow ends
@@ -450,6 +492,13 @@ pub(crate) fn advance_on_heap(
             }
             (item, None)
         }
+        IterState::Iterator { iter_id } => {
+            // The inner iterator hands back an owned value
+            let Some(item) = advance_on_heap(heap, iter_id, interns)? else {
+                return Ok(None);
+            };
+            (item, None)
+        }
     };
 
     // Phase 3: Advance the iterator
@@ -493,6 +542,8 @@ fn get_heap_item(
             ))
         }
         HeapData::Bytes(bytes) => Ok(Some(Value::Int(i64::from(bytes.as_slice()[index])))),
+        // Like lists, bytearrays may shrink during iteration
+        HeapData::ByteArray(b) => Ok(b.as_slice().get(index).map(|&byte| Value::Int(i64::from(byte)))),
         HeapData::Set(set) => {
             // Check for set mutation
             if let Some(expected) = expected_len
@@ -540,38 +591,28 @@ pub fn iterator_next(
     heap: &mut Heap<impl ResourceTracker>,
     interns: &Interns,
 ) -> RunResult<Value> {
-    let Value::Ref(iter_id) = iter_value else {
-        // Not a heap value - can't be an iterator
-        if let Some(d) = default {
-            d.drop_with_heap(heap);
-        }
-        return Err(ExcType::type_error_not_iterable(iter_value.py_type(heap)));
-    };
-
     // Check that it's actually an iterator
-    if !matches!(heap.get(*iter_id), HeapData::Iter(_)) {
-        if let Some(d) = default {
-            d.drop_with_heap(heap);
+    let iter_id = match iter_value {
+        Value::Ref(iter_id) if matches!(heap.get(*iter_id), HeapData::Iter(_)) => *iter_id,
+        _ => {
+            default.drop_with_heap(heap);
+            let data_type = iter_value.py_type(heap);
+            return Err(ExcType::type_error(format!("'{data_type}' object is not an iterator")));
         }
-        let data_type = heap.get(*iter_id).py_type(heap);
-        return Err(ExcType::type_error(format!("'{data_type}' object is not an iterator")));
-    }
+    };
 
     // Get next item using the MontyIter::advance_on_heap method
-    match advance_on_heap(heap, *iter_id, interns)? {
-        Some(item) => {
+    match advance_on_heap(heap, iter_id, interns) {
+        Ok(Some(item)) => {
             // Drop default if provided since we don't need it
-            if let Some(d) = default {
-                d.drop_with_heap(heap);
-            }
+            default.drop_with_heap(heap);
             Ok(item)
         }
-        None => {
-            // Iterator exhausted
-            match default {
-                Some(d) => Ok(d),
-                None => Err(ExcType::stop_iteration()),
-            }
+        // Iterator exhausted
+        Ok(None) => default.ok_or_else(ExcType::stop_iteration),
+        Err(e) => {
+            default.drop_with_heap(heap);
+            Err(e)
         }
     }
 }
@@ -597,6 +638,8 @@ enum IterState {
         index: usize,
         expected_len: Option<usize>,
     },
+    /// Iterator object that yields the next item when advanced itself.
+    Iterator { iter_id: HeapId },
 }
 
 /// Increments the reference count for a value copied via `copy_for_extend()`.
@@ -646,8 +689,9 @@ enum IterValue {
     InternBytes { bytes_id: BytesId, len: usize },
     /// Iterating over a heap-allocated container (List, Tuple, NamedTuple, Dict, Bytes, Set, FrozenSet).
     ///
-    /// - `len`: `None` for List (checked dynamically since lists can mutate during iteration),
-    ///   `Some(n)` for other types (captured at construction for exhaustion checking).
+    /// - `len`: `None` for List and ByteArray (checked dynamically since they can mutate during
+    ///   iteration) until they are exhausted, `Some(n)` for other types (captured at construction
+    ///   for exhaustion checking).
     /// - `checks_mutation`: `true` for Dict/Set (raises RuntimeError if size changes),
     ///   `false` for other types.
     HeapRef {
@@ -655,6 +699,10 @@ enum IterValue {
         len: Option<usize>,
         checks_mutation: bool,
     },
+    /// Iterating over another iterator object, such as the result of `iter()`, `map()` or `filter()`.
+    ///
+    /// Each step advances that iterator in place, so items consumed here are consumed from it too.
+    Iterator { iter_id: HeapId },
 }
 
 impl IterValue {
@@ -708,6 +756,12 @@ impl IterValue {
                 len: None,
                 checks_mutation: false,
             }),
+            // ByteArray: mutable like List, so also checked dynamically
+            HeapData::ByteArray(_) => Some(Self::HeapRef {
+                heap_id,
+                len: None,
+                checks_mutation: false,
+            }),
             // Tuple/NamedTuple/Bytes/FrozenSet: captured len, no mutation check
             HeapData::Tuple(tuple) => Some(Self::HeapRef {
                 heap_id,
@@ -744,19 +798,27 @@ impl IterValue {
             HeapData::Str(s) => Some(Self::from_str(s.as_str())),
             // Range: copy values for iteration
             HeapData::Range(range) => Some(Self::from_range(range)),
-            // Closures, FunctionDefaults, Cells, Exceptions, Dataclasses, Iterators, LongInts, Slices, Modules,
-            // Paths, and async types are not iterable
+            // Iterator: advanced in place
+            HeapData::Iter(_) => Some(Self::Iterator { iter_id: heap_id }),
+            // Closures, FunctionDefaults, Cells, Exceptions, Dataclasses, LongInts, Slices, Modules, Paths,
+            // regex objects, and async types are not iterable. Files are iterated by the `GetIter` opcode, which
+            // needs the host to read their lines first, and generators by the VM, which runs their frames
             HeapData::Closure(_, _, _)
             | HeapData::FunctionDefaults(_, _)
             | HeapData::Cell(_)
             | HeapData::Exception(_)
             | HeapData::Dataclass(_)
-            | HeapData::Iter(_)
             | HeapData::LongInt(_)
             | HeapData::Slice(_)
             | HeapData::Module(_)
             | HeapData::Path(_)
+            | HeapData::File(_)
+            | HeapData::RePattern(_)
+            | HeapData::ReMatch(_)
+            | HeapData::Getter(_)
+            | HeapData::ContextManager(_)
             | HeapData::Coroutine(_)
+            | HeapData::Generator(_)
             | HeapData::GatherFuture(_) => None,
         }
     }
@@ -6,11 +6,9 @@ use smallvec::SmallVec;
 use super::{MontyIter, PyTrait};
 use crate::{
     args::ArgValues,
-    builtins::Builtins,
-    exception_private::{ExcType, RunError, RunResult},
+    exception_private::{ExcType, RunResult},
     heap::{DropWithHeap, Heap, HeapData, HeapId},
     intern::{Interns, StaticStrings},
-    io::PrintWriter,
     resource::{DepthGuard, ResourceError, ResourceTracker},
     types::Type,
     value::{EitherStr, Value},
@@ -34,9 +32,8 @@ use crate::{
 /// - `reverse()` - Reverse in place
 /// - `sort([key][, reverse])` - Sort in place
 ///
-/// Note: `sort(key=...)` supports builtin key functions (len, abs, etc.)
-/// but not user-defined functions. This is handled at VM level for access
-/// to function calling machinery.
+/// Note: `sort()` is handled at VM level so its key function can be defined
+/// in Python.
 ///
 /// All list methods from Python's builtins are implemented.
 ///
@@ -206,7 +203,7 @@ impl PyTrait for List {
     }
 
     fn py_estimate_size(&self) -> usize {
-        std::mem::size_of::<Self>() + self.items.len() * std::mem::size_of::<Value>()
+        std::mem::size_of::<Self>() + self.items.capacity() * std::mem::size_of::<Value>()
     }
 
     fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
@@ -248,39 +245,16 @@ impl PyTrait for List {
         heap: &mut Heap<impl ResourceTracker>,
         _interns: &Interns,
     ) -> RunResult<()> {
-        // Extract integer index, accepting Int, Bool (True=1, False=0), and LongInt.
-        // Note: The LongInt-to-i64 conversion is defensive code. In normal execution,
-        // heap-allocated LongInt values always exceed i64 range because into_value()
-        // demotes i64-fitting values to Value::Int. However, this could be reached via
-        // deserialization of crafted snapshot data.
-        let index = match &key {
-            Value::Int(i) => *i,
-            Value::Bool(b) => i64::from(*b),
-            Value::Ref(heap_id) => {
-                if let HeapData::LongInt(li) = heap.get(*heap_id) {
-                    if let Some(i) = li.to_i64() {
-                        i
-                    } else {
-                        key.drop_with_heap(heap);
-                        value.drop_with_heap(heap);
-                        return Err(ExcType::index_error_int_too_large());
-                    }
-                } else {
-                    let key_type = key.py_type(heap);
-                    key.drop_with_heap(heap);
-                    value.drop_with_heap(heap);
-                    return Err(ExcType::type_error_list_assignment_indices(key_type));
-                }
-            }
-            _ => {
-                let key_type = key.py_type(heap);
-                key.drop_with_heap(heap);
+        // Extract integer index, accepting Int, Bool (True=1, False=0), and LongInt
+        let index = key.as_index(heap, Type::List);
+        key.drop_with_heap(heap);
+        let index = match index {
+            Ok(index) => index,
+            Err(err) => {
                 value.drop_with_heap(heap);
-                return Err(ExcType::type_error_list_assignment_indices(key_type));
+                return Err(err);
             }
         };
-        // Drop the key after extracting the index (Int and Bool are not ref-counted)
-        key.drop_with_heap(heap);
 
         // Normalize negative indices (Python-style: -1 = last element)
         let len = i64::try_from(self.items.len()).expect("list length exceeds i64::MAX");
@@ -766,233 +740,72 @@ fn normalize_list_index(index: i64, len: usize) -> usize {
     }
 }
 
-/// Performs an in-place sort on a list with optional key function and reverse flag.
-///
-/// This is called from the VM's `call_attr` when `list.sort()` is invoked.
-/// The function lives here (rather than in VM) to keep list-related logic together,
-/// with the VM only passing through its resources.
+/// Sorts `items` in place, comparing `keys` instead of the items themselves when given.
 ///
-/// Uses a staged approach to avoid borrow checker issues:
-/// 1. Parse and validate arguments
-/// 2. Extract items from the list (temporarily empties it)
-/// 3. Compute key values if a key function is provided
-/// 4. Sort indices based on items or key values
-/// 5. Rearrange items in sorted order and put back into the list
+/// Shared by `list.sort()` and `sorted()`, which compute the keys in the VM since the
+/// key function may be defined in Python. `keys[i]` is the key for `items[i]`.
 ///
-/// # Arguments
-/// * `list_id` - The heap ID of the list to sort
-/// * `args` - The method arguments (keyword-only: `key` and `reverse`)
-/// * `heap` - The heap for memory management
-/// * `interns` - Interned strings for comparisons
-/// * `print_writer` - Output writer (needed for builtin function calls)
-pub(crate) fn do_list_sort(
-    list_id: HeapId,
-    args: ArgValues,
+/// The sort is stable, and `reverse` keeps equal elements in their original order, matching
+/// CPython. Like CPython, it only asks whether one value is less than another, so NaN
+/// (which is less than nothing) leaves some order rather than raising. If two values can't
+/// be compared the error is returned and `items` is left in its original order.
+pub(crate) fn sort_values(
+    items: &mut Vec<Value>,
+    keys: Option<&[Value]>,
+    reverse: bool,
     heap: &mut Heap<impl ResourceTracker>,
     interns: &Interns,
-    print_writer: &mut impl PrintWriter,
-) -> Result<(), RunError> {
-    // Parse keyword-only arguments: key and reverse
-    let (key_arg, reverse_arg) = args.extract_two_kwargs_only("list.sort", "key", "reverse", heap, interns)?;
-
-    // Convert reverse to bool (default false)
-    let reverse = if let Some(v) = reverse_arg {
-        let result = v.py_bool(heap, interns);
-        v.drop_with_heap(heap);
-        result
-    } else {
-        false
-    };
-
-    // Handle key function (None means no key function)
-    let key_fn = match key_arg {
-        Some(v) if matches!(v, Value::None) => {
-            v.drop_with_heap(heap);
-            None
-        }
-        other => other,
-    };
-
-    // Step 1: Extract items from the list (temporarily empties it)
-    let mut items: Vec<Value> = {
-        let HeapData::List(list) = heap.get_mut(list_id) else {
-            if let Some(k) = key_fn {
-                k.drop_with_heap(heap);
-            }
-            return Err(RunError::internal("expected list in do_list_sort"));
-        };
-        list.as_vec_mut().drain(..).collect()
-    };
-
-    // Step 2: Compute key values if key function provided
-    let key_values: Option<Vec<Value>> = if let Some(ref key) = key_fn {
-        let mut keys: Vec<Value> = Vec::with_capacity(items.len());
-        for item in &items {
-            let elem = item.clone_with_heap(heap);
-            match call_key_function(key, elem, heap, interns, print_writer) {
-                Ok(key_value) => keys.push(key_value),
-                Err(e) => {
-                    // Clean up and restore items to list on error
-                    for k in keys {
-                        k.drop_with_heap(heap);
-                    }
-                    if let Some(k) = key_fn {
-                        k.drop_with_heap(heap);
-                    }
-                    // Restore items to the list
-                    if let HeapData::List(list) = heap.get_mut(list_id) {
-                        for item in items {
-                            list.as_vec_mut().push(item);
-                        }
-                    }
-                    return Err(e);
-                }
-            }
-        }
-        Some(keys)
-    } else {
-        None
-    };
-
-    // Drop the key function - we're done with it
-    if let Some(k) = key_fn {
-        k.drop_with_heap(heap);
-    }
-
-    // Step 3: Sort indices based on items or key values
-    let len = items.len();
-    let mut indices: Vec<usize> = (0..len).collect();
-    let mut sort_error: Option<RunError> = None;
-    // Create a guard for py_cmp calls. We use a RefCell to allow mutable borrows inside the closure.
-    let guard = std::cell::RefCell::new(DepthGuard::default());
-
-    if let Some(ref keys) = key_values {
-        indices.sort_by(|&a, &b| {
-            if sort_error.is_some() {
-                return Ordering::Equal;
-            }
-            match keys[a].py_cmp(&keys[b], heap, &mut guard.borrow_mut(), interns) {
-                Ok(Some(ord)) => {
-                    if reverse {
-                        ord.reverse()
-                    } else {
-                        ord
-                    }
-                }
-                Ok(None) => {
-                    sort_error = Some(ExcType::type_error(format!(
-                        "'<' not supported between instances of '{}' and '{}'",
-                        keys[a].py_type(heap),
-                        keys[b].py_type(heap)
-                    )));
-                    Ordering::Equal
-                }
-                Err(e) => {
-                    sort_error = Some(e.into());
-                    Ordering::Equal
-                }
-            }
-        });
-    } else {
-        indices.sort_by(|&a, &b| {
-            if sort_error.is_some() {
-                return Ordering::Equal;
-            }
-            match items[a].py_cmp(&items[b], heap, &mut guard.borrow_mut(), interns) {
-                Ok(Some(ord)) => {
-                    if reverse {
-                        ord.reverse()
-                    } else {
-                        ord
-                    }
-                }
-                Ok(None) => {
-                    sort_error = Some(ExcType::type_error(format!(
-                        "'<' not supported between instances of '{}' and '{}'",
-                        items[a].py_type(heap),
-                        items[b].py_type(heap)
-                    )));
-                    Ordering::Equal
-                }
-                Err(e) => {
-                    sort_error = Some(e.into());
-                    Ordering::Equal
-                }
-            }
-        });
-    }
-
-    // Clean up key values
-    if let Some(keys) = key_values {
-        for k in keys {
-            k.drop_with_heap(heap);
-        }
-    }
-
-    // Check for sort error
-    if let Some(err) = sort_error {
-        // Restore items to list before returning error
-        if let HeapData::List(list) = heap.get_mut(list_id) {
-            for item in items {
-                list.as_vec_mut().push(item);
-            }
-        }
-        return Err(err);
-    }
-
-    // Step 4: Rearrange items in sorted order using index permutation
-    let mut sorted_items: Vec<Value> = Vec::with_capacity(len);
-    for &i in &indices {
-        // Move the value out, replacing with Undefined as placeholder
-        sorted_items.push(std::mem::replace(&mut items[i], Value::Undefined));
-    }
-
-    // Put sorted items back into the list
-    let HeapData::List(list) = heap.get_mut(list_id) else {
-        return Err(RunError::internal("expected list in do_list_sort"));
-    };
-
-    for item in sorted_items {
-        list.as_vec_mut().push(item);
-    }
-
-    // items now contains Undefined values - no cleanup needed
+) -> RunResult<()> {
+    // Sort indices rather than values so the keys don't have to move with the items
+    let compared = keys.unwrap_or(items.as_slice());
+    let mut guard = DepthGuard::default();
+    let indices = merge_sort_indices(compared.len(), |a, b| {
+        let (left, right) = if reverse { (b, a) } else { (a, b) };
+        let ordering = compared[left].py_cmp_for_op(&compared[right], "<", heap, &mut guard, interns)?;
+        Ok(ordering == Some(Ordering::Less))
+    })?;
+
+    // Rearrange items in sorted order using the index permutation
+    let mut slots: Vec<Option<Value>> = items.drain(..).map(Some).collect();
+    items.extend(
+        indices
+            .into_iter()
+            .map(|i| slots[i].take().expect("sort index used twice")),
+    );
     Ok(())
 }
 
-/// Calls a key function on a single element for sorting.
+/// Returns the permutation of `0..len` that stably sorts by `less(a, b)` ("`a` sorts before `b`").
 ///
-/// Currently supports builtin functions directly. User-defined functions return
-/// an error since they would require VM frame management for proper execution.
-fn call_key_function(
-    key_fn: &Value,
-    elem: Value,
-    heap: &mut Heap<impl ResourceTracker>,
-    interns: &Interns,
-    print_writer: &mut impl PrintWriter,
-) -> Result<Value, RunError> {
-    match key_fn {
-        Value::Builtin(Builtins::Function(builtin)) => {
-            let args = ArgValues::One(elem);
-            builtin.call(heap, args, interns, print_writer)
-        }
-        Value::Builtin(Builtins::Type(t)) => {
-            // Type constructors (int, str, float, etc.) are callable key functions
-            let args = ArgValues::One(elem);
-            t.call(heap, args, interns)
-        }
-        Value::DefFunction(_) | Value::ExtFunction(_) | Value::Ref(_) => {
-            // User-defined or external functions require VM frame management
-            elem.drop_with_heap(heap);
-            Err(ExcType::type_error(
-                "list.sort() key argument must be a builtin function (user-defined functions not yet supported)",
-            ))
-        }
-        _ => {
-            elem.drop_with_heap(heap);
-            Err(ExcType::type_error("list.sort() key must be callable or None"))
+/// This is a bottom-up merge sort rather than `slice::sort_by`, which may panic when the
+/// comparison isn't a total order, as with NaN. The first error from `less` is returned.
+fn merge_sort_indices(len: usize, mut less: impl FnMut(usize, usize) -> RunResult<bool>) -> RunResult<Vec<usize>> {
+    let mut indices: Vec<usize> = (0..len).collect();
+    let mut merged: Vec<usize> = Vec::with_capacity(len);
+    let mut width = 1;
+    while width < len {
+        merged.clear();
+        for start in (0..len).step_by(2 * width) {
+            let mid = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+            let (mut i, mut j) = (start, mid);
+            while i < mid && j < end {
+                // Take from the right run only if strictly smaller, keeping the sort stable
+                if less(indices[j], indices[i])? {
+                    merged.push(indices[j]);
+                    j += 1;
+                } else {
+                    merged.push(indices[i]);
+                    i += 1;
+                }
+            }
+            merged.extend_from_slice(&indices[i..mid]);
+            merged.extend_from_slice(&indices[j..end]);
         }
+        std::mem::swap(&mut indices, &mut merged);
+        width *= 2;
     }
+    Ok(indices)
 }
 
 /// Writes a formatted sequence of values to a formatter.
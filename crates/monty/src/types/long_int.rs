@@ -9,37 +9,45 @@
 //! having freestanding functions scattered across the codebase.
 
 use std::{
-    collections::hash_map::DefaultHasher,
+    cmp::Ordering,
     fmt::{self, Display},
-    hash::{Hash, Hasher},
     ops::{Add, Mul, Neg, Sub},
+    sync::OnceLock,
 };
 
 use num_bigint::BigInt;
-use num_traits::{Signed, ToPrimitive, Zero};
+use num_traits::{FromPrimitive, Signed, ToPrimitive, Zero};
 
 use crate::{
+    exception_private::{ExcType, RunResult},
     heap::{Heap, HeapData},
     resource::{ResourceError, ResourceTracker},
-    value::Value,
+    value::{Value, bigint_hash},
 };
 
 /// Wrapper around `num_bigint::BigInt` for arbitrary precision integers.
 ///
 /// Named `LongInt` to avoid confusion with the external `BigInt` type from `num_bigint`.
-/// The inner `BigInt` is accessible via `.0` for arithmetic operations that need direct
-/// access to the underlying type.
+/// The inner `BigInt` is read through [`LongInt::inner`], so nothing outside this module
+/// can replace it and leave a stale decimal cache behind.
 ///
 /// Python treats all integers as one type - we use `Value::Int(i64)` for values that fit
 /// and `LongInt` for larger values. The `into_value()` method automatically demotes to
 /// i64 when the value fits, maintaining this optimization.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
-pub struct LongInt(pub BigInt);
+///
+/// The second field caches the decimal string, computed the first time the value is
+/// formatted. Converting a huge integer to decimal is quadratic in its size, so printing
+/// the same value repeatedly would otherwise redo that work every time. A `LongInt` is
+/// never mutated after creation, so the cache can't go stale. It isn't serialized.
+/// `estimate_size()` charges for it up front, since it's filled in without access to the
+/// heap's resource tracker.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LongInt(BigInt, #[serde(skip)] OnceLock<Box<str>>);
 
 impl LongInt {
     /// Creates a new `LongInt` from a `BigInt`.
     pub fn new(bi: BigInt) -> Self {
-        Self(bi)
+        Self(bi, OnceLock::new())
     }
 
     /// Converts to a `Value`, demoting to i64 if it fits.
@@ -57,43 +65,82 @@ impl LongInt {
         }
     }
 
-    /// Computes a hash consistent with i64 hashing.
+    /// Converts a float to an `int` value by truncating toward zero, like `int(x)`.
     ///
-    /// Critical: For values that fit in i64, this must return the same hash as
-    /// hashing the i64 directly. This ensures dict key consistency - e.g.,
-    /// `hash(5)` must equal `hash(LongInt(5))`.
-    pub fn hash(&self) -> u64 {
-        // If the LongInt fits in i64, hash as i64 for consistency
-        if let Some(i) = self.0.to_i64() {
-            let mut hasher = DefaultHasher::new();
-            // Hash the i64 discriminant and value to match Value::Int hashing
-            std::mem::discriminant(&Value::Int(0)).hash(&mut hasher);
-            i.hash(&mut hasher);
-            hasher.finish()
+    /// Returns `Value::Int` when the result fits in i64, otherwise a heap `LongInt`.
+    ///
+    /// # Errors
+    /// Returns `ValueError` for NaN and `OverflowError` for infinities, matching CPython.
+    pub fn from_f64(value: f64, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
+        if value.is_nan() {
+            return Err(ExcType::value_error_float_nan_to_int());
+        }
+        if value.is_infinite() {
+            return Err(ExcType::overflow_error_float_inf_to_int());
+        }
+        let bi = BigInt::from_f64(value.trunc()).expect("finite float converts to BigInt");
+        Self::new(bi).into_value(heap).map_err(Into::into)
+    }
+
+    /// Returns whether this integer equals the float `f` exactly.
+    ///
+    /// Like CPython, the comparison doesn't round the integer to a float, so `2**53 + 1`
+    /// doesn't equal `2.0**53`.
+    pub fn eq_float(&self, f: f64) -> bool {
+        f.fract() == 0.0 && BigInt::from_f64(f).is_some_and(|i| i == self.0)
+    }
+
+    /// Compares this integer with the float `f` exactly, or returns `None` if `f` is NaN.
+    ///
+    /// Like [`Self::eq_float`], the integer isn't rounded to a float, so `2**53 + 1`
+    /// is greater than `2.0**53`.
+    pub fn cmp_float(&self, f: f64) -> Option<Ordering> {
+        if f.is_nan() {
+            return None;
+        }
+        if f.is_infinite() {
+            return Some(if f > 0.0 { Ordering::Less } else { Ordering::Greater });
+        }
+        // Compare against the float's integer part; a fractional part only matters on a tie
+        let floor = f.floor();
+        let ordering = BigInt::from_f64(floor).map_or(Ordering::Equal, |whole| self.0.cmp(&whole));
+        if ordering == Ordering::Equal && floor != f {
+            Some(Ordering::Less)
         } else {
-            // For LongInts outside i64 range, use byte representation
-            let mut hasher = DefaultHasher::new();
-            // Use a unique discriminant for LongInt (we use the LongInt's sign and bytes)
-            let (sign, bytes) = self.0.to_bytes_le();
-            sign.hash(&mut hasher);
-            bytes.hash(&mut hasher);
-            hasher.finish()
+            Some(ordering)
         }
     }
 
+    /// Computes a hash consistent with i64 hashing.
+    ///
+    /// Critical: numbers that compare equal must hash equal for dict key consistency -
+    /// e.g. `hash(5)` must equal `hash(LongInt(5))`, and `hash(2**70)` must equal
+    /// `hash(2.0**70)`. See [`bigint_hash`].
+    pub fn hash(&self) -> u64 {
+        bigint_hash(&self.0)
+    }
+
     /// Estimates memory size in bytes.
     ///
     /// Used for resource tracking. The actual size includes the Vec overhead
     /// plus the digit storage. Rounds up bits to bytes to avoid underestimating
     /// (e.g., 1 bit = 1 byte, not 0 bytes).
+    ///
+    /// Also includes the largest the cached decimal string can be, so the cache is
+    /// charged whether or not the value is ever formatted.
     pub fn estimate_size(&self) -> usize {
         // Each BigInt digit is typically a u32 or u64
         // We estimate based on the number of significant bits
         let bits = self.0.bits();
         // Convert bits to bytes (round up), add overhead for Vec and sign
         // On 32-bit platforms, truncate to usize::MAX if bits is too large
-        let bit_bytes = usize::try_from(bits).unwrap_or(usize::MAX).saturating_add(7) / 8;
-        bit_bytes + std::mem::size_of::<BigInt>()
+        let bits = usize::try_from(bits).unwrap_or(usize::MAX);
+        let bit_bytes = bits.saturating_add(7) / 8;
+        // Each decimal digit holds log2(10) > 3 bits, plus one byte for the sign
+        let decimal_bytes = bits / 3 + 2;
+        bit_bytes
+            .saturating_add(decimal_bytes)
+            .saturating_add(std::mem::size_of::<BigInt>() + std::mem::size_of::<Box<str>>())
     }
 
     /// Returns a reference to the inner `BigInt`.
@@ -146,7 +193,7 @@ impl LongInt {
 
     /// Returns the absolute value as a new `LongInt`.
     pub fn abs(&self) -> Self {
-        Self(self.0.abs())
+        Self::new(self.0.abs())
     }
 
     /// Returns the number of significant bits in this LongInt.
@@ -156,19 +203,24 @@ impl LongInt {
     pub fn bits(&self) -> u64 {
         self.0.bits()
     }
+
+    /// Returns the decimal representation, computing it only on first use.
+    pub fn decimal(&self) -> &str {
+        self.1.get_or_init(|| self.0.to_string().into_boxed_str())
+    }
 }
 
 // === Trait Implementations ===
 
 impl From<BigInt> for LongInt {
     fn from(bi: BigInt) -> Self {
-        Self(bi)
+        Self::new(bi)
     }
 }
 
 impl From<i64> for LongInt {
     fn from(i: i64) -> Self {
-        Self(BigInt::from(i))
+        Self::new(BigInt::from(i))
     }
 }
 
@@ -176,7 +228,7 @@ impl Add for LongInt {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
+        Self::new(self.0 + rhs.0)
     }
 }
 
@@ -184,7 +236,7 @@ impl Sub for LongInt {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
+        Self::new(self.0 - rhs.0)
     }
 }
 
@@ -192,7 +244,7 @@ impl Mul for LongInt {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        Self(self.0 * rhs.0)
+        Self::new(self.0 * rhs.0)
     }
 }
 
@@ -200,12 +252,33 @@ impl Neg for LongInt {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Self(-self.0)
+        Self::new(-self.0)
+    }
+}
+
+// The cached decimal string is derived from the value, so only the value takes part in comparisons
+impl PartialEq for LongInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for LongInt {}
+
+impl PartialOrd for LongInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LongInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
     }
 }
 
 impl Display for LongInt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        f.write_str(self.decimal())
     }
 }
@@ -5,36 +5,48 @@
 ///
 /// The `AbstractValue` trait provides a common interface for all heap-allocated
 /// types, enabling efficient dispatch via `enum_dispatch`.
+pub mod bytearray;
 pub mod bytes;
+pub mod contextlib;
 pub mod dataclass;
 pub mod dict;
+pub mod file;
+pub mod generator;
 pub mod iter;
 pub mod list;
 pub mod long_int;
 pub mod module;
 pub mod namedtuple;
+pub mod operator;
 pub mod path;
 pub mod property;
 pub mod py_trait;
 pub mod range;
+pub mod re;
 pub mod set;
 pub mod slice;
 pub mod str;
 pub mod tuple;
 pub mod r#type;
 
+pub(crate) use bytearray::ByteArray;
 pub(crate) use bytes::Bytes;
+pub(crate) use contextlib::ContextManager;
 pub(crate) use dataclass::Dataclass;
 pub(crate) use dict::Dict;
+pub(crate) use file::File;
+pub(crate) use generator::Generator;
 pub(crate) use iter::MontyIter;
 pub(crate) use list::List;
 pub(crate) use long_int::LongInt;
 pub(crate) use module::Module;
 pub(crate) use namedtuple::NamedTuple;
+pub(crate) use operator::Getter;
 pub(crate) use path::Path;
 pub(crate) use property::Property;
 pub(crate) use py_trait::{AttrCallResult, PyTrait};
 pub(crate) use range::Range;
+pub(crate) use re::{ReMatch, RePattern};
 pub(crate) use set::{FrozenSet, Set};
 pub(crate) use slice::Slice;
 pub(crate) use str::Str;
@@ -166,11 +166,8 @@ impl PyTrait for NamedTuple {
     }
 
     fn py_getitem(&self, key: &Value, heap: &mut Heap<impl ResourceTracker>, _interns: &Interns) -> RunResult<Value> {
-        // Extract integer index from key, returning TypeError if not an int
-        let index = match key {
-            Value::Int(i) => *i,
-            _ => return Err(ExcType::type_error_indices(Type::NamedTuple, key.py_type(heap))),
-        };
+        // Named tuples are tuples, so they share the tuple error message
+        let index = key.as_index(heap, Type::Tuple)?;
 
         // Get by index with bounds checking
         match self.get_by_index(index) {
@@ -0,0 +1,231 @@
+//! Callables created by the `operator` module: `itemgetter` and `attrgetter`.
+//!
+//! These are mostly used as `key` functions, e.g. `sorted(pairs, key=itemgetter(1))` or
+//! `max(points, key=attrgetter('x'))`. Calling one subscripts or looks up attributes on the
+//! argument; with several items or attributes the results are returned as a tuple.
+
+use std::fmt::Write;
+
+use ahash::AHashSet;
+
+use crate::{
+    args::ArgValues,
+    defer_drop, defer_drop_mut,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::Interns,
+    resource::{DepthGuard, ResourceError, ResourceTracker},
+    types::{AttrCallResult, PyTrait, Type, allocate_tuple, str::StringRepr, tuple::TupleVec},
+    value::{EitherStr, Value},
+};
+
+/// An `operator.itemgetter` or `operator.attrgetter` object.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) enum Getter {
+    /// `itemgetter(*items)`: subscripts the argument with each item.
+    Item(Vec<Value>),
+    /// `attrgetter(*attrs)`: looks up each attribute, a dotted name like `'a.b'` being split
+    /// into its parts.
+    ///
+    /// The original names are kept for `repr()`. Parts are interned when possible; a part
+    /// that isn't interned can't be an attribute of anything, so looking it up always fails.
+    Attr(Vec<(String, Vec<EitherStr>)>),
+}
+
+impl Getter {
+    /// Creates an `attrgetter` from its arguments, which must all be strings.
+    pub fn attrgetter(names: Vec<Value>, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Self> {
+        defer_drop!(names, heap);
+        if names.is_empty() {
+            return Err(ExcType::type_error("attrgetter expected 1 argument, got 0"));
+        }
+        let mut attrs = Vec::with_capacity(names.len());
+        for name in names {
+            let Some(name) = name.as_either_str(heap) else {
+                return Err(ExcType::type_error("attribute name must be a string"));
+            };
+            let name = name.as_str(interns).to_owned();
+            let parts = name
+                .split('.')
+                .map(|part| match interns.find_str(part) {
+                    Some(id) => EitherStr::Interned(id),
+                    None => EitherStr::Heap(part.to_owned()),
+                })
+                .collect();
+            attrs.push((name, parts));
+        }
+        Ok(Self::Attr(attrs))
+    }
+
+    /// The name of the getter's type, used in error messages.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Item(_) => "itemgetter",
+            Self::Attr(_) => "attrgetter",
+        }
+    }
+
+    /// Returns whether the getter holds any heap references.
+    pub fn has_refs(&self) -> bool {
+        match self {
+            Self::Item(items) => items.iter().any(|item| matches!(item, Value::Ref(_))),
+            Self::Attr(_) => false,
+        }
+    }
+}
+
+impl PyTrait for Getter {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        match self {
+            Self::Item(_) => Type::ItemGetter,
+            Self::Attr(_) => Type::AttrGetter,
+        }
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        _other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Getters compare by identity, which is handled at the Value level
+        Ok(false)
+    }
+
+    fn py_bool(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        heap: &Heap<impl ResourceTracker>,
+        heap_ids: &mut AHashSet<HeapId>,
+        guard: &mut DepthGuard,
+        interns: &Interns,
+    ) -> std::fmt::Result {
+        write!(f, "operator.{}(", self.name())?;
+        match self {
+            Self::Item(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    item.py_repr_fmt(f, heap, heap_ids, guard, interns)?;
+                }
+            }
+            Self::Attr(attrs) => {
+                for (i, (name, _)) in attrs.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", StringRepr(name))?;
+                }
+            }
+        }
+        f.write_char(')')
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        if let Self::Item(items) = self {
+            for item in items {
+                item.py_dec_ref_ids(stack);
+            }
+        }
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + match self {
+                Self::Item(items) => items.len() * std::mem::size_of::<Value>(),
+                Self::Attr(attrs) => attrs
+                    .iter()
+                    .map(|(name, parts)| name.len() * 2 + parts.len() * std::mem::size_of::<EitherStr>())
+                    .sum(),
+            }
+    }
+}
+
+/// Calls the getter stored at `id` with `args`.
+///
+/// The items or attribute names are copied out first, so the lookups can use the heap
+/// freely, even if the argument is the getter itself.
+pub(crate) fn call_getter(
+    heap: &mut Heap<impl ResourceTracker>,
+    id: HeapId,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<Value> {
+    let HeapData::Getter(getter) = heap.get(id) else {
+        unreachable!("call_getter is only called for getters")
+    };
+    let name = getter.name();
+    let args: Vec<Value> = args.into_pos_only(name, heap)?.collect();
+    defer_drop!(args, heap);
+    let [obj] = args.as_slice() else {
+        return Err(ExcType::type_error(format!(
+            "{name} expected 1 argument, got {}",
+            args.len()
+        )));
+    };
+
+    let mut results: Vec<Value> = Vec::new();
+    defer_drop_mut!(results, heap);
+    match heap.get(id) {
+        HeapData::Getter(Getter::Item(items)) => {
+            let items: Vec<Value> = items.iter().map(Value::copy_for_extend).collect();
+            for item in &items {
+                if let Value::Ref(item_id) = item {
+                    heap.inc_ref(*item_id);
+                }
+            }
+            defer_drop!(items, heap);
+            for item in items {
+                results.push(obj.py_getitem(item, heap, interns)?);
+            }
+        }
+        HeapData::Getter(Getter::Attr(attrs)) => {
+            let paths: Vec<Vec<EitherStr>> = attrs.iter().map(|(_, parts)| parts.clone()).collect();
+            for path in &paths {
+                results.push(get_attr_path(obj, path, heap, interns)?);
+            }
+        }
+        _ => unreachable!("call_getter is only called for getters"),
+    }
+
+    if results.len() == 1 {
+        Ok(results.pop().expect("one result"))
+    } else {
+        Ok(allocate_tuple(TupleVec::from_vec(std::mem::take(results)), heap)?)
+    }
+}
+
+/// Looks up a dotted attribute path on `obj` for `attrgetter`.
+fn get_attr_path(
+    obj: &Value,
+    path: &[EitherStr],
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Value> {
+    let mut value = obj.clone_with_heap(heap);
+    for part in path {
+        let result = match part {
+            EitherStr::Interned(attr_id) => value.py_getattr(*attr_id, heap, interns),
+            EitherStr::Heap(attr) => Err(ExcType::attribute_error(value.py_type(heap), attr)),
+        };
+        value.drop_with_heap(heap);
+        value = match result? {
+            AttrCallResult::Value(attr) => attr,
+            AttrCallResult::OsCall(_, args) | AttrCallResult::ExternalCall(_, args) => {
+                args.drop_with_heap(heap);
+                return Err(ExcType::not_supported_in_callback("OS calls"));
+            }
+        };
+    }
+    Ok(value)
+}
@@ -110,6 +110,26 @@ impl Range {
         (n - self.start) % self.step == 0
     }
 
+    /// Returns the range yielding the same integers in reverse order, computed from the
+    /// bounds rather than by materializing the elements.
+    ///
+    /// Returns `None` if the reversed bounds don't fit in an `i64`.
+    #[must_use]
+    pub fn reversed(&self) -> Option<Self> {
+        let Some(last_index) = self.len().checked_sub(1) else {
+            return Some(Self::default());
+        };
+        let last = i64::try_from(last_index)
+            .ok()?
+            .checked_mul(self.step)?
+            .checked_add(self.start)?;
+        Some(Self::new(
+            last,
+            self.start.checked_sub(self.step)?,
+            self.step.checked_neg()?,
+        ))
+    }
+
     /// Creates a range from the `range()` constructor call.
     ///
     /// Supports:
@@ -168,40 +188,19 @@ impl Range {
             .indices(range_len)
             .map_err(|()| ExcType::value_error_slice_step_zero())?;
 
-        // Calculate the new range parameters
-        // new_start = self.start + start * self.step
-        // new_step = self.step * slice_step
-        // new_stop needs to be computed based on the number of elements
-
+        // Like CPython, map the slice's start and stop indices through the range, so the
+        // result keeps the slice bounds (`range(5)[::2]` is `range(0, 5, 2)`)
         let new_step = self.step.saturating_mul(step);
+        let value_at = |index: i64| self.start.saturating_add(index.saturating_mul(self.step));
         let start_i64 = i64::try_from(start).expect("start index fits in i64");
-        let new_start = self.start.saturating_add(start_i64.saturating_mul(self.step));
-
-        // Calculate the number of elements in the sliced range
-        // try_from succeeds for non-negative step; step==0 rejected by slice.indices()
-        let num_elements = if let Ok(step_usize) = usize::try_from(step) {
-            // Forward iteration
-            if start >= stop {
-                0
-            } else {
-                ((stop - start - 1) / step_usize) + 1
-            }
+        // A negative step's stop past the end is the sentinel for "before index 0"
+        let stop_i64 = if step < 0 && stop > range_len {
+            -1
         } else {
-            // Backward iteration
-            let step_abs = usize::try_from(-step).expect("step is negative so -step is positive");
-            if stop > range_len {
-                // stop sentinel means "go to the beginning"
-                (start / step_abs) + 1
-            } else if start <= stop {
-                0
-            } else {
-                ((start - stop - 1) / step_abs) + 1
-            }
+            i64::try_from(stop).expect("stop index fits in i64")
         };
-
-        // new_stop = new_start + num_elements * new_step
-        let num_elements_i64 = i64::try_from(num_elements).expect("num_elements fits in i64");
-        let new_stop = new_start.saturating_add(num_elements_i64.saturating_mul(new_step));
+        let new_start = value_at(start_i64);
+        let new_stop = value_at(stop_i64);
 
         let new_range = Self::new(new_start, new_stop, new_step);
         Ok(Value::Ref(heap.allocate(HeapData::Range(new_range))?))
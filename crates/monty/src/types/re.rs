@@ -0,0 +1,859 @@
+//! Compiled regular expressions and match objects for the `re` module.
+//!
+//! Patterns are compiled with the Rust `regex` crate, which guarantees matching in time
+//! linear in the size of the input, so no pattern can be used for a ReDoS attack. The price
+//! is that Python syntax which needs backtracking isn't supported:
+//!
+//! - backreferences (`\1`, `(?P=name)`)
+//! - lookahead and lookbehind (`(?=...)`, `(?!...)`, `(?<=...)`, `(?<!...)`)
+//! - conditionals (`(?(1)...)`), atomic groups and possessive quantifiers
+//! - `\Z`; use `\z` instead
+//!
+//! Everything else commonly used works as in Python: character classes, `\d`/`\w`/`\s` (all
+//! Unicode-aware like Python's `str` patterns), greedy and lazy quantifiers, alternation,
+//! numbered, non-capturing and named (`(?P<name>...)`) groups, inline flags and anchors.
+//! Patterns that can't be compiled raise `ValueError` (Monty has no `re.error`) with the
+//! reason reported by the regex crate.
+//!
+//! Known differences from CPython:
+//!
+//! - `$` without `re.MULTILINE` only matches at the very end of the string, not before a
+//!   trailing newline.
+//! - Empty matches directly after a previous match are reported differently by `findall()`,
+//!   `sub()` and `split()` in some cases.
+//! - The flags are plain ints rather than `re.RegexFlag` members.
+//!
+//! Positions returned by `start()`, `end()` and `span()` are in characters, like CPython.
+
+use std::{fmt::Write, sync::OnceLock};
+
+use ahash::AHashSet;
+use regex::{Regex, RegexBuilder};
+
+use crate::{
+    args::ArgValues,
+    defer_drop, defer_drop_mut,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings, StringId},
+    resource::{DepthGuard, ResourceError, ResourceTracker, check_repeat_size},
+    types::{
+        AttrCallResult, List, PyTrait, Type, allocate_tuple, str::StringRepr, str::allocate_string, tuple::TupleVec,
+    },
+    value::{EitherStr, Value},
+};
+
+/// `re.IGNORECASE`: case-insensitive matching.
+pub(crate) const IGNORECASE: i64 = 2;
+/// `re.MULTILINE`: `^` and `$` also match at line boundaries.
+pub(crate) const MULTILINE: i64 = 8;
+/// `re.DOTALL`: `.` also matches a newline.
+pub(crate) const DOTALL: i64 = 16;
+/// `re.UNICODE`: always on for `str` patterns, accepted for compatibility.
+pub(crate) const UNICODE: i64 = 32;
+/// `re.VERBOSE`: whitespace in the pattern is ignored and `#` starts a comment.
+pub(crate) const VERBOSE: i64 = 64;
+
+/// Largest compiled program the regex crate may build for a single pattern.
+///
+/// Bounded repetitions multiply the size of the program, so without a limit a short
+/// pattern like `(a{1000}){1000}` would use a huge amount of memory.
+const MAX_REGEX_SIZE: usize = 1 << 20;
+
+/// Size limit a pattern is first compiled with, see [`build_regex`].
+const MIN_REGEX_SIZE: usize = 1 << 14;
+
+/// A compiled regular expression, Python's `re.Pattern`.
+///
+/// The pattern source and flags are kept alongside the compiled regex for `repr()`, the
+/// `pattern` and `flags` attributes, and serialization: the regex is recompiled when a
+/// snapshot is loaded.
+///
+/// `match()` needs a search anchored at the start of the string, which the regex crate
+/// only offers through a second regex. It's compiled the first time `match()` is used.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(into = "PatternSource", try_from = "PatternSource")]
+pub(crate) struct RePattern {
+    source: String,
+    flags: i64,
+    regex: Regex,
+    /// Size limit `regex` was compiled with, which bounds the memory it uses.
+    size_limit: usize,
+    /// `regex` anchored at the start of the string, or `None` if the anchored form can't
+    /// be compiled because it's just over `size_limit`.
+    anchored: OnceLock<Option<Regex>>,
+}
+
+/// Serialized form of a [`RePattern`].
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PatternSource {
+    source: String,
+    flags: i64,
+}
+
+impl From<RePattern> for PatternSource {
+    fn from(pattern: RePattern) -> Self {
+        Self {
+            source: pattern.source,
+            flags: pattern.flags,
+        }
+    }
+}
+
+impl TryFrom<PatternSource> for RePattern {
+    type Error = String;
+
+    fn try_from(source: PatternSource) -> Result<Self, Self::Error> {
+        let (regex, size_limit) = build_regex(&source.source, source.flags)?;
+        Ok(Self {
+            source: source.source,
+            flags: source.flags,
+            regex,
+            size_limit,
+            anchored: OnceLock::new(),
+        })
+    }
+}
+
+impl RePattern {
+    /// Compiles a pattern, raising `ValueError` for unsupported flags or syntax.
+    pub fn new(source: String, flags: i64) -> RunResult<Self> {
+        if flags & !(IGNORECASE | MULTILINE | DOTALL | UNICODE | VERBOSE) != 0 {
+            return Err(
+                SimpleException::new_msg(ExcType::ValueError, format!("unsupported regex flags: {flags}")).into(),
+            );
+        }
+        match build_regex(&source, flags) {
+            Ok((regex, size_limit)) => Ok(Self {
+                source,
+                flags,
+                regex,
+                size_limit,
+                anchored: OnceLock::new(),
+            }),
+            Err(reason) => Err(SimpleException::new_msg(
+                ExcType::ValueError,
+                format!("invalid regex {}: {reason}", StringRepr(&source)),
+            )
+            .into()),
+        }
+    }
+
+    /// Returns what identifies the pattern for equality and hashing.
+    pub fn hash_key(&self) -> (&str, i64) {
+        (&self.source, self.flags)
+    }
+
+    /// Number of capturing groups in the pattern, not counting the whole match.
+    fn group_count(&self) -> usize {
+        self.regex.captures_len() - 1
+    }
+
+    /// Returns the index of the group with the given name.
+    fn group_index(&self, name: &str) -> Option<usize> {
+        self.regex.capture_names().position(|group| group == Some(name))
+    }
+
+    /// Implements `search()` and, with `at_start`, `match()`.
+    ///
+    /// Returns a `re.Match` or `None`.
+    pub fn find_match(&self, text: String, at_start: bool, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
+        let captures = if at_start {
+            match self
+                .anchored
+                .get_or_init(|| build_anchored_regex(&self.source, self.flags, self.size_limit))
+            {
+                Some(anchored) => anchored.captures(&text),
+                // The leftmost match is found, so if there's a match at the start this is it
+                None => self
+                    .regex
+                    .captures(&text)
+                    .filter(|captures| captures.get(0).is_some_and(|m| m.start() == 0)),
+            }
+        } else {
+            self.regex.captures(&text)
+        };
+        let Some(captures) = captures else {
+            return Ok(Value::None);
+        };
+        let spans = captures
+            .iter()
+            .map(|group| group.map(|m| (m.start(), m.end())))
+            .collect();
+        let names = self.regex.capture_names().map(|name| name.map(str::to_owned)).collect();
+        let re_match = ReMatch {
+            string: text,
+            spans,
+            names,
+        };
+        Ok(Value::Ref(heap.allocate(HeapData::ReMatch(re_match))?))
+    }
+
+    /// Implements `findall()`.
+    ///
+    /// Like CPython, the list contains the whole matches if the pattern has no groups, the
+    /// text of the group if it has one, and tuples of all groups otherwise.
+    pub fn findall(&self, text: &str, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
+        let group_count = self.group_count();
+        let mut items: Vec<Value> = Vec::new();
+        defer_drop_mut!(items, heap);
+        for captures in self.regex.captures_iter(text) {
+            // Empty strings are interned, so check the list itself doesn't grow too large
+            check_repeat_size(items.len() + 1, std::mem::size_of::<Value>(), heap.tracker())?;
+            let item = if group_count <= 1 {
+                let group = captures.get(group_count).map_or("", regex::Match::as_str);
+                allocate_string(group.to_owned(), heap)?
+            } else {
+                let groups = captures
+                    .iter()
+                    .skip(1)
+                    .map(|group| Some(group.map_or("", regex::Match::as_str)));
+                strings_tuple(groups, &Value::None, heap)?
+            };
+            items.push(item);
+        }
+        Ok(Value::Ref(
+            heap.allocate(HeapData::List(List::new(std::mem::take(items))))?,
+        ))
+    }
+
+    /// Implements `sub()` with a string replacement.
+    ///
+    /// `count` is the maximum number of replacements, 0 meaning no limit.
+    pub fn sub(&self, repl: &str, text: &str, count: i64, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
+        let template = self.parse_template(repl)?;
+        let limit = match usize::try_from(count) {
+            Ok(0) => usize::MAX,
+            Ok(limit) => limit,
+            // A negative count replaces nothing, like CPython
+            Err(_) => 0,
+        };
+
+        let mut result = String::new();
+        let mut last_end = 0;
+        for captures in self.regex.captures_iter(text).take(limit) {
+            let whole = captures.get(0).expect("group 0 always participates in a match");
+            result.push_str(&text[last_end..whole.start()]);
+            for part in &template {
+                match part {
+                    TemplatePart::Literal(literal) => result.push_str(literal),
+                    TemplatePart::Group(index) => {
+                        result.push_str(captures.get(*index).map_or("", regex::Match::as_str))
+                    }
+                }
+            }
+            last_end = whole.end();
+            check_repeat_size(result.len(), 1, heap.tracker())?;
+        }
+        result.push_str(&text[last_end..]);
+        allocate_string(result, heap)
+    }
+
+    /// Implements `split()`.
+    ///
+    /// The text of every group is included in the result between the pieces, `None` for
+    /// groups that didn't participate. `maxsplit` is the maximum number of splits, 0 meaning
+    /// no limit.
+    pub fn split(&self, text: &str, maxsplit: i64, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
+        let limit = match usize::try_from(maxsplit) {
+            Ok(0) => usize::MAX,
+            Ok(limit) => limit,
+            // A negative maxsplit doesn't split at all, like CPython
+            Err(_) => 0,
+        };
+
+        let mut items: Vec<Value> = Vec::new();
+        defer_drop_mut!(items, heap);
+        let mut last_end = 0;
+        for captures in self.regex.captures_iter(text).take(limit) {
+            check_repeat_size(
+                items.len() + captures.len(),
+                std::mem::size_of::<Value>(),
+                heap.tracker(),
+            )?;
+            let whole = captures.get(0).expect("group 0 always participates in a match");
+            items.push(allocate_string(text[last_end..whole.start()].to_owned(), heap)?);
+            for group in captures.iter().skip(1) {
+                let item = match group {
+                    Some(m) => allocate_string(m.as_str().to_owned(), heap)?,
+                    None => Value::None,
+                };
+                items.push(item);
+            }
+            last_end = whole.end();
+        }
+        items.push(allocate_string(text[last_end..].to_owned(), heap)?);
+        Ok(Value::Ref(
+            heap.allocate(HeapData::List(List::new(std::mem::take(items))))?,
+        ))
+    }
+
+    /// Parses the replacement string of `sub()` into literal text and group references.
+    ///
+    /// Supports the same syntax as CPython: `\1` to `\99`, `\g<1>`, `\g<name>`, octal
+    /// escapes and the usual character escapes like `\n`. Unknown escapes of ASCII letters
+    /// are errors, other unknown escapes are kept as they are.
+    fn parse_template(&self, repl: &str) -> RunResult<Vec<TemplatePart>> {
+        let chars: Vec<char> = repl.chars().collect();
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut pos = 0;
+        while pos < chars.len() {
+            let c = chars[pos];
+            pos += 1;
+            if c != '\\' {
+                literal.push(c);
+                continue;
+            }
+            let escape_pos = pos - 1;
+            let Some(&escape) = chars.get(pos) else {
+                return Err(template_error(format!(
+                    "bad escape (end of pattern) at position {escape_pos}"
+                )));
+            };
+            pos += 1;
+
+            let group = match escape {
+                'g' => {
+                    if chars.get(pos) != Some(&'<') {
+                        return Err(template_error(format!("missing < at position {pos}")));
+                    }
+                    let name_pos = pos + 1;
+                    let Some(len) = chars[name_pos..].iter().position(|&c| c == '>') else {
+                        return Err(template_error(format!(
+                            "missing >, unterminated name at position {name_pos}"
+                        )));
+                    };
+                    let name: String = chars[name_pos..name_pos + len].iter().collect();
+                    pos = name_pos + len + 1;
+                    if name.is_empty() {
+                        return Err(template_error(format!("missing group name at position {name_pos}")));
+                    }
+                    if name.bytes().all(|b| b.is_ascii_digit()) {
+                        self.check_group_reference(&name, name_pos)?
+                    } else {
+                        self.group_index(&name).ok_or_else(|| {
+                            RunError::from(SimpleException::new_msg(
+                                ExcType::IndexError,
+                                format!("unknown group name '{name}'"),
+                            ))
+                        })?
+                    }
+                }
+                '0' => {
+                    // `\0` followed by up to two more octal digits is an octal escape
+                    let digits = octal_digits(&chars[pos..], 2);
+                    let value = chars[pos..pos + digits]
+                        .iter()
+                        .fold(0, |value, c| value * 8 + c.to_digit(8).expect("octal digit"));
+                    pos += digits;
+                    literal.push(char::from_u32(value).expect("octal escape is below 0o100"));
+                    continue;
+                }
+                '1'..='9' => {
+                    // Three octal digits are an octal escape, otherwise up to two digits are a group
+                    if octal_digits(&chars[pos - 1..], 3) == 3 {
+                        let value = chars[pos - 1..pos + 2]
+                            .iter()
+                            .fold(0, |value, c| value * 8 + c.to_digit(8).expect("octal digit"));
+                        if value > 0o377 {
+                            let escape: String = chars[escape_pos..pos + 2].iter().collect();
+                            return Err(template_error(format!(
+                                "octal escape value {escape} outside of range 0-0o377 at position {escape_pos}"
+                            )));
+                        }
+                        pos += 2;
+                        literal.push(char::from_u32(value).expect("octal escape is at most 0o377"));
+                        continue;
+                    }
+                    let digits_pos = pos - 1;
+                    if chars.get(pos).is_some_and(char::is_ascii_digit) {
+                        pos += 1;
+                    }
+                    let number: String = chars[digits_pos..pos].iter().collect();
+                    self.check_group_reference(&number, digits_pos)?
+                }
+                _ => {
+                    let c = match escape {
+                        'a' => '\x07',
+                        'b' => '\x08',
+                        'f' => '\x0c',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'v' => '\x0b',
+                        '\\' => '\\',
+                        c if c.is_ascii_alphabetic() => {
+                            return Err(template_error(format!("bad escape \\{c} at position {escape_pos}")));
+                        }
+                        c => {
+                            literal.push('\\');
+                            c
+                        }
+                    };
+                    literal.push(c);
+                    continue;
+                }
+            };
+            if !literal.is_empty() {
+                parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+            }
+            parts.push(TemplatePart::Group(group));
+        }
+        if !literal.is_empty() {
+            parts.push(TemplatePart::Literal(literal));
+        }
+        Ok(parts)
+    }
+
+    /// Checks that a numeric group reference in a replacement string refers to a group.
+    fn check_group_reference(&self, number: &str, pos: usize) -> RunResult<usize> {
+        match number.parse::<usize>() {
+            Ok(index) if index <= self.group_count() => Ok(index),
+            _ => Err(template_error(format!(
+                "invalid group reference {number} at position {pos}"
+            ))),
+        }
+    }
+
+    /// Dispatches a method call on a pattern.
+    fn call_method(
+        &self,
+        heap: &mut Heap<impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+        interns: &Interns,
+    ) -> RunResult<Value> {
+        match attr.static_string() {
+            Some(StaticStrings::Search) => {
+                let text = text_arg(args.get_one_arg("search", heap)?, heap, interns)?;
+                self.find_match(text, false, heap)
+            }
+            Some(StaticStrings::Match) => {
+                let text = text_arg(args.get_one_arg("match", heap)?, heap, interns)?;
+                self.find_match(text, true, heap)
+            }
+            Some(StaticStrings::Findall) => {
+                let text = text_arg(args.get_one_arg("findall", heap)?, heap, interns)?;
+                self.findall(&text, heap)
+            }
+            Some(StaticStrings::Sub) => {
+                let [Some(repl), Some(string), count] =
+                    args.bind("sub", ["repl", "string", "count"], 2, heap, interns)?
+                else {
+                    unreachable!("bind checks required arguments")
+                };
+                // Every argument is converted before checking for errors so none are leaked
+                let repl = repl_arg(repl, heap, interns);
+                let text = text_arg(string, heap, interns);
+                let count = int_arg(count, 0, heap);
+                self.sub(&repl?, &text?, count?, heap)
+            }
+            Some(StaticStrings::Split) => {
+                let [Some(string), maxsplit] = args.bind("split", ["string", "maxsplit"], 1, heap, interns)? else {
+                    unreachable!("bind checks required arguments")
+                };
+                let text = text_arg(string, heap, interns);
+                let maxsplit = int_arg(maxsplit, 0, heap);
+                self.split(&text?, maxsplit?, heap)
+            }
+            _ => {
+                args.drop_with_heap(heap);
+                Err(ExcType::attribute_error(Type::RePattern, attr.as_str(interns)))
+            }
+        }
+    }
+}
+
+impl PyTrait for RePattern {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        Type::RePattern
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        Ok(self.source == other.source && self.flags == other.flags)
+    }
+
+    fn py_bool(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        _heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> std::fmt::Result {
+        write!(f, "re.compile({}", StringRepr(&self.source))?;
+        let mut separator = ", ";
+        for (flag, name) in [
+            (IGNORECASE, "IGNORECASE"),
+            (MULTILINE, "MULTILINE"),
+            (DOTALL, "DOTALL"),
+            (VERBOSE, "VERBOSE"),
+        ] {
+            if self.flags & flag != 0 {
+                write!(f, "{separator}re.{name}")?;
+                separator = "|";
+            }
+        }
+        f.write_char(')')
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {
+        // RePattern doesn't contain heap references, nothing to do
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        // The regex crate doesn't report how much memory a regex uses, so this charges the
+        // limits instead: the regex and its anchored form may each build a program of up to
+        // `size_limit` and a lazy DFA cache of as much again
+        std::mem::size_of::<Self>() + self.source.capacity() + 4 * self.size_limit
+    }
+
+    fn py_getattr(
+        &self,
+        attr_id: StringId,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<Option<AttrCallResult>> {
+        let v = match StaticStrings::from_string_id(attr_id) {
+            Some(StaticStrings::Pattern) => allocate_string(self.source.clone(), heap)?,
+            // str patterns are always Unicode, which CPython reports in the flags
+            Some(StaticStrings::Flags) => Value::Int(self.flags | UNICODE),
+            Some(StaticStrings::Groups) => Value::Int(i64::try_from(self.group_count()).unwrap_or(i64::MAX)),
+            _ => return Err(ExcType::attribute_error(Type::RePattern, interns.get_str(attr_id))),
+        };
+        Ok(Some(AttrCallResult::Value(v)))
+    }
+}
+
+/// The result of a successful match, Python's `re.Match`.
+///
+/// Holds a copy of the string that was searched and the byte offsets of every group,
+/// `None` for groups that didn't participate in the match.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ReMatch {
+    string: String,
+    spans: Vec<Option<(usize, usize)>>,
+    /// Group names by index, `None` for unnamed groups.
+    names: Vec<Option<String>>,
+}
+
+impl ReMatch {
+    /// Returns the text matched by a group.
+    fn group_text(&self, index: usize) -> Option<String> {
+        self.spans[index].map(|(start, end)| self.string[start..end].to_owned())
+    }
+
+    /// Returns a group's start and end as character offsets, or `(-1, -1)` if it didn't participate.
+    fn char_span(&self, index: usize) -> (i64, i64) {
+        match self.spans[index] {
+            Some((start, end)) => (self.char_offset(start), self.char_offset(end)),
+            None => (-1, -1),
+        }
+    }
+
+    /// Converts a byte offset into the string to a character offset.
+    fn char_offset(&self, byte_offset: usize) -> i64 {
+        i64::try_from(self.string[..byte_offset].chars().count()).unwrap_or(i64::MAX)
+    }
+
+    /// Resolves a group argument, an index or a group name, raising `IndexError` if there's
+    /// no such group.
+    fn resolve_group(&self, group: &Value, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<usize> {
+        let index = match group {
+            Value::Int(i) => usize::try_from(*i).ok(),
+            Value::Bool(b) => Some(usize::from(*b)),
+            other => other.as_either_str(heap).and_then(|name| {
+                let name = name.as_str(interns);
+                self.names.iter().position(|group| group.as_deref() == Some(name))
+            }),
+        };
+        index
+            .filter(|&index| index < self.spans.len())
+            .ok_or_else(|| SimpleException::new_msg(ExcType::IndexError, "no such group").into())
+    }
+}
+
+impl PyTrait for ReMatch {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        Type::ReMatch
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        _other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Matches compare by identity, which is handled at the Value level
+        Ok(false)
+    }
+
+    fn py_bool(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        _heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        _guard: &mut DepthGuard,
+        _interns: &Interns,
+    ) -> std::fmt::Result {
+        let (start, end) = self.char_span(0);
+        let text = self.group_text(0).unwrap_or_default();
+        write!(
+            f,
+            "<re.Match object; span=({start}, {end}), match={}>",
+            StringRepr(&text)
+        )
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {
+        // ReMatch doesn't contain heap references, nothing to do
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.string.capacity()
+            + self.spans.capacity() * std::mem::size_of::<Option<(usize, usize)>>()
+            + self.names.iter().flatten().map(String::capacity).sum::<usize>()
+    }
+}
+
+/// Calls a method on a `re.Pattern` or `re.Match`.
+///
+/// Called by [`Heap::call_attr_raw`] without taking the object's data out of the heap, so
+/// that arguments which happen to be the object itself (e.g. `m.group(m)`) can still be
+/// inspected. A pattern is cheap to clone (the compiled regex is reference counted), and
+/// match methods copy what they need before allocating their result.
+pub(crate) fn call_attr(
+    heap: &mut Heap<impl ResourceTracker>,
+    id: HeapId,
+    attr: &EitherStr,
+    args: ArgValues,
+    interns: &Interns,
+) -> RunResult<Value> {
+    if let HeapData::RePattern(pattern) = heap.get(id) {
+        let pattern = pattern.clone();
+        return pattern.call_method(heap, attr, args, interns);
+    }
+
+    match attr.static_string() {
+        Some(StaticStrings::Group) => {
+            let groups: Vec<Value> = args.into_pos_only("group", heap)?.collect();
+            defer_drop!(groups, heap);
+            let re_match = get_match(heap, id);
+            let mut texts = if groups.is_empty() {
+                vec![re_match.group_text(0)]
+            } else {
+                groups
+                    .iter()
+                    .map(|group| Ok(re_match.group_text(re_match.resolve_group(group, heap, interns)?)))
+                    .collect::<RunResult<Vec<_>>>()?
+            };
+            // A single group returns its text, several return a tuple
+            if texts.len() == 1 {
+                match texts.pop().flatten() {
+                    Some(text) => allocate_string(text, heap),
+                    None => Ok(Value::None),
+                }
+            } else {
+                strings_tuple(texts, &Value::None, heap)
+            }
+        }
+        Some(StaticStrings::Groups) => {
+            let default = args.get_zero_one_arg("groups", heap)?.unwrap_or(Value::None);
+            defer_drop!(default, heap);
+            let re_match = get_match(heap, id);
+            let texts: Vec<Option<String>> = (1..re_match.spans.len())
+                .map(|index| re_match.group_text(index))
+                .collect();
+            strings_tuple(texts, default, heap)
+        }
+        Some(method @ (StaticStrings::Start | StaticStrings::End | StaticStrings::Span)) => {
+            let name: &'static str = method.into();
+            let group = args.get_zero_one_arg(name, heap)?;
+            defer_drop!(group, heap);
+            let re_match = get_match(heap, id);
+            let index = match group {
+                Some(group) => re_match.resolve_group(group, heap, interns)?,
+                None => 0,
+            };
+            let (start, end) = re_match.char_span(index);
+            match method {
+                StaticStrings::Start => Ok(Value::Int(start)),
+                StaticStrings::End => Ok(Value::Int(end)),
+                _ => Ok(allocate_tuple(
+                    [Value::Int(start), Value::Int(end)].into_iter().collect(),
+                    heap,
+                )?),
+            }
+        }
+        _ => {
+            args.drop_with_heap(heap);
+            Err(ExcType::attribute_error(Type::ReMatch, attr.as_str(interns)))
+        }
+    }
+}
+
+/// Returns the match object stored at `id`.
+fn get_match(heap: &Heap<impl ResourceTracker>, id: HeapId) -> &ReMatch {
+    match heap.get(id) {
+        HeapData::ReMatch(re_match) => re_match,
+        _ => unreachable!("re.call_attr is only called for patterns and matches"),
+    }
+}
+
+/// Copies the string argument of a matching function.
+///
+/// Raises `TypeError` for anything other than a `str`, with CPython's message.
+pub(crate) fn text_arg(value: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<String> {
+    defer_drop!(value, heap);
+    match value.as_either_str(heap) {
+        Some(text) => Ok(text.as_str(interns).to_owned()),
+        None => Err(ExcType::type_error(format!(
+            "expected string or bytes-like object, got '{}'",
+            value.py_type(heap)
+        ))),
+    }
+}
+
+/// Copies the replacement argument of `sub()`, raising for a callable replacement.
+pub(crate) fn repl_arg(value: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<String> {
+    defer_drop!(value, heap);
+    if let Some(repl) = value.as_either_str(heap) {
+        return Ok(repl.as_str(interns).to_owned());
+    }
+    if matches!(value.py_type(heap), Type::Function | Type::BuiltinFunction) {
+        return Err(ExcType::not_implemented("re.sub() with a function as replacement is not supported").into());
+    }
+    Err(ExcType::type_error(format!(
+        "decoding to str: need a bytes-like object, {} found",
+        value.py_type(heap)
+    )))
+}
+
+/// Converts an optional int argument like `count` or `flags`, using `default` if it's missing.
+pub(crate) fn int_arg(value: Option<Value>, default: i64, heap: &mut Heap<impl ResourceTracker>) -> RunResult<i64> {
+    let Some(value) = value else {
+        return Ok(default);
+    };
+    defer_drop!(value, heap);
+    value.as_int(heap)
+}
+
+/// Allocates a tuple of strings, using `default` for the `None` entries.
+fn strings_tuple<S: Into<String>>(
+    texts: impl IntoIterator<Item = Option<S>>,
+    default: &Value,
+    heap: &mut Heap<impl ResourceTracker>,
+) -> RunResult<Value> {
+    let mut items: Vec<Value> = Vec::new();
+    defer_drop_mut!(items, heap);
+    for text in texts {
+        let item = match text {
+            Some(text) => allocate_string(text.into(), heap)?,
+            None => default.clone_with_heap(heap),
+        };
+        items.push(item);
+    }
+    Ok(allocate_tuple(TupleVec::from_vec(std::mem::take(items)), heap)?)
+}
+
+/// A piece of a parsed `sub()` replacement string.
+enum TemplatePart {
+    Literal(String),
+    Group(usize),
+}
+
+/// Counts the octal digits at the start of `chars`, up to `max`.
+fn octal_digits(chars: &[char], max: usize) -> usize {
+    chars.iter().take(max).take_while(|c| matches!(c, '0'..='7')).count()
+}
+
+/// Error for an invalid `sub()` replacement string; CPython raises `re.error`.
+fn template_error(msg: String) -> RunError {
+    SimpleException::new_msg(ExcType::ValueError, msg).into()
+}
+
+/// Compiles a pattern anchored at the start of the string, as `match()` needs.
+///
+/// The anchored form gets the pattern's own `size_limit`, so it's covered by the memory
+/// charged for the pattern. Returns `None` if it doesn't fit, which can only happen when the
+/// pattern itself is close to the limit.
+fn build_anchored_regex(source: &str, flags: i64, size_limit: usize) -> Option<Regex> {
+    // The source is valid, so wrapping it in a group only fails if the program gets too
+    // large or the pattern ends in a verbose `#` comment that swallows the `)`. Verbose mode
+    // may be set by the flags or inline with `(?x)`, so rather than the flag this checks for a
+    // `#`, and other patterns are compiled once. A newline ends the comment and is otherwise
+    // ignored in verbose mode; it can only be matched literally when the first form was too
+    // large already, and then this larger form is too.
+    match build_regex_with_limit(&format!(r"\A(?:{source})"), flags, size_limit) {
+        Ok(regex) => Some(regex),
+        Err(_) if source.contains('#') => build_regex_with_limit(&format!("\\A(?:{source}\n)"), flags, size_limit).ok(),
+        Err(_) => None,
+    }
+}
+
+/// Compiles a pattern with the regex crate, returning the reason it failed on error.
+///
+/// Most patterns compile to a small program, so the size limit starts at `MIN_REGEX_SIZE`
+/// and is only raised, up to `MAX_REGEX_SIZE`, for patterns that don't fit. Returns the
+/// regex with the limit it was compiled with, which the memory charged for it is based on.
+fn build_regex(source: &str, flags: i64) -> Result<(Regex, usize), String> {
+    let mut size_limit = MIN_REGEX_SIZE;
+    loop {
+        match build_regex_with_limit(source, flags, size_limit) {
+            Ok(regex) => return Ok((regex, size_limit)),
+            Err(regex::Error::CompiledTooBig(_)) if size_limit < MAX_REGEX_SIZE => size_limit *= 4,
+            Err(err) => return Err(regex_error_reason(err)),
+        }
+    }
+}
+
+/// Compiles a pattern with the regex crate, limiting both its program and its lazy DFA
+/// cache to `size_limit`.
+fn build_regex_with_limit(source: &str, flags: i64, size_limit: usize) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(source)
+        .case_insensitive(flags & IGNORECASE != 0)
+        .multi_line(flags & MULTILINE != 0)
+        .dot_matches_new_line(flags & DOTALL != 0)
+        .ignore_whitespace(flags & VERBOSE != 0)
+        .size_limit(size_limit)
+        .dfa_size_limit(size_limit)
+        .build()
+}
+
+/// Returns the reason a pattern failed to compile, for the `ValueError` message.
+fn regex_error_reason(err: regex::Error) -> String {
+    match err {
+        // Syntax errors are formatted over several lines with the reason on the last one
+        regex::Error::Syntax(msg) => {
+            let reason = msg.lines().last().unwrap_or_default();
+            reason.strip_prefix("error: ").unwrap_or(reason).to_owned()
+        }
+        regex::Error::CompiledTooBig(_) => "pattern too large".to_owned(),
+        other => other.to_string(),
+    }
+}
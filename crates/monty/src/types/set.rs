@@ -120,7 +120,8 @@ impl SetStorage {
     /// the set, it will be dropped.
     fn add(&mut self, value: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<bool> {
         let Some(hash) = value.py_hash(heap, interns) else {
-            let err = ExcType::type_error_unhashable_set_element(value.py_type(heap));
+            let unhashable = value.unhashable_type(heap, interns);
+            let err = ExcType::type_error_unhashable_set_element(value.py_type(heap), unhashable);
             value.drop_with_heap(heap);
             return Err(err);
         };
@@ -151,9 +152,13 @@ impl SetStorage {
     /// Returns `Ok(true)` if the element was removed, `Ok(false)` if not found.
     /// Returns `Err` if the key is unhashable.
     fn remove(&mut self, value: &Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<bool> {
-        let hash = value
-            .py_hash(heap, interns)
-            .ok_or_else(|| ExcType::type_error_unhashable_set_element(value.py_type(heap)))?;
+        let Some(hash) = value.py_hash(heap, interns) else {
+            let unhashable = value.unhashable_type(heap, interns);
+            return Err(ExcType::type_error_unhashable_set_element(
+                value.py_type(heap),
+                unhashable,
+            ));
+        };
 
         // Create a local guard for equality comparisons.
         let mut guard = DepthGuard::default();
@@ -240,9 +245,13 @@ impl SetStorage {
 
     /// Checks if the set contains a value.
     pub fn contains(&self, value: &Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<bool> {
-        let hash = value
-            .py_hash(heap, interns)
-            .ok_or_else(|| ExcType::type_error_unhashable_set_element(value.py_type(heap)))?;
+        let Some(hash) = value.py_hash(heap, interns) else {
+            let unhashable = value.unhashable_type(heap, interns);
+            return Err(ExcType::type_error_unhashable_set_element(
+                value.py_type(heap),
+                unhashable,
+            ));
+        };
 
         // Create a guard for value equality comparisons. Set values are typically
         // shallow (strings, ints, tuples of primitives), so recursion errors
@@ -282,7 +291,9 @@ impl SetStorage {
     }
 
     /// Compares two sets for equality.
-    fn eq(
+    ///
+    /// Also used to compare a set with a frozenset, which are equal if they have the same elements.
+    pub(crate) fn eq(
         &self,
         other: &Self,
         heap: &mut Heap<impl ResourceTracker>,
@@ -464,7 +475,10 @@ impl SetStorage {
 
     /// Estimates the memory size of this storage.
     fn estimate_size(&self) -> usize {
-        std::mem::size_of::<Self>() + self.len() * std::mem::size_of::<SetEntry>()
+        // Dense entries + index table, which needs a control byte per slot on top of the stored index
+        std::mem::size_of::<Self>()
+            + self.entries.capacity() * std::mem::size_of::<SetEntry>()
+            + self.indices.capacity() * (std::mem::size_of::<usize>() + 1)
     }
 }
 
@@ -573,6 +587,16 @@ impl Set {
         &self.0
     }
 
+    /// Returns a new set with elements from both this and another set.
+    pub(crate) fn union(
+        &self,
+        other: &SetStorage,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<Self> {
+        Ok(Self(self.0.union(other, heap, interns)?))
+    }
+
     /// Creates a set from the `set()` constructor call.
     ///
     /// - `set()` with no args returns an empty set
@@ -766,7 +790,7 @@ impl PyTrait for Set {
 /// Helper methods for set operations with arbitrary iterables.
 impl Set {
     /// Updates this set with elements from an iterable value.
-    fn update_from_value(
+    pub(crate) fn update_from_value(
         &mut self,
         other: Value,
         heap: &mut Heap<impl ResourceTracker>,
@@ -1047,14 +1071,18 @@ impl FrozenSet {
 
     /// Computes the hash of this frozenset.
     ///
-    /// The hash is the XOR of all element hashes, making it order-independent.
+    /// The hash combines the element hashes with XOR, making it order-independent. Like
+    /// CPython, each element hash is shuffled first, so that sets of small ints with the same
+    /// XOR (like `{1, 2}` and `{3}`) don't collide.
     pub fn compute_hash(&self, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Option<u64> {
         let mut hash: u64 = 0;
         for entry in &self.0.entries {
             // All elements must be hashable (enforced at construction)
             let elem_hash = entry.value.py_hash(heap, interns)?;
-            hash ^= elem_hash;
+            hash ^= ((elem_hash ^ 89_869_747) ^ (elem_hash << 16)).wrapping_mul(3_644_798_167);
         }
+        // Mix in the length so the empty set and sets of cancelling elements differ
+        hash ^= (self.0.entries.len() as u64 + 1).wrapping_mul(1_927_868_237);
         Some(hash)
     }
 
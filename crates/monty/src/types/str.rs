@@ -5,16 +5,18 @@ use std::fmt::Write;
 /// operations like length and equality comparison.
 use std::{borrow::Cow, fmt};
 
-use ahash::AHashSet;
+use ahash::{AHashMap, AHashSet};
 use smallvec::smallvec;
 
-use super::{Bytes, MontyIter, PyTrait};
+use super::{Bytes, Dict, MontyIter, PyTrait};
 use crate::{
     args::ArgValues,
-    exception_private::{ExcType, RunResult},
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
     heap::{DropWithHeap, Heap, HeapData, HeapId},
     intern::{Interns, StaticStrings, StringId},
-    resource::{DepthGuard, ResourceError, ResourceTracker},
+    resource::{DepthGuard, ResourceError, ResourceTracker, check_repeat_size},
+    str_format::format_map,
     types::Type,
     value::{EitherStr, Value},
 };
@@ -212,7 +214,7 @@ impl PyTrait for Str {
     }
 
     fn py_estimate_size(&self) -> usize {
-        std::mem::size_of::<Self>() + self.0.len()
+        std::mem::size_of::<Self>() + self.0.capacity()
     }
 
     fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
@@ -288,35 +290,6 @@ impl PyTrait for Str {
         Ok(Some(Value::Ref(id)))
     }
 
-    fn py_iadd(
-        &mut self,
-        other: Value,
-        heap: &mut Heap<impl ResourceTracker>,
-        self_id: Option<HeapId>,
-        interns: &Interns,
-    ) -> Result<bool, crate::resource::ResourceError> {
-        match &other {
-            Value::Ref(other_id) => {
-                if Some(*other_id) == self_id {
-                    let rhs = self.0.clone();
-                    self.0.push_str(&rhs);
-                } else if let HeapData::Str(rhs) = heap.get(*other_id) {
-                    self.0.push_str(rhs.as_str());
-                } else {
-                    return Ok(false);
-                }
-                // Drop the other value - we've consumed it
-                other.drop_with_heap(heap);
-                Ok(true)
-            }
-            Value::InternString(string_id) => {
-                self.0.push_str(interns.get_str(*string_id));
-                Ok(true)
-            }
-            _ => Ok(false),
-        }
-    }
-
     fn py_call_attr(
         &mut self,
         heap: &mut Heap<impl ResourceTracker>,
@@ -363,10 +336,6 @@ pub fn call_str_method(
 ///
 /// - `format()` - Requires implementing the format spec mini-language (PEP 3101),
 ///   which is complex and involves parsing format specifications like `{:>10.2f}`.
-/// - `format_map(mapping)` - Similar to `format()` but takes a mapping; depends on
-///   `format()` implementation.
-/// - `maketrans()` / `translate()` - Character translation tables; moderate complexity,
-///   requires building and applying Unicode translation maps.
 /// - `expandtabs(tabsize=8)` - Tab expansion; simple but rarely used in practice.
 /// - `isprintable()` - Checks if all characters are printable; requires accurate Unicode
 ///   category data for the "printable" property.
@@ -466,6 +435,10 @@ fn call_str_method_impl(
         StaticStrings::Ljust => str_ljust(s, args, heap, interns),
         StaticStrings::Rjust => str_rjust(s, args, heap, interns),
         StaticStrings::Zfill => str_zfill(s, args, heap),
+        StaticStrings::Translate => str_translate(s, args, heap, interns),
+        StaticStrings::FormatMap => str_format_map(s, args, heap, interns),
+        // maketrans is a static method but also accessible on instances
+        StaticStrings::Maketrans => str_maketrans(args, heap, interns),
         // Additional methods
         StaticStrings::Encode => str_encode(s, args, heap, interns),
         StaticStrings::Isidentifier => {
@@ -568,41 +541,85 @@ fn str_join(
 
 /// Writes a Python repr() string for a given string slice to a formatter.
 ///
-/// Chooses between single and double quotes based on the string content:
-/// - Uses double quotes if the string contains single quotes but not double quotes
-/// - Uses single quotes by default, escaping any contained single quotes
-///
-/// Common escape sequences (backslash, newline, tab, carriage return) are always escaped.
+/// Matches CPython's `str.__repr__`:
+/// - Uses double quotes if the string contains single quotes but not double quotes,
+///   otherwise single quotes, escaping any contained single quotes
+/// - Backslash, `\n`, `\t` and `\r` use their short escapes
+/// - Other non-printable characters use `\xNN`, `\uNNNN` or `\UNNNNNNNN`, whichever
+///   is shortest (see [`is_printable`])
 pub fn string_repr_fmt(s: &str, f: &mut impl Write) -> fmt::Result {
-    // Check if the string contains single quotes but not double quotes
-    if s.contains('\'') && !s.contains('"') {
-        // Use double quotes if string contains only single quotes
-        f.write_char('"')?;
-        for c in s.chars() {
-            match c {
-                '\\' => f.write_str("\\\\")?,
-                '\n' => f.write_str("\\n")?,
-                '\t' => f.write_str("\\t")?,
-                '\r' => f.write_str("\\r")?,
-                _ => f.write_char(c)?,
-            }
-        }
-        f.write_char('"')
+    let quote = if s.contains('\'') && !s.contains('"') {
+        '"'
     } else {
-        // Use single quotes by default, escape any single quotes in the string
-        f.write_char('\'')?;
-        for c in s.chars() {
-            match c {
-                '\\' => f.write_str("\\\\")?,
-                '\n' => f.write_str("\\n")?,
-                '\t' => f.write_str("\\t")?,
-                '\r' => f.write_str("\\r")?,
-                '\'' => f.write_str("\\'")?,
-                _ => f.write_char(c)?,
+        '\''
+    };
+    f.write_char(quote)?;
+    for c in s.chars() {
+        match c {
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\t' => f.write_str("\\t")?,
+            '\r' => f.write_str("\\r")?,
+            c if c == quote => {
+                f.write_char('\\')?;
+                f.write_char(c)?;
             }
+            c if is_printable(c) => f.write_char(c)?,
+            c => match u32::from(c) {
+                n @ 0..=0xff => write!(f, "\\x{n:02x}")?,
+                n @ 0x100..=0xffff => write!(f, "\\u{n:04x}")?,
+                n => write!(f, "\\U{n:08x}")?,
+            },
         }
-        f.write_char('\'')
     }
+    f.write_char(quote)
+}
+
+/// Returns whether `repr()` shows `c` as is rather than escaping it.
+///
+/// CPython treats every character as printable except those in the Unicode categories
+/// "Other" (`Cc`, `Cf`, `Cs`, `Co`, `Cn`) and "Separator" (`Zl`, `Zp`, `Zs`), apart from the
+/// ASCII space. The standard library has no category data, so this checks control
+/// characters, whitespace, format characters, private use characters and noncharacters;
+/// other unassigned code points are treated as printable.
+pub(crate) fn is_printable(c: char) -> bool {
+    if c == ' ' {
+        return true;
+    }
+    if c.is_control() || c.is_whitespace() {
+        return false;
+    }
+    !matches!(
+        u32::from(c),
+        // Cf: format characters
+        0xad
+            | 0x600..=0x605
+            | 0x61c
+            | 0x6dd
+            | 0x70f
+            | 0x890..=0x891
+            | 0x8e2
+            | 0x180e
+            | 0x200b..=0x200f
+            | 0x202a..=0x202e
+            | 0x2060..=0x2064
+            | 0x2066..=0x206f
+            | 0xfeff
+            | 0xfff9..=0xfffb
+            | 0x110bd
+            | 0x110cd
+            | 0x13430..=0x1343f
+            | 0x1bca0..=0x1bca3
+            | 0x1d173..=0x1d17a
+            | 0xe0001
+            | 0xe0020..=0xe007f
+            // Co: private use areas
+            | 0xe000..=0xf8ff
+            | 0xf0000..=0xffffd
+            | 0x100000..=0x10fffd
+            // Cn: noncharacters
+            | 0xfdd0..=0xfdef
+    ) && u32::from(c) & 0xfffe != 0xfffe
 }
 
 /// Formatter for a Python repr() string.
@@ -689,12 +706,162 @@ fn str_swapcase(s: &str, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Val
 /// Implements Python's `str.casefold()` method.
 ///
 /// Returns a casefolded copy of the string. Casefolding is similar to lowercasing
-/// but more aggressive because it is intended for caseless string matching.
+/// but more aggressive because it is intended for caseless string matching, so e.g.
+/// `'ß'.casefold()` is `'ss'` while `'ß'.lower()` is `'ß'`.
+///
+/// Most characters fold to their lowercase form; the exceptions are in [`CASEFOLD_SPECIAL`].
+/// The case data comes from the Unicode version of the Rust standard library, which may
+/// differ from CPython's for recently added characters.
 fn str_casefold(s: &str, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
-    // Rust's to_lowercase() is equivalent to Unicode casefolding for most purposes
-    allocate_string(s.to_lowercase(), heap)
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii() {
+            result.push(c.to_ascii_lowercase());
+        } else if let Ok(index) = CASEFOLD_SPECIAL.binary_search_by_key(&c, |&(from, _)| from) {
+            result.push_str(CASEFOLD_SPECIAL[index].1);
+        } else if matches!(c, '\u{13a0}'..='\u{13ff}' | '\u{ab70}'..='\u{abbf}') {
+            // Cherokee folds to its uppercase letters, which came first in Unicode
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+    }
+    allocate_string(result, heap)
 }
 
+/// Case foldings that differ from `char::to_lowercase()`, sorted by char.
+///
+/// These are the full foldings from Unicode's `CaseFolding.txt` (e.g. `ß` → `ss`) and the
+/// simple ones for characters that lowercase to themselves (e.g. `ſ` → `s`). Cherokee is
+/// handled separately in [`str_casefold`], since it folds to uppercase.
+const CASEFOLD_SPECIAL: &[(char, &str)] = &[
+    ('\u{b5}', "\u{3bc}"),
+    ('\u{df}', "ss"),
+    ('\u{149}', "\u{2bc}n"),
+    ('\u{17f}', "s"),
+    ('\u{1f0}', "j\u{30c}"),
+    ('\u{345}', "\u{3b9}"),
+    ('\u{390}', "\u{3b9}\u{308}\u{301}"),
+    ('\u{3b0}', "\u{3c5}\u{308}\u{301}"),
+    ('\u{3c2}', "\u{3c3}"),
+    ('\u{3d0}', "\u{3b2}"),
+    ('\u{3d1}', "\u{3b8}"),
+    ('\u{3d5}', "\u{3c6}"),
+    ('\u{3d6}', "\u{3c0}"),
+    ('\u{3f0}', "\u{3ba}"),
+    ('\u{3f1}', "\u{3c1}"),
+    ('\u{3f5}', "\u{3b5}"),
+    ('\u{587}', "\u{565}\u{582}"),
+    ('\u{1c80}', "\u{432}"),
+    ('\u{1c81}', "\u{434}"),
+    ('\u{1c82}', "\u{43e}"),
+    ('\u{1c83}', "\u{441}"),
+    ('\u{1c84}', "\u{442}"),
+    ('\u{1c85}', "\u{442}"),
+    ('\u{1c86}', "\u{44a}"),
+    ('\u{1c87}', "\u{463}"),
+    ('\u{1c88}', "\u{a64b}"),
+    ('\u{1e96}', "h\u{331}"),
+    ('\u{1e97}', "t\u{308}"),
+    ('\u{1e98}', "w\u{30a}"),
+    ('\u{1e99}', "y\u{30a}"),
+    ('\u{1e9a}', "a\u{2be}"),
+    ('\u{1e9b}', "\u{1e61}"),
+    ('\u{1e9e}', "ss"),
+    ('\u{1f50}', "\u{3c5}\u{313}"),
+    ('\u{1f52}', "\u{3c5}\u{313}\u{300}"),
+    ('\u{1f54}', "\u{3c5}\u{313}\u{301}"),
+    ('\u{1f56}', "\u{3c5}\u{313}\u{342}"),
+    ('\u{1f80}', "\u{1f00}\u{3b9}"),
+    ('\u{1f81}', "\u{1f01}\u{3b9}"),
+    ('\u{1f82}', "\u{1f02}\u{3b9}"),
+    ('\u{1f83}', "\u{1f03}\u{3b9}"),
+    ('\u{1f84}', "\u{1f04}\u{3b9}"),
+    ('\u{1f85}', "\u{1f05}\u{3b9}"),
+    ('\u{1f86}', "\u{1f06}\u{3b9}"),
+    ('\u{1f87}', "\u{1f07}\u{3b9}"),
+    ('\u{1f88}', "\u{1f00}\u{3b9}"),
+    ('\u{1f89}', "\u{1f01}\u{3b9}"),
+    ('\u{1f8a}', "\u{1f02}\u{3b9}"),
+    ('\u{1f8b}', "\u{1f03}\u{3b9}"),
+    ('\u{1f8c}', "\u{1f04}\u{3b9}"),
+    ('\u{1f8d}', "\u{1f05}\u{3b9}"),
+    ('\u{1f8e}', "\u{1f06}\u{3b9}"),
+    ('\u{1f8f}', "\u{1f07}\u{3b9}"),
+    ('\u{1f90}', "\u{1f20}\u{3b9}"),
+    ('\u{1f91}', "\u{1f21}\u{3b9}"),
+    ('\u{1f92}', "\u{1f22}\u{3b9}"),
+    ('\u{1f93}', "\u{1f23}\u{3b9}"),
+    ('\u{1f94}', "\u{1f24}\u{3b9}"),
+    ('\u{1f95}', "\u{1f25}\u{3b9}"),
+    ('\u{1f96}', "\u{1f26}\u{3b9}"),
+    ('\u{1f97}', "\u{1f27}\u{3b9}"),
+    ('\u{1f98}', "\u{1f20}\u{3b9}"),
+    ('\u{1f99}', "\u{1f21}\u{3b9}"),
+    ('\u{1f9a}', "\u{1f22}\u{3b9}"),
+    ('\u{1f9b}', "\u{1f23}\u{3b9}"),
+    ('\u{1f9c}', "\u{1f24}\u{3b9}"),
+    ('\u{1f9d}', "\u{1f25}\u{3b9}"),
+    ('\u{1f9e}', "\u{1f26}\u{3b9}"),
+    ('\u{1f9f}', "\u{1f27}\u{3b9}"),
+    ('\u{1fa0}', "\u{1f60}\u{3b9}"),
+    ('\u{1fa1}', "\u{1f61}\u{3b9}"),
+    ('\u{1fa2}', "\u{1f62}\u{3b9}"),
+    ('\u{1fa3}', "\u{1f63}\u{3b9}"),
+    ('\u{1fa4}', "\u{1f64}\u{3b9}"),
+    ('\u{1fa5}', "\u{1f65}\u{3b9}"),
+    ('\u{1fa6}', "\u{1f66}\u{3b9}"),
+    ('\u{1fa7}', "\u{1f67}\u{3b9}"),
+    ('\u{1fa8}', "\u{1f60}\u{3b9}"),
+    ('\u{1fa9}', "\u{1f61}\u{3b9}"),
+    ('\u{1faa}', "\u{1f62}\u{3b9}"),
+    ('\u{1fab}', "\u{1f63}\u{3b9}"),
+    ('\u{1fac}', "\u{1f64}\u{3b9}"),
+    ('\u{1fad}', "\u{1f65}\u{3b9}"),
+    ('\u{1fae}', "\u{1f66}\u{3b9}"),
+    ('\u{1faf}', "\u{1f67}\u{3b9}"),
+    ('\u{1fb2}', "\u{1f70}\u{3b9}"),
+    ('\u{1fb3}', "\u{3b1}\u{3b9}"),
+    ('\u{1fb4}', "\u{3ac}\u{3b9}"),
+    ('\u{1fb6}', "\u{3b1}\u{342}"),
+    ('\u{1fb7}', "\u{3b1}\u{342}\u{3b9}"),
+    ('\u{1fbc}', "\u{3b1}\u{3b9}"),
+    ('\u{1fbe}', "\u{3b9}"),
+    ('\u{1fc2}', "\u{1f74}\u{3b9}"),
+    ('\u{1fc3}', "\u{3b7}\u{3b9}"),
+    ('\u{1fc4}', "\u{3ae}\u{3b9}"),
+    ('\u{1fc6}', "\u{3b7}\u{342}"),
+    ('\u{1fc7}', "\u{3b7}\u{342}\u{3b9}"),
+    ('\u{1fcc}', "\u{3b7}\u{3b9}"),
+    ('\u{1fd2}', "\u{3b9}\u{308}\u{300}"),
+    ('\u{1fd3}', "\u{3b9}\u{308}\u{301}"),
+    ('\u{1fd6}', "\u{3b9}\u{342}"),
+    ('\u{1fd7}', "\u{3b9}\u{308}\u{342}"),
+    ('\u{1fe2}', "\u{3c5}\u{308}\u{300}"),
+    ('\u{1fe3}', "\u{3c5}\u{308}\u{301}"),
+    ('\u{1fe4}', "\u{3c1}\u{313}"),
+    ('\u{1fe6}', "\u{3c5}\u{342}"),
+    ('\u{1fe7}', "\u{3c5}\u{308}\u{342}"),
+    ('\u{1ff2}', "\u{1f7c}\u{3b9}"),
+    ('\u{1ff3}', "\u{3c9}\u{3b9}"),
+    ('\u{1ff4}', "\u{3ce}\u{3b9}"),
+    ('\u{1ff6}', "\u{3c9}\u{342}"),
+    ('\u{1ff7}', "\u{3c9}\u{342}\u{3b9}"),
+    ('\u{1ffc}', "\u{3c9}\u{3b9}"),
+    ('\u{fb00}', "ff"),
+    ('\u{fb01}', "fi"),
+    ('\u{fb02}', "fl"),
+    ('\u{fb03}', "ffi"),
+    ('\u{fb04}', "ffl"),
+    ('\u{fb05}', "st"),
+    ('\u{fb06}', "st"),
+    ('\u{fb13}', "\u{574}\u{576}"),
+    ('\u{fb14}', "\u{574}\u{565}"),
+    ('\u{fb15}', "\u{574}\u{56b}"),
+    ('\u{fb16}', "\u{57e}\u{576}"),
+    ('\u{fb17}', "\u{574}\u{56d}"),
+];
+
 // =============================================================================
 // Predicate methods (no arguments, return bool)
 // =============================================================================
@@ -957,7 +1124,9 @@ fn is_unicode_digit(c: char) -> bool {
 /// the slice s[start:end]. Returns -1 if sub is not found.
 fn str_find(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
     let (sub, start, end) = parse_search_args("str.find", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
+    let Some(slice) = search_slice(s, start, end) else {
+        return Ok(Value::Int(-1));
+    };
     let result = match slice.find(&sub) {
         Some(pos) => {
             // Convert byte offset to char offset, then add start offset
@@ -975,7 +1144,9 @@ fn str_find(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, int
 /// the slice s[start:end]. Returns -1 if sub is not found.
 fn str_rfind(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
     let (sub, start, end) = parse_search_args("str.rfind", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
+    let Some(slice) = search_slice(s, start, end) else {
+        return Ok(Value::Int(-1));
+    };
     let result = match slice.rfind(&sub) {
         Some(pos) => {
             // Convert byte offset to char offset, then add start offset
@@ -992,7 +1163,9 @@ fn str_rfind(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, in
 /// Like find(), but raises ValueError when the substring is not found.
 fn str_index(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
     let (sub, start, end) = parse_search_args("str.index", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
+    let Some(slice) = search_slice(s, start, end) else {
+        return Err(ExcType::value_error_substring_not_found());
+    };
     match slice.find(&sub) {
         Some(pos) => {
             let char_pos = slice[..pos].chars().count();
@@ -1008,7 +1181,9 @@ fn str_index(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, in
 /// Like rfind(), but raises ValueError when the substring is not found.
 fn str_rindex(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
     let (sub, start, end) = parse_search_args("str.rindex", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
+    let Some(slice) = search_slice(s, start, end) else {
+        return Err(ExcType::value_error_substring_not_found());
+    };
     match slice.rfind(&sub) {
         Some(pos) => {
             let char_pos = slice[..pos].chars().count();
@@ -1025,7 +1200,9 @@ fn str_rindex(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, i
 /// the string s[start:end].
 fn str_count(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
     let (sub, start, end) = parse_search_args("str.count", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
+    let Some(slice) = search_slice(s, start, end) else {
+        return Ok(Value::Int(0));
+    };
     let count = if sub.is_empty() {
         // Empty string matches between every character, plus start and end
         slice.chars().count() + 1
@@ -1047,7 +1224,9 @@ fn str_startswith(
     interns: &Interns,
 ) -> RunResult<Value> {
     let (prefixes, start, end) = parse_prefix_suffix_args("str.startswith", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
+    let Some(slice) = search_slice(s, start, end) else {
+        return Ok(Value::Bool(false));
+    };
     let result = prefixes.iter().any(|prefix| slice.starts_with(prefix));
     Ok(Value::Bool(result))
 }
@@ -1063,14 +1242,17 @@ fn str_endswith(
     interns: &Interns,
 ) -> RunResult<Value> {
     let (suffixes, start, end) = parse_prefix_suffix_args("str.endswith", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
+    let Some(slice) = search_slice(s, start, end) else {
+        return Ok(Value::Bool(false));
+    };
     let result = suffixes.iter().any(|suffix| slice.ends_with(suffix));
     Ok(Value::Bool(result))
 }
 
-/// Parses arguments for search methods (find, rfind, index, rindex, count, startswith, endswith).
+/// Parses arguments for search methods (find, rfind, index, rindex, count).
 ///
-/// Returns (substring, start, end) where start and end are character indices.
+/// Returns (substring, start, end) where start and end are character indices. `start` may be
+/// past `end`, which [`search_slice`] turns into a search that finds nothing.
 fn parse_search_args(
     method: &str,
     s: &str,
@@ -1101,46 +1283,22 @@ fn parse_search_args(
         return Err(ExcType::type_error_at_most(method, 3, 4));
     }
 
-    // Extract substring
-    let sub = extract_string_arg(&sub_value, heap, interns)?;
+    let sub = extract_string_arg(&sub_value, heap, interns);
     sub_value.drop_with_heap(heap);
+    let start = search_index_arg(start_value, heap);
+    let end = search_index_arg(end_value, heap);
+    let (sub, start, end) = (sub?, start?, end?);
 
-    // Extract start (default 0, None means default)
     let str_len = s.chars().count();
-    let start = if let Some(v) = start_value {
-        if matches!(v, Value::None) {
-            v.drop_with_heap(heap);
-            0
-        } else {
-            let result = extract_int_arg(&v, heap)?;
-            v.drop_with_heap(heap);
-            normalize_index(result, str_len)
-        }
-    } else {
-        0
-    };
-
-    // Extract end (default len, None means default)
-    let end = if let Some(v) = end_value {
-        if matches!(v, Value::None) {
-            v.drop_with_heap(heap);
-            str_len
-        } else {
-            let result = extract_int_arg(&v, heap)?;
-            v.drop_with_heap(heap);
-            normalize_index(result, str_len)
-        }
-    } else {
-        str_len
-    };
-
+    let start = start.map_or(0, |start| normalize_start(start, str_len));
+    let end = end.map_or(str_len, |end| normalize_index(end, str_len));
     Ok((sub, start, end))
 }
 
 /// Parses arguments for startswith/endswith methods.
 ///
-/// Returns (prefixes/suffixes as Vec, start, end) where start and end are character indices.
-/// The first argument can be either a string or a tuple of strings.
+/// Returns (prefixes/suffixes as Vec, start, end) where start and end are character indices,
+/// as for [`parse_search_args`]. The first argument can be either a string or a tuple of strings.
 fn parse_prefix_suffix_args(
     method: &str,
     s: &str,
@@ -1172,38 +1330,15 @@ fn parse_prefix_suffix_args(
     }
 
     // Extract prefix/suffix - can be a string or tuple of strings
-    let prefixes = extract_str_or_tuple_of_str(&prefix_value, heap, interns)?;
+    let prefixes = extract_str_or_tuple_of_str(&prefix_value, heap, interns);
     prefix_value.drop_with_heap(heap);
+    let start = search_index_arg(start_value, heap);
+    let end = search_index_arg(end_value, heap);
+    let (prefixes, start, end) = (prefixes?, start?, end?);
 
-    // Extract start (default 0, None means default)
     let str_len = s.chars().count();
-    let start = if let Some(v) = start_value {
-        if matches!(v, Value::None) {
-            v.drop_with_heap(heap);
-            0
-        } else {
-            let result = extract_int_arg(&v, heap)?;
-            v.drop_with_heap(heap);
-            normalize_index(result, str_len)
-        }
-    } else {
-        0
-    };
-
-    // Extract end (default len, None means default)
-    let end = if let Some(v) = end_value {
-        if matches!(v, Value::None) {
-            v.drop_with_heap(heap);
-            str_len
-        } else {
-            let result = extract_int_arg(&v, heap)?;
-            v.drop_with_heap(heap);
-            normalize_index(result, str_len)
-        }
-    } else {
-        str_len
-    };
-
+    let start = start.map_or(0, |start| normalize_start(start, str_len));
+    let end = end.map_or(str_len, |end| normalize_index(end, str_len));
     Ok((prefixes, start, end))
 }
 
@@ -1266,6 +1401,31 @@ fn extract_int_arg(value: &Value, heap: &Heap<impl ResourceTracker>) -> RunResul
     }
 }
 
+/// Extracts the optional `start` or `end` argument of a search method, where `None` means the default.
+///
+/// Like CPython's slice indices, bools are accepted and ints beyond the i64 range saturate
+/// rather than raising.
+fn search_index_arg(value: Option<Value>, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Option<i64>> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    defer_drop!(value, heap);
+    match value {
+        Value::None => return Ok(None),
+        Value::Int(i) => return Ok(Some(*i)),
+        Value::Bool(b) => return Ok(Some(i64::from(*b))),
+        Value::Ref(id) => {
+            if let HeapData::LongInt(li) = heap.get(*id) {
+                return Ok(Some(if li.is_negative() { i64::MIN } else { i64::MAX }));
+            }
+        }
+        _ => {}
+    }
+    Err(ExcType::type_error(
+        "slice indices must be integers or None or have an __index__ method",
+    ))
+}
+
 /// Normalizes a Python-style index to a valid index in range [0, len].
 fn normalize_index(index: i64, len: usize) -> usize {
     if index < 0 {
@@ -1280,6 +1440,25 @@ fn normalize_index(index: i64, len: usize) -> usize {
     }
 }
 
+/// Normalizes the `start` of a search like [`normalize_index`], except that a start past the
+/// end of the string stays past it rather than being clamped to `len`.
+fn normalize_start(index: i64, len: usize) -> usize {
+    if index < 0 {
+        normalize_index(index, len)
+    } else {
+        usize::try_from(index).unwrap_or(usize::MAX)
+    }
+}
+
+/// Returns the part of `s` a search from character index `start` to `end` looks at.
+///
+/// Like CPython, a `start` past `end` (including past the end of the string) gives `None`:
+/// nothing is found there, not even an empty string, whereas `start == end` searches an
+/// empty string.
+fn search_slice(s: &str, start: usize, end: usize) -> Option<&str> {
+    (start <= end).then(|| slice_string(s, start, end))
+}
+
 /// Returns a substring of s from character index start to end.
 fn slice_string(s: &str, start: usize, end: usize) -> &str {
     if start >= end {
@@ -1788,8 +1967,8 @@ fn str_partition(
     interns: &Interns,
 ) -> RunResult<Value> {
     let sep_value = args.get_one_arg("str.partition", heap)?;
-    let sep = extract_string_arg(&sep_value, heap, interns)?;
-    sep_value.drop_with_heap(heap);
+    defer_drop!(sep_value, heap);
+    let sep = extract_separator_arg(sep_value, heap, interns)?;
 
     if sep.is_empty() {
         return Err(ExcType::value_error_empty_separator());
@@ -1821,8 +2000,8 @@ fn str_rpartition(
     interns: &Interns,
 ) -> RunResult<Value> {
     let sep_value = args.get_one_arg("str.rpartition", heap)?;
-    let sep = extract_string_arg(&sep_value, heap, interns)?;
-    sep_value.drop_with_heap(heap);
+    defer_drop!(sep_value, heap);
+    let sep = extract_separator_arg(sep_value, heap, interns)?;
 
     if sep.is_empty() {
         return Err(ExcType::value_error_empty_separator());
@@ -1843,6 +2022,21 @@ fn str_rpartition(
     )?)
 }
 
+/// Extracts the `sep` argument of `str.partition()`/`str.rpartition()`.
+///
+/// Unlike `extract_string_arg`, this reports a non-string separator with CPython's
+/// `must be str, not {type}` message.
+fn extract_separator_arg(value: &Value, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<String> {
+    match value {
+        Value::InternString(id) => Ok(interns.get_str(*id).to_owned()),
+        Value::Ref(heap_id) => match heap.get(*heap_id) {
+            HeapData::Str(s) => Ok(s.as_str().to_owned()),
+            _ => Err(ExcType::type_error_must_be_str(value.py_type(heap))),
+        },
+        _ => Err(ExcType::type_error_must_be_str(value.py_type(heap))),
+    }
+}
+
 // =============================================================================
 // Replace/modify methods
 // =============================================================================
@@ -2116,6 +2310,247 @@ fn str_zfill(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>) ->
     allocate_string(result, heap)
 }
 
+/// Implements Python's `str.translate(table)` method.
+///
+/// Each character is looked up by its code point in `table`: a missing entry keeps the
+/// character, `None` deletes it, and an int or str replaces it. CPython accepts any object
+/// with `__getitem__`; here the table must be a dict (usually built by `str.maketrans()`),
+/// list or tuple.
+fn str_translate(
+    s: &str,
+    args: ArgValues,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Value> {
+    let table = args.get_one_arg("str.translate", heap)?;
+    defer_drop!(table, heap);
+
+    let mut result = String::with_capacity(s.len());
+    match table_entries(table, heap) {
+        Some(TranslateTable::Dict(entries)) => {
+            for c in s.chars() {
+                match entries.get(&i64::from(u32::from(c))) {
+                    Some(mapping) => push_translated(&mut result, mapping, heap, interns)?,
+                    None => result.push(c),
+                }
+            }
+        }
+        Some(TranslateTable::Sequence(items)) => {
+            for c in s.chars() {
+                match usize::try_from(u32::from(c)).ok().and_then(|index| items.get(index)) {
+                    Some(mapping) => push_translated(&mut result, mapping, heap, interns)?,
+                    None => result.push(c),
+                }
+            }
+        }
+        None => {
+            return Err(ExcType::type_error(format!(
+                "'{}' object is not subscriptable",
+                table.py_type(heap)
+            )));
+        }
+    }
+    allocate_string(result, heap)
+}
+
+/// Implements Python's `str.format_map(mapping)` method.
+///
+/// Fills the `{key}` replacement fields of the template from `mapping`; see
+/// [`crate::str_format`] for the supported syntax.
+fn str_format_map(
+    s: &str,
+    args: ArgValues,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Value> {
+    let mapping = args.get_one_arg("str.format_map", heap)?;
+    defer_drop!(mapping, heap);
+    let result = format_map(s, mapping, heap, interns)?;
+    allocate_string(result, heap)
+}
+
+/// The entries of a `str.translate()` table, borrowed from the heap.
+enum TranslateTable<'a> {
+    /// Dict entries with int keys; other keys can never match a code point.
+    Dict(AHashMap<i64, &'a Value>),
+    /// The items of a list or tuple, indexed by code point.
+    Sequence(&'a [Value]),
+}
+
+/// Borrows the entries of a `str.translate()` table, or returns `None` if it's not a
+/// supported table type.
+fn table_entries<'a>(table: &Value, heap: &'a Heap<impl ResourceTracker>) -> Option<TranslateTable<'a>> {
+    let Value::Ref(id) = table else {
+        return None;
+    };
+    match heap.get(*id) {
+        HeapData::Dict(dict) => {
+            let entries = dict
+                .iter()
+                .filter_map(|(key, value)| match key {
+                    Value::Int(i) => Some((*i, value)),
+                    Value::Bool(b) => Some((i64::from(*b), value)),
+                    _ => None,
+                })
+                .collect();
+            Some(TranslateTable::Dict(entries))
+        }
+        HeapData::List(list) => Some(TranslateTable::Sequence(list.as_slice())),
+        HeapData::Tuple(tuple) => Some(TranslateTable::Sequence(tuple.as_slice())),
+        _ => None,
+    }
+}
+
+/// Appends the replacement for one character of `str.translate()` to `result`.
+fn push_translated(
+    result: &mut String,
+    mapping: &Value,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<()> {
+    let code_point = match mapping {
+        Value::None => return Ok(()),
+        Value::Bool(b) => u32::from(*b),
+        Value::Int(i) => u32::try_from(*i).unwrap_or(u32::MAX),
+        other => {
+            if let Some(replacement) = other.as_either_str(heap) {
+                result.push_str(replacement.as_str(interns));
+                // Replacements can make the result much larger than the input
+                check_repeat_size(result.len(), 1, heap.tracker())?;
+                return Ok(());
+            }
+            if other.py_type(heap) != Type::Int {
+                return Err(ExcType::type_error(
+                    "character mapping must return integer, None or str",
+                ));
+            }
+            // Big ints are never valid code points
+            u32::MAX
+        }
+    };
+    let Some(c) = char::from_u32(code_point) else {
+        return Err(
+            SimpleException::new_msg(ExcType::ValueError, "character mapping must be in range(0x110000)").into(),
+        );
+    };
+    result.push(c);
+    Ok(())
+}
+
+/// Implements Python's `str.maketrans(x[, y[, z]])` static method.
+///
+/// Builds a translation table for `str.translate()`:
+/// - With one argument, `x` must be a dict mapping characters or code points to
+///   replacements; character keys are converted to code points.
+/// - With two, `x` and `y` are strings of equal length, each character of `x` mapping to
+///   the character of `y` at the same position.
+/// - A third string `z` lists characters mapped to `None`, i.e. deleted.
+pub fn str_maketrans(args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+    let args: Vec<Value> = args.into_pos_only("str.maketrans", heap)?.collect();
+    defer_drop!(args, heap);
+
+    let pairs = match args.as_slice() {
+        [] => return Err(ExcType::type_error_at_least("maketrans", 1, 0)),
+        [table] => maketrans_from_dict(table, heap, interns)?,
+        [x, y, rest @ ..] if rest.len() <= 1 => {
+            let Some(x) = x.as_either_str(heap) else {
+                return Err(ExcType::type_error(
+                    "first maketrans argument must be a string if there is a second argument",
+                ));
+            };
+            let strings = std::iter::once(y).chain(rest).zip(2..);
+            let mut texts = Vec::with_capacity(2);
+            for (arg, position) in strings {
+                match arg.as_either_str(heap) {
+                    Some(text) => texts.push(text),
+                    None => {
+                        return Err(ExcType::type_error(format!(
+                            "maketrans() argument {position} must be str, not {}",
+                            arg.py_type(heap)
+                        )));
+                    }
+                }
+            }
+            let (x, y) = (x.as_str(interns), texts[0].as_str(interns));
+            if x.chars().count() != y.chars().count() {
+                return Err(SimpleException::new_msg(
+                    ExcType::ValueError,
+                    "the first two maketrans arguments must have equal length",
+                )
+                .into());
+            }
+            let code_point = |c: char| Value::Int(i64::from(u32::from(c)));
+            let mut pairs: Vec<(Value, Value)> = x.chars().map(code_point).zip(y.chars().map(code_point)).collect();
+            if let Some(deleted) = texts.get(1) {
+                pairs.extend(deleted.as_str(interns).chars().map(|c| (code_point(c), Value::None)));
+            }
+            pairs
+        }
+        _ => return Err(ExcType::type_error_at_most("maketrans", 3, args.len())),
+    };
+
+    let dict = Dict::from_pairs(pairs, heap, interns)?;
+    Ok(Value::Ref(heap.allocate(HeapData::Dict(dict))?))
+}
+
+/// Converts the dict argument of `str.maketrans()` into the pairs of the table, with
+/// single-character keys replaced by their code points.
+fn maketrans_from_dict(
+    table: &Value,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Vec<(Value, Value)>> {
+    let dict = match table {
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Dict(dict) => dict,
+            _ => return Err(maketrans_not_dict()),
+        },
+        _ => return Err(maketrans_not_dict()),
+    };
+
+    // Copy the pairs first (without incrementing refcounts) so every key is checked
+    // before any refcount changes
+    let mut pairs = Vec::with_capacity(dict.len());
+    for (key, value) in dict {
+        let key = if let Some(key) = key.as_either_str(heap) {
+            let mut chars = key.as_str(interns).chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Value::Int(i64::from(u32::from(c))),
+                _ => {
+                    return Err(SimpleException::new_msg(
+                        ExcType::ValueError,
+                        "string keys in translate table must be of length 1",
+                    )
+                    .into());
+                }
+            }
+        } else if matches!(key.py_type(heap), Type::Int | Type::Bool) {
+            key.copy_for_extend()
+        } else {
+            return Err(ExcType::type_error(
+                "keys in translate table must be strings or integers",
+            ));
+        };
+        pairs.push((key, value.copy_for_extend()));
+    }
+
+    // Now the borrow of the dict is over, so increment the refcounts
+    for (key, value) in &pairs {
+        if let Value::Ref(id) = key {
+            heap.inc_ref(*id);
+        }
+        if let Value::Ref(id) = value {
+            heap.inc_ref(*id);
+        }
+    }
+    Ok(pairs)
+}
+
+#[cold]
+fn maketrans_not_dict() -> RunError {
+    ExcType::type_error("if you give only one argument to maketrans it must be a dict")
+}
+
 /// Implements Python's `str.encode(encoding='utf-8', errors='strict')` method.
 ///
 /// Returns an encoded version of the string as a bytes object. Only supports
@@ -14,7 +14,11 @@
 /// - `count(value)` - Count occurrences
 ///
 /// All tuple methods from Python's builtins are implemented.
-use std::fmt::Write;
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+};
 
 use ahash::AHashSet;
 use smallvec::SmallVec;
@@ -63,6 +67,22 @@ pub(crate) struct Tuple {
     contains_refs: bool,
 }
 
+/// Hashes the items of a tuple, or a named tuple, which hashes the same as the equal tuple.
+///
+/// Returns `None` if any item is unhashable.
+pub(crate) fn hash_tuple_items(
+    items: &[Value],
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    items.len().hash(&mut hasher);
+    for item in items {
+        item.py_hash(heap, interns)?.hash(&mut hasher);
+    }
+    Some(hasher.finish())
+}
+
 impl Tuple {
     /// Creates a new tuple from a vector of values.
     ///
@@ -281,6 +301,17 @@ impl PyTrait for Tuple {
         guard: &mut DepthGuard,
         interns: &Interns,
     ) -> std::fmt::Result {
+        // A single-item tuple needs a trailing comma, `(1)` would just be a parenthesized int
+        if let [item] = self.items.as_slice() {
+            if !guard.increase() {
+                return f.write_str("...");
+            }
+            f.write_char('(')?;
+            item.py_repr_fmt(f, heap, heap_ids, guard, interns)?;
+            f.write_str(",)")?;
+            guard.decrease();
+            return Ok(());
+        }
         repr_sequence_fmt('(', ')', &self.items, f, heap, heap_ids, guard, interns)
     }
 }
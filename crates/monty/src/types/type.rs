@@ -1,6 +1,7 @@
 use std::fmt;
 
 use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 use strum::EnumString;
 
 use crate::{
@@ -9,9 +10,11 @@ use crate::{
     exception_private::{ExcType, RunError, RunResult, SimpleException},
     heap::{Heap, HeapData},
     intern::Interns,
+    modules::math::{IntRounding, round_to_int},
     resource::ResourceTracker,
     types::{
-        Bytes, Dict, FrozenSet, List, LongInt, MontyIter, Path, PyTrait, Range, Set, Slice, Str, Tuple, str::StringRepr,
+        ByteArray, Bytes, Dict, FrozenSet, List, LongInt, MontyIter, Path, PyTrait, Range, Set, Slice, Str, Tuple,
+        str::StringRepr,
     },
     value::Value,
 };
@@ -30,6 +33,7 @@ pub enum Type {
     Ellipsis,
     Type,
     NoneType,
+    NotImplementedType,
     Bool,
     Int,
     Float,
@@ -37,6 +41,7 @@ pub enum Type {
     Slice,
     Str,
     Bytes,
+    ByteArray,
     List,
     Tuple,
     NamedTuple,
@@ -53,10 +58,22 @@ pub enum Type {
     Iterator,
     /// Coroutine type for async functions and external futures.
     Coroutine,
+    /// Generator objects from generator expressions - not a builtin name
+    #[strum(disabled)]
+    Generator,
     Module,
     /// Marker types like stdout/stderr - displays as "TextIOWrapper"
     #[strum(serialize = "TextIOWrapper")]
     TextIOWrapper,
+    /// Files opened with `open(..., 'rb')` - displays as "_io.BufferedReader"
+    #[strum(disabled)]
+    BufferedReader,
+    /// Files opened with `open(..., 'wb')`, `'ab'` or `'xb'` - displays as "_io.BufferedWriter"
+    #[strum(disabled)]
+    BufferedWriter,
+    /// Binary files opened for both reading and writing (`'r+b'` etc.) - displays as "_io.BufferedRandom"
+    #[strum(disabled)]
+    BufferedRandom,
     /// typing module special forms (Any, Optional, Union, etc.) - displays as "typing._SpecialForm"
     #[strum(serialize = "typing._SpecialForm")]
     SpecialForm,
@@ -66,6 +83,24 @@ pub enum Type {
     /// A property descriptor - displays as "property"
     #[strum(serialize = "property")]
     Property,
+    /// A compiled regular expression from `re.compile()` - displays as "re.Pattern"
+    #[strum(serialize = "re.Pattern")]
+    RePattern,
+    /// A regular expression match - displays as "re.Match"
+    #[strum(serialize = "re.Match")]
+    ReMatch,
+    /// A callable from `operator.itemgetter()` - displays as "operator.itemgetter"
+    #[strum(serialize = "operator.itemgetter")]
+    ItemGetter,
+    /// A callable from `operator.attrgetter()` - displays as "operator.attrgetter"
+    #[strum(serialize = "operator.attrgetter")]
+    AttrGetter,
+    /// A context manager from `contextlib.suppress()` - displays as "contextlib.suppress"
+    #[strum(serialize = "contextlib.suppress")]
+    Suppress,
+    /// A context manager from `contextlib.nullcontext()` - displays as "contextlib.nullcontext"
+    #[strum(serialize = "contextlib.nullcontext")]
+    NullContext,
 }
 
 impl fmt::Display for Type {
@@ -74,6 +109,7 @@ impl fmt::Display for Type {
             Self::Ellipsis => f.write_str("ellipsis"),
             Self::Type => f.write_str("type"),
             Self::NoneType => f.write_str("NoneType"),
+            Self::NotImplementedType => f.write_str("NotImplementedType"),
             Self::Bool => f.write_str("bool"),
             Self::Int => f.write_str("int"),
             Self::Float => f.write_str("float"),
@@ -81,6 +117,7 @@ impl fmt::Display for Type {
             Self::Slice => f.write_str("slice"),
             Self::Str => f.write_str("str"),
             Self::Bytes => f.write_str("bytes"),
+            Self::ByteArray => f.write_str("bytearray"),
             Self::List => f.write_str("list"),
             Self::Tuple => f.write_str("tuple"),
             Self::NamedTuple => f.write_str("namedtuple"),
@@ -94,11 +131,21 @@ impl fmt::Display for Type {
             Self::Cell => f.write_str("cell"),
             Self::Iterator => f.write_str("iterator"),
             Self::Coroutine => f.write_str("coroutine"),
+            Self::Generator => f.write_str("generator"),
             Self::Module => f.write_str("module"),
             Self::TextIOWrapper => f.write_str("_io.TextIOWrapper"),
+            Self::BufferedReader => f.write_str("_io.BufferedReader"),
+            Self::BufferedWriter => f.write_str("_io.BufferedWriter"),
+            Self::BufferedRandom => f.write_str("_io.BufferedRandom"),
             Self::SpecialForm => f.write_str("typing._SpecialForm"),
             Self::Path => f.write_str("PosixPath"),
             Self::Property => f.write_str("property"),
+            Self::RePattern => f.write_str("re.Pattern"),
+            Self::ReMatch => f.write_str("re.Match"),
+            Self::ItemGetter => f.write_str("operator.itemgetter"),
+            Self::AttrGetter => f.write_str("operator.attrgetter"),
+            Self::Suppress => f.write_str("contextlib.suppress"),
+            Self::NullContext => f.write_str("contextlib.nullcontext"),
         }
     }
 }
@@ -141,6 +188,7 @@ impl Type {
             Self::Slice => Some(11),
             Self::Iterator => Some(12),
             Self::Path => Some(13),
+            Self::ByteArray => Some(14),
             _ => None,
         }
     }
@@ -165,6 +213,7 @@ impl Type {
             11 => Some(Self::Slice),
             12 => Some(Self::Iterator),
             13 => Some(Self::Path),
+            14 => Some(Self::ByteArray),
             _ => None,
         }
     }
@@ -188,6 +237,7 @@ impl Type {
             Self::FrozenSet => FrozenSet::init(heap, args, interns),
             Self::Str => Str::init(heap, args, interns),
             Self::Bytes => Bytes::init(heap, args, interns),
+            Self::ByteArray => ByteArray::init(heap, args, interns),
             Self::Range => Range::init(heap, args),
             Self::Slice => Slice::init(heap, args),
             Self::Iterator => MontyIter::init(heap, args, interns),
@@ -199,22 +249,20 @@ impl Type {
                     return Ok(Value::Int(0));
                 };
                 defer_drop!(v, heap);
+                // Numbers truncate toward zero, sharing the rounding used by `math.trunc()`
+                if let Some(int) = round_to_int(v, IntRounding::Trunc, heap)? {
+                    return Ok(int);
+                }
                 match v {
-                    Value::Int(i) => Ok(Value::Int(*i)),
-                    Value::Float(f) => Ok(Value::Int(f64_to_i64_truncate(*f))),
-                    Value::Bool(b) => Ok(Value::Int(i64::from(*b))),
                     Value::InternString(string_id) => parse_int_from_str(interns.get_str(*string_id), heap),
-                    Value::Ref(heap_id) => {
-                        // Clone data to release the borrow on heap before mutation
-                        match heap.get(*heap_id) {
-                            HeapData::Str(s) => {
-                                let s = s.to_string();
-                                parse_int_from_str(&s, heap)
-                            }
-                            HeapData::LongInt(li) => li.clone().into_value(heap).map_err(Into::into),
-                            _ => Err(ExcType::type_error_int_conversion(v.py_type(heap))),
+                    Value::Ref(heap_id) => match heap.get(*heap_id) {
+                        HeapData::Str(s) => {
+                            // Clone data to release the borrow on heap before mutation
+                            let s = s.to_string();
+                            parse_int_from_str(&s, heap)
                         }
-                    }
+                        _ => Err(ExcType::type_error_int_conversion(v.py_type(heap))),
+                    },
                     _ => Err(ExcType::type_error_int_conversion(v.py_type(heap))),
                 }
             }
@@ -232,6 +280,14 @@ impl Type {
                     }
                     Value::Ref(heap_id) => match heap.get(*heap_id) {
                         HeapData::Str(s) => Ok(Value::Float(parse_f64_from_str(s.as_str())?)),
+                        HeapData::LongInt(li) => match li.inner().to_f64() {
+                            Some(f) if f.is_finite() => Ok(Value::Float(f)),
+                            _ => Err(SimpleException::new_msg(
+                                ExcType::OverflowError,
+                                "int too large to convert to float",
+                            )
+                            .into()),
+                        },
                         _ => Err(ExcType::type_error_float_conversion(v.py_type(heap))),
                     },
                     _ => Err(ExcType::type_error_float_conversion(v.py_type(heap))),
@@ -242,7 +298,7 @@ impl Type {
                     return Ok(Value::Bool(false));
                 };
                 defer_drop!(v, heap);
-                Ok(Value::Bool(v.py_bool(heap, interns)))
+                Ok(Value::Bool(v.to_bool(heap, interns)?))
             }
 
             // Non-callable types - raise TypeError
@@ -251,27 +307,6 @@ impl Type {
     }
 }
 
-/// Truncates f64 to i64 with clamping for out-of-range values.
-///
-/// Python's `int(float)` truncates toward zero. For values outside i64 range,
-/// we clamp to i64::MAX/MIN (Python would use arbitrary precision ints, which
-/// we don't support).
-fn f64_to_i64_truncate(value: f64) -> i64 {
-    // trunc() rounds toward zero, matching Python's int(float) behavior
-    let truncated = value.trunc();
-    if truncated >= i64::MAX as f64 {
-        i64::MAX
-    } else if truncated <= i64::MIN as f64 {
-        i64::MIN
-    } else {
-        // SAFETY for clippy: truncated is guaranteed to be in (i64::MIN, i64::MAX)
-        // after the bounds checks above, so truncation cannot overflow
-        #[expect(clippy::cast_possible_truncation, reason = "bounds checked above")]
-        let result = truncated as i64;
-        result
-    }
-}
-
 /// Parses a Python `float()` string argument into an `f64`.
 ///
 /// This supports:
@@ -22,9 +22,10 @@ use crate::{
     modules::ModuleFunctions,
     resource::{DepthGuard, ResourceError, ResourceTracker, check_lshift_size, check_pow_size, check_repeat_size},
     types::{
-        AttrCallResult, LongInt, Property, PyTrait, Str, Type,
+        AttrCallResult, ByteArray, LongInt, MontyIter, Property, PyTrait, Str, Type, bytearray,
         bytes::{bytes_repr_fmt, get_byte_at_index, get_bytes_slice},
         path,
+        set::SetStorage,
         str::{allocate_char, get_char_at_index, get_str_slice, string_repr_fmt},
     },
 };
@@ -46,6 +47,8 @@ pub(crate) enum Value {
     Undefined,
     Ellipsis,
     None,
+    /// The `NotImplemented` singleton, returned by binary operations to request the reflected operation.
+    NotImplemented,
     Bool(bool),
     Int(i64),
     Float(f64),
@@ -118,6 +121,7 @@ impl PyTrait for Value {
             Self::Undefined => panic!("Cannot get type of undefined value"),
             Self::Ellipsis => Type::Ellipsis,
             Self::None => Type::NoneType,
+            Self::NotImplemented => Type::NotImplementedType,
             Self::Bool(_) => Type::Bool,
             Self::Int(_) | Self::InternLongInt(_) => Type::Int,
             Self::Float(_) => Type::Float,
@@ -169,11 +173,14 @@ impl PyTrait for Value {
             (Self::Bool(v1), Self::Int(v2)) => Ok(i64::from(*v1) == *v2),
             (Self::Int(v1), Self::Bool(v2)) => Ok(*v1 == i64::from(*v2)),
             (Self::Float(v1), Self::Float(v2)) => Ok(v1 == v2),
-            (Self::Int(v1), Self::Float(v2)) => Ok((*v1 as f64) == *v2),
-            (Self::Float(v1), Self::Int(v2)) => Ok(*v1 == (*v2 as f64)),
+            (Self::Int(v1), Self::Float(v2)) | (Self::Float(v2), Self::Int(v1)) => {
+                Ok(int_cmp_float(*v1, *v2) == Some(Ordering::Equal))
+            }
             (Self::Bool(v1), Self::Float(v2)) => Ok((i64::from(*v1) as f64) == *v2),
             (Self::Float(v1), Self::Bool(v2)) => Ok(*v1 == (i64::from(*v2) as f64)),
             (Self::None, Self::None) => Ok(true),
+            (Self::Ellipsis, Self::Ellipsis) => Ok(true),
+            (Self::NotImplemented, Self::NotImplemented) => Ok(true),
 
             // Int == LongInt comparison
             (Self::Int(a), Self::Ref(id)) => {
@@ -191,10 +198,18 @@ impl PyTrait for Value {
                     Ok(false)
                 }
             }
+            // LongInt == Float comparison, in either order
+            (Self::Float(f), Self::Ref(id)) | (Self::Ref(id), Self::Float(f)) => {
+                if let HeapData::LongInt(li) = heap.get(*id) {
+                    Ok(li.eq_float(*f))
+                } else {
+                    Ok(false)
+                }
+            }
 
-            // For interned interns, compare by StringId first (fast path for same interned string)
+            // Interned strings are deduplicated, so equal contents always share a `StringId`
             (Self::InternString(s1), Self::InternString(s2)) => Ok(s1 == s2),
-            // for strings we need to account for the fact they might be either interned or not
+            // Strings built at runtime live on the heap, so compare contents across both representations
             (Self::InternString(string_id), Self::Ref(id2)) => {
                 if let HeapData::Str(s2) = heap.get(*id2) {
                     Ok(interns.get_str(*string_id) == s2.as_str())
@@ -216,20 +231,16 @@ impl PyTrait for Value {
                 Ok(b1 == b2 || interns.get_bytes(*b1) == interns.get_bytes(*b2))
             }
             // same for bytes
-            (Self::InternBytes(bytes_id), Self::Ref(id2)) => {
-                if let HeapData::Bytes(b2) = heap.get(*id2) {
-                    Ok(interns.get_bytes(*bytes_id) == b2.as_slice())
-                } else {
-                    Ok(false)
-                }
-            }
-            (Self::Ref(id1), Self::InternBytes(bytes_id)) => {
-                if let HeapData::Bytes(b1) = heap.get(*id1) {
-                    Ok(b1.as_slice() == interns.get_bytes(*bytes_id))
-                } else {
-                    Ok(false)
-                }
-            }
+            (Self::InternBytes(bytes_id), Self::Ref(id2)) => match heap.get(*id2) {
+                HeapData::Bytes(b2) => Ok(interns.get_bytes(*bytes_id) == b2.as_slice()),
+                HeapData::ByteArray(b2) => Ok(interns.get_bytes(*bytes_id) == b2.as_slice()),
+                _ => Ok(false),
+            },
+            (Self::Ref(id1), Self::InternBytes(bytes_id)) => match heap.get(*id1) {
+                HeapData::Bytes(b1) => Ok(b1.as_slice() == interns.get_bytes(*bytes_id)),
+                HeapData::ByteArray(b1) => Ok(b1.as_slice() == interns.get_bytes(*bytes_id)),
+                _ => Ok(false),
+            },
 
             (Self::Ref(id1), Self::Ref(id2)) => {
                 if *id1 == *id2 {
@@ -260,14 +271,13 @@ impl PyTrait for Value {
         guard: &mut DepthGuard,
         interns: &Interns,
     ) -> Result<Option<Ordering>, ResourceError> {
-        // py_cmp currently only handles non-recursive types (numbers, strings, bytes)
-        // so we don't need to recurse through the guard. The guard parameter exists
-        // for API consistency with py_eq.
+        // Numbers, strings and bytes are compared directly; lists and tuples recurse into
+        // their items through `cmp_heap_data`, which uses the guard.
         match (self, other) {
             (Self::Int(s), Self::Int(o)) => Ok(s.partial_cmp(o)),
             (Self::Float(s), Self::Float(o)) => Ok(s.partial_cmp(o)),
-            (Self::Int(s), Self::Float(o)) => Ok((*s as f64).partial_cmp(o)),
-            (Self::Float(s), Self::Int(o)) => Ok(s.partial_cmp(&(*o as f64))),
+            (Self::Int(s), Self::Float(o)) => Ok(int_cmp_float(*s, *o)),
+            (Self::Float(s), Self::Int(o)) => Ok(int_cmp_float(*o, *s).map(Ordering::reverse)),
             // Bool promotion: convert to Int and re-dispatch. Recursion is bounded
             // to at most 2 levels (Bool→Int, then Int matches directly above).
             (Self::Bool(s), _) => Self::Int(i64::from(*s)).py_cmp(other, heap, guard, interns),
@@ -288,6 +298,15 @@ impl PyTrait for Value {
                     Ok(None)
                 }
             }
+            // LongInt vs Float comparison, in either order
+            (Self::Ref(id), Self::Float(f)) => match heap.get(*id) {
+                HeapData::LongInt(li) => Ok(li.cmp_float(*f)),
+                _ => Ok(None),
+            },
+            (Self::Float(f), Self::Ref(id)) => match heap.get(*id) {
+                HeapData::LongInt(li) => Ok(li.cmp_float(*f).map(Ordering::reverse)),
+                _ => Ok(None),
+            },
             // LongInt vs LongInt comparison
             (Self::Ref(id1), Self::Ref(id2)) => {
                 let is_longint1 = matches!(heap.get(*id1), HeapData::LongInt(_));
@@ -301,15 +320,34 @@ impl PyTrait for Value {
                         }
                     }))
                 } else {
-                    Ok(None)
+                    heap.with_two(*id1, *id2, |heap, left, right| {
+                        cmp_heap_data(left, right, heap, guard, interns)
+                    })
                 }
             }
             (Self::InternString(s1), Self::InternString(s2)) => {
                 Ok(interns.get_str(*s1).partial_cmp(interns.get_str(*s2)))
             }
+            // strings and bytes may be interned or on the heap
+            (Self::InternString(string_id), Self::Ref(id)) => match heap.get(*id) {
+                HeapData::Str(s) => Ok(interns.get_str(*string_id).partial_cmp(s.as_str())),
+                _ => Ok(None),
+            },
+            (Self::Ref(id), Self::InternString(string_id)) => match heap.get(*id) {
+                HeapData::Str(s) => Ok(s.as_str().partial_cmp(interns.get_str(*string_id))),
+                _ => Ok(None),
+            },
             (Self::InternBytes(b1), Self::InternBytes(b2)) => {
                 Ok(interns.get_bytes(*b1).partial_cmp(interns.get_bytes(*b2)))
             }
+            (Self::InternBytes(bytes_id), Self::Ref(id)) => match heap.get(*id) {
+                HeapData::Bytes(b) => Ok(interns.get_bytes(*bytes_id).partial_cmp(b.as_slice())),
+                _ => Ok(None),
+            },
+            (Self::Ref(id), Self::InternBytes(bytes_id)) => match heap.get(*id) {
+                HeapData::Bytes(b) => Ok(b.as_slice().partial_cmp(interns.get_bytes(*bytes_id))),
+                _ => Ok(None),
+            },
             _ => Ok(None),
         }
     }
@@ -328,6 +366,7 @@ impl PyTrait for Value {
             Self::Undefined => false,
             Self::Ellipsis => true,
             Self::None => false,
+            Self::NotImplemented => true,
             Self::Bool(b) => *b,
             Self::Int(v) => *v != 0,
             Self::Float(f) => *f != 0.0,
@@ -358,23 +397,17 @@ impl PyTrait for Value {
             Self::Undefined => f.write_str("Undefined"),
             Self::Ellipsis => f.write_str("Ellipsis"),
             Self::None => f.write_str("None"),
+            Self::NotImplemented => f.write_str("NotImplemented"),
             Self::Bool(true) => f.write_str("True"),
             Self::Bool(false) => f.write_str("False"),
             Self::Int(v) => write!(f, "{v}"),
             Self::InternLongInt(long_int_id) => write!(f, "{}", interns.get_long_int(*long_int_id)),
-            Self::Float(v) => {
-                let s = v.to_string();
-                if s.contains('.') {
-                    f.write_str(&s)
-                } else {
-                    write!(f, "{s}.0")
-                }
-            }
+            Self::Float(v) => float_repr_fmt(*v, f),
             Self::Builtin(b) => b.py_repr_fmt(f),
             Self::ModuleFunction(mf) => mf.py_repr_fmt(f, self.id()),
             Self::DefFunction(f_id) => interns.get_function(*f_id).py_repr_fmt(f, interns, self.id()),
             Self::ExtFunction(f_id) => {
-                write!(f, "<function '{}' external>", interns.get_external_function_name(*f_id))
+                write!(f, "<function {} external>", interns.get_external_function_name(*f_id))
             }
             Self::InternString(string_id) => string_repr_fmt(interns.get_str(*string_id), f),
             Self::InternBytes(bytes_id) => bytes_repr_fmt(interns.get_bytes(*bytes_id), f),
@@ -391,6 +424,9 @@ impl PyTrait for Value {
                         // Other types don't typically have cycles, but handle gracefully
                         _ => f.write_str("..."),
                     }
+                } else if let HeapData::Closure(f_id, _, _) | HeapData::FunctionDefaults(f_id, _) = heap.get(*id) {
+                    // Functions show their id, which the heap data doesn't know
+                    interns.get_function(*f_id).py_repr_fmt(f, interns, self.id())
                 } else {
                     heap_ids.insert(*id);
                     let result = heap.get(*id).py_repr_fmt(f, heap, heap_ids, guard, interns);
@@ -411,7 +447,11 @@ impl PyTrait for Value {
     ) -> Cow<'static, str> {
         match self {
             Self::InternString(string_id) => interns.get_str(*string_id).to_owned().into(),
-            Self::Ref(id) => heap.get(*id).py_str(heap, guard, interns),
+            Self::Ref(id) => match heap.get(*id) {
+                // Function reprs include the value's id, so they're written from here
+                HeapData::Closure(..) | HeapData::FunctionDefaults(..) => self.py_repr(heap, guard, interns),
+                data => data.py_str(heap, guard, interns),
+            },
             _ => self.py_repr(heap, guard, interns),
         }
     }
@@ -503,27 +543,29 @@ impl PyTrait for Value {
                 Ok(Some(Self::Ref(heap.allocate(HeapData::Bytes(b.into()))?)))
             }
             (Self::InternBytes(bytes_id), Self::Ref(id2)) => {
-                if let HeapData::Bytes(b2) = heap.get(*id2) {
-                    let bytes1 = interns.get_bytes(*bytes_id);
-                    let mut b = Vec::with_capacity(bytes1.len() + b2.len());
-                    b.extend_from_slice(bytes1);
-                    b.extend_from_slice(b2);
-                    Ok(Some(Self::Ref(heap.allocate(HeapData::Bytes(b.into()))?)))
-                } else {
-                    Ok(None)
-                }
+                let b2 = match heap.get(*id2) {
+                    HeapData::Bytes(b2) => b2.as_slice(),
+                    HeapData::ByteArray(b2) => b2.as_slice(),
+                    _ => return Ok(None),
+                };
+                let b = [interns.get_bytes(*bytes_id), b2].concat();
+                Ok(Some(Self::Ref(heap.allocate(HeapData::Bytes(b.into()))?)))
             }
-            (Self::Ref(id1), Self::InternBytes(bytes_id)) => {
-                if let HeapData::Bytes(b1) = heap.get(*id1) {
-                    let bytes2 = interns.get_bytes(*bytes_id);
-                    let mut b = Vec::with_capacity(b1.len() + bytes2.len());
-                    b.extend_from_slice(b1);
-                    b.extend_from_slice(bytes2);
+            (Self::Ref(id1), Self::InternBytes(bytes_id)) => match heap.get(*id1) {
+                HeapData::Bytes(b1) => {
+                    let b = [b1.as_slice(), interns.get_bytes(*bytes_id)].concat();
                     Ok(Some(Self::Ref(heap.allocate(HeapData::Bytes(b.into()))?)))
-                } else {
-                    Ok(None)
                 }
-            }
+                // The result has the type of the left operand
+                HeapData::ByteArray(b1) => {
+                    let b = [b1.as_slice(), interns.get_bytes(*bytes_id)].concat();
+                    Ok(Some(Self::Ref(heap.allocate(HeapData::ByteArray(ByteArray::new(b)))?)))
+                }
+                _ => Ok(None),
+            },
+            // Bools are ints in arithmetic (True=1, False=0)
+            (Self::Bool(a), _) => Self::Int(i64::from(*a)).py_add(other, heap, interns),
+            (_, Self::Bool(b)) => self.py_add(&Self::Int(i64::from(*b)), heap, interns),
             _ => Ok(None),
         }
     }
@@ -584,6 +626,9 @@ impl PyTrait for Value {
             // Int - Float and Float - Int
             (Self::Int(a), Self::Float(b)) => Ok(Some(Self::Float(*a as f64 - b))),
             (Self::Float(a), Self::Int(b)) => Ok(Some(Self::Float(a - *b as f64))),
+            // Bools are ints in arithmetic (True=1, False=0)
+            (Self::Bool(a), _) => Self::Int(i64::from(*a)).py_sub(other, heap),
+            (_, Self::Bool(b)) => self.py_sub(&Self::Int(i64::from(*b)), heap),
             _ => Ok(None),
         }
     }
@@ -597,7 +642,8 @@ impl PyTrait for Value {
                     // Python modulo: result has the same sign as divisor (b)
                     // Standard remainder (%) in Rust has same sign as dividend (a)
                     // We need to adjust when signs differ and remainder is non-zero
-                    let r = *a % *b;
+                    // `wrapping_rem` gives 0 for `i64::MIN % -1` instead of overflowing
+                    let r = a.wrapping_rem(*b);
                     let result = if r != 0 && (*a < 0) != (*b < 0) { r + *b } else { r };
                     Ok(Some(Self::Int(result)))
                 }
@@ -655,21 +701,21 @@ impl PyTrait for Value {
                 if *v2 == 0.0 {
                     Err(ExcType::zero_division().into())
                 } else {
-                    Ok(Some(Self::Float(v1 % v2)))
+                    Ok(Some(Self::Float(float_mod(*v1, *v2))))
                 }
             }
             (Self::Float(v1), Self::Int(v2)) => {
                 if *v2 == 0 {
                     Err(ExcType::zero_division().into())
                 } else {
-                    Ok(Some(Self::Float(v1 % (*v2 as f64))))
+                    Ok(Some(Self::Float(float_mod(*v1, *v2 as f64))))
                 }
             }
             (Self::Int(v1), Self::Float(v2)) => {
                 if *v2 == 0.0 {
                     Err(ExcType::zero_division().into())
                 } else {
-                    Ok(Some(Self::Float((*v1 as f64) % v2)))
+                    Ok(Some(Self::Float(float_mod(*v1 as f64, *v2))))
                 }
             }
             _ => Ok(None),
@@ -678,15 +724,18 @@ impl PyTrait for Value {
 
     fn py_mod_eq(&self, other: &Self, right_value: i64) -> Option<bool> {
         match (self, other) {
+            // A zero divisor falls back to `py_mod`, which raises ZeroDivisionError
+            (_, Self::Int(0)) => None,
+            (_, Self::Float(v2)) if *v2 == 0.0 => None,
             (Self::Int(v1), Self::Int(v2)) => {
                 // Use Python's modulo semantics (result has same sign as divisor)
-                let r = *v1 % *v2;
+                let r = v1.wrapping_rem(*v2);
                 let result = if r != 0 && (*v1 < 0) != (*v2 < 0) { r + *v2 } else { r };
                 Some(result == right_value)
             }
-            (Self::Float(v1), Self::Float(v2)) => Some(v1 % v2 == right_value as f64),
-            (Self::Float(v1), Self::Int(v2)) => Some(v1 % (*v2 as f64) == right_value as f64),
-            (Self::Int(v1), Self::Float(v2)) => Some((*v1 as f64) % v2 == right_value as f64),
+            (Self::Float(v1), Self::Float(v2)) => Some(float_mod(*v1, *v2) == right_value as f64),
+            (Self::Float(v1), Self::Int(v2)) => Some(float_mod(*v1, *v2 as f64) == right_value as f64),
+            (Self::Int(v1), Self::Float(v2)) => Some(float_mod(*v1 as f64, *v2) == right_value as f64),
             _ => None,
         }
     }
@@ -730,14 +779,6 @@ impl PyTrait for Value {
                 other.drop_with_heap(heap);
                 Ok(result)
             }
-            (Self::Ref(id1), Self::InternString(string_id)) => {
-                if let HeapData::Str(s1) = heap.get_mut(*id1) {
-                    s1.as_string_mut().push_str(interns.get_str(*string_id));
-                    Ok(true)
-                } else {
-                    Ok(false)
-                }
-            }
             // same for bytes
             (Self::InternBytes(b1), Self::InternBytes(b2)) => {
                 let bytes1 = interns.get_bytes(*b1);
@@ -749,11 +790,13 @@ impl PyTrait for Value {
                 Ok(true)
             }
             (Self::InternBytes(bytes_id), Self::Ref(id2)) => {
-                let result = if let HeapData::Bytes(b2) = heap.get(*id2) {
-                    let bytes1 = interns.get_bytes(*bytes_id);
-                    let mut b = Vec::with_capacity(bytes1.len() + b2.len());
-                    b.extend_from_slice(bytes1);
-                    b.extend_from_slice(b2);
+                let b2 = match heap.get(*id2) {
+                    HeapData::Bytes(b2) => Some(b2.as_slice()),
+                    HeapData::ByteArray(b2) => Some(b2.as_slice()),
+                    _ => None,
+                };
+                let result = if let Some(b2) = b2 {
+                    let b = [interns.get_bytes(*bytes_id), b2].concat();
                     *self = Self::Ref(heap.allocate(HeapData::Bytes(b.into()))?);
                     true
                 } else {
@@ -763,16 +806,21 @@ impl PyTrait for Value {
                 other.drop_with_heap(heap);
                 Ok(result)
             }
-            (Self::Ref(id1), Self::InternBytes(bytes_id)) => {
-                if let HeapData::Bytes(b1) = heap.get_mut(*id1) {
+            // Only bytearrays are mutable, `str` and `bytes` concatenate into a new object
+            (Self::Ref(id1), Self::InternBytes(bytes_id)) => match heap.get_mut(*id1) {
+                HeapData::ByteArray(b1) => {
                     b1.as_vec_mut().extend_from_slice(interns.get_bytes(*bytes_id));
+                    heap.update_size(*id1)?;
                     Ok(true)
-                } else {
-                    Ok(false)
                 }
-            }
+                _ => Ok(false),
+            },
             (Self::Ref(id), Self::Ref(_)) => {
-                heap.with_entry_mut(*id, |heap, data| data.py_iadd(other, heap, Some(*id), interns))
+                let extended = heap.with_entry_mut(*id, |heap, data| data.py_iadd(other, heap, Some(*id), interns))?;
+                if extended {
+                    heap.update_size(*id)?;
+                }
+                Ok(extended)
             }
             _ => {
                 // Drop other if it's a Ref (ensure proper refcounting for unsupported type combinations)
@@ -830,6 +878,13 @@ impl PyTrait for Value {
                 let result = i64::from(*a) * i64::from(*b);
                 Ok(Some(Self::Int(result)))
             }
+            // Bools are ints for repetition too (`[0] * True`)
+            (Self::Ref(_) | Self::InternString(_) | Self::InternBytes(_), Self::Bool(b)) => {
+                self.py_mult(&Self::Int(i64::from(*b)), heap, interns)
+            }
+            (Self::Bool(a), Self::Ref(_) | Self::InternString(_) | Self::InternBytes(_)) => {
+                Self::Int(i64::from(*a)).py_mult(other, heap, interns)
+            }
 
             // String repetition: "ab" * 3 or 3 * "ab"
             (Self::InternString(s), Self::Int(n)) | (Self::Int(n), Self::InternString(s)) => {
@@ -1052,7 +1107,11 @@ impl PyTrait for Value {
                 } else {
                     // Python floor division rounds toward negative infinity
                     // div_euclid doesn't match Python semantics, so compute manually
-                    let d = a / b;
+                    let Some(d) = a.checked_div(*b) else {
+                        // i64::MIN // -1 overflows - promote to LongInt
+                        let li = -LongInt::from(*a);
+                        return Ok(Some(li.into_value(heap)?));
+                    };
                     let r = a % b;
                     // If there's a remainder and signs differ, round down (toward -∞)
                     let result = if r != 0 && (*a < 0) != (*b < 0) { d - 1 } else { d };
@@ -1111,21 +1170,21 @@ impl PyTrait for Value {
                 if *b == 0.0 {
                     Err(ExcType::zero_division().into())
                 } else {
-                    Ok(Some(Self::Float((a / b).floor())))
+                    Ok(Some(Self::Float(float_divmod(*a, *b).0)))
                 }
             }
             (Self::Int(a), Self::Float(b)) => {
                 if *b == 0.0 {
                     Err(ExcType::zero_division().into())
                 } else {
-                    Ok(Some(Self::Float((*a as f64 / b).floor())))
+                    Ok(Some(Self::Float(float_divmod(*a as f64, *b).0)))
                 }
             }
             (Self::Float(a), Self::Int(b)) => {
                 if *b == 0 {
                     Err(ExcType::zero_division().into())
                 } else {
-                    Ok(Some(Self::Float((a / *b as f64).floor())))
+                    Ok(Some(Self::Float(float_divmod(*a, *b as f64).0)))
                 }
             }
             // Bool floor division (True=1, False=0)
@@ -1152,12 +1211,12 @@ impl PyTrait for Value {
                 if *b == 0.0 {
                     Err(ExcType::zero_division().into())
                 } else {
-                    Ok(Some(Self::Float((f64::from(*a) / b).floor())))
+                    Ok(Some(Self::Float(float_divmod(f64::from(*a), *b).0)))
                 }
             }
             (Self::Float(a), Self::Bool(b)) => {
                 if *b {
-                    Ok(Some(Self::Float(a.floor()))) // a // 1.0 = floor(a)
+                    Ok(Some(Self::Float(float_divmod(*a, 1.0).0)))
                 } else {
                     Err(ExcType::zero_division().into())
                 }
@@ -1403,12 +1462,8 @@ impl PyTrait for Value {
                     return Ok(Self::Ref(heap_id));
                 }
 
-                // Handle interned string indexing, accepting Int and Bool
-                let index = match key {
-                    Self::Int(i) => *i,
-                    Self::Bool(b) => i64::from(*b),
-                    _ => return Err(ExcType::type_error_indices(Type::Str, key.py_type(heap))),
-                };
+                // Handle interned string indexing, accepting Int, Bool and LongInt
+                let index = key.as_index(heap, Type::Str)?;
 
                 let s = interns.get_str(*string_id);
                 let c = get_char_at_index(s, index).ok_or_else(ExcType::str_index_error)?;
@@ -1429,11 +1484,7 @@ impl PyTrait for Value {
                 }
 
                 // Handle interned bytes indexing - returns integer byte value
-                let index = match key {
-                    Self::Int(i) => *i,
-                    Self::Bool(b) => i64::from(*b),
-                    _ => return Err(ExcType::type_error_indices(Type::Bytes, key.py_type(heap))),
-                };
+                let index = key.as_index(heap, Type::Bytes)?;
 
                 let bytes = interns.get_bytes(*bytes_id);
                 let byte = get_byte_at_index(bytes, index).ok_or_else(ExcType::bytes_index_error)?;
@@ -1451,9 +1502,16 @@ impl PyTrait for Value {
         interns: &Interns,
     ) -> RunResult<()> {
         match self {
+            // Bytearrays are assigned in place so the value may be the bytearray itself
+            Self::Ref(id) if matches!(heap.get(*id), HeapData::ByteArray(_)) => {
+                bytearray::setitem(heap, *id, key, value, interns)
+            }
             Self::Ref(id) => {
                 let id = *id;
-                heap.with_entry_mut(id, |heap, data| data.py_setitem(key, value, heap, interns))
+                heap.with_entry_mut(id, |heap, data| data.py_setitem(key, value, heap, interns))?;
+                // Assigning a new dict key or a longer slice grows the container
+                heap.update_size(id)?;
+                Ok(())
             }
             _ => Err(ExcType::type_error(format!(
                 "'{}' object does not support item assignment",
@@ -1481,6 +1539,7 @@ impl Value {
             Self::Undefined => singleton_id(SingletonSlot::Undefined),
             Self::Ellipsis => singleton_id(SingletonSlot::Ellipsis),
             Self::None => singleton_id(SingletonSlot::None),
+            Self::NotImplemented => singleton_id(SingletonSlot::NotImplemented),
             Self::Bool(b) => {
                 if *b {
                     singleton_id(SingletonSlot::True)
@@ -1537,7 +1596,10 @@ impl Value {
 
     /// Equivalent of Python's `is` operator.
     ///
-    /// Compares value identity by comparing their IDs.
+    /// Compares value identity by comparing their IDs. Equal string literals are interned
+    /// to one `StringId` and so are identical, but strings built at runtime are separate
+    /// heap objects: `'ab' is ''.join(['a', 'b'])` is `False` even though they're equal,
+    /// matching CPython, where identity of equal strings is an implementation detail.
     pub fn is(&self, other: &Self) -> bool {
         self.id() == other.id()
     }
@@ -1557,25 +1619,13 @@ impl Value {
         match self {
             // Hash just the actual string or bytes content for consistency with heap Str/Bytes
             // hence we don't include the discriminant
-            Self::InternString(string_id) => {
-                let mut hasher = DefaultHasher::new();
-                interns.get_str(*string_id).hash(&mut hasher);
-                return Some(hasher.finish());
-            }
-            Self::InternBytes(bytes_id) => {
-                let mut hasher = DefaultHasher::new();
-                interns.get_bytes(*bytes_id).hash(&mut hasher);
-                return Some(hasher.finish());
-            }
-            // Hash BigInt consistently with LongInt (using sign and bytes for large values)
-            Self::InternLongInt(long_int_id) => {
-                let bi = interns.get_long_int(*long_int_id);
-                let mut hasher = DefaultHasher::new();
-                let (sign, bytes) = bi.to_bytes_le();
-                sign.hash(&mut hasher);
-                bytes.hash(&mut hasher);
-                return Some(hasher.finish());
-            }
+            Self::InternString(string_id) => return Some(str_hash(interns.get_str(*string_id))),
+            Self::InternBytes(bytes_id) => return Some(bytes_hash(interns.get_bytes(*bytes_id))),
+            // Numbers that compare equal must hash equal, so all numeric types share CPython's hash
+            Self::Bool(b) => return Some(int_hash(i64::from(*b))),
+            Self::Int(i) => return Some(int_hash(*i)),
+            Self::Float(f) => return Some(float_hash(*f)),
+            Self::InternLongInt(long_int_id) => return Some(bigint_hash(interns.get_long_int(*long_int_id))),
             // For heap-allocated values (includes Range and Exception), compute hash lazily and cache it
             Self::Ref(id) => return heap.get_or_compute_hash(*id, interns),
             _ => {}
@@ -1586,11 +1636,7 @@ impl Value {
         discriminant(self).hash(&mut hasher);
         match self {
             // Immediate values can be hashed directly
-            Self::Undefined | Self::Ellipsis | Self::None => {}
-            Self::Bool(b) => b.hash(&mut hasher),
-            Self::Int(i) => i.hash(&mut hasher),
-            // Hash the bit representation of float for consistency
-            Self::Float(f) => f.to_bits().hash(&mut hasher),
+            Self::Undefined | Self::Ellipsis | Self::None | Self::NotImplemented => {}
             Self::Builtin(b) => b.hash(&mut hasher),
             Self::ModuleFunction(mf) => mf.hash(&mut hasher),
             // Hash functions based on function ID
@@ -1602,7 +1648,13 @@ impl Value {
             Self::Property(p) => p.hash(&mut hasher),
             // ExternalFutures are hashable based on their call ID
             Self::ExternalFuture(call_id) => call_id.raw().hash(&mut hasher),
-            Self::InternString(_) | Self::InternBytes(_) | Self::InternLongInt(_) | Self::Ref(_) => {
+            Self::InternString(_)
+            | Self::InternBytes(_)
+            | Self::InternLongInt(_)
+            | Self::Ref(_)
+            | Self::Bool(_)
+            | Self::Int(_)
+            | Self::Float(_) => {
                 unreachable!("covered above")
             }
             #[cfg(feature = "ref-count-panic")]
@@ -1611,7 +1663,42 @@ impl Value {
         Some(hasher.finish())
     }
 
-    /// TODO this doesn't have many tests!!! also doesn't cover bytes
+    /// Returns the type that makes this unhashable value unhashable, for error messages.
+    ///
+    /// For tuples this is the type of the first unhashable item, found recursively, so
+    /// `(1, [2])` reports `list` like CPython. Otherwise it's the value's own type.
+    pub fn unhashable_type(&self, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Type {
+        if let Self::Ref(id) = self {
+            let item_type = heap.with_entry_mut(*id, |heap, data| {
+                let items = match data {
+                    HeapData::Tuple(t) => t.as_slice(),
+                    HeapData::NamedTuple(nt) => nt.as_vec().as_slice(),
+                    _ => return None,
+                };
+                items
+                    .iter()
+                    .find(|item| item.py_hash(heap, interns).is_none())
+                    .map(|item| item.unhashable_type(heap, interns))
+            });
+            if let Some(item_type) = item_type {
+                return item_type;
+            }
+        }
+        self.py_type(heap)
+    }
+
+    /// Tests the truth value of `self`, as `if`, `not`, `bool()` and the like do.
+    ///
+    /// Unlike [`PyTrait::py_bool`], this rejects `NotImplemented`: it has no meaningful truth
+    /// value, and CPython raises `TypeError` for it.
+    pub fn to_bool(&self, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<bool> {
+        if matches!(self, Self::NotImplemented) {
+            Err(ExcType::type_error_not_implemented_bool())
+        } else {
+            Ok(self.py_bool(heap, interns))
+        }
+    }
+
     /// Checks if `item` is contained in `self` (the container).
     ///
     /// Implements Python's `in` operator for various container types:
@@ -1619,6 +1706,12 @@ impl Value {
     /// - Dict: key lookup
     /// - Set/FrozenSet: element lookup
     /// - Str: substring search
+    /// - Bytes/ByteArray: byte or subsequence search
+    /// - Range: arithmetic check without iterating
+    ///
+    /// Any other iterable, such as an iterator from `iter()` or `map()`, is iterated and its
+    /// items compared with `==`, stopping at the first match. An iterator is consumed up to
+    /// and including the matching item, as in CPython.
     pub fn py_contains(
         &self,
         item: &Self,
@@ -1627,32 +1720,41 @@ impl Value {
     ) -> RunResult<bool> {
         match self {
             Self::Ref(heap_id) => {
+                // The container is taken out of the heap below, so an item that is the
+                // container itself could not be read while searching
+                if let Self::Ref(item_id) = item
+                    && item_id == heap_id
+                {
+                    return contains_itself(self, heap, interns);
+                }
                 // Use with_entry_mut to temporarily take ownership of the container.
                 // This allows iterating over container elements while calling py_eq
                 // (which needs &mut Heap for comparing nested heap values).
-                heap.with_entry_mut(*heap_id, |heap, data| match data {
+                let contained = heap.with_entry_mut(*heap_id, |heap, data| match data {
                     HeapData::List(list) => {
                         let mut guard = DepthGuard::default();
                         for el in list.as_slice() {
                             if item.py_eq(el, heap, &mut guard, interns)? {
-                                return Ok(true);
+                                return Ok(Some(true));
                             }
                         }
-                        Ok(false)
+                        Ok(Some(false))
                     }
                     HeapData::Tuple(tuple) => {
                         let mut guard = DepthGuard::default();
                         for el in tuple.as_slice() {
                             if item.py_eq(el, heap, &mut guard, interns)? {
-                                return Ok(true);
+                                return Ok(Some(true));
                             }
                         }
-                        Ok(false)
+                        Ok(Some(false))
                     }
-                    HeapData::Dict(dict) => dict.get(item, heap, interns).map(|m| m.is_some()),
-                    HeapData::Set(set) => set.contains(item, heap, interns),
-                    HeapData::FrozenSet(fset) => fset.contains(item, heap, interns),
-                    HeapData::Str(s) => str_contains(s.as_str(), item, heap, interns),
+                    HeapData::Dict(dict) => dict.get(item, heap, interns).map(|m| Some(m.is_some())),
+                    HeapData::Set(set) => set.contains(item, heap, interns).map(Some),
+                    HeapData::FrozenSet(fset) => fset.contains(item, heap, interns).map(Some),
+                    HeapData::Str(s) => str_contains(s.as_str(), item, heap, interns).map(Some),
+                    HeapData::Bytes(b) => bytes_contains(b.as_slice(), item, heap, interns).map(Some),
+                    HeapData::ByteArray(b) => bytes_contains(b.as_slice(), item, heap, interns).map(Some),
                     HeapData::Range(range) => {
                         // Range containment is O(1) - check bounds and step alignment
                         let n = match item {
@@ -1662,41 +1764,37 @@ impl Value {
                                 // Floats are contained if they equal an integer in the range
                                 // e.g., 3.0 in range(5) is True, but 3.5 in range(5) is False
                                 if f.fract() != 0.0 {
-                                    return Ok(false);
+                                    return Ok(Some(false));
                                 }
                                 // Check if float is within i64 range and convert safely
                                 // f64 can represent integers up to 2^53 exactly
                                 let int_val = f.trunc();
                                 if int_val < i64::MIN as f64 || int_val > i64::MAX as f64 {
-                                    return Ok(false);
+                                    return Ok(Some(false));
                                 }
                                 // Safe conversion: we've verified it's a whole number in i64 range
                                 #[expect(clippy::cast_possible_truncation)]
                                 let n = int_val as i64;
                                 n
                             }
-                            _ => return Ok(false),
+                            _ => return Ok(Some(false)),
                         };
-                        Ok(range.contains(n))
+                        Ok(Some(range.contains(n)))
                     }
-                    other => {
-                        let type_name = other.py_type(heap);
-                        Err(ExcType::type_error(format!(
-                            "argument of type '{type_name}' is not iterable"
-                        )))
-                    }
-                })
+                    // Not a container: iterate it once the entry is back on the heap
+                    _ => Ok(None),
+                })?;
+                match contained {
+                    Some(contained) => Ok(contained),
+                    None => iter_contains(self, item, heap, interns),
+                }
             }
             Self::InternString(string_id) => {
                 let container_str = interns.get_str(*string_id);
                 str_contains(container_str, item, heap, interns)
             }
-            _ => {
-                let type_name = self.py_type(heap);
-                Err(ExcType::type_error(format!(
-                    "argument of type '{type_name}' is not iterable"
-                )))
-            }
+            Self::InternBytes(bytes_id) => bytes_contains(interns.get_bytes(*bytes_id), item, heap, interns),
+            _ => iter_contains(self, item, heap, interns),
         }
     }
 
@@ -1785,8 +1883,9 @@ impl Value {
 
     /// Extracts an integer value from the Value.
     ///
-    /// Accepts `Int` and `LongInt` (if it fits in i64). Returns a `TypeError` for other types
-    /// and an `OverflowError` if the `LongInt` value is too large.
+    /// Accepts `Int`, `Bool` (True=1, False=0) and `LongInt` (if it fits in i64), like
+    /// CPython's `__index__`. Returns a `TypeError` for other types and an `OverflowError`
+    /// if the `LongInt` value is too large.
     ///
     /// Note: The LongInt-to-i64 conversion path is defensive code. In normal execution,
     /// heap-allocated `LongInt` values always exceed i64 range because `LongInt::into_value()`
@@ -1795,6 +1894,7 @@ impl Value {
     pub fn as_int(&self, heap: &Heap<impl ResourceTracker>) -> RunResult<i64> {
         match self {
             Self::Int(i) => Ok(*i),
+            Self::Bool(b) => Ok(i64::from(*b)),
             Self::Ref(heap_id) => {
                 if let HeapData::LongInt(li) = heap.get(*heap_id) {
                     li.to_i64().ok_or_else(ExcType::overflow_shift_count)
@@ -1838,7 +1938,8 @@ impl Value {
     /// Performs a binary bitwise operation on two values.
     ///
     /// Python only supports bitwise operations on integers (and bools, which coerce to int).
-    /// Returns a `TypeError` if either operand is not an integer, bool, or LongInt.
+    /// Returns `Ok(None)` if either operand is not an integer, bool, or LongInt, so the caller
+    /// can raise the `TypeError` naming its operator.
     ///
     /// For shift operations:
     /// - Negative shift counts raise `ValueError`
@@ -1849,10 +1950,11 @@ impl Value {
         other: &Self,
         op: BitwiseOp,
         heap: &mut Heap<impl ResourceTracker>,
-    ) -> Result<Self, RunError> {
-        // Capture types for error messages
-        let lhs_type = self.py_type(heap);
-        let rhs_type = other.py_type(heap);
+    ) -> Result<Option<Self>, RunError> {
+        // Fast path: i64 operands with an i64 result don't need a BigInt
+        if let Some(result) = i64_bitwise(self, other, op) {
+            return Ok(Some(Self::Int(result)));
+        }
 
         // Extract BigInt from all numeric types
         let lhs_bigint = extract_bigint(self, heap);
@@ -1879,6 +1981,9 @@ impl Value {
                         l << shift_u64
                     } else if r.sign() == num_bigint::Sign::Minus {
                         return Err(ExcType::value_error_negative_shift_count());
+                    } else if l.is_zero() {
+                        // Zero stays zero however far it is shifted
+                        BigInt::zero()
                     } else {
                         // Shift amount too large to fit in i64 - this would be astronomically large
                         return Err(ExcType::overflow_shift_count());
@@ -1908,12 +2013,147 @@ impl Value {
                 }
             };
             // Convert result back to Value, demoting to i64 if it fits
-            LongInt::new(result).into_value(heap).map_err(Into::into)
+            Ok(Some(LongInt::new(result).into_value(heap)?))
         } else {
-            Err(ExcType::binary_type_error(op.as_str(), lhs_type, rhs_type))
+            Ok(None)
         }
     }
 
+    /// Compares `self` and `other` for the ordering operator `op` (e.g. `"<"`).
+    ///
+    /// Like `py_cmp`, but values that can't be ordered raise the CPython `TypeError` naming
+    /// `op` and the types involved. Returns `Ok(None)` only for NaN, which makes every
+    /// ordering comparison false rather than an error.
+    pub fn py_cmp_for_op(
+        &self,
+        other: &Self,
+        op: &str,
+        heap: &mut Heap<impl ResourceTracker>,
+        guard: &mut DepthGuard,
+        interns: &Interns,
+    ) -> RunResult<Option<Ordering>> {
+        if let Some(ordering) = self.py_cmp(other, heap, guard, interns)? {
+            return Ok(Some(ordering));
+        }
+        match self.py_cmp_error_types(other, heap, interns)? {
+            None => Ok(None),
+            Some((lhs_type, rhs_type)) => Err(ExcType::type_error(format!(
+                "'{op}' not supported between instances of '{lhs_type}' and '{rhs_type}'"
+            ))),
+        }
+    }
+
+    /// Finds the types to report when `py_cmp` couldn't order `self` and `other`.
+    ///
+    /// For lists and tuples these are the types of the first pair of items that aren't equal,
+    /// since those decide the comparison. Returns `Ok(None)` when the values have orderable
+    /// types but are unordered, which only happens with NaN, so the comparison is simply false
+    /// as in CPython.
+    pub fn py_cmp_error_types(
+        &self,
+        other: &Self,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> Result<Option<(Type, Type)>, ResourceError> {
+        if let (Self::Ref(id1), Self::Ref(id2)) = (self, other) {
+            let first_difference = heap.with_two(*id1, *id2, |heap, left, right| {
+                let Some((left, right)) = sequence_items(left, right) else {
+                    return Ok(None);
+                };
+                let mut guard = DepthGuard::default();
+                for (l, r) in left.iter().zip(right) {
+                    if !l.py_eq(r, heap, &mut guard, interns)? {
+                        return Ok(Some((l.clone_with_heap(heap), r.clone_with_heap(heap))));
+                    }
+                }
+                Ok::<_, ResourceError>(None)
+            })?;
+            if let Some((l, r)) = first_difference {
+                let types = l.py_cmp_error_types(&r, heap, interns);
+                l.drop_with_heap(heap);
+                r.drop_with_heap(heap);
+                return types;
+            }
+        }
+
+        let (lhs_type, rhs_type) = (self.py_type(heap), other.py_type(heap));
+        let is_number = |t: Type| matches!(t, Type::Bool | Type::Int | Type::Float);
+        if is_number(lhs_type) && is_number(rhs_type) {
+            Ok(None)
+        } else {
+            Ok(Some((lhs_type, rhs_type)))
+        }
+    }
+
+    /// Performs the union operator `|` on dicts and sets.
+    ///
+    /// `dict | dict` returns a new dict where values from `other` win on key conflicts, and
+    /// a set or frozenset with another set or frozenset returns a new set of the same type as
+    /// `self`. Returns `Ok(None)` for any other operands.
+    pub fn py_union(
+        &self,
+        other: &Self,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<Option<Self>> {
+        let (Self::Ref(lhs_id), Self::Ref(rhs_id)) = (self, other) else {
+            return Ok(None);
+        };
+        let data = heap.with_two(*lhs_id, *rhs_id, |heap, lhs, rhs| match (lhs, set_storage(rhs)) {
+            (HeapData::Dict(lhs), _) => match rhs {
+                HeapData::Dict(rhs) => Ok(Some(HeapData::Dict(lhs.union(rhs, heap, interns)?))),
+                _ => Ok(None),
+            },
+            (HeapData::Set(lhs), Some(rhs)) => Ok(Some(HeapData::Set(lhs.union(rhs, heap, interns)?))),
+            (HeapData::FrozenSet(lhs), Some(rhs)) => Ok(Some(HeapData::FrozenSet(lhs.union(rhs, heap, interns)?))),
+            _ => Ok(None),
+        })?;
+        match data {
+            Some(data) => Ok(Some(Self::Ref(heap.allocate(data)?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Performs the in-place union `|=` on dicts and sets, mutating `self`.
+    ///
+    /// A dict is updated from `other` like `dict.update(other)`, so any mapping or iterable of
+    /// pairs is accepted; a set only accepts another set or frozenset. Returns `Ok(false)` if
+    /// `self` isn't a dict or set, or the operands aren't supported.
+    pub fn py_inplace_union(
+        &self,
+        other: &Self,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<bool> {
+        let Self::Ref(lhs_id) = *self else {
+            return Ok(false);
+        };
+        let rhs_id = match other {
+            Self::Ref(id) => Some(*id),
+            _ => None,
+        };
+        if rhs_id == Some(lhs_id) {
+            // the union of a dict or set with itself leaves it unchanged
+            return Ok(matches!(heap.get(lhs_id), HeapData::Dict(_) | HeapData::Set(_)));
+        }
+        let rhs_is_set = rhs_id.is_some_and(|id| set_storage(heap.get(id)).is_some());
+        let updated = heap.with_entry_mut(lhs_id, |heap, data| match data {
+            HeapData::Dict(dict) => {
+                dict.update(other.clone_with_heap(heap), heap, interns)?;
+                Ok(true)
+            }
+            HeapData::Set(set) if rhs_is_set => {
+                set.update_from_value(other.clone_with_heap(heap), heap, interns)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        })?;
+        if updated {
+            heap.update_size(lhs_id)?;
+        }
+        Ok(updated)
+    }
+
     /// Clones an value with proper heap reference counting.
     ///
     /// For immediate values (Int, Bool, None, etc.), this performs a simple copy.
@@ -1994,6 +2234,7 @@ impl Value {
             Self::Undefined => Self::Undefined,
             Self::Ellipsis => Self::Ellipsis,
             Self::None => Self::None,
+            Self::NotImplemented => Self::NotImplemented,
             Self::Bool(b) => Self::Bool(*b),
             Self::Int(v) => Self::Int(*v),
             Self::Float(v) => Self::Float(*v),
@@ -2047,6 +2288,68 @@ impl Value {
     }
 }
 
+/// Writes the Python `repr()` of a float, which is also its `str()`.
+///
+/// Like CPython, this uses the shortest digits that round-trip to the same value. Values
+/// with a decimal exponent below -4 or of 16 and above use scientific notation with a signed,
+/// at least two digit exponent (`1e-05`, `1e+16`); the rest use positional notation with at
+/// least one fractional digit (`1.0`). Infinities and NaN are written `inf`, `-inf` and `nan`.
+pub(crate) fn float_repr_fmt(v: f64, f: &mut impl Write) -> fmt::Result {
+    if v.is_nan() {
+        return f.write_str("nan");
+    }
+    if v.is_infinite() {
+        return f.write_str(if v < 0.0 { "-inf" } else { "inf" });
+    }
+    // `{:e}` gives the shortest round-tripping digits, e.g. `1.5e16` or `-2e-7`
+    let sci = format!("{v:e}");
+    let (mantissa, exp) = sci.split_once('e').expect("float exponent format always contains 'e'");
+    let exp: i32 = exp.parse().expect("float exponent is an integer");
+    if (-4..16).contains(&exp) {
+        let s = v.to_string();
+        f.write_str(&s)?;
+        if s.contains('.') { Ok(()) } else { f.write_str(".0") }
+    } else {
+        let sign = if exp < 0 { '-' } else { '+' };
+        write!(f, "{mantissa}e{sign}{:02}", exp.unsigned_abs())
+    }
+}
+
+/// Computes Python-style `divmod()` of two floats, returning `(x // y, x % y)`.
+///
+/// Follows CPython's `float_divmod`: the remainder takes the sign of the divisor (so
+/// `-7.5 % 2` is `0.5`), a zero remainder keeps the divisor's sign, and the quotient is
+/// computed from the exact remainder so it agrees with it even for infinite operands
+/// (`divmod(-5.0, inf)` is `(-1.0, inf)`). NaN operands give NaN results.
+///
+/// The caller must check for a zero divisor, which raises `ZeroDivisionError` in Python.
+pub(crate) fn float_divmod(x: f64, y: f64) -> (f64, f64) {
+    let mut rem = x % y;
+    // `x - rem` is exactly a multiple of `y`, so this division is exact up to rounding
+    let mut div = (x - rem) / y;
+    if rem == 0.0 {
+        rem = 0.0_f64.copysign(y);
+    } else if (y < 0.0) != (rem < 0.0) {
+        rem += y;
+        div -= 1.0;
+    }
+    let floordiv = if div == 0.0 {
+        0.0_f64.copysign(x / y)
+    } else {
+        // `div` may be slightly off an integer due to rounding, so snap to the nearest one
+        let floor = div.floor();
+        if div - floor > 0.5 { floor + 1.0 } else { floor }
+    };
+    (floordiv, rem)
+}
+
+/// Computes Python-style `x % y` for floats, where the result takes the sign of `y`.
+///
+/// See [`float_divmod`]; the caller must check for a zero divisor.
+pub(crate) fn float_mod(x: f64, y: f64) -> f64 {
+    float_divmod(x, y).1
+}
+
 /// Interned or heap-owned string identifier.
 ///
 /// Used when a string value can come from either the intern table (for known
@@ -2125,8 +2428,108 @@ impl EitherStr {
     }
 }
 
+/// Orders two heap values for `py_cmp`: strings, bytes, and lists or tuples of the same type.
+///
+/// Lists and tuples compare lexicographically: the first pair of items that aren't equal decides
+/// the order, and when one is a prefix of the other the shorter one is smaller. Returns `Ok(None)`
+/// if the values can't be ordered, including when the deciding items can't be.
+fn cmp_heap_data(
+    left: &HeapData,
+    right: &HeapData,
+    heap: &mut Heap<impl ResourceTracker>,
+    guard: &mut DepthGuard,
+    interns: &Interns,
+) -> Result<Option<Ordering>, ResourceError> {
+    match (left, right) {
+        (HeapData::Str(a), HeapData::Str(b)) => Ok(a.as_str().partial_cmp(b.as_str())),
+        (HeapData::Bytes(a), HeapData::Bytes(b)) => Ok(a.as_slice().partial_cmp(b.as_slice())),
+        _ => match sequence_items(left, right) {
+            Some((a, b)) => cmp_items(a, b, heap, guard, interns),
+            None => Ok(None),
+        },
+    }
+}
+
+/// Returns the items of two lists, or of two tuples, which compare item by item.
+///
+/// Named tuples are tuples, so they compare with plain tuples too.
+fn sequence_items<'a>(left: &'a HeapData, right: &'a HeapData) -> Option<(&'a [Value], &'a [Value])> {
+    let tuple_items = |data: &'a HeapData| match data {
+        HeapData::Tuple(tuple) => Some(tuple.as_slice()),
+        HeapData::NamedTuple(nt) => Some(nt.as_vec().as_slice()),
+        _ => None,
+    };
+    match (left, right) {
+        (HeapData::List(a), HeapData::List(b)) => Some((a.as_slice(), b.as_slice())),
+        _ => Some((tuple_items(left)?, tuple_items(right)?)),
+    }
+}
+
+/// Compares two item slices lexicographically, see `cmp_heap_data`.
+fn cmp_items(
+    left: &[Value],
+    right: &[Value],
+    heap: &mut Heap<impl ResourceTracker>,
+    guard: &mut DepthGuard,
+    interns: &Interns,
+) -> Result<Option<Ordering>, ResourceError> {
+    guard.increase_err()?;
+    for (l, r) in left.iter().zip(right) {
+        if !l.py_eq(r, heap, guard, interns)? {
+            let ordering = l.py_cmp(r, heap, guard, interns);
+            guard.decrease();
+            return ordering;
+        }
+    }
+    guard.decrease();
+    Ok(Some(left.len().cmp(&right.len())))
+}
+
+/// Returns the elements of a set or frozenset, for the set operators.
+fn set_storage(data: &HeapData) -> Option<&SetStorage> {
+    match data {
+        HeapData::Set(set) => Some(set.storage()),
+        HeapData::FrozenSet(set) => Some(set.storage()),
+        _ => None,
+    }
+}
+
+/// Applies a bitwise operation to two `Int`s for `py_bitwise` without going through BigInt.
+///
+/// `&`, `|` and `^` on i64s can't overflow, and right shifts by 64 or more give 0 or -1 like
+/// Python's arithmetic shift. Returns `None` when the BigInt path is needed instead: for other
+/// operand types, negative shift counts (which raise `ValueError`), and left shifts that
+/// overflow i64 (which promote to LongInt).
+fn i64_bitwise(lhs: &Value, rhs: &Value, op: BitwiseOp) -> Option<i64> {
+    let (Value::Int(l), Value::Int(r)) = (lhs, rhs) else {
+        return None;
+    };
+    let (value, other) = (*l, *r);
+    match op {
+        BitwiseOp::And => Some(value & other),
+        BitwiseOp::Or => Some(value | other),
+        BitwiseOp::Xor => Some(value ^ other),
+        BitwiseOp::RShift => {
+            let shift = u32::try_from(other).ok()?;
+            Some(value >> shift.min(63))
+        }
+        BitwiseOp::LShift => {
+            let shift = u32::try_from(other).ok()?;
+            if value == 0 {
+                Some(0)
+            } else if shift < 64 {
+                let result = value << shift;
+                // The shift lost bits (including the sign) if shifting back doesn't round-trip
+                (result >> shift == value).then_some(result)
+            } else {
+                None
+            }
+        }
+    }
+}
+
 /// Bitwise operation type for `py_bitwise`.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BitwiseOp {
     And,
     Or,
@@ -2135,19 +2538,6 @@ pub enum BitwiseOp {
     RShift,
 }
 
-impl BitwiseOp {
-    /// Returns the operator symbol for error messages.
-    pub fn as_str(self) -> &'static str {
-        match self {
-            Self::And => "&",
-            Self::Or => "|",
-            Self::Xor => "^",
-            Self::LShift => "<<",
-            Self::RShift => ">>",
-        }
-    }
-}
-
 /// Marker values for special objects that exist but have minimal functionality.
 ///
 /// These are used for:
@@ -2191,7 +2581,131 @@ impl Marker {
     }
 }
 
-/// High-bit tag reserved for literal singletons (None, Ellipsis, booleans).
+/// Compares an int with a float exactly, or returns `None` if the float is NaN.
+///
+/// Ints beyond `2**53` can't all be represented as floats, so converting them with
+/// `as f64` would round, making `2**53 + 1` equal to `2.0**53`. Those go through
+/// [`LongInt::cmp_float`], which compares without rounding like CPython.
+fn int_cmp_float(i: i64, f: f64) -> Option<Ordering> {
+    const EXACT_FLOAT_INT: u64 = 1 << f64::MANTISSA_DIGITS;
+    if i.unsigned_abs() <= EXACT_FLOAT_INT {
+        (i as f64).partial_cmp(&f)
+    } else {
+        LongInt::from(i).cmp_float(f)
+    }
+}
+
+/// Modulus of CPython's numeric hash, the Mersenne prime `2**61 - 1`.
+const NUMERIC_HASH_MODULUS: u64 = (1 << NUMERIC_HASH_BITS) - 1;
+/// Number of bits in [`NUMERIC_HASH_MODULUS`].
+const NUMERIC_HASH_BITS: u32 = 61;
+/// CPython's hash of `float('inf')`; `-inf` hashes to its negation.
+const NUMERIC_HASH_INF: i64 = 314_159;
+
+/// Hashes an int like CPython: the value reduced modulo `2**61 - 1`, keeping its sign.
+///
+/// Numbers that compare equal hash equal across `bool`, `int`, `LongInt` and `float`, so
+/// `1`, `1.0` and `True` are the same dict key. The results match CPython's `hash()`.
+pub(crate) fn int_hash(value: i64) -> u64 {
+    let reduced = i64::try_from(value.unsigned_abs() % NUMERIC_HASH_MODULUS).expect("reduced hash fits in i64");
+    finish_numeric_hash(if value < 0 { -reduced } else { reduced })
+}
+
+/// Hashes a big integer consistently with [`int_hash`].
+pub(crate) fn bigint_hash(value: &BigInt) -> u64 {
+    let reduced = (value.magnitude() % NUMERIC_HASH_MODULUS)
+        .to_i64()
+        .expect("reduced hash fits in i64");
+    finish_numeric_hash(if value.sign() == num_bigint::Sign::Minus {
+        -reduced
+    } else {
+        reduced
+    })
+}
+
+/// Hashes a float consistently with [`int_hash`], following CPython's `_Py_HashDouble`.
+///
+/// The float is treated as the exact fraction it represents and reduced modulo `2**61 - 1`,
+/// so integral floats hash like the equal int. NaN hashes to 0: it never equals anything,
+/// so its hash doesn't matter for lookups.
+pub(crate) fn float_hash(value: f64) -> u64 {
+    if value.is_nan() {
+        return 0;
+    }
+    if value.is_infinite() {
+        return finish_numeric_hash(if value > 0.0 {
+            NUMERIC_HASH_INF
+        } else {
+            -NUMERIC_HASH_INF
+        });
+    }
+
+    let (mut mantissa, mut exponent) = frexp(value.abs());
+    let mut hash: u64 = 0;
+    // Consume the mantissa 28 bits at a time, rotating the hash left by the same amount
+    while mantissa > 0.0 {
+        hash = ((hash << 28) & NUMERIC_HASH_MODULUS) | (hash >> (NUMERIC_HASH_BITS - 28));
+        mantissa *= 268_435_456.0; // 2**28
+        exponent -= 28;
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "mantissa is in [0, 2**28), taking its integer part is intended"
+        )]
+        let digits = mantissa as u64;
+        mantissa -= digits as f64;
+        hash += digits;
+        if hash >= NUMERIC_HASH_MODULUS {
+            hash -= NUMERIC_HASH_MODULUS;
+        }
+    }
+
+    // Multiplying by 2**exponent is a rotation, since 2**61 is 1 modulo 2**61 - 1
+    let exponent = exponent.rem_euclid(61);
+    let exponent = u32::try_from(exponent).expect("rem_euclid is non-negative");
+    hash = ((hash << exponent) & NUMERIC_HASH_MODULUS) | (hash >> (NUMERIC_HASH_BITS - exponent));
+    let hash = i64::try_from(hash).expect("reduced hash fits in i64");
+    finish_numeric_hash(if value < 0.0 { -hash } else { hash })
+}
+
+/// Hashes string contents, shared by `InternString` and heap `Str` so equal strings hash equal
+/// whichever representation they use.
+pub(crate) fn str_hash(value: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes bytes contents, shared by `InternBytes` and heap `Bytes` like [`str_hash`].
+pub(crate) fn bytes_hash(value: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Converts a signed numeric hash to the `u64` used for hashing, mapping -1 to -2 like CPython.
+fn finish_numeric_hash(hash: i64) -> u64 {
+    let hash = if hash == -1 { -2 } else { hash };
+    u64::from_ne_bytes(hash.to_ne_bytes())
+}
+
+/// Splits a non-negative finite float into a mantissa in `[0.5, 1)` and an exponent, like C's `frexp`.
+fn frexp(value: f64) -> (f64, i32) {
+    let bits = value.to_bits();
+    if bits == 0 {
+        return (value, 0);
+    }
+    let biased_exponent = i32::try_from((bits >> 52) & 0x7ff).expect("11 bits fit in i32");
+    if biased_exponent == 0 {
+        // Subnormal: scale into the normal range first
+        let (mantissa, exponent) = frexp(value * 2f64.powi(54));
+        return (mantissa, exponent - 54);
+    }
+    let mantissa = f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52));
+    (mantissa, biased_exponent - 1022)
+}
+
+/// High-bit tag reserved for literal singletons (None, Ellipsis, NotImplemented, booleans).
 const SINGLETON_ID_TAG: usize = 1usize << (usize::BITS - 1);
 /// High-bit tag reserved for interned string `id()` values.
 const INTERN_STR_ID_TAG: usize = 1usize << (usize::BITS - 2);
@@ -2251,6 +2765,7 @@ enum SingletonSlot {
     None = 2,
     False = 3,
     True = 4,
+    NotImplemented = 5,
 }
 
 /// Returns the fully tagged `id()` value for the requested singleton literal.
@@ -2376,11 +2891,11 @@ fn longint_to_repeat_count(li: &LongInt) -> RunResult<usize> {
     }
 }
 
-/// Extracts a BigInt from a Value for bitwise operations.
+/// Extracts a BigInt from a Value for bitwise operations and exact integer math.
 ///
 /// Returns `Some(BigInt)` for Int, Bool, and LongInt values.
 /// Returns `None` for other types (Float, Str, etc.).
-fn extract_bigint(value: &Value, heap: &Heap<impl ResourceTracker>) -> Option<BigInt> {
+pub(crate) fn extract_bigint(value: &Value, heap: &Heap<impl ResourceTracker>) -> Option<BigInt> {
     match value {
         Value::Int(i) => Some(BigInt::from(*i)),
         Value::Bool(b) => Some(BigInt::from(i64::from(*b))),
@@ -2408,17 +2923,104 @@ fn str_contains(
     match item {
         Value::InternString(item_id) => {
             let item_str = interns.get_str(*item_id);
-            Ok(container_str.contains(item_str))
+            return Ok(container_str.contains(item_str));
         }
         Value::Ref(item_heap_id) => {
             if let HeapData::Str(item_str) = heap.get(*item_heap_id) {
-                Ok(container_str.contains(item_str.as_str()))
-            } else {
-                Err(ExcType::type_error("'in <str>' requires string as left operand"))
+                return Ok(container_str.contains(item_str.as_str()));
             }
         }
-        _ => Err(ExcType::type_error("'in <str>' requires string as left operand")),
+        _ => {}
     }
+    Err(ExcType::type_error(format!(
+        "'in <string>' requires string as left operand, not {}",
+        item.py_type(heap)
+    )))
+}
+
+/// Tests `item in container` for bytes and bytearrays.
+///
+/// An int must be in `range(0, 256)` and matches a single byte, while a bytes-like
+/// item matches a contiguous subsequence.
+fn bytes_contains(
+    container: &[u8],
+    item: &Value,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<bool> {
+    let n = match item {
+        Value::Int(n) => *n,
+        Value::Bool(b) => i64::from(*b),
+        _ => {
+            if let Some(sub) = bytearray::bytes_like(item, heap, interns) {
+                return Ok(memchr::memmem::find(container, sub).is_some());
+            }
+            if let Value::Ref(id) = item
+                && matches!(heap.get(*id), HeapData::LongInt(_))
+            {
+                return Err(bytearray::byte_range_error());
+            }
+            return Err(ExcType::type_error(format!(
+                "a bytes-like object is required, not '{}'",
+                item.py_type(heap)
+            )));
+        }
+    };
+    let byte = u8::try_from(n).map_err(|_| bytearray::byte_range_error())?;
+    Ok(container.contains(&byte))
+}
+
+/// Tests `container in container`, where the container can't be taken out of the heap
+/// to search it because the item would then be unreadable.
+///
+/// Strings and bytes contain themselves as a substring, a dict raises because it is
+/// unhashable, and anything else is searched by iterating, which reads the container
+/// in place.
+fn contains_itself(container: &Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<bool> {
+    let Value::Ref(id) = container else {
+        unreachable!("only heap containers can be their own item")
+    };
+    match heap.get(*id) {
+        HeapData::Str(_) | HeapData::Bytes(_) | HeapData::ByteArray(_) => Ok(true),
+        HeapData::Dict(_) => Err(ExcType::type_error_unhashable_dict_key(Type::Dict, Type::Dict)),
+        _ => iter_contains(container, container, heap, interns),
+    }
+}
+
+/// Tests `item in iterable` for iterables that aren't containers by comparing each
+/// item with `==`, stopping at the first match.
+///
+/// Values that can't be iterated raise a `TypeError`.
+fn iter_contains(
+    iterable: &Value,
+    item: &Value,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<bool> {
+    let Ok(mut iter) = MontyIter::new(iterable.clone_with_heap(heap), heap, interns) else {
+        let type_name = iterable.py_type(heap);
+        return Err(ExcType::type_error(format!(
+            "argument of type '{type_name}' is not iterable"
+        )));
+    };
+    let mut guard = DepthGuard::default();
+    let result = loop {
+        match iter.for_next(heap, interns) {
+            Ok(Some(el)) => {
+                let eq = item.py_eq(&el, heap, &mut guard, interns);
+                el.drop_with_heap(heap);
+                match eq {
+                    Ok(true) => break Ok(true),
+                    Ok(false) => {}
+                    Err(err) => break Err(err),
+                }
+            }
+            Ok(None) => break Ok(false),
+            Err(err) => break Err(err),
+        }
+    };
+    iter.drop_with_heap(heap);
+    result
 }
 
 /// Computes the number of significant bits in an i64.
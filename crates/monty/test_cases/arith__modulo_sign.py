@@ -0,0 +1,106 @@
+inf = float('inf')
+nan = float('nan')
+
+
+def is_nan(x):
+    return x != x
+
+
+# === int modulo takes the sign of the divisor ===
+assert 7 % 2 == 1, 'int mod positive'
+assert -7 % 2 == 1, 'int mod negative dividend'
+assert 7 % -2 == -1, 'int mod negative divisor'
+assert -7 % -2 == -1, 'int mod both negative'
+assert -6 % 3 == 0, 'int mod exact'
+assert -9223372036854775808 % -1 == 0, 'int mod min by -1'
+assert divmod(7, 2) == (3, 1), 'divmod int'
+assert divmod(-7, 2) == (-4, 1), 'divmod int negative dividend'
+assert divmod(7, -2) == (-4, -1), 'divmod int negative divisor'
+assert divmod(-7, -2) == (3, -1), 'divmod int both negative'
+assert -7 // 2 == -4, 'int floordiv rounds down'
+
+# === float modulo takes the sign of the divisor ===
+assert 7.5 % 2 == 1.5, 'float mod positive'
+assert -7.5 % 2 == 0.5, 'float mod negative dividend'
+assert 7.5 % -2 == -0.5, 'float mod negative divisor'
+assert -7.5 % -2 == -1.5, 'float mod both negative'
+assert -7 % 2.5 == 0.5, 'int mod float'
+assert 7.5 % -2.0 == -0.5, 'float mod negative float'
+assert 0.1 % 0.01 < 0.01, 'float mod small divisor'
+assert str(-0.0 % 2) == '0.0', 'zero remainder takes divisor sign'
+assert str(0.0 % -2) == '-0.0', 'zero remainder takes negative divisor sign'
+assert str(-6.0 % 3) == '0.0', 'exact negative float mod is positive zero'
+
+assert divmod(7.5, 2) == (3.0, 1.5), 'divmod float'
+assert divmod(-7.5, 2) == (-4.0, 0.5), 'divmod float negative dividend'
+assert divmod(7.5, -2) == (-4.0, -0.5), 'divmod float negative divisor'
+assert divmod(-7.5, -2.0) == (3.0, -1.5), 'divmod float both negative'
+assert divmod(-7, 2.5) == (-3.0, 0.5), 'divmod int and float'
+assert -7.5 // 2 == -4.0, 'float floordiv rounds down'
+assert str(divmod(-0.0, 2)) == '(-0.0, 0.0)', 'divmod negative zero'
+
+# divmod agrees with // and %
+for x in [7.5, -7.5, 3.0, -0.1, 1e20]:
+    for y in [2, -2, 0.3, -0.3]:
+        assert divmod(x, y) == (x // y, x % y), 'divmod matches // and %'
+        q, r = divmod(x, y)
+        assert abs(q * y + r - x) < 1e-6 * max(1.0, abs(x)), 'divmod reconstructs dividend'
+
+# === in comparisons ===
+assert -7.5 % 2 == 0.5, 'mod in comparison'
+assert (7 % -2 == -1) is True, 'int mod comparison'
+n = -3.0
+assert n % 2 == 1, 'float mod compared to int'
+
+# === infinite and nan operands ===
+assert 5.0 % inf == 5.0, 'finite mod inf'
+assert -5.0 % inf == inf, 'negative mod inf'
+assert 5.0 % -inf == -inf, 'positive mod negative inf'
+assert -5.0 % -inf == -5.0, 'negative mod negative inf'
+assert is_nan(inf % 2), 'inf mod is nan'
+assert is_nan(2 % nan), 'mod nan is nan'
+assert is_nan(nan % 2), 'nan mod is nan'
+assert divmod(5.0, inf) == (0.0, 5.0), 'divmod finite by inf'
+assert divmod(-5.0, inf) == (-1.0, inf), 'divmod negative by inf'
+assert divmod(3, -inf) == (-1.0, -inf), 'divmod int by negative inf'
+assert -5.0 // inf == -1.0, 'floordiv negative by inf'
+assert 5.0 // inf == 0.0, 'floordiv positive by inf'
+q, r = divmod(inf, 2)
+assert is_nan(q) and is_nan(r), 'divmod inf is nan'
+q, r = divmod(nan, 1)
+assert is_nan(q) and is_nan(r), 'divmod nan is nan'
+
+# === zero divisors raise ===
+try:
+    7.5 % 0.0
+    assert False, 'float mod zero should raise'
+except ZeroDivisionError:
+    pass
+
+try:
+    divmod(7.5, 0)
+    assert False, 'divmod float by zero should raise'
+except ZeroDivisionError:
+    pass
+
+try:
+    inf % 0
+    assert False, 'inf mod zero should raise'
+except ZeroDivisionError:
+    pass
+
+try:
+    x = 7
+    if x % 0 == 0:
+        pass
+    assert False, 'int mod zero in comparison should raise'
+except ZeroDivisionError:
+    pass
+
+try:
+    y = 7.5
+    if y % 0.0 == 0:
+        pass
+    assert False, 'float mod zero in comparison should raise'
+except ZeroDivisionError:
+    pass
@@ -0,0 +1,75 @@
+# === Chained assignment ===
+a = b = 0
+assert a == 0, 'chained assignment first target'
+assert b == 0, 'chained assignment second target'
+
+x = y = z = 'hello'
+assert (x, y, z) == ('hello', 'hello', 'hello'), 'three targets'
+
+# All targets share the same object
+p = q = []
+p.append(1)
+assert q == [1], 'chained targets share the same object'
+assert p is q, 'chained targets are identical'
+
+# === Right-hand side evaluated once ===
+calls = []
+
+
+def make():
+    calls.append('make')
+    return [len(calls)]
+
+
+m = n = make()
+assert calls == ['make'], 'right-hand side evaluated exactly once'
+assert m is n, 'chained targets share the result'
+
+# === Chained assignment with unpacking targets ===
+pair = (first, second) = (1, 2)
+assert pair == (1, 2), 'name target gets the whole value'
+assert first == 1, 'unpack target first'
+assert second == 2, 'unpack target second'
+
+head, *tail = whole = [1, 2, 3]
+assert head == 1, 'starred chained target head'
+assert tail == [2, 3], 'starred chained target tail'
+assert whole == [1, 2, 3], 'starred chained target whole'
+
+# === Targets assigned left to right ===
+i = i_copy = 5
+assert i == 5, 'left to right: i'
+assert i_copy == 5, 'left to right: i_copy'
+(c, d) = (d, c) = (1, 2)
+assert c == 2, 'later targets overwrite earlier ones: c'
+assert d == 1, 'later targets overwrite earlier ones: d'
+
+
+# === Inside a function ===
+def chain():
+    local_a = local_b = 42
+    return local_a + local_b
+
+
+assert chain() == 84, 'chained assignment to locals'
+
+# === Multiple assignment ===
+a, b, c = 1, 2, 3
+assert (a, b, c) == (1, 2, 3), 'multiple assignment'
+
+# === Swap ===
+a, b = 1, 2
+a, b = b, a
+assert a == 2, 'swap a'
+assert b == 1, 'swap b'
+
+# Rotate three values
+a, b, c = 1, 2, 3
+a, b, c = c, a, b
+assert (a, b, c) == (3, 1, 2), 'rotate three values'
+
+# Swap list elements via names
+items = [10, 20]
+low, high = items
+low, high = high, low
+assert (low, high) == (20, 10), 'swap values read from a list'
@@ -0,0 +1,97 @@
+import bisect
+from bisect import bisect_left, bisect_right, insort, insort_left, insort_right
+
+# === bisect_left and bisect_right ===
+a = [1, 2, 4, 4, 4, 7, 9]
+assert bisect_left(a, 4) == 2, 'bisect_left before equal items'
+assert bisect_right(a, 4) == 5, 'bisect_right after equal items'
+assert bisect.bisect(a, 4) == 5, 'bisect is bisect_right'
+assert bisect_left(a, 5) == bisect_right(a, 5) == 5, 'missing item'
+assert bisect_left(a, 0) == 0, 'smaller than everything'
+assert bisect_right(a, 10) == 7, 'larger than everything'
+assert bisect_left(a, 1) == 0, 'equal to the first item'
+assert bisect_right(a, 9) == 7, 'equal to the last item'
+assert bisect_left(a, 4.5) == 5, 'float in an int list'
+
+# === duplicates ===
+same = [3, 3, 3, 3]
+assert bisect_left(same, 3) == 0, 'bisect_left on all duplicates'
+assert bisect_right(same, 3) == 4, 'bisect_right on all duplicates'
+assert bisect_left(same, 2) == 0 and bisect_right(same, 4) == 4, 'outside all duplicates'
+
+# === empty lists ===
+assert bisect_left([], 1) == 0, 'bisect_left on an empty list'
+assert bisect_right([], 1) == 0, 'bisect_right on an empty list'
+
+# === lo and hi ===
+assert bisect_left(a, 4, 3) == 3, 'bisect_left with lo'
+assert bisect_right(a, 4, 0, 3) == 3, 'bisect_right with hi'
+assert bisect_left(a, 9, lo=2, hi=4) == 4, 'lo and hi as keywords'
+assert bisect_right(a, 1, hi=None) == 1, 'hi=None means len(a)'
+assert bisect_left(a, 4, 5, 2) == 5, 'lo after hi returns lo'
+
+try:
+    bisect_left(a, 1, -1)
+    assert False, 'negative lo should raise'
+except ValueError as e:
+    assert str(e) == 'lo must be non-negative', 'negative lo message'
+
+# === other sequences ===
+assert bisect_left((1, 3, 5), 3) == 1, 'bisect on a tuple'
+assert bisect_right('acegi', 'e') == 3, 'bisect on a str'
+assert bisect_left(range(0, 100, 10), 35) == 4, 'bisect on a range'
+words = ['apple', 'banana', 'cherry']
+assert bisect_left(words, 'blueberry') == 2, 'bisect on strings'
+
+# === insort ===
+lst = []
+for x in [5, 1, 4, 1, 3, 9, 2]:
+    insort(lst, x)
+assert lst == [1, 1, 2, 3, 4, 5, 9], 'insort keeps the list sorted'
+assert insort_left(lst, 6) is None, 'insort_left returns None'
+assert lst == [1, 1, 2, 3, 4, 5, 6, 9], 'insort_left inserts'
+insort_right(lst, 0)
+assert lst == [0, 1, 1, 2, 3, 4, 5, 6, 9], 'insort_right at the start'
+insort(lst, 10)
+assert lst == [0, 1, 1, 2, 3, 4, 5, 6, 9, 10], 'insort at the end'
+
+# insort_left and insort_right differ for equal items that aren't identical
+lst = [1, 2, 3]
+insort_left(lst, 2.0)
+assert lst == [1, 2.0, 2, 3] and type(lst[1]) == float, 'insort_left before equal items'
+lst = [1, 2, 3]
+insort_right(lst, 2.0)
+assert lst == [1, 2, 2.0, 3] and type(lst[2]) == float, 'insort_right after equal items'
+
+lst = []
+insort_left(lst, 'x')
+assert lst == ['x'], 'insort into an empty list'
+
+lst = [1, 5, 2]
+insort(lst, 3, 1, 2)
+assert lst == [1, 3, 5, 2], 'insort with lo and hi'
+
+# === errors ===
+try:
+    insort((1, 2), 3)  # pyright: ignore
+    assert False, 'insort into a tuple should raise'
+except AttributeError as e:
+    assert str(e) == "'tuple' object has no attribute 'insert'", 'insort tuple message'
+
+try:
+    bisect_left(5, 1)  # pyright: ignore
+    assert False, 'bisect on a non-sequence should raise'
+except TypeError as e:
+    assert str(e) == "object of type 'int' has no len()", 'no len message'
+
+try:
+    bisect_left([1, 2], 1, 0, 5)
+    assert False, 'hi past the end should raise'
+except IndexError as e:
+    assert str(e) == 'list index out of range', 'hi past the end message'
+
+try:
+    bisect_left(['a'], 1)
+    assert False, 'unorderable items should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'str' and 'int'", 'unorderable message'
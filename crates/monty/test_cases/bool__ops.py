@@ -18,3 +18,48 @@ assert (not None) == True, 'not None'
 # === Complex boolean expressions ===
 assert ((1 and 2) or (3 and 0)) == 2, 'complex and/or'
 assert (not (0 and 1)) == True, 'not and combined'
+
+# === 'and'/'or' return an operand, not a bool ===
+r = 0 or 'x'
+assert r == 'x', 'or returns the second operand when the first is falsy'
+assert type(r) is str, 'or result keeps the operand type'
+
+r = [] and 5
+assert r == [], 'and returns the first operand when it is falsy'
+assert type(r) is list, 'and result is the falsy operand, not False'
+
+r = 'a' and 'b'
+assert r == 'b', 'and returns the last operand when all are truthy'
+
+r = '' or 0 or None
+assert r is None, 'or returns the last operand when all are falsy'
+
+r = 0.0 and 1
+assert type(r) is float, 'and keeps a falsy float operand'
+
+# Identity of the returned operand is preserved
+items = [1, 2]
+assert (items or []) is items, 'or returns the same object'
+empty = []
+assert (empty and items) is empty, 'and returns the same falsy object'
+assert (empty or items) is items, 'or returns the same truthy object'
+assert (items and empty) is empty, 'and returns the same last object'
+
+# Operands are evaluated at most once and short-circuit
+calls = []
+
+
+def f(value):
+    calls.append(value)
+    return value
+
+
+assert (f(0) or f(2) or f(3)) == 2, 'or stops at the first truthy operand'
+assert calls == [0, 2], 'or short-circuits after the first truthy operand'
+calls = []
+assert (f(1) and f(0) and f(3)) == 0, 'and stops at the first falsy operand'
+assert calls == [1, 0], 'and short-circuits after the first falsy operand'
+
+# Common default-value idiom
+name = ''
+assert (name or 'anonymous') == 'anonymous', 'or as a default value'
@@ -0,0 +1,237 @@
+# === sorted() with key and reverse ===
+def neg(x):
+    return -x
+
+
+assert sorted([3, 1, 2], key=neg) == [3, 2, 1], 'sorted with def key'
+assert sorted([3, 1, 2], key=lambda x: -x) == [3, 2, 1], 'sorted with lambda key'
+assert sorted(['bb', 'a', 'ccc'], key=len) == ['a', 'bb', 'ccc'], 'sorted with builtin key'
+assert sorted([3, 1, 2], reverse=True) == [3, 2, 1], 'sorted reverse'
+assert sorted([3, 1, 2], key=None) == [1, 2, 3], 'sorted key=None'
+assert sorted([3, 1, 2], key=neg, reverse=True) == [1, 2, 3], 'sorted key and reverse'
+assert sorted((2, 1)) == [1, 2], 'sorted tuple'
+assert sorted([], key=neg) == [], 'sorted empty with key'
+
+# sorting is stable, including in reverse
+words = ['bb', 'a', 'cc', 'd']
+assert sorted(words, key=len) == ['a', 'd', 'bb', 'cc'], 'sorted stable'
+assert sorted(words, key=len, reverse=True) == ['bb', 'cc', 'a', 'd'], 'sorted reverse stable'
+
+pairs = [(1, 'b'), (0, 'a'), (1, 'a')]
+assert sorted(pairs, key=lambda p: p[1]) == [(0, 'a'), (1, 'a'), (1, 'b')], 'sorted by tuple element'
+
+# key is called once per item
+calls = []
+
+
+def tracking_key(x):
+    calls.append(x)
+    return x
+
+
+sorted([3, 1, 2], key=tracking_key)
+assert calls == [3, 1, 2], 'key called once per item in order'
+
+# === closures and defaults as key functions ===
+def make_key(offset):
+    def key(x):
+        return abs(x - offset)
+
+    return key
+
+
+assert sorted([1, 5, 9, 4], key=make_key(5)) == [5, 4, 1, 9], 'sorted with closure key'
+
+
+def scaled(x, factor=-1):
+    return x * factor
+
+
+assert sorted([1, 3, 2], key=scaled) == [3, 2, 1], 'sorted with default-arg key'
+
+# === list.sort() with user key ===
+lst = ['ccc', 'a', 'bb']
+lst.sort(key=lambda s: len(s))
+assert lst == ['a', 'bb', 'ccc'], 'list.sort with lambda key'
+
+lst = [1, 2, 3]
+lst.sort(key=neg, reverse=True)
+assert lst == [1, 2, 3], 'list.sort key and reverse'
+
+seen_lengths = []
+lst = [3, 1, 2]
+
+
+def len_during_sort(x):
+    seen_lengths.append(len(lst))
+    return x
+
+
+lst.sort(key=len_during_sort)
+assert seen_lengths == [0, 0, 0], 'list is empty while sorting'
+assert lst == [1, 2, 3], 'list sorted after key saw it empty'
+
+lst = [3, 1, 2]
+
+
+def grow(x):
+    lst.append(x)
+    return x
+
+
+try:
+    lst.sort(key=grow)
+    assert False, 'modifying list during sort should raise'
+except ValueError as e:
+    assert str(e) == 'list modified during sort', 'list modified during sort message'
+assert lst == [1, 2, 3], 'sorted items put back after modification'
+
+# === min() and max() with key and default ===
+assert min([3, -5, 2], key=abs) == 2, 'min with builtin key'
+assert max([3, -5, 2], key=abs) == -5, 'max with builtin key'
+assert min(['bb', 'a', 'ccc'], key=lambda s: len(s)) == 'a', 'min with lambda key'
+assert max('bb', 'a', 'ccc', key=len) == 'ccc', 'max of args with key'
+assert min([1, -1, 2], key=abs) == 1, 'min ties keep first'
+assert max([1, -1, 0], key=abs) == 1, 'max ties keep first'
+assert min([], default=7) == 7, 'min empty with default'
+assert max([], key=neg, default=None) is None, 'max empty with default None'
+assert min([4, 2], default=0) == 2, 'min ignores default when not empty'
+assert max([1, 2], key=None) == 2, 'max key=None'
+
+try:
+    min(1, 2, default=0)
+    assert False, 'default with multiple args should raise'
+except TypeError as e:
+    assert str(e) == 'Cannot specify a default for min() with multiple positional arguments', 'default error'
+
+try:
+    max([])
+    assert False, 'max of empty should raise'
+except ValueError:
+    pass
+
+try:
+    max([1, 'a'])
+    assert False, 'max of mixed types should raise'
+except TypeError as e:
+    assert str(e) == "'>' not supported between instances of 'str' and 'int'", 'max comparison message'
+
+# === filter() ===
+def is_even(x):
+    return x % 2 == 0
+
+
+assert list(filter(is_even, [1, 2, 3, 4])) == [2, 4], 'filter with def'
+assert list(filter(lambda x: x > 1, [1, 2, 3])) == [2, 3], 'filter with lambda'
+assert list(filter(None, [0, 1, '', 'a', None, [], [0]])) == [1, 'a', [0]], 'filter None keeps truthy items'
+assert list(filter(len, ['', 'a', ''])) == ['a'], 'filter with builtin'
+assert list(filter(is_even, [])) == [], 'filter empty'
+assert list(filter(lambda x: x, range(3))) == [1, 2], 'filter range'
+
+total = 0
+for n in filter(is_even, range(10)):
+    total += n
+assert total == 20, 'iterate over filter result'
+
+# === map() ===
+assert list(map(neg, [1, 2, 3])) == [-1, -2, -3], 'map with def'
+assert list(map(lambda x: x * 2, [1, 2])) == [2, 4], 'map with lambda'
+assert list(map(str, [1, 2])) == ['1', '2'], 'map with type'
+assert list(map(len, ['a', 'bb'])) == [1, 2], 'map with builtin'
+assert list(map(lambda a, b: a + b, [1, 2, 3], [10, 20])) == [11, 22], 'map stops at shortest'
+assert list(map(lambda a, b, c: a * b * c, [1, 2], [3, 4], [5, 6])) == [15, 48], 'map three iterables'
+assert list(map(neg, [])) == [], 'map empty'
+assert next(map(neg, [5])) == -5, 'next on map result'
+assert sum(map(neg, range(4))) == -6, 'sum of map result'
+
+# === nested callbacks ===
+def sort_inner(lists):
+    return sorted(lists, key=lambda x: -x)
+
+
+assert list(map(sort_inner, [[1, 2], [4, 3]])) == [[2, 1], [4, 3]], 'map calling sorted with key'
+assert sorted([[3, 1], [2]], key=lambda xs: min(xs, key=neg)) == [[2], [3, 1]], 'key calling min with key'
+assert list(map(lambda x: list(filter(is_even, x)), [[1, 2], [4]])) == [[2], [4]], 'map calling filter'
+
+# the builtin itself can be the callback
+assert list(map(sorted, ['ba', 'dc'])) == [['a', 'b'], ['c', 'd']], 'map with sorted'
+assert list(map(max, [1, 5], [4, 2])) == [4, 5], 'map with max'
+
+# chains of builtins calling each other
+rows = [[[5, -1], [2]], [[-3, 4]], [[1]]]
+assert sorted(rows, key=lambda row: max(sum(abs(x) for x in pair) for pair in row)) == [
+    [[1]],
+    [[5, -1], [2]],
+    [[-3, 4]],
+], 'key calling max over generators calling sum'
+assert sorted(rows, key=lambda row: max(row, key=lambda pair: min(pair, key=abs))) == [
+    [[-3, 4]],
+    [[1]],
+    [[5, -1], [2]],
+], 'key calling max with key calling min with key'
+assert [any(all(x > 0 for x in pair) for pair in row) for row in rows] == [True, False, True], 'any over all'
+
+# recursion through a callback
+def depth(x):
+    if isinstance(x, list):
+        return 1 + max(map(depth, x), default=0)
+    return 0
+
+
+assert depth([1, [2, [3]], []]) == 3, 'recursion through map and max'
+
+nested = []
+for _ in range(15):
+    nested = [nested]
+assert depth(nested) == 16, 'deep recursion through map and max'
+
+# === exceptions in callbacks ===
+def bad_key(x):
+    if x == 2:
+        raise ValueError('bad item')
+    return x
+
+
+try:
+    sorted([1, 2, 3], key=bad_key)
+    assert False, 'error in key should propagate'
+except ValueError as e:
+    assert str(e) == 'bad item', 'error from key function propagates'
+
+lst = [3, 2, 1]
+try:
+    lst.sort(key=bad_key)
+except ValueError:
+    pass
+assert lst == [3, 2, 1], 'list unchanged when key raises'
+
+
+def safe_key(x):
+    try:
+        return bad_key(x)
+    except ValueError:
+        return 0
+
+
+assert sorted([1, 2, 3], key=safe_key) == [2, 1, 3], 'error handled inside key function'
+
+try:
+    list(map(lambda x: 1 // x, [1, 0]))
+    assert False, 'error in map function should propagate'
+except ZeroDivisionError:
+    pass
+
+try:
+    sorted([1], key=lambda: 0)
+    assert False, 'wrong arity key should raise'
+except TypeError as e:
+    assert str(e) == '<lambda>() takes 0 positional arguments but 1 was given', 'wrong arity message'
+
+try:
+    list(map(5, [1]))
+    assert False, 'non-callable map function should raise'
+except TypeError:
+    pass
+
+# state after handled errors is intact
+assert sorted([2, 1], key=neg) == [2, 1], 'callbacks still work after errors'
@@ -0,0 +1,14 @@
+# `__debug__` is True unless running in optimized mode
+assert __debug__ is True, '__debug__ is True by default'
+
+checked = False
+if __debug__:
+    checked = True
+assert checked, 'if __debug__ body runs'
+
+
+def in_function():
+    return __debug__
+
+
+assert in_function() is True, '__debug__ inside a function'
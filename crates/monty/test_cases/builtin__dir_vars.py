@@ -0,0 +1,75 @@
+import sys
+
+# === dir() of a module ===
+names = dir(sys)
+assert 'version_info' in names, 'dir(sys) lists version_info'
+assert 'platform' in names, 'dir(sys) lists platform'
+assert names == sorted(names), 'dir(sys) is sorted'
+assert all(type(name) == str for name in names), 'dir(sys) returns strings'
+
+# === dir() of the current scope ===
+x = 1
+y = 'two'
+scope = dir()
+assert 'x' in scope and 'y' in scope, 'dir() lists module variables'
+assert 'sys' in scope, 'dir() lists imported modules'
+assert scope == sorted(scope), 'dir() is sorted'
+
+
+def f(a, b=2):
+    c = a + b
+    return dir()
+
+
+assert f(1) == ['a', 'b', 'c'], 'dir() in a function lists its locals'
+
+
+def unused_params(first, *args, last=None, **kwargs):
+    return dir()
+
+
+assert unused_params(1) == ['args', 'first', 'kwargs', 'last'], 'dir() lists parameters that are never read'
+
+
+def g():
+    assigned_later = dir()
+    late = 1
+    return assigned_later, late
+
+
+assert g()[0] == [], 'dir() skips unassigned locals'
+
+# === vars() of a module ===
+sys_vars = vars(sys)
+assert sys_vars['platform'] == sys.platform, 'vars(sys) maps names to values'
+assert sorted(sys_vars) == [n for n in dir(sys) if n in sys_vars], 'vars(sys) keys are in dir(sys)'
+
+# === vars() of the current scope ===
+assert vars()['x'] == 1 and vars()['y'] == 'two', 'vars() maps module variables'
+
+
+def h(p):
+    q = [p]
+    return vars()
+
+
+assert h(3) == {'p': 3, 'q': [3]}, 'vars() in a function returns its locals'
+
+# === errors ===
+try:
+    vars(1)
+    assert False, 'vars() of an int should raise'
+except TypeError as e:
+    assert str(e) == 'vars() argument must have __dict__ attribute', 'vars() error message'
+
+try:
+    vars([1, 2])
+    assert False, 'vars() of a list should raise'
+except TypeError as e:
+    assert str(e) == 'vars() argument must have __dict__ attribute', 'vars() list error message'
+
+try:
+    dir(1, 2)  # pyright: ignore
+    assert False, 'dir() with two arguments should raise'
+except TypeError as e:
+    assert str(e) == 'dir expected at most 1 argument, got 2', 'dir() arg count message'
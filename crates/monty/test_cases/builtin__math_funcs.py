@@ -111,6 +111,65 @@ assert r[0] == 3.0 and r[1] == 0.0, 'divmod floats'
 assert divmod(True, 2) == (0, 1), 'divmod accepts bool numerator'
 assert divmod(5, True) == (5, 0), 'divmod accepts bool denominator'
 
+# === Every numeric type through abs(), round() and divmod() ===
+big = 10**30
+min_i64 = -(2**63)
+
+assert type(abs(True)) == int and type(abs(-big)) == int and type(abs(-1.5)) == float, 'abs result types'
+assert abs(-big) == big and abs(big) == big, 'abs of a big int'
+assert abs(min_i64) == 2**63 and abs(min_i64 + 1) == 2**63 - 1, 'abs at the i64 boundary'
+assert abs(-(2**64)) == 2**64, 'abs of a negative big int'
+
+assert type(round(True)) == int and round(True) == 1, 'round of a bool is an int'
+assert type(round(big)) == int and round(big) == big, 'round of a big int'
+assert round(big + 4999, -4) == big and round(big + 5001, -4) == big + 10000, 'big int negative digits'
+assert round(min_i64, -1) == min_i64 - 2, 'int rounded past the i64 boundary'
+assert round(min_i64, 3) == min_i64, 'int with positive digits is unchanged'
+assert type(round(2.5, 0)) == float and type(round(2.5)) == int, 'float round result types'
+
+assert divmod(True, True) == (1, 0), 'divmod of bools'
+assert divmod(big, 7) == (big // 7, big % 7), 'big int by int'
+assert divmod(-7, big) == (-1, big - 7), 'int by big int'
+assert divmod(big * 3, big) == (3, 0), 'big int by big int'
+assert divmod(min_i64, -1) == (2**63, 0), 'i64 overflow promotes'
+assert divmod(big, 4.0) == (2.5e29, 0.0), 'big int by float'
+assert divmod(7.5, big) == (0.0, 7.5), 'float by big int'
+assert divmod(-7, 2.0) == (-4.0, 1.0), 'int by float'
+assert divmod(True, 0.5) == (2.0, 0.0), 'bool by float'
+q, r = divmod(big * 3, big)
+assert type(q) == int and type(r) == int, 'a big int quotient that fits is an int'
+
+for a, b in [(1, 0), (big, 0), (1, False), (1.5, 0), (1, 0.0), (big, 0.0)]:
+    try:
+        divmod(a, b)
+        assert False, 'divmod by zero should raise'
+    except ZeroDivisionError:
+        pass
+
+try:
+    divmod(10**400, 1.5)
+    assert False, 'a huge int with a float should raise'
+except OverflowError as e:
+    assert str(e) == 'int too large to convert to float', 'divmod overflow message'
+
+try:
+    divmod(big, 'a')
+    assert False, 'divmod with a str should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for divmod(): 'int' and 'str'", 'divmod type error'
+
+try:
+    abs('a')
+    assert False, 'abs of a str should raise'
+except TypeError as e:
+    assert str(e) == "bad operand type for abs(): 'str'", 'abs type error'
+
+try:
+    round([1])
+    assert False, 'round of a list should raise'
+except TypeError as e:
+    assert str(e) == "type list doesn't define __round__ method", 'round type error'
+
 # === pow() ===
 # Basic pow operations
 assert pow(2, 3) == 8, 'pow 2^3'
@@ -69,6 +69,13 @@ assert list(reversed('abc')) == ['c', 'b', 'a'], 'reversed string'
 
 # reversed range
 assert list(reversed(range(1, 4))) == [3, 2, 1], 'reversed range'
+assert list(reversed(range(0, 10, 3))) == [9, 6, 3, 0], 'reversed range with step'
+assert list(reversed(range(10, 0, -4))) == [2, 6, 10], 'reversed range with negative step'
+assert list(reversed(range(0))) == [], 'reversed empty range'
+assert list(reversed(range(5, 1))) == [], 'reversed range with start past stop'
+assert list(reversed(range(7, 8))) == [7], 'reversed single element range'
+assert list(reversed(range(-3, 4, 3))) == [3, 0, -3], 'reversed range across zero'
+assert [i for i in reversed(range(4))] == [3, 2, 1, 0], 'iterating a reversed range'
 
 # === enumerate() ===
 # Basic enumerate operations
@@ -108,3 +115,26 @@ assert list(zip([1, 2, 3])) == [(1,), (2,), (3,)], 'zip single iterable'
 # zip with empty
 assert list(zip([1, 2], [])) == [], 'zip with empty second'
 assert list(zip([], [1, 2])) == [], 'zip with empty first'
+
+# zip strict
+assert list(zip([1, 2], 'ab', strict=True)) == [(1, 'a'), (2, 'b')], 'zip strict equal lengths'
+assert list(zip([], [], strict=True)) == [], 'zip strict empty'
+assert list(zip(strict=True)) == [], 'zip strict without iterables'
+assert list(zip([1, 2], [3], strict=False)) == [(1, 3)], 'zip strict=False truncates'
+for args, message in [
+    (([1, 2], [1]), 'zip() argument 2 is shorter than argument 1'),
+    (([1], [1, 2]), 'zip() argument 2 is longer than argument 1'),
+    (([1], [1], [1, 2]), 'zip() argument 3 is longer than arguments 1-2'),
+    (([1, 2], [1, 2], [1]), 'zip() argument 3 is shorter than arguments 1-2'),
+]:
+    try:
+        list(zip(*args, strict=True))
+        assert False, f'zip strict {args} should raise'
+    except ValueError as e:
+        assert str(e) == message, f'zip strict {args} message'
+
+try:
+    zip([1], foo=True)  # pyright: ignore
+    assert False, 'zip unknown keyword should raise'
+except TypeError as e:
+    assert str(e) == "'foo' is an invalid keyword argument for zip()", 'zip unknown keyword message'
@@ -33,6 +33,27 @@ assert chr(0x10FFFF) != '', 'chr max unicode'
 assert chr(ord('x')) == 'x', 'ord/chr roundtrip'
 assert ord(chr(1000)) == 1000, 'chr/ord roundtrip'
 
+# Plane boundaries
+for code in [0x7F, 0x80, 0x7FF, 0x800, 0xD7FF, 0xE000, 0xFFFF, 0x10000, 0x1F600, 0xFFFFF, 0x100000, 0x10FFFF]:
+    assert ord(chr(code)) == code, 'chr/ord roundtrip across planes'
+assert len(chr(0x10000)) == 1, 'supplementary character is one character'
+assert chr(0x1F600) == '😀', 'chr emoji literal'
+assert ord('😀') == 128512, 'ord emoji literal'
+assert ord('\U0010ffff') == 0x10FFFF, 'ord max unicode'
+assert '😀a'[0] == chr(0x1F600), 'indexing keeps supplementary characters whole'
+
+try:
+    chr(0x110000)
+    assert False, 'chr above max unicode should raise'
+except ValueError as e:
+    assert str(e) == 'chr() arg not in range(0x110000)', 'chr above max unicode message'
+
+try:
+    chr(-1)
+    assert False, 'chr negative should raise'
+except ValueError as e:
+    assert str(e) == 'chr() arg not in range(0x110000)', 'chr negative message'
+
 # === bin() ===
 # Basic bin operations
 assert bin(0) == '0b0', 'bin 0'
@@ -0,0 +1,48 @@
+# === one argument ===
+assert type(1) == int, 'type of an int'
+assert type('a') == str, 'type of a str'
+assert type([]) == list, 'type of a list'
+
+# === argument counts ===
+try:
+    type()  # pyright: ignore
+    assert False, 'type() without arguments should raise'
+except TypeError as e:
+    assert str(e) == 'type() takes 1 or 3 arguments', 'no arguments message'
+
+try:
+    type('A', ())  # pyright: ignore
+    assert False, 'type() with two arguments should raise'
+except TypeError as e:
+    assert str(e) == 'type() takes 1 or 3 arguments', 'two arguments message'
+
+try:
+    type(1, 2, 3, 4)  # pyright: ignore
+    assert False, 'type() with four arguments should raise'
+except TypeError as e:
+    assert str(e) == 'type() takes 1 or 3 arguments', 'four arguments message'
+
+try:
+    type(x=1)  # pyright: ignore
+    assert False, 'type() with keywords should raise'
+except TypeError as e:
+    assert str(e) == 'type() takes 1 or 3 arguments', 'keyword arguments message'
+
+# === three argument types ===
+try:
+    type(1, (), {})  # pyright: ignore
+    assert False, 'non-str name should raise'
+except TypeError as e:
+    assert str(e) == 'type.__new__() argument 1 must be str, not int', 'name type message'
+
+try:
+    type('A', [], {})  # pyright: ignore
+    assert False, 'non-tuple bases should raise'
+except TypeError as e:
+    assert str(e) == 'type.__new__() argument 2 must be tuple, not list', 'bases type message'
+
+try:
+    type('A', (), [])  # pyright: ignore
+    assert False, 'non-dict namespace should raise'
+except TypeError as e:
+    assert str(e) == 'type.__new__() argument 3 must be dict, not list', 'namespace type message'
@@ -0,0 +1,215 @@
+# === Construction ===
+assert bytearray() == bytearray(b''), 'empty bytearray'
+assert len(bytearray()) == 0, 'empty bytearray has no bytes'
+assert bytearray(3) == b'\x00\x00\x00', 'int gives zero bytes'
+assert bytearray(b'abc') == b'abc', 'copy of bytes'
+assert bytearray([104, 105]) == b'hi', 'iterable of ints'
+assert bytearray(range(3)) == b'\x00\x01\x02', 'range of ints'
+assert bytes(bytearray(b'xy')) == b'xy', 'bytes of a bytearray'
+assert type(bytearray()) == bytearray, 'type is bytearray'
+assert isinstance(bytearray(), bytearray), 'isinstance bytearray'
+
+original = bytearray(b'ab')
+copy = bytearray(original)
+copy.append(99)
+assert original == b'ab', 'bytearray(bytearray) copies'
+
+# === Repr and truthiness ===
+assert repr(bytearray(b'hi')) == "bytearray(b'hi')", 'repr'
+assert repr(bytearray()) == "bytearray(b'')", 'empty repr'
+assert str(bytearray(b'\x00\n')) == "bytearray(b'\\x00\\n')", 'str escapes like bytes'
+assert not bytearray(), 'empty bytearray is falsy'
+assert bytearray(b'x'), 'non-empty bytearray is truthy'
+
+# === Building a buffer incrementally ===
+buf = bytearray()
+buf.append(72)
+buf.append(105)
+assert buf == b'Hi', 'append ints'
+buf.extend(b', ')
+buf.extend([116, 104, 101, 114, 101])
+assert buf == b'Hi, there', 'extend with bytes and a list'
+buf += b'!'
+assert buf == b'Hi, there!', '+= bytes'
+buf += bytearray(b'?')
+assert buf == b'Hi, there!?', '+= bytearray'
+assert buf.decode() == 'Hi, there!?', 'decode the buffer'
+assert len(buf) == 11, 'length after building'
+
+alias = buf
+buf += b'.'
+assert alias is buf, '+= keeps the same object'
+assert alias == b'Hi, there!?.', '+= changes the bytearray in place'
+
+# extending with itself reads the old contents
+doubled = bytearray(b'ab')
+doubled.extend(doubled)
+assert doubled == b'abab', 'extend with itself'
+
+# extend accepts an iterable of ints (fed in chunks, like a buffer)
+chunks = bytearray()
+for chunk in [b'ab', b'', b'cd']:
+    chunks.extend(chunk)
+chunks.extend(range(101, 103))
+assert chunks == b'abcdef', 'extend in a loop'
+
+# === Indexing and slicing ===
+data = bytearray(b'hello')
+assert data[0] == 104, 'index gives an int'
+assert data[-1] == 111, 'negative index'
+assert data[1:3] == b'el', 'slice'
+assert type(data[1:3]) == bytearray, 'slice is a bytearray'
+assert data[::-1] == b'olleh', 'reversed slice'
+assert list(data) == [104, 101, 108, 108, 111], 'iteration yields ints'
+
+# === Item assignment ===
+data[0] = 72
+assert data == b'Hello', 'assign an index'
+data[-1] = 0x21
+assert data == b'Hell!', 'assign a negative index'
+data[1] = True
+assert data[1] == 1, 'bools are ints'
+
+# === Slice assignment ===
+data = bytearray(b'hello')
+data[1:3] = b'EY'
+assert data == b'hEYlo', 'same-size slice'
+data[1:3] = b''
+assert data == b'hlo', 'shrinking slice'
+data[1:1] = b'el'
+assert data == b'hello', 'inserting slice'
+data[5:] = [33, 33]
+assert data == b'hello!!', 'slice past the end appends'
+data[:] = data
+assert data == b'hello!!', 'slice assigned from itself'
+data[::2] = b'ABCD'
+assert data == b'AeBlC!D', 'extended slice'
+data[::-3] = [49, 50, 51]
+assert data == b'3eB2C!1', 'negative extended slice'
+data[3:1] = b'xy'
+assert data == b'3eBxy2C!1', 'empty slice with stop before start inserts'
+
+# === Concatenation and repetition ===
+assert bytearray(b'ab') + b'cd' == b'abcd', 'bytearray + bytes'
+assert type(bytearray(b'ab') + b'cd') == bytearray, 'bytearray + bytes is a bytearray'
+assert type(b'ab' + bytearray(b'cd')) == bytes, 'bytes + bytearray is bytes'
+assert bytearray(b'ab') + bytearray(b'cd') == b'abcd', 'bytearray + bytearray'
+assert bytearray(b'ab') * 2 == b'abab', 'repetition'
+assert type(bytearray(b'ab') * 2) == bytearray, 'repetition is a bytearray'
+
+# === Equality ===
+assert bytearray(b'ab') == bytearray(b'ab'), 'equal bytearrays'
+assert bytearray(b'ab') != bytearray(b'ba'), 'different bytearrays'
+assert b'ab' == bytearray(b'ab'), 'bytes equals bytearray'
+assert bytearray(b'ab') != 'ab', 'bytearray is not a str'
+
+# === Byte range errors ===
+for bad in [256, -1, 2**70]:
+    try:
+        bytearray().append(bad)
+        assert False, f'append({bad}) should raise'
+    except ValueError as e:
+        assert str(e) == 'byte must be in range(0, 256)', f'append({bad}) message'
+
+try:
+    bytearray().extend([1, 300])
+    assert False, 'extend with a large int should raise'
+except ValueError as e:
+    assert str(e) == 'byte must be in range(0, 256)', 'extend range message'
+
+try:
+    bytearray([-5])
+    assert False, 'constructor with a negative int should raise'
+except ValueError as e:
+    assert str(e) == 'byte must be in range(0, 256)', 'constructor range message'
+
+try:
+    data = bytearray(b'abc')
+    data[0] = 1000
+    assert False, 'assigning a large int should raise'
+except ValueError as e:
+    assert str(e) == 'byte must be in range(0, 256)', 'setitem range message'
+
+try:
+    data = bytearray(b'abc')
+    data[0:1] = [256]
+    assert False, 'slice assignment with a large int should raise'
+except ValueError as e:
+    assert str(e) == 'byte must be in range(0, 256)', 'slice range message'
+
+# === Other errors ===
+try:
+    bytearray().append('a')  # pyright: ignore
+    assert False, 'append(str) should raise'
+except TypeError as e:
+    assert str(e) == "'str' object cannot be interpreted as an integer", 'append(str) message'
+
+try:
+    bytearray().append()  # pyright: ignore
+    assert False, 'append() should raise'
+except TypeError as e:
+    assert str(e) == 'bytearray.append() takes exactly one argument (0 given)', 'append() message'
+
+try:
+    bytearray().extend(5)  # pyright: ignore
+    assert False, 'extend(int) should raise'
+except TypeError as e:
+    assert str(e) == "can't extend bytearray with int", 'extend(int) message'
+
+try:
+    bytearray('abc')  # pyright: ignore
+    assert False, 'bytearray(str) should raise'
+except TypeError as e:
+    assert str(e) == 'string argument without an encoding', 'bytearray(str) message'
+
+try:
+    bytearray(-1)
+    assert False, 'negative count should raise'
+except ValueError as e:
+    assert str(e) == 'negative count', 'negative count message'
+
+try:
+    bytearray(1.5)  # pyright: ignore
+    assert False, 'bytearray(float) should raise'
+except TypeError as e:
+    assert str(e) == "cannot convert 'float' object to bytearray", 'bytearray(float) message'
+
+try:
+    bytearray(b'ab')[2]
+    assert False, 'index out of range should raise'
+except IndexError as e:
+    assert str(e) == 'bytearray index out of range', 'getitem index message'
+
+try:
+    data = bytearray(b'ab')
+    data[-3] = 1
+    assert False, 'assigning out of range should raise'
+except IndexError as e:
+    assert str(e) == 'bytearray index out of range', 'setitem index message'
+
+try:
+    data = bytearray(b'abcd')
+    data[::2] = b'xyz'
+    assert False, 'extended slice size mismatch should raise'
+except ValueError as e:
+    assert str(e) == 'attempt to assign bytes of size 3 to extended slice of size 2', 'extended slice message'
+
+try:
+    data = bytearray(b'ab')
+    data[0:1] = 5  # pyright: ignore
+    assert False, 'assigning an int to a slice should raise'
+except TypeError as e:
+    assert str(e) == 'can assign only bytes, buffers, or iterables of ints in range(0, 256)', 'slice value message'
+
+try:
+    data = bytearray(b'ab')
+    data += [1]  # pyright: ignore
+    assert False, '+= list should raise'
+except TypeError:
+    pass
+
+try:
+    hash(bytearray())
+    assert False, 'bytearray should be unhashable'
+except TypeError as e:
+    assert str(e) == "unhashable type: 'bytearray'", 'unhashable message'
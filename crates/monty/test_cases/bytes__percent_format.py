@@ -0,0 +1,93 @@
+# === Basic conversions ===
+assert b'hello %s' % b'world' == b'hello world', 'single %s'
+assert b'%s and %b' % (b'a', b'b') == b'a and b', '%b is an alias of %s'
+assert b'%d items' % 3 == b'3 items', 'single %d'
+assert b'%r' % 'x' == b"'x'", '%r uses the ascii repr'
+assert b'%r' % 'é' == b"'\\xe9'", '%r escapes non-ascii'
+assert b'%a' % b'x' == b"b'x'", '%a of bytes'
+assert b'100%%' % () == b'100%', 'literal percent'
+assert b'no specifiers' % () == b'no specifiers', 'template without specifiers'
+assert type(b'%d' % 1) == bytes, 'the result is bytes'
+
+# === Bytes-like arguments ===
+assert b'%s' % bytearray(b'ab') == b'ab', 'bytearray argument'
+assert b'%s' % bytes([1, 2]) == b'\x01\x02', 'heap bytes argument'
+assert b'\xff%s' % b'\xfe' == b'\xff\xfe', 'non-ascii bytes pass through'
+assert b'%5s|%-4b|' % (b'ab', b'c') == b'   ab|c   |', 'width and left-justify'
+assert b'%.1s' % b'xyz' == b'x', 'precision truncates'
+
+# === %c ===
+assert b'%c' % 65 == b'A', 'int code'
+assert b'%c' % 255 == b'\xff', 'top of the byte range'
+assert b'%c' % b'z' == b'z', 'single byte'
+assert b'%3c' % True == b'  \x01', 'bool code with width'
+
+# === Numbers ===
+assert b'%d %x %.2f %05d' % (3.5, 255, 1.234, -4) == b'3 ff 1.23 -0004', 'numeric conversions'
+assert b'%#o %X %e' % (8, 255, 1.5) == b'0o10 FF 1.500000e+00', 'alternate forms and exponents'
+assert b'%d' % 10**20 == b'100000000000000000000', 'big int'
+
+# === Mappings ===
+assert b'%(a)s-%(b)d' % {b'a': b'x', b'b': 2} == b'x-2', 'bytes keys'
+assert b'abc' % [] == b'abc', 'a list counts as a mapping'
+
+try:
+    b'%(a)s' % {'a': b'x'}
+    assert False, 'str keys do not match bytes'
+except KeyError:
+    pass
+
+# === Errors ===
+try:
+    b'%s' % 'a'
+    assert False, '%s with a str should raise'
+except TypeError as e:
+    assert str(e) == "%b requires a bytes-like object, or an object that implements __bytes__, not 'str'", 'str msg'
+
+try:
+    b'%s' % 1
+    assert False, '%s with an int should raise'
+except TypeError as e:
+    assert str(e) == "%b requires a bytes-like object, or an object that implements __bytes__, not 'int'", 'int msg'
+
+try:
+    b'%c' % 300
+    assert False, '%c out of range should raise'
+except OverflowError as e:
+    assert str(e) == '%c arg not in range(256)', '%c range message'
+
+try:
+    b'%c' % b'ab'
+    assert False, '%c with two bytes should raise'
+except TypeError as e:
+    assert str(e) == '%c requires an integer in range(256) or a single byte', '%c bytes message'
+
+try:
+    b'%c' % 'a'
+    assert False, '%c with a str should raise'
+except TypeError as e:
+    assert str(e) == '%c requires an integer in range(256) or a single byte', '%c str message'
+
+try:
+    b'abc' % b'x'
+    assert False, 'a bytes argument is not a mapping'
+except TypeError as e:
+    assert str(e) == 'not all arguments converted during bytes formatting', 'not all converted'
+
+try:
+    b'%s %s' % (b'a',)
+    assert False, 'missing argument should raise'
+except TypeError as e:
+    assert str(e) == 'not enough arguments for format string', 'not enough arguments'
+
+try:
+    b'%y' % 1
+    assert False, 'unknown conversion should raise'
+except ValueError as e:
+    assert str(e) == "unsupported format character 'y' (0x79) at index 1", 'unsupported character'
+
+try:
+    '%b' % b'x'
+    assert False, '%b is only for bytes templates'
+except ValueError as e:
+    assert str(e) == "unsupported format character 'b' (0x62) at index 1", '%b in a str template'
@@ -0,0 +1,60 @@
+# === nested lists and tuples ===
+assert [[1, 2], [3]] == [[1, 2], [3]], 'nested lists equal'
+assert [[1, 2], [3]] != [[1, 2], [4]], 'nested lists differ in an item'
+assert [[1, 2], [3]] != [[1, 2], [3, 4]], 'nested lists differ in length'
+assert ((1, (2, 3)), 4) == ((1, (2, 3)), 4), 'nested tuples equal'
+assert ((1, (2, 3)), 4) != ((1, (2, 4)), 4), 'nested tuples differ'
+assert [(1, [2])] == [(1, [2])], 'lists of tuples of lists'
+assert [1.0, [2]] == [1, [2.0]], 'nested numbers compare by value'
+
+# === nested dicts ===
+assert {'a': [1]} == {'a': [1]}, 'dict of lists equal'
+assert {'a': [1]} != {'a': [2]}, 'dict of lists differ'
+assert {'a': {'b': {'c': 1}}} == {'a': {'b': {'c': 1}}}, 'nested dicts equal'
+assert {'a': {'b': {'c': 1}}} != {'a': {'b': {'c': 2}}}, 'nested dicts differ deep down'
+assert {'a': 1, 'b': 2} == {'b': 2, 'a': 1}, 'dict order does not matter'
+assert {'a': 1} != {'a': 1, 'b': 2}, 'dicts differ in length'
+assert {'a': 1} != {'b': 1}, 'dicts differ in keys'
+assert {(1, 2): [3]} == {(1, 2): [3]}, 'tuple keys'
+
+# === sets ===
+assert {1, (2, 3)} == {(2, 3), 1}, 'sets with tuples'
+assert {frozenset([1]), frozenset([2])} == {frozenset([2]), frozenset([1])}, 'sets of frozensets'
+assert {1, 2} == frozenset([1, 2]), 'set equals frozenset'
+assert frozenset([1, 2]) == {1, 2}, 'frozenset equals set'
+assert {1, 2} != frozenset([1]), 'set and frozenset differ'
+assert [{1}, {'a': {2}}] == [{1}, {'a': {2}}], 'sets inside containers'
+
+# === different container types ===
+assert [1] != (1,), 'list is not equal to tuple'
+assert not ([1] == (1,)), 'list == tuple is False'
+assert [[1]] != [(1,)], 'nested list and tuple'
+assert {'a': [1]} != {'a': (1,)}, 'dict values of different types'
+assert [1, 2] != {1, 2}, 'list is not equal to set'
+assert {'a': 1} != [('a', 1)], 'dict is not equal to list of pairs'
+assert [] != (), 'empty list and tuple'
+assert [] != {}, 'empty list and dict'
+
+# === deeply nested ===
+a = [0]
+b = [0]
+for i in range(50):
+    a = [a, i]
+    b = [b, i]
+assert a == b, 'deeply nested equal'
+c = [1]
+for i in range(50):
+    c = [c, i]
+assert a != c, 'deeply nested differ at the bottom'
+
+d1 = {}
+d2 = {}
+for i in range(50):
+    d1 = {'k': d1, 'i': (i, [i])}
+    d2 = {'k': d2, 'i': (i, [i])}
+assert d1 == d2, 'deeply nested dicts equal'
+
+# === self-referencing ===
+s = []
+s.append(s)
+assert s == s, 'self-referencing list equals itself'
@@ -0,0 +1,96 @@
+# Comparisons the compiler fuses into specialized opcodes must behave like the unfused forms
+
+# === i < len(x) ===
+items = [10, 20, 30]
+total = 0
+i = 0
+while i < len(items):
+    total += items[i]
+    i += 1
+assert total == 60, 'while i < len loop'
+assert i == 3, 'loop stops at len'
+
+# the length is recomputed each time, so growing the list is seen
+grow = [1]
+i = 0
+while i < len(grow):
+    if len(grow) < 5:
+        grow.append(i)
+    i += 1
+assert grow == [1, 0, 1, 2, 3], 'len recomputed each iteration'
+
+assert (2 < len('abc')) is True, 'int less than len'
+assert (3 < len('abc')) is False, 'int equal to len'
+assert (-1 < len([])) is True, 'negative int'
+assert (0 < len({})) is False, 'empty dict'
+assert (1.5 < len((1, 2))) is True, 'float less than len'
+assert (2.5 < len((1, 2))) is False, 'float greater than len'
+assert (True < len('ab')) is True, 'bool less than len'
+assert (2**70 < len('ab')) is False, 'long int compared to len'
+assert (-(2**70) < len('ab')) is True, 'negative long int compared to len'
+assert (1 < len({1, 2, 3})) is True, 'set len'
+assert (1 < len(range(5))) is True, 'range len'
+assert (0 < len(b'x')) is True, 'bytes len'
+assert not (5 < len('ab')), 'not of fused comparison'
+assert [n for n in range(5) if n < len('abc')] == [0, 1, 2], 'fused comparison in comprehension'
+assert 0 < len('ab') < 3, 'chained comparison with len'
+
+
+def count_below(seq):
+    count = 0
+    while count < len(seq):
+        count += 1
+    return count
+
+
+assert count_below('hello') == 5, 'fused comparison in function'
+
+try:
+    0 < len(5)
+    assert False, 'len of int should raise'
+except TypeError as e:
+    assert str(e) == "object of type 'int' has no len()", 'len error message'
+
+try:
+    'a' < len('abc')
+    assert False, 'str compared with len should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'str' and 'int'", 'comparison error message'
+
+try:
+    None < len('abc')
+    assert False, 'None compared with len should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'NoneType' and 'int'", 'None comparison message'
+
+# other comparisons with len are not fused but still work
+assert 3 <= len('abc'), 'less or equal len'
+assert len('abc') > 2, 'len on the left'
+
+# === x == None and x != None ===
+x = None
+assert x == None, 'None == None'
+assert not (x != None), 'None != None'
+y = 0
+assert not (y == None), 'zero == None'
+assert y != None, 'zero != None'
+assert not ('' == None), 'empty string == None'
+assert not ([] == None), 'empty list == None'
+assert not (False == None), 'False == None'
+assert [v == None for v in [None, 0, 'a']] == [True, False, False], 'None comparison in comprehension'
+assert None == None, 'literal None == None'
+
+
+def find(seq, target):
+    for index, value in enumerate(seq):
+        if value == target:
+            return index
+    return None
+
+
+assert find([1, 2], 3) == None, 'function result compared to None'
+assert find([1, 2], 2) != None, 'function result not None'
+
+# === x % k == v still works next to the other fusions ===
+assert [n for n in range(10) if n % 3 == 0] == [0, 3, 6, 9], 'mod eq'
+assert (7 % 2 == None) is False, 'mod compared to None'
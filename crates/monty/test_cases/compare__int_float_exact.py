@@ -0,0 +1,45 @@
+# Comparisons between ints and floats are exact, without rounding the int to a float
+
+# === Near the f64 precision boundary (2**53) ===
+big = 2**53
+assert big + 1 > 2.0**53, 'int just above a float'
+assert 2.0**53 < big + 1, 'float just below an int'
+assert big + 1 != 2.0**53, 'unequal despite rounding to the same float'
+assert float(big + 1) == 2.0**53, 'the int does round to that float'
+assert big == 2.0**53, 'exactly representable'
+assert -big - 1 < -(2.0**53), 'negative int just below a float'
+assert 2**62 + 1 > 2.0**62, 'larger i64 above a float'
+assert 2**62 - 1 < 2.0**62, 'larger i64 below a float'
+assert 9007199254740993 >= 9007199254740992.0, 'ge near the boundary'
+assert not 9007199254740993 <= 9007199254740992.0, 'le near the boundary'
+
+# === Beyond i64, against LongInt ===
+assert 2**63 == 2.0**63, 'LongInt equal to a float'
+assert 2**63 + 1 > 2.0**63, 'LongInt above a float'
+assert 2.0**63 < 2**63 + 1, 'float below a LongInt'
+assert 2**100 < 2.0**100 + 2.0**48, 'LongInt below a larger float'
+assert 2**100 > 1e20, 'LongInt above a smaller float'
+assert 10**400 < float('inf'), 'LongInt below inf'
+assert -(10**400) > float('-inf'), 'negative LongInt above -inf'
+assert 2**64 > 1.5, 'LongInt above a fractional float'
+assert not (float('nan') < 2**64) and not (float('nan') > 2**64), 'nan is unordered'
+
+# === Fractional floats ===
+assert 3 < 3.5 and 4 > 3.5, 'int against a fraction'
+assert -3 > -3.5 and -4 < -3.5, 'negative int against a fraction'
+assert 2**63 < 2.0**63 + 4096.0, 'LongInt against the next float up'
+
+# === min and max keep the first of equal items and never round ===
+r = max(2**63, 2.0**63)
+assert r == 2**63 and type(r) == int, 'max of equal int and float keeps the int'
+r = max(2.0**63, 2**63)
+assert type(r) == float, 'max of equal float and int keeps the float'
+r = max(2.0**53, big + 1)
+assert r == big + 1 and type(r) == int, 'max picks the larger int near the boundary'
+r = min(big + 1, 2.0**53)
+assert type(r) == float, 'min picks the smaller float near the boundary'
+r = max([2.0**63, 2**63 + 1, 2**63 - 1])
+assert r == 2**63 + 1 and type(r) == int, 'max over a list with a LongInt'
+r = min(2**64 + 1, 2.0**64, 2**64 - 1)
+assert r == 2**64 - 1, 'min over mixed LongInts and a float'
+assert sorted([big + 1, 2.0**53, big - 1]) == [big - 1, 2.0**53, big + 1], 'sorted near the boundary'
@@ -0,0 +1,73 @@
+# === lists ===
+assert [1, 2] < [1, 3], 'list less'
+assert [1, 3] > [1, 2], 'list greater'
+assert [1, 2] <= [1, 2] and [1, 2] >= [1, 2], 'equal lists'
+assert not [1, 2] < [1, 2], 'equal lists are not less'
+assert [2] > [1, 100], 'first difference decides'
+assert [] < [0], 'empty list is smallest'
+
+# === tuples ===
+assert (1, 2) < (1, 2, 3), 'prefix is smaller'
+assert (1, 2, 3) > (1, 2), 'longer is greater'
+assert not (1, 2, 3) < (1, 2), 'longer is not less'
+assert (0, 'b') < (0, 'c'), 'string items'
+assert (1, 2.5) < (1, 3), 'mixed int and float items'
+assert () <= (), 'empty tuples'
+
+# === strings and bytes ===
+assert 'abc' < 'abd', 'str less'
+assert 'ab' < 'abc', 'str prefix'
+assert 'b' > 'abc', 'str first char decides'
+assert 'Z' < 'a', 'str compares code points'
+s = ''.join(['a', 'b', 'c'])
+assert s < 'abd' and 'abd' > s, 'runtime str with literal'
+assert s <= 'abc' and s >= 'abc', 'runtime str equal to literal'
+assert s + 'x' > s, 'runtime strs'
+assert b'abc' < b'abd', 'bytes less'
+assert bytes([97]) < b'b', 'runtime bytes with literal'
+
+# === nested ===
+assert [[1, 2], [3]] < [[1, 2], [4]], 'nested lists'
+assert [[1, 2], [3]] > [[1, 2]], 'nested prefix'
+assert [(1, 'a'), (1, 'b')] < [(1, 'a'), (2, 'a')], 'nested tuples'
+assert ([1], 2) < ([1], 3), 'tuple with list items'
+assert sorted([[2, 1], [1, 2], [1]]) == [[1], [1, 2], [2, 1]], 'sorted lists'
+assert max([(1, 'b'), (1, 'a')]) == (1, 'b'), 'max of tuples'
+
+# === NaN items compare false ===
+nan = float('nan')
+assert not [nan] < [1] and not [nan] > [1], 'nan item is unordered'
+assert [1, nan] < [2, nan], 'items before the nan decide'
+
+# === type errors ===
+try:
+    [1, 2] < [1, 'a']  # pyright: ignore
+    assert False, 'mismatched items should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'int' and 'str'", 'mismatched items message'
+
+try:
+    [[1]] >= [['a']]  # pyright: ignore
+    assert False, 'mismatched nested items should raise'
+except TypeError as e:
+    assert str(e) == "'>=' not supported between instances of 'int' and 'str'", 'nested mismatch message'
+
+try:
+    [1] < (1,)  # pyright: ignore
+    assert False, 'list and tuple should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'list' and 'tuple'", 'list and tuple message'
+
+try:
+    'a' > b'a'  # pyright: ignore
+    assert False, 'str and bytes should raise'
+except TypeError as e:
+    assert str(e) == "'>' not supported between instances of 'str' and 'bytes'", 'str and bytes message'
+
+try:
+    {1} < [1]  # pyright: ignore
+    assert False, 'set and list should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'set' and 'list'", 'set and list message'
+
+assert [1, 'a'] < [2, 3], 'items after the first difference are not compared'
@@ -0,0 +1,68 @@
+# Operations on literals are folded at compile time, results must match the runtime
+
+# === Int arithmetic ===
+assert 60 * 60 * 24 == 86400, 'int mult chain'
+assert (1 + 2) * 3 == 9, 'nested fold'
+assert -5 // 2 == -3, 'floor div rounds down'
+assert -7 % 3 == 2, 'mod takes sign of divisor'
+assert 7 % -3 == -2, 'mod with negative divisor'
+assert 2**10 == 1024, 'small pow'
+assert 2**-1 == 0.5, 'negative exponent gives float'
+assert 7 / 2 == 3.5, 'true division'
+assert 6 & 3 == 2, 'bitand'
+assert 6 | 3 == 7, 'bitor'
+assert 6 ^ 3 == 5, 'bitxor'
+assert -(-3) == 3, 'double negation'
+assert +5 == 5, 'unary plus'
+
+# === Overflow promotes to LongInt ===
+assert 9223372036854775807 + 1 == 9223372036854775808, 'add overflow'
+assert -9223372036854775807 - 2 == -9223372036854775809, 'sub overflow'
+assert 2**62 + 2**62 == 9223372036854775808, 'pow then add overflow'
+assert 4294967296 * 4294967296 == 18446744073709551616, 'mult overflow'
+assert -9223372036854775808 // -1 == 9223372036854775808, 'floordiv overflow'
+assert -(-9223372036854775808) == 9223372036854775808, 'neg overflow'
+assert 9223372036854775808 - 1 == 9223372036854775807, 'LongInt demoted to int'
+assert 2**100 == 1267650600228229401496703205376, 'big pow left to runtime'
+
+# === Float arithmetic ===
+assert 1.5 + 2 == 3.5, 'float add int'
+assert 7.5 % -2 == -0.5, 'float mod sign'
+assert -7.5 // 2 == -4.0, 'float floor div'
+assert 1e308 * 10 == float('inf'), 'float overflow to inf'
+assert -0.0 == 0.0, 'negative zero'
+
+# === Strings ===
+assert 'a' + 'b' == 'ab', 'str concat'
+assert 'ab' + 'cd' + 'ef' == 'abcdef', 'str concat chain'
+assert len('x' * 3) == 3, 'str repeat'
+
+# === Comparisons ===
+assert (1 < 2) is True, 'int lt'
+assert (2 <= 1) is False, 'int le'
+assert ('a' < 'b') is True, 'str lt'
+assert ('abc' == 'abc') is True, 'str eq'
+assert (1.5 != 1.5) is False, 'float ne'
+assert (1 == 1.0) is True, 'mixed int float eq'
+nan = float('nan')
+assert (nan == nan) is False, 'nan eq'
+assert (nan != nan) is True, 'nan ne'
+
+# === Errors are raised at runtime ===
+try:
+    1 / 0
+    assert False, 'should have raised'
+except ZeroDivisionError as e:
+    assert str(e) == 'division by zero', 'div by zero message'
+
+try:
+    10 % 0
+    assert False, 'should have raised'
+except ZeroDivisionError:
+    pass
+
+try:
+    1.0 // 0.0
+    assert False, 'should have raised'
+except ZeroDivisionError:
+    pass
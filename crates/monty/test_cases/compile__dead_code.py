@@ -0,0 +1,93 @@
+# Branches that can never run are dropped at compile time, behavior must be unchanged
+
+# === Literal if tests ===
+x = 0
+if False:
+    x = 1
+assert x == 0, 'if False body skipped'
+
+if True:
+    x = 2
+else:
+    x = 3
+assert x == 2, 'if True runs body'
+
+if 0:
+    x = 4
+elif None:
+    x = 5
+else:
+    x = 6
+assert x == 6, 'falsy literals go to else'
+
+if 1 > 2:
+    x = 7
+assert x == 6, 'folded comparison'
+
+
+# === Names assigned in dead code are still local ===
+def local_from_dead_code():
+    if False:
+        y = 1
+    try:
+        return y  # pyright: ignore
+    except NameError:
+        return 'unbound'
+
+
+assert local_from_dead_code() == 'unbound', 'dead assignment still makes name local'
+
+
+# === Code after return / raise ===
+def early_return():
+    return 1
+    return 2
+
+
+assert early_return() == 1, 'code after return skipped'
+
+
+def after_raise():
+    try:
+        raise ValueError('first')
+        raise TypeError('second')
+    except ValueError as e:
+        return str(e)
+
+
+assert after_raise() == 'first', 'code after raise skipped'
+
+# === Loops ===
+count = 0
+for i in range(5):
+    if i % 2:
+        continue
+        count += 100
+    count += 1
+assert count == 3, 'code after continue skipped'
+
+count = 0
+while True:
+    count += 1
+    if count == 3:
+        break
+        count += 100
+assert count == 3, 'while True with break'
+
+ran_else = False
+while False:
+    count = -1
+else:
+    ran_else = True
+assert ran_else, 'while False runs else'
+assert count == 3, 'while False body skipped'
+
+ran_else = False
+n = 0
+while True:
+    n += 1
+    if n > 2:
+        break
+else:
+    ran_else = True
+assert not ran_else, 'while True else never runs'
@@ -0,0 +1,124 @@
+from contextlib import nullcontext, suppress
+
+# === suppress swallows the listed exceptions ===
+reached = []
+with suppress(ValueError):
+    reached.append('before')
+    int('not a number')
+    reached.append('after')
+assert reached == ['before'], 'the rest of the block is skipped'
+
+with suppress(KeyError, IndexError):
+    [][0]
+with suppress(KeyError, IndexError):
+    {}['missing']
+
+with suppress(LookupError):
+    {}['missing']
+
+with suppress(Exception):
+    1 / 0
+
+result = 'unset'
+with suppress(ValueError):
+    result = 'no exception'
+assert result == 'no exception', 'a block without an exception runs to the end'
+
+# === Other exceptions propagate ===
+try:
+    with suppress(ValueError):
+        raise TypeError('not suppressed')
+    assert False, 'TypeError should propagate'
+except TypeError as e:
+    assert str(e) == 'not suppressed', 'the original exception propagates'
+
+try:
+    with suppress():
+        raise ValueError('nothing is suppressed')
+    assert False, 'suppress() with no exceptions should suppress nothing'
+except ValueError:
+    pass
+
+try:
+    with suppress(KeyError):
+        raise LookupError('a base class is not suppressed')
+    assert False, 'LookupError should propagate'
+except LookupError as e:
+    assert type(e) == LookupError, 'the base class propagates'
+
+
+# === Control flow leaves the block normally ===
+def first_int(values):
+    for value in values:
+        with suppress(ValueError):
+            return int(value)
+    return None
+
+
+assert first_int(['a', '12', '3']) == 12, 'return inside with'
+assert first_int(['x', 'y']) is None, 'every item suppressed'
+
+seen = []
+for i in range(5):
+    with suppress(ZeroDivisionError):
+        if i == 3:
+            break
+        if i == 1:
+            continue
+        seen.append(10 // (2 - i))
+assert seen == [5], 'break, continue and a suppressed error inside the loop'
+
+# === Nesting and several items ===
+log = []
+with suppress(KeyError):
+    with suppress(ValueError):
+        log.append('inner')
+        raise KeyError('outer handles this')
+    log.append('skipped')
+log.append('done')
+assert log == ['inner', 'done'], 'the outer suppress handles what the inner one does not'
+
+with suppress(ValueError), suppress(TypeError):
+    raise TypeError('the second item suppresses this')
+
+with suppress(TypeError), suppress(ValueError):
+    raise TypeError('the first item suppresses this')
+
+# === nullcontext ===
+with nullcontext() as value:
+    assert value is None, 'nullcontext() enters as None'
+
+data = [1, 2, 3]
+with nullcontext(data) as value:
+    assert value is data, 'nullcontext(x) enters as x'
+
+with nullcontext((1, 2)) as (a, b):
+    assert (a, b) == (1, 2), 'unpacking the enter result'
+
+try:
+    with nullcontext():
+        raise ValueError('nullcontext does not suppress')
+    assert False, 'ValueError should propagate'
+except ValueError:
+    pass
+
+with suppress(ValueError), nullcontext('ctx') as name:
+    assert name == 'ctx', 'mixed items'
+    raise ValueError('suppressed by the first item')
+
+# === Calling the methods directly ===
+s = suppress(ValueError, KeyError)
+assert s.__enter__() is None, 'suppress enters as None'
+assert s.__exit__(None, None, None) is False, 'nothing to suppress'
+assert s.__exit__(ValueError, ValueError('x'), None) is True, 'a listed exception'
+assert s.__exit__(KeyError, KeyError('x'), None) is True, 'another listed exception'
+assert s.__exit__(TypeError, TypeError('x'), None) is False, 'an exception that is not listed'
+assert nullcontext(5).__enter__() == 5, 'nullcontext enter result'
+assert nullcontext().__exit__(None, None, None) is None, 'nullcontext exit returns None'
+
+# a suppress object can be used more than once
+s = suppress(ValueError)
+with s:
+    raise ValueError('first')
+with s:
+    raise ValueError('second')
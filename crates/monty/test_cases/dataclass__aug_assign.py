@@ -0,0 +1,70 @@
+# call-external
+# === Augmented attribute assignment ===
+p = make_mutable_point()
+p.x += 10
+p.y *= 3
+assert p.x == 11, 'attribute += updates field'
+assert p.y == 6, 'attribute *= updates field'
+
+p.x -= 1
+p.x //= 2
+assert p.x == 5, 'chained augmented attribute updates'
+
+# === String and list field values ===
+p.x = 'a'
+p.x += 'b'
+assert p.x == 'ab', 'str += on attribute'
+
+items = [1]
+p.y = items
+p.y += [2]
+assert p.y == [1, 2], 'list += on attribute'
+assert p.y is items, 'list += on attribute mutates in place'
+
+# === Object expression evaluated once ===
+calls = []
+shared = make_mutable_point()
+
+
+def get_point():
+    calls.append('get')
+    return shared
+
+
+get_point().x += 5
+assert shared.x == 6, 'augmented assignment through a call'
+assert calls == ['get'], 'object expression evaluated exactly once'
+
+# === Nested attribute targets ===
+outer = make_mutable_point()
+outer.x = make_mutable_point()
+outer.x.y += 40
+assert outer.x.y == 42, 'nested attribute augmented assignment'
+
+# === Subscript inside attribute target ===
+holder = make_mutable_point()
+holder.x = {'count': 0}
+holder.x['count'] += 1
+holder.x['count'] += 1
+assert holder.x == {'count': 2}, 'subscript augmented assignment on an attribute'
+
+
+# === Inside a function ===
+def bump(point):
+    point.x += 1
+    return point.x
+
+
+q = make_mutable_point()
+assert bump(q) == 2, 'augmented attribute on a parameter'
+assert bump(q) == 3, 'augmented attribute on a parameter again'
+
+# === Frozen dataclass ===
+frozen = make_point()
+try:
+    frozen.x += 1
+    result = 'no error'
+except AttributeError as e:
+    result = str(e)
+assert result == "cannot assign to field 'x'", f'augmented assignment on frozen dataclass, {result=}'
+assert frozen.x == 1, 'frozen field unchanged'
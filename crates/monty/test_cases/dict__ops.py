@@ -29,6 +29,11 @@ d = {'a': 1}
 d['a'] = 99
 assert d == {'a': 99}, 'subscript set existing key'
 
+d = {1: 'one'}
+d[True] = 'true'
+d[1.0] = 'float'
+assert repr(d) == "{1: 'float'}", 'setting an equal key keeps the original key'
+
 # === Dict.get() method ===
 d = {'a': 1, 'b': 2}
 assert d.get('a') == 1, 'get existing'
@@ -58,3 +63,38 @@ assert d['self'] is d, 'getitem self'
 
 d = {}
 assert d.get('missing', d) is d, 'get default same dict'
+
+# === Dict union operators ===
+d1 = {'a': 1, 'b': 2}
+d2 = {'b': 20, 'c': 30}
+merged = d1 | d2
+assert merged == {'a': 1, 'b': 20, 'c': 30}, 'union with right side winning'
+assert list(merged) == ['a', 'b', 'c'], 'union keeps insertion order'
+assert d1 == {'a': 1, 'b': 2} and d2 == {'b': 20, 'c': 30}, 'union leaves operands unchanged'
+assert merged is not d1, 'union returns a new dict'
+assert d2 | d1 == {'b': 2, 'c': 30, 'a': 1}, 'union is not commutative on values'
+assert {} | {} == {}, 'union of empty dicts'
+assert d1 | d1 == d1, 'union with itself'
+
+d = {'a': 1}
+alias = d
+d |= {'a': 10, 'b': 2}
+assert d == {'a': 10, 'b': 2}, 'in-place union'
+assert alias is d, 'in-place union mutates the dict'
+d |= [('c', 3)]
+assert d == {'a': 10, 'b': 2, 'c': 3}, 'in-place union with pairs'
+d |= d
+assert d == {'a': 10, 'b': 2, 'c': 3}, 'in-place union with itself'
+
+try:
+    {'a': 1} | [('b', 2)]  # pyright: ignore
+    assert False, 'dict | list should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for |: 'dict' and 'list'", 'dict | list message'
+
+try:
+    d = {'a': 1}
+    d |= 5  # pyright: ignore
+    assert False, 'dict |= int should raise'
+except TypeError as e:
+    assert str(e) == "'int' object is not iterable", 'dict |= int message'
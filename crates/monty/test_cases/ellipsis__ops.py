@@ -0,0 +1,33 @@
+# === literal and builtin name ===
+assert ... is Ellipsis, 'literal is the Ellipsis builtin'
+assert Ellipsis == ..., 'Ellipsis equality'
+assert repr(Ellipsis) == 'Ellipsis', 'Ellipsis repr'
+assert str(...) == 'Ellipsis', 'Ellipsis str'
+assert type(...) == type(Ellipsis), 'same type'
+assert bool(Ellipsis), 'Ellipsis is truthy'
+assert Ellipsis is not None, 'Ellipsis is not None'
+assert [..., Ellipsis] == [Ellipsis, ...], 'Ellipsis in list'
+
+
+# === placeholder bodies ===
+def placeholder(): ...
+
+
+result = placeholder()
+assert result is None, 'ellipsis body returns None'
+
+
+def stub(x: int) -> int:
+    """Docstring then ellipsis."""
+    ...
+
+
+assert stub(1) is None, 'stub returns None'  # pyright: ignore
+
+# === ellipsis as a key ===
+d = {...: 'ellipsis', None: 'none'}
+assert d[...] == 'ellipsis', 'ellipsis dict key'
+assert d[Ellipsis] == 'ellipsis', 'Ellipsis name dict key'
+d[...] = 'updated'
+assert d[Ellipsis] == 'updated', 'ellipsis key assignment'
+assert hash(...) == hash(Ellipsis), 'ellipsis hash'
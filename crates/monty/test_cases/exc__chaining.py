@@ -0,0 +1,149 @@
+# === Explicit cause with raise ... from ===
+try:
+    try:
+        int('abc')
+    except ValueError as original:
+        raise RuntimeError('could not parse') from original
+except RuntimeError as e:
+    assert str(e) == 'could not parse', 'the new exception is raised'
+    assert type(e.__cause__) == ValueError, 'cause type'
+    assert str(e.__cause__) == "invalid literal for int() with base 10: 'abc'", 'cause message'
+    assert e.__suppress_context__ is True, 'from hides the context'
+    assert type(e.__context__) == ValueError, 'the handled exception is still the context'
+
+try:
+    raise KeyError('outer') from ValueError('not raised')
+except KeyError as e:
+    assert type(e.__cause__) == ValueError, 'the cause does not have to be raised'
+    assert e.__cause__.args == ('not raised',), 'cause args'  # pyright: ignore
+    assert e.__context__ is None, 'no context outside a handler'
+
+try:
+    raise ValueError('x') from TypeError
+except ValueError as e:
+    assert type(e.__cause__) == TypeError, 'an exception class is instantiated'
+    assert e.__cause__.args == (), 'instantiated with no args'  # pyright: ignore
+
+try:
+    raise ValueError from IndexError('y')
+except ValueError as e:
+    assert e.args == (), 'the raised class is instantiated too'
+    assert type(e.__cause__) == IndexError, 'cause of an instantiated class'
+
+# === raise ... from None ===
+try:
+    try:
+        {}['missing']
+    except KeyError:
+        raise LookupError('not found') from None
+except LookupError as e:
+    assert e.__cause__ is None, 'from None sets no cause'
+    assert e.__suppress_context__ is True, 'from None hides the context'
+    assert type(e.__context__) == KeyError, 'the context is still recorded'
+
+# === Implicit context ===
+try:
+    try:
+        raise ValueError('first')
+    except ValueError:
+        raise TypeError('second')
+except TypeError as e:
+    assert type(e.__context__) == ValueError, 'raised while handling'
+    assert str(e.__context__) == 'first', 'context message'
+    assert e.__cause__ is None, 'no explicit cause'
+    assert e.__suppress_context__ is False, 'the context is shown'
+
+try:
+    try:
+        [][0]
+    except IndexError:
+        1 / 0
+except ZeroDivisionError as e:
+    assert type(e.__context__) == IndexError, 'an error inside a handler'
+
+try:
+    raise ValueError('fresh')
+except ValueError as e:
+    assert e.__context__ is None, 'no context without a handled exception'
+    assert e.__cause__ is None, 'no cause by default'
+    assert e.__suppress_context__ is False, 'suppress_context defaults to False'
+
+
+def fail():
+    raise KeyError('in function')
+
+
+try:
+    try:
+        raise ValueError('handled')
+    except ValueError:
+        fail()
+except KeyError as e:
+    assert type(e.__context__) == ValueError, 'context across a function call'
+
+# a chain of contexts
+try:
+    try:
+        try:
+            raise ValueError('a')
+        except ValueError:
+            raise TypeError('b')
+    except TypeError:
+        raise KeyError('c')
+except KeyError as e:
+    assert type(e.__context__) == TypeError, 'first link'
+    assert type(e.__context__.__context__) == ValueError, 'second link'  # pyright: ignore
+    assert e.__context__.__context__.__context__ is None, 'end of the chain'  # pyright: ignore
+
+# === Re-raising keeps the original context ===
+try:
+    try:
+        raise ValueError('handled')
+    except ValueError as handled:
+        raise handled
+except ValueError as e:
+    assert e.__context__ is None, 'an exception is not its own context'
+
+try:
+    try:
+        raise ValueError('outer')
+    except ValueError:
+        try:
+            raise TypeError('inner')
+        except TypeError:
+            raise
+except TypeError as e:
+    assert type(e.__context__) == ValueError, 'bare raise keeps the context'
+
+# === Leaving a handler ends the context ===
+try:
+    raise ValueError('handled and done')
+except ValueError:
+    pass
+try:
+    raise TypeError('later')
+except TypeError as e:
+    assert e.__context__ is None, 'the earlier handler has finished'
+
+try:
+    try:
+        raise ValueError('escapes')
+    except ValueError:
+        raise TypeError('escaping')
+except TypeError:
+    pass
+try:
+    raise KeyError('after an escaped handler')
+except KeyError as e:
+    assert e.__context__ is None, 'a handler left by an exception has finished too'
+
+# === Invalid causes ===
+try:
+    raise ValueError('x') from 1  # pyright: ignore
+except TypeError as e:
+    assert str(e) == 'exception causes must derive from BaseException', 'invalid cause message'
+
+try:
+    raise 1 from ValueError()  # pyright: ignore
+except TypeError as e:
+    assert str(e) == 'exceptions must derive from BaseException', 'the exception is checked first'
@@ -0,0 +1,73 @@
+# call-external
+# === Loop else clauses with external calls ===
+
+# Search loop: ext call in body, no match runs else
+found = None
+for x in [1, 3, 5]:
+    if return_value(x) % 2 == 0:
+        found = x
+        break
+else:
+    found = 'none'
+assert found == 'none', 'search loop with ext calls runs else when nothing found'
+
+# Search loop: break after resuming skips else
+found = None
+for x in [1, 4, 5]:
+    if return_value(x) % 2 == 0:
+        found = x
+        break
+else:
+    found = 'none'
+assert found == 4, 'break after an ext call skips else'
+
+# Ext call inside the else block itself
+result = []
+for x in range(2):
+    result.append(x)
+else:
+    result.append(add_ints(10, 20))
+assert result == [0, 1, 30], 'ext call in for else block'
+
+# While loop with ext-call condition and else
+n = 0
+while add_ints(n, 0) < 3:
+    n += 1
+else:
+    n = concat_strings('done-', str(n))
+assert n == 'done-3', 'while else after ext-call condition'
+
+# While loop broken from after an ext call
+n = 0
+while True:
+    n = add_ints(n, 1)
+    if n == 2:
+        break
+else:
+    n = -1
+assert n == 2, 'while break after ext call skips else'
+
+# Nested loops: inner else runs per outer iteration, ext call resumes in both
+result = []
+for i in range(2):
+    for j in range(2):
+        if add_ints(i, j) == 2:
+            break
+        result.append((i, j))
+    else:
+        result.append(return_value('inner-else'))
+assert result == [(0, 0), (0, 1), 'inner-else', (1, 0)], f'nested loop else with ext calls, {result=}'
+
+
+# Loop else inside a function that makes ext calls
+def index_of(items, target):
+    for i, item in enumerate(items):
+        if return_value(item) == target:
+            break
+    else:
+        return -1
+    return i
+
+
+assert index_of(['a', 'b', 'c'], 'b') == 1, 'function search loop found'
+assert index_of(['a', 'b', 'c'], 'z') == -1, 'function search loop else'
@@ -0,0 +1,77 @@
+# IEEE 754 semantics for infinities and NaN
+inf = float('inf')
+nan = float('nan')
+
+# === Arithmetic ===
+assert inf + 1 == inf, 'inf plus a number'
+assert inf * 2 == inf, 'inf times a number'
+assert -inf * 2 == -inf, 'negative inf times a number'
+assert inf + inf == inf, 'inf plus inf'
+assert str(inf - inf) == 'nan', 'inf minus inf is nan'
+assert str(inf * 0) == 'nan', 'inf times zero is nan'
+assert str(nan + 1) == 'nan', 'nan propagates'
+assert 1 / inf == 0.0, 'dividing by inf'
+assert 5 % inf == 5.0, 'positive mod inf'
+assert -5 % inf == inf, 'negative mod inf takes the sign of the divisor'
+assert 5 // inf == 0.0, 'positive floordiv inf'
+assert -5 // inf == -1.0, 'negative floordiv inf'
+assert str(inf // 1) == 'nan', 'inf floordiv is nan'
+assert str(inf % 2) == 'nan', 'inf mod is nan'
+assert divmod(5, inf) == (0.0, 5.0), 'divmod by inf'
+assert inf**0 == 1.0, 'inf to the zero'
+assert nan**0 == 1.0, 'nan to the zero'
+assert abs(-inf) == inf, 'abs of negative inf'
+
+# === Parsing and repr ===
+assert float('-Infinity') == -inf, 'parse -Infinity'
+assert float(' +inf ') == inf, 'parse +inf with spaces'
+assert str(float(' nan ')) == 'nan', 'parse nan with spaces'
+assert repr(inf) == 'inf' and repr(-inf) == '-inf', 'repr of infinities'
+assert repr(nan) == 'nan', 'repr of nan'
+
+# === Equality ===
+assert nan != nan, 'nan is not equal to itself'
+assert not (nan == nan), 'nan == nan is false'
+x = nan
+assert not (x == x), 'the same nan object is unequal too'
+assert nan != 1 and nan != 1.0, 'nan is unequal to numbers'
+assert inf == inf, 'inf equals itself'
+assert inf != -inf, 'signed infinities differ'
+
+# === Ordering ===
+assert inf > 10**1000, 'inf above a huge int'
+assert -inf < -(10**1000), 'negative inf below a huge negative int'
+assert 10**1000 < inf, 'huge int below inf'
+assert inf != 10**1000, 'inf is not equal to a huge int'
+assert inf > 1e308, 'inf above the largest floats'
+assert not (nan < 1) and not (nan > 1), 'nan is unordered against ints'
+assert not (nan <= nan) and not (nan >= nan), 'nan is unordered against itself'
+assert not (nan < 10**1000) and not (nan > 10**1000), 'nan is unordered against huge ints'
+assert not ([nan] < [nan]), 'nan in a list compares false'
+
+# === Sorting and min/max ===
+assert str(sorted([2.0, 1.0, nan])) == '[1.0, 2.0, nan]', 'sorting with a trailing nan'
+assert str(sorted([nan, 2.0, 1.0])) == '[nan, 1.0, 2.0]', 'sorting with a leading nan'
+assert len(sorted([3.0, nan, 1.0, nan, 2.0])) == 5, 'sorting nans does not raise'
+assert sorted([inf, 1, -inf, 10**1000]) == [-inf, 1, 10**1000, inf], 'sorting infinities with ints'
+items = [1.0, nan]
+items.sort(reverse=True)
+assert len(items) == 2, 'reverse sort with nan'
+assert max([1, nan, 3]) == 3, 'max skips over a nan'
+assert str(max([nan, 1, 3])) == 'nan', 'max keeps a leading nan'
+assert str(min([nan, 1])) == 'nan', 'min keeps a leading nan'
+assert min([1, nan]) == 1, 'min is not replaced by nan'
+assert max(inf, 10**1000) == inf, 'max of inf and a huge int'
+
+# === Conversion to int ===
+try:
+    int(inf)
+    assert False, 'int(inf) should raise'
+except OverflowError as e:
+    assert str(e) == 'cannot convert float infinity to integer', 'int(inf) message'
+
+try:
+    int(nan)
+    assert False, 'int(nan) should raise'
+except ValueError as e:
+    assert str(e) == 'cannot convert float NaN to integer', 'int(nan) message'
@@ -0,0 +1,50 @@
+# === shortest round-tripping digits ===
+assert repr(0.1) == '0.1', 'repr 0.1'
+assert repr(0.1 + 0.2) == '0.30000000000000004', 'repr 0.1 + 0.2'
+assert repr(1 / 3) == '0.3333333333333333', 'repr 1/3'
+assert repr(2 / 3) == '0.6666666666666666', 'repr 2/3'
+assert repr(3.14159) == '3.14159', 'repr pi approximation'
+assert repr(-2.5) == '-2.5', 'repr negative'
+assert repr(123456.789) == '123456.789', 'repr many digits'
+assert repr(5e-324) == '5e-324', 'repr smallest subnormal'
+assert repr(1.7976931348623157e308) == '1.7976931348623157e+308', 'repr max float'
+assert float(repr(0.1 + 0.2)) == 0.1 + 0.2, 'repr round-trips'
+
+# === integral floats ===
+assert repr(1.0) == '1.0', 'repr 1.0'
+assert repr(0.0) == '0.0', 'repr zero'
+assert repr(-0.0) == '-0.0', 'repr negative zero'
+assert repr(100.0) == '100.0', 'repr 100.0'
+assert repr(float(2**53)) == '9007199254740992.0', 'repr 2**53'
+
+# === switch to scientific notation ===
+assert repr(1e15) == '1000000000000000.0', 'repr 1e15 positional'
+assert repr(1e16) == '1e+16', 'repr 1e16 scientific'
+assert repr(1.5e16) == '1.5e+16', 'repr 1.5e16'
+assert repr(1e300) == '1e+300', 'repr 1e300'
+assert repr(-1e300) == '-1e+300', 'repr -1e300'
+assert repr(1e22) == '1e+22', 'repr 1e22'
+assert repr(0.0001) == '0.0001', 'repr 0.0001 positional'
+assert repr(0.00001) == '1e-05', 'repr 1e-05 scientific'
+assert repr(1.5e-7) == '1.5e-07', 'repr 1.5e-07'
+assert repr(1e-100) == '1e-100', 'repr 1e-100'
+assert repr(123e-20) == '1.23e-18', 'repr 1.23e-18'
+
+# === special values ===
+inf = float('inf')
+nan = float('nan')
+assert repr(inf) == 'inf', 'repr inf'
+assert repr(-inf) == '-inf', 'repr -inf'
+assert repr(nan) == 'nan', 'repr nan'
+assert repr(inf * 0) == 'nan', 'repr computed nan'
+assert repr(1e308 * 10) == 'inf', 'repr overflow to inf'
+
+# === str() and containers match repr() ===
+assert str(0.1) == '0.1', 'str 0.1'
+assert str(1e16) == '1e+16', 'str 1e16'
+assert str(1.0) == '1.0', 'str 1.0'
+assert str(nan) == 'nan', 'str nan'
+assert str([0.1, 1e-05, inf]) == '[0.1, 1e-05, inf]', 'str list of floats'
+assert f'{1e16}' == '1e+16', 'f-string float'
+assert f'{2.5!r}' == '2.5', 'f-string float repr'
+assert '%s' % 1e-05 == '1e-05', 'percent s float'
@@ -0,0 +1,6 @@
+def f(*, a, b, c):
+    return a + b + c
+
+
+f()
+# Raise=TypeError("f() missing 3 required keyword-only arguments: 'a', 'b', and 'c'")
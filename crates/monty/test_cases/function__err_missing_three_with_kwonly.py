@@ -0,0 +1,6 @@
+def f(a, b, c, *, d):
+    return a + b + c + d
+
+
+f(d=1)
+# Raise=TypeError("f() missing 3 required positional arguments: 'a', 'b', and 'c'")
@@ -0,0 +1,48 @@
+# === defined functions ===
+def foo():
+    pass
+
+
+r = repr(foo)
+assert r.startswith('<function foo at 0x'), f'function repr: {r}'
+assert r.endswith('>'), f'function repr ends with >: {r}'
+assert r == f'<function foo at {hex(id(foo))}>', 'function repr shows its id'
+assert str(foo) == r, 'str of a function is its repr'
+assert f'{foo}' == r, 'f-string of a function is its repr'
+
+
+def with_defaults(a, b=1):
+    return a + b
+
+
+r = repr(with_defaults)
+assert r == f'<function with_defaults at {hex(id(with_defaults))}>', f'function with defaults repr: {r}'
+assert str(with_defaults) == r, 'str of a function with defaults is its repr'
+
+# === closures ===
+def outer():
+    x = 1
+
+    def inner():
+        return x
+
+    return inner
+
+
+inner = outer()
+r = repr(inner)
+assert r.startswith('<function ') and r.endswith(f'inner at {hex(id(inner))}>'), f'closure repr: {r}'
+assert str(inner) == r, 'str of a closure is its repr'
+
+# === lambdas ===
+square = lambda n: n * n  # noqa: E731
+assert repr(square) == f'<function <lambda> at {hex(id(square))}>', 'lambda repr'
+
+# === builtins and types ===
+assert repr(len) == '<built-in function len>', 'repr(len)'
+assert str(len) == '<built-in function len>', 'str(len)'
+assert repr(sorted) == '<built-in function sorted>', 'repr(sorted)'
+assert repr(int) == "<class 'int'>", 'repr(int)'
+assert str(str) == "<class 'str'>", 'str(str)'
+assert repr(ValueError) == "<class 'ValueError'>", 'repr(ValueError)'
+assert repr([len, int]) == "[<built-in function len>, <class 'int'>]", 'reprs inside a list'
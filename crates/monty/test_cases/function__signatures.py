@@ -45,3 +45,42 @@ assert collect_all(*source_tuple) == (1, 2, 3), 'tuple unpacked with *args'
 
 source_list = [4, 5]
 assert collect_all(0, *source_list) == (0, 4, 5), 'positional args followed by *args'
+
+
+# === Keyword-only parameters ===
+def kw_only(a, *, b, c=3):
+    return (a, b, c)
+
+
+assert kw_only(1, b=2) == (1, 2, 3), 'keyword-only with default'
+assert kw_only(1, c=4, b=2) == (1, 2, 4), 'keyword-only in any order'
+assert kw_only(a=1, b=2) == (1, 2, 3), 'all arguments by keyword'
+
+
+# === *args and **kwargs collection ===
+def collect(a, b=2, *args, c, d=4, **kwargs):
+    return (a, b, args, c, d, kwargs)
+
+
+assert collect(1, c=3) == (1, 2, (), 3, 4, {}), 'defaults with empty varargs'
+assert collect(1, 5, 6, 7, c=3) == (1, 5, (6, 7), 3, 4, {}), 'extra positionals collected'
+assert collect(1, c=3, e=5, d=6) == (1, 2, (), 3, 6, {'e': 5}), 'extra keywords collected'
+assert collect(*[1, 2, 3], **{'c': 0, 'z': 9}) == (1, 2, (3,), 0, 4, {'z': 9}), 'unpacked call'
+
+
+def only_kwargs(**kwargs):
+    return sorted(kwargs.items())
+
+
+assert only_kwargs() == [], 'empty kwargs'
+assert only_kwargs(b=2, a=1) == [('a', 1), ('b', 2)], 'kwargs collected into a dict'
+
+
+def kwargs_is_fresh(**kwargs):
+    kwargs['added'] = True
+    return kwargs
+
+
+options = {'x': 1}
+assert kwargs_is_fresh(**options) == {'x': 1, 'added': True}, 'kwargs is a new dict'
+assert options == {'x': 1}, 'caller dict unchanged'
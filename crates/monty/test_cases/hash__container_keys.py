@@ -0,0 +1,102 @@
+import sys
+
+# === tuples as dict keys ===
+grid = {}
+for x in range(3):
+    for y in range(3):
+        grid[(x, y)] = x * 3 + y
+assert len(grid) == 9, 'nine distinct tuple keys'
+assert grid[(2, 1)] == 7, 'lookup with a new equal tuple'
+pos = (1, 1)
+assert grid[pos] == 4, 'lookup with a tuple variable'
+grid[(1, 1)] = 'center'
+assert grid[pos] == 'center' and len(grid) == 9, 'equal tuple key updates in place'
+assert (0, 0) in grid and (3, 3) not in grid, 'in with tuple keys'
+assert grid.get((5, 5), 'missing') == 'missing', 'get with a missing tuple key'
+
+try:
+    grid[(9, 9)]
+    assert False, 'missing tuple key should raise'
+except KeyError as e:
+    assert str(e) == '(9, 9)', 'KeyError shows the tuple key'
+
+# nested and mixed tuples
+nested = {((1, 2), 'a'): 1, (): 'empty', (None, True, 2.5): 'mixed'}
+assert nested[((1, 2), 'a')] == 1, 'nested tuple key'
+assert nested[()] == 'empty', 'empty tuple key'
+assert nested[(None, 1, 2.5)] == 'mixed', 'tuple keys compare elements by value'
+assert {(1, 2): 'x'}[(1.0, 2.0)] == 'x', 'tuples of equal numbers are equal keys'
+
+# tuples that differ only in order are different keys
+order = {(1, 2): 'a', (2, 1): 'b'}
+assert order[(1, 2)] == 'a' and order[(2, 1)] == 'b', 'tuple keys are ordered'
+assert hash((1, 2)) == hash((1, 2)), 'equal tuples hash equal'
+
+# === frozensets as dict keys ===
+groups = {frozenset({1, 2}): 'one-two', frozenset(): 'none', frozenset({'a'}): 'letter'}
+assert groups[frozenset({2, 1})] == 'one-two', 'frozenset key ignores order'
+assert groups[frozenset([1, 2, 2, 1])] == 'one-two', 'frozenset key from a list with duplicates'
+assert groups[frozenset()] == 'none', 'empty frozenset key'
+assert frozenset({'a'}) in groups, 'in with a frozenset key'
+assert frozenset({1, 2, 3}) not in groups, 'missing frozenset key'
+assert hash(frozenset({1, 2, 3})) == hash(frozenset({3, 2, 1})), 'frozenset hash ignores order'
+assert frozenset({1, 2, 3}) not in {frozenset(): 0}, 'superset is not the empty frozenset'
+
+try:
+    groups[frozenset({9})]
+    assert False, 'missing frozenset key should raise'
+except KeyError:
+    pass
+
+# frozensets of tuples and tuples of frozensets
+edges = {frozenset({(0, 1), (1, 2)}): 'path', (frozenset({1}), 2): 'pair'}
+assert edges[frozenset({(1, 2), (0, 1)})] == 'path', 'frozenset of tuples key'
+assert edges[(frozenset({1}), 2)] == 'pair', 'tuple containing a frozenset key'
+
+# === set members ===
+seen = set()
+for point in [(1, 2), (3, 4), (1, 2), (1.0, 2.0)]:
+    seen.add(point)
+assert seen == {(1, 2), (3, 4)}, 'duplicate tuples are one member'
+assert (3, 4) in seen and (4, 3) not in seen, 'in with tuple members'
+seen.remove((3, 4))
+assert seen == {(1, 2)}, 'remove a tuple member'
+
+families = {frozenset({'x', 'y'}), frozenset({'y', 'x'}), frozenset({'z'})}
+assert len(families) == 2, 'equal frozensets are one member'
+assert frozenset({'z'}) in families, 'in with frozenset members'
+
+# === collisions ===
+# many keys with small, overlapping element hashes
+keys = [(a, b) for a in range(20) for b in range(20)]
+table = {k: i for i, k in enumerate(keys)}
+assert len(table) == 400, 'all tuple keys are distinct'
+assert all(table[k] == i for i, k in enumerate(keys)), 'every tuple key is found'
+subsets = [frozenset(s) for s in [(), (1,), (2,), (3,), (1, 2), (1, 3), (2, 3), (1, 2, 3)]]
+by_subset = {s: len(s) for s in subsets}
+assert len(by_subset) == 8, 'all frozenset keys are distinct'
+assert all(by_subset[s] == len(s) for s in subsets), 'every frozenset key is found'
+
+# === unhashable contents ===
+# the message names the item that can't be hashed
+try:
+    {(1, [2]): 'x'}  # pyright: ignore
+    assert False, 'a tuple containing a list should be unhashable'
+except TypeError as e:
+    assert "unhashable type: 'list'" in str(e), 'unhashable tuple key message'
+
+try:
+    {(1, (2, {})), 3}  # pyright: ignore
+    assert False, 'a nested tuple containing a dict should be unhashable'
+except TypeError as e:
+    assert "unhashable type: 'dict'" in str(e), 'unhashable nested tuple message'
+
+try:
+    hash(('a', {1}))
+    assert False, 'hash of a tuple containing a set should raise'
+except TypeError as e:
+    assert str(e) == "unhashable type: 'set'", 'hash() of an unhashable tuple message'
+
+# === named tuples hash like tuples ===
+assert hash(sys.version_info) == hash(tuple(sys.version_info)), 'named tuple hashes like its tuple'
+assert {tuple(sys.version_info): 'v'}[sys.version_info] == 'v', 'named tuple finds the equal tuple key'
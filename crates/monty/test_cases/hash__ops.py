@@ -65,6 +65,32 @@ b1 = b'test'
 b2 = b'te' + b'st'
 assert hash(b1) == hash(b2), 'concatenated bytes hashes same as literal'
 
+# equal strings built at runtime are the same dict key as literals
+runtime_key = ''.join(['ke', 'y'])
+assert hash(runtime_key) == hash('key'), 'joined string hashes same as literal'
+assert hash(str(12)) == hash('12'), 'str() result hashes same as literal'
+assert hash('AB'.lower()) == hash('ab'), 'method result hashes same as literal'
+assert hash(bytes([116, 101, 115, 116])) == hash(b'test'), 'bytes() result hashes same as literal'
+
+mixed = {'key': 'literal', runtime_key: 'runtime'}
+assert len(mixed) == 1, 'literal and runtime strings are one key'
+assert mixed['key'] == 'runtime', 'runtime string overwrote the literal key'
+mixed[f'{"a"}{"b"}'] = 'f-string'
+mixed['ab'] = 'literal ab'
+assert len(mixed) == 2, 'f-string and literal are one key'
+assert mixed['a' * 1 + 'b'] == 'literal ab', 'lookup with another runtime string'
+assert 'ke' + 'y' in mixed and 'ab' in mixed, 'in with runtime and literal strings'
+assert {b'test': 1}[b'te' + b'st'] == 1, 'runtime bytes find a literal bytes key'
+assert {str(12): 1}.get('12') == 1, 'literal finds a runtime string key'
+assert len({'x', 'x'.upper().lower(), ''.join('x')}) == 1, 'set members across representations'
+
+
+def kwarg_lookup(key):
+    return key
+
+
+assert kwarg_lookup(**{runtime_key: 'found'}) == 'found', 'runtime string as a keyword name'
+
 
 # === Function hashing ===
 def f():
@@ -151,3 +177,50 @@ td[ValueError] = 'value_error'
 assert td[int] == 'int_type', 'int type as dict key'
 assert td[str] == 'str_type', 'str type as dict key'
 assert td[ValueError] == 'value_error', 'exception type as dict key'
+
+# === equal numbers hash equal ===
+assert hash(1) == hash(1.0) == hash(True), 'hash(1) == hash(1.0) == hash(True)'
+assert hash(0) == hash(0.0) == hash(-0.0) == hash(False), 'zero hashes'
+assert hash(-5) == hash(-5.0), 'negative int and float'
+assert hash(2**64) == hash(float(2**64)), 'LongInt and float'
+assert hash(2**100) == hash(2.0**100), 'huge LongInt and float'
+assert hash(-(2**70)) == hash(-(2.0**70)), 'negative LongInt and float'
+big = 2**64
+assert hash(big - 2**64 + 7) == hash(7), 'LongInt arithmetic down to a small int'
+
+# === values match CPython ===
+assert hash(1) == 1, 'hash(1)'
+assert hash(-1) == -2, 'hash(-1) is -2'
+assert hash(-2) == -2, 'hash(-2)'
+assert hash(2**61 - 1) == 0, 'the modulus hashes to 0'
+assert hash(2**61) == 1, 'the modulus plus one hashes to 1'
+assert hash(-(2**61)) == -2, 'the negated modulus plus one hashes to -2'
+assert hash(2**63) == 4, 'hash(2**63)'
+assert hash(0.5) == 1152921504606846976, 'hash(0.5)'
+assert hash(1.5) == 1152921504606846977, 'hash(1.5)'
+assert hash(-1.5) == -1152921504606846977, 'hash(-1.5)'
+assert hash(float('inf')) == 314159, 'hash(inf)'
+assert hash(float('-inf')) == -314159, 'hash(-inf)'
+assert hash(1e100) == hash(int(1e100)), 'hash(1e100) matches the equal int'
+assert hash(2**-1074) == hash(5e-324), 'smallest subnormal'
+
+# === numeric dict keys and set members ===
+d = {1: 'one', 2.5: 'two and a half'}
+assert d[1.0] == 'one', 'float key finds an int key'
+assert d[True] == 'one', 'bool key finds an int key'
+d[1.0] = 'uno'
+assert d == {1: 'uno', 2.5: 'two and a half'}, 'equal keys update in place'
+assert len(d) == 2, 'equal keys do not add entries'
+assert {1, 1.0, True} == {1}, 'set of equal numbers has one member'
+assert len({0, 0.0, False, -0.0}) == 1, 'equal zeros collapse'
+assert 2**64 in {float(2**64)}, 'LongInt found in a set of floats'
+assert {2**70: 'x'}[2.0**70] == 'x', 'float key finds a LongInt key'
+assert 2**64 == 2.0**64 and 2.0**64 == 2**64, 'LongInt equals the equal float'
+assert 2**64 + 1 != 2.0**64, 'LongInt comparison with a float is exact'
+assert float(2**70) == 2.0**70, 'float() of a LongInt'
+
+try:
+    float(10**400)
+    assert False, 'float() of a huge LongInt should raise'
+except OverflowError as e:
+    assert str(e) == 'int too large to convert to float', 'float() overflow message'
@@ -0,0 +1,118 @@
+import heapq
+from heapq import heapify, heappop, heappush
+
+
+def is_heap(h):
+    for i in range(1, len(h)):
+        if h[(i - 1) // 2] > h[i]:
+            return False
+    return True
+
+
+# === heappush ===
+h = []
+for x in [5, 3, 8, 1, 9, 2, 7]:
+    heappush(h, x)
+    assert is_heap(h), 'heap order after each push'
+assert h == [1, 3, 2, 5, 9, 8, 7], 'heap layout matches CPython'
+assert len(h) == 7, 'pushes add items'
+assert heapq.heappush(h, 4) is None, 'heappush returns None'
+
+# === heappop ===
+popped = []
+while h:
+    popped.append(heappop(h))
+    assert is_heap(h), 'heap order after each pop'
+assert popped == [1, 2, 3, 4, 5, 7, 8, 9], 'pops come out in sorted order'
+assert h == [], 'popping everything empties the heap'
+
+h = [1]
+assert heappop(h) == 1, 'pop the only item'
+assert h == [], 'heap empty after popping the only item'
+
+try:
+    heappop([])
+    assert False, 'popping an empty heap should raise'
+except IndexError as e:
+    assert str(e) == 'index out of range', 'empty heap message'
+
+# === interleaved pushes and pops ===
+h = []
+out = []
+for x in [10, 4, 6, 4, 1, 12, 0, 5]:
+    heappush(h, x)
+    if x % 3 == 0:
+        out.append(heappop(h))
+    assert is_heap(h), 'heap order with interleaved operations'
+assert out == [4, 1, 0], 'interleaved pops'
+assert h == [4, 5, 6, 12, 10], 'remaining heap layout'
+assert [heappop(h) for _ in range(len(h))] == [4, 5, 6, 10, 12], 'drain in order'
+
+# === duplicates, negatives and floats ===
+h = []
+for x in [2, -1, 2.5, -1, 0, 2]:
+    heappush(h, x)
+assert [heappop(h) for _ in range(6)] == [-1, -1, 0, 2, 2, 2.5], 'duplicates and mixed numbers'
+
+# === strings ===
+h = []
+for word in ['pear', 'apple', 'fig', 'banana']:
+    heappush(h, word)
+assert heappop(h) == 'apple', 'smallest string'
+assert heappop(h) == 'banana', 'next smallest string'
+
+# === heapify ===
+data = [9, 7, 5, 3, 1, 8, 6, 4, 2, 0]
+assert heapify(data) is None, 'heapify returns None'
+assert is_heap(data), 'heapify gives heap order'
+assert data == [0, 1, 5, 2, 7, 8, 6, 4, 3, 9], 'heapify layout matches CPython'
+assert [heappop(data) for _ in range(10)] == list(range(10)), 'heapify then drain'
+
+empty = []
+heapify(empty)
+assert empty == [], 'heapify an empty list'
+
+single = [3]
+heapify(single)
+assert single == [3], 'heapify a single item'
+
+# === nsmallest and nlargest ===
+nums = [5, 1, 8, 3, 9, 2, 8]
+assert heapq.nsmallest(3, nums) == [1, 2, 3], 'nsmallest'
+assert heapq.nlargest(3, nums) == [9, 8, 8], 'nlargest'
+assert heapq.nsmallest(0, nums) == [], 'nsmallest zero'
+assert heapq.nlargest(-2, nums) == [], 'nlargest negative'
+assert heapq.nsmallest(10, nums) == [1, 2, 3, 5, 8, 8, 9], 'nsmallest more than available'
+assert heapq.nlargest(2, (4, 6, 5)) == [6, 5], 'nlargest of a tuple'
+assert heapq.nsmallest(2, range(10, 0, -1)) == [1, 2], 'nsmallest of a range'
+assert heapq.nsmallest(2, []) == [], 'nsmallest of an empty list'
+assert heapq.nlargest(2, 'hello') == ['o', 'l'], 'nlargest of a str'
+assert heapq.nsmallest(2, [3, 1, 2], key=None) == [1, 2], 'nsmallest with key=None'
+assert nums == [5, 1, 8, 3, 9, 2, 8], 'nsmallest does not modify its argument'
+
+# === errors ===
+try:
+    heappush(1, 2)  # pyright: ignore
+    assert False, 'heappush on a non-list should raise'
+except TypeError as e:
+    assert str(e) == 'heappush() argument 1 must be list, not int', 'heappush type message'
+
+try:
+    heappop((1,))  # pyright: ignore
+    assert False, 'heappop on a non-list should raise'
+except TypeError as e:
+    assert str(e) == 'heappop() argument must be list, not tuple', 'heappop type message'
+
+try:
+    heapify('abc')  # pyright: ignore
+    assert False, 'heapify on a non-list should raise'
+except TypeError as e:
+    assert str(e) == 'heapify() argument must be list, not str', 'heapify type message'
+
+h = [1]
+try:
+    heappush(h, 'a')  # pyright: ignore
+    assert False, 'pushing an unorderable item should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'str' and 'int'", 'unorderable message'
+assert h == [1, 'a'], 'the item stays in the list after a failed comparison'
@@ -95,3 +95,27 @@ assert (a is [1, 2]) == False, 'array is new literal'
 x = None
 assert (x is None) == True, 'var is None'
 assert (1 is None) == False, 'int is not None'
+
+# === ids are never negative ===
+assert id(None) >= 0 and id(True) >= 0 and id(False) >= 0, 'singleton ids are non-negative'
+assert id(...) >= 0, 'Ellipsis id is non-negative'
+assert id([]) >= 0 and id('abc') >= 0 and id(3.5) >= 0, 'other ids are non-negative'
+assert type(id(None)) == int, 'a large id is still an int'
+
+# === ids are stable across bindings ===
+none_id = id(None)
+x = None
+assert id(x) == none_id, 'None bound to a variable keeps its id'
+flag = True
+assert id(flag) == id(True) == id(1 == 1), 'True keeps its id'
+d = {'k': [1]}
+inner = d['k']
+assert id(inner) == id(d['k']), 'an object in a container keeps its id'
+
+
+def identity(v):
+    return v
+
+
+assert id(identity(d)) == id(d), 'passing an object through a function keeps its id'
+assert len({id(None), id(None), id(x)}) == 1, 'ids of the same object hash equal'
@@ -53,3 +53,56 @@ assert f'{"yes" if 1 else "no"}' == 'yes', 'fstring with string result'
 # === F-string with format spec ===
 x = 42
 assert f'{x if True else 0:05d}' == '00042', 'fstring format spec with if/else'
+
+# === Even/odd classification ===
+labels = [('even' if n % 2 == 0 else 'odd') for n in range(4)]
+assert labels == ['even', 'odd', 'even', 'odd'], 'even/odd ternary in comprehension'
+
+
+# === Only the selected branch is evaluated ===
+calls = []
+
+
+def record(name):
+    calls.append(name)
+    return name
+
+
+value = record('body') if True else record('orelse')
+assert value == 'body', 'true branch value'
+assert calls == ['body'], 'false branch not evaluated'
+
+calls = []
+value = record('body') if False else record('orelse')
+assert value == 'orelse', 'false branch value'
+assert calls == ['orelse'], 'true branch not evaluated'
+
+calls = []
+value = record('body') if record('test') else record('orelse')
+assert calls == ['test', 'body'], 'condition evaluated before the selected branch'
+
+# Branch that would raise is never evaluated
+d = {}
+assert (d['missing'] if 'missing' in d else 'default') == 'default', 'untaken branch does not raise'
+
+
+# === Nested ternaries (elif chains) ===
+def sign(n):
+    return 'negative' if n < 0 else 'zero' if n == 0 else 'positive'
+
+
+assert sign(-5) == 'negative', 'chained ternary first'
+assert sign(0) == 'zero', 'chained ternary middle'
+assert sign(7) == 'positive', 'chained ternary last'
+
+
+def grade(score):
+    return 'A' if score >= 90 else ('B' if score >= 80 else ('C' if score >= 70 else 'F'))
+
+
+assert [grade(s) for s in [95, 85, 75, 10]] == ['A', 'B', 'C', 'F'], 'deeply nested ternary'
+
+# === Ternary as a function argument and in a lambda ===
+assert len('abc' if True else 'de') == 3, 'ternary as call argument'
+pick = lambda flag: 'on' if flag else 'off'
+assert (pick(1), pick(0)) == ('on', 'off'), 'ternary in lambda'
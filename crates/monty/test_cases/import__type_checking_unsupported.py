@@ -0,0 +1,35 @@
+# Code under `if TYPE_CHECKING:` is never run, so it may contain anything,
+# including statements Monty doesn't support
+import typing
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    class Hidden:
+        pass
+
+    match Hidden:
+        case _:
+            pass
+
+guarded = 'unset'
+if typing.TYPE_CHECKING:
+    import nonexistent_module
+else:
+    guarded = 'else branch'
+assert guarded == 'else branch', 'else branch of TYPE_CHECKING runs'
+
+value = 0
+if TYPE_CHECKING:
+    value = 1
+elif guarded:
+    value = 2
+assert value == 2, 'elif after TYPE_CHECKING runs'
+
+
+def count(items: 'Sequence[int]') -> int:
+    return len(items)
+
+
+assert count([1, 2, 3]) == 3, 'annotation names from TYPE_CHECKING imports'
@@ -0,0 +1,115 @@
+from collections import namedtuple
+
+# === Bools as subscripts ===
+assert [10, 20][True] == 20, 'list[True]'
+assert [10, 20][False] == 10, 'list[False]'
+assert (10, 20)[True] == 20, 'tuple[True]'
+assert 'ab'[True] == 'b', 'str[True]'
+assert 'ab'[False] == 'a', 'str[False]'
+assert b'ab'[True] == 98, 'bytes[True]'
+assert range(5, 10)[True] == 6, 'range[True]'
+assert bytearray(b'ab')[True] == 98, 'bytearray[True]'
+assert [1, 2, 3][-True] == 3, 'negated bool is a negative index'
+
+Point = namedtuple('Point', ['x', 'y'])
+assert Point(1, 2)[True] == 2, 'namedtuple[True]'
+
+s = 'hello'
+assert s[True:3] == 'el', 'bool slice bounds'
+
+# === Bools as assignment indices ===
+items = [1, 2, 3]
+items[True] = 20
+assert items == [1, 20, 3], 'list setitem with True'
+items[False] = 10
+assert items == [10, 20, 3], 'list setitem with False'
+
+# === Bools as repetition counts ===
+assert [0] * True == [0], 'list * True'
+assert [0] * False == [], 'list * False'
+assert True * [0] == [0], 'True * list'
+assert 'ab' * False == '', 'str * False'
+assert True * 'ab' == 'ab', 'True * str'
+assert (1, 2) * True == (1, 2), 'tuple * True'
+assert b'x' * True == b'x', 'bytes * True'
+
+# === Bools as counts and positions in builtins ===
+assert list(range(True)) == [0], 'range(True)'
+assert list(range(False, 3, True)) == [0, 1, 2], 'range with bool start and step'
+assert list(enumerate('ab', start=True)) == [(1, 'a'), (2, 'b')], 'enumerate start=True'
+assert list(enumerate('ab', True)) == [(1, 'a'), (2, 'b')], 'enumerate positional True start'
+
+items = [1, 2, 3]
+assert items.pop(True) == 2, 'list.pop(True)'
+items.insert(True, 9)
+assert items == [1, 9, 3], 'list.insert(True, ...)'
+assert (1, 2, 1).index(1, True) == 2, 'tuple.index with bool start'
+
+# === Floats are rejected ===
+f = 1.0
+try:
+    [1, 2][f]  # pyright: ignore
+    assert False, 'list[float] should raise'
+except TypeError as e:
+    assert str(e) == 'list indices must be integers or slices, not float', 'list[float] message'
+
+try:
+    (1, 2)[f]  # pyright: ignore
+    assert False, 'tuple[float] should raise'
+except TypeError as e:
+    assert str(e) == 'tuple indices must be integers or slices, not float', 'tuple[float] message'
+
+try:
+    Point(1, 2)[f]  # pyright: ignore
+    assert False, 'namedtuple[float] should raise'
+except TypeError as e:
+    assert str(e) == 'tuple indices must be integers or slices, not float', 'namedtuple[float] message'
+
+try:
+    'ab'[f]  # pyright: ignore
+    assert False, 'str[float] should raise'
+except TypeError as e:
+    assert str(e) == "string indices must be integers, not 'float'", 'str[float] message'
+
+try:
+    b'ab'[f]  # pyright: ignore
+    assert False, 'bytes[float] should raise'
+except TypeError as e:
+    assert str(e) == 'byte indices must be integers or slices, not float', 'bytes[float] message'
+
+try:
+    range(3)[f]  # pyright: ignore
+    assert False, 'range[float] should raise'
+except TypeError as e:
+    assert str(e) == 'range indices must be integers or slices, not float', 'range[float] message'
+
+try:
+    bytearray(b'ab')[f]  # pyright: ignore
+    assert False, 'bytearray[float] should raise'
+except TypeError as e:
+    assert str(e) == 'bytearray indices must be integers or slices, not float', 'bytearray[float] message'
+
+try:
+    items = [1, 2]
+    items[f] = 3  # pyright: ignore
+    assert False, 'list setitem with float should raise'
+except TypeError as e:
+    assert str(e) == 'list indices must be integers or slices, not float', 'list setitem float message'
+
+try:
+    [0] * f  # pyright: ignore
+    assert False, 'list * float should raise'
+except TypeError:
+    pass
+
+try:
+    range(f)  # pyright: ignore
+    assert False, 'range(float) should raise'
+except TypeError as e:
+    assert str(e) == "'float' object cannot be interpreted as an integer", 'range(float) message'
+
+try:
+    enumerate('ab', start=f)  # pyright: ignore
+    assert False, 'enumerate float start should raise'
+except TypeError as e:
+    assert str(e) == "'float' object cannot be interpreted as an integer", 'enumerate float start message'
@@ -332,6 +332,16 @@ expected_repr = str(MAX_I64 + 1)
 assert repr_result == expected_repr, 'repr of bigint'
 assert str_result == expected_repr, 'str of bigint'
 
+# formatting the same value again gives the same text, and derived values get their own
+huge = 7**200
+first = str(huge)
+assert str(huge) == first, 'str of bigint is stable'
+assert f'{huge}' == first, 'f-string matches str'
+assert repr(huge) == first, 'repr matches str'
+assert str(huge + 1) == first[:-1] + '2', 'str of a derived bigint'
+assert str(-huge) == '-' + first, 'str of a negated bigint'
+assert str(huge) == first, 'original unchanged after deriving'
+
 # === Bool conversion ===
 assert bool(bigger), 'bigint is truthy'
 assert bool(-bigger), 'negative bigint is truthy'
@@ -0,0 +1,64 @@
+# Bitwise operators on LongInt and mixed int/LongInt operands, including negative values
+
+# === Values that straddle the i64 boundary ===
+assert ~(2**70) == -1180591620717411303425, 'invert LongInt'
+assert ~(-(2**70)) == 1180591620717411303423, 'invert negative LongInt'
+assert ~(2**63) == -9223372036854775809, 'invert 2**63 demotes'
+assert ~(-(2**63)) == 9223372036854775807, 'invert i64 min'
+assert (2**70) & 0xFF == 0, 'LongInt & small mask'
+assert (2**70 + 0xAB) & 0xFF == 171, 'LongInt low bits & mask'
+assert -(2**70) & 0xFF == 0, 'negative LongInt & mask'
+assert (-(2**70) - 1) & 0xFFFF == 65535, 'negative LongInt low bits & mask'
+assert -1 & (2**70) == 1180591620717411303424, '-1 & LongInt'
+assert -(2**70) & -(2**65) == -1180591620717411303424, 'negative LongInt & negative LongInt'
+assert (2**70 - 1) & -(2**65) == 1143698132569992200192, 'LongInt & negative LongInt'
+assert -(2**70) | 0xFF == -1180591620717411303169, 'negative LongInt | mask'
+assert -(2**70) | -(2**65) == -36893488147419103232, 'negative LongInt | negative LongInt'
+assert (2**70) | (2**65) == 1217485108864830406656, 'LongInt | LongInt'
+assert -1 | (2**70) == -1, '-1 | LongInt'
+assert -(2**70) ^ 0xFF == -1180591620717411303169, 'negative LongInt ^ mask'
+assert -(2**70) ^ -(2**65) == 1143698132569992200192, 'negative LongInt ^ negative LongInt'
+assert (2**70) ^ -1 == -1180591620717411303425, 'LongInt ^ -1 is invert'
+assert -(2**64) ^ (2**64) == -36893488147419103232, 'negative LongInt ^ LongInt'
+assert (2**70 + 5) & 7 == 5, 'LongInt & small int demotes'
+assert ((2**70) | 1) ^ (2**70) == 1, 'xor back to small int'
+assert -5 & 3 == 3, 'negative int & int'
+assert -5 | 3 == -5, 'negative int | int'
+assert -5 ^ 3 == -8, 'negative int ^ int'
+assert ~-9223372036854775808 == 9223372036854775807, 'invert i64 min int'
+assert True & 3 == 1, 'bool & int'
+assert True | (2**70) == 1180591620717411303425, 'bool | LongInt'
+
+# === Two's complement identities ===
+big = 2**100 + 12345
+for x in [big, -big, 2**64, -(2**64), 2**63 - 1, -(2**63), 7, -7, 0]:
+    assert ~x == -x - 1, 'invert is -x - 1'
+    assert x & ~x == 0, 'x & ~x is zero'
+    assert x | ~x == -1, 'x | ~x is -1'
+    assert x ^ ~x == -1, 'x ^ ~x is -1'
+    assert x ^ x == 0, 'x ^ x is zero'
+    assert (x & big) | (x & ~big) == x, 'masking splits x'
+    assert x & -1 == x, 'x & -1 is x'
+
+# === Results are ints and in-place ops work ===
+assert type((2**70) & 0xFF) == int, 'masked LongInt is int'
+x = 2**70
+x |= 1
+assert x == 2**70 + 1, '|= LongInt'
+x &= 0xF
+assert x == 1, '&= demotes'
+x ^= -(2**70)
+assert x == -(2**70) + 1, '^= negative LongInt'
+
+# === Type errors ===
+try:
+    (2**70) & 1.0
+    assert False, 'LongInt & float should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for &: 'int' and 'float'", 'LongInt & float message'
+
+try:
+    ~1.5
+    assert False, 'invert float should raise'
+except TypeError as e:
+    assert str(e) == "bad operand type for unary ~: 'float'", 'invert float message'
@@ -0,0 +1,91 @@
+# Tests for promotion to LongInt when results straddle the i64 boundary
+MAX_I64 = 9223372036854775807
+MIN_I64 = -MAX_I64 - 1
+
+# === Examples that must not wrap ===
+assert 2**100 == 1267650600228229401496703205376, '2**100'
+assert 10**20 * 10**20 == 10**40, '10**20 * 10**20'
+assert (2**62) + (2**62) == 9223372036854775808, '2**62 + 2**62'
+assert (2**62) + (2**62) - 1 == MAX_I64, 'back down to i64::MAX'
+
+# === Addition ===
+assert MAX_I64 + 1 == 9223372036854775808, 'max + 1'
+assert MAX_I64 + MAX_I64 == 18446744073709551614, 'max + max'
+assert MIN_I64 + -1 == -9223372036854775809, 'min + -1'
+assert MIN_I64 + MIN_I64 == -18446744073709551616, 'min + min'
+assert MAX_I64 + MIN_I64 == -1, 'max + min stays small'
+assert (MAX_I64 + 1) + -1 == MAX_I64, 'demotes after addition'
+
+# === Subtraction ===
+assert MIN_I64 - 1 == -9223372036854775809, 'min - 1'
+assert MAX_I64 - -1 == 9223372036854775808, 'max - -1'
+assert 0 - MIN_I64 == 9223372036854775808, '0 - min'
+assert MAX_I64 - MIN_I64 == 18446744073709551615, 'max - min'
+assert (MIN_I64 - 1) - -1 == MIN_I64, 'demotes after subtraction'
+
+# === Multiplication ===
+assert MAX_I64 * 2 == 18446744073709551614, 'max * 2'
+assert MIN_I64 * -1 == 9223372036854775808, 'min * -1'
+assert -1 * MIN_I64 == 9223372036854775808, '-1 * min'
+assert MIN_I64 * MIN_I64 == 85070591730234615865843651857942052864, 'min * min'
+assert 3037000500 * 3037000500 == 9223372037000250000, 'square just over max'
+assert 3037000499 * 3037000499 == 9223372030926249001, 'square just under max'
+
+# === Power ===
+assert 2**63 == 9223372036854775808, '2**63'
+assert 2**63 - 1 == MAX_I64, '2**63 - 1'
+assert (-2) ** 63 == MIN_I64, '(-2)**63 fits'
+assert (-2) ** 64 == 18446744073709551616, '(-2)**64'
+assert 3**40 == 12157665459056928801, '3**40'
+assert pow(10, 19) == 10000000000000000000, 'pow builtin'
+
+# === Floor division and modulo ===
+assert MIN_I64 // -1 == 9223372036854775808, 'min // -1'
+assert MIN_I64 % -1 == 0, 'min % -1'
+assert divmod(MIN_I64, -1) == (9223372036854775808, 0), 'divmod min by -1'
+assert MIN_I64 // 1 == MIN_I64, 'min // 1'
+
+# === Unary operators and abs ===
+assert -MIN_I64 == 9223372036854775808, 'negate min'
+assert abs(MIN_I64) == 9223372036854775808, 'abs min'
+assert -(-MIN_I64) == MIN_I64, 'negate back'
+
+# === In-place operators ===
+x = MAX_I64
+x += 1
+assert x == 9223372036854775808, '+= promotes'
+x -= 1
+assert x == MAX_I64, '-= demotes'
+y = MIN_I64
+y -= 1
+assert y == -9223372036854775809, '-= promotes'
+z = MAX_I64
+z *= MAX_I64
+assert z == 85070591730234615847396907784232501249, '*= promotes'
+w = 2
+w **= 64
+assert w == 18446744073709551616, '**= promotes'
+
+# === Bools are ints in arithmetic ===
+assert True + 1 == 2, 'bool + int'
+assert 1 + True == 2, 'int + bool'
+assert True + True == 2, 'bool + bool'
+assert True - 1 == 0, 'bool - int'
+assert 1 - True == 0, 'int - bool'
+assert False - True == -1, 'bool - bool'
+assert True + 1.5 == 2.5, 'bool + float'
+assert 1.5 - True == 0.5, 'float - bool'
+assert type(True + True) == int, 'bool + bool is int'
+assert MAX_I64 + True == 9223372036854775808, 'max + True promotes'
+assert MIN_I64 - True == -9223372036854775809, 'min - True promotes'
+assert 2**64 + True == 18446744073709551617, 'long int + bool'
+assert sum([True, True, False]) == 2, 'sum of bools'
+assert sum([MAX_I64, True]) == 9223372036854775808, 'sum promotes'
+n = MAX_I64
+n += True
+assert n == 9223372036854775808, '+= bool promotes'
+
+# === Results are still ints ===
+assert type(MAX_I64 + 1) == int, 'promoted type is int'
+assert type(MIN_I64 // -1) == int, 'promoted floordiv type is int'
+assert str(MAX_I64 * 10) == '92233720368547758070', 'promoted str'
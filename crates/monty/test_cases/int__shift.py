@@ -0,0 +1,86 @@
+MAX_I64 = 9223372036854775807
+MIN_I64 = -MAX_I64 - 1
+
+# === Left shift promotes to LongInt ===
+assert 1 << 100 == 1267650600228229401496703205376, '1 << 100'
+assert 1 << 62 == 4611686018427387904, '1 << 62 fits'
+assert 1 << 63 == 9223372036854775808, '1 << 63 promotes'
+assert 1 << 64 == 18446744073709551616, '1 << 64 promotes'
+assert -1 << 63 == MIN_I64, '-1 << 63 fits'
+assert -1 << 64 == -18446744073709551616, '-1 << 64 promotes'
+assert 3 << 62 == 13835058055282163712, 'lost bits promote'
+assert MAX_I64 << 1 == 18446744073709551614, 'max << 1'
+assert MIN_I64 << 1 == -18446744073709551616, 'min << 1'
+assert 5 << 0 == 5, 'shift by zero'
+assert 0 << 1000 == 0, 'zero shifted'
+assert 0 << 2**100 == 0, 'zero shifted by huge count'
+assert (1 << 100) >> 100 == 1, 'round trip through LongInt'
+assert type(1 << 100) == int, 'promoted shift is int'
+
+# === Right shift by more than the bit width ===
+assert 5 >> 1 == 2, 'simple right shift'
+assert -5 >> 1 == -3, 'right shift rounds down'
+assert 1 >> 63 == 0, '1 >> 63'
+assert 1 >> 64 == 0, '1 >> 64'
+assert 12345 >> 65 == 0, 'positive >> 65'
+assert -1 >> 65 == -1, 'negative >> 65'
+assert MIN_I64 >> 63 == -1, 'min >> 63'
+assert MIN_I64 >> 64 == -1, 'min >> 64'
+assert MAX_I64 >> 62 == 1, 'max >> 62'
+assert 7 >> 2**100 == 0, 'right shift by huge count'
+assert -7 >> 2**100 == -1, 'negative right shift by huge count'
+assert (2**100) >> 99 == 2, 'LongInt right shift'
+assert (-(2**100)) >> 200 == -1, 'negative LongInt right shift'
+assert (2**70) >> 10 == 2**60, 'LongInt right shift demotes'
+
+# === Bools and in-place shifts ===
+assert True << 3 == 8, 'bool left shift'
+assert 8 >> True == 4, 'bool shift count'
+x = 1
+x <<= 70
+assert x == 2**70, '<<= promotes'
+x >>= 70
+assert x == 1, '>>= demotes'
+
+# === Negative shift counts raise ===
+try:
+    1 << -1
+    assert False, 'negative left shift should raise'
+except ValueError as e:
+    assert str(e) == 'negative shift count', 'left shift message'
+
+try:
+    1 >> -1
+    assert False, 'negative right shift should raise'
+except ValueError as e:
+    assert str(e) == 'negative shift count', 'right shift message'
+
+try:
+    0 << -1
+    assert False, 'negative shift of zero should raise'
+except ValueError as e:
+    assert str(e) == 'negative shift count', 'zero shift message'
+
+try:
+    1 >> -(2**100)
+    assert False, 'huge negative shift should raise'
+except ValueError as e:
+    assert str(e) == 'negative shift count', 'huge negative shift message'
+
+try:
+    (2**100) << -3
+    assert False, 'negative shift of LongInt should raise'
+except ValueError as e:
+    assert str(e) == 'negative shift count', 'LongInt shift message'
+
+try:
+    1 << 2**100
+    assert False, 'huge left shift should raise'
+except OverflowError:
+    pass
+
+try:
+    1 << 1.5
+    assert False, 'float shift count should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for <<: 'int' and 'float'", 'float shift message'
@@ -0,0 +1,60 @@
+# === Singletons ===
+x = None
+assert x is None, 'None is None'
+assert not (x is not None), 'None is not None is False'
+assert (None is None) is True, 'is returns a bool'
+
+
+def returns_none():
+    pass
+
+
+assert returns_none() is None, 'implicit return value is None'
+assert {}.get('missing') is None, 'dict.get default is None'
+
+assert True is True, 'True is True'
+assert False is False, 'False is False'
+assert (1 == 1) is True, 'comparison result is the True singleton'
+assert (1 == 2) is False, 'comparison result is the False singleton'
+assert bool([]) is False, 'bool() returns the False singleton'
+assert True is not False, 'True is not False'
+assert 1 is not True, 'int 1 is not True'
+assert 0 is not False, 'int 0 is not False'
+assert None is not False, 'None is not False'
+
+# === Heap objects use object identity ===
+assert [] is not [], 'distinct empty lists'
+assert {} is not {}, 'distinct empty dicts'
+a = []
+assert a is a, 'a list is itself'
+b = a
+assert b is a, 'aliases are identical'
+c = list(a)
+assert c is not a, 'copies are not identical'
+assert c == a, 'copies are equal'
+
+d = {'k': a}
+assert d['k'] is a, 'values stored in a dict keep their identity'
+items = [a, a]
+assert items[0] is items[1], 'same object stored twice'
+
+
+def identity(value):
+    return value
+
+
+assert identity(a) is a, 'passing through a function keeps identity'
+
+# === Small integers ===
+assert int('5') is 5, 'small ints are cached'
+n = 256
+assert n is 256, 'small int variable is the literal'
+
+# === not in / is not ===
+assert 4 not in [1, 2, 3], 'not in list'
+assert not (2 not in [1, 2, 3]), 'not in is False for a member'
+assert 'z' not in 'abc', 'not in str'
+assert 'k' not in {'a': 1}, 'not in dict checks keys'
+assert (1 not in [1]) is False, 'not in returns a bool'
+assert a not in [[1], [2]], 'not in compares by equality'
+assert [] in [a], 'in uses equality, not identity'
@@ -0,0 +1,72 @@
+# === Aggregates accept iterator objects ===
+assert sum(iter([1, 2, 3])) == 6, 'sum of an iterator'
+assert sum(map(abs, [-1, -2, 3]), 10) == 16, 'sum of map with a start'
+assert max(filter(None, [0, 5, 0, 7, 2])) == 7, 'max of filter'
+assert min(map(len, ['abc', 'de', 'fghi'])) == 2, 'min of map'
+assert max(iter(['pear', 'fig']), key=len) == 'pear', 'max of an iterator with a key'
+assert min(iter([]), default='empty') == 'empty', 'min of an empty iterator with a default'
+assert any(map(lambda x: x > 2, [1, 2, 3])), 'any of map'
+assert not all(map(lambda x: x > 2, [1, 2, 3])), 'all of map'
+assert sum(x * x for x in range(100)) == 328350, 'sum of a generator expression'
+assert max(x % 7 for x in range(100)) == 6, 'max of a generator expression'
+assert sum(iter(range(1000001))) == 500000500000, 'sum of an iterator over a large range'
+
+# === Consuming an iterator shares its position ===
+it = iter([1, 2, 3, 4, 5])
+assert next(it) == 1, 'first item taken by next'
+assert sum(it) == 14, 'sum consumes the remaining items'
+assert sum(it) == 0, 'an exhausted iterator sums to zero'
+assert list(it) == [], 'nothing left to collect'
+
+it = iter([1, 2, 0, 3, 4])
+assert not all(it), 'all stops at the first false item'
+assert next(it) == 3, 'all leaves the rest of the iterator'
+it = iter([0, 0, 5, 6])
+assert any(it), 'any stops at the first true item'
+assert list(it) == [6], 'any leaves the rest of the iterator'
+
+it = iter('abc')
+for c in it:
+    if c == 'a':
+        break
+assert list(it) == ['b', 'c'], 'a for loop advances the same iterator'
+
+it = iter(range(6))
+pairs = []
+for x in it:
+    pairs.append((x, next(it)))
+assert pairs == [(0, 1), (2, 3), (4, 5)], 'next inside a loop over the same iterator'
+
+# === Constructors and unpacking accept iterator objects ===
+assert list(map(str, [1, 2])) == ['1', '2'], 'list of map'
+assert tuple(iter((1, 2))) == (1, 2), 'tuple of an iterator'
+assert sorted(iter([3, 1, 2])) == [1, 2, 3], 'sorted of an iterator'
+assert ''.join(map(str, range(4))) == '0123', 'join of map'
+assert set(filter(None, [0, 1, 1, 2])) == {1, 2}, 'set of filter'
+a, b = map(abs, [-1, -2])
+assert (a, b) == (1, 2), 'unpacking map'
+first, *rest = iter('xyz')
+assert (first, rest) == ('x', ['y', 'z']), 'starred unpacking of an iterator'
+assert [x * 2 for x in iter([1, 2])] == [2, 4], 'comprehension over an iterator'
+
+# === Errors ===
+try:
+    sum(iter(['a', 'b']))
+    assert False, 'sum of strs should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for +: 'int' and 'str'", 'sum of strs message'
+
+try:
+    max(iter([]))
+    assert False, 'max of an empty iterator should raise'
+except ValueError:
+    pass
+
+d = {'a': 1}
+it = iter(d)
+try:
+    for k in it:
+        d['b'] = 2
+    assert False, 'dict size change should raise'
+except RuntimeError as e:
+    assert str(e) == 'dictionary changed size during iteration', 'dict size change message'
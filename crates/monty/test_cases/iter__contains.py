@@ -0,0 +1,107 @@
+# `in` searches containers directly and falls back to iterating other iterables
+
+# === Iterators ===
+assert 3 in iter([1, 2, 3]), 'item in an iterator'
+assert 4 not in iter([1, 2, 3]), 'missing item in an iterator'
+assert 'b' in iter('abc'), 'char in a string iterator'
+assert (1, 2) in iter([(0, 0), (1, 2)]), 'tuple in an iterator'
+assert 2.0 in iter([1, 2, 3]), 'float equal to an int item'
+assert 1 not in iter([]), 'empty iterator'
+
+assert 10 in map(lambda x: x * 5, [1, 2, 3]), 'item in map()'
+assert 11 not in map(lambda x: x * 5, [1, 2, 3]), 'missing item in map()'
+assert 4 in filter(lambda x: x % 2 == 0, range(10)), 'item in filter()'
+assert 5 not in filter(lambda x: x % 2 == 0, range(10)), 'filtered-out item'
+assert 6 in (x * 2 for x in range(5)), 'item in a generator expression'
+assert 7 not in (x * 2 for x in range(5)), 'missing item in a generator expression'
+
+# the search consumes the iterator up to and including the match
+it = iter([1, 2, 3, 4, 5])
+assert 2 in it, 'first search'
+assert list(it) == [3, 4, 5], 'items after the match remain'
+
+it = iter([1, 2, 3])
+assert 9 not in it, 'a failed search'
+assert list(it) == [], 'a failed search exhausts the iterator'
+
+it = iter([1, 2, 3])
+assert 2 in it, 'found once'
+assert 2 not in it, 'a consumed item is not found again'
+
+log = []
+
+
+def record(x):
+    log.append(x)
+    return x
+
+
+assert 2 in map(record, [1, 2, 3, 4]), 'map() search'
+assert log == [1, 2], 'the search stops at the first match'
+
+# === Ranges use arithmetic ===
+assert 5 in range(10), 'int in range'
+assert 10 not in range(10), 'stop is excluded'
+assert 4 in range(0, 10, 2) and 5 not in range(0, 10, 2), 'step alignment'
+assert -3 in range(0, -10, -3), 'negative step'
+assert True in range(2), 'bool in range'
+assert 3.0 in range(5) and 3.5 not in range(5), 'float in range'
+assert 'a' not in range(5), 'str in range'
+assert 10**12 in range(0, 10**13, 10**6), 'large range'
+
+# === Bytes ===
+assert 98 in b'abc', 'int in bytes'
+assert 100 not in b'abc', 'missing int in bytes'
+assert b'bc' in b'abc', 'subsequence in bytes'
+assert b'ac' not in b'abc', 'non-contiguous subsequence'
+assert b'' in b'abc', 'empty bytes is always contained'
+assert bytearray(b'b') in b'abc', 'bytearray in bytes'
+assert b'b' in bytearray(b'abc'), 'bytes in bytearray'
+assert 99 in bytearray(b'abc'), 'int in bytearray'
+heap_bytes = bytes([1, 2, 3])
+assert 2 in heap_bytes and b'\x02\x03' in heap_bytes, 'heap bytes'
+assert heap_bytes in heap_bytes, 'bytes contain themselves'
+
+try:
+    'a' in b'abc'
+    assert False, 'str in bytes should raise'
+except TypeError as e:
+    assert str(e) == "a bytes-like object is required, not 'str'", 'str in bytes message'
+
+try:
+    256 in b'abc'
+    assert False, 'int out of byte range should raise'
+except ValueError as e:
+    assert str(e) == 'byte must be in range(0, 256)', 'byte range message'
+
+# === A container tested against itself ===
+items = [1, 2]
+assert items not in items, 'a list does not contain itself'
+items.append(items)
+assert items in items, 'a list that holds itself'
+pair = (1, 2)
+assert pair not in pair, 'a tuple does not contain itself'
+runtime = ''.join(['a', 'b'])
+assert runtime in runtime, 'a string contains itself'
+it = iter([1, 2])
+assert it not in it, 'an iterator does not contain itself'
+
+try:
+    d = {}
+    d in d
+    assert False, 'dict in itself should raise'
+except TypeError:
+    pass
+
+# === Non-iterables ===
+try:
+    1 in 5
+    assert False, 'in an int should raise'
+except TypeError:
+    pass
+
+try:
+    1 in None
+    assert False, 'in None should raise'
+except TypeError:
+    pass
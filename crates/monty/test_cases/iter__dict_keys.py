@@ -0,0 +1,84 @@
+# === Iterating a dict yields its keys in insertion order ===
+d = {'b': 2, 'a': 1, 'c': 3}
+keys = []
+for k in d:
+    keys.append(k)
+assert keys == ['b', 'a', 'c'], 'keys in insertion order'
+assert list(d) == ['b', 'a', 'c'], 'list of a dict'
+assert [k for k in d] == ['b', 'a', 'c'], 'comprehension over a dict'
+assert tuple(d) == ('b', 'a', 'c'), 'tuple of a dict'
+
+d['z'] = 26
+d['a'] = 100
+assert list(d) == ['b', 'a', 'c', 'z'], 'updating a key keeps its position'
+d.pop('b')
+d['b'] = 0
+assert list(d) == ['a', 'c', 'z', 'b'], 'a re-added key goes last'
+
+mixed = {1: 'int', (2, 3): 'tuple', None: 'none'}
+assert list(mixed) == [1, (2, 3), None], 'keys of any hashable type'
+
+total = 0
+for k in {}:
+    total += 1
+assert total == 0, 'empty dict has no keys'
+
+# === Items, values and keys ===
+d = {'x': 1, 'y': 2, 'z': 3}
+pairs = []
+for k, v in d.items():
+    pairs.append(f'{k}={v}')
+assert pairs == ['x=1', 'y=2', 'z=3'], 'items unpacking in order'
+assert [v for v in d.values()] == [1, 2, 3], 'values in order'
+assert [k for k in d.keys()] == ['x', 'y', 'z'], 'keys() in order'
+assert [d[k] for k in d] == [1, 2, 3], 'lookup by iterated key'
+
+# === Assigning to existing keys during iteration is allowed ===
+d = {'a': 1, 'b': 2, 'c': 3}
+for k in d:
+    d[k] = d[k] * 10
+assert d == {'a': 10, 'b': 20, 'c': 30}, 'values updated during iteration'
+
+# the size is only checked when the next key is fetched
+d = {'a': 1, 'b': 2}
+for k in d:
+    d['c'] = 3
+    break
+assert list(d) == ['a', 'b', 'c'], 'mutation right before break'
+
+# === Changing the size during iteration raises RuntimeError ===
+d = {'a': 1, 'b': 2, 'c': 3}
+seen = []
+try:
+    for k in d:
+        seen.append(k)
+        d['new'] = 0
+    assert False, 'adding a key during iteration should raise'
+except RuntimeError as e:
+    assert str(e) == 'dictionary changed size during iteration', 'adding a key message'
+assert seen == ['a'], 'iteration stops at the next key'
+assert len(d) == 4, 'the added key is kept'
+
+d = {'a': 1, 'b': 2, 'c': 3}
+try:
+    for k in d:
+        d.pop(k)
+    assert False, 'removing a key during iteration should raise'
+except RuntimeError as e:
+    assert str(e) == 'dictionary changed size during iteration', 'removing a key message'
+assert d == {'b': 2, 'c': 3}, 'only the first key was removed'
+
+d = {'a': 1, 'b': 2}
+try:
+    for k in d:
+        d.clear()
+    assert False, 'clearing during iteration should raise'
+except RuntimeError as e:
+    assert str(e) == 'dictionary changed size during iteration', 'clearing message'
+
+d = {'a': 1, 'b': 2}
+try:
+    result = [d.setdefault(k + k, 0) for k in d]
+    assert False, 'mutating in a comprehension should raise'
+except RuntimeError as e:
+    assert str(e) == 'dictionary changed size during iteration', 'comprehension message'
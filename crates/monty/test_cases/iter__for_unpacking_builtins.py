@@ -0,0 +1,132 @@
+# === enumerate with unpacked targets ===
+names = ['ann', 'bob', 'cy']
+pairs = []
+for i, name in enumerate(names):
+    pairs.append((i, name))
+assert pairs == [(0, 'ann'), (1, 'bob'), (2, 'cy')], 'enumerate unpacking'
+
+numbered = []
+for n, name in enumerate(names, 1):
+    numbered.append(f'{n}. {name}')
+assert numbered == ['1. ann', '2. bob', '3. cy'], 'enumerate with a start'
+
+chars = []
+for i, c in enumerate('hé'):
+    chars.append((i, c))
+assert chars == [(0, 'h'), (1, 'é')], 'enumerate a string'
+
+# === zip with unpacked targets ===
+xs = [1, 2, 3]
+ys = ['a', 'b', 'c', 'd']
+zipped = []
+for x, y in zip(xs, ys):
+    zipped.append(y * x)
+assert zipped == ['a', 'bb', 'ccc'], 'zip unpacking stops at the shortest'
+
+totals = []
+for a, b, c in zip(xs, [10, 20, 30], [100, 200, 300]):
+    totals.append(a + b + c)
+assert totals == [111, 222, 333], 'zip of three'
+
+# === reversed with unpacked targets ===
+backwards = []
+for k, v in reversed([('a', 1), ('b', 2)]):
+    backwards.append(k * v)
+assert backwards == ['bb', 'a'], 'reversed unpacking'
+
+countdown = []
+for i, x in enumerate(reversed(xs)):
+    countdown.append((i, x))
+assert countdown == [(0, 3), (1, 2), (2, 1)], 'enumerate of reversed'
+
+# === Nested targets ===
+nested = []
+for i, (x, y) in enumerate(zip(xs, ys)):
+    nested.append((i, x, y))
+assert nested == [(0, 1, 'a'), (1, 2, 'b'), (2, 3, 'c')], 'enumerate of zip'
+
+nested = []
+for (a, (b, c)) in enumerate(zip(xs, ys)):
+    nested.append(f'{a}{b}{c}')
+assert nested == ['01a', '12b', '23c'], 'parenthesized nested target'
+
+nested = []
+for (i, x), y in zip(enumerate(xs), ys):
+    nested.append(i * x)
+assert nested == [0, 2, 6], 'enumerate inside zip'
+
+deep = []
+for a, (b, (c, d)) in [(1, (2, (3, 4)))]:
+    deep.append(a + b + c + d)
+assert deep == [10], 'three levels deep'
+
+# === Starred targets ===
+heads = []
+tails = []
+for head, *tail in zip(xs, ys, xs):
+    heads.append(head)
+    tails.append(tail)
+assert heads == [1, 2, 3], 'starred target head'
+assert tails == [['a', 1], ['b', 2], ['c', 3]], 'starred target rest is a list'
+
+for i, (first, *rest) in enumerate([[1, 2, 3]]):
+    assert (i, first, rest) == (0, 1, [2, 3]), 'nested starred target'
+
+# === Unpacking other iterables ===
+for a, b in [range(2)]:
+    assert (a, b) == (0, 1), 'unpack a range'
+for a, b in [{'k': 1, 'v': 2}]:
+    assert (a, b) == ('k', 'v'), 'unpack dict keys'
+for (a,) in [{7}]:
+    assert a == 7, 'unpack a set'
+for a, b in [b'hi']:
+    assert (a, b) == (104, 105), 'unpack bytes'
+first, *middle, last = range(5)
+assert (first, middle, last) == (0, [1, 2, 3], 4), 'starred unpack of a range'
+k1, k2 = {'x': 1, 'y': 2}
+assert (k1, k2) == ('x', 'y'), 'assignment unpack of a dict'
+
+# === Wrong arity ===
+try:
+    for a, b in enumerate([(1, 2)]):
+        a, b, c = b  # pyright: ignore
+    assert False, 'wrong arity should raise'
+except ValueError as e:
+    assert str(e) == 'not enough values to unpack (expected 3, got 2)', 'not enough values message'
+
+try:
+    for a, b in zip(xs, ys, xs):  # pyright: ignore
+        pass
+    assert False, 'too many values should raise'
+except ValueError as e:
+    assert str(e).startswith('too many values to unpack (expected 2'), 'too many values message'
+
+seen = []
+try:
+    for a, b in [(1, 2), (3,), (4, 5)]:  # pyright: ignore
+        seen.append(a)
+    assert False, 'short tuple should raise'
+except ValueError as e:
+    assert str(e) == 'not enough values to unpack (expected 2, got 1)', 'short tuple message'
+assert seen == [1], 'the loop stops at the bad item'
+
+try:
+    for (a, (b, c)) in enumerate([(1, 2, 3)]):  # pyright: ignore
+        pass
+    assert False, 'nested wrong arity should raise'
+except ValueError as e:
+    assert str(e).startswith('too many values to unpack (expected 2'), 'nested wrong arity message'
+
+try:
+    for a, *b, c in [range(1)]:
+        pass
+    assert False, 'starred target with too few values should raise'
+except ValueError as e:
+    assert str(e) == 'not enough values to unpack (expected at least 2, got 1)', 'starred too few message'
+
+try:
+    for a, b in enumerate([1]):
+        x, y = b  # pyright: ignore
+    assert False, 'unpacking an int should raise'
+except TypeError as e:
+    assert str(e) == 'cannot unpack non-iterable int object', 'non-iterable message'
@@ -18,3 +18,29 @@ assert result == 10, 'generator in sum()'
 pairs = [(1, 2), (3, 4)]
 result = list(a + b for a, b in pairs)
 assert result == [3, 7], 'generator with unpacking'
+
+# === Generator expressions passed directly to builtins ===
+assert sum(x * 2 for x in range(5)) == 20, 'sum of a generator expression'
+assert sum((x for x in [1.5, 2.5]), 1) == 5.0, 'sum with a start value'
+assert max(len(w) for w in ['a', 'abc', 'ab']) == 3, 'max of a generator expression'
+assert min(x % 5 for x in range(6, 10)) == 1, 'min of a generator expression'
+assert max((x for x in []), default=-1) == -1, 'max of an empty generator expression with a default'
+assert any(x > 3 for x in range(5)), 'any of a generator expression'
+assert all(x < 5 for x in range(5)), 'all of a generator expression'
+assert sorted(-x for x in range(3)) == [-2, -1, 0], 'sorted of a generator expression'
+assert tuple(c.upper() for c in 'ab') == ('A', 'B'), 'tuple of a generator expression'
+assert ', '.join(str(n) for n in range(3)) == '0, 1, 2', 'join of a generator expression'
+
+# === Scoping ===
+scale = 3
+assert list(x * scale for x in range(3)) == [0, 3, 6], 'reads enclosing variables'
+x = 'outer'
+assert sum(x for x in range(4)) == 6, 'loop variable is local'
+assert x == 'outer', 'loop variable does not leak'
+
+
+def total_of_squares(n):
+    return sum(i * i for i in range(n))
+
+
+assert total_of_squares(4) == 14, 'generator expression inside a function'
@@ -0,0 +1,155 @@
+import heapq
+import statistics
+
+log: list[int] = []
+
+
+def seen(n: int) -> int:
+    log.append(n)
+    return n
+
+
+# === Items are produced only when they're consumed ===
+gen = (seen(x) for x in range(5))
+assert log == [], 'creating a generator expression runs nothing'
+assert next(gen) == 0, 'first item'
+assert log == [0], 'next() produces one item'
+assert next(gen) == 1, 'second item'
+assert log == [0, 1], 'next() produces the following item'
+
+# === any() and all() stop early ===
+log.clear()
+assert any(seen(x) > 1 for x in range(10)), 'any() finds a true item'
+assert log == [0, 1, 2], 'any() stops at the first true item'
+
+log.clear()
+assert not all(seen(x) < 2 for x in range(10)), 'all() finds a false item'
+assert log == [0, 1, 2], 'all() stops at the first false item'
+
+gen = (x for x in range(10))
+assert any(x == 3 for x in gen), 'any() over a generator'
+assert list(gen) == [4, 5, 6, 7, 8, 9], 'the items after the match are left'
+
+# === Huge ranges are never materialized ===
+assert any(x == 5 for x in range(10**18)), 'any() over a huge range'
+assert next(x for x in range(10**18) if x > 2) == 3, 'next() over a huge range'
+assert 7 in (x for x in range(10**18)), 'in over a huge range'
+
+# === for loops ===
+log.clear()
+gen = (seen(x) for x in range(6))
+for x in gen:
+    if x == 2:
+        break
+assert log == [0, 1, 2], 'break leaves the rest unproduced'
+rest: list[int] = []
+for x in gen:
+    rest.append(x)
+assert rest == [3, 4, 5], 'a second loop resumes where the first stopped'
+
+total = 0
+for x in (y * y for y in range(4)):
+    for z in (x + w for w in range(2)):
+        total += z
+assert total == 32, 'nested loops over generator expressions'
+
+# === Nesting and closures ===
+assert sum(sum(y for y in range(x)) for x in range(5)) == 10, 'generator expression inside a generator expression'
+assert list(x + y for x in range(3) if x for y in range(x)) == [1, 2, 3], 'several for clauses'
+
+
+def pairs_matching(xs: list[int], ys: list[int]) -> list[bool]:
+    return [any(x == y for y in ys) for x in xs]
+
+
+assert pairs_matching([1, 2, 3], [2, 3]) == [False, True, True], 'reads a comprehension variable'
+
+
+def scaled_total(factor: int) -> int:
+    def times(v: int) -> int:
+        return v * factor
+
+    gen = (x * factor for x in range(3))
+    return sum(gen) + sum(times(x) for x in range(3))
+
+
+assert scaled_total(2) == 12, 'reads and calls enclosing function variables'
+
+offset = 1
+gen = (x + offset for x in range(3))
+offset = 10
+assert list(gen) == [10, 11, 12], 'enclosing variables are read as items are produced'
+
+# === Errors in the body reach the consumer ===
+gen = (10 // x for x in [2, 1, 0, 5])
+got: list[int] = []
+try:
+    for x in gen:
+        got.append(x)
+    assert False, 'dividing by zero should raise'
+except ZeroDivisionError:
+    pass
+assert got == [5, 10], 'items before the error were produced'
+assert list(gen) == [], 'a generator that raised is exhausted'
+
+try:
+    sum(10 // x for x in [1, 0])
+    assert False, 'sum() should raise'
+except ZeroDivisionError:
+    pass
+
+try:
+    (x for x in 5)  # pyright: ignore
+    assert False, 'the first iterable is checked when the generator expression is created'
+except TypeError as e:
+    assert str(e) == "'int' object is not iterable", 'not iterable message'
+
+# === Unpacking and membership ===
+a, b = (x * 3 for x in range(2))
+assert (a, b) == (0, 3), 'unpacking a generator expression'
+first, *others = (x for x in 'abc')
+assert first == 'a' and others == ['b', 'c'], 'starred unpacking of a generator expression'
+
+try:
+    a, b = (x for x in range(3))
+    assert False, 'unpacking too many items should raise'
+except ValueError as e:
+    assert str(e) == 'too many values to unpack (expected 2)', 'too many values message'
+
+gen = (x for x in range(10))
+assert 3 in gen, 'in advances to the item'
+assert next(gen) == 4, 'in stops after the item'
+assert 3 not in gen, 'items already produced are not seen again'
+
+# === Generator expressions passed to other builtins and methods ===
+assert list(enumerate(c for c in 'ab')) == [(0, 'a'), (1, 'b')], 'enumerate()'
+assert list(zip((x for x in range(3)), 'ab')) == [(0, 'a'), (1, 'b')], 'zip()'
+assert list(reversed(list(x for x in range(3)))) == [2, 1, 0], 'list() then reversed()'
+assert list(map(str, (x for x in range(3)))) == ['0', '1', '2'], 'map()'
+assert list(filter(None, (x % 2 for x in range(4)))) == [1, 1], 'filter()'
+assert dict((x, x * x) for x in range(3)) == {0: 0, 1: 1, 2: 4}, 'dict()'
+assert set(x % 2 for x in range(5)) == {0, 1}, 'set()'
+assert frozenset(x for x in 'aab') == frozenset('ab'), 'frozenset()'
+assert bytes(x for x in range(65, 68)) == b'ABC', 'bytes()'
+assert '-'.join(c for c in 'abc') == 'a-b-c', 'str.join()'
+
+items = [1]
+items.extend(x for x in range(2, 4))
+assert items == [1, 2, 3], 'list.extend()'
+s = {1}
+s.update(x for x in range(3))
+assert s == {0, 1, 2}, 'set.update()'
+assert {1, 2}.issubset(x for x in range(5)), 'set.issubset()'
+
+assert heapq.nlargest(2, (x * 7 % 5 for x in range(5))) == [4, 3], 'heapq.nlargest()'
+assert statistics.mean(x for x in range(5)) == 2, 'statistics.mean()'
+
+# === A running generator can't be advanced again ===
+gen2 = (next(gen2) for _ in range(1))  # pyright: ignore
+try:
+    next(gen2)
+    assert False, 'advancing a running generator should raise'
+except ValueError as e:
+    assert str(e) == 'generator already executing', 'already executing message'
+
+assert type(x for x in range(0)).__name__ == 'generator', 'type name'
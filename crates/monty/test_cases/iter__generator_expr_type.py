@@ -1,7 +1,20 @@
-# xfail=cpython
-# TODO: When proper generators are implemented, this test should be removed.
-# Currently generator expressions return lists in Monty, not generator objects.
-# This test verifies the temporary behavior until generators are properly implemented.
-gen_result = (x * 2 for x in range(5))
-assert type(gen_result) == list, 'generator expr currently returns list'
-assert gen_result == [0, 2, 4, 6, 8], 'generator expr value'
+# === A generator expression is an iterator, not a list ===
+gen = (x * 2 for x in range(5))
+assert type(gen) != list, 'not a list'
+assert iter(gen) is gen, 'iter() of a generator expression returns it'
+assert next(gen) == 0, 'next() takes the first item'
+assert next(gen) == 2, 'next() takes the second item'
+assert list(gen) == [4, 6, 8], 'list() takes the remaining items'
+assert list(gen) == [], 'an exhausted generator expression yields nothing'
+assert next(gen, 'done') == 'done', 'next() default when exhausted'
+
+try:
+    next(gen)
+    assert False, 'next() on an exhausted generator expression should raise'
+except StopIteration:
+    pass
+
+# === First match idiom ===
+words = ['apple', 'banana', 'cherry']
+assert next(w for w in words if w.startswith('b')) == 'banana', 'first matching item'
+assert next((w for w in words if w.startswith('z')), None) is None, 'default when nothing matches'
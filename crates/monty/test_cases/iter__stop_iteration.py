@@ -0,0 +1,91 @@
+# === Driving an iterator manually with next() ===
+it = iter([10, 20, 30])
+seen = []
+while True:
+    try:
+        item = next(it)
+    except StopIteration:
+        break
+    seen.append(item)
+assert seen == [10, 20, 30], 'while loop with next() and StopIteration'
+
+# the same items come out of a for loop, which stops without raising
+seen = []
+for item in iter([10, 20, 30]):
+    seen.append(item)
+assert seen == [10, 20, 30], 'for loop stops on its own'
+
+chars = iter('héllo')
+seen = []
+while True:
+    try:
+        seen.append(next(chars))
+    except StopIteration:
+        break
+assert seen == ['h', 'é', 'l', 'l', 'o'], 'manual iteration over a string'
+
+numbers = iter(range(3))
+seen = []
+while True:
+    try:
+        seen.append(next(numbers))
+    except StopIteration as e:
+        assert e.args == (), 'StopIteration from next() has no args'
+        break
+assert seen == [0, 1, 2], 'manual iteration over a range'
+
+# === Mixing next() and for loops ===
+it = iter([1, 2, 3, 4])
+assert next(it) == 1, 'next before the loop'
+rest = []
+for x in it:
+    rest.append(x)
+assert rest == [2, 3, 4], 'for loop continues where next stopped'
+assert next(it, 'done') == 'done', 'the for loop exhausted the iterator'
+
+# === Exhausted iterators stay exhausted ===
+items = [1]
+it = iter(items)
+assert next(it) == 1, 'the only item'
+assert next(it, None) is None, 'exhausted'
+items.append(2)
+assert next(it, None) is None, 'still exhausted after the list grows'
+for x in it:
+    assert False, 'a for loop over an exhausted iterator does nothing'
+
+items = [1]
+it = iter(items)
+items.append(2)
+assert list(it) == [1, 2], 'items added before exhaustion are yielded'
+
+# === Errors ===
+def first(iterable):
+    return next(iter(iterable))
+
+
+try:
+    first([])
+    assert False, 'next() on an empty iterator should raise'
+except StopIteration:
+    pass
+
+try:
+    next([1, 2])  # pyright: ignore
+    assert False, 'next() on a list should raise'
+except TypeError as e:
+    assert str(e) == "'list' object is not an iterator", 'next() on a list message'
+
+try:
+    next(42)  # pyright: ignore
+    assert False, 'next() on an int should raise'
+except TypeError as e:
+    assert str(e) == "'int' object is not an iterator", 'next() on an int message'
+
+d = {'a': 1}
+it = iter(d)
+d['b'] = 2
+try:
+    next(it, 'default')
+    assert False, 'dict size change should raise even with a default'
+except RuntimeError as e:
+    assert str(e) == 'dictionary changed size during iteration', 'dict size change message'
@@ -168,6 +168,38 @@ assert copy == [1, 2, 3], 'copy creates equal list'
 assert copy is not lst, 'copy creates new list object'
 lst.append(4)
 assert copy == [1, 2, 3], 'copy is independent'
+copy.append(5)
+copy[0] = 'changed'
+copy.reverse()
+assert lst == [1, 2, 3, 4], 'mutating the copy leaves the original unchanged'
+assert [].copy() == [], 'copy of an empty list'
+
+# the copy is shallow, nested objects are shared
+inner = [1]
+lst = [inner, {'k': 'v'}]
+copy = lst.copy()
+assert copy[0] is inner and copy[1] is lst[1], 'copy shares nested objects'
+copy[0].append(2)
+assert lst[0] == [1, 2], 'nested mutation is visible through the original'
+copy[0] = [9]
+assert lst[0] is inner, 'replacing an item in the copy does not affect the original'
+
+# a full slice copies the same way
+lst = [1, [2], 3]
+sliced = lst[:]
+assert sliced == lst and sliced is not lst, 'lst[:] creates an equal new list'
+assert sliced[1] is lst[1], 'lst[:] shares nested objects'
+sliced.append(4)
+sliced[0] = 0
+assert lst == [1, [2], 3], 'mutating lst[:] leaves the original unchanged'
+assert lst[::1] == lst and lst[::1] is not lst, 'step 1 slice copies'
+
+# snapshot before mutating in a loop
+lst = [1, 2, 3, 4]
+for item in lst.copy():
+    if item % 2 == 0:
+        lst.remove(item)
+assert lst == [1, 3], 'iterating a copy while removing from the original'
 
 # === list.extend() ===
 lst = [1, 2]
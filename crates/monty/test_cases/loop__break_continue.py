@@ -111,3 +111,71 @@ for x in [1, 2, 3]:
     continue
     result.append('after')  # unreachable
 assert result == [1, 2, 3], 'continue on every iteration'
+
+# === Break from for nested in while ===
+result = []
+n = 0
+while n < 3:
+    for x in [1, 2, 3]:
+        if x == 2:
+            break
+        result.append((n, x))
+    n += 1
+assert result == [(0, 1), (1, 1), (2, 1)], 'break in for only exits the for loop'
+
+# === Continue in while nested in for ===
+result = []
+for i in [1, 2]:
+    j = 0
+    while j < 3:
+        j += 1
+        if j == 2:
+            continue
+        result.append((i, j))
+assert result == [(1, 1), (1, 3), (2, 1), (2, 3)], 'continue in while only affects the while loop'
+
+
+# === Break leaves the stack balanced ===
+def first_even(items):
+    found = None
+    for x in items:
+        if x % 2 == 0:
+            found = x
+            break
+    return found
+
+
+assert [first_even([1, 3, 4, 6]) for _ in range(3)] == [4, 4, 4], 'repeated break inside a function'
+assert (1, first_even([2]), 3) == (1, 2, 3), 'break does not disturb values below the iterator'
+
+# === Search loop patterns ===
+def find_prime_factor(n):
+    for d in range(2, n):
+        if n % d == 0:
+            result = d
+            break
+    else:
+        result = None
+    return result
+
+
+assert find_prime_factor(15) == 3, 'search loop finds factor'
+assert find_prime_factor(13) is None, 'search loop falls through to else'
+
+primes = []
+for n in range(2, 20):
+    for d in range(2, n):
+        if n % d == 0:
+            break
+    else:
+        primes.append(n)
+assert primes == [2, 3, 5, 7, 11, 13, 17, 19], 'nested search loop with inner else'
+
+i = 0
+while i < 5:
+    if i == 10:
+        break
+    i += 1
+else:
+    i = 'exhausted'
+assert i == 'exhausted', 'while search loop runs else when condition fails'
@@ -53,3 +53,24 @@ def test_empty_stack():
 
 
 assert test_empty_stack() == ['breaking', 'after'], 'should execute code after break'
+
+
+# Test 4: break from a loop inside an except handler keeps the handler's exception
+def test_loop_in_handler():
+    try:
+        raise ValueError('handled')
+    except ValueError:
+        for i in range(3):
+            try:
+                raise TypeError('inner')
+            except TypeError:
+                break
+        for i in range(3):
+            continue
+        try:
+            raise
+        except ValueError as e:
+            return str(e)
+
+
+assert test_loop_in_handler() == 'handled', 'the handler exception is still active after the loops'
@@ -0,0 +1,83 @@
+import math
+
+# === math.comb ===
+assert math.comb(52, 5) == 2598960, 'poker hands'
+assert math.comb(5, 0) == 1, 'choose none'
+assert math.comb(5, 5) == 1, 'choose all'
+assert math.comb(5, 2) == math.comb(5, 3), 'symmetric'
+assert math.comb(0, 0) == 1, 'comb(0, 0)'
+assert math.comb(3, 5) == 0, 'k greater than n'
+assert math.comb(10, True) == 10, 'bool k'
+assert [math.comb(4, k) for k in range(5)] == [1, 4, 6, 4, 1], 'row of pascal triangle'
+
+# results past i64 promote to LongInt
+assert math.comb(100, 50) == 100891344545564193334812497256, 'comb(100, 50)'
+assert math.comb(68, 34) == 28453041475240576740, 'just past i64'
+assert math.comb(2**70, 1) == 2**70, 'long int n'
+assert math.comb(2**70, 2**70 - 1) == 2**70, 'long int n and k'
+assert math.comb(2**70, 2) == 2**70 * (2**70 - 1) // 2, 'long int n choose 2'
+
+# === math.perm ===
+assert math.perm(5, 2) == 20, 'perm(5, 2)'
+assert math.perm(5) == 120, 'perm(n) is n!'
+assert math.perm(5, None) == 120, 'perm(n, None) is n!'
+assert math.perm(5, 0) == 1, 'perm(5, 0)'
+assert math.perm(0) == 1, 'perm(0)'
+assert math.perm(3, 5) == 0, 'k greater than n'
+assert math.perm(25) == 15511210043330985984000000, '25! promotes to LongInt'
+assert math.perm(2**70, 2) == 2**70 * (2**70 - 1), 'long int n'
+
+# === errors ===
+try:
+    math.comb(-1, 2)
+    assert False, 'negative n should raise'
+except ValueError as e:
+    assert str(e) == 'n must be a non-negative integer', 'comb negative n message'
+
+try:
+    math.comb(5, -1)
+    assert False, 'negative k should raise'
+except ValueError as e:
+    assert str(e) == 'k must be a non-negative integer', 'comb negative k message'
+
+try:
+    math.perm(-3)
+    assert False, 'negative n should raise'
+except ValueError as e:
+    assert str(e) == 'factorial() not defined for negative values', 'perm(n) negative message'
+
+try:
+    math.perm(-3, 1)
+    assert False, 'negative n with k should raise'
+except ValueError as e:
+    assert str(e) == 'n must be a non-negative integer', 'perm negative n message'
+
+try:
+    math.perm(3, -1)
+    assert False, 'negative k should raise'
+except ValueError as e:
+    assert str(e) == 'k must be a non-negative integer', 'perm negative k message'
+
+try:
+    math.comb(5.0, 2)  # pyright: ignore
+    assert False, 'float n should raise'
+except TypeError as e:
+    assert str(e) == "'float' object cannot be interpreted as an integer", 'comb float message'
+
+try:
+    math.perm(5, '2')  # pyright: ignore
+    assert False, 'str k should raise'
+except TypeError as e:
+    assert str(e) == "'str' object cannot be interpreted as an integer", 'perm str message'
+
+try:
+    math.comb(5)  # pyright: ignore
+    assert False, 'comb with one argument should raise'
+except TypeError as e:
+    assert str(e) == 'comb expected 2 arguments, got 1', 'comb arg count message'
+
+try:
+    math.perm()  # pyright: ignore
+    assert False, 'perm with no arguments should raise'
+except TypeError as e:
+    assert str(e) == 'perm expected at least 1 argument, got 0', 'perm arg count message'
@@ -0,0 +1,123 @@
+import math
+
+inf = float('inf')
+nan = float('nan')
+
+# === isclose default tolerances ===
+assert math.isclose(1.0, 1.0), 'equal values are close'
+assert math.isclose(1.0, 1.0 + 1e-10), 'within default rel_tol'
+assert not math.isclose(1.0, 1.0 + 1e-8), 'outside default rel_tol'
+assert math.isclose(0.1 + 0.2, 0.3), 'float rounding error is close'
+assert not math.isclose(0.0, 1e-10), 'nothing is close to zero by default'
+assert math.isclose(0.0, 0.0), 'zero is close to itself'
+assert math.isclose(0.0, -0.0), 'signed zeros are close'
+assert math.isclose(3, 3), 'ints are accepted'
+assert math.isclose(True, 1.0), 'bools are accepted'
+assert math.isclose(10**20, 1e20), 'big ints are accepted'
+assert type(math.isclose(1, 2)) == bool, 'isclose returns a bool'
+
+# === isclose tolerance edges ===
+assert math.isclose(100.0, 110.0, rel_tol=0.1), 'rel_tol measured against the larger value'
+assert not math.isclose(100.0, 112.0, rel_tol=0.1), 'outside rel_tol of both values'
+assert math.isclose(1.0, 2.0, rel_tol=0.5), 'difference equal to rel_tol * b'
+assert math.isclose(0.0, 1e-10, abs_tol=1e-9), 'abs_tol near zero'
+assert math.isclose(1.0, 1.5, abs_tol=0.5), 'difference equal to abs_tol'
+assert not math.isclose(1.0, 1.5, abs_tol=0.4), 'outside abs_tol'
+assert math.isclose(1.0, 1.1, rel_tol=0.0, abs_tol=0.2), 'abs_tol alone'
+assert not math.isclose(1.0, 1.0 + 1e-15, rel_tol=0.0), 'zero tolerances mean exact equality'
+assert math.isclose(5, 5, rel_tol=0, abs_tol=0), 'zero tolerances still allow equality'
+assert math.isclose(-1.0, -1.05, rel_tol=0.05), 'negative values'
+assert math.isclose(a=1.0, b=1.0), 'a and b by keyword'
+
+# === isclose with inf and nan ===
+assert math.isclose(inf, inf), 'inf is close to itself'
+assert math.isclose(-inf, -inf), '-inf is close to itself'
+assert not math.isclose(inf, -inf), 'inf is not close to -inf'
+assert not math.isclose(inf, 1e308), 'inf is not close to a large float'
+assert not math.isclose(1.0, inf, rel_tol=1.0), 'inf is not close to anything with large rel_tol'
+assert not math.isclose(inf, 0.0, abs_tol=inf), 'inf is not close to anything with infinite abs_tol'
+assert math.isclose(1.0, 1e300, abs_tol=inf), 'infinite abs_tol makes finite values close'
+assert not math.isclose(nan, nan), 'nan is not close to itself'
+assert not math.isclose(nan, 1.0, abs_tol=inf), 'nan is not close to anything'
+assert not math.isclose(inf, nan), 'nan is not close to inf'
+
+# === isclose errors ===
+try:
+    math.isclose(1.0, 1.0, rel_tol=-1e-9)
+    assert False, 'negative rel_tol should raise'
+except ValueError as e:
+    assert str(e) == 'tolerances must be non-negative', 'negative rel_tol message'
+
+try:
+    math.isclose(1.0, 1.0, abs_tol=-1)
+    assert False, 'negative abs_tol should raise'
+except ValueError as e:
+    assert str(e) == 'tolerances must be non-negative', 'negative abs_tol message'
+
+try:
+    math.isclose('a', 1.0)  # pyright: ignore
+    assert False, 'isclose(str) should raise'
+except TypeError as e:
+    assert str(e) == 'must be real number, not str', 'isclose(str) message'
+
+try:
+    math.isclose(1.0)  # pyright: ignore
+    assert False, 'isclose with one argument should raise'
+except TypeError:
+    pass
+
+try:
+    math.isclose(10**400, 1.0)
+    assert False, 'isclose with a huge int should raise'
+except OverflowError as e:
+    assert str(e) == 'int too large to convert to float', 'isclose(huge) message'
+
+# === isnan ===
+assert math.isnan(nan), 'nan is nan'
+assert not math.isnan(inf), 'inf is not nan'
+assert not math.isnan(1.5), 'finite float is not nan'
+assert not math.isnan(3), 'int is not nan'
+assert not math.isnan(False), 'bool is not nan'
+assert type(math.isnan(1.0)) == bool, 'isnan returns a bool'
+
+# === isinf ===
+assert math.isinf(inf), 'inf is inf'
+assert math.isinf(-inf), '-inf is inf'
+assert not math.isinf(nan), 'nan is not inf'
+assert not math.isinf(1e308), 'large finite float is not inf'
+assert math.isinf(1e308 * 10), 'overflowed float is inf'
+assert not math.isinf(10**300), 'large int is not inf'
+
+# === isfinite ===
+assert math.isfinite(0.0), 'zero is finite'
+assert math.isfinite(-1e308), 'large negative float is finite'
+assert not math.isfinite(inf), 'inf is not finite'
+assert not math.isfinite(-inf), '-inf is not finite'
+assert not math.isfinite(nan), 'nan is not finite'
+assert math.isfinite(42), 'int is finite'
+assert math.isfinite(True), 'bool is finite'
+
+# === Predicate errors ===
+try:
+    math.isnan('x')  # pyright: ignore
+    assert False, 'isnan(str) should raise'
+except TypeError as e:
+    assert str(e) == 'must be real number, not str', 'isnan(str) message'
+
+try:
+    math.isinf(None)  # pyright: ignore
+    assert False, 'isinf(None) should raise'
+except TypeError as e:
+    assert str(e) == 'must be real number, not NoneType', 'isinf(None) message'
+
+try:
+    math.isfinite(10**400)
+    assert False, 'isfinite with a huge int should raise'
+except OverflowError as e:
+    assert str(e) == 'int too large to convert to float', 'isfinite(huge) message'
+
+try:
+    math.isnan()  # pyright: ignore
+    assert False, 'isnan() should raise'
+except TypeError as e:
+    assert str(e) == 'math.isnan() takes exactly one argument (0 given)', 'isnan() message'
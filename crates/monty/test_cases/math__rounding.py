@@ -0,0 +1,128 @@
+import math
+from math import floor
+
+# === math.floor / math.ceil / math.trunc on ints and bools ===
+assert math.floor(3) == 3, 'floor int'
+assert math.ceil(-3) == -3, 'ceil negative int'
+assert math.trunc(0) == 0, 'trunc zero'
+assert math.floor(True) == 1, 'floor bool'
+assert type(math.floor(True)) == int, 'floor bool gives int'
+assert floor(5) == 5, 'from math import floor'
+
+# === on floats ===
+assert math.floor(2.7) == 2, 'floor positive float'
+assert math.floor(-2.7) == -3, 'floor negative float'
+assert math.ceil(2.1) == 3, 'ceil positive float'
+assert math.ceil(-2.1) == -2, 'ceil negative float'
+assert math.trunc(2.7) == 2, 'trunc positive float'
+assert math.trunc(-2.7) == -2, 'trunc negative float'
+assert math.floor(-0.5) == -1, 'floor -0.5'
+assert math.ceil(-0.5) == 0, 'ceil -0.5'
+assert type(math.floor(2.5)) == int, 'floor float gives int'
+assert type(math.ceil(2.5)) == int, 'ceil float gives int'
+assert type(math.trunc(2.5)) == int, 'trunc float gives int'
+
+# large floats promote instead of saturating
+assert math.floor(1e20) == 100000000000000000000, 'floor large float'
+assert math.ceil(-1e20) == -100000000000000000000, 'ceil large negative float'
+assert math.trunc(2.0**70) == 2**70, 'trunc 2**70 float'
+
+# === on long ints ===
+big = 2**100
+assert math.floor(big) == big, 'floor long int'
+assert math.ceil(-big) == -big, 'ceil negative long int'
+assert math.trunc(big + 1) == big + 1, 'trunc long int'
+
+# === int() truncates like math.trunc ===
+assert int(2.9) == 2, 'int positive float'
+assert int(-2.9) == -2, 'int negative float'
+assert int(1e20) == 100000000000000000000, 'int large float'
+assert int(-1e20) == math.trunc(-1e20), 'int agrees with trunc'
+assert int(True) == 1, 'int bool'
+
+# === round() to an integer uses banker's rounding ===
+assert round(0.5) == 0, 'round 0.5'
+assert round(1.5) == 2, 'round 1.5'
+assert round(2.5) == 2, 'round 2.5'
+assert round(-0.5) == 0, 'round -0.5'
+assert round(-1.5) == -2, 'round -1.5'
+assert round(2.6) == 3, 'round 2.6'
+assert type(round(2.6)) == int, 'round float gives int'
+assert round(1e20) == 100000000000000000000, 'round large float'
+assert round(7) == 7, 'round int'
+assert round(big) == big, 'round long int'
+
+# === round() with ndigits on floats uses the exact value ===
+assert round(2.675, 2) == 2.67, 'round 2.675 is below the midpoint'
+assert round(0.125, 2) == 0.12, 'round exact tie to even'
+assert round(0.375, 2) == 0.38, 'round exact tie to even up'
+assert round(1.23456, 3) == 1.235, 'round to 3 digits'
+assert round(-1.23456, 3) == -1.235, 'round negative to 3 digits'
+assert round(2.5, 0) == 2.0, 'round ndigits 0'
+assert type(round(2.5, 0)) == float, 'round float with ndigits gives float'
+assert round(1234.5, -1) == 1230.0, 'round float negative ndigits'
+assert round(1250.0, -2) == 1200.0, 'round float tie negative ndigits'
+assert round(0.1, 400) == 0.1, 'round huge ndigits unchanged'
+assert round(123.0, -400) == 0.0, 'round huge negative ndigits'
+assert str(round(-0.001, 1)) == '-0.0', 'round keeps sign of zero'
+assert str(round(-123.0, -400)) == '-0.0', 'round huge negative ndigits keeps sign'
+
+# === round() with negative ndigits on ints is exact ===
+assert round(123456789012345678, -2) == 123456789012345700, 'round large int'
+assert round(150, -2) == 200, 'round int tie to even up'
+assert round(250, -2) == 200, 'round int tie to even down'
+assert round(-250, -2) == -200, 'round negative int tie'
+assert round(-251, -2) == -300, 'round negative int'
+assert round(5, -1) == 0, 'round 5 to tens'
+assert round(15, -1) == 20, 'round 15 to tens'
+assert round(123, -10) == 0, 'round int beyond its digits'
+assert round(123, 2) == 123, 'round int positive ndigits'
+assert round(2**70 + 50, -2) == 1180591620717411303500, 'round long int to hundreds'
+assert round(2**70, -100) == 0, 'round long int beyond its digits'
+assert round(9223372036854775807, -1) == 9223372036854775810, 'round int overflowing i64'
+assert type(round(150, -2)) == int, 'round int with ndigits gives int'
+
+# === errors ===
+try:
+    math.floor('a')
+    assert False, 'floor of str should raise'
+except TypeError as e:
+    assert str(e) == 'must be real number, not str', 'floor str message'
+
+try:
+    math.ceil(None)
+    assert False, 'ceil of None should raise'
+except TypeError as e:
+    assert str(e) == 'must be real number, not NoneType', 'ceil None message'
+
+try:
+    math.trunc('a')
+    assert False, 'trunc of str should raise'
+except TypeError as e:
+    assert str(e) == "type str doesn't define __trunc__ method", 'trunc str message'
+
+try:
+    int(float('nan'))
+    assert False, 'int of nan should raise'
+except ValueError as e:
+    assert str(e) == 'cannot convert float NaN to integer', 'int nan message'
+
+try:
+    int(float('inf'))
+    assert False, 'int of inf should raise'
+except OverflowError as e:
+    assert str(e) == 'cannot convert float infinity to integer', 'int inf message'
+
+try:
+    math.floor(float('-inf'))
+    assert False, 'floor of -inf should raise'
+except OverflowError as e:
+    assert str(e) == 'cannot convert float infinity to integer', 'floor inf message'
+
+try:
+    round(float('nan'))
+    assert False, 'round of nan should raise'
+except ValueError as e:
+    assert str(e) == 'cannot convert float NaN to integer', 'round nan message'
+
+assert str(round(float('inf'), 2)) == 'inf', 'round inf with ndigits'
@@ -0,0 +1,104 @@
+import math
+
+pi = 3.141592653589793
+inf = float('inf')
+nan = float('nan')
+
+# === degrees and radians ===
+assert math.degrees(pi) == 180.0, 'pi radians is 180 degrees'
+assert math.degrees(pi / 2) == 90.0, 'half pi is 90 degrees'
+assert math.degrees(0) == 0.0, 'zero degrees'
+assert math.radians(180) == pi, '180 degrees is pi radians'
+assert math.radians(-90) == -pi / 2, 'negative degrees'
+assert math.radians(True) == pi / 180, 'bool is accepted'
+assert type(math.degrees(1)) == float, 'degrees returns a float'
+assert math.isclose(math.degrees(math.radians(37.5)), 37.5), 'degrees undoes radians'
+assert math.isinf(math.degrees(inf)), 'degrees of inf'
+
+# === atan, asin and acos ===
+assert math.atan(0) == 0.0, 'atan(0)'
+assert math.atan(1) == pi / 4, 'atan(1) is a quarter pi'
+assert math.atan(inf) == pi / 2, 'atan(inf) is half pi'
+assert math.atan(-inf) == -pi / 2, 'atan(-inf) is minus half pi'
+assert math.asin(0) == 0.0, 'asin(0)'
+assert math.asin(1) == pi / 2, 'asin(1) is half pi'
+assert math.asin(-1.0) == -pi / 2, 'asin(-1) is minus half pi'
+assert math.isclose(math.asin(0.5), pi / 6), 'asin(0.5) is a sixth of pi'
+assert math.acos(1) == 0.0, 'acos(1)'
+assert math.acos(-1) == pi, 'acos(-1) is pi'
+assert math.acos(0) == pi / 2, 'acos(0) is half pi'
+assert math.isnan(math.asin(nan)), 'asin passes nan through'
+assert math.isnan(math.acos(nan)), 'acos passes nan through'
+
+# === atan2 ===
+assert math.atan2(1, 1) == pi / 4, 'atan2(1, 1) is a quarter pi'
+assert math.atan2(1, -1) == 3 * pi / 4, 'second quadrant'
+assert math.atan2(-1, -1) == -3 * pi / 4, 'third quadrant'
+assert math.atan2(-1, 1) == -pi / 4, 'fourth quadrant'
+assert math.atan2(0, -1) == pi, 'negative x axis'
+assert math.atan2(1, 0) == pi / 2, 'positive y axis'
+assert math.atan2(0, 0) == 0.0, 'origin'
+assert math.atan2(inf, inf) == pi / 4, 'both infinite'
+assert math.atan2(3, 2.5) == math.atan(3 / 2.5), 'matches atan of the ratio'
+
+# === hypot ===
+assert math.hypot(3, 4) == 5.0, 'hypot(3, 4) is 5'
+assert math.hypot(3.0, -4.0) == 5.0, 'signs are ignored'
+assert math.hypot(1, 2, 2) == 3.0, 'three coordinates'
+assert math.hypot(2, 3, 6) == 7.0, 'another three coordinates'
+assert math.hypot(1, 1, 1, 1) == 2.0, 'four coordinates'
+assert math.hypot(-7) == 7.0, 'one coordinate is its absolute value'
+assert math.hypot() == 0.0, 'no coordinates'
+assert math.hypot(0, 0) == 0.0, 'zero vector'
+assert type(math.hypot(3, 4)) == float, 'hypot returns a float'
+assert math.hypot(3e300, 4e300) == 5e300, 'large components do not overflow'
+assert math.hypot(3e-300, 4e-300) == 5e-300, 'tiny components do not underflow'
+assert math.hypot(1e308, 1e308) == 1.4142135623730951e308, 'sum of squares beyond the float range'
+assert math.hypot(1e200, 1) == 1e200, 'small component swamped by a large one'
+assert math.hypot(10**20, 0) == 1e20, 'big int coordinate'
+assert math.isinf(math.hypot(inf, 1)), 'infinite coordinate'
+assert math.isinf(math.hypot(nan, -inf)), 'infinity wins over nan'
+assert math.isnan(math.hypot(nan, 1)), 'nan coordinate'
+
+# === Errors ===
+try:
+    math.asin(2)
+    assert False, 'asin(2) should raise'
+except ValueError:
+    pass
+
+try:
+    math.acos(-1.5)
+    assert False, 'acos(-1.5) should raise'
+except ValueError:
+    pass
+
+try:
+    math.atan('x')  # pyright: ignore
+    assert False, 'atan(str) should raise'
+except TypeError as e:
+    assert str(e) == 'must be real number, not str', 'atan(str) message'
+
+try:
+    math.hypot(3, '4')  # pyright: ignore
+    assert False, 'hypot with a str should raise'
+except TypeError as e:
+    assert str(e) == 'must be real number, not str', 'hypot(str) message'
+
+try:
+    math.hypot(x=3)  # pyright: ignore
+    assert False, 'hypot with a keyword should raise'
+except TypeError as e:
+    assert str(e) == 'math.hypot() takes no keyword arguments', 'hypot keyword message'
+
+try:
+    math.atan2(1)  # pyright: ignore
+    assert False, 'atan2 with one argument should raise'
+except TypeError as e:
+    assert str(e) == 'atan2 expected 2 arguments, got 1', 'atan2 argument count message'
+
+try:
+    math.degrees()  # pyright: ignore
+    assert False, 'degrees() should raise'
+except TypeError as e:
+    assert str(e) == 'math.degrees() takes exactly one argument (0 given)', 'degrees() message'
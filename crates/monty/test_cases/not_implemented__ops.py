@@ -0,0 +1,51 @@
+# === singleton ===
+assert repr(NotImplemented) == 'NotImplemented', 'NotImplemented repr'
+assert str(NotImplemented) == 'NotImplemented', 'NotImplemented str'
+assert NotImplemented is NotImplemented, 'NotImplemented identity'
+assert NotImplemented == NotImplemented, 'NotImplemented equality'
+assert id(NotImplemented) == id(NotImplemented), 'NotImplemented id is stable'
+
+# === distinct from None and NotImplementedError ===
+assert NotImplemented is not None, 'not None'
+assert NotImplemented != None, 'not equal to None'
+assert NotImplemented is not ..., 'not Ellipsis'
+assert NotImplemented is not NotImplementedError, 'not the exception type'  # pyright: ignore
+assert type(NotImplemented) != type(None), 'own type'
+
+
+# === returned from functions ===
+def fallback(other):
+    if isinstance(other, int):
+        return other * 2
+    return NotImplemented
+
+
+assert fallback(3) == 6, 'implemented branch'
+assert fallback('x') is NotImplemented, 'fallback branch'
+
+# === as a value ===
+d = {NotImplemented: 'ni'}
+assert d[NotImplemented] == 'ni', 'NotImplemented dict key'
+assert hash(NotImplemented) == hash(NotImplemented), 'NotImplemented hash'
+assert [NotImplemented] == [NotImplemented], 'NotImplemented in list'
+
+
+# === no truth value ===
+def raises_bool_error(f):
+    try:
+        f()
+    except TypeError as e:
+        return str(e) == 'NotImplemented should not be used in a boolean context'
+    return False
+
+
+ni = fallback('x')
+assert raises_bool_error(lambda: bool(ni)), 'bool()'
+assert raises_bool_error(lambda: not ni), 'not'
+assert raises_bool_error(lambda: 1 if ni else 2), 'conditional expression'
+assert raises_bool_error(lambda: ni and 1), 'and'
+assert raises_bool_error(lambda: ni or 1), 'or'
+assert raises_bool_error(lambda: any([0, ni])), 'any()'
+assert raises_bool_error(lambda: all([1, ni])), 'all()'
+assert raises_bool_error(lambda: list(filter(None, [ni]))), 'filter()'
+assert any([1, ni]), 'any() stops before NotImplemented'
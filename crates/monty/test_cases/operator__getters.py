@@ -0,0 +1,125 @@
+import re
+import sys
+from operator import attrgetter, itemgetter
+
+# === itemgetter ===
+second = itemgetter(1)
+assert second((1, 2, 3)) == 2, 'itemgetter on a tuple'
+assert second([4, 5]) == 5, 'itemgetter on a list'
+assert second('abc') == 'b', 'itemgetter on a str'
+assert itemgetter('a')({'a': 1}) == 1, 'itemgetter on a dict'
+assert itemgetter(-1)([1, 2, 3]) == 3, 'itemgetter with a negative index'
+assert itemgetter(slice(1, 3))([1, 2, 3, 4]) == [2, 3], 'itemgetter with a slice'
+assert itemgetter(0, 2)('abc') == ('a', 'c'), 'itemgetter with several items returns a tuple'
+assert itemgetter(0, 0)([7]) == (7, 7), 'itemgetter with repeated items'
+assert repr(itemgetter(1, 'a')) == "operator.itemgetter(1, 'a')", 'itemgetter repr'
+assert repr(type(second)) == "<class 'operator.itemgetter'>", 'itemgetter type'
+
+# === attrgetter ===
+assert attrgetter('major')(sys.version_info) == 3, 'attrgetter on a namedtuple'
+assert attrgetter('version_info.major')(sys) == 3, 'attrgetter with a dotted name'
+p = re.compile('(a)(b)')
+assert attrgetter('pattern', 'groups')(p) == ('(a)(b)', 2), 'attrgetter with several names returns a tuple'
+assert repr(attrgetter('x', 'y.z')) == "operator.attrgetter('x', 'y.z')", 'attrgetter repr'
+assert repr(type(attrgetter('x'))) == "<class 'operator.attrgetter'>", 'attrgetter type'
+
+# === as keys ===
+pairs = [(1, 'b'), (0, 'c'), (2, 'a')]
+assert sorted(pairs, key=itemgetter(1)) == [(2, 'a'), (1, 'b'), (0, 'c')], 'sorted by itemgetter'
+assert sorted(pairs, key=itemgetter(0), reverse=True) == [(2, 'a'), (1, 'b'), (0, 'c')], 'sorted reverse'
+assert min(pairs, key=itemgetter(1)) == (2, 'a'), 'min by itemgetter'
+assert max(pairs, key=itemgetter(1)) == (0, 'c'), 'max by itemgetter'
+
+records = [('x', 1, 'b'), ('y', 0, 'a'), ('z', 1, 'a')]
+assert sorted(records, key=itemgetter(1, 2)) == [('y', 0, 'a'), ('z', 1, 'a'), ('x', 1, 'b')], 'sorted by two items'
+
+lst = [(3, 'c'), (1, 'a'), (2, 'b')]
+lst.sort(key=itemgetter(0))
+assert lst == [(1, 'a'), (2, 'b'), (3, 'c')], 'list.sort by itemgetter'
+
+patterns = [re.compile('(a)(b)'), re.compile('x'), re.compile('(y)')]
+assert [q.pattern for q in sorted(patterns, key=attrgetter('groups'))] == ['x', '(y)', '(a)(b)'], 'sorted by attrgetter'
+assert max(patterns, key=attrgetter('groups')).pattern == '(a)(b)', 'max by attrgetter'
+assert min(patterns, key=attrgetter('groups')).pattern == 'x', 'min by attrgetter'
+
+# === stability ===
+rows = [('b', 1), ('a', 2), ('c', 1), ('d', 2), ('e', 0)]
+assert sorted(rows, key=itemgetter(1)) == [('e', 0), ('b', 1), ('c', 1), ('a', 2), ('d', 2)], 'sorted is stable'
+assert sorted(rows, key=itemgetter(1), reverse=True) == [
+    ('a', 2),
+    ('d', 2),
+    ('b', 1),
+    ('c', 1),
+    ('e', 0),
+], 'sorted reverse is stable'
+assert min(rows, key=itemgetter(1)) == ('e', 0), 'min by key'
+assert max(rows, key=itemgetter(1)) == ('a', 2), 'max keeps the first of equal items'
+assert min([('b', 1), ('a', 1)], key=itemgetter(1)) == ('b', 1), 'min keeps the first of equal items'
+assert max(('b', 1), ('a', 1), key=itemgetter(1)) == ('b', 1), 'max with several arguments keeps the first'
+
+# === key caching ===
+calls = []
+
+
+def tracking_key(x):
+    calls.append(x)
+    return x
+
+
+assert min([3, 1, 2], key=tracking_key) == 1, 'min with tracking key'
+assert calls == [3, 1, 2], 'min calls key once per item in order'
+calls.clear()
+assert max([3, 1, 2], key=tracking_key) == 3, 'max with tracking key'
+assert calls == [3, 1, 2], 'max calls key once per item in order'
+calls.clear()
+assert sorted([2, 3, 1, 2], key=tracking_key) == [1, 2, 2, 3], 'sorted with tracking key'
+assert calls == [2, 3, 1, 2], 'sorted calls key once per item in order'
+
+# === errors ===
+try:
+    itemgetter()  # pyright: ignore
+    assert False, 'itemgetter without items should raise'
+except TypeError as e:
+    assert str(e) == 'itemgetter expected 1 argument, got 0', 'itemgetter without items message'
+
+try:
+    attrgetter()  # pyright: ignore
+    assert False, 'attrgetter without names should raise'
+except TypeError as e:
+    assert str(e) == 'attrgetter expected 1 argument, got 0', 'attrgetter without names message'
+
+try:
+    attrgetter(1)  # pyright: ignore
+    assert False, 'attrgetter with a non-str name should raise'
+except TypeError as e:
+    assert str(e) == 'attribute name must be a string', 'attrgetter name type message'
+
+try:
+    second(1, 2)  # pyright: ignore
+    assert False, 'calling a getter with two arguments should raise'
+except TypeError as e:
+    assert str(e) == 'itemgetter expected 1 argument, got 2', 'getter argument count message'
+
+try:
+    itemgetter(1, x=1)  # pyright: ignore
+    assert False, 'itemgetter with keywords should raise'
+except TypeError as e:
+    assert str(e) == 'itemgetter() takes no keyword arguments', 'itemgetter keyword message'
+
+try:
+    second([1])
+    assert False, 'itemgetter with a missing index should raise'
+except IndexError as e:
+    assert str(e) == 'list index out of range', 'itemgetter index error message'
+
+try:
+    attrgetter('nope')(1)
+    assert False, 'attrgetter with a missing attribute should raise'
+except AttributeError as e:
+    assert str(e) == "'int' object has no attribute 'nope'", 'attrgetter missing attribute message'
+
+try:
+    sorted([(1,), ()], key=itemgetter(0))
+    assert False, 'key errors propagate from sorted'
+except IndexError as e:
+    assert str(e) == 'tuple index out of range', 'sorted key error message'
@@ -60,6 +60,14 @@ assert repr(range(1, 5)) == 'range(1, 5)', 'repr range two args'
 assert repr(range(1, 5, 2)) == 'range(1, 5, 2)', 'repr range three args'
 assert repr(range(0, 10, 1)) == 'range(0, 10)', 'repr range step 1 omitted'
 assert repr(range(5, 0, -1)) == 'range(5, 0, -1)', 'repr range negative step'
+assert repr(range(-3)) == 'range(0, -3)', 'repr empty range keeps its bounds'
+assert str(range(2, 4)) == 'range(2, 4)', 'str is the same as repr'
+assert repr([range(2)]) == '[range(0, 2)]', 'repr range inside a list'
+assert repr(range(5)[::2]) == 'range(0, 5, 2)', 'repr sliced range keeps the slice stop'
+assert repr(range(1, 10)[2:5]) == 'range(3, 6)', 'repr sliced range with offsets'
+assert repr(range(10)[3:1]) == 'range(3, 1)', 'repr empty sliced range'
+assert repr(range(10)[::-1]) == 'range(9, -1, -1)', 'repr reversed range'
+assert repr(range(0, 10, 3)[::-2]) == 'range(9, -3, -6)', 'repr reversed range with a step'
 
 # === range type ===
 assert type(range(5)) == range, 'type of range'
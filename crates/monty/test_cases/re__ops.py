@@ -0,0 +1,174 @@
+import re
+from re import findall, sub
+
+# === search and match ===
+m = re.search(r'\d+', 'abc 123 def 456')
+assert m is not None, 'search finds a match'
+assert m.group() == '123', 'group() is the whole match'
+assert m.group(0) == '123', 'group(0) is the whole match'
+assert m.span() == (4, 7), 'span of the match'
+assert m.start() == 4, 'start of the match'
+assert m.end() == 7, 'end of the match'
+assert repr(m) == "<re.Match object; span=(4, 7), match='123'>", 'match repr'
+assert re.search(r'\d', 'abc') is None, 'search without a match'
+
+assert re.match(r'\d+', 'abc 123') is None, 'match only matches at the start'
+m = re.match(r'[a-z]+', 'abc 123')
+assert m is not None and m.group() == 'abc', 'match at the start'
+assert re.match(r'b', 'ab') is None, 'match does not search'
+assert re.match('^b', 'a\nb', re.M) is None, 'match anchors at the start of the string, not a line'
+m = re.match('b|ab', 'ab')
+assert m is not None and m.group() == 'ab', 'match tries each alternative at the start'
+m = re.match('(?x) a b  # trailing comment', 'abc')
+assert m is not None and m.group() == 'ab', 'match with an inline verbose flag and a trailing comment'
+p = re.compile(r'(\d)(?P<rest>\d*)')
+m = p.match('123')
+assert m is not None and m.groups() == ('1', '23') and m.group('rest') == '23', 'groups of an anchored match'
+assert p.match('x123') is None and p.search('x123') is not None, 'one pattern used for match and search'
+
+m = re.match(r'(\w+)@(\w+)\.com', 'alice@example.com')
+assert m is not None, 'match with groups'
+assert m.group(1) == 'alice', 'first group'
+assert m.group(2) == 'example', 'second group'
+assert m.group(1, 2) == ('alice', 'example'), 'several groups give a tuple'
+assert m.groups() == ('alice', 'example'), 'groups()'
+assert m.span(2) == (6, 13), 'span of a group'
+assert m.start(1) == 0 and m.end(1) == 5, 'start and end of a group'
+
+# === optional groups ===
+m = re.match(r'(a)(b)?', 'a')
+assert m is not None, 'optional group match'
+assert m.group(2) is None, 'group that did not participate is None'
+assert m.groups() == ('a', None), 'groups() uses None by default'
+assert m.groups('-') == ('a', '-'), 'groups() with a default'
+assert m.span(2) == (-1, -1), 'span of a group that did not participate'
+assert m.start(2) == -1, 'start of a group that did not participate'
+
+# === named groups ===
+m = re.search(r'(?P<year>\d{4})-(?P<month>\d{2})', 'date: 2024-05-17')
+assert m is not None, 'named groups'
+assert m.group('year') == '2024', 'group by name'
+assert m.group('month') == '05', 'second group by name'
+assert m.group(1) == '2024', 'named groups are also numbered'
+assert m.span('month') == (11, 13), 'span by name'
+
+try:
+    m.group(3)
+    assert False, 'group out of range should raise'
+except IndexError as e:
+    assert str(e) == 'no such group', 'no such group message'
+
+try:
+    m.group('day')
+    assert False, 'unknown group name should raise'
+except IndexError as e:
+    assert str(e) == 'no such group', 'unknown group name message'
+
+# === positions are characters ===
+m = re.search('b', 'ééb')
+assert m is not None and m.span() == (2, 3), 'span counts characters'
+
+# === findall ===
+assert re.findall(r'\d+', 'a1b22c333') == ['1', '22', '333'], 'findall without groups'
+assert re.findall(r'(\d)x', '1x2y3x') == ['1', '3'], 'findall with one group'
+assert re.findall(r'(\w)=(\d)', 'a=1, b=2') == [('a', '1'), ('b', '2')], 'findall with groups'
+assert re.findall(r'(a)|(b)', 'ab') == [('a', ''), ('', 'b')], 'findall uses empty strings for missing groups'
+assert re.findall(r'z', 'abc') == [], 'findall without matches'
+assert findall('o', 'foo') == ['o', 'o'], 'findall imported from re'
+
+# === sub ===
+assert re.sub(r'\d', '#', 'a1b2c3') == 'a#b#c#', 'sub replaces all matches'
+assert re.sub(r'\d', '#', 'a1b2c3', count=2) == 'a#b#c3', 'sub with count'
+assert re.sub(r'\d', '#', 'a1b2c3', 1) == 'a#b2c3', 'sub with positional count'
+assert re.sub(r'(\w+) (\w+)', r'\2 \1', 'hello world') == 'world hello', 'sub with group references'
+assert re.sub(r'(?P<word>\w+)', r'<\g<word>>', 'a b') == '<a> <b>', 'sub with named group references'
+assert re.sub(r'(\w)', r'\g<1>\g<0>', 'ab') == 'aabb', 'sub with \\g<n>'
+assert re.sub(r'x', r'\n', 'axb') == 'a\nb', 'sub processes escapes'
+assert re.sub(r'x', r'\\', 'axb') == 'a\\b', 'sub with an escaped backslash'
+assert re.sub(r'(a)|b', r'[\1]', 'ab') == '[a][]', 'missing groups are replaced by empty strings'
+assert sub('a', 'b', 'aaa') == 'bbb', 'sub imported from re'
+
+try:
+    re.sub(r'a', r'\q', 'a')
+    assert False, 'bad escape should raise'
+except Exception as e:
+    assert str(e) == 'bad escape \\q at position 0', 'bad escape message'
+
+try:
+    re.sub(r'(a)', r'\2', 'a')
+    assert False, 'invalid group reference should raise'
+except Exception as e:
+    assert str(e) == 'invalid group reference 2 at position 1', 'invalid group reference message'
+
+# === split ===
+assert re.split(r',\s*', 'a, b,c') == ['a', 'b', 'c'], 'split'
+assert re.split(r'(,)', 'a,b') == ['a', ',', 'b'], 'split keeps groups'
+assert re.split(r',', 'a,b,c', maxsplit=1) == ['a', 'b,c'], 'split with maxsplit'
+assert re.split(r'x', 'abc') == ['abc'], 'split without matches'
+assert re.split(r'(a)|(b)', 'xaybz') == ['x', 'a', None, 'y', None, 'b', 'z'], 'split with missing groups'
+
+# === compile ===
+p = re.compile(r'(\d+)-(\d+)')
+assert p.pattern == r'(\d+)-(\d+)', 'pattern attribute'
+assert p.groups == 2, 'groups attribute'
+assert p.flags == 32, 'str patterns always have the UNICODE flag'
+assert re.compile('a', re.I).flags == 34, 'flags attribute'
+assert repr(p) == r"re.compile('(\\d+)-(\\d+)')", 'pattern repr'
+m = p.search('range 10-20')
+assert m is not None and m.groups() == ('10', '20'), 'compiled search'
+assert p.match('range 10-20') is None, 'compiled match'
+assert p.findall('1-2 3-4') == [('1', '2'), ('3', '4')], 'compiled findall'
+assert p.sub(r'\2-\1', '1-2 3-4') == '2-1 4-3', 'compiled sub'
+assert p.sub('x', '1-2 3-4', count=1) == 'x 3-4', 'compiled sub with count'
+assert re.compile(',').split('a,b,c', maxsplit=1) == ['a', 'b,c'], 'compiled split'
+assert re.search(p, '5-6') is not None, 'module functions accept compiled patterns'
+assert re.compile(p) is p, 'compiling a compiled pattern returns it'
+assert re.compile('a') == re.compile('a'), 'patterns with the same source are equal'
+assert re.compile('a') != re.compile('a', re.I), 'patterns with different flags are not equal'
+assert re.compile('a') != re.compile('b'), 'patterns with different sources are not equal'
+
+try:
+    re.compile(p, re.I)
+    assert False, 'flags with a compiled pattern should raise'
+except ValueError as e:
+    assert str(e) == 'cannot process flags argument with a compiled pattern', 'compiled pattern flags message'
+
+# === flags ===
+assert re.search('abc', 'xABCx', re.IGNORECASE) is not None, 'IGNORECASE'
+assert re.search('abc', 'xABCx', flags=re.I) is not None, 'I alias'
+assert re.search('abc', 'xABCx') is None, 'case sensitive by default'
+assert re.findall('^b', 'a\nb', re.MULTILINE) == ['b'], 'MULTILINE'
+assert re.findall('^b', 'a\nb', re.M) == ['b'], 'M alias'
+assert re.findall('^b', 'a\nb') == [], 'not multiline by default'
+assert re.match('a.b', 'a\nb', re.DOTALL) is not None, 'DOTALL'
+assert re.match('a.b', 'a\nb', re.S) is not None, 'S alias'
+assert re.match('a.b', 'a\nb') is None, 'dot does not match newline by default'
+assert re.match('a b  # comment', 'ab', re.VERBOSE) is not None, 'VERBOSE'
+assert re.match('a b', 'ab', re.X) is not None, 'X alias'
+assert re.match('(?i)abc', 'ABC') is not None, 'inline flags'
+assert repr(re.compile('a', re.I | re.M)) == "re.compile('a', re.IGNORECASE|re.MULTILINE)", 'repr with flags'
+
+# === argument errors ===
+try:
+    re.search('a', 5)  # pyright: ignore
+    assert False, 'non-string text should raise'
+except TypeError as e:
+    assert str(e) == "expected string or bytes-like object, got 'int'", 'text type error'
+
+try:
+    re.search(5, 'a')  # pyright: ignore
+    assert False, 'non-string pattern should raise'
+except TypeError as e:
+    assert str(e) == 'first argument must be string or compiled pattern', 'pattern type error'
+
+try:
+    re.search(pattern='a')  # pyright: ignore
+    assert False, 'missing argument should raise'
+except TypeError as e:
+    assert str(e) == "search() missing 1 required positional argument: 'string'", 'missing argument error'
+
+try:
+    re.search('a', 'b', flag=1)  # pyright: ignore
+    assert False, 'unknown keyword should raise'
+except TypeError as e:
+    assert str(e) == "search() got an unexpected keyword argument 'flag'", 'unexpected keyword error'
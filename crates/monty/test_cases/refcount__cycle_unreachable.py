@@ -0,0 +1,17 @@
+# Unreachable cycles: two lists that reference each other, and a dict containing itself
+# Once the names are rebound, nothing reachable refers to the cycles, but each value
+# keeps a refcount of 1 from the other side of its cycle, so reference counting never
+# frees them. Strict matching excuses them because garbage collection would.
+a = []
+b = []
+a.append(b)
+b.append(a)
+d = {}
+d['self'] = d
+a = None
+b = None
+d = None
+
+c = [1, 2]
+len(c)
+# ref-counts={'c': 1}
@@ -104,3 +104,34 @@ assert len(s) == 3, 'set literal deduplication'
 x = 5
 s = {x, x + 1, x + 2}
 assert len(s) == 3, 'set literal with expressions'
+
+# === Union operators ===
+s1 = {1, 2}
+s2 = {2, 3}
+assert s1 | s2 == {1, 2, 3}, 'set union operator'
+assert s1 == {1, 2} and s2 == {2, 3}, 'union leaves operands unchanged'
+assert set() | set() == set(), 'union of empty sets'
+assert s1 | s1 == s1, 'union with itself'
+assert s1 | frozenset([5]) == {1, 2, 5}, 'set | frozenset'
+assert type(s1 | frozenset([5])) == set, 'set | frozenset is a set'
+assert type(frozenset([5]) | s1) == frozenset, 'frozenset | set is a frozenset'
+
+s = {1}
+alias = s
+s |= {2, 3}
+assert s == {1, 2, 3}, 'in-place set union'
+assert alias is s, 'in-place union mutates the set'
+s |= s
+assert s == {1, 2, 3}, 'in-place union with itself'
+
+f = frozenset([1])
+f_alias = f
+f |= {2}
+assert f == frozenset([1, 2]), 'in-place union on a frozenset'
+assert f_alias == frozenset([1]), 'frozenset in-place union makes a new frozenset'
+
+try:
+    {1} | [2]  # pyright: ignore
+    assert False, 'set | list should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for |: 'set' and 'list'", 'set | list message'
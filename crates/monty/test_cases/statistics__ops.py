@@ -0,0 +1,136 @@
+import math
+import statistics
+
+# === mean ===
+assert statistics.mean([1, 2, 3, 4, 4]) == 2.8, 'mean of ints'
+assert statistics.mean([1, 2, 3]) == 2, 'whole mean of ints'
+assert type(statistics.mean([1, 2, 3])) == int, 'whole mean of ints is an int'
+assert type(statistics.mean([1, 2])) == float, 'fractional mean of ints is a float'
+assert statistics.mean([1, 2]) == 1.5, 'fractional mean of ints'
+assert statistics.mean([-1.0, 2.5, 3.25, 5.75]) == 2.625, 'mean of floats'
+assert statistics.mean([1, 2.0]) == 1.5, 'mixed ints and floats'
+assert type(statistics.mean([2, 2.0])) == float, 'mixed data gives a float'
+assert statistics.mean([True, True, False, True]) == 0.75, 'bools count as ints'
+assert statistics.mean(range(101)) == 50, 'mean of a range'
+assert statistics.mean(x * 2 for x in [1, 2, 3]) == 4, 'mean of a generator'
+assert statistics.mean((0.5,)) == 0.5, 'single float'
+assert statistics.mean([10**30, 10**30 + 2]) == 10**30 + 1, 'exact for big ints'
+assert statistics.mean([1e308, 1e308]) == 1e308, 'no overflow for large floats'
+
+# === median ===
+assert statistics.median([1, 3, 5]) == 3, 'odd count gives the middle value'
+assert type(statistics.median([1, 3, 5])) == int, 'odd count keeps the type'
+assert statistics.median([1, 3, 5, 7]) == 4.0, 'even count averages the middle two'
+assert type(statistics.median([1, 3, 5, 7])) == float, 'even count divides'
+assert statistics.median([5, 1, 3]) == 3, 'data is sorted first'
+assert statistics.median([2.5, -1.0, 7.0, 0.0]) == 1.25, 'floats'
+assert statistics.median([7]) == 7, 'single value'
+assert statistics.median(['b', 'a', 'c']) == 'b', 'odd count works on any ordered values'
+assert statistics.median(range(10)) == 4.5, 'median of a range'
+
+# === mode ===
+assert statistics.mode([1, 1, 2, 3, 3, 3, 3, 4]) == 3, 'most common int'
+assert statistics.mode(['red', 'blue', 'blue', 'red', 'green', 'red', 'red']) == 'red', 'most common str'
+assert statistics.mode('aabbbc') == 'b', 'mode of a string'
+assert statistics.mode([1, 2, 2, 1]) == 1, 'first value seen wins ties'
+assert statistics.mode([3, 1, 2]) == 3, 'all distinct gives the first'
+assert statistics.mode([(1, 2), (3, 4), (1, 2)]) == (1, 2), 'tuples are counted'
+assert statistics.mode([1, 1.0, True, 2, 2]) == 1, 'equal numbers are counted together'
+
+# === variance and stdev (sample) ===
+data = [2.75, 1.75, 1.25, 0.25, 0.5, 1.25, 3.5]
+assert math.isclose(statistics.variance(data), 1.3720238095238095), 'sample variance of floats'
+assert statistics.variance([1, 2, 3]) == 1, 'whole variance of ints'
+assert type(statistics.variance([1, 2, 3])) == int, 'whole variance of ints is an int'
+assert statistics.variance([1, 2, 3, 4]) == 1.6666666666666667, 'fractional variance of ints'
+assert statistics.variance([5, 5, 5]) == 0, 'constant data'
+assert statistics.variance([True, False]) == 0.5, 'bools'
+assert statistics.variance(range(10)) == 9.166666666666666, 'variance of a range'
+assert statistics.stdev([1, 2, 3]) == 1.0, 'sample stdev'
+assert type(statistics.stdev([1, 2, 3])) == float, 'stdev is a float'
+assert statistics.stdev([1.5, 2.5, 2.5, 2.75, 3.25, 4.75]) == 1.0810874155219827, 'sample stdev of floats'
+
+# precision: a large offset does not swamp small deviations
+offset = 1e9
+shifted = [offset + x for x in [4.0, 7.0, 13.0, 16.0]]
+assert statistics.variance(shifted) == 30.0, 'variance with a large offset'
+assert statistics.variance([10**20 + x for x in [4, 7, 13, 16]]) == 30, 'exact with huge ints'
+
+# === pstdev (population) ===
+assert statistics.pstdev([2, 4, 4, 4, 5, 5, 7, 9]) == 2.0, 'population stdev'
+assert statistics.pstdev([1.5, 2.5, 2.5, 2.75, 3.25, 4.75]) == 0.986893273527251, 'population stdev of floats'
+assert statistics.pstdev([42]) == 0.0, 'single value has no spread'
+assert statistics.pstdev([offset + 1, offset + 3]) == 1.0, 'pstdev with a large offset'
+
+# === Errors ===
+try:
+    statistics.mean([])
+    assert False, 'mean of empty data should raise'
+except statistics.StatisticsError as e:
+    assert str(e) == 'mean requires at least one data point', 'empty mean message'
+
+try:
+    statistics.median([])
+    assert False, 'median of empty data should raise'
+except statistics.StatisticsError as e:
+    assert str(e) == 'no median for empty data', 'empty median message'
+
+try:
+    statistics.mode([])
+    assert False, 'mode of empty data should raise'
+except statistics.StatisticsError as e:
+    assert str(e) == 'no mode for empty data', 'empty mode message'
+
+try:
+    statistics.variance([1])
+    assert False, 'variance of one value should raise'
+except statistics.StatisticsError as e:
+    assert str(e) == 'variance requires at least two data points', 'short variance message'
+
+try:
+    statistics.stdev([1.0])
+    assert False, 'stdev of one value should raise'
+except statistics.StatisticsError as e:
+    assert str(e) == 'stdev requires at least two data points', 'short stdev message'
+
+try:
+    statistics.pstdev([])
+    assert False, 'pstdev of empty data should raise'
+except statistics.StatisticsError as e:
+    assert str(e) == 'pstdev requires at least one data point', 'empty pstdev message'
+
+try:
+    statistics.mean([])
+    assert False, 'mean of empty data should raise'
+except ValueError:
+    pass
+
+try:
+    statistics.mean(['a', 'b'])  # pyright: ignore
+    assert False, 'mean of strs should raise'
+except TypeError as e:
+    assert str(e) == "can't convert type 'str' to numerator/denominator", 'mean of strs message'
+
+try:
+    statistics.variance([1, None])  # pyright: ignore
+    assert False, 'variance with None should raise'
+except TypeError as e:
+    assert str(e) == "can't convert type 'NoneType' to numerator/denominator", 'variance with None message'
+
+try:
+    statistics.mode([[1], [1]])  # pyright: ignore
+    assert False, 'mode of lists should raise'
+except TypeError as e:
+    assert str(e) == "unhashable type: 'list'", 'mode of lists message'
+
+try:
+    statistics.median([1, 'a'])  # pyright: ignore
+    assert False, 'median of mixed types should raise'
+except TypeError:
+    pass
+
+try:
+    statistics.mean(5)  # pyright: ignore
+    assert False, 'mean of an int should raise'
+except TypeError as e:
+    assert str(e) == "'int' object is not iterable", 'mean of an int message'
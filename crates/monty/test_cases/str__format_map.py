@@ -0,0 +1,98 @@
+# === plain fields ===
+assert '{name} is {age}'.format_map({'name': 'Ann', 'age': 30}) == 'Ann is 30', 'fields from a dict'
+assert 'no fields'.format_map({}) == 'no fields', 'template without fields'
+assert ''.format_map({}) == '', 'empty template'
+assert '{a}{a}'.format_map({'a': 'x'}) == 'xx', 'same field twice'
+assert '{x}'.format_map({'x': None}) == 'None', 'values are converted with str()'
+assert '{x}'.format_map({'x': [1, 'a']}) == "[1, 'a']", 'container values'
+assert '{ a }'.format_map({' a ': 1}) == '1', 'spaces are part of the key'
+assert '{café}'.format_map({'café': 'ok'}) == 'ok', 'non-ascii keys'
+
+# === escapes ===
+assert '{{}}'.format_map({}) == '{}', 'doubled braces'
+assert 'x{{y}}z'.format_map({}) == 'x{y}z', 'doubled braces around text'
+assert '{{{a}}}'.format_map({'a': 1}) == '{1}', 'field inside escaped braces'
+
+# === indexing ===
+assert '{a[0]}'.format_map({'a': [5, 6]}) == '5', 'int index'
+assert '{a[1]}'.format_map({'a': 'xy'}) == 'y', 'index into a str'
+assert '{a[k]}'.format_map({'a': {'k': 3}}) == '3', 'str index'
+assert '{a[-1]}'.format_map({'a': {'-1': 2}}) == '2', 'non-digit index is a str key'
+assert '{a[0][1]}'.format_map({'a': [[1, 2]]}) == '2', 'chained indexes'
+assert '{a[}]}'.format_map({'a': {'}': 1}}) == '1', 'braces inside brackets'
+assert '{a[:]}'.format_map({'a': {':': 1}}) == '1', 'colon inside brackets'
+
+# === conversions and format specs ===
+assert '{a!r}'.format_map({'a': 'x'}) == "'x'", 'repr conversion'
+assert '{a!s}'.format_map({'a': 'x'}) == 'x', 'str conversion'
+assert '{a!a}'.format_map({'a': 'é'}) == "'\\xe9'", 'ascii conversion'
+assert '{a:>5}'.format_map({'a': 'x'}) == '    x', 'alignment'
+assert '{a:05d}'.format_map({'a': 42}) == '00042', 'int spec'
+assert '{a:.2f}'.format_map({'a': 3.14159}) == '3.14', 'float spec'
+assert '{a:x}'.format_map({'a': 255}) == 'ff', 'hex spec'
+assert '{a!r:>6}'.format_map({'a': 'x'}) == "   'x'", 'conversion then spec'
+assert '{a:}'.format_map({'a': 1}) == '1', 'empty spec'
+assert '{a:{w}}'.format_map({'a': 1, 'w': 5}) == '    1', 'nested field in spec'
+assert '{a:{w}d}'.format_map({'a': 1, 'w': 3}) == '  1', 'nested field with more spec'
+assert '{a!s:{w}}'.format_map({'a': 'x', 'w': 3}) == 'x  ', 'conversion with nested spec'
+
+# === missing keys ===
+try:
+    '{b}'.format_map({'a': 1})
+    assert False, 'missing key should raise'
+except KeyError as e:
+    assert e.args == ('b',), 'missing key is the KeyError argument'
+
+try:
+    '{a[b]}'.format_map({'a': {}})
+    assert False, 'missing index key should raise'
+except KeyError as e:
+    assert e.args == ('b',), 'missing index key'
+
+try:
+    '{a[5]}'.format_map({'a': [1]})
+    assert False, 'index out of range should raise'
+except IndexError as e:
+    assert str(e) == 'list index out of range', 'index out of range message'
+
+try:
+    '{a}'.format_map(5)  # pyright: ignore
+    assert False, 'non-mapping should raise'
+except TypeError as e:
+    assert str(e) == "'int' object is not subscriptable", 'non-mapping message'
+
+# === malformed templates ===
+for template, message in [
+    ('{', "Single '{' encountered in format string"),
+    ('}', "Single '}' encountered in format string"),
+    ('{a}}', "Single '}' encountered in format string"),
+    ('{a', "expected '}' before end of string"),
+    ('{a[}', "expected '}' before end of string"),
+    ('{a{b}', "unexpected '{' in field name"),
+    ('{a!x}', 'Unknown conversion specifier x'),
+    ('{a!rr}', "expected ':' after conversion specifier"),
+    ('{a[0]x}', "Only '.' or '[' may follow ']' in format field specifier"),
+    ('{a[]}', 'Empty attribute in format string'),
+    ('{a:{b:{c}}}', 'Max string recursion exceeded'),
+    ('{}', 'Format string contains positional fields'),
+    ('{0}', 'Format string contains positional fields'),
+    ('{[0]}', 'Format string contains positional fields'),
+]:
+    try:
+        template.format_map({'a': [1], 'b': 1, 'c': 1})
+        assert False, f'{template!r} should raise'
+    except ValueError as e:
+        assert str(e) == message, f'{template!r} message'
+
+try:
+    '{a:d}'.format_map({'a': 'text'})
+    assert False, 'bad format code should raise'
+except ValueError as e:
+    assert str(e) == "Unknown format code 'd' for object of type 'str'", 'bad format code message'
+
+# === arguments ===
+try:
+    '{a}'.format_map()  # pyright: ignore
+    assert False, 'format_map without a mapping should raise'
+except TypeError as e:
+    assert 'format_map' in str(e), 'missing argument message'
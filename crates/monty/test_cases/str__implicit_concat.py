@@ -0,0 +1,32 @@
+# === plain strings ===
+assert 'a' 'b' == 'ab', 'adjacent literals'
+assert 'a' "b" '''c''' == 'abc', 'mixed quote styles'
+s = (
+    'first line\n'
+    'second line\n'
+    'third line'
+)
+assert s == 'first line\nsecond line\nthird line', 'multi-line parenthesized'
+assert r'\d' '\n' == '\\d\n', 'raw and normal strings'
+assert 'x' '' 'y' == 'xy', 'empty segment'
+
+# === bytes ===
+assert b'ab' b'cd' == b'abcd', 'adjacent bytes'
+assert rb'\x' b'\x41' == b'\\xA', 'raw and normal bytes'
+
+# === f-strings ===
+x = 42
+name = 'monty'
+assert f'{x}' 'y' == '42y', 'f-string then literal'
+assert 'y' f'{x}' == 'y42', 'literal then f-string'
+assert f'{x}' f'{name}' == '42monty', 'adjacent f-strings'
+assert f'a{x}b' 'c' f'd{name}' 'e' == 'a42bcdmontye', 'alternating segments'
+msg = (
+    f'value: {x}, '
+    'name: '
+    f'{name!r}'
+)
+assert msg == "value: 42, name: 'monty'", 'multi-line f-string concatenation'
+assert rf'\{x}' '\t' == '\\42\t', 'raw f-string and normal string'
+assert f'{x:>5}' '|' == '   42|', 'format spec before literal'
+assert f'{{' '}}' == '{}}', 'escaped braces only in the f-string'
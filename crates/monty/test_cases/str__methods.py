@@ -38,6 +38,18 @@ assert ''.swapcase() == '', 'swapcase empty'
 assert 'Hello'.casefold() == 'hello', 'casefold basic'
 assert 'HELLO'.casefold() == 'hello', 'casefold all upper'
 assert ''.casefold() == '', 'casefold empty'
+assert 'abc 123!'.casefold() == 'abc 123!', 'casefold leaves lowercase and symbols'
+assert 'ß'.casefold() == 'ss', 'casefold sharp s'
+assert 'ß'.lower() == 'ß', 'lower keeps sharp s'
+assert 'Straße'.casefold() == 'STRASSE'.casefold(), 'caseless match with sharp s'
+assert 'ẞ'.casefold() == 'ss', 'casefold capital sharp s'
+assert 'ﬁ'.casefold() == 'fi', 'casefold ligature'
+assert 'ſ'.casefold() == 's', 'casefold long s'
+assert 'ΣΑΣ'.casefold() == 'σασ', 'casefold capital sigma'
+assert 'ς'.casefold() == 'σ', 'casefold final sigma'
+assert 'µ'.casefold() == 'μ', 'casefold micro sign'
+assert 'ÀÉÎ'.casefold() == 'àéî', 'casefold accented letters'
+assert 'ᏸ'.casefold() == 'Ᏸ', 'casefold cherokee folds to uppercase'
 
 # === Phase 2: Predicate methods ===
 
@@ -190,11 +202,35 @@ assert ''.splitlines() == [], 'splitlines empty'
 assert 'hello world'.partition(' ') == ('hello', ' ', 'world'), 'partition basic'
 assert 'hello'.partition('x') == ('hello', '', ''), 'partition not found'
 assert 'hello world test'.partition(' ') == ('hello', ' ', 'world test'), 'partition first'
+assert 'ab'.partition('ab') == ('', 'ab', ''), 'partition whole string'
+assert 'a::b::c'.partition('::') == ('a', '::', 'b::c'), 'partition multi-char sep'
+assert ''.partition('x') == ('', '', ''), 'partition empty string'
+try:
+    'hello'.partition(1)
+    assert False, 'partition non-str sep should error'
+except TypeError as e:
+    assert str(e) == 'must be str, not int', f'partition non-str sep message, error: {e}'
+try:
+    'hello'.partition([])
+    assert False, 'partition list sep should error'
+except TypeError as e:
+    assert str(e) == 'must be str, not list', f'partition list sep message, error: {e}'
 
 # rpartition()
 assert 'hello world'.rpartition(' ') == ('hello', ' ', 'world'), 'rpartition basic'
 assert 'hello'.rpartition('x') == ('', '', 'hello'), 'rpartition not found'
 assert 'hello world test'.rpartition(' ') == ('hello world', ' ', 'test'), 'rpartition last'
+assert 'a::b::c'.rpartition('::') == ('a::b', '::', 'c'), 'rpartition multi-char sep'
+try:
+    'hello'.rpartition('')
+    assert False, 'rpartition empty sep should error'
+except ValueError as e:
+    assert str(e) == 'empty separator', f'rpartition empty sep message, error: {e}'
+try:
+    'hello'.rpartition(None)
+    assert False, 'rpartition non-str sep should error'
+except TypeError as e:
+    assert str(e) == 'must be str, not NoneType', f'rpartition non-str sep message, error: {e}'
 
 # === Phase 6: Replace/modify methods ===
 
@@ -23,6 +23,69 @@ s = 'ab'
 s += s
 assert s == 'abab', 'iadd self'
 
+# building a string in a loop
+s = ''
+for i in range(1000):
+    s += str(i % 10)
+assert len(s) == 1000 and s[:12] == '012345678901', 'iadd in a loop'
+
+
+def build(n):
+    out = ''
+    for _ in range(n):
+        out += 'ab'
+    return out
+
+
+assert build(500) == 'ab' * 500, 'iadd in a loop inside a function'
+
+# strings are immutable, so += on a shared string leaves other references alone
+s = ''.join(['a', 'b'])
+alias = s
+s += 'c'
+assert s == 'abc' and alias == 'ab', 'iadd does not change an alias'
+s += alias
+assert s == 'abcab' and alias == 'ab', 'iadd with an alias on the right'
+
+s = ''.join(['k', 'ey'])
+d = {s: 1}
+s += '!'
+assert d == {'key': 1} and d['key'] == 1, 'iadd does not change a dict key'
+assert s not in d, 'the new string is a different key'
+
+s = ''.join(['h', 'i'])
+h = hash(s)
+s += '!'
+assert hash(s) == hash('hi!') and hash(s) != h, 'iadd result hashes by its new contents'
+
+items = [''.join(['x', 'y'])]
+s = items[0]
+s += 'z'
+assert items == ['xy'] and s == 'xyz', 'iadd does not change a list item'
+items[0] += 'w'
+assert items == ['xyw'], 'iadd on a list item stores the new string'
+
+parts = []
+s = ''
+for i in range(3):
+    s += str(i)
+    parts.append(s)
+assert parts == ['0', '01', '012'], 'each saved prefix keeps its value'
+
+
+def append_to(value):
+    value += '!'
+    return value
+
+
+s = ''.join(['o', 'k'])
+assert append_to(s) == 'ok!' and s == 'ok', 'iadd on an argument does not change the caller'
+
+b = bytes([1, 2])
+b_alias = b
+b += b'\x03'
+assert b == b'\x01\x02\x03' and b_alias == b'\x01\x02', 'bytes iadd does not change an alias'
+
 # === String length ===
 assert len('') == 0, 'len empty'
 assert len('a') == 1, 'len single'
@@ -0,0 +1,97 @@
+# === Splitting paths with rfind and slicing ===
+path = 'home/user/docs/report.final.txt'
+slash = path.rfind('/')
+assert slash == 14, 'rfind gives the last separator'
+assert path[slash + 1 :] == 'report.final.txt', 'basename'
+assert path[:slash] == 'home/user/docs', 'dirname'
+name = path[slash + 1 :]
+dot = name.rfind('.')
+assert name[:dot] == 'report.final', 'stem keeps earlier dots'
+assert name[dot:] == '.txt', 'suffix'
+assert name[dot + 1 :] == 'txt', 'extension'
+assert path[path.find('/') + 1 :] == 'user/docs/report.final.txt', 'strip the first component'
+assert path[path.rfind('/', 0, slash) + 1 : slash] == 'docs', 'parent directory name'
+
+# no separator: rfind gives -1, so rfind + 1 slices from the start
+bare = 'README'
+assert bare.rfind('/') == -1, 'rfind without a match'
+assert bare[bare.rfind('/') + 1 :] == 'README', 'basename without a separator'
+assert bare[: max(bare.rfind('/'), 0)] == '', 'dirname without a separator'
+assert bare.rfind('.') == -1, 'no extension'
+
+# === Unicode paths use char indices, not bytes ===
+upath = 'données/日本語/résumé.pdf'
+slash = upath.rfind('/')
+assert slash == 11, 'rfind counts chars before the separator'
+assert upath[slash + 1 :] == 'résumé.pdf', 'unicode basename'
+assert upath[:slash] == 'données/日本語', 'unicode dirname'
+assert upath.find('/') == 7, 'find counts chars'
+assert upath[upath.find('/') + 1 : slash] == '日本語', 'unicode middle component'
+fname = upath[slash + 1 :]
+assert fname[fname.rindex('.') :] == '.pdf', 'rindex suffix'
+assert fname[: fname.rindex('.')] == 'résumé', 'rindex stem'
+assert upath.index('日') == 8, 'index counts chars'
+assert upath[upath.index('日')] == '日', 'index result is usable as a subscript'
+assert upath.rindex('é') == 17, 'rindex gives the last occurrence'
+assert upath[upath.rindex('é')] == 'é', 'rindex result is usable as a subscript'
+assert len(upath[: upath.rfind('/')]) == upath.rfind('/'), 'slice length equals the char index'
+
+emoji = 'a😀b😀c'
+assert emoji.rfind('😀') == 3, 'rfind past a wide char'
+assert emoji[emoji.rfind('😀') + 1 :] == 'c', 'slice after a wide char'
+assert emoji.find('b') == 2, 'find after a wide char'
+
+# === Negative and computed indices ===
+assert path[-4:] == '.txt', 'negative slice start'
+sep = path.rfind('/')
+assert path[sep - len(path) :] == path[sep:], 'negative equivalent of a computed index'
+assert path.rfind('/', -10) == -1, 'negative start excludes the separators'
+assert path.rfind('/', -20) == 14, 'negative start includes the last separator'
+assert path.rfind('/', 0, -17) == 9, 'negative end'
+assert path.find('/', sep + 1) == -1, 'find after the last separator'
+assert upath.rfind('/', 0, -1) == 11, 'unicode with negative end'
+assert upath[-len('résumé.pdf') :] == 'résumé.pdf', 'unicode negative slice'
+
+# === Search ranges with start and end ===
+s = 'abcabc'
+assert s.rfind('abc', 1) == 3, 'rfind from a start'
+assert s.rfind('abc', 0, 5) == 0, 'rfind before an end'
+assert s.rindex('b', 0, 3) == 1, 'rindex within a range'
+assert s.find('c', True) == 2, 'bool start'
+assert s.rfind('a', None, None) == 3, 'None start and end'
+assert s.find('a', 10**30) == -1, 'huge start finds nothing'
+assert s.rfind('a', -(10**30)) == 3, 'huge negative start is the beginning'
+
+# empty substrings follow the range, but nothing is found past the end
+assert s.find('') == 0, 'empty at the start'
+assert s.rfind('') == 6, 'empty rfind at the end'
+assert s.find('', 6) == 6, 'empty at the end'
+assert s.find('', 7) == -1, 'empty past the end'
+assert s.rfind('', 7) == -1, 'empty rfind past the end'
+assert s.find('', 4, 2) == -1, 'empty with start after end'
+assert s.rfind('', 2, 4) == 4, 'empty rfind within a range'
+assert s.count('', 7) == 0, 'count empty past the end'
+assert s.count('', 6) == 1, 'count empty at the end'
+assert s.count('a', 4, 2) == 0, 'count with start after end'
+assert s.startswith('', 6), 'startswith empty at the end'
+assert not s.startswith('', 7), 'startswith empty past the end'
+assert not s.endswith('', 4, 2), 'endswith empty with start after end'
+assert s.endswith('bc', 0, 3), 'endswith within a range'
+
+try:
+    s.rindex('', 7)
+    assert False, 'rindex past the end should raise'
+except ValueError as e:
+    assert str(e) == 'substring not found', 'rindex past the end message'
+
+try:
+    s.rindex('z')
+    assert False, 'rindex without a match should raise'
+except ValueError as e:
+    assert str(e) == 'substring not found', 'rindex message'
+
+try:
+    s.rfind('a', 1.5)  # pyright: ignore
+    assert False, 'float start should raise'
+except TypeError as e:
+    assert str(e) == 'slice indices must be integers or None or have an __index__ method', 'float start message'
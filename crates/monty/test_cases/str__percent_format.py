@@ -0,0 +1,199 @@
+# === Basic conversions ===
+assert 'hello %s' % 'world' == 'hello world', 'single %s'
+assert '%s and %s' % ('a', 'b') == 'a and b', 'tuple args'
+assert '%d items' % 3 == '3 items', 'single %d'
+assert '%i' % -7 == '-7', '%i is an alias of %d'
+assert '%r' % 'x' == "'x'", '%r uses repr'
+assert '%a' % 'é' == "'\\xe9'", '%a uses ascii repr'
+assert '100%%' % () == '100%', 'literal percent'
+assert '%s%%' % 5 == '5%', 'percent after a conversion'
+assert 'no specifiers' % () == 'no specifiers', 'template without specifiers'
+
+# === %s with different values ===
+assert '%s' % (1,) == '1', 'one-element tuple is unpacked'
+assert '%s' % ((1, 2),) == '(1, 2)', 'tuple inside a tuple'
+assert '%s' % [1, 2] == '[1, 2]', 'list is a single argument'
+assert '%s' % None == 'None', 'None'
+assert '%s' % True == 'True', 'bool with %s'
+assert '%.3s' % 12345 == '123', 'precision truncates %s'
+assert '%5s|' % 'ab' == '   ab|', 'width right-justifies'
+assert '%-5s|' % 'ab' == 'ab   |', 'minus flag left-justifies'
+assert '%05s' % 'ab' == '   ab', 'zero flag has no effect on strings'
+
+# === Integers ===
+assert '%5d' % 42 == '   42', 'width'
+assert '%-5d|' % 42 == '42   |', 'left-justified int'
+assert '%05d' % -3 == '-0003', 'zero padding after the sign'
+assert '%+d' % 5 == '+5', 'plus flag'
+assert '% d' % 5 == ' 5', 'space flag'
+assert '% 05d' % 5 == ' 0005', 'space flag with zero padding'
+assert '%.2d' % 1 == '01', 'precision is the minimum number of digits'
+assert '%d' % True == '1', 'bool with %d'
+assert '%d' % 3.99 == '3', 'float truncated by %d'
+assert '%d' % -3.99 == '-3', 'negative float truncated towards zero'
+assert '%d' % 2**70 == '1180591620717411303424', 'long int'
+assert '%ld' % 7 == '7', 'length modifier ignored'
+
+# === Hex and octal ===
+assert '%x' % 255 == 'ff', 'hex'
+assert '%X' % 255 == 'FF', 'uppercase hex'
+assert '%o' % 8 == '10', 'octal'
+assert '%#x' % 255 == '0xff', 'alternate hex'
+assert '%#X' % -255 == '-0XFF', 'alternate uppercase hex with sign'
+assert '%#o' % 8 == '0o10', 'alternate octal'
+assert '%#.3x' % 5 == '0x005', 'alternate hex with precision'
+assert '%#06x' % 255 == '0x00ff', 'zero padding after the prefix'
+assert '%x' % 2**70 == '400000000000000000', 'long int hex'
+
+# === Floats ===
+assert '%f' % 3.14159 == '3.141590', 'default precision is 6'
+assert '%.2f' % 3.14159 == '3.14', 'precision'
+assert '%8.3f|' % 2.5 == '   2.500|', 'width and precision'
+assert '%-8.3f|' % 2.5 == '2.500   |', 'left-justified float'
+assert '%010.3f' % -3.14159 == '-00003.142', 'zero padded negative float'
+assert '%+.1f' % 2.0 == '+2.0', 'plus flag on float'
+assert '%f' % 2 == '2.000000', 'int with %f'
+assert '%.0f' % 2.5 == '2', 'round half to even'
+assert '%e' % 12345.678 == '1.234568e+04', 'exponent'
+assert '%.2E' % 0.000123 == '1.23E-04', 'uppercase exponent'
+assert '%g' % 0.00001 == '1e-05', 'general small'
+assert '%g' % 123.456 == '123.456', 'general fixed'
+assert '%G' % 1e20 == '1E+20', 'uppercase general'
+assert '%f' % float('inf') == 'inf', 'infinity'
+assert '%F' % float('inf') == 'INF', 'uppercase infinity'
+assert '%f' % float('-inf') == '-inf', 'negative infinity'
+assert '%f' % float('nan') == 'nan', 'nan'
+assert '%05f' % float('inf') == '00inf', 'zero padding applies to infinity'
+
+# === %c ===
+assert '%c' % 65 == 'A', 'int code point'
+assert '%c' % 'z' == 'z', 'single character string'
+assert '%3c|' % 'z' == '  z|', 'width with %c'
+
+# === * width and precision ===
+assert '%*d' % (5, 42) == '   42', 'star width'
+assert '%*s|' % (-5, 'a') == 'a    |', 'negative star width left-justifies'
+assert '%.*f' % (2, 3.14159) == '3.14', 'star precision'
+assert '%*.*f' % (8, 2, 3.14159) == '    3.14', 'star width and precision'
+
+# === Mapping-like right operands ===
+assert 'abc' % [] == 'abc', 'unused list argument is allowed'
+assert 'abc' % {} == 'abc', 'unused dict argument is allowed'
+
+# === Named fields ===
+person = {'name': 'Ann', 'age': 30}
+assert '%(name)s is %(age)d' % person == 'Ann is 30', 'named fields'
+assert '%(name)s %(name)r' % person == "Ann 'Ann'", 'same key twice'
+assert '%(age)5d|' % person == '   30|', 'named field with width'
+assert '%(age)-5d|' % person == '30   |', 'named field left-justified'
+assert '%(age)05.1f' % person == '030.0', 'named field with zero padding and precision'
+assert '%(name).2s' % person == 'An', 'named field with precision'
+assert '%(age)+x' % person == '+1e', 'named field with sign flag'
+assert '%(a(b))s' % {'a(b)': 1} == '1', 'nested parentheses in the key'
+assert '%()s' % {'': 2} == '2', 'empty key'
+assert '%(name)s %%' % person == 'Ann %', 'literal percent with named fields'
+assert '%s %(age)d' % person == "{'name': 'Ann', 'age': 30} 30", 'unkeyed field uses the whole dict'
+
+try:
+    '%(missing)s' % person
+    assert False, 'missing key should raise KeyError'
+except KeyError as e:
+    assert e.args == ('missing',), f'missing key error: {e.args}'
+
+try:
+    '%(name)s' % ('Ann',)
+    assert False, 'named field with a tuple should raise TypeError'
+except TypeError as e:
+    assert str(e) == 'format requires a mapping', f'non-mapping error: {e}'
+
+try:
+    '%(name)s %s' % person
+    assert False, 'unkeyed field after a named one should raise TypeError'
+except TypeError as e:
+    assert str(e).startswith('not enough arguments for format string'), f'mixed fields error: {e}'
+
+try:
+    '%(name' % person
+    assert False, 'unclosed key should raise ValueError'
+except ValueError as e:
+    assert str(e) == 'incomplete format key', f'unclosed key error: {e}'
+
+# === %= ===
+s = 'x=%d'
+s %= 5
+assert s == 'x=5', 'in-place formatting'
+
+# === Errors ===
+# Newer CPython versions append argument counts/positions to some of these messages,
+# so those are checked by prefix or suffix only.
+try:
+    '%s %s' % ('a',)
+    assert False, 'too few arguments should raise TypeError'
+except TypeError as e:
+    assert str(e).startswith('not enough arguments for format string'), f'too few arguments error: {e}'
+
+try:
+    '%s' % ('a', 'b')
+    assert False, 'too many arguments should raise TypeError'
+except TypeError as e:
+    assert str(e).startswith('not all arguments converted during string formatting'), f'too many arguments error: {e}'
+
+try:
+    'abc' % 5
+    assert False, 'unused int argument should raise TypeError'
+except TypeError as e:
+    assert str(e).startswith('not all arguments converted during string formatting'), f'unused argument error: {e}'
+
+try:
+    '%d' % 'x'
+    assert False, '%d with str should raise TypeError'
+except TypeError as e:
+    assert str(e).endswith('%d format: a real number is required, not str'), f'%d type error: {e}'
+
+try:
+    '%x' % 1.5
+    assert False, '%x with float should raise TypeError'
+except TypeError as e:
+    assert str(e).endswith('%x format: an integer is required, not float'), f'%x type error: {e}'
+
+try:
+    '%f' % 'x'
+    assert False, '%f with str should raise TypeError'
+except TypeError as e:
+    assert str(e).endswith('must be real number, not str'), f'%f type error: {e}'
+
+try:
+    '%*d' % ('x', 1)
+    assert False, 'non-int star width should raise TypeError'
+except TypeError as e:
+    assert str(e).endswith('* wants int'), f'star width error: {e}'
+
+try:
+    '%d' % float('nan')
+    assert False, '%d with nan should raise ValueError'
+except ValueError as e:
+    assert str(e) == 'cannot convert float NaN to integer', f'%d nan error: {e}'
+
+try:
+    '%c' % 0x110000
+    assert False, '%c out of range should raise OverflowError'
+except OverflowError as e:
+    assert str(e).endswith('%c arg not in range(0x110000)'), f'%c range error: {e}'
+
+try:
+    'abc %' % ()
+    assert False, 'trailing percent should raise ValueError'
+except ValueError:
+    pass
+
+try:
+    '%q' % 1
+    assert False, 'unknown conversion should raise ValueError'
+except ValueError:
+    pass
+
+try:
+    5 % 'x'
+    assert False, 'int % str should raise TypeError'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for %: 'int' and 'str'", f'int % str error: {e}'
@@ -0,0 +1,44 @@
+# === short escapes ===
+assert repr('tab\there\n') == "'tab\\there\\n'", 'tab and newline'
+assert repr('\r\n') == "'\\r\\n'", 'carriage return'
+assert repr('\\') == "'\\\\'", 'backslash'
+
+# === quote choice ===
+assert repr("'") == '"\'"', 'single quote uses double quotes'
+assert repr('"') == '\'"\'', 'double quote uses single quotes'
+assert repr('\'"') == '\'\\\'"\'', 'both quotes escapes single quote'
+assert repr("it's\n") == '"it\'s\\n"', 'double quotes still escape newline'
+
+# === other control characters use \x ===
+assert repr('\x00') == "'\\x00'", 'null'
+assert repr('\x07\x08') == "'\\x07\\x08'", 'bell and backspace'
+assert repr('\x0b\x0c') == "'\\x0b\\x0c'", 'vertical tab and form feed'
+assert repr('\x1b[0m') == "'\\x1b[0m'", 'escape'
+assert repr('\x7f') == "'\\x7f'", 'delete'
+assert repr('\x80\x9f') == "'\\x80\\x9f'", 'C1 controls'
+assert repr('\xa0') == "'\\xa0'", 'no-break space'
+assert repr('\xad') == "'\\xad'", 'soft hyphen'
+
+# === non-printable characters above 0xff use \u and \U ===
+assert repr('\u200b') == "'\\u200b'", 'zero width space'
+assert repr('\u2028\u2029') == "'\\u2028\\u2029'", 'line and paragraph separators'
+assert repr('\u3000') == "'\\u3000'", 'ideographic space'
+assert repr('\ufeff') == "'\\ufeff'", 'byte order mark'
+assert repr('\ue000') == "'\\ue000'", 'private use'
+assert repr('\uffff') == "'\\uffff'", 'noncharacter'
+assert repr('\U000e0001') == "'\\U000e0001'", 'language tag'
+assert repr('\U0010fffd') == "'\\U0010fffd'", 'supplementary private use'
+
+# === printable characters are kept ===
+assert repr(' ') == "' '", 'space'
+assert repr('café') == "'café'", 'latin accent'
+assert repr('\xe9') == "'é'", 'latin-1 letter'
+assert repr('日本') == "'日本'", 'CJK'
+assert repr('\U0001f600') == "'\U0001f600'", 'emoji'
+assert len(repr('\U0001f600')) == 3, 'emoji is one character'
+
+# === escapes in containers and other contexts ===
+assert repr(['\x00', 'a\tb']) == "['\\x00', 'a\\tb']", 'list items'
+assert repr({'\n': '\u200b'}) == "{'\\n': '\\u200b'}", 'dict items'
+assert f'{chr(1)!r}' == "'\\x01'", 'f-string !r'
+assert '%r' % '\x1f' == "'\\x1f'", 'percent r'
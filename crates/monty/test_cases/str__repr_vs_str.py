@@ -0,0 +1,41 @@
+# === str() returns the string itself ===
+assert str('a') == 'a', 'str of simple string'
+assert str('') == '', 'str of empty string'
+assert str("it's") == "it's", 'str keeps single quote'
+assert str('tab\there') == 'tab\there', 'str keeps tab'
+assert str('line\n') == 'line\n', 'str keeps newline'
+assert str('back\\slash') == 'back\\slash', 'str keeps backslash'
+assert len(str('a\nb')) == 3, 'str does not add escapes'
+
+# === repr() adds quotes and escapes ===
+assert repr('a') == "'a'", 'repr of simple string'
+assert repr('') == "''", 'repr of empty string'
+assert repr('tab\there') == "'tab\\there'", 'repr escapes tab'
+assert repr('line\n') == "'line\\n'", 'repr escapes newline'
+assert repr('cr\r') == "'cr\\r'", 'repr escapes carriage return'
+assert repr('back\\slash') == "'back\\\\slash'", 'repr escapes backslash'
+assert len(repr('a\nb')) == 6, 'repr newline is two characters'
+
+# === repr() picks the quote character like CPython ===
+assert repr("it's") == '"it\'s"', 'single quote inside uses double quotes'
+assert repr('say "hi"') == '\'say "hi"\'', 'double quote inside uses single quotes'
+assert repr('it\'s "x"') == '\'it\\\'s "x"\'', 'both quotes escapes single quote'
+assert repr("'") == '"\'"', 'lone single quote'
+assert repr('"') == '\'"\'', 'lone double quote'
+
+# === str() and repr() of containers use repr() of items ===
+assert str(['a', "it's"]) == '[\'a\', "it\'s"]', 'str of list uses item repr'
+assert str(('x',)) == "('x',)", 'str of tuple uses item repr'
+assert str({'k': 'v'}) == "{'k': 'v'}", 'str of dict uses item repr'
+assert str(['a\n']) == "['a\\n']", 'str of list escapes items'
+assert repr(['a']) == str(['a']), 'repr and str of list agree'
+
+# === formatting ===
+s = 'hi'
+assert f'{s}' == 'hi', 'f-string uses str'
+assert f'{s!r}' == "'hi'", 'f-string !r uses repr'
+assert f'{s!s}' == 'hi', 'f-string !s uses str'
+assert '%s' % s == 'hi', '%s uses str'
+assert '%r' % s == "'hi'", '%r uses repr'
+assert str(repr(s)) == "'hi'", 'str of repr'
+assert repr(repr(s)) == '"\'hi\'"', 'repr of repr'
@@ -0,0 +1,65 @@
+# Literal strings are interned while strings built at runtime live on the heap;
+# both must behave as the same str values.
+
+runtime = ''.join(['he', 'llo'])
+upper = 'HELLO'.lower()
+number = str(42)
+
+# === Equality ===
+assert 'hello' == runtime, 'literal == runtime string'
+assert runtime == 'hello', 'runtime string == literal'
+assert runtime == upper, 'two runtime strings'
+assert not (runtime != 'hello'), 'literal != runtime string'
+assert number == '42', 'str() result == literal'
+assert 'hell' + 'o' == runtime, 'concatenation == runtime string'
+assert runtime != 'hello ', 'different contents are not equal'
+assert '' == ''.join([]), 'empty literal == empty runtime string'
+assert 'é' == ''.join(['é']), 'non-ascii literal == runtime string'
+
+# === Ordering ===
+assert 'abc' < ''.join(['abd']), 'literal < runtime string'
+assert ''.join(['b']) > 'a', 'runtime string > literal'
+assert 'hello' <= runtime and runtime >= 'hello', 'equal strings are <= and >='
+assert sorted([runtime, 'apple', 'zebra'.upper().lower()]) == ['apple', 'hello', 'zebra'], 'mixed sort'
+
+# === Membership ===
+assert runtime in ['x', 'hello'], 'runtime string in a list of literals'
+assert 'hello' in [upper], 'literal in a list of runtime strings'
+assert runtime in ('hello',), 'runtime string in a tuple'
+assert runtime in {'hello': 1}, 'runtime string in a dict'
+assert 'hello' in {upper: 1}, 'literal in a dict with a runtime key'
+assert runtime in {'hello'}, 'runtime string in a set'
+assert 'hello' in frozenset([upper]), 'literal in a frozenset of runtime strings'
+assert ['x', 'hello'].index(runtime) == 1, 'list.index with a runtime string'
+assert ('hello', 'hello').count(upper) == 2, 'tuple.count with a runtime string'
+
+# substring containment in either representation
+assert 'ell' in runtime, 'literal substring of a runtime string'
+assert ''.join(['el', 'l']) in 'hello', 'runtime substring of a literal'
+assert ''.join(['el', 'l']) in runtime, 'runtime substring of a runtime string'
+assert '' in runtime, 'empty string is always contained'
+assert 'xyz' not in runtime, 'missing substring'
+
+try:
+    1 in runtime
+    assert False, 'in <str> with a non-string should raise'
+except TypeError as e:
+    assert str(e) == "'in <string>' requires string as left operand, not int", 'in <str> message'
+
+try:
+    [1] in 'hello'
+    assert False, 'in <str> with a list should raise'
+except TypeError as e:
+    assert str(e) == "'in <string>' requires string as left operand, not list", 'in <str> list message'
+
+# === Identity ===
+# Equal literals share one interned string and aliases share identity, but equal
+# strings built separately at runtime are distinct objects.
+first = 'hello'
+second = 'hello'
+assert first is second, 'equal literals are the same interned string'
+alias = runtime
+assert alias is runtime, 'an alias of a runtime string is identical'
+assert [runtime][0] is runtime, 'a runtime string keeps its identity in a list'
+assert {'k': runtime}['k'] is runtime, 'a runtime string keeps its identity in a dict'
+assert runtime == upper and runtime is not upper, 'equal runtime strings are distinct objects'
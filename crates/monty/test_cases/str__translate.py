@@ -0,0 +1,70 @@
+# === maketrans with two strings ===
+table = str.maketrans('abc', 'xyz')
+assert table == {97: 120, 98: 121, 99: 122}, 'maketrans maps code points'
+assert 'aabbcc'.translate(table) == 'xxyyzz', 'translate with a maketrans table'
+assert 'hello'.translate(table) == 'hello', 'characters not in the table are kept'
+assert ''.translate(table) == '', 'translate empty string'
+
+# === maketrans with a delete set ===
+table = str.maketrans('', '', 'aeiou')
+assert table == {97: None, 101: None, 105: None, 111: None, 117: None}, 'deleted characters map to None'
+assert 'hello world'.translate(table) == 'hll wrld', 'translate deletes characters'
+assert 'abc'.translate(str.maketrans('ab', 'xy', 'c')) == 'xy', 'replace and delete together'
+assert 'ab'.translate(str.maketrans('a', 'b', 'a')) == 'b', 'the delete set wins'
+
+# === maketrans with a dict ===
+table = str.maketrans({'a': 'AA', 'b': None, 99: 'C', 'd': 101})
+assert table == {97: 'AA', 98: None, 99: 'C', 100: 101}, 'string keys become code points'
+assert 'abcd'.translate(table) == 'AACe', 'translate with a dict table'
+assert 'x'.maketrans('x', 'y') == {120: 121}, 'maketrans on an instance'
+
+# === translate with a plain dict ===
+assert 'abc'.translate({ord('a'): 'à', ord('c'): ''}) == 'àb', 'translate with a plain dict'
+assert 'héllo'.translate({ord('é'): 'e'}) == 'hello', 'translate non-ascii'
+assert 'a😀b'.translate({ord('😀'): None}) == 'ab', 'translate supplementary plane'
+assert 'ab'.translate({97: 0x1F600}) == '😀b', 'translate to a code point'
+assert 'ab'.translate({}) == 'ab', 'translate with an empty table'
+assert 'ab'.translate(['x'] * 98) == 'xb', 'translate with a list table'
+
+# === errors ===
+try:
+    str.maketrans('ab', 'c')
+    assert False, 'maketrans with strings of different lengths should raise'
+except ValueError as e:
+    assert str(e) == 'the first two maketrans arguments must have equal length', 'length mismatch message'
+
+try:
+    str.maketrans({'ab': 'c'})
+    assert False, 'maketrans with a long key should raise'
+except ValueError as e:
+    assert str(e) == 'string keys in translate table must be of length 1', 'long key message'
+
+try:
+    str.maketrans({1.5: 'c'})  # pyright: ignore
+    assert False, 'maketrans with a float key should raise'
+except TypeError as e:
+    assert str(e) == 'keys in translate table must be strings or integers', 'bad key message'
+
+try:
+    str.maketrans('a')  # pyright: ignore
+    assert False, 'maketrans with one string should raise'
+except TypeError as e:
+    assert str(e) == 'if you give only one argument to maketrans it must be a dict', 'one argument message'
+
+try:
+    str.maketrans('a', 1)  # pyright: ignore
+    assert False, 'maketrans with an int should raise'
+except TypeError as e:
+    assert str(e) == 'maketrans() argument 2 must be str, not int', 'second argument message'
+
+try:
+    'a'.translate({97: 1.5})  # pyright: ignore
+    assert False, 'translate to a float should raise'
+except TypeError as e:
+    assert str(e) == 'character mapping must return integer, None or str', 'bad mapping message'
+
+try:
+    'a'.translate({97: -1})
+    assert False, 'translate to a negative code point should raise'
+except ValueError as e:
+    assert str(e) == 'character mapping must be in range(0x110000)', 'mapping range message'
@@ -0,0 +1,86 @@
+# === List augmented assignment ===
+lst = [1, 2, 3]
+lst[0] += 10
+lst[1] *= 5
+lst[-1] -= 1
+assert lst == [11, 10, 2], 'list augmented subscript assignment'
+
+# === Dict augmented assignment ===
+d = {'a': 1, 'b': 2}
+d['a'] += 1
+d['b'] *= 2
+assert d == {'a': 2, 'b': 4}, 'dict augmented subscript assignment'
+
+# === Frequency counting ===
+counts = {}
+for word in ['x', 'y', 'x', 'z', 'x', 'y']:
+    if word not in counts:
+        counts[word] = 0
+    counts[word] += 1
+assert counts == {'x': 3, 'y': 2, 'z': 1}, 'frequency counting idiom'
+
+# === String and list values ===
+names = {'greeting': 'hello'}
+names['greeting'] += ' world'
+assert names['greeting'] == 'hello world', 'str += via subscript'
+
+nested = [[1], [2]]
+alias = nested[0]
+nested[0] += [3]
+assert nested == [[1, 3], [2]], 'list += via subscript'
+assert alias is nested[0], 'list += via subscript mutates in place'
+
+# === Other operators ===
+nums = [7, 7, 7, 7, 7, 7]
+nums[0] //= 2
+nums[1] %= 4
+nums[2] **= 2
+nums[3] &= 3
+nums[4] |= 8
+nums[5] <<= 1
+assert nums == [3, 3, 49, 3, 15, 14], 'other augmented operators via subscript'
+
+# === Index expression evaluated once ===
+calls = []
+
+
+def index(i):
+    calls.append(i)
+    return i
+
+
+lst = [0, 0, 0]
+lst[index(1)] += 5
+assert lst == [0, 5, 0], 'side-effecting index updates the right element'
+assert calls == [1], 'index expression evaluated exactly once'
+
+# === Value evaluated after the current item is loaded ===
+order = []
+
+
+d = {'k': 1}
+
+
+def value():
+    order.append('value')
+    d['k'] = 100
+    return 1
+
+
+d['k'] += value()
+assert d['k'] == 2, 'current item is read before the right-hand side runs'
+assert order == ['value'], 'right-hand side evaluated once'
+
+# === Nested subscripts ===
+grid = [[0, 0], [0, 0]]
+grid[1][0] += 3
+assert grid == [[0, 0], [3, 0]], 'nested subscript augmented assignment'
+
+# === Augmented assignment inside a function ===
+def bump(container, key):
+    container[key] += 1
+    return container
+
+
+assert bump({'n': 1}, 'n') == {'n': 2}, 'augmented subscript on a parameter'
+assert bump([4, 5], 1) == [4, 6], 'augmented subscript on a list parameter'
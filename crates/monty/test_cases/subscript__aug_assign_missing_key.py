@@ -0,0 +1,3 @@
+d = {'a': 1}
+d['missing'] += 1
+# Raise=KeyError('missing')
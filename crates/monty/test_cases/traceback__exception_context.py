@@ -0,0 +1,26 @@
+def parse(text):
+    try:
+        return int(text)
+    except ValueError:
+        raise RuntimeError('not a number')
+
+
+parse('x')
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "traceback__exception_context.py", line 3, in parse
+    return int(text)
+           ~~~~~~~~~
+ValueError: invalid literal for int() with base 10: 'x'
+
+During handling of the above exception, another exception occurred:
+
+Traceback (most recent call last):
+  File "traceback__exception_context.py", line 8, in <module>
+    parse('x')
+    ~~~~~~~~~~
+  File "traceback__exception_context.py", line 5, in parse
+    raise RuntimeError('not a number')
+RuntimeError: not a number
+"""
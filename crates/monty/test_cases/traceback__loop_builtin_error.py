@@ -0,0 +1,20 @@
+def accumulate(rows):
+    total = 0
+    for row in rows:
+        for item in row:
+            total += abs(item)
+    return total
+
+
+accumulate([[1, -2], [3, 'x']])
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "traceback__loop_builtin_error.py", line 9, in <module>
+    accumulate([[1, -2], [3, 'x']])
+    ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+  File "traceback__loop_builtin_error.py", line 5, in accumulate
+    total += abs(item)
+             ~~~~~~~~~
+TypeError: bad operand type for abs(): 'str'
+"""
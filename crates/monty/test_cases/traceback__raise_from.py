@@ -0,0 +1,34 @@
+def check(value):
+    if value < 0:
+        raise ValueError('negative value')
+    return value
+
+
+def load(value):
+    try:
+        return check(value)
+    except ValueError as e:
+        raise RuntimeError('could not load') from e
+
+
+load(-1)
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "traceback__raise_from.py", line 9, in load
+    return check(value)
+           ~~~~~~~~~~~~
+  File "traceback__raise_from.py", line 3, in check
+    raise ValueError('negative value')
+ValueError: negative value
+
+The above exception was the direct cause of the following exception:
+
+Traceback (most recent call last):
+  File "traceback__raise_from.py", line 14, in <module>
+    load(-1)
+    ~~~~~~~~
+  File "traceback__raise_from.py", line 11, in load
+    raise RuntimeError('could not load') from e
+RuntimeError: could not load
+"""
@@ -0,0 +1,30 @@
+def c(value):
+    return int(value)
+
+
+def b(value):
+    return c(value) + 1
+
+
+def a():
+    return b('not a number')
+
+
+a()
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "traceback__three_frames.py", line 13, in <module>
+    a()
+    ~~~
+  File "traceback__three_frames.py", line 10, in a
+    return b('not a number')
+           ~~~~~~~~~~~~~~~~~
+  File "traceback__three_frames.py", line 6, in b
+    return c(value) + 1
+           ~~~~~~~~
+  File "traceback__three_frames.py", line 2, in c
+    return int(value)
+           ~~~~~~~~~~
+ValueError: invalid literal for int() with base 10: 'not a number'
+"""
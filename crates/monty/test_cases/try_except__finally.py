@@ -0,0 +1,297 @@
+# === finally runs on every way out of the try body ===
+log = []
+
+
+def fall_through():
+    try:
+        log.append('try')
+    finally:
+        log.append('finally')
+    log.append('after')
+
+
+fall_through()
+assert log == ['try', 'finally', 'after'], 'normal fall-through'
+
+log = []
+
+
+def returns():
+    try:
+        log.append('try')
+        return 'from try'
+    finally:
+        log.append('finally')
+
+
+assert returns() == 'from try', 'the try return value is kept'
+assert log == ['try', 'finally'], 'finally runs before returning'
+
+log = []
+for i in range(3):
+    try:
+        log.append(i)
+        break
+    finally:
+        log.append('finally')
+assert log == [0, 'finally'], 'break'
+
+log = []
+for i in range(2):
+    try:
+        log.append(i)
+        continue
+    finally:
+        log.append('finally')
+assert log == [0, 'finally', 1, 'finally'], 'continue'
+
+log = []
+try:
+    try:
+        log.append('try')
+        raise ValueError('boom')
+    finally:
+        log.append('finally')
+except ValueError as e:
+    assert str(e) == 'boom', 'the exception propagates after finally'
+assert log == ['try', 'finally'], 'exception'
+
+# === finally runs on every way out of a handler or else block ===
+log = []
+
+
+def return_in_handler():
+    try:
+        raise KeyError('k')
+    except KeyError:
+        return 'from except'
+    finally:
+        log.append('finally')
+
+
+assert return_in_handler() == 'from except', 'return from a handler'
+assert log == ['finally'], 'finally after a handler return'
+
+log = []
+
+
+def return_in_else():
+    try:
+        log.append('try')
+    except ValueError:
+        log.append('except')
+    else:
+        return 'from else'
+    finally:
+        log.append('finally')
+
+
+assert return_in_else() == 'from else', 'return from else'
+assert log == ['try', 'finally'], 'finally after an else return'
+
+log = []
+for i in range(3):
+    try:
+        log.append(i)
+    except ValueError:
+        pass
+    else:
+        if i == 1:
+            break
+    finally:
+        log.append('finally')
+assert log == [0, 'finally', 1, 'finally'], 'break from else'
+
+log = []
+try:
+    try:
+        raise ValueError('first')
+    except ValueError:
+        raise TypeError('second')
+    finally:
+        log.append('finally')
+except TypeError as e:
+    assert type(e.__context__) == ValueError, 'a handler error propagates after finally'
+assert log == ['finally'], 'finally after an error in a handler'
+
+# === return in finally overrides ===
+
+
+def override_return():
+    try:
+        return 'try'
+    finally:
+        return 'finally'
+
+
+assert override_return() == 'finally', 'return in finally overrides return in try'
+
+
+def override_exception():
+    try:
+        raise ValueError('discarded')
+    finally:
+        return 'finally'
+
+
+assert override_exception() == 'finally', 'return in finally discards the exception'
+
+
+def override_handler_exception():
+    try:
+        raise ValueError('first')
+    except ValueError:
+        raise TypeError('second')
+    finally:
+        return 'finally'
+
+
+assert override_handler_exception() == 'finally', 'return in finally discards a handler exception'
+
+log = []
+
+
+def override_runs_finally_once():
+    try:
+        raise ValueError('discarded')
+    finally:
+        log.append('finally')
+        return len(log)
+
+
+assert override_runs_finally_once() == 1, 'finally runs once'
+assert log == ['finally'], 'finally ran once'
+
+
+def nested_override():
+    try:
+        try:
+            return 'inner try'
+        finally:
+            return 'inner finally'
+    finally:
+        log.append('outer')
+
+
+log = []
+assert nested_override() == 'inner finally', 'inner finally return'
+assert log == ['outer'], 'the outer finally still runs'
+
+
+def conditional_override(flag):
+    try:
+        return 'try'
+    finally:
+        if flag:
+            return 'finally'
+
+
+assert conditional_override(True) == 'finally', 'conditional override taken'
+assert conditional_override(False) == 'try', 'conditional override not taken'
+
+# a discarded exception does not leak into later code
+try:
+    raise
+except RuntimeError as e:
+    assert str(e) == 'No active exception to reraise', 'no exception is active'
+
+try:
+    raise KeyError('fresh')
+except KeyError as e:
+    assert e.__context__ is None, 'the discarded exception is not a context'
+
+# === break and continue in finally discard the exception ===
+log = []
+for i in range(3):
+    try:
+        raise ValueError(i)
+    finally:
+        log.append(i)
+        break
+assert log == [0], 'break in finally'
+
+log = []
+for i in range(3):
+    try:
+        raise ValueError(i)
+    finally:
+        log.append(i)
+        continue
+assert log == [0, 1, 2], 'continue in finally'
+
+log = []
+for i in range(2):
+    try:
+        log.append('try')
+        break
+    finally:
+        log.append('finally')
+        continue
+assert log == ['try', 'finally', 'try', 'finally'], 'continue in finally overrides break'
+
+# === loops and handlers inside finally ===
+log = []
+try:
+    try:
+        raise ValueError('pending')
+    finally:
+        for i in range(3):
+            if i == 1:
+                break
+            log.append(i)
+        try:
+            raise KeyError('handled in finally')
+        except KeyError:
+            log.append('handled')
+except ValueError as e:
+    assert str(e) == 'pending', 'the pending exception is re-raised'
+assert log == [0, 'handled'], 'loops and handlers inside finally'
+
+
+def loop_return_in_finally():
+    try:
+        raise ValueError('pending')
+    finally:
+        for i in range(3):
+            if i == 2:
+                return i
+
+
+assert loop_return_in_finally() == 2, 'return from a loop in finally'
+
+# === errors inside finally ===
+log = []
+try:
+    try:
+        log.append('try')
+    finally:
+        log.append('finally')
+        raise KeyError('from finally')
+except KeyError:
+    pass
+assert log == ['try', 'finally'], 'an error in finally propagates'
+
+log = []
+
+
+def error_in_finally_after_return():
+    try:
+        return 'lost'
+    finally:
+        log.append('finally')
+        raise KeyError('from finally')
+
+
+try:
+    error_in_finally_after_return()
+except KeyError as e:
+    assert str(e) == "'from finally'", 'an error in finally replaces the return'
+assert log == ['finally'], 'finally runs once when it raises'
+
+try:
+    try:
+        raise ValueError('pending')
+    finally:
+        raise TypeError('from finally')
+except TypeError as e:
+    assert type(e.__context__) == ValueError, 'the pending exception is the context'
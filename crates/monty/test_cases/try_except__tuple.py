@@ -0,0 +1,78 @@
+# === A tuple clause matches any of its types ===
+def catch(exc):
+    try:
+        raise exc
+    except (ValueError, KeyError) as e:
+        return type(e)
+    except Exception:
+        return None
+
+
+assert catch(ValueError('v')) == ValueError, 'first type in the tuple'
+assert catch(KeyError('k')) == KeyError, 'second type in the tuple'
+assert catch(TypeError('t')) is None, 'a type not in the tuple'
+
+try:
+    int('x')
+except (KeyError, ValueError) as e:
+    assert str(e) == "invalid literal for int() with base 10: 'x'", 'the exception is bound with as'
+
+try:
+    [][0]
+except (ZeroDivisionError, LookupError) as e:
+    assert type(e) == IndexError, 'a base class in the tuple matches subclasses'
+
+try:
+    raise ValueError('single')
+except (ValueError,):
+    pass
+
+handlers = (TypeError, ValueError)
+try:
+    raise TypeError('from a variable')
+except handlers as e:
+    assert str(e) == 'from a variable', 'a tuple held in a variable'
+
+# === The empty tuple matches nothing ===
+try:
+    try:
+        raise ValueError('unmatched')
+    except ():
+        assert False, 'the empty tuple should not match'
+except ValueError as e:
+    assert str(e) == 'unmatched', 'the exception propagates past an empty tuple'
+
+# === Invalid items in the tuple ===
+try:
+    try:
+        raise KeyError('k')
+    except (TypeError, (KeyError, ValueError)):
+        assert False, 'nested tuples are not allowed'
+except TypeError as e:
+    assert str(e) == 'catching classes that do not inherit from BaseException is not allowed', 'nested tuple'
+    assert type(e.__context__) == KeyError, 'the original exception is the context'
+
+try:
+    try:
+        raise ValueError('v')
+    except (ValueError, int):
+        assert False, 'every item is checked before matching'
+except TypeError as e:
+    assert str(e) == 'catching classes that do not inherit from BaseException is not allowed', 'non-exception item'
+    assert type(e.__context__) == ValueError, 'context of the invalid item error'
+
+try:
+    try:
+        raise ValueError('v')
+    except (KeyError, 'ValueError'):
+        assert False, 'a string is not an exception type'
+except TypeError as e:
+    assert str(e) == 'catching classes that do not inherit from BaseException is not allowed', 'string item'
+
+try:
+    try:
+        raise ValueError('v')
+    except [ValueError]:
+        assert False, 'a list is not a tuple of types'
+except TypeError as e:
+    assert str(e) == 'catching classes that do not inherit from BaseException is not allowed', 'list of types'
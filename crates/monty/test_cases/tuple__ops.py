@@ -29,6 +29,16 @@ assert ((1, 2), (3, 4)) == ((1, 2), (3, 4)), 'nested tuple'
 # === Tuple repr/str ===
 assert repr((1, 2)) == '(1, 2)', 'tuple repr'
 assert str((1, 2)) == '(1, 2)', 'tuple str'
+assert repr(()) == '()', 'empty tuple repr'
+assert repr((1,)) == '(1,)', 'single item tuple repr has a trailing comma'
+assert str(('a',)) == "('a',)", 'single item tuple str'
+assert repr((1, 2, 3)) == '(1, 2, 3)', 'no trailing comma for longer tuples'
+assert repr(((1,),)) == '((1,),)', 'nested single item tuples'
+assert repr(((), (1,), (1, 2))) == '((), (1,), (1, 2))', 'nested tuples of each length'
+assert repr([(1,)]) == '[(1,)]', 'single item tuple in a list'
+assert repr({'k': (None,)}) == "{'k': (None,)}", 'single item tuple in a dict'
+assert f'{(1,)}' == '(1,)', 'single item tuple in an f-string'
+assert repr(tuple([5])) == '(5,)', 'single item tuple built at runtime'
 
 # === Tuple concatenation (+) ===
 assert (1, 2) + (3, 4) == (1, 2, 3, 4), 'tuple add basic'
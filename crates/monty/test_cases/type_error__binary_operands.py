@@ -0,0 +1,107 @@
+# Unsupported operands raise TypeError naming the operator and both operand types
+
+
+def binary_error(op, a, b):
+    try:
+        if op == '+':
+            a + b
+        elif op == '-':
+            a - b
+        elif op == '*':
+            a * b
+        elif op == '/':
+            a / b
+        elif op == '//':
+            a // b
+        elif op == '%':
+            a % b
+        elif op == '**':
+            a**b
+        elif op == '&':
+            a & b
+        elif op == '|':
+            a | b
+        elif op == '^':
+            a ^ b
+        elif op == '<<':
+            a << b
+        elif op == '>>':
+            a >> b
+    except TypeError as e:
+        return str(e)
+    return 'no error'
+
+
+def inplace_error(op, a, b):
+    try:
+        if op == '+=':
+            a += b
+        elif op == '-=':
+            a -= b
+        elif op == '*=':
+            a *= b
+        elif op == '/=':
+            a /= b
+        elif op == '//=':
+            a //= b
+        elif op == '%=':
+            a %= b
+        elif op == '**=':
+            a **= b
+        elif op == '&=':
+            a &= b
+        elif op == '|=':
+            a |= b
+        elif op == '^=':
+            a ^= b
+        elif op == '<<=':
+            a <<= b
+        elif op == '>>=':
+            a >>= b
+    except TypeError as e:
+        return str(e)
+    return 'no error'
+
+
+# === Binary operators ===
+for op in ['-', '/', '//', '&', '|', '^', '<<', '>>']:
+    assert binary_error(op, 'a', 1) == f"unsupported operand type(s) for {op}: 'str' and 'int'", f'str {op} int'
+    assert binary_error(op, 1, 'a') == f"unsupported operand type(s) for {op}: 'int' and 'str'", f'int {op} str'
+    assert binary_error(op, None, 1.5) == f"unsupported operand type(s) for {op}: 'NoneType' and 'float'", op
+
+assert binary_error('+', 1, 'a') == "unsupported operand type(s) for +: 'int' and 'str'", 'int + str'
+assert binary_error('+', None, None) == "unsupported operand type(s) for +: 'NoneType' and 'NoneType'", 'None + None'
+assert binary_error('+', 'a', 1) == 'can only concatenate str (not "int") to str', 'str + int'
+assert binary_error('*', None, 2.5) == "unsupported operand type(s) for *: 'NoneType' and 'float'", 'None * float'
+assert binary_error('%', 1, 'a') == "unsupported operand type(s) for %: 'int' and 'str'", 'int % str'
+assert binary_error('%', [1], 1.5) == "unsupported operand type(s) for %: 'list' and 'float'", 'list % float'
+assert binary_error('**', 'a', 1) == "unsupported operand type(s) for ** or pow(): 'str' and 'int'", 'str ** int'
+assert binary_error('**', 1, None) == "unsupported operand type(s) for ** or pow(): 'int' and 'NoneType'", 'int ** None'
+assert binary_error('//', 10**30, 'a') == "unsupported operand type(s) for //: 'int' and 'str'", 'big int // str'
+assert binary_error('&', 10**30, 1.5) == "unsupported operand type(s) for &: 'int' and 'float'", 'big int & float'
+
+# sequence repetition needs an int
+assert binary_error('*', 'a', 1.5) == "can't multiply sequence by non-int of type 'float'", 'str * float'
+assert binary_error('*', 1.5, [1]) == "can't multiply sequence by non-int of type 'float'", 'float * list'
+assert binary_error('*', (1,), None) == "can't multiply sequence by non-int of type 'NoneType'", 'tuple * None'
+assert binary_error('*', [1], [2]) == "can't multiply sequence by non-int of type 'list'", 'list * list'
+assert binary_error('*', b'a', 'b') == "can't multiply sequence by non-int of type 'str'", 'bytes * str'
+
+# === In-place operators ===
+for op in ['-=', '/=', '//=', '%=', '&=', '|=', '^=', '<<=', '>>=']:
+    assert inplace_error(op, 1, 'a') == f"unsupported operand type(s) for {op}: 'int' and 'str'", f'int {op} str'
+    assert inplace_error(op, None, 1.5) == f"unsupported operand type(s) for {op}: 'NoneType' and 'float'", op
+
+assert inplace_error('+=', 1, 'a') == "unsupported operand type(s) for +=: 'int' and 'str'", 'int += str'
+assert inplace_error('+=', 'a', 1) == 'can only concatenate str (not "int") to str', 'str += int'
+assert inplace_error('*=', None, 2) == "unsupported operand type(s) for *=: 'NoneType' and 'int'", 'None *= int'
+assert inplace_error('*=', [1], 1.5) == "can't multiply sequence by non-int of type 'float'", 'list *= float'
+assert inplace_error('**=', 'a', 1) == "unsupported operand type(s) for **=: 'str' and 'int'", 'str **= int'
+assert inplace_error('|=', {1}, [2]) == "unsupported operand type(s) for |=: 'set' and 'list'", 'set |= list'
+assert inplace_error('//=', 'a', 1) == "unsupported operand type(s) for //=: 'str' and 'int'", 'str //= int'
+
+# === Modulo compared with a constant ===
+try:
+    None % 2 == 0
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for %: 'NoneType' and 'int'", 'modulo in a comparison'
@@ -151,3 +151,58 @@ assert tail == [], 'single item: tail is empty'
 assert a == 1, 'bracket syntax: a'
 assert b == [2, 3], 'bracket syntax: b'
 assert c == 4, 'bracket syntax: c'
+
+# Star unpacking from a range
+first, *rest = range(4)
+assert first == 0, 'star from range: first'
+assert rest == [1, 2, 3], 'star from range: rest is list'
+
+# Star unpacking from dict keys
+first, *others = {'x': 1, 'y': 2, 'z': 3}
+assert first == 'x', 'star from dict: first key'
+assert others == ['y', 'z'], 'star from dict: remaining keys'
+
+# Star inside a nested target
+a, (b, *c) = 1, (2, 3, 4)
+assert a == 1, 'nested star: a'
+assert b == 2, 'nested star: b'
+assert c == [3, 4], 'nested star: c'
+
+# Nested target after a star
+*init, (x, y) = [1, 2, (3, 4)]
+assert init == [1, 2], 'star before nested target: init'
+assert (x, y) == (3, 4), 'star before nested target: x, y'
+
+# Star target gets a fresh list each time
+src = [1, 2, 3]
+_, *copy1 = src
+_, *copy2 = src
+assert copy1 == copy2, 'star results equal'
+assert copy1 is not copy2, 'star results are distinct lists'
+
+# Star unpacking in a for loop target
+pairs = []
+for head, *tail in [[1, 2, 3], [4], [5, 6]]:
+    pairs.append((head, tail))
+assert pairs == [(1, [2, 3]), (4, []), (5, [6])], 'star in for target'
+
+# Unpacking an iterator stops one item past the targets
+it = iter([1, 2, 3, 4, 5])
+try:
+    a, b = it
+    assert False, 'too many values from an iterator should raise'
+except ValueError as e:
+    assert str(e) == 'too many values to unpack (expected 2)', 'iterator too many message'
+assert list(it) == [4, 5], 'only one extra item is consumed'
+
+try:
+    a, b = range(10**18)
+    assert False, 'too many values from a huge range should raise'
+except ValueError as e:
+    assert str(e) == 'too many values to unpack (expected 2)', 'huge range is not collected'
+
+try:
+    a, b, c = {1: 2}
+    assert False, 'not enough values from a dict should raise'
+except ValueError as e:
+    assert str(e) == 'not enough values to unpack (expected 3, got 1)', 'dict not enough message'
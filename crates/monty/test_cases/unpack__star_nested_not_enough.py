@@ -0,0 +1,9 @@
+a, (b, *c, d) = 1, (2,)
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "unpack__star_nested_not_enough.py", line 1, in <module>
+    a, (b, *c, d) = 1, (2,)
+       ~~~~~~~~~~
+ValueError: not enough values to unpack (expected at least 2, got 1)
+"""
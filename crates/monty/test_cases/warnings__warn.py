@@ -0,0 +1,36 @@
+import warnings
+from warnings import warn
+
+# === warn returns None ===
+assert warnings.warn('a message') is None, 'warn returns None'
+assert warn('deprecated', DeprecationWarning) is None, 'warn with category'
+assert warn('kw', category=RuntimeWarning, stacklevel=2) is None, 'warn with kwargs'
+assert warn(UserWarning('instance')) is None, 'warn with warning instance'
+
+# === warning hierarchy ===
+for category in [UserWarning, DeprecationWarning, RuntimeWarning, FutureWarning]:
+    caught = False
+    try:
+        raise category('boom')
+    except Warning as e:
+        caught = True
+        assert str(e) == 'boom', 'warning message'
+    assert caught, 'warning categories are caught by Warning'
+
+try:
+    raise Warning('base')
+except Exception as e:
+    assert repr(e) == "Warning('base')", 'Warning is an Exception'
+
+# === invalid category ===
+try:
+    warn('bad', ValueError)  # pyright: ignore
+    assert False, 'non-warning category should raise'
+except TypeError as e:
+    assert str(e) == "category must be a Warning subclass, not 'type'", 'category error message'
+
+try:
+    warn('bad', 'UserWarning')  # pyright: ignore
+    assert False, 'non-class category should raise'
+except TypeError as e:
+    assert str(e) == "category must be a Warning subclass, not 'str'", 'category error message for str'
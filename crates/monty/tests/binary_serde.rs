@@ -4,7 +4,10 @@
 //! - Caching parsed code to avoid re-parsing
 //! - Snapshotting execution state for external function calls
 
-use monty::{MontyObject, MontyRun, NoLimitTracker, RunProgress, StdPrint};
+use monty::{
+    ExcType, ExternalResult, FutureSnapshot, LimitedTracker, MontyObject, MontyRun, NoLimitTracker, ResourceLimits,
+    RunProgress, Snapshot, StdPrint,
+};
 
 // === MontyRun dump/load Tests ===
 
@@ -181,3 +184,119 @@ fn run_progress_complete_roundtrip() {
 
     assert_eq!(loaded.into_complete().unwrap(), MontyObject::Int(3));
 }
+
+// === Snapshot and FutureSnapshot dump/load Tests ===
+
+#[test]
+fn snapshot_dump_load_preserves_heap_state() {
+    // Heap values, closures and locals of a suspended function all survive the round trip
+    let code = r"
+def make_counter(start):
+    count = [start]
+    def step():
+        count[0] += 1
+        return count[0]
+    return step
+
+def work(data):
+    step = make_counter(len(data['items']))
+    extra = fetch(step())
+    return data['items'] + [extra, step()]
+
+work({'items': [1, 2, 3], 'name': 'job'})
+";
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![], vec!["fetch".to_owned()]).unwrap();
+
+    let (fn_name, args, _, _, state) = runner
+        .start(vec![], NoLimitTracker, &mut StdPrint)
+        .unwrap()
+        .into_function_call()
+        .expect("should be at function call");
+    assert_eq!(fn_name, "fetch");
+    assert_eq!(args, vec![MontyObject::Int(4)]);
+
+    // Persist the paused state and resume it from the bytes alone
+    let bytes = state.dump().unwrap();
+    drop(state);
+    let loaded: Snapshot<NoLimitTracker> = Snapshot::load(&bytes).unwrap();
+
+    let result = loaded.run(MontyObject::Int(40), &mut StdPrint).unwrap();
+    assert_eq!(
+        result.into_complete().unwrap(),
+        MontyObject::List(vec![
+            MontyObject::Int(1),
+            MontyObject::Int(2),
+            MontyObject::Int(3),
+            MontyObject::Int(40),
+            MontyObject::Int(5),
+        ])
+    );
+}
+
+#[test]
+fn snapshot_dump_load_preserves_tracker_state() {
+    // Allocations made before the pause still count against the limit after loading
+    let code = "x = [[i] for i in range(10)]\nfoo()\ny = [[i] for i in range(10)]\nlen(y)";
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![], vec!["foo".to_owned()]).unwrap();
+
+    let limits = ResourceLimits::new().max_allocations(20);
+    let (_, _, _, _, state) = runner
+        .start(vec![], LimitedTracker::new(limits), &mut StdPrint)
+        .unwrap()
+        .into_function_call()
+        .expect("should be at function call");
+
+    let bytes = state.dump().unwrap();
+    let loaded: Snapshot<LimitedTracker> = Snapshot::load(&bytes).unwrap();
+    assert_eq!(loaded.heap_stats(), state.heap_stats());
+
+    let exc = loaded
+        .run(MontyObject::None, &mut StdPrint)
+        .expect_err("should exceed the allocation limit after loading");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
+#[test]
+fn future_snapshot_dump_load_roundtrip() {
+    let code = r"
+import asyncio
+
+async def main():
+    a, b = await asyncio.gather(foo(), bar())
+    return a + b
+
+await main()
+";
+    let runner = MontyRun::new(
+        code.to_owned(),
+        "test.py",
+        vec![],
+        vec!["foo".to_owned(), "bar".to_owned()],
+    )
+    .unwrap();
+
+    // Defer both external calls so execution pauses waiting on their futures
+    let mut progress = runner.start(vec![], NoLimitTracker, &mut StdPrint).unwrap();
+    let mut call_ids = Vec::new();
+    let state = loop {
+        match progress {
+            RunProgress::FunctionCall { call_id, state, .. } => {
+                call_ids.push(call_id);
+                progress = state.run_pending(&mut StdPrint).unwrap();
+            }
+            RunProgress::ResolveFutures(state) => break state,
+            _ => panic!("expected function calls then ResolveFutures"),
+        }
+    };
+
+    let bytes = state.dump().unwrap();
+    let loaded: FutureSnapshot<NoLimitTracker> = FutureSnapshot::load(&bytes).unwrap();
+    assert_eq!(loaded.pending_call_ids(), state.pending_call_ids());
+
+    let results = vec![
+        (call_ids[0], ExternalResult::Return(MontyObject::Int(10))),
+        (call_ids[1], ExternalResult::Return(MontyObject::Int(32))),
+    ];
+    let progress = loaded.resume(results, &mut StdPrint).unwrap();
+    assert_eq!(progress.into_complete().unwrap(), MontyObject::Int(42));
+}
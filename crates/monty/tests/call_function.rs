@@ -0,0 +1,115 @@
+/// Tests for calling functions returned by Monty code from the host.
+use monty::{ExcType, MontyFunction, MontyObject, MontyRun, NoLimitTracker, StdPrint};
+
+fn function(code: &str) -> MontyFunction<NoLimitTracker> {
+    MontyRun::new(code.to_owned(), "plugin.py", vec![], vec![])
+        .unwrap()
+        .run_to_function(vec![], NoLimitTracker, &mut StdPrint)
+        .unwrap()
+}
+
+#[test]
+fn call_defined_function() {
+    let mut add = function("def add(a, b):\n    return a + b\nadd");
+    let result = add
+        .call(vec![MontyObject::Int(1), MontyObject::Int(2)], vec![], &mut StdPrint)
+        .unwrap();
+    assert_eq!(result, MontyObject::Int(3));
+}
+
+#[test]
+fn call_lambda_with_kwargs() {
+    let mut greet = function("lambda name, greeting='hello': f'{greeting} {name}'");
+    let result = greet
+        .call(
+            vec![MontyObject::String("world".to_owned())],
+            vec![(
+                MontyObject::String("greeting".to_owned()),
+                MontyObject::String("hi".to_owned()),
+            )],
+            &mut StdPrint,
+        )
+        .unwrap();
+    assert_eq!(result, MontyObject::String("hi world".to_owned()));
+}
+
+#[test]
+fn calls_share_state() {
+    let code = "
+counts = []
+
+def record(item):
+    counts.append(item)
+    return len(counts)
+
+record
+";
+    let mut record = function(code);
+    for expected in 1..=3 {
+        let result = record.call(vec![MontyObject::None], vec![], &mut StdPrint).unwrap();
+        assert_eq!(result, MontyObject::Int(expected));
+    }
+}
+
+#[test]
+fn call_closure() {
+    let code = "
+def make_counter(start):
+    count = start
+    def counter():
+        nonlocal count
+        count += 1
+        return count
+    return counter
+
+make_counter(10)
+";
+    let mut counter = function(code);
+    assert_eq!(
+        counter.call(vec![], vec![], &mut StdPrint).unwrap(),
+        MontyObject::Int(11)
+    );
+    assert_eq!(
+        counter.call(vec![], vec![], &mut StdPrint).unwrap(),
+        MontyObject::Int(12)
+    );
+}
+
+#[test]
+fn call_raises_and_can_be_called_again() {
+    let mut invert = function("def invert(x):\n    return 1 / x\ninvert");
+    let err = invert
+        .call(vec![MontyObject::Int(0)], vec![], &mut StdPrint)
+        .unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ZeroDivisionError);
+
+    let result = invert.call(vec![MontyObject::Int(2)], vec![], &mut StdPrint).unwrap();
+    assert_eq!(result, MontyObject::Float(0.5));
+}
+
+#[test]
+fn call_with_wrong_arguments() {
+    let mut add = function("def add(a, b):\n    return a + b\nadd");
+    let err = add.call(vec![MontyObject::Int(1)], vec![], &mut StdPrint).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::TypeError);
+    assert_eq!(err.message(), Some("add() missing 1 required positional argument: 'b'"));
+}
+
+#[test]
+fn call_builtin() {
+    let mut len = function("len");
+    let result = len
+        .call(vec![MontyObject::String("abc".to_owned())], vec![], &mut StdPrint)
+        .unwrap();
+    assert_eq!(result, MontyObject::Int(3));
+}
+
+#[test]
+fn result_not_callable() {
+    let runner = MontyRun::new("42".to_owned(), "plugin.py", vec![], vec![]).unwrap();
+    let err = runner
+        .run_to_function(vec![], NoLimitTracker, &mut StdPrint)
+        .unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::TypeError);
+    assert_eq!(err.message(), Some("'int' object is not callable"));
+}
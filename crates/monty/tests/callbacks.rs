@@ -0,0 +1,70 @@
+//! Tests for builtins that call Python functions where Monty differs from CPython: `map()` and
+//! `filter()` call the function up front rather than lazily, and callbacks can only nest so deep.
+
+use monty::{ExcType, MontyObject, MontyRun};
+
+fn run(code: &str) -> Result<MontyObject, monty::MontyException> {
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    ex.run_no_limits(vec![])
+}
+
+fn ints(values: &[i64]) -> MontyObject {
+    MontyObject::List(values.iter().copied().map(MontyObject::Int).collect())
+}
+
+#[test]
+fn map_calls_function_for_every_item_up_front() {
+    let code = "
+log = []
+def f(x):
+    log.append(x)
+    return x * 10
+m = map(f, [1, 2, 3])
+before = list(log)
+first = next(m)
+(before, first, log)
+";
+    // CPython would give ([], 10, [1])
+    assert_eq!(
+        run(code).unwrap(),
+        MontyObject::Tuple(vec![ints(&[1, 2, 3]), MontyObject::Int(10), ints(&[1, 2, 3])])
+    );
+}
+
+#[test]
+fn filter_tests_every_item_up_front() {
+    let code = "
+log = []
+def f(x):
+    log.append(x)
+    return x > 1
+it = filter(f, [1, 2, 3])
+before = list(log)
+first = next(it)
+(before, first, log)
+";
+    // CPython would give ([], 2, [1, 2])
+    assert_eq!(
+        run(code).unwrap(),
+        MontyObject::Tuple(vec![ints(&[1, 2, 3]), MontyObject::Int(2), ints(&[1, 2, 3])])
+    );
+}
+
+#[test]
+fn recursion_through_callbacks_is_limited() {
+    // CPython allows recursion up to its recursion limit; each level here is a callback
+    // running in a nested `run()`, which Monty limits to protect the Rust stack
+    let code = "
+def depth(x):
+    if isinstance(x, list):
+        return 1 + max(map(depth, x), default=0)
+    return 0
+nested = []
+for _ in range(30):
+    nested = [nested]
+depth(nested)
+";
+    let exc = run(code).unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::RecursionError);
+    assert_eq!(exc.message(), Some("maximum recursion depth exceeded"));
+}
@@ -676,6 +676,23 @@ fn dispatch_os_call(
         return MontyObject::Dict(env_dict.into()).into();
     }
 
+    // The virtual filesystem only supports the path-based API, not file handles from open()
+    if matches!(
+        function,
+        OsFunction::Open
+            | OsFunction::FileRead
+            | OsFunction::FileReadline
+            | OsFunction::FileReadlines
+            | OsFunction::FileWrite
+            | OsFunction::FileClose
+    ) {
+        return MontyException::new(
+            ExcType::NotImplementedError,
+            Some(format!("'{function}' is not supported by the virtual filesystem")),
+        )
+        .into();
+    }
+
     // Extract path from MontyObject::Path (or String for backwards compatibility)
     let path = match &args[0] {
         MontyObject::Path(p) => p.clone(),
@@ -684,7 +701,17 @@ fn dispatch_os_call(
     };
 
     match function {
-        OsFunction::GetEnviron => unreachable!("handled above"),
+        OsFunction::GetEnviron
+        | OsFunction::Open
+        | OsFunction::FileRead
+        | OsFunction::FileReadline
+        | OsFunction::FileReadlines
+        | OsFunction::FileWrite
+        | OsFunction::FileClose => unreachable!("handled above"),
+        OsFunction::Input => {
+            // Virtual stdin has no data, like CPython reading input() at end of file
+            MontyException::new(ExcType::EOFError, Some("EOF when reading a line".to_owned())).into()
+        }
         OsFunction::Exists => {
             let exists = get_virtual_file(&path).is_some() || is_virtual_dir(&path);
             MontyObject::Bool(exists).into()
@@ -983,15 +1010,20 @@ fn try_run_test(path: &Path, code: &str, expectation: &Expectation) -> Result<()
                         counts,
                         unique_refs,
                         heap_count,
+                        cyclic_garbage,
                         ..
                     }) => {
-                        // Strict matching: verify all heap objects are accounted for by variables
-                        if unique_refs != heap_count {
+                        // Strict matching: verify all heap objects are accounted for by variables,
+                        // apart from unreachable cycles which garbage collection would free
+                        if unique_refs + cyclic_garbage != heap_count {
                             return Err(TestFailure {
                                 test_name,
                                 kind: "Strict matching".to_string(),
                                 expected: format!("{heap_count} heap objects"),
-                                actual: format!("{unique_refs} referenced by variables, counts: {counts:?}"),
+                                actual: format!(
+                                    "{unique_refs} referenced by variables and {cyclic_garbage} in unreachable \
+                                     cycles, counts: {counts:?}"
+                                ),
                             });
                         }
                         if &counts != expected {
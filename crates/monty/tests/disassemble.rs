@@ -0,0 +1,141 @@
+//! Tests for `MontyRun::disassemble`.
+//!
+//! The listing format is for humans and not stable, so these tests only check that the
+//! important pieces of information are present rather than matching the whole output.
+
+use monty::MontyRun;
+
+fn disassemble(code: &str) -> String {
+    MontyRun::new(code.to_owned(), "test.py", vec![], vec![])
+        .unwrap()
+        .disassemble()
+}
+
+#[test]
+fn module_and_functions_listed() {
+    let listing = disassemble("def add(a, b):\n    return a + b\n\nadd(1, 2)");
+    assert!(listing.starts_with("Disassembly of <module>:\n"), "{listing}");
+    assert!(listing.contains("\nDisassembly of add:\n"), "{listing}");
+    assert!(listing.contains("MakeFunction"), "{listing}");
+    assert!(listing.contains("BinaryAdd"), "{listing}");
+    assert!(listing.contains("ReturnValue"), "{listing}");
+}
+
+#[test]
+fn names_and_constants_resolved() {
+    let listing = disassemble("x = 'hello'\nx.upper()");
+    let const_line = listing.lines().find(|l| l.contains("LoadConst")).expect(&listing);
+    assert!(const_line.ends_with("('hello')"), "{listing}");
+    let attr_line = listing.lines().find(|l| l.contains("CallAttr")).expect(&listing);
+    assert!(attr_line.ends_with("(upper)"), "{listing}");
+}
+
+#[test]
+fn jump_targets_resolved() {
+    let listing = disassemble("x = 1\nif x:\n    x = 2\nelse:\n    x = 3\nx");
+    let jump_line = listing.lines().find(|l| l.contains("JumpIfFalse")).expect(&listing);
+    assert!(jump_line.contains("(to "), "{listing}");
+}
+
+#[test]
+fn source_lines_shown() {
+    let listing = disassemble("a = 1\nb = 2\na + b");
+    let line_numbers: Vec<&str> = listing
+        .lines()
+        .skip(1)
+        .filter_map(|l| l.get(..4))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    assert_eq!(line_numbers, vec!["1", "2", "3"], "{listing}");
+}
+
+#[test]
+fn builtin_calls_resolved() {
+    let listing = disassemble("len([1, 2, 3])");
+    let call_line = listing
+        .lines()
+        .find(|l| l.contains("CallBuiltinFunction"))
+        .expect(&listing);
+    assert!(call_line.ends_with("(len)"), "{listing}");
+}
+
+#[test]
+fn aug_subscript_evaluates_target_once() {
+    let listing = disassemble("def key():\n    return 'k'\n\nd = {'k': 1}\nd[key()] += 1");
+    let module = listing.split("\nDisassembly of key:").next().unwrap();
+    assert_eq!(
+        module.lines().filter(|l| l.contains("CallFunction")).count(),
+        1,
+        "{listing}"
+    );
+    assert!(module.contains("Dup2"), "{listing}");
+    assert!(module.contains("InplaceAdd"), "{listing}");
+    assert!(module.contains("StoreSubscr"), "{listing}");
+}
+
+#[test]
+fn lt_len_fused() {
+    let listing = disassemble("a = [1, 2]\ni = 0\ni < len(a)");
+    assert!(listing.contains("CompareLtLen"), "{listing}");
+    assert!(!listing.contains("CallBuiltinFunction"), "{listing}");
+}
+
+#[test]
+fn eq_none_uses_identity() {
+    let listing = disassemble("x = 1\nx == None");
+    assert!(listing.contains("CompareIs"), "{listing}");
+    assert!(!listing.contains("CompareEq"), "{listing}");
+
+    let listing = disassemble("x = 1\nx != None");
+    assert!(listing.contains("CompareIsNot"), "{listing}");
+    assert!(!listing.contains("CompareNe"), "{listing}");
+}
+
+#[test]
+fn constants_folded() {
+    let listing = disassemble("60 * 60 * 24");
+    assert!(!listing.contains("BinaryMul"), "{listing}");
+    assert!(listing.contains("86400"), "{listing}");
+
+    let listing = disassemble("'a' + 'b' < 'b'");
+    assert!(!listing.contains("BinaryAdd"), "{listing}");
+    assert!(!listing.contains("CompareLt"), "{listing}");
+}
+
+#[test]
+fn errors_not_folded() {
+    let listing = disassemble("1 / 0");
+    assert!(listing.contains("BinaryDiv"), "{listing}");
+
+    let listing = disassemble("2 ** 1000");
+    assert!(listing.contains("BinaryPow"), "{listing}");
+}
+
+#[test]
+fn dead_branches_dropped() {
+    let listing = disassemble("x = 1\nif False:\n    print(x)\nx");
+    assert!(!listing.contains("JumpIfFalse"), "{listing}");
+    assert!(!listing.contains("CallBuiltinFunction"), "{listing}");
+
+    let listing = disassemble("x = 1\nwhile False:\n    print(x)\nx");
+    assert!(!listing.contains("CallBuiltinFunction"), "{listing}");
+
+    let listing = disassemble("x = 0\nwhile True:\n    x += 1\n    if x > 3:\n        break\nx");
+    assert_eq!(listing.matches("JumpIfFalse").count(), 1, "{listing}");
+}
+
+#[test]
+fn code_after_return_dropped() {
+    let listing = disassemble("def f():\n    return 1\n    print(2)\n\nf()");
+    assert!(!listing.contains("CallBuiltinFunction"), "{listing}");
+}
+
+#[test]
+fn implicit_fstring_concat_merged() {
+    let listing = disassemble("x = 1\nf'{x}a' 'b' f'c{x}'");
+    let const_line = listing.lines().find(|l| l.contains("LoadConst")).expect(&listing);
+    assert!(const_line.ends_with("('abc')"), "{listing}");
+    let build_line = listing.lines().find(|l| l.contains("BuildFString")).expect(&listing);
+    assert!(build_line.ends_with(" 3"), "{listing}");
+}
@@ -4,7 +4,7 @@
 //! and can be used in Python code execution.
 
 use indexmap::IndexMap;
-use monty::{ExcType, MontyObject, MontyRun};
+use monty::{ExcType, MontyObject, MontyRun, NoLimitTracker, NoPrint};
 
 // === Immediate Value Tests ===
 
@@ -57,6 +57,27 @@ fn input_ellipsis() {
     assert_eq!(result, MontyObject::Ellipsis);
 }
 
+#[test]
+fn input_not_implemented() {
+    let ex = MontyRun::new(
+        "x is NotImplemented".to_owned(),
+        "test.py",
+        vec!["x".to_owned()],
+        vec![],
+    )
+    .unwrap();
+    let result = ex.run_no_limits(vec![MontyObject::NotImplemented]).unwrap();
+    assert_eq!(result, MontyObject::Bool(true));
+}
+
+#[test]
+fn output_not_implemented() {
+    let ex = MontyRun::new("NotImplemented".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let result = ex.run_no_limits(vec![]).unwrap();
+    assert_eq!(result, MontyObject::NotImplemented);
+    assert_eq!(result.to_string(), "NotImplemented");
+}
+
 // === Heap-Allocated Value Tests ===
 
 #[test]
@@ -431,3 +452,34 @@ double(2)
     let result = ex.run_no_limits(vec![MontyObject::Int(7)]).unwrap();
     assert_eq!(result, MontyObject::Int(4));
 }
+
+// === Reuse Tests ===
+
+#[test]
+fn runner_reused_with_different_inputs() {
+    let ex = MontyRun::new("x * 2".to_owned(), "test.py", vec!["x".to_owned()], vec![]).unwrap();
+    for x in 0..5 {
+        let progress = ex
+            .start(vec![MontyObject::Int(x)], NoLimitTracker, &mut NoPrint)
+            .unwrap();
+        assert_eq!(progress.into_complete(), Some(MontyObject::Int(x * 2)));
+        assert_eq!(
+            ex.run_no_limits(vec![MontyObject::Int(x)]).unwrap(),
+            MontyObject::Int(x * 2)
+        );
+    }
+}
+
+#[test]
+fn runs_do_not_share_state() {
+    let code = "
+items = []
+items.append(x)
+items
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec!["x".to_owned()], vec![]).unwrap();
+    let first = ex.clone().run_no_limits(vec![MontyObject::Int(1)]).unwrap();
+    let second = ex.run_no_limits(vec![MontyObject::Int(2)]).unwrap();
+    assert_eq!(first, MontyObject::List(vec![MontyObject::Int(1)]));
+    assert_eq!(second, MontyObject::List(vec![MontyObject::Int(2)]));
+}
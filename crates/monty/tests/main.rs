@@ -1,4 +1,4 @@
-use monty::MontyRun;
+use monty::{ExcType, MontyRun};
 
 /// Test we can reuse exec without borrow checker issues.
 #[test]
@@ -26,3 +26,81 @@ fn test_get_interned_string() {
     let int_value: String = r.as_ref().try_into().unwrap();
     assert_eq!(int_value, "foobar");
 }
+
+/// Monty strings can't hold lone surrogates, so `chr()` rejects them where CPython doesn't.
+#[test]
+fn chr_surrogate() {
+    for code in ["chr(0xD800)", "chr(0xDFFF)"] {
+        let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+        let exc = ex.run_no_limits(vec![]).unwrap_err();
+        assert_eq!(exc.exc_type(), ExcType::ValueError);
+        assert_eq!(
+            exc.message(),
+            Some("chr() arg not in range: surrogate code points are not supported")
+        );
+    }
+
+    let ex = MontyRun::new("chr(0xD7FF) + chr(0xE000)".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let r = ex.run_no_limits(vec![]).unwrap();
+    let s: String = r.as_ref().try_into().unwrap();
+    assert_eq!(s, "\u{D7FF}\u{E000}");
+}
+
+/// Monty has no classes, so the three-argument form of `type()` can't create one.
+#[test]
+fn type_three_args_not_supported() {
+    let ex = MontyRun::new("type('A', (), {})".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let exc = ex.run_no_limits(vec![]).unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::NotImplementedError);
+    assert_eq!(exc.message(), Some("creating classes with type() is not supported"));
+}
+
+/// The cause and context of an uncaught exception are kept in the `MontyException`.
+#[test]
+fn exception_chain() {
+    let code = "
+try:
+    try:
+        raise LookupError('first')
+    except LookupError:
+        raise TypeError('second')
+except TypeError as e:
+    raise ValueError('third') from e
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let exc = ex.run_no_limits(vec![]).unwrap_err();
+    assert_eq!(exc.summary(), "ValueError: third");
+    assert!(exc.suppress_context());
+
+    let cause = exc.cause().unwrap();
+    assert_eq!(cause.summary(), "TypeError: second");
+    assert_eq!(cause.traceback().len(), 1);
+    assert!(cause.cause().is_none());
+    assert!(!cause.suppress_context());
+    assert_eq!(cause.context().unwrap().summary(), "LookupError: first");
+    assert_eq!(
+        exc.context().unwrap(),
+        cause,
+        "the handled exception is also the context"
+    );
+
+    let output = exc.to_string();
+    assert!(output.starts_with("Traceback (most recent call last):\n  File \"test.py\", line 4, in <module>"));
+    assert_eq!(output.matches("During handling of the above exception").count(), 1);
+    assert_eq!(output.matches("The above exception was the direct cause").count(), 1);
+    assert!(output.ends_with("ValueError: third"));
+
+    // `from None` hides the context but keeps it
+    let code = "
+try:
+    {}['missing']
+except KeyError:
+    raise LookupError('not found') from None
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let exc = ex.run_no_limits(vec![]).unwrap_err();
+    assert!(exc.cause().is_none());
+    assert!(exc.suppress_context());
+    assert_eq!(exc.context().unwrap().exc_type(), ExcType::KeyError);
+    assert!(!exc.to_string().contains("KeyError"));
+}
@@ -0,0 +1,69 @@
+/// Tests for compiling in optimized mode with `CompileOptions::optimize`, mirroring `python -O`.
+use monty::{CompileOptions, MontyObject, MontyRun};
+
+fn run(code: &str, optimize: bool) -> MontyObject {
+    let options = CompileOptions::new().optimize(optimize);
+    MontyRun::new_with_options(code.to_owned(), "test.py", vec![], vec![], options)
+        .unwrap()
+        .run_no_limits(vec![])
+        .unwrap()
+}
+
+#[test]
+fn debug_constant() {
+    assert_eq!(run("__debug__", false), MontyObject::Bool(true));
+    assert_eq!(run("__debug__", true), MontyObject::Bool(false));
+}
+
+#[test]
+fn default_options_not_optimized() {
+    let runner = MontyRun::new("__debug__".to_owned(), "test.py", vec![], vec![]).unwrap();
+    assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Bool(true));
+}
+
+#[test]
+fn asserts_removed() {
+    let code = "assert False, 'boom'\n'ok'";
+    assert_eq!(run(code, true), MontyObject::String("ok".to_owned()));
+
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let err = runner.run_no_limits(vec![]).unwrap_err();
+    assert_eq!(err.message(), Some("boom"));
+}
+
+#[test]
+fn assert_test_not_evaluated() {
+    let code = "
+calls = []
+def check():
+    calls.append(1)
+    return True
+assert check()
+len(calls)
+";
+    assert_eq!(run(code, false), MontyObject::Int(1));
+    assert_eq!(run(code, true), MontyObject::Int(0));
+}
+
+#[test]
+fn debug_in_function_and_branch() {
+    let code = "
+def mode():
+    if __debug__:
+        return 'debug'
+    return 'optimized'
+mode()
+";
+    assert_eq!(run(code, false), MontyObject::String("debug".to_owned()));
+    assert_eq!(run(code, true), MontyObject::String("optimized".to_owned()));
+}
+
+#[test]
+fn debug_branch_dropped_when_optimized() {
+    let code = "if __debug__:\n    print('checking')\n";
+    let options = CompileOptions::new().optimize(true);
+    let listing = MontyRun::new_with_options(code.to_owned(), "test.py", vec![], vec![], options)
+        .unwrap()
+        .disassemble();
+    assert!(!listing.contains("CallBuiltinFunction"), "{listing}");
+}
@@ -1,10 +1,12 @@
 //! Tests for OS function calls.
 //!
-//! Verifies that Path filesystem methods and os module functions yield
+//! Verifies that Path filesystem methods, os module functions, `input()` and `open()` yield
 //! `RunProgress::OsCall` with the correct `OsFunction` variant and arguments,
 //! and that return values are correctly used by Python code.
 
-use monty::{MontyObject, MontyRun, NoLimitTracker, OsFunction, RunProgress, StdPrint, file_stat};
+use monty::{
+    ExcType, MontyException, MontyObject, MontyRun, NoLimitTracker, OsFunction, RunProgress, StdPrint, file_stat,
+};
 
 /// Helper to run code and extract the OsCall progress.
 ///
@@ -38,6 +40,12 @@ fn run_to_oscall(code: &str) -> (OsFunction, Vec<MontyObject>) {
                 | OsFunction::Rename => MontyObject::None,
                 OsFunction::Getenv => MontyObject::String("mock_env_value".to_owned()),
                 OsFunction::GetEnviron => MontyObject::Dict(vec![].into()),
+                OsFunction::Input | OsFunction::FileRead | OsFunction::FileReadline => {
+                    MontyObject::String("mock line".to_owned())
+                }
+                OsFunction::Open | OsFunction::FileWrite => MontyObject::Int(1),
+                OsFunction::FileReadlines => MontyObject::List(vec![]),
+                OsFunction::FileClose => MontyObject::None,
             };
             let _ = state.run(mock_result, &mut StdPrint);
             (function, args)
@@ -457,3 +465,472 @@ import os
     assert_eq!(func, OsFunction::GetEnviron);
     assert_eq!(result, MontyObject::Bool(true));
 }
+
+// =============================================================================
+// input() tests
+// =============================================================================
+
+#[test]
+fn input_yields_oscall_with_prompt() {
+    let (func, args) = run_to_oscall("input('Name: ')");
+    assert_eq!(func, OsFunction::Input);
+    assert_eq!(args, vec![MontyObject::String("Name: ".to_owned())]);
+}
+
+#[test]
+fn input_default_prompt_is_empty() {
+    let (func, args) = run_to_oscall("input()");
+    assert_eq!(func, OsFunction::Input);
+    assert_eq!(args, vec![MontyObject::String(String::new())]);
+}
+
+#[test]
+fn input_prompt_converted_with_str() {
+    let (func, args) = run_to_oscall("input(42)");
+    assert_eq!(func, OsFunction::Input);
+    assert_eq!(args, vec![MontyObject::String("42".to_owned())]);
+}
+
+#[test]
+fn input_result_used() {
+    let code = r"
+name = input('Name: ')
+'Hello ' + name
+";
+    let (func, _, result) = run_oscall_with_result(code, MontyObject::String("World".to_owned()));
+    assert_eq!(func, OsFunction::Input);
+    assert_eq!(result, MontyObject::String("Hello World".to_owned()));
+}
+
+#[test]
+fn input_eof_error_from_host() {
+    let code = r"
+try:
+    input()
+    result = 'no error'
+except EOFError as e:
+    result = str(e)
+result
+";
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let progress = runner.start(vec![], NoLimitTracker, &mut StdPrint).unwrap();
+    let RunProgress::OsCall { function, state, .. } = progress else {
+        panic!("expected OsCall, got {progress:?}");
+    };
+    assert_eq!(function, OsFunction::Input);
+    let eof = MontyException::new(ExcType::EOFError, Some("EOF when reading a line".to_owned()));
+    let result = state.run(eof, &mut StdPrint).unwrap().into_complete().unwrap();
+    assert_eq!(result, MontyObject::String("EOF when reading a line".to_owned()));
+}
+
+#[test]
+fn input_too_many_args() {
+    let runner = MontyRun::new("input('a', 'b')".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let err = runner.run_no_limits(vec![]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::TypeError);
+    assert_eq!(err.message(), Some("input expected at most 1 argument, got 2"));
+}
+
+#[test]
+fn input_not_supported_by_standard_execution() {
+    let runner = MontyRun::new("input()".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let err = runner.run_no_limits(vec![]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::NotImplementedError);
+    assert_eq!(
+        err.message(),
+        Some("OS function 'input' not implemented with standard execution")
+    );
+}
+
+// =============================================================================
+// open() and file object tests
+// =============================================================================
+
+/// Runs code to completion, answering every OS call with `respond`.
+///
+/// Returns the OS calls made, in order, along with the final value.
+fn run_with_os_host(
+    code: &str,
+    mut respond: impl FnMut(OsFunction, &[MontyObject]) -> Result<MontyObject, MontyException>,
+) -> (Vec<(OsFunction, Vec<MontyObject>)>, Result<MontyObject, MontyException>) {
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut calls = Vec::new();
+    let mut progress = runner.start(vec![], NoLimitTracker, &mut StdPrint);
+    loop {
+        match progress {
+            Ok(RunProgress::OsCall {
+                function, args, state, ..
+            }) => {
+                let result = respond(function, &args);
+                calls.push((function, args));
+                progress = match result {
+                    Ok(value) => state.run(value, &mut StdPrint),
+                    Err(exc) => state.run(exc, &mut StdPrint),
+                };
+            }
+            Ok(RunProgress::Complete(value)) => return (calls, Ok(value)),
+            Ok(other) => panic!("expected OsCall or Complete, got {other:?}"),
+            Err(exc) => return (calls, Err(exc)),
+        }
+    }
+}
+
+/// Host for file tests: `open()` returns handle 7 and reads come from a fixed two-line file.
+fn file_host(function: OsFunction, _args: &[MontyObject]) -> Result<MontyObject, MontyException> {
+    Ok(match function {
+        OsFunction::Open => MontyObject::Int(7),
+        OsFunction::FileRead => MontyObject::String("one\ntwo\n".to_owned()),
+        OsFunction::FileReadline => MontyObject::String("one\n".to_owned()),
+        OsFunction::FileReadlines => MontyObject::List(vec![
+            MontyObject::String("one\n".to_owned()),
+            MontyObject::String("two\n".to_owned()),
+        ]),
+        OsFunction::FileWrite => MontyObject::Int(3),
+        OsFunction::FileClose => MontyObject::None,
+        other => panic!("unexpected OS call {other}"),
+    })
+}
+
+fn string(s: &str) -> MontyObject {
+    MontyObject::String(s.to_owned())
+}
+
+#[test]
+fn open_yields_oscall_with_path_and_default_mode() {
+    let (func, args) = run_to_oscall("open('/data/in.txt')");
+    assert_eq!(func, OsFunction::Open);
+    assert_eq!(args, vec![string("/data/in.txt"), string("r")]);
+}
+
+#[test]
+fn open_mode_and_path_object() {
+    let (func, args) = run_to_oscall("from pathlib import Path\nopen(Path('/data/out.bin'), mode='wb')");
+    assert_eq!(func, OsFunction::Open);
+    assert_eq!(args, vec![string("/data/out.bin"), string("wb")]);
+}
+
+#[test]
+fn open_invalid_mode() {
+    let runner = MontyRun::new("open('f.txt', 'rw')".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let err = runner.run_no_limits(vec![]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ValueError);
+    assert_eq!(
+        err.message(),
+        Some("must have exactly one of create/read/write/append mode")
+    );
+}
+
+#[test]
+fn open_file_arg_type_error() {
+    let runner = MontyRun::new("open(1.5)".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let err = runner.run_no_limits(vec![]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::TypeError);
+    assert_eq!(
+        err.message(),
+        Some("expected str, bytes or os.PathLike object, not float")
+    );
+}
+
+#[test]
+fn file_read_methods_use_handle() {
+    let code = r"
+f = open('in.txt')
+a = f.read()
+b = f.readline()
+c = f.read(2)
+d = f.readlines()
+f.close()
+(a, b, c, d, f.closed)
+";
+    let (calls, result) = run_with_os_host(code, file_host);
+    let handle = MontyObject::Int(7);
+    assert_eq!(
+        calls,
+        vec![
+            (OsFunction::Open, vec![string("in.txt"), string("r")]),
+            (OsFunction::FileRead, vec![handle.clone(), MontyObject::Int(-1)]),
+            (OsFunction::FileReadline, vec![handle.clone(), MontyObject::Int(-1)]),
+            (OsFunction::FileRead, vec![handle.clone(), MontyObject::Int(2)]),
+            (OsFunction::FileReadlines, vec![handle.clone()]),
+            (OsFunction::FileClose, vec![handle]),
+        ]
+    );
+    let lines = MontyObject::List(vec![string("one\n"), string("two\n")]);
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::Tuple(vec![
+            string("one\ntwo\n"),
+            string("one\n"),
+            string("one\ntwo\n"),
+            lines,
+            MontyObject::Bool(true),
+        ])
+    );
+}
+
+#[test]
+fn file_write() {
+    let code = r"
+f = open('out.txt', 'w')
+n = f.write('abc')
+f.close()
+n
+";
+    let (calls, result) = run_with_os_host(code, file_host);
+    assert_eq!(
+        calls[1],
+        (OsFunction::FileWrite, vec![MontyObject::Int(7), string("abc")])
+    );
+    assert_eq!(result.unwrap(), MontyObject::Int(3));
+}
+
+#[test]
+fn file_write_type_error() {
+    let code = r"
+f = open('out.txt', 'w')
+try:
+    f.write(b'abc')
+    result = 'no error'
+except TypeError as e:
+    result = str(e)
+result
+";
+    let (calls, result) = run_with_os_host(code, file_host);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(result.unwrap(), string("write() argument must be str, not bytes"));
+}
+
+#[test]
+fn file_iteration_reads_lines() {
+    let code = r"
+f = open('in.txt')
+out = []
+for line in f:
+    out.append(line.strip())
+out
+";
+    let (calls, result) = run_with_os_host(code, file_host);
+    assert_eq!(calls[1], (OsFunction::FileReadlines, vec![MontyObject::Int(7)]));
+    assert_eq!(result.unwrap(), MontyObject::List(vec![string("one"), string("two")]));
+}
+
+#[test]
+fn file_closed_operations() {
+    let code = r"
+f = open('in.txt')
+f.close()
+f.close()
+try:
+    f.read()
+    result = 'no error'
+except ValueError as e:
+    result = str(e)
+result
+";
+    let (calls, result) = run_with_os_host(code, file_host);
+    // The second close() doesn't reach the host
+    assert_eq!(
+        calls.iter().map(|(function, _)| *function).collect::<Vec<_>>(),
+        vec![OsFunction::Open, OsFunction::FileClose]
+    );
+    assert_eq!(result.unwrap(), string("I/O operation on closed file."));
+}
+
+#[test]
+fn with_open_closes_file() {
+    let code = r"
+with open('in.txt') as f:
+    data = f.read()
+inside = []
+try:
+    with open('in.txt') as g:
+        inside.append(g.closed)
+        raise KeyError('boom')
+except KeyError:
+    pass
+(data, f.closed, inside, g.closed)
+";
+    let (calls, result) = run_with_os_host(code, file_host);
+    assert_eq!(
+        calls.iter().map(|(function, _)| *function).collect::<Vec<_>>(),
+        vec![
+            OsFunction::Open,
+            OsFunction::FileRead,
+            OsFunction::FileClose,
+            OsFunction::Open,
+            OsFunction::FileClose,
+        ]
+    );
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::Tuple(vec![
+            string("one\ntwo\n"),
+            MontyObject::Bool(true),
+            MontyObject::List(vec![MontyObject::Bool(false)]),
+            MontyObject::Bool(true),
+        ])
+    );
+}
+
+#[test]
+fn file_attributes_and_repr() {
+    let code = r"
+f = open('in.txt', 'rb')
+(f.name, f.mode, f.closed, repr(f), repr(open('in.txt')))
+";
+    let (_, result) = run_with_os_host(code, file_host);
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::Tuple(vec![
+            string("in.txt"),
+            string("rb"),
+            MontyObject::Bool(false),
+            string("<_io.BufferedReader name='in.txt'>"),
+            string("<_io.TextIOWrapper name='in.txt' mode='r' encoding='utf-8'>"),
+        ])
+    );
+}
+
+#[test]
+fn file_types_follow_mode() {
+    let code = r"
+[repr(type(open('f', mode))) for mode in ('r', 'w+', 'rb', 'wb', 'ab', 'xb', 'r+b', 'wb+')]
+";
+    let (_, result) = run_with_os_host(code, file_host);
+    let names = [
+        "TextIOWrapper",
+        "TextIOWrapper",
+        "BufferedReader",
+        "BufferedWriter",
+        "BufferedWriter",
+        "BufferedWriter",
+        "BufferedRandom",
+        "BufferedRandom",
+    ];
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::List(
+            names
+                .iter()
+                .map(|name| string(&format!("<class '_io.{name}'>")))
+                .collect()
+        )
+    );
+}
+
+#[test]
+fn file_mode_rejects_unsupported_operations() {
+    let code = r"
+errors = []
+for mode, op in [('w', 'read'), ('a', 'readline'), ('wb', 'readlines'), ('x', 'iter'), ('r', 'write'), ('rb', 'write')]:
+    f = open('f', mode)
+    try:
+        if op == 'write':
+            f.write(b'x' if 'b' in mode else 'x')
+        elif op == 'iter':
+            list(f)
+        else:
+            getattr(f, op)()
+        errors.append('no error')
+    except OSError as e:
+        errors.append(str(e))
+errors
+";
+    let (calls, result) = run_with_os_host(code, file_host);
+    // Every operation is rejected without reaching the host
+    assert!(calls.iter().all(|(function, _)| *function == OsFunction::Open));
+    let messages = [
+        "not readable",
+        "not readable",
+        "read",
+        "not readable",
+        "not writable",
+        "write",
+    ];
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::List(messages.iter().map(|msg| string(msg)).collect())
+    );
+}
+
+#[test]
+fn w_plus_file_can_read_and_write() {
+    let code = r"
+f = open('f', 'w+')
+(f.write('abc'), f.read())
+";
+    let (_, result) = run_with_os_host(code, file_host);
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::Tuple(vec![MontyObject::Int(3), string("one\ntwo\n")])
+    );
+}
+
+#[test]
+fn open_file_not_found_from_host() {
+    let code = r"
+try:
+    open('missing.txt')
+    result = 'no error'
+except FileNotFoundError as e:
+    result = str(e)
+result
+";
+    let (_, result) = run_with_os_host(code, |_, _| {
+        Err(MontyException::new(
+            ExcType::FileNotFoundError,
+            Some("[Errno 2] No such file or directory: 'missing.txt'".to_owned()),
+        ))
+    });
+    assert_eq!(
+        result.unwrap(),
+        string("[Errno 2] No such file or directory: 'missing.txt'")
+    );
+}
+
+#[test]
+fn open_invalid_handle_from_host() {
+    let (_, result) = run_with_os_host("open('in.txt')", |_, _| Ok(string("not a handle")));
+    let err = result.unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::RuntimeError);
+    assert_eq!(
+        err.message(),
+        Some("invalid return type: open() handle must be int, not str")
+    );
+}
+
+#[test]
+fn open_invalid_handle_can_be_caught() {
+    let code = r"
+try:
+    open('in.txt')
+    result = 'no error'
+except RuntimeError as e:
+    result = str(e)
+result
+";
+    let (_, result) = run_with_os_host(code, |_, _| Ok(string("not a handle")));
+    assert_eq!(
+        result.unwrap(),
+        string("invalid return type: open() handle must be int, not str")
+    );
+}
+
+#[test]
+fn open_inside_key_function_raises() {
+    let code = r"
+def key(name):
+    try:
+        return open(name).read()
+    except NotImplementedError as e:
+        return str(e)
+
+sorted(['in.txt'], key=key)[0]
+";
+    let (calls, result) = run_with_os_host(code, file_host);
+    assert!(calls.is_empty(), "a key function must not yield to the host");
+    assert_eq!(
+        result.unwrap(),
+        string("OS calls are not supported in functions called by builtins like sorted() or map()")
+    );
+}
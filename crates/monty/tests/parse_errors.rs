@@ -43,6 +43,133 @@ fn classes_return_not_implemented_error() {
     assert_eq!(get_exc_type(result), ExcType::NotImplementedError);
 }
 
+#[test]
+fn exception_subclasses_return_not_implemented_error() {
+    // User-defined exceptions need class support, so subclassing a builtin exception fails
+    // at parse time like any other class definition
+    let code = "class AppError(Exception):\n    pass\n\nclass NotFound(AppError):\n    pass\n";
+    let result = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]);
+    let exc = result.expect_err("expected parse error");
+    assert_eq!(exc.exc_type(), ExcType::NotImplementedError);
+    assert!(
+        exc.message().is_some_and(|m| m.contains("class definitions")),
+        "message should mention class definitions, got: {exc}"
+    );
+}
+
+/// Asserts that `code`, which defines a class, fails to parse because classes aren't supported.
+fn assert_class_not_implemented(code: &str) {
+    let result = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]);
+    let exc = result.expect_err("expected parse error");
+    assert_eq!(exc.exc_type(), ExcType::NotImplementedError, "for {code}");
+    assert!(
+        exc.message().is_some_and(|m| m.contains("class definitions")),
+        "message should mention class definitions, got: {exc}"
+    );
+}
+
+#[test]
+fn comparison_dunders_return_not_implemented_error() {
+    // Operator dispatch to `__eq__`, `__lt__` and `__hash__` needs user classes to define them on
+    assert_class_not_implemented(
+        r"
+class Version:
+    def __init__(self, n):
+        self.n = n
+
+    def __eq__(self, other):
+        return self.n == other.n
+
+    def __lt__(self, other):
+        return self.n < other.n
+
+    def __hash__(self):
+        return hash(self.n)
+",
+    );
+}
+
+#[test]
+fn container_dunders_return_not_implemented_error() {
+    // Subscripts, `len()` and iteration can only dispatch to dunders once user classes exist
+    assert_class_not_implemented(
+        r"
+class Deck:
+    def __init__(self):
+        self.cards = [1, 2, 3]
+
+    def __getitem__(self, i):
+        return self.cards[i]
+
+    def __setitem__(self, i, v):
+        self.cards[i] = v
+
+    def __len__(self):
+        return len(self.cards)
+
+    def __iter__(self):
+        return iter(self.cards)
+",
+    );
+}
+
+#[test]
+fn display_dunders_return_not_implemented_error() {
+    // `str()`, `repr()` and truthiness can only dispatch to dunders once user classes exist,
+    // whichever of them a class defines
+    for methods in [
+        "    def __str__(self):\n        return 'point'\n",
+        "    def __repr__(self):\n        return 'Point()'\n",
+        "    def __bool__(self):\n        return False\n",
+        "    def __len__(self):\n        return 0\n",
+    ] {
+        assert_class_not_implemented(&format!("class Point:\n{methods}"));
+    }
+}
+
+#[test]
+fn super_is_not_implemented() {
+    // `super()` needs classes and inheritance, so a subclass calling it fails at parse time
+    assert_class_not_implemented(
+        r"
+class Base:
+    def __init__(self, x):
+        self.x = x
+
+class Child(Base):
+    def __init__(self, x):
+        super().__init__(x)
+",
+    );
+    // Without classes it isn't a builtin either
+    assert_eq!(
+        run_and_get_exc_type("def f():\n    return super()\nf()"),
+        ExcType::NameError
+    );
+}
+
+#[test]
+fn property_is_not_implemented() {
+    // Properties need classes to be defined on, so a class using them fails at parse time
+    assert_class_not_implemented(
+        r"
+class Temperature:
+    def __init__(self):
+        self._celsius = 0
+
+    @property
+    def celsius(self):
+        return self._celsius
+
+    @celsius.setter
+    def celsius(self, value):
+        self._celsius = value
+",
+    );
+    // Without classes it isn't a builtin either
+    assert_eq!(run_and_get_exc_type("getter = property(lambda: 1)"), ExcType::NameError);
+}
+
 #[test]
 fn unknown_imports_compile_successfully_error_deferred_to_runtime() {
     // Unknown modules (not sys, typing, os, etc.) compile successfully.
@@ -53,9 +180,22 @@ fn unknown_imports_compile_successfully_error_deferred_to_runtime() {
 }
 
 #[test]
-fn with_statement_returns_not_implemented_error() {
-    let result = MontyRun::new("with open('f') as f: pass".to_owned(), "test.py", vec![], vec![]);
-    assert_eq!(get_exc_type(result), ExcType::NotImplementedError);
+fn async_with_statement_returns_not_implemented_error() {
+    let code = "async def foo():\n    async with bar() as b:\n        pass";
+    let result = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]);
+    let exc = result.expect_err("expected parse error");
+    assert_eq!(exc.exc_type(), ExcType::NotImplementedError);
+    assert!(
+        exc.message().is_some_and(|m| m.contains("async with")),
+        "message should mention 'async with', got: {exc}"
+    );
+}
+
+#[test]
+fn unsupported_statements_under_type_checking_are_skipped() {
+    let code = "from typing import TYPE_CHECKING\nif TYPE_CHECKING:\n    class Foo:\n        pass\n";
+    let result = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]);
+    assert!(result.is_ok(), "TYPE_CHECKING body should not be parsed");
 }
 
 #[test]
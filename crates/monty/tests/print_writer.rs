@@ -1,4 +1,6 @@
-use monty::{CollectStringPrint, MontyRun, NoLimitTracker, NoPrint};
+use std::borrow::Cow;
+
+use monty::{CollectStringPrint, MontyException, MontyRun, NoLimitTracker, NoPrint, PrintWriter};
 
 #[test]
 fn print_single_string() {
@@ -30,6 +32,42 @@ fn print_multiple_statements() {
     assert_eq!(writer.output(), "one\ntwo\nthree\n");
 }
 
+#[test]
+fn print_calls_recorded_separately() {
+    let code = r"
+print('one')
+print()
+print('two', 'three', sep=', ')
+print('no', end=' ')
+print('newline')
+print('multi\nline')
+print('end', end='')
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut writer = CollectStringPrint::new();
+    ex.run(vec![], NoLimitTracker, &mut writer).unwrap();
+    assert_eq!(
+        writer.prints(),
+        vec![
+            "one\n",
+            "\n",
+            "two, three\n",
+            "no ",
+            "newline\n",
+            "multi\nline\n",
+            "end"
+        ]
+    );
+}
+
+#[test]
+fn print_empty_end_still_recorded() {
+    let ex = MontyRun::new("print(end='')\nprint('a')".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut writer = CollectStringPrint::new();
+    ex.run(vec![], NoLimitTracker, &mut writer).unwrap();
+    assert_eq!(writer.prints(), vec!["", "a\n"]);
+}
+
 #[test]
 fn print_empty() {
     let ex = MontyRun::new("print()".to_owned(), "test.py", vec![], vec![]).unwrap();
@@ -218,3 +256,74 @@ fn print_multiline_sep() {
     ex.run(vec![], NoLimitTracker, &mut writer).unwrap();
     assert_eq!(writer.output(), "1\n2\n3\n");
 }
+
+#[test]
+fn print_uses_str_and_containers_use_repr() {
+    let code = r#"print("it's", 'tab\there', ["it's", 'a\n'])"#;
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut writer = CollectStringPrint::new();
+    ex.run(vec![], NoLimitTracker, &mut writer).unwrap();
+    assert_eq!(writer.output(), "it's tab\there [\"it's\", 'a\\n']\n");
+}
+
+/// Collects warnings issued with `warnings.warn()` alongside printed output.
+#[derive(Default)]
+struct CollectWarnings {
+    output: String,
+    warnings: Vec<(String, String)>,
+}
+
+impl PrintWriter for CollectWarnings {
+    fn stdout_write(&mut self, output: Cow<'_, str>) -> Result<(), MontyException> {
+        self.output.push_str(&output);
+        Ok(())
+    }
+
+    fn stdout_push(&mut self, end: char) -> Result<(), MontyException> {
+        self.output.push(end);
+        Ok(())
+    }
+
+    fn warn(&mut self, category: &str, message: Cow<'_, str>) -> Result<(), MontyException> {
+        self.warnings.push((category.to_owned(), message.into_owned()));
+        Ok(())
+    }
+}
+
+#[test]
+fn warnings_routed_to_writer() {
+    let code = "
+import warnings
+from warnings import warn
+
+warnings.warn('default category')
+warn('old api', DeprecationWarning)
+warnings.warn(message='from kwargs', category=RuntimeWarning)
+warn(FutureWarning('instance category'))
+print('done')
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut writer = CollectWarnings::default();
+    ex.run(vec![], NoLimitTracker, &mut writer).unwrap();
+    assert_eq!(writer.output, "done\n");
+    let expected = [
+        ("UserWarning", "default category"),
+        ("DeprecationWarning", "old api"),
+        ("RuntimeWarning", "from kwargs"),
+        ("FutureWarning", "instance category"),
+    ];
+    let expected: Vec<(String, String)> = expected
+        .iter()
+        .map(|(category, message)| ((*category).to_owned(), (*message).to_owned()))
+        .collect();
+    assert_eq!(writer.warnings, expected);
+}
+
+#[test]
+fn warnings_discarded_by_default() {
+    let code = "import warnings\nwarnings.warn('ignored')\nprint('ok')";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut writer = CollectStringPrint::new();
+    ex.run(vec![], NoLimitTracker, &mut writer).unwrap();
+    assert_eq!(writer.output(), "ok\n");
+}
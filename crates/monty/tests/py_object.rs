@@ -12,6 +12,11 @@ fn is_truthy_ellipsis_is_truthy() {
     assert!(MontyObject::Ellipsis.is_truthy());
 }
 
+#[test]
+fn is_truthy_not_implemented_is_truthy() {
+    assert!(MontyObject::NotImplemented.is_truthy());
+}
+
 #[test]
 fn is_truthy_false_is_falsy() {
     assert!(!MontyObject::Bool(false).is_truthy());
@@ -105,6 +110,7 @@ fn is_truthy_nonempty_dict_is_truthy() {
 fn type_name() {
     assert_eq!(MontyObject::None.type_name(), "NoneType");
     assert_eq!(MontyObject::Ellipsis.type_name(), "ellipsis");
+    assert_eq!(MontyObject::NotImplemented.type_name(), "NotImplementedType");
     assert_eq!(MontyObject::Bool(true).type_name(), "bool");
     assert_eq!(MontyObject::Bool(false).type_name(), "bool");
     assert_eq!(MontyObject::Int(0).type_name(), "int");
@@ -119,3 +125,27 @@ fn type_name() {
     assert_eq!(MontyObject::Tuple(vec![]).type_name(), "tuple");
     assert_eq!(MontyObject::dict(vec![]).type_name(), "dict");
 }
+
+/// Tests that `Display` gives `str()` output and `py_repr()` gives `repr()` output.
+
+#[test]
+fn string_display_and_repr() {
+    let s = MontyObject::String("it's\n".to_string());
+    assert_eq!(s.to_string(), "it's\n");
+    assert_eq!(s.py_repr(), "\"it's\\n\"");
+
+    let list = MontyObject::List(vec![MontyObject::String("a".to_string())]);
+    assert_eq!(list.to_string(), "['a']");
+    assert_eq!(list.py_repr(), "['a']");
+}
+
+/// Tests that only a single-item tuple gets a trailing comma in its repr.
+#[test]
+fn tuple_repr_trailing_comma() {
+    assert_eq!(MontyObject::Tuple(vec![]).py_repr(), "()");
+    assert_eq!(MontyObject::Tuple(vec![MontyObject::Int(1)]).py_repr(), "(1,)");
+    let pair = MontyObject::Tuple(vec![MontyObject::Int(1), MontyObject::Int(2)]);
+    assert_eq!(pair.py_repr(), "(1, 2)");
+    let nested = MontyObject::Tuple(vec![MontyObject::Tuple(vec![MontyObject::Int(1)])]);
+    assert_eq!(nested.to_string(), "((1,),)");
+}
@@ -0,0 +1,131 @@
+//! Tests for the `re` module behaviour that differs from CPython or can't be checked from Python:
+//! unsupported syntax, resource limits, host conversion and snapshots.
+
+use monty::{ExcType, LimitedTracker, MontyObject, MontyRun, NoLimitTracker, ResourceLimits, RunProgress, StdPrint};
+
+fn run(code: &str) -> Result<MontyObject, monty::MontyException> {
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    ex.run_no_limits(vec![])
+}
+
+#[test]
+fn backreference_not_supported() {
+    let exc = run("import re\nre.search(r'(a)\\1', 'aa')").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ValueError);
+    let message = exc.message().unwrap();
+    assert!(
+        message.starts_with("invalid regex '(a)\\\\1': ") && message.contains("backreferences are not supported"),
+        "unexpected message: {message}"
+    );
+}
+
+#[test]
+fn lookahead_not_supported() {
+    let exc = run("import re\nre.compile('a(?=b)')").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ValueError);
+    let message = exc.message().unwrap();
+    assert!(message.contains("look-around"), "unexpected message: {message}");
+}
+
+#[test]
+fn invalid_syntax_message() {
+    let exc = run("import re\nre.compile('a(b')").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ValueError);
+    assert_eq!(exc.message(), Some("invalid regex 'a(b': unclosed group"));
+}
+
+#[test]
+fn huge_pattern_rejected() {
+    let exc = run("import re\nre.compile('(?:a{1000}){1000}')").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ValueError);
+    assert_eq!(
+        exc.message(),
+        Some("invalid regex '(?:a{1000}){1000}': pattern too large")
+    );
+}
+
+#[test]
+fn unsupported_flag_rejected() {
+    // re.ASCII
+    let exc = run("import re\nre.compile('a', 256)").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ValueError);
+    assert_eq!(exc.message(), Some("unsupported regex flags: 256"));
+}
+
+#[test]
+fn callable_replacement_not_supported() {
+    let exc = run("import re\nre.sub('a', lambda m: 'b', 'a')").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::NotImplementedError);
+}
+
+#[test]
+fn pattern_and_match_repr_on_host() {
+    assert_eq!(
+        run("import re\nre.compile('a+', re.I)").unwrap(),
+        MontyObject::Repr("re.compile('a+', re.IGNORECASE)".to_owned())
+    );
+    assert_eq!(
+        run("import re\nre.search('b', 'abc')").unwrap(),
+        MontyObject::Repr("<re.Match object; span=(1, 2), match='b'>".to_owned())
+    );
+}
+
+#[test]
+fn match_methods_with_self_argument() {
+    // The match is passed to its own method, which must not panic
+    let exc = run("import re\nm = re.search('b', 'abc')\nm.group(m)").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::IndexError);
+}
+
+#[test]
+fn sub_respects_memory_limit() {
+    let code = "import re\nre.sub('', 'x' * 1000, 'a' * 1000)";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let limits = ResourceLimits::new().max_memory(100_000);
+    let exc = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint).unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
+#[test]
+fn findall_respects_memory_limit() {
+    // Every match is an empty string, which doesn't allocate, but the list still grows
+    let code = "import re\nre.findall('', 'a' * 20000)";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let limits = ResourceLimits::new().max_memory(100_000);
+    let exc = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint).unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
+#[test]
+fn small_patterns_fit_modest_memory_limit() {
+    // Each pattern is charged for the size limit it was compiled with, not the largest allowed
+    let code = "
+import re
+patterns = [re.compile('a' + str(i) + 'b') for i in range(10)]
+[i for i, p in enumerate(patterns) if p.match('a3b')]
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let limits = ResourceLimits::new().max_memory(1_000_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint).unwrap();
+    assert_eq!(result, MontyObject::List(vec![MontyObject::Int(3)]));
+}
+
+#[test]
+fn pattern_survives_snapshot() {
+    let code = "import re\np = re.compile(r'(\\d+)', re.M)\next_fn()\np.findall('1 22 333')";
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![], vec!["ext_fn".to_owned()]).unwrap();
+    let progress = runner.start(vec![], NoLimitTracker, &mut StdPrint).unwrap();
+
+    let bytes = progress.dump().unwrap();
+    let loaded: RunProgress<NoLimitTracker> = RunProgress::load(&bytes).unwrap();
+    let (_, _, _, _, state) = loaded.into_function_call().expect("should be at function call");
+    let result = state.run(MontyObject::None, &mut StdPrint).unwrap();
+    assert_eq!(
+        result.into_complete().unwrap(),
+        MontyObject::List(vec![
+            MontyObject::String("1".to_owned()),
+            MontyObject::String("22".to_owned()),
+            MontyObject::String("333".to_owned()),
+        ])
+    );
+}
@@ -0,0 +1,80 @@
+/// Tests for compiling in REPL mode with `CompileOptions::repl`, mirroring CPython's interactive echo.
+use monty::{CollectStringPrint, CompileOptions, MontyObject, MontyRun, NoLimitTracker};
+
+/// Runs `code` in REPL mode, returning the result and everything written to stdout.
+fn run_repl(code: &str) -> (MontyObject, String) {
+    let options = CompileOptions::new().repl(true);
+    let runner = MontyRun::new_with_options(code.to_owned(), "<stdin>", vec![], vec![], options).unwrap();
+    let mut print = CollectStringPrint::new();
+    let result = runner.run(vec![], NoLimitTracker, &mut print).unwrap();
+    (result, print.into_output())
+}
+
+#[test]
+fn each_expression_echoed() {
+    let (result, output) = run_repl("1 + 1\n'two'\n[3]");
+    assert_eq!(output, "2\n'two'\n[3]\n");
+    assert_eq!(result, MontyObject::None);
+}
+
+#[test]
+fn statements_and_none_not_echoed() {
+    let code = "
+x = 5
+None
+def f():
+    pass
+f()
+x
+";
+    let (_, output) = run_repl(code);
+    assert_eq!(output, "5\n");
+}
+
+#[test]
+fn echo_interleaves_with_print() {
+    let (_, output) = run_repl("print('a')\n'b'\nprint('c')");
+    assert_eq!(output, "a\n'b'\nc\n");
+}
+
+#[test]
+fn expressions_in_top_level_blocks_echoed() {
+    let code = "
+for i in range(3):
+    i * 10
+if True:
+    'yes'
+";
+    let (_, output) = run_repl(code);
+    assert_eq!(output, "0\n10\n20\n'yes'\n");
+}
+
+#[test]
+fn expressions_in_functions_not_echoed() {
+    let code = "
+def f():
+    'inside'
+    return 'result'
+f()
+";
+    let (_, output) = run_repl(code);
+    assert_eq!(output, "'result'\n");
+}
+
+#[test]
+fn default_mode_returns_last_expression() {
+    let runner = MontyRun::new("1\n2".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut print = CollectStringPrint::new();
+    let result = runner.run(vec![], NoLimitTracker, &mut print).unwrap();
+    assert_eq!(result, MontyObject::Int(2));
+    assert_eq!(print.output(), "");
+}
+
+#[test]
+fn echo_disassembled() {
+    let options = CompileOptions::new().repl(true);
+    let listing = MontyRun::new_with_options("1".to_owned(), "<stdin>", vec![], vec![], options)
+        .unwrap()
+        .disassemble();
+    assert!(listing.contains("PrintExpr"), "{listing}");
+}
@@ -4,7 +4,9 @@
 /// allocation limits, time limits, and triggers garbage collection.
 use std::time::Duration;
 
-use monty::{ExcType, LimitedTracker, MontyObject, MontyRun, ResourceLimits, StdPrint};
+use monty::{
+    CollectStringPrint, ExcType, LimitedTracker, MontyObject, MontyRun, NoLimitTracker, ResourceLimits, StdPrint,
+};
 
 /// Test that GC properly collects dict cycles via the has_refs() check in allocate().
 ///
@@ -43,7 +45,7 @@ result
 
     let output = ex.run_ref_counts(vec![]).expect("should succeed");
 
-    // GC_INTERVAL is 100,000. With 200,001 iterations creating dict cycles,
+    // DEFAULT_GC_INTERVAL is 100,000. With 200,001 iterations creating dict cycles,
     // GC must have run at least once, resetting allocations_since_gc.
     // If may_have_cycles was never set (has_refs() disabled), GC never runs
     // and allocations_since_gc would be ~400k (2 dicts per iteration).
@@ -94,7 +96,7 @@ len(result)
 
     let output = ex.run_ref_counts(vec![]).expect("should succeed");
 
-    // GC_INTERVAL is 100,000. With 200,001 iterations creating list cycles,
+    // DEFAULT_GC_INTERVAL is 100,000. With 200,001 iterations creating list cycles,
     // GC must have run at least twice, resetting allocations_since_gc.
     assert!(
         output.allocations_since_gc < 100_000,
@@ -125,6 +127,43 @@ len(result)
     );
 }
 
+/// Test that GC collects cycles made of two lists that reference each other.
+///
+/// Neither list's refcount reaches zero once the names are rebound, since each is
+/// still held by the other, so only mark-sweep can free them.
+#[test]
+#[cfg(feature = "ref-count-return")]
+fn gc_collects_mutual_list_cycles() {
+    // Two lists per iteration, so 100,001 iterations make 200,002 GC-tracked allocations
+    // and GC runs twice. After the loop only the final pair is reachable.
+    let code = r"
+for i in range(100001):
+    a = []
+    b = [a]
+    a.append(b)
+
+len(a)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let output = ex.run_ref_counts(vec![]).expect("should succeed");
+
+    assert!(
+        output.allocations_since_gc < 100_000,
+        "GC should have run: allocations_since_gc = {}",
+        output.allocations_since_gc
+    );
+    assert!(
+        output.heap_count < 20,
+        "GC should collect most unreachable list pairs: {} heap objects (expected < 20)",
+        output.heap_count
+    );
+
+    // `a` and `b` each have refcount 2: their variable + being inside the other
+    assert_eq!(output.counts.get("a"), Some(&2));
+    assert_eq!(output.counts.get("b"), Some(&2));
+}
+
 /// Test that allocation limits return an error.
 #[test]
 fn allocation_limit_exceeded() {
@@ -170,6 +209,34 @@ result
     assert!(result.is_ok(), "should not exceed allocation limit");
 }
 
+#[test]
+fn aggregates_over_generator_expressions_within_limits() {
+    // Generator expressions produce their items as they're consumed, so aggregating a million
+    // items needs a constant number of allocations and little memory, where building the
+    // items into a list first would need at least 16 bytes per item
+    let code = r"
+total = sum(x * x for x in range(1000000))
+largest = max(x % 1000 for x in range(1000000))
+rest = sum(iter(range(1000000)))
+(total, largest, rest)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    // Allocations: a range and its iterator per line, a generator per generator expression,
+    // and the result tuple
+    let limits = ResourceLimits::new().max_allocations(20).max_memory(100_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::Tuple(vec![
+            MontyObject::Int(333_332_833_333_500_000),
+            MontyObject::Int(999),
+            MontyObject::Int(499_999_500_000),
+        ])
+    );
+}
+
 #[test]
 fn time_limit_exceeded() {
     // Create a long-running loop using for + range (while isn't implemented yet)
@@ -210,6 +277,45 @@ fn time_limit_not_exceeded() {
     assert!(result.is_ok(), "should not exceed time limit");
 }
 
+#[test]
+fn step_limit_is_deterministic() {
+    let code = r"
+for i in range(100000000):
+    print(i)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    // Unlike a time limit, a step limit stops the program at the same instruction every run
+    let run_until_limit = || {
+        let mut print = CollectStringPrint::new();
+        let limits = ResourceLimits::new().max_steps(1000);
+        let exc = ex
+            .run(vec![], LimitedTracker::new(limits), &mut print)
+            .expect_err("should exceed step limit");
+        (exc, print.into_output())
+    };
+    let (exc, output) = run_until_limit();
+    assert_eq!(exc.exc_type(), ExcType::TimeoutError);
+    assert_eq!(exc.message(), Some("step limit exceeded: 1001 > 1000"));
+    assert!(!output.is_empty(), "should print before hitting the limit");
+
+    for _ in 0..3 {
+        let (_, again) = run_until_limit();
+        assert_eq!(again, output, "every run should stop after the same output");
+    }
+}
+
+#[test]
+fn step_limit_not_exceeded() {
+    let code = "x = 1 + 2\nx";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_steps(1000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    assert_eq!(result.unwrap(), MontyObject::Int(3));
+}
+
 /// Test that memory limits return an error.
 #[test]
 fn memory_limit_exceeded() {
@@ -237,6 +343,155 @@ result
     );
 }
 
+/// Test that a list growing in place is charged against the memory limit.
+///
+/// The ints are stored inline, so the only allocation is the empty list; the growth has to
+/// be charged as the list resizes.
+#[test]
+fn list_growth_memory_limit() {
+    let code = r"
+result = []
+for i in range(100_000):
+    result.append(i)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_memory(100_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    let exc = result.expect_err("list growth should exceed memory limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+    assert!(
+        exc.message().is_some_and(|m| m.contains("memory limit exceeded")),
+        "expected memory limit error, got: {exc}"
+    );
+}
+
+/// Test that `list.append()` charges the list's growth even when nothing else allocates.
+///
+/// Appending the same object over and over allocates nothing new, so only the growth of
+/// the list's storage counts against the limit.
+#[test]
+fn list_append_same_object_memory_limit() {
+    let code = r"
+item = [1, 2, 3]
+result = []
+for i in range(100_000):
+    result.append(item)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_memory(100_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    let exc = result.expect_err("appending should exceed memory limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
+/// Test that building a string with `+=` is charged against the memory limit.
+///
+/// The string is appended to in place rather than copied, so only its growth is charged.
+#[test]
+fn string_append_in_place_memory_limit() {
+    let code = r"
+s = ''
+for i in range(1_000_000):
+    s += 'x'
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_memory(100_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    let exc = result.expect_err("string growth should exceed memory limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
+/// Test that `%` formatting checks its whole result against the memory limit.
+///
+/// Each field is small enough on its own, but together they make a result over the limit.
+#[test]
+fn percent_format_many_wide_fields_memory_limit() {
+    for code in [
+        "'%9999d' * 100 % tuple(range(100))",
+        "b'%9999d' * 100 % tuple(range(100))",
+        "'%.9999f' * 100 % tuple(range(100))",
+    ] {
+        let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+        let limits = ResourceLimits::new().max_memory(500_000);
+        let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+        let exc = result.expect_err("formatting should exceed memory limit");
+        assert_eq!(exc.exc_type(), ExcType::MemoryError, "for {code}");
+    }
+}
+
+/// Test that a list comprehension's growth is charged against the memory limit.
+#[test]
+fn list_comprehension_growth_memory_limit() {
+    let code = "[i for i in range(100_000)]";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_memory(100_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    let exc = result.expect_err("list comprehension should exceed memory limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
+/// Test that appending to, extending and assigning into a bytearray is charged against the
+/// memory limit.
+#[test]
+fn bytearray_growth_memory_limit() {
+    for code in [
+        "b = bytearray()\nfor _ in range(200_000):\n    b.append(1)",
+        "b = bytearray()\nfor _ in range(200):\n    b.extend(b'x' * 1000)",
+        "b = bytearray()\nfor _ in range(200_000):\n    b += b'x'",
+        "b = bytearray()\nfor _ in range(200):\n    b[len(b):] = b'x' * 1000",
+    ] {
+        let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+        let limits = ResourceLimits::new().max_memory(100_000);
+        let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+        let exc = result.expect_err("bytearray growth should exceed memory limit");
+        assert_eq!(exc.exc_type(), ExcType::MemoryError, "code: {code}");
+    }
+}
+
+/// Test that a starred target collecting a huge iterable is stopped by the memory limit.
+#[test]
+fn star_unpack_memory_limit() {
+    let code = "first, *rest = range(10**12)";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_memory(1_000_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    let exc = result.expect_err("star unpacking should exceed memory limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
+/// Test that freeing a grown list credits back everything it was charged.
+///
+/// Each list needs over 1MB once grown, so the loop only fits in the limit if the old list's
+/// growth is released when it's replaced.
+#[test]
+fn grown_list_free_releases_growth() {
+    let code = r"
+for _ in range(10):
+    result = [i for i in range(50_000)]
+len(result)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_memory(5_000_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    assert_eq!(result.unwrap(), MontyObject::Int(50_000));
+}
+
 #[test]
 fn combined_limits() {
     // Test multiple limits together
@@ -289,6 +544,118 @@ len(result)
     assert!(result.is_ok(), "should succeed with GC enabled");
 }
 
+#[test]
+fn heap_stats_track_live_and_peak_objects() {
+    let code = r"
+x = [[i] for i in range(10)]
+foo()
+x = None
+foo()
+";
+    let run = MontyRun::new(code.to_owned(), "test.py", vec![], vec!["foo".to_owned()]).unwrap();
+
+    let (_, _, _, _, state) = run
+        .start(vec![], NoLimitTracker, &mut StdPrint)
+        .unwrap()
+        .into_function_call()
+        .expect("function call");
+    let before = state.heap_stats();
+    // The outer list and its 10 inner lists
+    assert!(before.live_objects >= 11, "live objects: {before:?}");
+    assert!(before.memory > 0, "memory: {before:?}");
+    assert!(before.peak_objects >= before.live_objects, "peak objects: {before:?}");
+
+    let (_, _, _, _, state) = state
+        .run(MontyObject::None, &mut StdPrint)
+        .unwrap()
+        .into_function_call()
+        .expect("second function call");
+    let after = state.heap_stats();
+    // Rebinding `x` frees all 11 lists, but the peak remembers them
+    assert_eq!(after.live_objects, before.live_objects - 11);
+    assert!(after.memory < before.memory, "memory: {before:?} -> {after:?}");
+    assert!(
+        after.peak_objects >= before.peak_objects,
+        "peak objects: {before:?} -> {after:?}"
+    );
+}
+
+/// Runs `code` until it calls `foo()` and returns the estimated heap memory at that point.
+fn memory_at_pause(code: &str) -> usize {
+    let run = MontyRun::new(code.to_owned(), "test.py", vec![], vec!["foo".to_owned()]).unwrap();
+    let (_, _, _, _, state) = run
+        .start(vec![], NoLimitTracker, &mut StdPrint)
+        .unwrap()
+        .into_function_call()
+        .expect("function call");
+    state.heap_stats().memory
+}
+
+#[test]
+fn heap_stats_memory_counts_owned_buffers() {
+    // Each structure's payload alone is ~100KB, so the estimate must be at least that,
+    // and shouldn't be more than a few times it once struct and growth overhead are added
+    let cases = [
+        ("a string's bytes", "s = 'x' * 100000\nfoo()"),
+        ("a list's items", "l = list(range(6250))\nfoo()"),
+        ("a dict's keys and values", "d = {i: None for i in range(3125)}\nfoo()"),
+        ("a set's items", "s = set(range(6250))\nfoo()"),
+    ];
+    for (what, code) in cases {
+        let memory = memory_at_pause(code);
+        assert!(
+            (100_000..400_000).contains(&memory),
+            "estimate should count {what}: {memory} bytes"
+        );
+    }
+}
+
+/// Code that leaves 2,000 unreachable self-referencing lists behind, far more than
+/// `CYCLE_MEMORY_LIMIT` bytes of them.
+const CYCLES_CODE: &str = r"
+for i in range(2000):
+    a = [i]
+    a.append(a)
+del a
+'done'
+";
+
+/// Memory limit that only fits the leaked cycles if GC reclaims them.
+const CYCLE_MEMORY_LIMIT: usize = 50_000;
+
+#[test]
+fn collect_cycles_reclaims_unreachable_cycles() {
+    let ex = MontyRun::new(CYCLES_CODE.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    // Each collection frees the cycles made since the last one, so memory stays bounded
+    let limits = ResourceLimits::new()
+        .max_memory(CYCLE_MEMORY_LIMIT)
+        .gc_interval(100)
+        .collect_cycles(true);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    assert_eq!(result.unwrap(), MontyObject::String("done".to_owned()));
+}
+
+#[test]
+#[cfg_attr(
+    feature = "ref-count-panic",
+    ignore = "resource exhaustion doesn't guarantee heap state consistency"
+)]
+fn collect_cycles_disabled_keeps_unreachable_cycles() {
+    let ex = MontyRun::new(CYCLES_CODE.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    // Reference counting alone never frees the cycles, so they exhaust the memory limit
+    let limits = ResourceLimits::new()
+        .max_memory(CYCLE_MEMORY_LIMIT)
+        .gc_interval(100)
+        .collect_cycles(false);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    let exc = result.expect_err("cycles should exhaust the memory limit without GC");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
 #[test]
 #[cfg_attr(
     feature = "ref-count-panic",
@@ -704,6 +1071,21 @@ fn bigint_rejected_before_allocation() {
     );
 }
 
+/// Test that a big int is charged for its cached decimal string as well as its digits.
+///
+/// `2 ** 200000` passes the 50KB pre-check, but the decimal cache adds about 67KB more.
+#[test]
+fn bigint_charges_decimal_cache() {
+    let code = "2 ** 200000";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_memory(60_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut StdPrint);
+
+    let exc = result.expect_err("the decimal cache should count towards the limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
 // === String/Bytes large result pre-check tests ===
 // These tests verify that string/bytes multiplication operations that would produce
 // very large results are rejected before the computation begins.
@@ -92,6 +92,11 @@ def run_file_and_get_traceback(
                     if frame.startswith('Traceback'):
                         result_frames.append(frame)
                         continue
+                    elif frame.startswith(('\nThe above exception', '\nDuring handling')):
+                        # A chained exception follows, skip its runner frames too
+                        result_frames.append(frame)
+                        found_user_code = False
+                        continue
                     elif '__asy.run(__test_main())' in frame:
                         # Skip the asyncio.run(__test_main()) wrapper frame
                         continue